@@ -125,6 +125,41 @@ pub enum Commands {
         yes: bool,
     },
 
+    /// Bulk-load nodes from a CSV file
+    Import {
+        /// Database path
+        #[arg(long, visible_alias = "db", default_value = "./db")]
+        path: PathBuf,
+
+        /// Target graph (e.g. /schema/graph), created if it does not exist
+        #[arg(long)]
+        graph: String,
+
+        /// Label given to every imported node
+        #[arg(long)]
+        label: String,
+
+        /// CSV file with a header row naming the properties
+        #[arg(long)]
+        file: PathBuf,
+
+        /// Column that identifies each row; rows with a missing or repeated id are rejected
+        #[arg(long)]
+        id: Option<String>,
+
+        /// Number of rows written per INSERT statement
+        #[arg(long, default_value_t = 1000)]
+        batch_size: usize,
+
+        /// Report and skip malformed rows instead of aborting
+        #[arg(long)]
+        skip_bad_rows: bool,
+
+        /// Parse and count rows without writing to the database
+        #[arg(long)]
+        dry_run: bool,
+    },
+
     /// Session management commands
     Session {
         #[command(subcommand)]
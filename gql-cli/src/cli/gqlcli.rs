@@ -241,7 +241,8 @@ pub fn handle_gql(
                 Err(e) => {
                     // Don't show error for duplicate entries with IF NOT EXISTS
                     // These are gracefully handled and expected
-                    if !e.contains("Duplicate entry") && !e.contains("already exists") {
+                    let message = e.message();
+                    if !message.contains("Duplicate entry") && !message.contains("already exists") {
                         eprintln!("{}", format!("Error: {}", e).red());
                     }
                 }
@@ -299,8 +300,17 @@ pub fn handle_query(
 
     // Show execution plan if requested
     if explain {
-        println!("{}", "Query execution plan not yet implemented".yellow());
-        return Ok(());
+        return match coordinator.explain_query(&query) {
+            Ok(plan) => {
+                print!("{}", plan.format_tree());
+                println!("{}", plan.summary());
+                Ok(())
+            }
+            Err(e) => {
+                eprintln!("{}", format!("Error: {}", e).red());
+                Err(e.into())
+            }
+        };
     }
 
     // Execute query
@@ -0,0 +1,370 @@
+// Copyright (c) 2024-2025 DeepGraph Inc.
+// SPDX-License-Identifier: Apache-2.0
+//
+//! Bulk CSV import for the `import` command
+//!
+//! Each CSV row becomes one node with the given label. The header row names
+//! the properties; cells are typed as numbers or booleans when they parse as
+//! such and stored as strings otherwise, and empty cells leave the property
+//! unset. Rows are written in batches, one INSERT statement per batch, so a
+//! failed batch never leaves a partial write behind.
+
+use colored::Colorize;
+use std::collections::HashSet;
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+use std::path::PathBuf;
+use std::time::Instant;
+
+use graphlite::QueryCoordinator;
+
+/// Largest integer an f64 represents exactly (2^53)
+const MAX_SAFE_INTEGER: f64 = 9_007_199_254_740_992.0;
+
+/// Options for a CSV import
+pub struct ImportOptions {
+    pub path: PathBuf,
+    pub graph: String,
+    pub label: String,
+    pub file: PathBuf,
+    pub id: Option<String>,
+    pub batch_size: usize,
+    pub skip_bad_rows: bool,
+    pub dry_run: bool,
+}
+
+/// Handle the import command
+pub fn handle_import(
+    options: ImportOptions,
+    user: Option<String>,
+    password: Option<String>,
+) -> Result<(), Box<dyn std::error::Error>> {
+    if options.batch_size == 0 {
+        return Err("--batch-size must be at least 1".into());
+    }
+    if !options.graph.starts_with('/') || options.graph.trim_matches('/').split('/').count() != 2 {
+        return Err(format!(
+            "Graph must be a full path like /schema/graph, got '{}'",
+            options.graph
+        )
+        .into());
+    }
+
+    let file =
+        File::open(&options.file).map_err(|e| format!("Cannot open {:?}: {}", options.file, e))?;
+    let mut reader = CsvReader::new(BufReader::new(file));
+
+    let header = match reader.next_record()? {
+        Some((_, header)) => header,
+        None => return Err(format!("{:?} is empty", options.file).into()),
+    };
+    let mut seen = HashSet::new();
+    for column in &header {
+        if column.is_empty() {
+            return Err("CSV header contains an empty column name".into());
+        }
+        if !seen.insert(column.as_str()) {
+            return Err(format!("CSV header repeats column '{}'", column).into());
+        }
+    }
+    let id_column = match &options.id {
+        Some(id) => match header.iter().position(|column| column == id) {
+            Some(index) => Some(index),
+            None => return Err(format!("--id column '{}' is not in the CSV header", id).into()),
+        },
+        None => None,
+    };
+
+    let mut target = if options.dry_run {
+        None
+    } else {
+        if !options.path.exists() {
+            return Err(format!(
+                "Database not found at {:?}. Run 'cargo run -- install' first.",
+                options.path
+            )
+            .into());
+        }
+        let coordinator = QueryCoordinator::from_path(&options.path)?;
+        let session_id = if let (Some(u), Some(p)) = (user, password) {
+            coordinator.authenticate_and_create_session(&u, &p)?
+        } else {
+            coordinator.create_simple_session("anonymous")?
+        };
+        ensure_graph(&coordinator, &session_id, &options.graph)?;
+        Some((coordinator, session_id))
+    };
+
+    let label = quote_identifier(&options.label);
+    let started = Instant::now();
+    let mut seen_ids = HashSet::new();
+    let mut batch = Vec::with_capacity(options.batch_size);
+    let mut imported = 0usize;
+    let mut skipped = 0usize;
+
+    let flush = |batch: &mut Vec<String>, imported: &mut usize| -> Result<(), String> {
+        if batch.is_empty() {
+            return Ok(());
+        }
+        if let Some((coordinator, session_id)) = &target {
+            let statement = format!("INSERT {}", batch.join(", "));
+            coordinator.process_query(&statement, session_id)?;
+        }
+        *imported += batch.len();
+        batch.clear();
+        let elapsed = started.elapsed().as_secs_f64();
+        println!(
+            "  {} rows ({:.0} rows/sec)",
+            imported,
+            *imported as f64 / elapsed.max(f64::EPSILON)
+        );
+        Ok(())
+    };
+
+    while let Some((line, record)) = reader.next_record()? {
+        let node = build_node(&label, &header, &record, id_column, &mut seen_ids);
+        match node {
+            Ok(node) => batch.push(node),
+            Err(reason) if options.skip_bad_rows => {
+                eprintln!("{}", format!("Skipping line {}: {}", line, reason).yellow());
+                skipped += 1;
+                continue;
+            }
+            Err(reason) => {
+                return Err(format!(
+                    "Line {}: {} (use --skip-bad-rows to continue past it)",
+                    line, reason
+                )
+                .into())
+            }
+        }
+
+        if batch.len() >= options.batch_size {
+            flush(&mut batch, &mut imported)?;
+        }
+    }
+    flush(&mut batch, &mut imported)?;
+
+    if let Some((coordinator, session_id)) = target.take() {
+        let _ = coordinator.close_session(&session_id);
+    }
+
+    let elapsed = started.elapsed().as_secs_f64();
+    let rate = imported as f64 / elapsed.max(f64::EPSILON);
+    if options.dry_run {
+        println!(
+            "{}",
+            format!(
+                "Dry run: {} rows parsed, {} bad rows, nothing written",
+                imported, skipped
+            )
+            .green()
+        );
+    } else {
+        println!(
+            "{}",
+            format!(
+                "Imported {} rows into {} in {:.2}s ({:.0} rows/sec), {} bad rows skipped",
+                imported, options.graph, elapsed, rate, skipped
+            )
+            .green()
+        );
+    }
+
+    Ok(())
+}
+
+/// Create the target schema and graph if needed and make it the session graph
+fn ensure_graph(
+    coordinator: &QueryCoordinator,
+    session_id: &str,
+    graph: &str,
+) -> Result<(), String> {
+    let set_graph = format!("SESSION SET GRAPH {}", graph);
+    if coordinator.process_query(&set_graph, session_id).is_ok() {
+        return Ok(());
+    }
+
+    let schema = graph
+        .trim_end_matches('/')
+        .rsplit_once('/')
+        .map_or("", |(s, _)| s);
+    coordinator.process_query(
+        &format!("CREATE SCHEMA IF NOT EXISTS {}", schema),
+        session_id,
+    )?;
+    coordinator.process_query(&format!("CREATE GRAPH IF NOT EXISTS {}", graph), session_id)?;
+    coordinator.process_query(&set_graph, session_id)?;
+    Ok(())
+}
+
+/// Render one CSV record as a node pattern, or explain why the row is bad
+fn build_node(
+    label: &str,
+    header: &[String],
+    record: &[String],
+    id_column: Option<usize>,
+    seen_ids: &mut HashSet<String>,
+) -> Result<String, String> {
+    if record.len() != header.len() {
+        return Err(format!(
+            "expected {} fields, found {}",
+            header.len(),
+            record.len()
+        ));
+    }
+
+    if let Some(index) = id_column {
+        let id = &record[index];
+        if id.is_empty() {
+            return Err(format!("missing value for id column '{}'", header[index]));
+        }
+        if seen_ids.contains(id) {
+            return Err(format!("duplicate id '{}'", id));
+        }
+    }
+
+    let mut properties = Vec::with_capacity(header.len());
+    for (column, cell) in header.iter().zip(record) {
+        if cell.is_empty() {
+            continue;
+        }
+        let literal = match literal_for_cell(cell) {
+            Some(literal) => literal,
+            None => {
+                return Err(format!(
+                    "value for '{}' cannot be written as a string literal",
+                    column
+                ))
+            }
+        };
+        properties.push(format!("{}: {}", quote_identifier(column), literal));
+    }
+
+    if let Some(index) = id_column {
+        seen_ids.insert(record[index].clone());
+    }
+    Ok(format!("(:{} {{{}}})", label, properties.join(", ")))
+}
+
+/// Type a CSV cell as a GQL literal
+///
+/// Returns None for strings the lexer cannot represent: strings containing
+/// both quote characters, or ending in a backslash that would escape the
+/// closing quote.
+fn literal_for_cell(cell: &str) -> Option<String> {
+    // Numbers beyond 2^53 lose precision and may overflow the lexer's integer
+    // literal, so they stay strings. So do cells whose text is not the number's
+    // canonical form, such as "0123" or "1e3": leading zeros mark codes and
+    // identifiers rather than quantities.
+    if let Ok(n) = cell.parse::<f64>() {
+        let canonical = n.to_string();
+        if n.is_finite() && n.abs() <= MAX_SAFE_INTEGER && canonical == cell {
+            return Some(canonical);
+        }
+    }
+    if cell.eq_ignore_ascii_case("true") || cell.eq_ignore_ascii_case("false") {
+        return Some(cell.to_ascii_lowercase());
+    }
+    if cell.ends_with('\\') {
+        return None;
+    }
+    if !cell.contains('\'') {
+        Some(format!("'{}'", cell))
+    } else if !cell.contains('"') {
+        Some(format!("\"{}\"", cell))
+    } else {
+        None
+    }
+}
+
+/// Quote a label or property name with backticks unless it is a plain identifier
+fn quote_identifier(name: &str) -> String {
+    let mut chars = name.chars();
+    let plain = chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_');
+    if plain {
+        name.to_string()
+    } else {
+        format!("`{}`", name.replace('`', "``"))
+    }
+}
+
+/// Minimal RFC 4180 reader: quoted fields may contain commas, doubled
+/// quotes and line breaks
+struct CsvReader<R: BufRead> {
+    input: R,
+    line: usize,
+}
+
+impl<R: BufRead> CsvReader<R> {
+    fn new(input: R) -> Self {
+        Self { input, line: 0 }
+    }
+
+    /// Read the next non-blank record along with the line it starts on
+    fn next_record(&mut self) -> Result<Option<(usize, Vec<String>)>, String> {
+        let mut buffer = String::new();
+        loop {
+            buffer.clear();
+            let read = self
+                .input
+                .read_line(&mut buffer)
+                .map_err(|e| format!("Failed to read CSV: {}", e))?;
+            if read == 0 {
+                return Ok(None);
+            }
+            self.line += 1;
+            if !buffer.trim_end_matches(['\r', '\n']).is_empty() {
+                break;
+            }
+        }
+
+        let start_line = self.line;
+        let mut fields = Vec::new();
+        let mut field = String::new();
+        let mut in_quotes = false;
+        loop {
+            let mut chars = buffer.chars().peekable();
+            while let Some(c) = chars.next() {
+                if in_quotes {
+                    if c == '"' {
+                        if chars.peek() == Some(&'"') {
+                            chars.next();
+                            field.push('"');
+                        } else {
+                            in_quotes = false;
+                        }
+                    } else {
+                        field.push(c);
+                    }
+                } else {
+                    match c {
+                        '"' if field.is_empty() => in_quotes = true,
+                        ',' => fields.push(std::mem::take(&mut field)),
+                        '\r' | '\n' => {}
+                        _ => field.push(c),
+                    }
+                }
+            }
+
+            if !in_quotes {
+                break;
+            }
+            buffer.clear();
+            let read = self
+                .input
+                .read_line(&mut buffer)
+                .map_err(|e| format!("Failed to read CSV: {}", e))?;
+            if read == 0 {
+                return Err(format!("Line {}: unterminated quoted field", start_line));
+            }
+            self.line += 1;
+        }
+        fields.push(field);
+
+        Ok(Some((start_line, fields)))
+    }
+}
@@ -4,11 +4,13 @@
 //! CLI module for GraphLite
 //!
 //! Provides command-line interface for database initialization,
-//! interactive GQL console (REPL), and one-off query execution.
+//! interactive GQL console (REPL), one-off query execution, and bulk CSV import.
 
 pub mod commands;
 pub mod gqlcli;
+pub mod import;
 pub mod output;
 
 pub use commands::{Cli, Commands};
 pub use gqlcli::{handle_gql, handle_install, handle_query};
+pub use import::{handle_import, ImportOptions};
@@ -163,6 +163,7 @@ impl ResultFormatter {
             Value::DateTimeWithFixedOffset(dt) => dt.to_string(),
             Value::DateTimeWithNamedTz(tz, dt) => format!("{} {}", dt, tz),
             Value::TimeWindow(tw) => format!("TIME_WINDOW({} to {})", tw.start, tw.end),
+            Value::Duration(d) => d.to_string(),
             Value::Path(path) => format!("{:?}", path),
             Value::Array(arr) | Value::List(arr) => format!(
                 "[{}]",
@@ -190,6 +191,18 @@ impl ResultFormatter {
                 format!("[{}:{}]", edge.id, edge.label)
             }
             Value::Temporal(temporal) => format!("TEMPORAL({:?})", temporal),
+            Value::Map(map) => {
+                let mut entries: Vec<_> = map.iter().collect();
+                entries.sort_by_key(|(k, _)| *k);
+                format!(
+                    "{{{}}}",
+                    entries
+                        .into_iter()
+                        .map(|(k, v)| format!("{}: {}", k, Self::value_to_string(v)))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                )
+            }
         }
     }
 
@@ -210,6 +223,7 @@ impl ResultFormatter {
                 "start": tw.start.to_string(),
                 "end": tw.end.to_string(),
             }),
+            Value::Duration(d) => serde_json::Value::String(d.to_string()),
             Value::Path(path) => serde_json::json!(format!("{:?}", path)),
             Value::Array(arr) | Value::List(arr) => {
                 serde_json::Value::Array(arr.iter().map(Self::value_to_json).collect())
@@ -238,6 +252,11 @@ impl ResultFormatter {
             Value::Temporal(temporal) => {
                 serde_json::json!(format!("{:?}", temporal))
             }
+            Value::Map(map) => serde_json::Value::Object(
+                map.iter()
+                    .map(|(k, v)| (k.clone(), Self::value_to_json(v)))
+                    .collect(),
+            ),
         }
     }
 
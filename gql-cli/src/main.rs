@@ -56,6 +56,30 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             ast,
         } => cli::handle_query(path, query, cli.user, cli.password, format, explain, ast),
 
+        Commands::Import {
+            path,
+            graph,
+            label,
+            file,
+            id,
+            batch_size,
+            skip_bad_rows,
+            dry_run,
+        } => cli::handle_import(
+            cli::ImportOptions {
+                path,
+                graph,
+                label,
+                file,
+                id,
+                batch_size,
+                skip_bad_rows,
+                dry_run,
+            },
+            cli.user,
+            cli.password,
+        ),
+
         Commands::Session { action: _, path: _ } => {
             println!("{}", "Session management not yet implemented".yellow());
             Ok(())
@@ -113,7 +113,7 @@ pub struct YieldItem {
 /// Query can be either a basic query or a set operation
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum Query {
-    Basic(BasicQuery),
+    Basic(Box<BasicQuery>),
     SetOperation(SetOperation),
     /// Query with top-level ORDER BY and/or LIMIT clauses
     Limited {
@@ -268,9 +268,24 @@ pub struct UnwindStatement {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MatchClause {
     pub patterns: Vec<PathPattern>,
+    /// USING hints that override the planner's choice of scan
+    pub hints: Vec<ScanHint>,
     pub location: Location,
 }
 
+/// Planner hint following a MATCH pattern
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub enum ScanHint {
+    /// USING INDEX var:Label(property): scan the variable through a property index
+    Index {
+        variable: String,
+        label: String,
+        property: String,
+    },
+    /// USING SCAN var[:Label]: scan the variable sequentially
+    Scan { variable: String },
+}
+
 /// Path type constraints for graph traversal
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Default)]
 pub enum PathType {
@@ -313,6 +328,9 @@ pub struct PathPattern {
     pub assignment: Option<String>,  // Optional path variable assignment
     pub path_type: Option<PathType>, // None means default (WALK)
     pub elements: Vec<PatternElement>,
+    /// Graph to match in (`MATCH (a) FROM /schema/graph`); None means the session graph
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub graph: Option<CatalogPath>,
     pub location: Location,
 }
 
@@ -323,12 +341,18 @@ pub enum PatternElement {
     Edge(Edge),
 }
 
-/// Node pattern: (identifier? :label? {properties}?)
+/// Node pattern: (identifier? :label? {properties}? (WHERE predicate)?)
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Node {
     pub identifier: Option<String>,
     pub labels: Vec<String>,
+    /// Label expression as written, e.g. `:Person&Employee` or `:Person|Company`;
+    /// `labels` holds every label it names
+    #[serde(default)]
+    pub label_expression: Option<LabelExpression>,
     pub properties: Option<PropertyMap>,
+    /// Inline predicate, e.g. `(p:Person WHERE p.age > 30)`
+    pub predicate: Option<Expression>,
     pub location: Location,
 }
 
@@ -338,6 +362,8 @@ pub struct Edge {
     pub identifier: Option<String>,
     pub labels: Vec<String>,
     pub properties: Option<PropertyMap>,
+    /// Inline predicate, e.g. `-[r:KNOWS WHERE r.since > 2020]->`
+    pub predicate: Option<Expression>,
     pub direction: EdgeDirection,
     pub quantifier: Option<PathQuantifier>,
     pub location: Location,
@@ -456,6 +482,25 @@ pub struct OrderItem {
     pub location: Location,
 }
 
+impl OrderItem {
+    /// Whether this item sorts ascending
+    pub fn is_ascending(&self) -> bool {
+        self.direction == OrderDirection::Ascending
+    }
+
+    /// Whether NULLs sort before all other values
+    ///
+    /// Without an explicit NULLS FIRST/LAST, NULL sorts as the largest value:
+    /// last for ASC and first for DESC.
+    pub fn nulls_first(&self) -> bool {
+        match self.nulls_ordering {
+            Some(NullsOrdering::First) => true,
+            Some(NullsOrdering::Last) => false,
+            None => !self.is_ascending(),
+        }
+    }
+}
+
 /// Order direction
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub enum OrderDirection {
@@ -470,14 +515,42 @@ pub enum NullsOrdering {
     Last,
 }
 
-/// LIMIT clause: LIMIT count [OFFSET offset]
+/// LIMIT clause: LIMIT count [OFFSET|SKIP offset]
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct LimitClause {
-    pub count: usize,
-    pub offset: Option<usize>,
+    pub count: LimitValue,
+    pub offset: Option<LimitValue>,
     pub location: Location,
 }
 
+/// Row count in a LIMIT or OFFSET/SKIP clause
+///
+/// Parameters are bound to literals at execution time, before planning.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum LimitValue {
+    Literal(usize),
+    Parameter(Parameter),
+}
+
+impl LimitValue {
+    /// The literal count, or None for an unbound parameter
+    pub fn literal(&self) -> Option<usize> {
+        match self {
+            LimitValue::Literal(count) => Some(*count),
+            LimitValue::Parameter(_) => None,
+        }
+    }
+}
+
+impl std::fmt::Display for LimitValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LimitValue::Literal(count) => write!(f, "{}", count),
+            LimitValue::Parameter(parameter) => write!(f, "${}", parameter.name),
+        }
+    }
+}
+
 /// GROUP BY clause: GROUP BY expression, expression, ...
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GroupClause {
@@ -504,6 +577,8 @@ pub enum Expression {
     Literal(Literal),
     Case(CaseExpression),
     PathConstructor(PathConstructor),
+    ListConstructor(ListConstructor),
+    MapConstructor(MapConstructor),
     Cast(CastExpression),
     Subquery(SubqueryExpression),
     ExistsSubquery(ExistsSubqueryExpression),
@@ -514,6 +589,8 @@ pub enum Expression {
     IsPredicate(IsPredicateExpression),
     Pattern(PatternExpression),
     ArrayIndex(ArrayIndexExpression),
+    ArraySlice(ArraySliceExpression),
+    Window(WindowFunction),
 }
 
 /// Binary expression: left op right
@@ -539,6 +616,18 @@ pub struct FunctionCall {
     pub name: String,
     pub distinct: DistinctQualifier,
     pub arguments: Vec<Expression>,
+    /// ORDER BY inside the argument list, e.g. ARRAY_AGG(x ORDER BY y)
+    #[serde(default)]
+    pub order_by: Vec<OrderItem>,
+    pub location: Location,
+}
+
+/// Window function call: name(args...) OVER ([PARTITION BY ...] [ORDER BY ...])
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WindowFunction {
+    pub function: FunctionCall,
+    pub partition_by: Vec<Expression>,
+    pub order_by: Vec<OrderItem>,
     pub location: Location,
 }
 
@@ -619,6 +708,8 @@ pub enum Literal {
     TimeWindow(String),
     Vector(Vec<f64>),
     List(Vec<Literal>),
+    /// Map literal: {key: value, ...} (entries kept in source order)
+    Map(Vec<(String, Literal)>),
 }
 
 /// Catalog statements (DDL operations)
@@ -630,6 +721,7 @@ pub enum CatalogStatement {
     DropGraph(DropGraphStatement),
     TruncateGraph(TruncateGraphStatement),
     ClearGraph(ClearGraphStatement),
+    CopyGraph(CopyGraphStatement),
     CreateGraphType(CreateGraphTypeStatement),
     DropGraphType(DropGraphTypeStatement),
     AlterGraphType(AlterGraphTypeStatement),
@@ -668,6 +760,8 @@ pub struct CreateGraphStatement {
     pub if_not_exists: bool,
     pub or_replace: bool,
     pub as_query: Option<Box<Query>>,
+    /// Options from a trailing `WITH (key = value, ...)` clause
+    pub options: Option<IndexOptions>,
     pub location: Location,
 }
 
@@ -694,6 +788,14 @@ pub struct ClearGraphStatement {
     pub location: Location,
 }
 
+/// COPY GRAPH statement
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CopyGraphStatement {
+    pub source_path: CatalogPath,
+    pub destination_path: CatalogPath,
+    pub location: Location,
+}
+
 /// CREATE GRAPH TYPE statement
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CreateGraphTypeStatement {
@@ -770,9 +872,20 @@ pub struct EdgeTypeSpec {
     pub properties: Option<PropertyTypeList>,
     pub source_vertex: Option<String>,
     pub destination_vertex: Option<String>,
+    /// ON DELETE clause: what a plain DELETE of an endpoint node does to edges of this type
+    pub on_delete: Option<EdgeDeleteAction>,
     pub location: Location,
 }
 
+/// ON DELETE action of an edge type
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub enum EdgeDeleteAction {
+    /// ON DELETE CASCADE: remove the edges together with the endpoint node
+    Cascade,
+    /// ON DELETE RESTRICT: refuse to delete the endpoint node
+    Restrict,
+}
+
 /// Label expression for type matching
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct LabelExpression {
@@ -787,6 +900,52 @@ pub struct LabelTerm {
     pub location: Location,
 }
 
+impl LabelExpression {
+    /// A conjunction of plain labels, as in `:Person&Employee`
+    pub fn all_of(labels: &[String]) -> Self {
+        LabelExpression {
+            terms: vec![LabelTerm {
+                factors: labels
+                    .iter()
+                    .map(|label| LabelFactor::Identifier(label.clone()))
+                    .collect(),
+                location: Location::default(),
+            }],
+            location: Location::default(),
+        }
+    }
+
+    /// Whether an element with `labels` satisfies the expression
+    ///
+    /// Terms are alternatives (`|`); every factor of a term is required
+    /// (`&`). The wildcard `%` matches any element with at least one label.
+    pub fn matches(&self, labels: &[String]) -> bool {
+        self.terms.iter().any(|term| {
+            term.factors.iter().all(|factor| match factor {
+                LabelFactor::Identifier(label) => labels.contains(label),
+                LabelFactor::Wildcard => !labels.is_empty(),
+                LabelFactor::Parenthesized(expr) => expr.matches(labels),
+            })
+        })
+    }
+
+    /// The labels every matching element must have, if the expression is a
+    /// plain conjunction such as `:Person&Employee`
+    pub fn required_labels(&self) -> Option<Vec<String>> {
+        match self.terms.as_slice() {
+            [term] => term
+                .factors
+                .iter()
+                .map(|factor| match factor {
+                    LabelFactor::Identifier(label) => Some(label.clone()),
+                    _ => None,
+                })
+                .collect(),
+            _ => None,
+        }
+    }
+}
+
 /// Label factor (basic label element)
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum LabelFactor {
@@ -894,9 +1053,24 @@ pub enum DataStatement {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct InsertStatement {
     pub graph_patterns: Vec<PathPattern>,
+    /// What to do when an inserted element already exists; without a clause
+    /// the INSERT fails
+    pub on_conflict: Option<ConflictAction>,
     pub location: Location,
 }
 
+/// ON CONFLICT clause of INSERT
+///
+/// Elements are identified by their content (labels and properties), so a
+/// conflict means an identical element is already stored.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum ConflictAction {
+    /// ON CONFLICT DO NOTHING: skip the duplicate silently
+    DoNothing,
+    /// ON CONFLICT DO UPDATE SET key = value, ...: update the stored element
+    DoUpdate(Vec<Property>),
+}
+
 /// MATCH INSERT statement: combines MATCH for binding variables with INSERT
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MatchInsertStatement {
@@ -921,10 +1095,10 @@ pub enum SetItem {
         property: PropertyAccess,
         value: Expression,
     },
-    Variable {
-        variable: String,
-        value: Expression,
-    },
+    /// `variable = {..}` replaces all of the entity's properties
+    Variable { variable: String, value: Expression },
+    /// `variable += {..}` adds or overwrites the listed properties
+    MergeProperties { variable: String, value: Expression },
     Label {
         variable: String,
         labels: LabelExpression,
@@ -964,6 +1138,8 @@ pub struct MatchSetStatement {
     pub with_clause: Option<WithClause>,
     pub where_clause: Option<WhereClause>,
     pub items: Vec<SetItem>,
+    /// Items of a REMOVE clause following SET, applied after the SET items
+    pub remove_items: Vec<RemoveItem>,
     pub location: Location,
 }
 
@@ -985,6 +1161,8 @@ pub struct MatchDeleteStatement {
     pub where_clause: Option<WhereClause>,
     pub expressions: Vec<Expression>,
     pub detach: bool,
+    /// Columns projected from the matched entities before they are deleted
+    pub return_clause: Option<ReturnClause>,
     pub location: Location,
 }
 
@@ -1015,6 +1193,10 @@ pub enum SessionSetClause {
     TimeZone {
         time_zone: String,
     },
+    /// `SESSION SET COLLATION 'name'`
+    Collation {
+        collation: String,
+    },
     GraphParameter {
         parameter: String,
         graph_initializer: GraphExpression,
@@ -1230,6 +1412,24 @@ pub struct PathConstructor {
     pub location: Location,
 }
 
+/// List constructor with computed elements: [expr1, expr2, ...]
+///
+/// Lists of literals parse to [`Literal::List`] instead.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ListConstructor {
+    pub elements: Vec<Expression>,
+    pub location: Location,
+}
+
+/// Map constructor with computed values: {key: expr, ...} (entries kept in source order)
+///
+/// Maps of literals parse to [`Literal::Map`] instead.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MapConstructor {
+    pub entries: Vec<(String, Expression)>,
+    pub location: Location,
+}
+
 /// CAST expression: CAST(expr AS type-spec)
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CastExpression {
@@ -1595,6 +1795,15 @@ pub struct ArrayIndexExpression {
     pub location: Location,
 }
 
+/// Array slice expression: array[start..end], where either bound may be omitted
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ArraySliceExpression {
+    pub array: Box<Expression>,
+    pub start: Option<Box<Expression>>,
+    pub end: Option<Box<Expression>>,
+    pub location: Location,
+}
+
 /// IS predicate types
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum IsPredicateType {
@@ -1712,6 +1921,8 @@ pub enum IndexStatement {
     Alter(AlterIndexStatement),
     Optimize(OptimizeIndexStatement),
     Reindex(ReindexStatement),
+    CreateConstraint(CreateConstraintStatement),
+    DropConstraint(DropConstraintStatement),
 }
 
 /// CREATE GRAPH INDEX statement
@@ -1756,6 +1967,33 @@ pub struct ReindexStatement {
     pub location: Location,
 }
 
+/// CREATE CONSTRAINT statement - a per-label property constraint
+/// Syntax: CREATE CONSTRAINT [IF NOT EXISTS] [name] ON Label ASSERT property IS UNIQUE | IS NOT NULL
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateConstraintStatement {
+    pub name: Option<String>,
+    pub label: String,
+    pub property: String,
+    pub assertion: ConstraintAssertion,
+    pub if_not_exists: bool,
+    pub location: Location,
+}
+
+/// What a property constraint asserts
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ConstraintAssertion {
+    Unique,
+    NotNull,
+}
+
+/// DROP CONSTRAINT statement
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DropConstraintStatement {
+    pub name: String,
+    pub if_exists: bool,
+    pub location: Location,
+}
+
 /// ALTER INDEX operations
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum AlterIndexOperation {
@@ -57,6 +57,8 @@ use nom::{
     IResult,
 };
 
+use super::ast::Location;
+
 /// Token types for GQL graph language
 #[derive(Debug, Clone, PartialEq)]
 pub enum Token {
@@ -231,6 +233,7 @@ pub enum Token {
 
     // Operators
     Plus,         // +
+    PlusEqual,    // +=
     Minus,        // -
     Star,         // *
     Slash,        // /
@@ -308,10 +311,25 @@ impl Lexer {
         }
     }
 
+    #[allow(dead_code)] // Token-only variant used by parser tests
     pub fn tokenize(&mut self) -> Result<Vec<Token>, String> {
-        let mut remaining = self.input.as_str();
+        Ok(self
+            .tokenize_with_locations()?
+            .into_iter()
+            .map(|(token, _)| token)
+            .collect())
+    }
+
+    /// Tokenize the input, pairing each token with where it starts in the source
+    ///
+    /// Lines and columns are 1-based; columns count characters, not bytes.
+    pub fn tokenize_with_locations(&mut self) -> Result<Vec<(Token, Location)>, String> {
+        let input = self.input.as_str();
+        let mut remaining = input;
         let mut tokens = Vec::new();
         let mut iteration = 0;
+        let mut line = 1;
+        let mut column = 1;
 
         // Main tokenization loop - processes input string character by character
         while !remaining.is_empty() {
@@ -323,7 +341,9 @@ impl Lexer {
             // 1. Whitespace function returning Token::Whitespace without consuming input
             // 2. Function parsers not advancing the input position
             // 3. Parser functions returning the same remaining string
-            if iteration > 1000 {
+            // Every token consumes at least one byte, so more iterations than
+            // input bytes means no progress is being made.
+            if iteration > input.len() {
                 return Err("Infinite loop detected in lexer".to_string());
             }
 
@@ -341,7 +361,22 @@ impl Lexer {
 
                     // Only add non-whitespace/non-comment tokens to the result
                     if !matches!(token, Token::Whitespace | Token::Comment(_)) {
-                        tokens.push(token);
+                        let location = Location {
+                            line,
+                            column,
+                            offset: input.len() - remaining.len(),
+                        };
+                        tokens.push((token, location));
+                    }
+
+                    let consumed = &remaining[..remaining.len() - next_remaining.len()];
+                    for ch in consumed.chars() {
+                        if ch == '\n' {
+                            line += 1;
+                            column = 1;
+                        } else {
+                            column += 1;
+                        }
                     }
                     remaining = next_remaining;
                 }
@@ -350,8 +385,15 @@ impl Lexer {
                 }
             }
         }
-        tokens.push(Token::Eof);
-        self.tokens = tokens.clone();
+        tokens.push((
+            Token::Eof,
+            Location {
+                line,
+                column,
+                offset: input.len(),
+            },
+        ));
+        self.tokens = tokens.iter().map(|(token, _)| token.clone()).collect();
         Ok(tokens)
     }
 }
@@ -380,8 +422,8 @@ fn token(input: &str) -> IResult<&str, Token> {
         map(integer_literal, Token::Integer),
         // Complex literals (use nom for parsing) - must come before function calls
         map(backtick_identifier, |s| {
-            Token::BacktickString(s.to_string())
-        }), // ISO GQL delimited identifiers
+            Token::BacktickString(s.replace("``", "`"))
+        }), // ISO GQL delimited identifiers, stored verbatim
         map(string_literal, |s| Token::String(s.to_string())),
         // Function calls removed - now handled by parser using individual tokens
         // This allows proper ISO GQL compliant parsing of nested function calls
@@ -427,14 +469,19 @@ fn is_word_boundary(c: char) -> bool {
     !c.is_alphanumeric() && c != '_'
 }
 
+/// Check if input starts with an ASCII keyword, ignoring case
+///
+/// Slices with `get` so multi-byte characters in the input never split mid-character.
+fn starts_with_ignore_case(input: &str, keyword: &str) -> bool {
+    input
+        .get(..keyword.len())
+        .is_some_and(|prefix| prefix.eq_ignore_ascii_case(keyword))
+}
+
 /// Check if a keyword match has a proper word boundary
 fn is_keyword_match(input: &str, keyword: &str) -> bool {
-    if input.len() < keyword.len() {
-        return false;
-    }
-
     // Check if the input starts with the keyword (case insensitive)
-    if !input[..keyword.len()].eq_ignore_ascii_case(keyword) {
+    if !starts_with_ignore_case(input, keyword) {
         return false;
     }
 
@@ -451,6 +498,8 @@ fn simple_patterns(input: &str) -> IResult<&str, Token> {
     // Multi-character operators (must come before single character)
     if let Some(rest) = input.strip_prefix("||") {
         Ok((rest, Token::Concat))
+    } else if let Some(rest) = input.strip_prefix("+=") {
+        Ok((rest, Token::PlusEqual))
     } else if let Some(rest) = input.strip_prefix("!=") {
         Ok((rest, Token::NotEqual))
     } else if let Some(rest) = input.strip_prefix("<>") {
@@ -472,458 +521,458 @@ fn simple_patterns(input: &str) -> IResult<&str, Token> {
     }
     // Keywords (case insensitive check) - longer keywords first
     else if input.len() >= 11
-        && input[..11].eq_ignore_ascii_case("DESTINATION")
+        && starts_with_ignore_case(input, "DESTINATION")
         && (input.len() == 11
             || (!input.chars().nth(11).unwrap().is_alphanumeric()
                 && input.chars().nth(11).unwrap() != '_'))
     {
         Ok((&input[11..], Token::Destination))
     } else if input.len() >= 10
-        && input[..10].eq_ignore_ascii_case("NORMALIZED")
+        && starts_with_ignore_case(input, "NORMALIZED")
         && (input.len() == 10
             || (!input.chars().nth(10).unwrap().is_alphanumeric()
                 && input.chars().nth(10).unwrap() != '_'))
     {
         Ok((&input[10..], Token::Normalized))
     } else if input.len() >= 8
-        && input[..8].eq_ignore_ascii_case("DIRECTED")
+        && starts_with_ignore_case(input, "DIRECTED")
         && (input.len() == 8
             || (!input.chars().nth(8).unwrap().is_alphanumeric()
                 && input.chars().nth(8).unwrap() != '_'))
     {
         Ok((&input[8..], Token::Directed))
     } else if input.len() >= 8
-        && input[..8].eq_ignore_ascii_case("PROPERTY")
+        && starts_with_ignore_case(input, "PROPERTY")
         && (input.len() == 8
             || (!input.chars().nth(8).unwrap().is_alphanumeric()
                 && input.chars().nth(8).unwrap() != '_'))
     {
         Ok((&input[8..], Token::Property))
     } else if input.len() >= 8
-        && input[..8].eq_ignore_ascii_case("DISTINCT")
+        && starts_with_ignore_case(input, "DISTINCT")
         && (input.len() == 8
             || (!input.chars().nth(8).unwrap().is_alphanumeric()
                 && input.chars().nth(8).unwrap() != '_'))
     {
         Ok((&input[8..], Token::Distinct))
     } else if input.len() >= 8
-        && input[..8].eq_ignore_ascii_case("CONTAINS")
+        && starts_with_ignore_case(input, "CONTAINS")
         && (input.len() == 8
             || (!input.chars().nth(8).unwrap().is_alphanumeric()
                 && input.chars().nth(8).unwrap() != '_'))
     {
         Ok((&input[8..], Token::Contains))
     } else if input.len() >= 6
-        && input[..6].eq_ignore_ascii_case("WITHIN")
+        && starts_with_ignore_case(input, "WITHIN")
         && (input.len() == 6
             || (!input.chars().nth(6).unwrap().is_alphanumeric()
                 && input.chars().nth(6).unwrap() != '_'))
     {
         Ok((&input[6..], Token::Within))
     } else if input.len() >= 7
-        && input[..7].eq_ignore_ascii_case("REPLACE")
+        && starts_with_ignore_case(input, "REPLACE")
         && (input.len() == 7
             || (input.chars().nth(7).unwrap_or(' ') != '('
                 && !input.chars().nth(7).unwrap_or(' ').is_alphanumeric()))
     {
         Ok((&input[7..], Token::Replace))
     } else if input.len() >= 7
-        && input[..7].eq_ignore_ascii_case("BOOLEAN")
+        && starts_with_ignore_case(input, "BOOLEAN")
         && (input.len() == 7
             || (!input.chars().nth(7).unwrap().is_alphanumeric()
                 && input.chars().nth(7).unwrap() != '_'))
     {
         Ok((&input[7..], Token::BooleanType))
     } else if input.len() >= 7
-        && input[..7].eq_ignore_ascii_case("INTEGER")
+        && starts_with_ignore_case(input, "INTEGER")
         && (input.len() == 7
             || (!input.chars().nth(7).unwrap().is_alphanumeric()
                 && input.chars().nth(7).unwrap() != '_'))
     {
         Ok((&input[7..], Token::IntegerType))
     } else if input.len() >= 8
-        && input[..8].eq_ignore_ascii_case("SMALLINT")
+        && starts_with_ignore_case(input, "SMALLINT")
         && (input.len() == 8
             || (!input.chars().nth(8).unwrap().is_alphanumeric()
                 && input.chars().nth(8).unwrap() != '_'))
     {
         Ok((&input[8..], Token::SmallIntType))
     } else if input.len() >= 6
-        && input[..6].eq_ignore_ascii_case("BIGINT")
+        && starts_with_ignore_case(input, "BIGINT")
         && (input.len() == 6
             || (!input.chars().nth(6).unwrap().is_alphanumeric()
                 && input.chars().nth(6).unwrap() != '_'))
     {
         Ok((&input[6..], Token::BigIntType))
     } else if input.len() >= 7
-        && input[..7].eq_ignore_ascii_case("DECIMAL")
+        && starts_with_ignore_case(input, "DECIMAL")
         && (input.len() == 7
             || (!input.chars().nth(7).unwrap().is_alphanumeric()
                 && input.chars().nth(7).unwrap() != '_'))
     {
         Ok((&input[7..], Token::DecimalType))
     } else if input.len() >= 6
-        && input[..6].eq_ignore_ascii_case("DOUBLE")
+        && starts_with_ignore_case(input, "DOUBLE")
         && (input.len() == 6
             || (!input.chars().nth(6).unwrap().is_alphanumeric()
                 && input.chars().nth(6).unwrap() != '_'))
     {
         Ok((&input[6..], Token::DoubleType))
     } else if input.len() >= 5
-        && input[..5].eq_ignore_ascii_case("FLOAT")
+        && starts_with_ignore_case(input, "FLOAT")
         && (input.len() == 5
             || (!input.chars().nth(5).unwrap().is_alphanumeric()
                 && input.chars().nth(5).unwrap() != '_'))
     {
         Ok((&input[5..], Token::FloatType))
     } else if input.len() >= 4
-        && input[..4].eq_ignore_ascii_case("REAL")
+        && starts_with_ignore_case(input, "REAL")
         && (input.len() == 4
             || (!input.chars().nth(4).unwrap().is_alphanumeric()
                 && input.chars().nth(4).unwrap() != '_'))
     {
         Ok((&input[4..], Token::RealType))
     } else if input.len() >= 7
-        && input[..7].eq_ignore_ascii_case("CASCADE")
+        && starts_with_ignore_case(input, "CASCADE")
         && (input.len() == 7
             || (!input.chars().nth(7).unwrap().is_alphanumeric()
                 && input.chars().nth(7).unwrap() != '_'))
     {
         Ok((&input[7..], Token::Cascade))
     } else if input.len() >= 7
-        && input[..7].eq_ignore_ascii_case("UNKNOWN")
+        && starts_with_ignore_case(input, "UNKNOWN")
         && (input.len() == 7
             || (!input.chars().nth(7).unwrap().is_alphanumeric()
                 && input.chars().nth(7).unwrap() != '_'))
     {
         Ok((&input[7..], Token::Unknown))
     } else if input.len() >= 8
-        && input[..8].eq_ignore_ascii_case("RESTRICT")
+        && starts_with_ignore_case(input, "RESTRICT")
         && (input.len() == 8
             || (!input.chars().nth(8).unwrap().is_alphanumeric()
                 && input.chars().nth(8).unwrap() != '_'))
     {
         Ok((&input[8..], Token::Restrict))
     } else if input.len() >= 14
-        && input[..14].eq_ignore_ascii_case("CHARACTERISTICS")
+        && starts_with_ignore_case(input, "CHARACTERISTICS")
         && (input.len() == 14
             || (!input.chars().nth(14).unwrap().is_alphanumeric()
                 && input.chars().nth(14).unwrap() != '_'))
     {
         Ok((&input[14..], Token::Characteristics))
     } else if input.len() >= 10
-        && input[..10].eq_ignore_ascii_case("PARAMETERS")
+        && starts_with_ignore_case(input, "PARAMETERS")
         && (input.len() == 10
             || (!input.chars().nth(10).unwrap().is_alphanumeric()
                 && input.chars().nth(10).unwrap() != '_'))
     {
         Ok((&input[10..], Token::Parameters))
     } else if input.len() >= 9
-        && input[..9].eq_ignore_ascii_case("PARAMETER")
+        && starts_with_ignore_case(input, "PARAMETER")
         && (input.len() == 9
             || (!input.chars().nth(9).unwrap().is_alphanumeric()
                 && input.chars().nth(9).unwrap() != '_'))
     {
         Ok((&input[9..], Token::Parameter))
     } else if input.len() >= 7
-        && input[..7].eq_ignore_ascii_case("SESSION")
+        && starts_with_ignore_case(input, "SESSION")
         && (input.len() == 7
             || (!input.chars().nth(7).unwrap().is_alphanumeric()
                 && input.chars().nth(7).unwrap() != '_'))
     {
         Ok((&input[7..], Token::Session))
     } else if input.len() >= 7
-        && input[..7].eq_ignore_ascii_case("BINDING")
+        && starts_with_ignore_case(input, "BINDING")
         && (input.len() == 7
             || (!input.chars().nth(7).unwrap().is_alphanumeric()
                 && input.chars().nth(7).unwrap() != '_'))
     {
         Ok((&input[7..], Token::Binding))
     } else if input.len() >= 7
-        && input[..7].eq_ignore_ascii_case("CURRENT")
+        && starts_with_ignore_case(input, "CURRENT")
         && (input.len() == 7
             || !input.chars().nth(7).unwrap_or(' ').is_alphanumeric()
                 && input.chars().nth(7).unwrap_or(' ') != '_')
     {
         Ok((&input[7..], Token::Current))
     } else if input.len() >= 8
-        && input[..8].eq_ignore_ascii_case("PASSWORD")
+        && starts_with_ignore_case(input, "PASSWORD")
         && (input.len() == 8
             || (!input.chars().nth(8).unwrap().is_alphanumeric()
                 && input.chars().nth(8).unwrap() != '_'))
     {
         Ok((&input[8..], Token::Password))
     } else if input.len() >= 6
-        && input[..6].eq_ignore_ascii_case("STRING")
+        && starts_with_ignore_case(input, "STRING")
         && (input.len() == 6
             || (!input.chars().nth(6).unwrap().is_alphanumeric()
                 && input.chars().nth(6).unwrap() != '_'))
     {
         Ok((&input[6..], Token::StringType))
     } else if input.len() >= 11
-        && input[..11].eq_ignore_ascii_case("DESCRIPTION")
+        && starts_with_ignore_case(input, "DESCRIPTION")
         && (input.len() == 11
             || !input.chars().nth(11).unwrap_or(' ').is_alphanumeric()
                 && input.chars().nth(11).unwrap_or(' ') != '_')
     {
         Ok((&input[11..], Token::Description))
     } else if input.len() >= 8
-        && input[..8].eq_ignore_ascii_case("DESCRIBE")
+        && starts_with_ignore_case(input, "DESCRIBE")
         && (input.len() == 8
             || !input.chars().nth(8).unwrap_or(' ').is_alphanumeric()
                 && input.chars().nth(8).unwrap_or(' ') != '_')
     {
         Ok((&input[8..], Token::Describe))
     } else if input.len() >= 8
-        && input[..8].eq_ignore_ascii_case("VALIDATE")
+        && starts_with_ignore_case(input, "VALIDATE")
         && (input.len() == 8
             || !input.chars().nth(8).unwrap_or(' ').is_alphanumeric()
                 && input.chars().nth(8).unwrap_or(' ') != '_')
     {
         Ok((&input[8..], Token::Validate))
     } else if input.len() >= 7
-        && input[..7].eq_ignore_ascii_case("VERSION")
+        && starts_with_ignore_case(input, "VERSION")
         && (input.len() == 7
             || !input.chars().nth(7).unwrap_or(' ').is_alphanumeric()
                 && input.chars().nth(7).unwrap_or(' ') != '_')
     {
         Ok((&input[7..], Token::Version))
     } else if input.len() >= 7
-        && input[..7].eq_ignore_ascii_case("AGAINST")
+        && starts_with_ignore_case(input, "AGAINST")
         && (input.len() == 7
             || !input.chars().nth(7).unwrap_or(' ').is_alphanumeric()
                 && input.chars().nth(7).unwrap_or(' ') != '_')
     {
         Ok((&input[7..], Token::Against))
     } else if input.len() >= 6
-        && input[..6].eq_ignore_ascii_case("SCHEMA")
+        && starts_with_ignore_case(input, "SCHEMA")
         && (input.len() == 6
             || !input.chars().nth(6).unwrap_or(' ').is_alphanumeric()
                 && input.chars().nth(6).unwrap_or(' ') != '_')
     {
         Ok((&input[6..], Token::Schema))
     } else if input.len() >= 6
-        && input[..6].eq_ignore_ascii_case("INLINE")
+        && starts_with_ignore_case(input, "INLINE")
         && (input.len() == 6
             || !input.chars().nth(6).unwrap_or(' ').is_alphanumeric()
                 && input.chars().nth(6).unwrap_or(' ') != '_')
     {
         Ok((&input[6..], Token::Inline))
     } else if input.len() >= 5
-        && input[..5].eq_ignore_ascii_case("ALTER")
+        && starts_with_ignore_case(input, "ALTER")
         && (input.len() == 5
             || !input.chars().nth(5).unwrap_or(' ').is_alphanumeric()
                 && input.chars().nth(5).unwrap_or(' ') != '_')
     {
         Ok((&input[5..], Token::Alter))
     } else if input.len() >= 4
-        && input[..4].eq_ignore_ascii_case("SHOW")
+        && starts_with_ignore_case(input, "SHOW")
         && (input.len() == 4
             || !input.chars().nth(4).unwrap_or(' ').is_alphanumeric()
                 && input.chars().nth(4).unwrap_or(' ') != '_')
     {
         Ok((&input[4..], Token::Show))
     } else if input.len() >= 4
-        && input[..4].eq_ignore_ascii_case("DATA")
+        && starts_with_ignore_case(input, "DATA")
         && (input.len() == 4
             || !input.chars().nth(4).unwrap_or(' ').is_alphanumeric()
                 && input.chars().nth(4).unwrap_or(' ') != '_')
     {
         Ok((&input[4..], Token::Data))
     } else if input.len() >= 4
-        && input[..4].eq_ignore_ascii_case("FILE")
+        && starts_with_ignore_case(input, "FILE")
         && (input.len() == 4
             || !input.chars().nth(4).unwrap_or(' ').is_alphanumeric()
                 && input.chars().nth(4).unwrap_or(' ') != '_')
     {
         Ok((&input[4..], Token::File))
     } else if input.len() >= 3
-        && input[..3].eq_ignore_ascii_case("URL")
+        && starts_with_ignore_case(input, "URL")
         && (input.len() == 3
             || !input.chars().nth(3).unwrap_or(' ').is_alphanumeric()
                 && input.chars().nth(3).unwrap_or(' ') != '_')
     {
         Ok((&input[3..], Token::Url))
     } else if input.len() >= 7
-        && input[..7].eq_ignore_ascii_case("ACYCLIC")
+        && starts_with_ignore_case(input, "ACYCLIC")
         && (input.len() == 7
             || (!input.chars().nth(7).unwrap().is_alphanumeric()
                 && input.chars().nth(7).unwrap() != '_'))
     {
         Ok((&input[7..], Token::Acyclic))
     } else if input.len() >= 6
-        && input[..6].eq_ignore_ascii_case("SIMPLE")
+        && starts_with_ignore_case(input, "SIMPLE")
         && (input.len() == 6
             || (!input.chars().nth(6).unwrap_or(' ').is_alphanumeric()
                 && input.chars().nth(6).unwrap_or(' ') != '_'))
     {
         Ok((&input[6..], Token::Simple))
     } else if input.len() >= 5
-        && input[..5].eq_ignore_ascii_case("TRAIL")
+        && starts_with_ignore_case(input, "TRAIL")
         && (input.len() == 5
             || (!input.chars().nth(5).unwrap().is_alphanumeric()
                 && input.chars().nth(5).unwrap() != '_'))
     {
         Ok((&input[5..], Token::Trail))
     } else if input.len() >= 4
-        && input[..4].eq_ignore_ascii_case("WALK")
+        && starts_with_ignore_case(input, "WALK")
         && (input.len() == 4
             || (!input.chars().nth(4).unwrap().is_alphanumeric()
                 && input.chars().nth(4).unwrap() != '_'))
     {
         Ok((&input[4..], Token::Walk))
     } else if input.len() >= 4
-        && input[..4].eq_ignore_ascii_case("PATH")
+        && starts_with_ignore_case(input, "PATH")
         && (input.len() == 4
             || (!input.chars().nth(4).unwrap().is_alphanumeric()
                 && input.chars().nth(4).unwrap() != '_'))
     {
         Ok((&input[4..], Token::Path))
     } else if input.len() >= 6
-        && input[..6].eq_ignore_ascii_case("CREATE")
-        && (input.len() == 6 || !input.chars().nth(6).unwrap_or(' ').is_alphanumeric())
+        && starts_with_ignore_case(input, "CREATE")
+        && (input.len() == 6 || is_word_boundary(input.chars().nth(6).unwrap_or(' ')))
     {
         Ok((&input[6..], Token::Create))
     } else if input.len() >= 6
-        && input[..6].eq_ignore_ascii_case("INSERT")
+        && starts_with_ignore_case(input, "INSERT")
         && (input.len() == 6
             || (!input.chars().nth(6).unwrap().is_alphanumeric()
                 && input.chars().nth(6).unwrap() != '_'))
     {
         Ok((&input[6..], Token::Insert))
     } else if input.len() >= 6
-        && input[..6].eq_ignore_ascii_case("DELETE")
+        && starts_with_ignore_case(input, "DELETE")
         && (input.len() == 6
             || (!input.chars().nth(6).unwrap().is_alphanumeric()
                 && input.chars().nth(6).unwrap() != '_'))
     {
         Ok((&input[6..], Token::Delete))
     } else if input.len() >= 6
-        && input[..6].eq_ignore_ascii_case("REMOVE")
+        && starts_with_ignore_case(input, "REMOVE")
         && (input.len() == 6
             || (!input.chars().nth(6).unwrap().is_alphanumeric()
                 && input.chars().nth(6).unwrap() != '_'))
     {
         Ok((&input[6..], Token::Remove))
     } else if input.len() >= 6
-        && input[..6].eq_ignore_ascii_case("UNWIND")
+        && starts_with_ignore_case(input, "UNWIND")
         && (input.len() == 6
             || (!input.chars().nth(6).unwrap().is_alphanumeric()
                 && input.chars().nth(6).unwrap() != '_'))
     {
         Ok((&input[6..], Token::Unwind))
     } else if input.len() >= 6
-        && input[..6].eq_ignore_ascii_case("STARTS")
+        && starts_with_ignore_case(input, "STARTS")
         && (input.len() == 6
             || (!input.chars().nth(6).unwrap().is_alphanumeric()
                 && input.chars().nth(6).unwrap() != '_'))
     {
         Ok((&input[6..], Token::Starts))
     } else if input.len() >= 6
-        && input[..6].eq_ignore_ascii_case("EXISTS")
+        && starts_with_ignore_case(input, "EXISTS")
         && (input.len() == 6
             || (!input.chars().nth(6).unwrap().is_alphanumeric()
                 && input.chars().nth(6).unwrap() != '_'))
     {
         Ok((&input[6..], Token::Exists))
     } else if input.len() >= 7
-        && input[..7].eq_ignore_ascii_case("MATCHES")
+        && starts_with_ignore_case(input, "MATCHES")
         && (input.len() == 7
             || (!input.chars().nth(7).unwrap().is_alphanumeric()
                 && input.chars().nth(7).unwrap() != '_'))
     {
         Ok((&input[7..], Token::Matches))
     } else if input.len() >= 6
-        && input[..6].eq_ignore_ascii_case("SOURCE")
+        && starts_with_ignore_case(input, "SOURCE")
         && (input.len() == 6
             || (!input.chars().nth(6).unwrap().is_alphanumeric()
                 && input.chars().nth(6).unwrap() != '_'))
     {
         Ok((&input[6..], Token::Source))
     } else if input.len() >= 4
-        && input[..4].eq_ignore_ascii_case("CASE")
+        && starts_with_ignore_case(input, "CASE")
         && (input.len() == 4
             || (!input.chars().nth(4).unwrap().is_alphanumeric()
                 && input.chars().nth(4).unwrap() != '_'))
     {
         Ok((&input[4..], Token::Case))
     } else if input.len() >= 4
-        && input[..4].eq_ignore_ascii_case("CAST")
+        && starts_with_ignore_case(input, "CAST")
         && (input.len() == 4
             || (!input.chars().nth(4).unwrap().is_alphanumeric()
                 && input.chars().nth(4).unwrap() != '_'))
     {
         Ok((&input[4..], Token::Cast))
     } else if input.len() >= 4
-        && input[..4].eq_ignore_ascii_case("WHEN")
+        && starts_with_ignore_case(input, "WHEN")
         && (input.len() == 4
             || (!input.chars().nth(4).unwrap().is_alphanumeric()
                 && input.chars().nth(4).unwrap() != '_'))
     {
         Ok((&input[4..], Token::When))
     } else if input.len() >= 4
-        && input[..4].eq_ignore_ascii_case("THEN")
+        && starts_with_ignore_case(input, "THEN")
         && (input.len() == 4
             || (!input.chars().nth(4).unwrap().is_alphanumeric()
                 && input.chars().nth(4).unwrap() != '_'))
     {
         Ok((&input[4..], Token::Then))
     } else if input.len() >= 4
-        && input[..4].eq_ignore_ascii_case("ELSE")
+        && starts_with_ignore_case(input, "ELSE")
         && (input.len() == 4
             || (!input.chars().nth(4).unwrap().is_alphanumeric()
                 && input.chars().nth(4).unwrap() != '_'))
     {
         Ok((&input[4..], Token::Else))
     } else if input.len() >= 3
-        && input[..3].eq_ignore_ascii_case("END")
+        && starts_with_ignore_case(input, "END")
         && (input.len() == 3
             || (!input.chars().nth(3).unwrap().is_alphanumeric()
                 && input.chars().nth(3).unwrap() != '_'))
     {
         Ok((&input[3..], Token::End))
     } else if input.len() >= 7
-        && input[..7].eq_ignore_ascii_case("LEADING")
-        && (input.len() == 7 || !input.chars().nth(7).unwrap_or(' ').is_alphanumeric())
+        && starts_with_ignore_case(input, "LEADING")
+        && (input.len() == 7 || is_word_boundary(input.chars().nth(7).unwrap_or(' ')))
     {
         Ok((&input[7..], Token::Leading))
     } else if input.len() >= 8
-        && input[..8].eq_ignore_ascii_case("TRAILING")
-        && (input.len() == 8 || !input.chars().nth(8).unwrap_or(' ').is_alphanumeric())
+        && starts_with_ignore_case(input, "TRAILING")
+        && (input.len() == 8 || is_word_boundary(input.chars().nth(8).unwrap_or(' ')))
     {
         Ok((&input[8..], Token::Trailing))
     } else if input.len() >= 4
-        && input[..4].eq_ignore_ascii_case("BOTH")
-        && (input.len() == 4 || !input.chars().nth(4).unwrap_or(' ').is_alphanumeric())
+        && starts_with_ignore_case(input, "BOTH")
+        && (input.len() == 4 || is_word_boundary(input.chars().nth(4).unwrap_or(' ')))
     {
         Ok((&input[4..], Token::Both))
     } else if input.len() >= 3
-        && input[..3].eq_ignore_ascii_case("LET")
-        && (input.len() == 3 || !input.chars().nth(3).unwrap_or(' ').is_alphanumeric())
+        && starts_with_ignore_case(input, "LET")
+        && (input.len() == 3 || is_word_boundary(input.chars().nth(3).unwrap_or(' ')))
     {
         Ok((&input[3..], Token::Let))
     } else if input.len() >= 3
-        && input[..3].eq_ignore_ascii_case("FOR")
-        && (input.len() == 3 || !input.chars().nth(3).unwrap_or(' ').is_alphanumeric())
+        && starts_with_ignore_case(input, "FOR")
+        && (input.len() == 3 || is_word_boundary(input.chars().nth(3).unwrap_or(' ')))
     {
         Ok((&input[3..], Token::For))
     } else if input.len() >= 6
-        && input[..6].eq_ignore_ascii_case("FILTER")
-        && (input.len() == 6 || !input.chars().nth(6).unwrap_or(' ').is_alphanumeric())
+        && starts_with_ignore_case(input, "FILTER")
+        && (input.len() == 6 || is_word_boundary(input.chars().nth(6).unwrap_or(' ')))
     {
         Ok((&input[6..], Token::Filter))
     } else if input.len() >= 7
-        && input[..7].eq_ignore_ascii_case("DECLARE")
-        && (input.len() == 7 || !input.chars().nth(7).unwrap_or(' ').is_alphanumeric())
+        && starts_with_ignore_case(input, "DECLARE")
+        && (input.len() == 7 || is_word_boundary(input.chars().nth(7).unwrap_or(' ')))
     {
         Ok((&input[7..], Token::Declare))
     } else if input.len() >= 4
-        && input[..4].eq_ignore_ascii_case("NEXT")
-        && (input.len() == 4 || !input.chars().nth(4).unwrap_or(' ').is_alphanumeric())
+        && starts_with_ignore_case(input, "NEXT")
+        && (input.len() == 4 || is_word_boundary(input.chars().nth(4).unwrap_or(' ')))
     {
         Ok((&input[4..], Token::Next))
     } else if input.len() >= 2
-        && input[..2].eq_ignore_ascii_case("AT")
+        && starts_with_ignore_case(input, "AT")
         && (input.len() == 2
             || (!input.chars().nth(2).unwrap_or(' ').is_alphanumeric()
                 && input.chars().nth(2).unwrap_or(' ') != '_'))
@@ -962,98 +1011,98 @@ fn simple_patterns(input: &str) -> IResult<&str, Token> {
     } else if is_keyword_match(input, "COMMIT") {
         Ok((&input[6..], Token::Commit))
     } else if input.len() >= 6
-        && input[..6].eq_ignore_ascii_case("VERTEX")
+        && starts_with_ignore_case(input, "VERTEX")
         && (input.len() == 6
             || (!input.chars().nth(6).unwrap().is_alphanumeric()
                 && input.chars().nth(6).unwrap() != '_'))
     {
         Ok((&input[6..], Token::Vertex))
     } else if input.len() >= 8
-        && input[..8].eq_ignore_ascii_case("NODETACH")
+        && starts_with_ignore_case(input, "NODETACH")
         && (input.len() == 8
             || (!input.chars().nth(8).unwrap().is_alphanumeric()
                 && input.chars().nth(8).unwrap() != '_'))
     {
         Ok((&input[8..], Token::NoDetach))
     } else if input.len() >= 6
-        && input[..6].eq_ignore_ascii_case("DETACH")
+        && starts_with_ignore_case(input, "DETACH")
         && (input.len() == 6
             || (!input.chars().nth(6).unwrap().is_alphanumeric()
                 && input.chars().nth(6).unwrap() != '_'))
     {
         Ok((&input[6..], Token::Detach))
     } else if input.len() >= 6
-        && input[..6].eq_ignore_ascii_case("SELECT")
+        && starts_with_ignore_case(input, "SELECT")
         && (input.len() == 6
             || (!input.chars().nth(6).unwrap().is_alphanumeric()
                 && input.chars().nth(6).unwrap() != '_'))
     {
         Ok((&input[6..], Token::Select))
     } else if input.len() >= 4
-        && input[..4].eq_ignore_ascii_case("FROM")
+        && starts_with_ignore_case(input, "FROM")
         && (input.len() == 4
             || (!input.chars().nth(4).unwrap().is_alphanumeric()
                 && input.chars().nth(4).unwrap() != '_'))
     {
         Ok((&input[4..], Token::From))
     } else if input.len() >= 6
-        && input[..6].eq_ignore_ascii_case("RETURN")
+        && starts_with_ignore_case(input, "RETURN")
         && (input.len() == 6
             || (!input.chars().nth(6).unwrap().is_alphanumeric()
                 && input.chars().nth(6).unwrap() != '_'))
     {
         Ok((&input[6..], Token::Return))
     } else if input.len() >= 5
-        && input[..5].eq_ignore_ascii_case("MATCH")
+        && starts_with_ignore_case(input, "MATCH")
         && (input.len() == 5
             || (!input.chars().nth(5).unwrap_or(' ').is_alphanumeric()
                 && input.chars().nth(5).unwrap_or(' ') != '_'))
     {
         Ok((&input[5..], Token::Match))
     } else if input.len() >= 5
-        && input[..5].eq_ignore_ascii_case("WHERE")
+        && starts_with_ignore_case(input, "WHERE")
         && (input.len() == 5
             || (!input.chars().nth(5).unwrap().is_alphanumeric()
                 && input.chars().nth(5).unwrap() != '_'))
     {
         Ok((&input[5..], Token::Where))
     } else if input.len() >= 5
-        && input[..5].eq_ignore_ascii_case("MERGE")
+        && starts_with_ignore_case(input, "MERGE")
         && (input.len() == 5
             || (!input.chars().nth(5).unwrap().is_alphanumeric()
                 && input.chars().nth(5).unwrap() != '_'))
     {
         Ok((&input[5..], Token::Merge))
     } else if input.len() >= 5
-        && input[..5].eq_ignore_ascii_case("STORE")
+        && starts_with_ignore_case(input, "STORE")
         && (input.len() == 5
             || (!input.chars().nth(5).unwrap().is_alphanumeric()
                 && input.chars().nth(5).unwrap() != '_'))
     {
         Ok((&input[5..], Token::Store))
     } else if input.len() >= 5
-        && input[..5].eq_ignore_ascii_case("ORDER")
+        && starts_with_ignore_case(input, "ORDER")
         && (input.len() == 5
             || (!input.chars().nth(5).unwrap().is_alphanumeric()
                 && input.chars().nth(5).unwrap() != '_'))
     {
         Ok((&input[5..], Token::Order))
     } else if input.len() >= 5
-        && input[..5].eq_ignore_ascii_case("LIMIT")
+        && starts_with_ignore_case(input, "LIMIT")
         && (input.len() == 5
             || (!input.chars().nth(5).unwrap().is_alphanumeric()
                 && input.chars().nth(5).unwrap() != '_'))
     {
         Ok((&input[5..], Token::Limit))
     } else if input.len() >= 6
-        && input[..6].eq_ignore_ascii_case("HAVING")
+        && starts_with_ignore_case(input, "HAVING")
         && (input.len() == 6
             || (!input.chars().nth(6).unwrap().is_alphanumeric()
                 && input.chars().nth(6).unwrap() != '_'))
     {
         Ok((&input[6..], Token::Having))
     } else if input.len() >= 6
-        && input[..6].eq_ignore_ascii_case("OFFSET")
+        && starts_with_ignore_case(input, "OFFSET")
         && (input.len() == 6
             || (!input.chars().nth(6).unwrap().is_alphanumeric()
                 && input.chars().nth(6).unwrap() != '_'))
@@ -1062,133 +1111,133 @@ fn simple_patterns(input: &str) -> IResult<&str, Token> {
     } else if is_keyword_match(input, "GRAPH") {
         Ok((&input[5..], Token::Graph))
     } else if input.len() >= 5
-        && input[..5].eq_ignore_ascii_case("GROUP")
+        && starts_with_ignore_case(input, "GROUP")
         && (input.len() == 5
             || (!input.chars().nth(5).unwrap().is_alphanumeric()
                 && input.chars().nth(5).unwrap() != '_'))
     {
         Ok((&input[5..], Token::Group))
     } else if input.len() >= 5
-        && input[..5].eq_ignore_ascii_case("TYPES")
+        && starts_with_ignore_case(input, "TYPES")
         && (input.len() == 5
             || (!input.chars().nth(5).unwrap().is_alphanumeric()
                 && input.chars().nth(5).unwrap() != '_'))
     {
         Ok((&input[5..], Token::Types))
     } else if input.len() >= 5
-        && input[..5].eq_ignore_ascii_case("ROLES")
+        && starts_with_ignore_case(input, "ROLES")
         && (input.len() == 5
             || (!input.chars().nth(5).unwrap().is_alphanumeric()
                 && input.chars().nth(5).unwrap() != '_'))
     {
         Ok((&input[5..], Token::Roles))
     } else if input.len() >= 5
-        && input[..5].eq_ignore_ascii_case("YIELD")
+        && starts_with_ignore_case(input, "YIELD")
         && (input.len() == 5
             || (!input.chars().nth(5).unwrap().is_alphanumeric()
                 && input.chars().nth(5).unwrap() != '_'))
     {
         Ok((&input[5..], Token::Yield))
     } else if input.len() >= 5
-        && input[..5].eq_ignore_ascii_case("RESET")
+        && starts_with_ignore_case(input, "RESET")
         && (input.len() == 5
             || (!input.chars().nth(5).unwrap().is_alphanumeric()
                 && input.chars().nth(5).unwrap() != '_'))
     {
         Ok((&input[5..], Token::Reset))
     } else if input.len() >= 5
-        && input[..5].eq_ignore_ascii_case("CLOSE")
+        && starts_with_ignore_case(input, "CLOSE")
         && (input.len() == 5
             || (!input.chars().nth(5).unwrap().is_alphanumeric()
                 && input.chars().nth(5).unwrap() != '_'))
     {
         Ok((&input[5..], Token::Close))
     } else if input.len() >= 5
-        && input[..5].eq_ignore_ascii_case("VALUE")
+        && starts_with_ignore_case(input, "VALUE")
         && (input.len() == 5
             || (!input.chars().nth(5).unwrap().is_alphanumeric()
                 && input.chars().nth(5).unwrap() != '_'))
     {
         Ok((&input[5..], Token::Value))
     } else if input.len() >= 5
-        && input[..5].eq_ignore_ascii_case("TABLE")
+        && starts_with_ignore_case(input, "TABLE")
         && (input.len() == 5
             || (!input.chars().nth(5).unwrap().is_alphanumeric()
                 && input.chars().nth(5).unwrap() != '_'))
     {
         Ok((&input[5..], Token::Table))
     } else if input.len() >= 5
-        && input[..5].eq_ignore_ascii_case("TYPED")
+        && starts_with_ignore_case(input, "TYPED")
         && (input.len() == 5
             || (!input.chars().nth(5).unwrap().is_alphanumeric()
                 && input.chars().nth(5).unwrap() != '_'))
     {
         Ok((&input[5..], Token::Typed))
     } else if input.len() >= 4
-        && input[..4].eq_ignore_ascii_case("TIME")
+        && starts_with_ignore_case(input, "TIME")
         && (input.len() == 4
             || (!input.chars().nth(4).unwrap().is_alphanumeric()
                 && input.chars().nth(4).unwrap() != '_'))
     {
         Ok((&input[4..], Token::Time))
     } else if input.len() >= 4
-        && input[..4].eq_ignore_ascii_case("ZONE")
+        && starts_with_ignore_case(input, "ZONE")
         && (input.len() == 4
             || (!input.chars().nth(4).unwrap().is_alphanumeric()
                 && input.chars().nth(4).unwrap() != '_'))
     {
         Ok((&input[4..], Token::Zone))
     } else if input.len() >= 4
-        && input[..4].eq_ignore_ascii_case("HOME")
+        && starts_with_ignore_case(input, "HOME")
         && (input.len() == 4
             || (!input.chars().nth(4).unwrap().is_alphanumeric()
                 && input.chars().nth(4).unwrap() != '_'))
     {
         Ok((&input[4..], Token::Home))
     } else if input.len() >= 4
-        && &input[..4] == "USER"
+        && input.starts_with("USER")
         && (input.len() == 4
             || !input.chars().nth(4).unwrap_or(' ').is_alphanumeric()
                 && input.chars().nth(4).unwrap_or(' ') != '_')
     {
         Ok((&input[4..], Token::User))
     } else if input.len() >= 4
-        && input[..4].eq_ignore_ascii_case("ROLE")
+        && starts_with_ignore_case(input, "ROLE")
         && (input.len() == 4
             || (!input.chars().nth(4).unwrap().is_alphanumeric()
                 && input.chars().nth(4).unwrap() != '_'))
     {
         Ok((&input[4..], Token::Role))
     } else if input.len() >= 5
-        && input[..5].eq_ignore_ascii_case("GRANT")
+        && starts_with_ignore_case(input, "GRANT")
         && (input.len() == 5
             || (!input.chars().nth(5).unwrap().is_alphanumeric()
                 && input.chars().nth(5).unwrap() != '_'))
     {
         Ok((&input[5..], Token::Grant))
     } else if input.len() >= 6
-        && input[..6].eq_ignore_ascii_case("REVOKE")
+        && starts_with_ignore_case(input, "REVOKE")
         && (input.len() == 6
             || (!input.chars().nth(6).unwrap().is_alphanumeric()
                 && input.chars().nth(6).unwrap() != '_'))
     {
         Ok((&input[6..], Token::Revoke))
     } else if input.len() >= 9
-        && input[..9].eq_ignore_ascii_case("PROCEDURE")
+        && starts_with_ignore_case(input, "PROCEDURE")
         && (input.len() == 9
             || (!input.chars().nth(9).unwrap().is_alphanumeric()
                 && input.chars().nth(9).unwrap() != '_'))
     {
         Ok((&input[9..], Token::Procedure))
     } else if input.len() >= 2
-        && input[..2].eq_ignore_ascii_case("TO")
+        && starts_with_ignore_case(input, "TO")
         && (input.len() == 2
             || (!input.chars().nth(2).unwrap().is_alphanumeric()
                 && input.chars().nth(2).unwrap() != '_'))
     {
         Ok((&input[2..], Token::To))
     } else if input.len() >= 4
-        && input[..4].eq_ignore_ascii_case("NODE")
+        && starts_with_ignore_case(input, "NODE")
         && (input.len() == 4
             || (!input.chars().nth(4).unwrap().is_alphanumeric()
                 && input.chars().nth(4).unwrap() != '_'))
@@ -1204,166 +1253,166 @@ fn simple_patterns(input: &str) -> IResult<&str, Token> {
             )))
         }
     } else if input.len() >= 4
-        && input[..4].eq_ignore_ascii_case("EDGE")
+        && starts_with_ignore_case(input, "EDGE")
         && (input.len() == 4
             || (!input.chars().nth(4).unwrap().is_alphanumeric()
                 && input.chars().nth(4).unwrap() != '_'))
     {
         Ok((&input[4..], Token::Edge))
     } else if input.len() >= 4
-        && input[..4].eq_ignore_ascii_case("WITH")
+        && starts_with_ignore_case(input, "WITH")
         && (input.len() == 4
             || (!input.chars().nth(4).unwrap().is_alphanumeric()
                 && input.chars().nth(4).unwrap() != '_'))
     {
         Ok((&input[4..], Token::With))
     } else if input.len() >= 4
-        && input[..4].eq_ignore_ascii_case("CALL")
+        && starts_with_ignore_case(input, "CALL")
         && (input.len() == 4
             || (!input.chars().nth(4).unwrap().is_alphanumeric()
                 && input.chars().nth(4).unwrap() != '_'))
     {
         Ok((&input[4..], Token::Call))
     } else if input.len() >= 4
-        && input[..4].eq_ignore_ascii_case("LOAD")
+        && starts_with_ignore_case(input, "LOAD")
         && (input.len() == 4
             || (!input.chars().nth(4).unwrap().is_alphanumeric()
                 && input.chars().nth(4).unwrap() != '_'))
     {
         Ok((&input[4..], Token::Load))
     } else if input.len() >= 4
-        && input[..4].eq_ignore_ascii_case("ENDS")
+        && starts_with_ignore_case(input, "ENDS")
         && (input.len() == 4
             || (!input.chars().nth(4).unwrap().is_alphanumeric()
                 && input.chars().nth(4).unwrap() != '_'))
     {
         Ok((&input[4..], Token::Ends))
     } else if input.len() >= 4
-        && input[..4].eq_ignore_ascii_case("LIKE")
+        && starts_with_ignore_case(input, "LIKE")
         && (input.len() == 4 || is_word_boundary(input.chars().nth(4).unwrap_or(' ')))
     {
         Ok((&input[4..], Token::Like))
     } else if input.len() >= 4
-        && input[..4].eq_ignore_ascii_case("TYPE")
+        && starts_with_ignore_case(input, "TYPE")
         && (input.len() == 4
             || (!input.chars().nth(4).unwrap().is_alphanumeric()
                 && input.chars().nth(4).unwrap() != '_'))
     {
         Ok((&input[4..], Token::Type))
     } else if input.len() >= 4
-        && input[..4].eq_ignore_ascii_case("COPY")
+        && starts_with_ignore_case(input, "COPY")
         && (input.len() == 4
             || (!input.chars().nth(4).unwrap().is_alphanumeric()
                 && input.chars().nth(4).unwrap() != '_'))
     {
         Ok((&input[4..], Token::Copy))
     } else if input.len() >= 8
-        && input[..8].eq_ignore_ascii_case("TRUNCATE")
+        && starts_with_ignore_case(input, "TRUNCATE")
         && (input.len() == 8
             || (!input.chars().nth(8).unwrap().is_alphanumeric()
                 && input.chars().nth(8).unwrap() != '_'))
     {
         Ok((&input[8..], Token::Truncate))
     } else if input.len() >= 5
-        && input[..5].eq_ignore_ascii_case("CLEAR")
+        && starts_with_ignore_case(input, "CLEAR")
         && (input.len() == 5
             || (!input.chars().nth(5).unwrap().is_alphanumeric()
                 && input.chars().nth(5).unwrap() != '_'))
     {
         Ok((&input[5..], Token::Clear))
     } else if input.len() >= 4
-        && input[..4].eq_ignore_ascii_case("DROP")
+        && starts_with_ignore_case(input, "DROP")
         && (input.len() == 4
             || (!input.chars().nth(4).unwrap().is_alphanumeric()
                 && input.chars().nth(4).unwrap() != '_'))
     {
         Ok((&input[4..], Token::Drop))
     } else if input.len() >= 8
-        && input[..8].eq_ignore_ascii_case("REGISTER")
+        && starts_with_ignore_case(input, "REGISTER")
         && (input.len() == 8
             || (!input.chars().nth(8).unwrap().is_alphanumeric()
                 && input.chars().nth(8).unwrap() != '_'))
     {
         Ok((&input[8..], Token::Register))
     } else if input.len() >= 10
-        && input[..10].eq_ignore_ascii_case("UNREGISTER")
+        && starts_with_ignore_case(input, "UNREGISTER")
         && (input.len() == 10
             || (!input.chars().nth(10).unwrap().is_alphanumeric()
                 && input.chars().nth(10).unwrap() != '_'))
     {
         Ok((&input[10..], Token::Unregister))
     } else if input.len() >= 4
-        && input[..4].eq_ignore_ascii_case("SKIP")
+        && starts_with_ignore_case(input, "SKIP")
         && (input.len() == 4
             || (!input.chars().nth(4).unwrap().is_alphanumeric()
                 && input.chars().nth(4).unwrap() != '_'))
     {
         Ok((&input[4..], Token::Skip))
     } else if input.len() >= 7
-        && input[..7].eq_ignore_ascii_case("LEADING")
+        && starts_with_ignore_case(input, "LEADING")
         && (input.len() == 7
             || (!input.chars().nth(7).unwrap().is_alphanumeric()
                 && input.chars().nth(7).unwrap() != '_'))
     {
         Ok((&input[7..], Token::Leading))
     } else if input.len() >= 8
-        && input[..8].eq_ignore_ascii_case("TRAILING")
+        && starts_with_ignore_case(input, "TRAILING")
         && (input.len() == 8
             || (!input.chars().nth(8).unwrap().is_alphanumeric()
                 && input.chars().nth(8).unwrap() != '_'))
     {
         Ok((&input[8..], Token::Trailing))
     } else if input.len() >= 4
-        && input[..4].eq_ignore_ascii_case("BOTH")
+        && starts_with_ignore_case(input, "BOTH")
         && (input.len() == 4
             || !input.chars().nth(4).unwrap_or(' ').is_alphanumeric()
                 && input.chars().nth(4).unwrap_or(' ') != '_')
     {
         Ok((&input[4..], Token::Both))
     } else if input.len() >= 3
-        && input[..3].eq_ignore_ascii_case("SET")
+        && starts_with_ignore_case(input, "SET")
         && (input.len() == 3
             || (!input.chars().nth(3).unwrap().is_alphanumeric()
                 && input.chars().nth(3).unwrap() != '_'))
     {
         Ok((&input[3..], Token::Set))
     } else if input.len() >= 10
-        && input[..10].eq_ignore_ascii_case("DESCENDING")
+        && starts_with_ignore_case(input, "DESCENDING")
         && (input.len() == 10
             || (!input.chars().nth(10).unwrap().is_alphanumeric()
                 && input.chars().nth(10).unwrap() != '_'))
     {
         Ok((&input[10..], Token::Descending))
     } else if input.len() >= 9
-        && input[..9].eq_ignore_ascii_case("INTERSECT")
+        && starts_with_ignore_case(input, "INTERSECT")
         && (input.len() == 9
             || (!input.chars().nth(9).unwrap().is_alphanumeric()
                 && input.chars().nth(9).unwrap() != '_'))
     {
         Ok((&input[9..], Token::Intersect))
     } else if input.len() >= 9
-        && input[..9].eq_ignore_ascii_case("ASCENDING")
+        && starts_with_ignore_case(input, "ASCENDING")
         && (input.len() == 9
             || (!input.chars().nth(9).unwrap().is_alphanumeric()
                 && input.chars().nth(9).unwrap() != '_'))
     {
         Ok((&input[9..], Token::Ascending))
     } else if input.len() >= 6
-        && input[..6].eq_ignore_ascii_case("EXCEPT")
+        && starts_with_ignore_case(input, "EXCEPT")
         && (input.len() == 6
             || (!input.chars().nth(6).unwrap().is_alphanumeric()
                 && input.chars().nth(6).unwrap() != '_'))
     {
         Ok((&input[6..], Token::Except))
     } else if input.len() >= 5
-        && input[..5].eq_ignore_ascii_case("UNION")
+        && starts_with_ignore_case(input, "UNION")
         && (input.len() == 5
             || (!input.chars().nth(5).unwrap().is_alphanumeric()
                 && input.chars().nth(5).unwrap() != '_'))
     {
         Ok((&input[5..], Token::Union))
     } else if input.len() >= 5
-        && input[..5].eq_ignore_ascii_case("NULLS")
+        && starts_with_ignore_case(input, "NULLS")
         && (input.len() == 5
             || (!input.chars().nth(5).unwrap().is_alphanumeric()
                 && input.chars().nth(5).unwrap() != '_'))
@@ -1374,112 +1423,112 @@ fn simple_patterns(input: &str) -> IResult<&str, Token> {
     } else if is_keyword_match(input, "LAST") {
         Ok((&input[4..], Token::Last))
     } else if input.len() >= 4
-        && input[..4].eq_ignore_ascii_case("DESC")
+        && starts_with_ignore_case(input, "DESC")
         && (input.len() == 4
             || (!input.chars().nth(4).unwrap().is_alphanumeric()
                 && input.chars().nth(4).unwrap() != '_'))
     {
         Ok((&input[4..], Token::Desc))
     } else if input.len() >= 3
-        && input[..3].eq_ignore_ascii_case("ASC")
+        && starts_with_ignore_case(input, "ASC")
         && (input.len() == 3
             || (!input.chars().nth(3).unwrap().is_alphanumeric()
                 && input.chars().nth(3).unwrap() != '_'))
     {
         Ok((&input[3..], Token::Asc))
     } else if input.len() >= 3
-        && input[..3].eq_ignore_ascii_case("ALL")
+        && starts_with_ignore_case(input, "ALL")
         && (input.len() == 3
             || (!input.chars().nth(3).unwrap().is_alphanumeric()
                 && input.chars().nth(3).unwrap() != '_'))
     {
         Ok((&input[3..], Token::All))
     } else if input.len() >= 3
-        && input[..3].eq_ignore_ascii_case("ANY")
+        && starts_with_ignore_case(input, "ANY")
         && (input.len() == 3
             || (!input.chars().nth(3).unwrap().is_alphanumeric()
                 && input.chars().nth(3).unwrap() != '_'))
     {
         Ok((&input[3..], Token::Any))
     } else if input.len() >= 4
-        && input[..4].eq_ignore_ascii_case("SOME")
+        && starts_with_ignore_case(input, "SOME")
         && (input.len() == 4
             || (!input.chars().nth(4).unwrap().is_alphanumeric()
                 && input.chars().nth(4).unwrap() != '_'))
     {
         Ok((&input[4..], Token::Some))
     } else if input.len() >= 3
-        && input[..3].eq_ignore_ascii_case("AND")
+        && starts_with_ignore_case(input, "AND")
         && (input.len() == 3
             || (!input.chars().nth(3).unwrap().is_alphanumeric()
                 && input.chars().nth(3).unwrap() != '_'))
     {
         Ok((&input[3..], Token::And))
     } else if input.len() >= 3
-        && input[..3].eq_ignore_ascii_case("NOT")
+        && starts_with_ignore_case(input, "NOT")
         && (input.len() == 3
             || (!input.chars().nth(3).unwrap().is_alphanumeric()
                 && input.chars().nth(3).unwrap() != '_'))
     {
         Ok((&input[3..], Token::Not))
     } else if input.len() >= 3
-        && input[..3].eq_ignore_ascii_case("XOR")
+        && starts_with_ignore_case(input, "XOR")
         && (input.len() == 3
             || (!input.chars().nth(3).unwrap().is_alphanumeric()
                 && input.chars().nth(3).unwrap() != '_'))
     {
         Ok((&input[3..], Token::Xor))
     } else if input.len() >= 2
-        && input[..2].eq_ignore_ascii_case("BY")
+        && starts_with_ignore_case(input, "BY")
         && (input.len() == 2
             || (!input.chars().nth(2).unwrap().is_alphanumeric()
                 && input.chars().nth(2).unwrap() != '_'))
     {
         Ok((&input[2..], Token::By))
     } else if input.len() >= 2
-        && input[..2].eq_ignore_ascii_case("ON")
+        && starts_with_ignore_case(input, "ON")
         && (input.len() == 2
             || (!input.chars().nth(2).unwrap().is_alphanumeric()
                 && input.chars().nth(2).unwrap() != '_'))
     {
         Ok((&input[2..], Token::On))
     } else if input.len() >= 2
-        && input[..2].eq_ignore_ascii_case("AS")
+        && starts_with_ignore_case(input, "AS")
         && (input.len() == 2
             || (!input.chars().nth(2).unwrap().is_alphanumeric()
                 && input.chars().nth(2).unwrap() != '_'))
     {
         Ok((&input[2..], Token::As))
     } else if input.len() >= 2
-        && input[..2].eq_ignore_ascii_case("OR")
+        && starts_with_ignore_case(input, "OR")
         && (input.len() == 2
             || (!input.chars().nth(2).unwrap().is_alphanumeric()
                 && input.chars().nth(2).unwrap() != '_'))
     {
         Ok((&input[2..], Token::Or))
     } else if input.len() >= 2
-        && input[..2].eq_ignore_ascii_case("OF")
+        && starts_with_ignore_case(input, "OF")
         && (input.len() == 2
             || (!input.chars().nth(2).unwrap().is_alphanumeric()
                 && input.chars().nth(2).unwrap() != '_'))
     {
         Ok((&input[2..], Token::Of))
     } else if input.len() >= 2
-        && input[..2].eq_ignore_ascii_case("IS")
+        && starts_with_ignore_case(input, "IS")
         && (input.len() == 2
             || (!input.chars().nth(2).unwrap_or(' ').is_alphanumeric()
                 && input.chars().nth(2).unwrap_or(' ') != '_'))
     {
         Ok((&input[2..], Token::Is))
     } else if input.len() >= 2
-        && input[..2].eq_ignore_ascii_case("IF")
+        && starts_with_ignore_case(input, "IF")
         && (input.len() == 2
             || (!input.chars().nth(2).unwrap().is_alphanumeric()
                 && input.chars().nth(2).unwrap() != '_'))
     {
         Ok((&input[2..], Token::If))
     } else if input.len() >= 2
-        && input[..2].eq_ignore_ascii_case("IN")
+        && starts_with_ignore_case(input, "IN")
         && (input.len() == 2
             || (!input.chars().nth(2).unwrap().is_alphanumeric()
                 && input.chars().nth(2).unwrap() != '_'))
@@ -1488,21 +1537,21 @@ fn simple_patterns(input: &str) -> IResult<&str, Token> {
     }
     // Boolean literals
     else if input.len() >= 4
-        && input[..4].eq_ignore_ascii_case("true")
+        && starts_with_ignore_case(input, "true")
         && (input.len() == 4
             || (!input.chars().nth(4).unwrap().is_alphanumeric()
                 && input.chars().nth(4).unwrap() != '_'))
     {
         Ok((&input[4..], Token::Boolean(true)))
     } else if input.len() >= 5
-        && input[..5].eq_ignore_ascii_case("false")
+        && starts_with_ignore_case(input, "false")
         && (input.len() == 5
             || (!input.chars().nth(5).unwrap().is_alphanumeric()
                 && input.chars().nth(5).unwrap() != '_'))
     {
         Ok((&input[5..], Token::Boolean(false)))
     } else if input.len() >= 4
-        && input[..4].eq_ignore_ascii_case("NULL")
+        && starts_with_ignore_case(input, "NULL")
         && (input.len() == 4
             || (!input.chars().nth(4).unwrap().is_alphanumeric()
                 && input.chars().nth(4).unwrap() != '_'))
@@ -1858,9 +1907,87 @@ fn identifier(input: &str) -> IResult<&str, &str> {
 }
 
 /// Public function to tokenize input
+#[allow(dead_code)] // Token-only variant used by parser tests
 pub fn tokenize(input: &str) -> Result<Vec<Token>, String> {
     let mut lexer = Lexer::new(input.to_string());
     lexer.tokenize()
 }
 
+/// Tokenize input, keeping the source location of each token
+pub fn tokenize_with_locations(input: &str) -> Result<Vec<(Token, Location)>, String> {
+    let mut lexer = Lexer::new(input.to_string());
+    lexer.tokenize_with_locations()
+}
+
+/// Canonical form of a query, for caching and for grouping queries in logs
+///
+/// Keywords are uppercased, literal values become `$?`, comments are dropped
+/// and tokens are separated by single spaces (none inside brackets or around
+/// `.` and `:`), so queries that differ only in layout or in literal values
+/// normalize identically. Identifiers and `$parameters` are kept as written.
+/// Input the lexer rejects is returned with its whitespace collapsed.
+pub fn normalize_query(input: &str) -> String {
+    let mut remaining = input;
+    let mut normalized = String::with_capacity(input.len());
+    let mut previous: Option<Token> = None;
+
+    while !remaining.is_empty() {
+        let (next_remaining, token) = match token(remaining) {
+            Ok(result) if result.0.len() < remaining.len() => result,
+            _ => return input.split_whitespace().collect::<Vec<_>>().join(" "),
+        };
+        let text = &remaining[..remaining.len() - next_remaining.len()];
+        remaining = next_remaining;
+
+        let canonical = match &token {
+            Token::Whitespace | Token::Comment(_) => continue,
+            Token::String(_)
+            | Token::Integer(_)
+            | Token::Float(_)
+            | Token::Boolean(_)
+            | Token::Vector(_) => "$?",
+            Token::Identifier(_)
+            | Token::Variable(_)
+            | Token::PropertyAccess(_)
+            | Token::BacktickString(_) => text.trim(),
+            _ => &text.trim().to_uppercase(),
+        };
+
+        if let Some(previous) = &previous {
+            if needs_space_between(previous, &token) {
+                normalized.push(' ');
+            }
+        }
+        normalized.push_str(canonical);
+        previous = Some(token);
+    }
+
+    normalized
+}
+
+/// Whether normalized output separates two adjacent tokens with a space
+fn needs_space_between(previous: &Token, next: &Token) -> bool {
+    let opens = matches!(
+        previous,
+        Token::LeftParen
+            | Token::LeftBracket
+            | Token::LeftBrace
+            | Token::Dot
+            | Token::Colon
+            | Token::Dollar
+    );
+    let closes = matches!(
+        next,
+        Token::RightParen
+            | Token::RightBracket
+            | Token::RightBrace
+            | Token::Comma
+            | Token::Semicolon
+            | Token::Dot
+            | Token::Colon
+    );
+    let call = matches!(previous, Token::Identifier(_)) && matches!(next, Token::LeftParen);
+    !(opens || closes || call)
+}
+
 // Removed unused test_lexer function
@@ -12,8 +12,10 @@ use nom::{
     IResult,
 };
 
+use std::cell::Cell;
+
 use super::ast::*;
-use super::lexer::{tokenize, Token};
+use super::lexer::{tokenize_with_locations, Token};
 use super::pretty_printer::pretty_print_ast;
 
 /// Parser error type
@@ -21,8 +23,8 @@ use super::pretty_printer::pretty_print_ast;
 pub enum ParserError {
     #[error("Lexer error: {0}")]
     LexerError(String),
-    #[error("Unexpected token: {0:?}")]
-    UnexpectedToken(Token),
+    #[error("Unexpected token {token:?} at line {line}, column {column}", line = .location.line, column = .location.column)]
+    UnexpectedToken { token: Token, location: Location },
     #[error("Expected token: {0:?}")]
     ExpectedToken(Token),
     #[error("Invalid syntax 'DELETE SCHEMA'. Schema deletion uses 'DROP SCHEMA'. Correct syntax: DROP SCHEMA [IF EXISTS] schema_name [CASCADE | RESTRICT]. Example: DROP SCHEMA analytics_db")]
@@ -35,64 +37,264 @@ pub enum ParserError {
     IncompleteExcept,
     #[error("Incomplete INTERSECT operation. Expected a query after INTERSECT. Syntax: query1 INTERSECT [ALL] query2")]
     IncompleteIntersect,
+    #[error("expression nesting too deep: more than {max_depth} levels of nested expressions, patterns or subqueries")]
+    NestingTooDeep { max_depth: usize },
+}
+
+impl ParserError {
+    /// Where in the query text the error was detected, when known
+    pub fn location(&self) -> Option<&Location> {
+        match self {
+            ParserError::UnexpectedToken { location, .. } => Some(location),
+            _ => None,
+        }
+    }
+}
+
+thread_local! {
+    /// Index of the furthest token that any parser failed to match
+    ///
+    /// Alternatives that fail early are retried from the same position, so the
+    /// furthest mismatch is the best guess at where a bad query went wrong.
+    static FURTHEST_MISMATCH: Cell<usize> = const { Cell::new(0) };
+
+    /// How many nested expressions, label expressions and parenthesized queries
+    /// are currently being parsed on this thread
+    static NESTING_DEPTH: Cell<usize> = const { Cell::new(0) };
+
+    /// Whether the current parse gave up because it nested too deeply
+    static NESTING_TOO_DEEP: Cell<bool> = const { Cell::new(false) };
+
+    /// Nesting depth the current parse allows
+    static MAX_NESTING_DEPTH: Cell<usize> = const { Cell::new(DEFAULT_MAX_NESTING_DEPTH) };
+}
+
+/// Nesting depth [`parse_query`] allows
+///
+/// The same in every build profile: the stack this many levels use fits a
+/// 2 MiB thread stack even in unoptimized builds.
+pub const DEFAULT_MAX_NESTING_DEPTH: usize = 16;
+
+/// Parser input: a run of tokens together with their source locations
+///
+/// Sub-ranges keep each token paired with its location and remember where
+/// they start in the whole stream, so parsers can report positions without
+/// any lookup outside their input.
+#[derive(Debug, Clone, Copy)]
+struct Tokens<'a> {
+    tokens: &'a [Token],
+    locations: &'a [Location],
+    /// Index of the first token in the stream being parsed
+    offset: usize,
+}
+
+impl<'a> Tokens<'a> {
+    /// A whole token stream; `locations` holds one entry per token
+    fn new(tokens: &'a [Token], locations: &'a [Location]) -> Self {
+        Self {
+            tokens,
+            locations,
+            offset: 0,
+        }
+    }
+
+    /// The tokens after the first `count`
+    fn skip(self, count: usize) -> Self {
+        let count = count.min(self.tokens.len());
+        Self {
+            tokens: &self.tokens[count..],
+            locations: self.locations.get(count..).unwrap_or_default(),
+            offset: self.offset + count,
+        }
+    }
+
+    /// The first `count` tokens
+    fn take(self, count: usize) -> Self {
+        let count = count.min(self.tokens.len());
+        Self {
+            tokens: &self.tokens[..count],
+            locations: &self.locations[..count.min(self.locations.len())],
+            offset: self.offset,
+        }
+    }
+
+    /// Source location of the first token, or the default location when the
+    /// input is empty
+    fn location(&self) -> Location {
+        self.locations.first().cloned().unwrap_or_default()
+    }
+}
+
+impl std::ops::Deref for Tokens<'_> {
+    type Target = [Token];
+
+    fn deref(&self) -> &[Token] {
+        self.tokens
+    }
+}
+
+impl nom::InputLength for Tokens<'_> {
+    fn input_len(&self) -> usize {
+        self.tokens.len()
+    }
+}
+
+/// Resets the per-parse state on entry and restores the caller's on exit, so
+/// parses started from within a parse do not disturb each other
+struct ParseScope {
+    previous_mismatch: usize,
+    previous_depth: usize,
+    previous_too_deep: bool,
+    previous_max_depth: usize,
+}
+
+impl ParseScope {
+    fn enter(max_depth: usize) -> Self {
+        let previous_mismatch = FURTHEST_MISMATCH.with(|cell| cell.replace(0));
+        let previous_depth = NESTING_DEPTH.with(|cell| cell.replace(0));
+        let previous_too_deep = NESTING_TOO_DEEP.with(|cell| cell.replace(false));
+        let previous_max_depth = MAX_NESTING_DEPTH.with(|cell| cell.replace(max_depth));
+        Self {
+            previous_mismatch,
+            previous_depth,
+            previous_too_deep,
+            previous_max_depth,
+        }
+    }
+}
+
+impl Drop for ParseScope {
+    fn drop(&mut self) {
+        FURTHEST_MISMATCH.with(|cell| cell.set(self.previous_mismatch));
+        NESTING_DEPTH.with(|cell| cell.set(self.previous_depth));
+        NESTING_TOO_DEEP.with(|cell| cell.set(self.previous_too_deep));
+        MAX_NESTING_DEPTH.with(|cell| cell.set(self.previous_max_depth));
+    }
+}
+
+/// One level of nesting, released when dropped
+struct NestingGuard;
+
+impl NestingGuard {
+    /// Enter a nested construct starting at `tokens`
+    ///
+    /// Past the maximum depth this fails with a nom `Failure` rather than an
+    /// `Error`, so alternatives are not retried at every enclosing level.
+    fn enter(tokens: Tokens<'_>) -> Result<Self, nom::Err<nom::error::Error<Tokens<'_>>>> {
+        let depth = NESTING_DEPTH.with(|cell| cell.get()) + 1;
+        if depth > MAX_NESTING_DEPTH.with(Cell::get) {
+            NESTING_TOO_DEEP.with(|cell| cell.set(true));
+            return Err(nom::Err::Failure(nom::error::Error::new(
+                tokens,
+                nom::error::ErrorKind::TooLarge,
+            )));
+        }
+        NESTING_DEPTH.with(|cell| cell.set(depth));
+        Ok(Self)
+    }
+}
+
+impl Drop for NestingGuard {
+    fn drop(&mut self) {
+        NESTING_DEPTH.with(|cell| cell.set(cell.get().saturating_sub(1)));
+    }
+}
+
+/// Error for a parser that did not find the token it expected at `tokens`
+fn mismatch(tokens: Tokens<'_>) -> nom::Err<nom::error::Error<Tokens<'_>>> {
+    FURTHEST_MISMATCH.with(|cell| cell.set(cell.get().max(tokens.offset)));
+    nom::Err::Error(nom::error::Error::new(tokens, nom::error::ErrorKind::Tag))
+}
+
+/// Attach a source location to an expression that does not have one yet
+fn locate_expression(mut expr: Expression, location: Location) -> Expression {
+    let slot = match &mut expr {
+        Expression::Variable(variable) => &mut variable.location,
+        Expression::FunctionCall(func_call) => &mut func_call.location,
+        Expression::PropertyAccess(prop_access) => &mut prop_access.location,
+        Expression::Parameter(parameter) => &mut parameter.location,
+        Expression::Window(window) => &mut window.location,
+        _ => return expr,
+    };
+    if *slot == Location::default() {
+        *slot = location;
+    }
+    expr
 }
 
 /// Filter SQL-style comments from a token stream
 /// SQL comments are two consecutive Dash tokens followed by any tokens until EOF or newline
-fn filter_sql_comments(tokens: Vec<Token>) -> Vec<Token> {
+fn filter_sql_comments(tokens: Vec<(Token, Location)>) -> (Vec<Token>, Vec<Location>) {
     let mut filtered = Vec::new();
     let mut i = 0;
 
     while i < tokens.len() {
         // Check for SQL comment pattern: Dash, Dash, ...
         if i + 1 < tokens.len()
-            && matches!(tokens[i], Token::Dash)
-            && matches!(tokens[i + 1], Token::Dash)
+            && matches!(tokens[i].0, Token::Dash)
+            && matches!(tokens[i + 1].0, Token::Dash)
         {
             // Check if this is actually a comment (not part of an edge pattern)
             // SQL comments are recognized when -- is followed by whitespace or identifiers
             // but NOT when followed by >, <, [, or (
             if i + 2 < tokens.len() {
-                match &tokens[i + 2] {
+                match &tokens[i + 2].0 {
                     Token::Arrow
                     | Token::ArrowLeft
                     | Token::ArrowBoth
                     | Token::LeftParen
                     | Token::LeftBracket => {
                         // This is an edge pattern, not a comment
-                        filtered.push(tokens[i].clone());
+                        filtered.push(i);
                         i += 1;
                     }
                     Token::Eof => {
                         // Just -- at end of input, keep as edge pattern
-                        filtered.push(tokens[i].clone());
+                        filtered.push(i);
                         i += 1;
                     }
                     _ => {
                         // This looks like a SQL comment, skip all tokens until EOF
                         // (In a real implementation, we'd skip until newline, but our lexer
                         // doesn't produce newline tokens, so we skip to EOF)
-                        while i < tokens.len() && !matches!(tokens[i], Token::Eof) {
+                        while i < tokens.len() && !matches!(tokens[i].0, Token::Eof) {
                             i += 1;
                         }
                     }
                 }
             } else {
                 // Just -- at end, keep as edge pattern
-                filtered.push(tokens[i].clone());
+                filtered.push(i);
                 i += 1;
             }
         } else {
-            filtered.push(tokens[i].clone());
+            filtered.push(i);
             i += 1;
         }
     }
 
     filtered
+        .into_iter()
+        .map(|index| tokens[index].clone())
+        .unzip()
 }
 
 /// Parse a GQL query or statement into an AST Document
+///
+/// Queries nested deeper than [`DEFAULT_MAX_NESTING_DEPTH`] are rejected with
+/// [`ParserError::NestingTooDeep`].
 pub fn parse_query(input: &str) -> Result<Document, ParserError> {
+    parse_query_with_max_depth(input, DEFAULT_MAX_NESTING_DEPTH)
+}
+
+/// Parse like [`parse_query`], allowing expressions, label expressions and
+/// parenthesized queries to nest at most `max_depth` levels
+///
+/// The parser is recursive descent, so every level of nesting uses stack; the
+/// limit turns a query that would overflow the stack into a
+/// [`ParserError::NestingTooDeep`]. Raising it far above the default needs a
+/// correspondingly larger thread stack.
+pub fn parse_query_with_max_depth(input: &str, max_depth: usize) -> Result<Document, ParserError> {
     // Debug: Print query text if it contains GROUP BY
     if input.contains("GROUP BY") {
         log::debug!(
@@ -102,10 +304,12 @@ pub fn parse_query(input: &str) -> Result<Document, ParserError> {
     }
 
     // First tokenize the input
-    let mut tokens = tokenize(input).map_err(ParserError::LexerError)?;
+    let located_tokens = tokenize_with_locations(input).map_err(ParserError::LexerError)?;
 
     // Filter out SQL-style comments at the parser level
-    tokens = filter_sql_comments(tokens);
+    let (tokens, locations) = filter_sql_comments(located_tokens);
+    let tokens = Tokens::new(&tokens, &locations);
+    let _scope = ParseScope::enter(max_depth);
 
     // Debug: Check if GROUP BY tokens exist
     if input.contains("GROUP BY") {
@@ -162,8 +366,17 @@ pub fn parse_query(input: &str) -> Result<Document, ParserError> {
         )));
     }
 
+    let document = parse_statement(tokens);
+    if NESTING_TOO_DEEP.with(Cell::get) {
+        return Err(ParserError::NestingTooDeep { max_depth });
+    }
+    document
+}
+
+/// Parse a token stream as whichever statement type it matches
+fn parse_statement(tokens: Tokens<'_>) -> Result<Document, ParserError> {
     // Try to parse as different statement types
-    if let Ok((_, at_stmt)) = at_location_statement(&tokens) {
+    if let Ok((_, at_stmt)) = at_location_statement(tokens) {
         let document = Document {
             statement: Statement::AtLocation(at_stmt),
             location: Location::default(),
@@ -173,7 +386,7 @@ pub fn parse_query(input: &str) -> Result<Document, ParserError> {
         pretty_print_ast(&document);
 
         Ok(document)
-    } else if let Ok((_, declare_stmt)) = declare_statement(&tokens) {
+    } else if let Ok((_, declare_stmt)) = declare_statement(tokens) {
         let document = Document {
             statement: Statement::Declare(declare_stmt),
             location: Location::default(),
@@ -185,7 +398,7 @@ pub fn parse_query(input: &str) -> Result<Document, ParserError> {
         Ok(document)
     // NEXT statements are not allowed as standalone statements
     // They can only appear within procedure body contexts
-    } else if let Ok((_, session_stmt)) = session_statement(&tokens) {
+    } else if let Ok((_, session_stmt)) = session_statement(tokens) {
         let document = Document {
             statement: Statement::SessionStatement(session_stmt),
             location: Location::default(),
@@ -195,7 +408,7 @@ pub fn parse_query(input: &str) -> Result<Document, ParserError> {
         pretty_print_ast(&document);
 
         Ok(document)
-    } else if let Ok((_, transaction_stmt)) = transaction_statement(&tokens) {
+    } else if let Ok((_, transaction_stmt)) = transaction_statement(tokens) {
         let document = Document {
             statement: Statement::TransactionStatement(transaction_stmt),
             location: Location::default(),
@@ -205,7 +418,7 @@ pub fn parse_query(input: &str) -> Result<Document, ParserError> {
         pretty_print_ast(&document);
 
         Ok(document)
-    } else if let Ok((_, catalog_stmt)) = catalog_statement(&tokens) {
+    } else if let Ok((_, catalog_stmt)) = catalog_statement(tokens) {
         let document = Document {
             statement: Statement::CatalogStatement(catalog_stmt),
             location: Location::default(),
@@ -215,7 +428,7 @@ pub fn parse_query(input: &str) -> Result<Document, ParserError> {
         pretty_print_ast(&document);
 
         Ok(document)
-    } else if let Ok((_, index_stmt)) = index_statement(&tokens) {
+    } else if let Ok((_, index_stmt)) = index_statement(tokens) {
         let document = Document {
             statement: Statement::IndexStatement(index_stmt),
             location: Location::default(),
@@ -225,7 +438,7 @@ pub fn parse_query(input: &str) -> Result<Document, ParserError> {
         pretty_print_ast(&document);
 
         Ok(document)
-    } else if let Ok((_, data_stmt)) = data_statement(&tokens) {
+    } else if let Ok((_, data_stmt)) = data_statement(tokens) {
         log::debug!(
             "PARSER: Matched as DataStatement: {:?}",
             std::mem::discriminant(&data_stmt)
@@ -248,7 +461,7 @@ pub fn parse_query(input: &str) -> Result<Document, ParserError> {
         pretty_print_ast(&document);
 
         Ok(document)
-    } else if let Ok((_, procedure_body)) = procedure_body_statement(&tokens) {
+    } else if let Ok((_, procedure_body)) = procedure_body_statement(tokens) {
         let document = Document {
             statement: Statement::ProcedureBody(procedure_body),
             location: Location::default(),
@@ -258,7 +471,7 @@ pub fn parse_query(input: &str) -> Result<Document, ParserError> {
         pretty_print_ast(&document);
 
         Ok(document)
-    } else if let Ok((remaining, call_stmt)) = call_statement(&tokens) {
+    } else if let Ok((remaining, call_stmt)) = call_statement(tokens) {
         // Validate that only Semicolon/EOF remain after CALL statement
         // CALL cannot be combined with RETURN, MATCH, or other clauses
         let only_terminators = remaining
@@ -268,11 +481,14 @@ pub fn parse_query(input: &str) -> Result<Document, ParserError> {
         if !only_terminators {
             // Found unexpected tokens after CALL statement
             // Get the first unexpected token
-            let unexpected = remaining
+            let position = remaining
                 .iter()
-                .find(|t| !matches!(t, Token::Semicolon | Token::Eof))
-                .unwrap_or(&Token::Eof);
-            return Err(ParserError::UnexpectedToken(unexpected.clone()));
+                .position(|t| !matches!(t, Token::Semicolon | Token::Eof))
+                .unwrap_or(0);
+            return Err(ParserError::UnexpectedToken {
+                token: remaining.get(position).cloned().unwrap_or(Token::Eof),
+                location: remaining.skip(position).location(),
+            });
         }
 
         let document = Document {
@@ -284,7 +500,7 @@ pub fn parse_query(input: &str) -> Result<Document, ParserError> {
         pretty_print_ast(&document);
 
         Ok(document)
-    } else if let Ok((_, select_stmt)) = select_statement(&tokens) {
+    } else if let Ok((_, select_stmt)) = select_statement(tokens) {
         let document = Document {
             statement: Statement::Select(select_stmt),
             location: Location::default(),
@@ -294,7 +510,7 @@ pub fn parse_query(input: &str) -> Result<Document, ParserError> {
         pretty_print_ast(&document);
 
         Ok(document)
-    } else if let Ok((_, query)) = query(&tokens) {
+    } else if let Ok((_, query)) = query(tokens) {
         log::debug!(
             "PARSER: Matched as Query: {:?}",
             std::mem::discriminant(&query)
@@ -340,22 +556,27 @@ pub fn parse_query(input: &str) -> Result<Document, ParserError> {
             "PARSER: First few tokens: {:?}",
             tokens.get(0..10).unwrap_or(&[])
         );
-        // Return the first token as unexpected since we couldn't parse it
-        let unexpected = tokens.first().unwrap_or(&Token::Eof);
-        Err(ParserError::UnexpectedToken(unexpected.clone()))
+        // Report the furthest token any statement parser got to
+        let position = FURTHEST_MISMATCH
+            .with(Cell::get)
+            .min(tokens.len().saturating_sub(1));
+        Err(ParserError::UnexpectedToken {
+            token: tokens[position].clone(),
+            location: tokens.skip(position).location(),
+        })
     }
 }
 
 /// Parse a complete query: MATCH [WHERE] RETURN [GROUP BY] [HAVING] [ORDER BY] [LIMIT]
 /// Parse query with set operations support
 /// <query-statement> ::= <query-term> (("UNION" | "EXCEPT") ["ALL"] <query-term> | "INTERSECT" ["ALL"] <query-term>)*
-fn query(tokens: &[Token]) -> IResult<&[Token], Query> {
+fn query(tokens: Tokens<'_>) -> IResult<Tokens<'_>, Query> {
     parse_set_operation(tokens)
 }
 
 /// Parse core query logic (set operations, basic queries) without modifiers
 /// This is the clean separation: core query parsing vs modifier parsing
-fn parse_core_query(tokens: &[Token]) -> IResult<&[Token], Query> {
+fn parse_core_query(tokens: Tokens<'_>) -> IResult<Tokens<'_>, Query> {
     parse_union_except(tokens)
 }
 
@@ -406,7 +627,7 @@ fn apply_query_modifiers(
 
 /// Parse complete query with clean precedence: core query + modifiers
 /// This implements Option 3: Restructured Parser Precedence
-fn parse_set_operation(tokens: &[Token]) -> IResult<&[Token], Query> {
+fn parse_set_operation(tokens: Tokens<'_>) -> IResult<Tokens<'_>, Query> {
     // Step 1: Parse the core query (UNION, INTERSECT, MATCH, etc.)
     let (remaining, core_query) = parse_core_query(tokens)?;
 
@@ -461,7 +682,7 @@ fn parse_set_operation(tokens: &[Token]) -> IResult<&[Token], Query> {
 }
 
 /// Parse UNION and EXCEPT operations (lower precedence)
-fn parse_union_except(tokens: &[Token]) -> IResult<&[Token], Query> {
+fn parse_union_except(tokens: Tokens<'_>) -> IResult<Tokens<'_>, Query> {
     let (mut remaining, mut left) = parse_intersect(tokens)?;
 
     while let Ok((new_remaining, (operation, right))) = parse_union_except_op(remaining) {
@@ -501,7 +722,7 @@ fn parse_union_except(tokens: &[Token]) -> IResult<&[Token], Query> {
 }
 
 /// Parse INTERSECT operations (higher precedence)
-fn parse_intersect(tokens: &[Token]) -> IResult<&[Token], Query> {
+fn parse_intersect(tokens: Tokens<'_>) -> IResult<Tokens<'_>, Query> {
     let (mut remaining, mut left) = parse_query_term(tokens)?;
 
     while let Ok((new_remaining, (operation, right))) = parse_intersect_op(remaining) {
@@ -532,7 +753,7 @@ fn parse_intersect(tokens: &[Token]) -> IResult<&[Token], Query> {
 }
 
 /// Parse a single query term (basic query or parenthesized set operation)
-fn parse_query_term(tokens: &[Token]) -> IResult<&[Token], Query> {
+fn parse_query_term(tokens: Tokens<'_>) -> IResult<Tokens<'_>, Query> {
     alt((
         // Parenthesized query with optional ORDER BY and LIMIT
         parse_parenthesized_query_with_modifiers,
@@ -559,7 +780,8 @@ fn parse_query_term(tokens: &[Token]) -> IResult<&[Token], Query> {
 
 /// Parse parenthesized query - only parse clauses that are actually inside parentheses
 /// This prevents consuming trailing clauses that belong to outer set operations
-fn parse_parenthesized_query_with_modifiers(tokens: &[Token]) -> IResult<&[Token], Query> {
+fn parse_parenthesized_query_with_modifiers(tokens: Tokens<'_>) -> IResult<Tokens<'_>, Query> {
+    let _nesting = NestingGuard::enter(tokens)?;
     // Parse parenthesized query without trailing modifiers
     let (remaining, query) = delimited(
         expect_token(Token::LeftParen),
@@ -571,7 +793,7 @@ fn parse_parenthesized_query_with_modifiers(tokens: &[Token]) -> IResult<&[Token
 }
 
 /// Parse basic query (MATCH ... RETURN ...)
-fn basic_query(tokens: &[Token]) -> IResult<&[Token], Query> {
+fn basic_query(tokens: Tokens<'_>) -> IResult<Tokens<'_>, Query> {
     log::debug!("basic_query function called");
     map(
         tuple((
@@ -598,7 +820,7 @@ fn basic_query(tokens: &[Token]) -> IResult<&[Token], Query> {
                 order_clause.is_some(),
                 limit_clause.is_some()
             );
-            Query::Basic(BasicQuery {
+            Query::Basic(Box::new(BasicQuery {
                 match_clause,
                 where_clause,
                 return_clause,
@@ -607,13 +829,13 @@ fn basic_query(tokens: &[Token]) -> IResult<&[Token], Query> {
                 order_clause,
                 limit_clause,
                 location: Location::default(),
-            })
+            }))
         },
     )(tokens)
 }
 
 /// Parse standalone RETURN query: RETURN [DISTINCT|ALL] items [GROUP BY] [HAVING] [ORDER BY] [LIMIT]
-fn return_query(tokens: &[Token]) -> IResult<&[Token], Query> {
+fn return_query(tokens: Tokens<'_>) -> IResult<Tokens<'_>, Query> {
     map(
         tuple((
             return_clause,
@@ -636,7 +858,7 @@ fn return_query(tokens: &[Token]) -> IResult<&[Token], Query> {
 }
 
 /// Parse final mutation action
-fn final_mutation(tokens: &[Token]) -> IResult<&[Token], FinalMutation> {
+fn final_mutation(tokens: Tokens<'_>) -> IResult<Tokens<'_>, FinalMutation> {
     alt((
         // REMOVE items
         map(
@@ -673,7 +895,7 @@ fn final_mutation(tokens: &[Token]) -> IResult<&[Token], FinalMutation> {
 }
 
 /// Parse mutation pipeline: MATCH ... WITH ... [UNWIND ... [WHERE ...]] REMOVE/SET/DELETE ...
-fn mutation_pipeline(tokens: &[Token]) -> IResult<&[Token], Query> {
+fn mutation_pipeline(tokens: Tokens<'_>) -> IResult<Tokens<'_>, Query> {
     log::debug!(
         "mutation_pipeline: trying to parse with tokens: {:?}",
         tokens.get(0..10)
@@ -717,7 +939,7 @@ fn mutation_pipeline(tokens: &[Token]) -> IResult<&[Token], Query> {
 }
 
 /// Parse a query segment without UNWIND: MATCH [WHERE] WITH
-fn query_segment_no_unwind(tokens: &[Token]) -> IResult<&[Token], QuerySegment> {
+fn query_segment_no_unwind(tokens: Tokens<'_>) -> IResult<Tokens<'_>, QuerySegment> {
     map(
         tuple((match_clause, opt(where_clause), opt(with_clause))),
         |(match_clause, where_clause, with_clause)| QuerySegment {
@@ -732,7 +954,7 @@ fn query_segment_no_unwind(tokens: &[Token]) -> IResult<&[Token], QuerySegment>
 }
 
 /// Parse WITH query: MATCH ... WITH ... [MATCH ... WITH ...] RETURN ...
-fn with_query(tokens: &[Token]) -> IResult<&[Token], Query> {
+fn with_query(tokens: Tokens<'_>) -> IResult<Tokens<'_>, Query> {
     map(
         tuple((
             many1(query_segment),
@@ -757,7 +979,7 @@ fn with_query(tokens: &[Token]) -> IResult<&[Token], Query> {
 }
 
 /// Parse LET statement: LET variable = expression [, variable = expression]*
-fn let_statement(tokens: &[Token]) -> IResult<&[Token], Query> {
+fn let_statement(tokens: Tokens<'_>) -> IResult<Tokens<'_>, Query> {
     preceded(
         tag_token(Token::Let),
         map(
@@ -773,7 +995,7 @@ fn let_statement(tokens: &[Token]) -> IResult<&[Token], Query> {
 }
 
 /// Parse variable definition: variable = expression
-fn variable_definition(tokens: &[Token]) -> IResult<&[Token], VariableDefinition> {
+fn variable_definition(tokens: Tokens<'_>) -> IResult<Tokens<'_>, VariableDefinition> {
     map(
         tuple((identifier, tag_token(Token::Equal), expression)),
         |(variable_name, _, expr)| VariableDefinition {
@@ -785,7 +1007,7 @@ fn variable_definition(tokens: &[Token]) -> IResult<&[Token], VariableDefinition
 }
 
 /// Parse FOR statement: FOR [alias:] variable IN expression
-fn for_statement(tokens: &[Token]) -> IResult<&[Token], Query> {
+fn for_statement(tokens: Tokens<'_>) -> IResult<Tokens<'_>, Query> {
     preceded(
         tag_token(Token::For),
         map(
@@ -808,7 +1030,7 @@ fn for_statement(tokens: &[Token]) -> IResult<&[Token], Query> {
 }
 
 /// Parse FILTER statement: FILTER [WHERE] expression
-fn filter_statement(tokens: &[Token]) -> IResult<&[Token], Query> {
+fn filter_statement(tokens: Tokens<'_>) -> IResult<Tokens<'_>, Query> {
     preceded(
         tag_token(Token::Filter),
         map(
@@ -832,7 +1054,7 @@ fn filter_statement(tokens: &[Token]) -> IResult<&[Token], Query> {
 }
 
 /// Parse UNWIND statement: UNWIND expression AS variable
-fn unwind_statement(tokens: &[Token]) -> IResult<&[Token], Query> {
+fn unwind_statement(tokens: Tokens<'_>) -> IResult<Tokens<'_>, Query> {
     map(
         tuple((
             tag_token(Token::Unwind),
@@ -851,7 +1073,7 @@ fn unwind_statement(tokens: &[Token]) -> IResult<&[Token], Query> {
 }
 
 /// Parse UNWIND clause: UNWIND expression AS variable
-fn unwind_clause(tokens: &[Token]) -> IResult<&[Token], UnwindClause> {
+fn unwind_clause(tokens: Tokens<'_>) -> IResult<Tokens<'_>, UnwindClause> {
     map(
         tuple((
             tag_token(Token::Unwind),
@@ -868,7 +1090,7 @@ fn unwind_clause(tokens: &[Token]) -> IResult<&[Token], UnwindClause> {
 }
 
 /// Parse a query segment: MATCH [WHERE] [WITH] [WHERE] [ORDER BY] [LIMIT] [UNWIND ... AS ...] [WHERE]
-fn query_segment(tokens: &[Token]) -> IResult<&[Token], QuerySegment> {
+fn query_segment(tokens: Tokens<'_>) -> IResult<Tokens<'_>, QuerySegment> {
     map(
         tuple((
             match_clause,
@@ -891,7 +1113,7 @@ fn query_segment(tokens: &[Token]) -> IResult<&[Token], QuerySegment> {
 }
 
 /// Parse UNION or EXCEPT operation
-fn parse_union_except_op(tokens: &[Token]) -> IResult<&[Token], (SetOperationType, Query)> {
+fn parse_union_except_op(tokens: Tokens<'_>) -> IResult<Tokens<'_>, (SetOperationType, Query)> {
     alt((
         // UNION [ALL]
         map(
@@ -929,7 +1151,7 @@ fn parse_union_except_op(tokens: &[Token]) -> IResult<&[Token], (SetOperationTyp
 }
 
 /// Parse INTERSECT operation
-fn parse_intersect_op(tokens: &[Token]) -> IResult<&[Token], (SetOperationType, Query)> {
+fn parse_intersect_op(tokens: Tokens<'_>) -> IResult<Tokens<'_>, (SetOperationType, Query)> {
     map(
         tuple((
             expect_token(Token::Intersect),
@@ -948,7 +1170,7 @@ fn parse_intersect_op(tokens: &[Token]) -> IResult<&[Token], (SetOperationType,
 }
 
 /// Parse a SELECT statement: SELECT [DISTINCT|ALL] (* | return_items) [FROM graph_expression [match_statement]] [WHERE] [GROUP BY] [HAVING] [ORDER BY] [LIMIT]
-fn select_statement(tokens: &[Token]) -> IResult<&[Token], SelectStatement> {
+fn select_statement(tokens: Tokens<'_>) -> IResult<Tokens<'_>, SelectStatement> {
     map(
         tuple((
             expect_token(Token::Select),
@@ -986,7 +1208,7 @@ fn select_statement(tokens: &[Token]) -> IResult<&[Token], SelectStatement> {
 }
 
 /// Parse SELECT list: * | return_item (, return_item)*
-fn select_list(tokens: &[Token]) -> IResult<&[Token], SelectItems> {
+fn select_list(tokens: Tokens<'_>) -> IResult<Tokens<'_>, SelectItems> {
     alt((
         // SELECT *
         map(expect_token(Token::Star), |_| SelectItems::Wildcard {
@@ -1004,7 +1226,7 @@ fn select_list(tokens: &[Token]) -> IResult<&[Token], SelectItems> {
 }
 
 /// Parse FROM clause: FROM graph_expression [match_statement] (, graph_expression [match_statement])*
-fn from_clause(tokens: &[Token]) -> IResult<&[Token], FromClause> {
+fn from_clause(tokens: Tokens<'_>) -> IResult<Tokens<'_>, FromClause> {
     map(
         tuple((
             expect_token(Token::From),
@@ -1023,7 +1245,7 @@ fn from_clause(tokens: &[Token]) -> IResult<&[Token], FromClause> {
 /// which is not part of ISO GQL standard. In ISO GQL, FROM clause requires a graph expression.
 /// Standard syntax: SELECT ... FROM /graph/path MATCH ...
 /// Extension syntax: SELECT ... FROM MATCH ... (uses current session graph)
-fn from_graph_expression(tokens: &[Token]) -> IResult<&[Token], FromGraphExpression> {
+fn from_graph_expression(tokens: Tokens<'_>) -> IResult<Tokens<'_>, FromGraphExpression> {
     alt((
         // NON-STANDARD: Support "FROM MATCH ..." syntax (Neo4j Cypher-style)
         // This uses the current session graph implicitly
@@ -1046,7 +1268,7 @@ fn from_graph_expression(tokens: &[Token]) -> IResult<&[Token], FromGraphExpress
 }
 
 /// Parse DISTINCT qualifier: DISTINCT | ALL
-fn distinct_qualifier(tokens: &[Token]) -> IResult<&[Token], DistinctQualifier> {
+fn distinct_qualifier(tokens: Tokens<'_>) -> IResult<Tokens<'_>, DistinctQualifier> {
     alt((
         value(DistinctQualifier::Distinct, expect_token(Token::Distinct)),
         value(DistinctQualifier::All, expect_token(Token::All)),
@@ -1055,7 +1277,7 @@ fn distinct_qualifier(tokens: &[Token]) -> IResult<&[Token], DistinctQualifier>
 
 /// Parse CALL statement: CALL procedure_name(args...) [YIELD ...] [WHERE ...]
 /// NOTE: WHERE on CALL is a GraphLite extension (not in ISO GQL standard)
-fn call_statement(tokens: &[Token]) -> IResult<&[Token], CallStatement> {
+fn call_statement(tokens: Tokens<'_>) -> IResult<Tokens<'_>, CallStatement> {
     map(
         tuple((
             expect_token(Token::Call),
@@ -1074,7 +1296,7 @@ fn call_statement(tokens: &[Token]) -> IResult<&[Token], CallStatement> {
 }
 
 /// Parse procedure call: procedure_name(args...)
-fn procedure_call(tokens: &[Token]) -> IResult<&[Token], (String, Vec<Expression>)> {
+fn procedure_call(tokens: Tokens<'_>) -> IResult<Tokens<'_>, (String, Vec<Expression>)> {
     map(
         tuple((
             property_access_as_string,
@@ -1087,17 +1309,17 @@ fn procedure_call(tokens: &[Token]) -> IResult<&[Token], (String, Vec<Expression
 }
 
 /// Parse property access as string (for procedure names like gql.list_schemas)
-fn property_access_as_string(tokens: &[Token]) -> IResult<&[Token], String> {
+fn property_access_as_string(tokens: Tokens<'_>) -> IResult<Tokens<'_>, String> {
     if let Some(Token::PropertyAccess(prop_access)) = tokens.first() {
         // Handle PropertyAccess token (e.g., "gql.list_schemas")
-        Ok((&tokens[1..], prop_access.clone()))
+        Ok((tokens.skip(1), prop_access.clone()))
     } else if let Some(Token::Identifier(name)) = tokens.first() {
-        let rest = &tokens[1..];
+        let rest = tokens.skip(1);
 
         // Check if this is a property access (name.property)
         if let Some(Token::Dot) = rest.first() {
             if let Some(Token::Identifier(property)) = rest.get(1) {
-                return Ok((&rest[2..], format!("{}.{}", name, property)));
+                return Ok((rest.skip(2), format!("{}.{}", name, property)));
             }
         }
 
@@ -1112,7 +1334,7 @@ fn property_access_as_string(tokens: &[Token]) -> IResult<&[Token], String> {
 }
 
 /// Parse YIELD clause: YIELD item1 [AS alias1], item2 [AS alias2], ...
-fn yield_clause(tokens: &[Token]) -> IResult<&[Token], YieldClause> {
+fn yield_clause(tokens: Tokens<'_>) -> IResult<Tokens<'_>, YieldClause> {
     map(
         tuple((
             expect_token(Token::Yield),
@@ -1131,7 +1353,7 @@ fn yield_clause(tokens: &[Token]) -> IResult<&[Token], YieldClause> {
 }
 
 /// Parse YIELD item: column_name [AS alias]
-fn yield_item(tokens: &[Token]) -> IResult<&[Token], YieldItem> {
+fn yield_item(tokens: Tokens<'_>) -> IResult<Tokens<'_>, YieldItem> {
     map(
         tuple((
             identifier,
@@ -1146,26 +1368,74 @@ fn yield_item(tokens: &[Token]) -> IResult<&[Token], YieldItem> {
 }
 
 /// Parse MATCH clause
-fn match_clause(tokens: &[Token]) -> IResult<&[Token], MatchClause> {
+fn match_clause(tokens: Tokens<'_>) -> IResult<Tokens<'_>, MatchClause> {
     map(
         tuple((
             expect_token(Token::Match),
-            path_pattern,
-            many0(tuple((expect_token(Token::Comma), path_pattern))),
+            scoped_path_pattern,
+            many0(tuple((expect_token(Token::Comma), scoped_path_pattern))),
+            many0(scan_hint),
         )),
-        |(_, first_pattern, additional_patterns)| {
+        |(_, first_pattern, additional_patterns, hints)| {
             let mut patterns = vec![first_pattern];
             patterns.extend(additional_patterns.into_iter().map(|(_, pattern)| pattern));
             MatchClause {
                 patterns,
+                hints,
                 location: Location::default(),
             }
         },
     )(tokens)
 }
 
+/// Parse a scan hint: USING INDEX var:Label(property) | USING SCAN var[:Label]
+fn scan_hint(tokens: Tokens<'_>) -> IResult<Tokens<'_>, ScanHint> {
+    preceded(
+        expect_identifier("USING"),
+        alt((
+            map(
+                tuple((
+                    expect_identifier("INDEX"),
+                    identifier,
+                    expect_token(Token::Colon),
+                    identifier,
+                    delimited(
+                        expect_token(Token::LeftParen),
+                        identifier,
+                        expect_token(Token::RightParen),
+                    ),
+                )),
+                |(_, variable, _, label, property)| ScanHint::Index {
+                    variable,
+                    label,
+                    property,
+                },
+            ),
+            map(
+                tuple((
+                    expect_identifier("SCAN"),
+                    identifier,
+                    opt(preceded(expect_token(Token::Colon), identifier)),
+                )),
+                |(_, variable, _)| ScanHint::Scan { variable },
+            ),
+        )),
+    )(tokens)
+}
+
+/// Parse a MATCH pattern with an optional graph: path_pattern [FROM graph_path]
+fn scoped_path_pattern(tokens: Tokens<'_>) -> IResult<Tokens<'_>, PathPattern> {
+    map(
+        tuple((
+            path_pattern,
+            opt(preceded(expect_token(Token::From), catalog_path)),
+        )),
+        |(pattern, graph)| PathPattern { graph, ..pattern },
+    )(tokens)
+}
+
 /// Parse path pattern: [identifier =] [path_type] node (edge node)*
-fn path_pattern(tokens: &[Token]) -> IResult<&[Token], PathPattern> {
+fn path_pattern(tokens: Tokens<'_>) -> IResult<Tokens<'_>, PathPattern> {
     map(
         tuple((
             opt(tuple((identifier, expect_token(Token::Equal)))),
@@ -1175,6 +1445,7 @@ fn path_pattern(tokens: &[Token]) -> IResult<&[Token], PathPattern> {
         |(assignment, path_type, elements)| PathPattern {
             assignment: assignment.map(|(id, _)| id),
             path_type,
+            graph: None,
             elements,
             location: Location::default(),
         },
@@ -1182,7 +1453,7 @@ fn path_pattern(tokens: &[Token]) -> IResult<&[Token], PathPattern> {
 }
 
 /// Parse path type keywords: WALK | TRAIL | SIMPLE PATH | ACYCLIC PATH
-fn path_type_keywords(tokens: &[Token]) -> IResult<&[Token], PathType> {
+fn path_type_keywords(tokens: Tokens<'_>) -> IResult<Tokens<'_>, PathType> {
     alt((
         // ACYCLIC PATH (must come before ACYCLIC alone)
         map(
@@ -1202,7 +1473,7 @@ fn path_type_keywords(tokens: &[Token]) -> IResult<&[Token], PathType> {
 }
 
 /// Parse path quantifier: {n,m} | {n,} | {,m} | {n} | ? (per ISO GQL grammar)
-fn path_quantifier(tokens: &[Token]) -> IResult<&[Token], PathQuantifier> {
+fn path_quantifier(tokens: Tokens<'_>) -> IResult<Tokens<'_>, PathQuantifier> {
     alt((
         // Optional pattern: ?
         map(expect_token(Token::Question), |_| PathQuantifier::Optional),
@@ -1237,34 +1508,46 @@ fn path_quantifier(tokens: &[Token]) -> IResult<&[Token], PathQuantifier> {
 }
 
 /// Parse pattern element (node or edge)
-fn pattern_element(tokens: &[Token]) -> IResult<&[Token], PatternElement> {
+fn pattern_element(tokens: Tokens<'_>) -> IResult<Tokens<'_>, PatternElement> {
     alt((
         map(node_pattern, PatternElement::Node),
         map(edge_pattern, PatternElement::Edge),
+        map(abbreviated_edge_pattern, PatternElement::Edge),
     ))(tokens)
 }
 
-/// Parse node pattern: (identifier? :label? {properties}?)
-fn node_pattern(tokens: &[Token]) -> IResult<&[Token], Node> {
+/// Parse node pattern: (identifier? :label? {properties}? (WHERE predicate)?)
+fn node_pattern(tokens: Tokens<'_>) -> IResult<Tokens<'_>, Node> {
     map(
         tuple((
             expect_token(Token::LeftParen),
             opt(identifier),
-            opt(label_list),
+            opt(preceded(expect_token(Token::Colon), label_expression)),
             opt(property_map),
+            opt(inline_where),
             expect_token(Token::RightParen),
         )),
-        |(_, identifier, labels, properties, _)| Node {
+        |(_, identifier, label_expression, properties, predicate, _)| Node {
             identifier,
-            labels: labels.unwrap_or_default(),
+            labels: label_expression
+                .clone()
+                .map(label_expression_to_strings)
+                .unwrap_or_default(),
+            label_expression,
             properties,
+            predicate,
             location: Location::default(),
         },
     )(tokens)
 }
 
+/// Parse the inline WHERE of a node or edge pattern: WHERE predicate
+fn inline_where(tokens: Tokens<'_>) -> IResult<Tokens<'_>, Expression> {
+    preceded(expect_token(Token::Where), expression)(tokens)
+}
+
 /// Parse edge pattern: -[:label {properties}]-
-fn edge_pattern(tokens: &[Token]) -> IResult<&[Token], Edge> {
+fn edge_pattern(tokens: Tokens<'_>) -> IResult<Tokens<'_>, Edge> {
     map(
         tuple((
             edge_direction,
@@ -1291,22 +1574,18 @@ fn edge_pattern(tokens: &[Token]) -> IResult<&[Token], Edge> {
                 value((None, None), success(())),
             )),
             opt(property_map),
+            opt(inline_where),
             expect_token(Token::RightBracket),
             opt(path_quantifier), // ISO GQL: quantifier after ] but before final direction
             edge_direction,
         )),
-        |(left_dir, _, (identifier, labels), properties, _, quantifier, right_dir)| {
-            let direction = match (left_dir, right_dir) {
-                (EdgeDirection::Incoming, EdgeDirection::Outgoing) => EdgeDirection::Both,
-                (EdgeDirection::Incoming, _) => EdgeDirection::Incoming,
-                (_, EdgeDirection::Outgoing) => EdgeDirection::Outgoing,
-                _ => EdgeDirection::Undirected,
-            };
+        |(left_dir, _, (identifier, labels), properties, predicate, _, quantifier, right_dir)| {
             Edge {
                 identifier,
                 labels: labels.unwrap_or_default(),
                 properties,
-                direction,
+                predicate,
+                direction: combine_edge_directions(left_dir, right_dir),
                 quantifier, // ISO GQL: quantifier parsed after ] but before final direction
                 location: Location::default(),
             }
@@ -1314,31 +1593,63 @@ fn edge_pattern(tokens: &[Token]) -> IResult<&[Token], Edge> {
     )(tokens)
 }
 
-/// Parse label list: :label (&label)* OR :label (:label)*  (BNF-compliant + backward compatible)
-/// Supports ISO GQL delimited identifiers: :`Special-Label`
-fn label_list(tokens: &[Token]) -> IResult<&[Token], Vec<String>> {
+/// Direction of an edge pattern from the arrows on either side of its brackets
+fn combine_edge_directions(left: EdgeDirection, right: EdgeDirection) -> EdgeDirection {
+    match (left, right) {
+        (EdgeDirection::Incoming, EdgeDirection::Outgoing) => EdgeDirection::Both,
+        (EdgeDirection::Incoming, _) => EdgeDirection::Incoming,
+        (_, EdgeDirection::Outgoing) => EdgeDirection::Outgoing,
+        _ => EdgeDirection::Undirected,
+    }
+}
+
+/// Parse an edge pattern that matches any relationship:
+/// `-->` | `<--` | `<-->` | `--`, or empty brackets such as `-[]->`
+fn abbreviated_edge_pattern(tokens: Tokens<'_>) -> IResult<Tokens<'_>, Edge> {
     map(
-        tuple((
-            expect_token(Token::Colon),
-            identifier_or_quoted,
-            many0(tuple((
-                alt((
-                    expect_token(Token::Ampersand), // BNF syntax: &label
-                    expect_token(Token::Colon),     // Legacy syntax: :label
+        alt((
+            // The lexer reads `[]` as an empty list literal
+            map(
+                tuple((
+                    edge_direction,
+                    expect_token_variant(
+                        &|token: &Token| matches!(token, Token::Vector(values) if values.is_empty()),
+                    ),
+                    edge_direction,
                 )),
-                identifier_or_quoted,
-            ))),
+                |(left, _, right)| combine_edge_directions(left, right),
+            ),
+            value(
+                EdgeDirection::Both,
+                tuple((expect_token(Token::ArrowLeft), expect_token(Token::Arrow))),
+            ),
+            value(
+                EdgeDirection::Incoming,
+                tuple((expect_token(Token::ArrowLeft), expect_token(Token::Dash))),
+            ),
+            value(
+                EdgeDirection::Outgoing,
+                tuple((expect_token(Token::Dash), expect_token(Token::Arrow))),
+            ),
+            value(
+                EdgeDirection::Undirected,
+                tuple((expect_token(Token::Dash), expect_token(Token::Dash))),
+            ),
         )),
-        |(_, first_label, additional_labels)| {
-            let mut labels = vec![first_label];
-            labels.extend(additional_labels.into_iter().map(|(_, label)| label));
-            labels
+        |direction| Edge {
+            identifier: None,
+            labels: Vec::new(),
+            properties: None,
+            predicate: None,
+            direction,
+            quantifier: None,
+            location: Location::default(),
         },
     )(tokens)
 }
 
 /// Parse property map: {property, ...}
-fn property_map(tokens: &[Token]) -> IResult<&[Token], PropertyMap> {
+fn property_map(tokens: Tokens<'_>) -> IResult<Tokens<'_>, PropertyMap> {
     map(
         delimited(
             expect_token(Token::LeftBrace),
@@ -1366,7 +1677,7 @@ fn property_map(tokens: &[Token]) -> IResult<&[Token], PropertyMap> {
 
 /// Parse property pair: key: value
 /// Supports regular identifiers, backtick-delimited identifiers, and keywords
-fn property_pair(tokens: &[Token]) -> IResult<&[Token], Property> {
+fn property_pair(tokens: Tokens<'_>) -> IResult<Tokens<'_>, Property> {
     map(
         tuple((identifier_or_quoted, expect_token(Token::Colon), expression)),
         |(key, _, value)| Property {
@@ -1378,7 +1689,7 @@ fn property_pair(tokens: &[Token]) -> IResult<&[Token], Property> {
 }
 
 /// Parse WHERE clause
-fn where_clause(tokens: &[Token]) -> IResult<&[Token], WhereClause> {
+fn where_clause(tokens: Tokens<'_>) -> IResult<Tokens<'_>, WhereClause> {
     map(
         tuple((expect_token(Token::Where), expression)),
         |(_, condition)| WhereClause {
@@ -1389,7 +1700,7 @@ fn where_clause(tokens: &[Token]) -> IResult<&[Token], WhereClause> {
 }
 
 /// Parse RETURN clause: RETURN [DISTINCT|ALL] items...
-fn return_clause(tokens: &[Token]) -> IResult<&[Token], ReturnClause> {
+fn return_clause(tokens: Tokens<'_>) -> IResult<Tokens<'_>, ReturnClause> {
     map(
         tuple((
             expect_token(Token::Return),
@@ -1412,8 +1723,23 @@ fn return_clause(tokens: &[Token]) -> IResult<&[Token], ReturnClause> {
     )(tokens)
 }
 
-/// Parse return item: expression [AS alias]
-fn return_item(tokens: &[Token]) -> IResult<&[Token], ReturnItem> {
+/// Parse return item: expression [AS alias] | *
+fn return_item(tokens: Tokens<'_>) -> IResult<Tokens<'_>, ReturnItem> {
+    // RETURN * is represented as the wildcard variable and expanded during planning
+    if let Ok((remaining, _)) = expect_token(Token::Star)(tokens) {
+        return Ok((
+            remaining,
+            ReturnItem {
+                expression: Expression::Variable(Variable {
+                    name: "*".to_string(),
+                    location: Location::default(),
+                }),
+                alias: None,
+                location: Location::default(),
+            },
+        ));
+    }
+
     map(
         tuple((
             expression,
@@ -1422,13 +1748,13 @@ fn return_item(tokens: &[Token]) -> IResult<&[Token], ReturnItem> {
         |(expression, opt_alias)| ReturnItem {
             expression,
             alias: opt_alias.map(|(_, alias)| alias),
-            location: Location::default(),
+            location: tokens.location(),
         },
     )(tokens)
 }
 
 /// Parse WITH clause: WITH [DISTINCT|ALL] expr [AS alias] [, expr [AS alias]]* [WHERE condition] [ORDER BY ...] [LIMIT ...]
-fn with_clause(tokens: &[Token]) -> IResult<&[Token], WithClause> {
+fn with_clause(tokens: Tokens<'_>) -> IResult<Tokens<'_>, WithClause> {
     map(
         tuple((
             expect_token(Token::With),
@@ -1458,7 +1784,7 @@ fn with_clause(tokens: &[Token]) -> IResult<&[Token], WithClause> {
 }
 
 /// Parse WITH item: expression [AS alias]
-fn with_item(tokens: &[Token]) -> IResult<&[Token], WithItem> {
+fn with_item(tokens: Tokens<'_>) -> IResult<Tokens<'_>, WithItem> {
     map(
         tuple((
             expression,
@@ -1473,7 +1799,7 @@ fn with_item(tokens: &[Token]) -> IResult<&[Token], WithItem> {
 }
 
 /// Parse ORDER BY clause: ORDER BY expr [ASC|DESC] [, expr [ASC|DESC]]*
-fn order_clause(tokens: &[Token]) -> IResult<&[Token], OrderClause> {
+fn order_clause(tokens: Tokens<'_>) -> IResult<Tokens<'_>, OrderClause> {
     map(
         tuple((
             expect_token(Token::Order),
@@ -1493,7 +1819,7 @@ fn order_clause(tokens: &[Token]) -> IResult<&[Token], OrderClause> {
 }
 
 /// Parse ORDER BY item: expression [ASC|ASCENDING|DESC|DESCENDING] [NULLS FIRST|LAST]
-fn order_item(tokens: &[Token]) -> IResult<&[Token], OrderItem> {
+fn order_item(tokens: Tokens<'_>) -> IResult<Tokens<'_>, OrderItem> {
     map(
         tuple((
             expression,
@@ -1521,7 +1847,7 @@ fn order_item(tokens: &[Token]) -> IResult<&[Token], OrderItem> {
 }
 
 /// Parse GROUP BY clause: GROUP BY expression, expression, ...
-fn group_clause(tokens: &[Token]) -> IResult<&[Token], GroupClause> {
+fn group_clause(tokens: Tokens<'_>) -> IResult<Tokens<'_>, GroupClause> {
     map(
         tuple((
             expect_token(Token::Group),
@@ -1534,46 +1860,67 @@ fn group_clause(tokens: &[Token]) -> IResult<&[Token], GroupClause> {
             expressions.extend(additional_exprs.into_iter().map(|(_, expr)| expr));
             GroupClause {
                 expressions,
-                location: Location::default(),
+                location: tokens.location(),
             }
         },
     )(tokens)
 }
 
 /// Parse HAVING clause: HAVING expression
-fn having_clause(tokens: &[Token]) -> IResult<&[Token], HavingClause> {
+fn having_clause(tokens: Tokens<'_>) -> IResult<Tokens<'_>, HavingClause> {
     map(
         tuple((expect_token(Token::Having), expression)),
         |(_, condition)| HavingClause {
             condition,
-            location: Location::default(),
+            location: tokens.location(),
         },
     )(tokens)
 }
 
-/// Parse LIMIT clause: LIMIT count [OFFSET offset]
-fn limit_clause(tokens: &[Token]) -> IResult<&[Token], LimitClause> {
+/// Parse LIMIT clause: LIMIT count [OFFSET|SKIP offset]
+fn limit_clause(tokens: Tokens<'_>) -> IResult<Tokens<'_>, LimitClause> {
     map(
         tuple((
             expect_token(Token::Limit),
-            integer_literal,
-            opt(tuple((expect_token(Token::Offset), integer_literal))),
+            limit_value,
+            opt(tuple((
+                alt((expect_token(Token::Offset), expect_token(Token::Skip))),
+                limit_value,
+            ))),
         )),
         |(_, count, opt_offset)| LimitClause {
-            count: count as usize,
-            offset: opt_offset.map(|(_, offset)| offset as usize),
+            count,
+            offset: opt_offset.map(|(_, offset)| offset),
             location: Location::default(),
         },
     )(tokens)
 }
 
+/// Parse LIMIT/OFFSET count: non-negative integer literal or $parameter
+fn limit_value(tokens: Tokens<'_>) -> IResult<Tokens<'_>, LimitValue> {
+    // A negative count is an error, not the end of the query
+    if matches!(tokens.first(), Some(Token::Integer(count)) if *count < 0) {
+        return Err(nom::Err::Failure(nom::error::Error::new(
+            tokens,
+            nom::error::ErrorKind::Verify,
+        )));
+    }
+    alt((
+        map(verify(integer_literal, |count| *count >= 0), |count| {
+            LimitValue::Literal(count as usize)
+        }),
+        map(parameter, LimitValue::Parameter),
+    ))(tokens)
+}
+
 /// Parse expression with operator precedence
-fn expression(tokens: &[Token]) -> IResult<&[Token], Expression> {
+fn expression(tokens: Tokens<'_>) -> IResult<Tokens<'_>, Expression> {
+    let _nesting = NestingGuard::enter(tokens)?;
     or_expression(tokens)
 }
 
 /// Parse OR expressions: xor_expr OR xor_expr
-fn or_expression(tokens: &[Token]) -> IResult<&[Token], Expression> {
+fn or_expression(tokens: Tokens<'_>) -> IResult<Tokens<'_>, Expression> {
     map(
         tuple((
             xor_expression,
@@ -1593,7 +1940,7 @@ fn or_expression(tokens: &[Token]) -> IResult<&[Token], Expression> {
 }
 
 /// Parse XOR expressions: and_expr XOR and_expr
-fn xor_expression(tokens: &[Token]) -> IResult<&[Token], Expression> {
+fn xor_expression(tokens: Tokens<'_>) -> IResult<Tokens<'_>, Expression> {
     map(
         tuple((
             and_expression,
@@ -1613,7 +1960,7 @@ fn xor_expression(tokens: &[Token]) -> IResult<&[Token], Expression> {
 }
 
 /// Parse AND expressions: not_expr AND not_expr
-fn and_expression(tokens: &[Token]) -> IResult<&[Token], Expression> {
+fn and_expression(tokens: Tokens<'_>) -> IResult<Tokens<'_>, Expression> {
     map(
         tuple((
             not_expression,
@@ -1633,7 +1980,7 @@ fn and_expression(tokens: &[Token]) -> IResult<&[Token], Expression> {
 }
 
 /// Parse NOT expressions: [NOT] comparison
-fn not_expression(tokens: &[Token]) -> IResult<&[Token], Expression> {
+fn not_expression(tokens: Tokens<'_>) -> IResult<Tokens<'_>, Expression> {
     alt((
         map(
             tuple((expect_token(Token::Not), comparison)),
@@ -1650,7 +1997,7 @@ fn not_expression(tokens: &[Token]) -> IResult<&[Token], Expression> {
 }
 
 /// Parse shorthand label predicate: identifier:label (e.g., n:Person)
-fn shorthand_label_predicate(tokens: &[Token]) -> IResult<&[Token], Expression> {
+fn shorthand_label_predicate(tokens: Tokens<'_>) -> IResult<Tokens<'_>, Expression> {
     map(
         tuple((identifier, expect_token(Token::Colon), identifier)),
         |(var_name, _, label_name)| {
@@ -1677,185 +2024,195 @@ fn shorthand_label_predicate(tokens: &[Token]) -> IResult<&[Token], Expression>
     )(tokens)
 }
 
-/// Parse comparison: expr op expr | expr IN (subquery) | expr IN (list) | expr NOT IN (subquery) | expr NOT IN (list) | expr IS [NOT] predicate
-fn comparison(tokens: &[Token]) -> IResult<&[Token], Expression> {
-    alt((
-        // Handle shorthand label predicates: variable:label
-        shorthand_label_predicate,
-        // Handle IS predicates
-        is_predicate,
-        // Handle quantified comparisons: expr op ALL/ANY/SOME (subquery)
+/// Parse comparison: expr op expr | expr op ALL|ANY|SOME (subquery) | expr [NOT] IN (subquery)
+/// | expr [NOT] IN (list) | expr [NOT] BETWEEN low AND high | expr IS [NOT] predicate
+///
+/// The left operand is parsed once and the tokens after it pick the form.
+/// Trying each form from the start instead would re-parse the operand once
+/// per form at every level of parentheses.
+fn comparison(tokens: Tokens<'_>) -> IResult<Tokens<'_>, Expression> {
+    // Handle shorthand label predicates: variable:label
+    if let Ok(result) = shorthand_label_predicate(tokens) {
+        return Ok(result);
+    }
+
+    let (remaining, left) = additive_expression(tokens)?;
+    let is_between = |token: Option<&Token>| matches!(token, Some(Token::Identifier(id)) if id.eq_ignore_ascii_case("BETWEEN"));
+    let tail = match remaining.first() {
+        Some(Token::Is) => is_predicate(&left, remaining),
+        Some(Token::In) => in_predicate(&left, false, remaining.skip(1)),
+        Some(Token::Not) if matches!(remaining.get(1), Some(Token::In)) => {
+            in_predicate(&left, true, remaining.skip(2))
+        }
+        Some(Token::Not) if is_between(remaining.get(1)) => {
+            between_predicate(&left, true, remaining.skip(2))
+        }
+        token if is_between(token) => between_predicate(&left, false, remaining.skip(1)),
+        _ => alt((
+            // Quantified comparisons: expr op ALL/ANY/SOME (subquery)
+            map(
+                tuple((
+                    comparison_operator,
+                    quantifier,
+                    expect_token(Token::LeftParen),
+                    basic_query,
+                    expect_token(Token::RightParen),
+                )),
+                |(op, quant, _, query, _)| {
+                    Expression::QuantifiedComparison(QuantifiedComparisonExpression {
+                        left: Box::new(left.clone()),
+                        operator: op,
+                        quantifier: quant,
+                        subquery: Box::new(Expression::Subquery(SubqueryExpression {
+                            query: Box::new(query),
+                            location: Location::default(),
+                        })),
+                        location: Location::default(),
+                    })
+                },
+            ),
+            // Regular comparison operations
+            map(
+                tuple((comparison_operator, additive_expression)),
+                |(op, right)| {
+                    Expression::Binary(BinaryExpression {
+                        left: Box::new(left.clone()),
+                        operator: op,
+                        right: Box::new(right),
+                        location: Location::default(),
+                    })
+                },
+            ),
+        ))(remaining),
+    };
+
+    match tail {
+        // Without a complete predicate after it the operand stands alone
+        Err(nom::Err::Error(_)) => Ok((remaining, left)),
+        tail => tail,
+    }
+}
+
+/// Parse the rest of expr [NOT] IN (subquery) | expr [NOT] IN (list) | expr [NOT] IN expr,
+/// starting after IN
+fn in_predicate<'a>(
+    left: &Expression,
+    negated: bool,
+    tokens: Tokens<'a>,
+) -> IResult<Tokens<'a>, Expression> {
+    let (remaining, right) = alt((
+        // Try subquery first: IN (SELECT ...)
         map(
             tuple((
-                additive_expression,
-                comparison_operator,
-                quantifier,
                 expect_token(Token::LeftParen),
                 basic_query,
                 expect_token(Token::RightParen),
             )),
-            |(left, op, quant, _, query, _)| {
-                Expression::QuantifiedComparison(QuantifiedComparisonExpression {
-                    left: Box::new(left),
-                    operator: op,
-                    quantifier: quant,
-                    subquery: Box::new(Expression::Subquery(SubqueryExpression {
-                        query: Box::new(query),
+            |(_, query, _)| {
+                let expression = Box::new(left.clone());
+                let query = Box::new(query);
+                if negated {
+                    Expression::NotInSubquery(NotInSubqueryExpression {
+                        expression,
+                        query,
                         location: Location::default(),
-                    })),
-                    location: Location::default(),
-                })
-            },
-        ),
-        // Handle NOT IN subquery specially
-        map(
-            tuple((
-                additive_expression,
-                expect_token(Token::Not),
-                expect_token(Token::In),
-                alt((
-                    // Try subquery first: NOT IN (SELECT ...)
-                    map(
-                        tuple((
-                            expect_token(Token::LeftParen),
-                            basic_query,
-                            expect_token(Token::RightParen),
-                        )),
-                        |(_, query, _)| {
-                            Expression::NotInSubquery(NotInSubqueryExpression {
-                                expression: Box::new(Expression::Literal(Literal::String(
-                                    "placeholder".to_string(),
-                                ))), // Will be replaced
-                                query: Box::new(query),
-                                location: Location::default(),
-                            })
-                        },
-                    ),
-                    // Try parenthesized list: NOT IN ('a', 'b', 'c')
-                    map(
-                        tuple((
-                            expect_token(Token::LeftParen),
-                            expression_list,
-                            expect_token(Token::RightParen),
-                        )),
-                        |(_, exprs, _)| {
-                            Expression::Literal(Literal::List(
-                                exprs
-                                    .into_iter()
-                                    .map(|e| {
-                                        // Convert expressions to literals if possible
-                                        match e {
-                                            Expression::Literal(lit) => lit,
-                                            _ => Literal::String(format!("{:?}", e)), // Fallback
-                                        }
-                                    })
-                                    .collect(),
-                            ))
-                        },
-                    ),
-                    // Fallback: variable or array expression
-                    additive_expression,
-                )),
-            )),
-            |(left, _, _, right)| match right {
-                Expression::NotInSubquery(mut not_in_subquery) => {
-                    not_in_subquery.expression = Box::new(left);
-                    Expression::NotInSubquery(not_in_subquery)
+                    })
+                } else {
+                    Expression::InSubquery(InSubqueryExpression {
+                        expression,
+                        query,
+                        location: Location::default(),
+                    })
                 }
-                _ => Expression::Binary(BinaryExpression {
-                    left: Box::new(left),
-                    operator: Operator::NotIn,
-                    right: Box::new(right),
-                    location: Location::default(),
-                }),
             },
         ),
-        // Handle IN subquery specially
+        // Try parenthesized list: IN ('a', 'b', 'c')
         map(
             tuple((
-                additive_expression,
-                expect_token(Token::In),
-                alt((
-                    // Try subquery first: IN (SELECT ...)
-                    map(
-                        tuple((
-                            expect_token(Token::LeftParen),
-                            basic_query,
-                            expect_token(Token::RightParen),
-                        )),
-                        |(_, query, _)| {
-                            Expression::InSubquery(InSubqueryExpression {
-                                expression: Box::new(Expression::Literal(Literal::String(
-                                    "placeholder".to_string(),
-                                ))), // Will be replaced
-                                query: Box::new(query),
-                                location: Location::default(),
-                            })
-                        },
-                    ),
-                    // Try parenthesized list: IN ('a', 'b', 'c')
-                    map(
-                        tuple((
-                            expect_token(Token::LeftParen),
-                            expression_list,
-                            expect_token(Token::RightParen),
-                        )),
-                        |(_, exprs, _)| {
-                            Expression::Literal(Literal::List(
-                                exprs
-                                    .into_iter()
-                                    .map(|e| {
-                                        // Convert expressions to literals if possible
-                                        match e {
-                                            Expression::Literal(lit) => lit,
-                                            _ => Literal::String(format!("{:?}", e)), // Fallback
-                                        }
-                                    })
-                                    .collect(),
-                            ))
-                        },
-                    ),
-                    // Fallback: variable or array expression
-                    additive_expression,
-                )),
+                expect_token(Token::LeftParen),
+                expression_list,
+                expect_token(Token::RightParen),
             )),
-            |(left, _, right)| match right {
-                Expression::InSubquery(mut in_subquery) => {
-                    in_subquery.expression = Box::new(left);
-                    Expression::InSubquery(in_subquery)
-                }
-                _ => Expression::Binary(BinaryExpression {
-                    left: Box::new(left),
-                    operator: Operator::In,
-                    right: Box::new(right),
-                    location: Location::default(),
-                }),
+            |(_, exprs, _)| {
+                Expression::Literal(Literal::List(
+                    exprs
+                        .into_iter()
+                        .map(|e| {
+                            // Convert expressions to literals if possible
+                            match e {
+                                Expression::Literal(lit) => lit,
+                                _ => Literal::String(format!("{:?}", e)), // Fallback
+                            }
+                        })
+                        .collect(),
+                ))
             },
         ),
-        // Regular comparison operations
-        map(
-            tuple((
-                additive_expression,
-                opt(tuple((comparison_operator, additive_expression))),
-            )),
-            |(left, opt_right)| {
-                if let Some((op, right)) = opt_right {
-                    Expression::Binary(BinaryExpression {
-                        left: Box::new(left),
-                        operator: op,
-                        right: Box::new(right),
-                        location: Location::default(),
-                    })
-                } else {
-                    left
-                }
+        // Fallback: variable or array expression
+        additive_expression,
+    ))(tokens)?;
+
+    let expression = match right {
+        Expression::InSubquery(_) | Expression::NotInSubquery(_) => right,
+        right => Expression::Binary(BinaryExpression {
+            left: Box::new(left.clone()),
+            operator: if negated {
+                Operator::NotIn
+            } else {
+                Operator::In
             },
-        ),
-    ))(tokens)
+            right: Box::new(right),
+            location: Location::default(),
+        }),
+    };
+    Ok((remaining, expression))
+}
+
+/// Parse the rest of expr [NOT] BETWEEN low AND high, starting after BETWEEN
+///
+/// The range is inclusive and is rewritten to `expr >= low AND expr <= high`,
+/// so it evaluates and uses range indexes like the two comparisons would.
+fn between_predicate<'a>(
+    value: &Expression,
+    negated: bool,
+    tokens: Tokens<'a>,
+) -> IResult<Tokens<'a>, Expression> {
+    map(
+        tuple((
+            additive_expression,
+            expect_token(Token::And),
+            additive_expression,
+        )),
+        |(low, _, high)| {
+            let bound = |operator, bound| {
+                Expression::Binary(BinaryExpression {
+                    left: Box::new(value.clone()),
+                    operator,
+                    right: Box::new(bound),
+                    location: Location::default(),
+                })
+            };
+            let range = Expression::Binary(BinaryExpression {
+                left: Box::new(bound(Operator::GreaterEqual, low)),
+                operator: Operator::And,
+                right: Box::new(bound(Operator::LessEqual, high)),
+                location: Location::default(),
+            });
+            if negated {
+                Expression::Unary(UnaryExpression {
+                    operator: Operator::Not,
+                    expression: Box::new(range),
+                    location: Location::default(),
+                })
+            } else {
+                range
+            }
+        },
+    )(tokens)
 }
 
 /// Parse additive expressions: mult_expr (+|-|*|||) mult_expr
-fn additive_expression(tokens: &[Token]) -> IResult<&[Token], Expression> {
+fn additive_expression(tokens: Tokens<'_>) -> IResult<Tokens<'_>, Expression> {
     map(
         tuple((
             multiplicative_expression,
@@ -1890,7 +2247,7 @@ fn additive_expression(tokens: &[Token]) -> IResult<&[Token], Expression> {
 }
 
 /// Parse multiplicative expressions: postfix (*|/) postfix
-fn multiplicative_expression(tokens: &[Token]) -> IResult<&[Token], Expression> {
+fn multiplicative_expression(tokens: Tokens<'_>) -> IResult<Tokens<'_>, Expression> {
     map(
         tuple((
             postfix_expression,
@@ -1925,7 +2282,7 @@ fn multiplicative_expression(tokens: &[Token]) -> IResult<&[Token], Expression>
 /// Parse array expression: [expr1, expr2, ...]
 /// Returns a Literal::Vector if all elements are numeric, otherwise fails
 /// This allows arrays to be used as arguments to functions that accept vector inputs
-fn array_expression(tokens: &[Token]) -> IResult<&[Token], Literal> {
+fn array_expression(tokens: Tokens<'_>) -> IResult<Tokens<'_>, Literal> {
     // Parse [expr1, expr2, ...]
     let (tokens, _) = expect_token(Token::LeftBracket)(tokens)?;
 
@@ -1991,8 +2348,58 @@ fn array_expression(tokens: &[Token]) -> IResult<&[Token], Literal> {
     }
 }
 
+/// Parse map literal: {key: value, ...}
+/// Values must themselves be literals (including nested maps and lists)
+fn map_expression(tokens: Tokens<'_>) -> IResult<Tokens<'_>, Literal> {
+    let (remaining, prop_map) = property_map(tokens)?;
+
+    let mut entries = Vec::with_capacity(prop_map.properties.len());
+    for property in prop_map.properties {
+        match property.value {
+            Expression::Literal(literal) => entries.push((property.key, literal)),
+            _ => {
+                return Err(nom::Err::Error(nom::error::Error::new(
+                    tokens,
+                    nom::error::ErrorKind::Tag,
+                )));
+            }
+        }
+    }
+
+    Ok((remaining, Literal::Map(entries)))
+}
+
+/// Parse list constructor: [expr1, expr2, ...] with computed elements
+/// Tried after array_expression, so lists of literals stay literals
+fn list_constructor(tokens: Tokens<'_>) -> IResult<Tokens<'_>, ListConstructor> {
+    map(
+        delimited(
+            expect_token(Token::LeftBracket),
+            expression_list,
+            expect_token(Token::RightBracket),
+        ),
+        |elements| ListConstructor {
+            elements,
+            location: Location::default(),
+        },
+    )(tokens)
+}
+
+/// Parse map constructor: {key: expr, ...} with computed values
+/// Tried after map_expression, so maps of literals stay literals
+fn map_constructor(tokens: Tokens<'_>) -> IResult<Tokens<'_>, MapConstructor> {
+    map(property_map, |prop_map| MapConstructor {
+        entries: prop_map
+            .properties
+            .into_iter()
+            .map(|property| (property.key, property.value))
+            .collect(),
+        location: Location::default(),
+    })(tokens)
+}
+
 /// Parse PATH constructor: PATH[expr1, expr2, ...] or PATH + vector
-fn path_constructor(tokens: &[Token]) -> IResult<&[Token], PathConstructor> {
+fn path_constructor(tokens: Tokens<'_>) -> IResult<Tokens<'_>, PathConstructor> {
     let (tokens, _) = expect_token(Token::Path)(tokens)?;
 
     // Handle case where lexer parsed brackets with numbers as a vector literal
@@ -2041,32 +2448,66 @@ fn path_constructor(tokens: &[Token]) -> IResult<&[Token], PathConstructor> {
     ))
 }
 
-/// Parse postfix expressions: primary_expr[index] | primary_expr
-fn postfix_expression(tokens: &[Token]) -> IResult<&[Token], Expression> {
+/// Parse postfix expressions: primary_expr[index] | primary_expr[start..end] | primary_expr
+fn postfix_expression(tokens: Tokens<'_>) -> IResult<Tokens<'_>, Expression> {
     let (mut remaining, mut expr) = primary_expression(tokens)?;
 
-    // Check for array indexing: [expression]
+    // Check for array indexing: [expression] or slicing: [start..end]
     while let Ok((tokens_after_bracket, _)) = expect_token(Token::LeftBracket)(remaining) {
-        // Parse the index expression
-        let (tokens_after_index, index_expr) = expression(tokens_after_bracket)?;
-        let (tokens_after_close, _) = expect_token(Token::RightBracket)(tokens_after_index)?;
-
-        // Create an ArrayIndexExpression
-        expr = Expression::ArrayIndex(ArrayIndexExpression {
-            array: Box::new(expr),
-            index: Box::new(index_expr),
-            location: Location::default(),
-        });
+        // The start bound of a slice may be omitted: [..end]
+        let (tokens_after_start, start_expr) =
+            if tokens_after_bracket.starts_with(&[Token::Dot, Token::Dot]) {
+                (tokens_after_bracket, None)
+            } else {
+                let (tokens, start_expr) = expression(tokens_after_bracket)?;
+                (tokens, Some(start_expr))
+            };
+
+        match (tokens_after_start, start_expr) {
+            (tokens_after_start, start_expr)
+                if tokens_after_start.starts_with(&[Token::Dot, Token::Dot]) =>
+            {
+                let tokens_after_range = tokens_after_start.skip(2);
+                // The end bound of a slice may be omitted: [start..]
+                let (tokens_after_end, end_expr) =
+                    if matches!(tokens_after_range.first(), Some(Token::RightBracket)) {
+                        (tokens_after_range, None)
+                    } else {
+                        let (tokens, end_expr) = expression(tokens_after_range)?;
+                        (tokens, Some(end_expr))
+                    };
+                let (tokens_after_close, _) = expect_token(Token::RightBracket)(tokens_after_end)?;
+
+                expr = Expression::ArraySlice(ArraySliceExpression {
+                    array: Box::new(expr),
+                    start: start_expr.map(Box::new),
+                    end: end_expr.map(Box::new),
+                    location: Location::default(),
+                });
+                remaining = tokens_after_close;
+            }
+            (tokens_after_index, Some(index_expr)) => {
+                let (tokens_after_close, _) =
+                    expect_token(Token::RightBracket)(tokens_after_index)?;
 
-        remaining = tokens_after_close;
+                expr = Expression::ArrayIndex(ArrayIndexExpression {
+                    array: Box::new(expr),
+                    index: Box::new(index_expr),
+                    location: Location::default(),
+                });
+                remaining = tokens_after_close;
+            }
+            (tokens_after_index, None) => return Err(mismatch(tokens_after_index)),
+        }
     }
 
     Ok((remaining, expr))
 }
 
 /// Parse primary expression: (expr) | function_call | property_access | value | case_expr
-fn primary_expression(tokens: &[Token]) -> IResult<&[Token], Expression> {
-    alt((
+fn primary_expression(tokens: Tokens<'_>) -> IResult<Tokens<'_>, Expression> {
+    let location = tokens.location();
+    let (remaining, expr) = alt((
         // NOT EXISTS subquery must be checked before EXISTS to avoid conflicts
         map(not_exists_subquery, Expression::NotExistsSubquery),
         // EXISTS subquery must be checked before general parenthesized expressions
@@ -2092,19 +2533,23 @@ fn primary_expression(tokens: &[Token]) -> IResult<&[Token], Expression> {
         map(cast_expression, Expression::Cast),
         map(path_constructor, Expression::PathConstructor),
         map(array_expression, Expression::Literal),
+        map(map_expression, Expression::Literal),
+        map(list_constructor, Expression::ListConstructor),
+        map(map_constructor, Expression::MapConstructor),
         map(trim_function_call, Expression::FunctionCall), // Special TRIM FROM syntax
-        map(function_call, Expression::FunctionCall),
+        function_or_window_call,
         map(property_access, Expression::PropertyAccess),
         map(property_access_continued, Expression::PropertyAccess),
         map(property_access_token, Expression::PropertyAccess),
         map(parameter, Expression::Parameter),
         map(variable, Expression::Variable),
         map(literal, Expression::Literal),
-    ))(tokens)
+    ))(tokens)?;
+    Ok((remaining, locate_expression(expr, location)))
 }
 
 /// Parse expression list: expr1, expr2, expr3, ...
-fn expression_list(tokens: &[Token]) -> IResult<&[Token], Vec<Expression>> {
+fn expression_list(tokens: Tokens<'_>) -> IResult<Tokens<'_>, Vec<Expression>> {
     map(
         tuple((
             expression,
@@ -2119,11 +2564,15 @@ fn expression_list(tokens: &[Token]) -> IResult<&[Token], Vec<Expression>> {
 }
 
 /// Parse function call: name(args...) using ISO GQL compliant token-based parsing
-fn function_call(tokens: &[Token]) -> IResult<&[Token], FunctionCall> {
-    // ISO GQL: <function-call> ::= <identifier> "(" [DISTINCT|ALL] [<expression> ("," <expression>)*] ")"
-
-    // Parse function name (identifier)
-    let (tokens, name) = identifier(tokens)?;
+fn function_call(tokens: Tokens<'_>) -> IResult<Tokens<'_>, FunctionCall> {
+    // ISO GQL: <function-call> ::= <identifier> "(" [DISTINCT|ALL] [<expression> ("," <expression>)*] [ORDER BY ...] ")"
+
+    // Parse function name (identifier, or the FIRST/LAST aggregate keywords)
+    let (tokens, name) = alt((
+        identifier,
+        value("FIRST".to_string(), expect_token(Token::First)),
+        value("LAST".to_string(), expect_token(Token::Last)),
+    ))(tokens)?;
     let name = name.to_uppercase(); // Normalize function names to uppercase
 
     // Parse opening parenthesis
@@ -2146,22 +2595,48 @@ fn function_call(tokens: &[Token]) -> IResult<&[Token], FunctionCall> {
                 name: "*".to_string(),
                 location: Location::default(),
             }));
-            remaining = &remaining[1..]; // consume the star
+            remaining = remaining.skip(1); // consume the star
         } else {
-            // Regular argument parsing loop
+            // Regular argument parsing loop; named arguments (`name => literal`)
+            // follow the positional ones and are passed as one trailing map
+            let mut named_arguments: Vec<(String, Literal)> = Vec::new();
             loop {
-                // Parse an expression as argument
-                let (new_remaining, expr) = expression(remaining)?;
-                arguments.push(expr);
-                remaining = new_remaining;
+                let named = match named_argument(remaining) {
+                    Err(nom::Err::Failure(e)) => return Err(nom::Err::Failure(e)),
+                    named => named.ok(),
+                };
+                if let Some((new_remaining, (name, value))) = named {
+                    if named_arguments
+                        .iter()
+                        .any(|(existing, _)| *existing == name)
+                    {
+                        return Err(nom::Err::Failure(nom::error::Error::new(
+                            remaining,
+                            nom::error::ErrorKind::Verify,
+                        )));
+                    }
+                    named_arguments.push((name, value));
+                    remaining = new_remaining;
+                } else if !named_arguments.is_empty() {
+                    // A positional argument after a named one
+                    return Err(nom::Err::Failure(nom::error::Error::new(
+                        remaining,
+                        nom::error::ErrorKind::Verify,
+                    )));
+                } else {
+                    // Parse an expression as argument
+                    let (new_remaining, expr) = expression(remaining)?;
+                    arguments.push(expr);
+                    remaining = new_remaining;
+                }
 
-                // Check for comma (more arguments) or closing paren (end)
+                // Check for comma (more arguments) or closing paren / ORDER BY (end)
                 match remaining.first() {
                     Some(Token::Comma) => {
-                        remaining = &remaining[1..]; // consume comma
+                        remaining = remaining.skip(1); // consume comma
                         continue;
                     }
-                    Some(Token::RightParen) => break,
+                    Some(Token::RightParen) | Some(Token::Order) => break,
                     _ => {
                         return Err(nom::Err::Error(nom::error::Error::new(
                             remaining,
@@ -2170,9 +2645,16 @@ fn function_call(tokens: &[Token]) -> IResult<&[Token], FunctionCall> {
                     }
                 }
             }
+            if !named_arguments.is_empty() {
+                arguments.push(Expression::Literal(Literal::Map(named_arguments)));
+            }
         }
     }
 
+    // Parse optional ORDER BY for order-sensitive aggregates: ARRAY_AGG(x ORDER BY y)
+    let (remaining, order_by) = opt(order_clause)(remaining)?;
+    let order_by = order_by.map(|clause| clause.items).unwrap_or_default();
+
     // Parse closing parenthesis
     let (remaining, _) = expect_token(Token::RightParen)(remaining)?;
 
@@ -2182,14 +2664,73 @@ fn function_call(tokens: &[Token]) -> IResult<&[Token], FunctionCall> {
             name,
             distinct,
             arguments,
+            order_by,
             location: Location::default(),
         },
     ))
 }
 
+/// Parse a named function argument: `name => literal`
+fn named_argument(tokens: Tokens<'_>) -> IResult<Tokens<'_>, (String, Literal)> {
+    let (tokens, name) = identifier(tokens)?;
+    let (tokens, _) = expect_token(Token::Equal)(tokens)?;
+    let (tokens, _) = expect_token(Token::GreaterThan)(tokens)?;
+    let (tokens, value) = expression(tokens)?;
+    match value {
+        Expression::Literal(literal) => Ok((tokens, (name, literal))),
+        _ => Err(nom::Err::Failure(nom::error::Error::new(
+            tokens,
+            nom::error::ErrorKind::Verify,
+        ))),
+    }
+}
+
+/// Parse a function call, which is a window function when followed by OVER
+fn function_or_window_call(tokens: Tokens<'_>) -> IResult<Tokens<'_>, Expression> {
+    map(
+        pair(function_call, opt(window_specification)),
+        |(function, window)| match window {
+            Some((partition_by, order_by)) => Expression::Window(WindowFunction {
+                function,
+                partition_by,
+                order_by,
+                location: Location::default(),
+            }),
+            None => Expression::FunctionCall(function),
+        },
+    )(tokens)
+}
+
+/// Parse a window specification: OVER "(" [PARTITION BY expr, ...] [ORDER BY item, ...] ")"
+fn window_specification(
+    tokens: Tokens<'_>,
+) -> IResult<Tokens<'_>, (Vec<Expression>, Vec<OrderItem>)> {
+    map(
+        tuple((
+            expect_identifier("OVER"),
+            expect_token(Token::LeftParen),
+            opt(preceded(
+                pair(expect_identifier("PARTITION"), expect_token(Token::By)),
+                expression_list,
+            )),
+            opt(preceded(
+                pair(expect_token(Token::Order), expect_token(Token::By)),
+                separated_list1(expect_token(Token::Comma), order_item),
+            )),
+            expect_token(Token::RightParen),
+        )),
+        |(_, _, partition_by, order_by, _)| {
+            (
+                partition_by.unwrap_or_default(),
+                order_by.unwrap_or_default(),
+            )
+        },
+    )(tokens)
+}
+
 /// Parse special TRIM function with ISO GQL FROM clause syntax
 /// TRIM "(" [("LEADING" | "TRAILING" | "BOTH") [<string-expr>] "FROM"] <string-expr> ")"
-fn trim_function_call(tokens: &[Token]) -> IResult<&[Token], FunctionCall> {
+fn trim_function_call(tokens: Tokens<'_>) -> IResult<Tokens<'_>, FunctionCall> {
     // Check if this is a TRIM function call
     if !matches!(tokens.first(), Some(Token::Identifier(name)) if name.eq_ignore_ascii_case("TRIM"))
     {
@@ -2208,15 +2749,15 @@ fn trim_function_call(tokens: &[Token]) -> IResult<&[Token], FunctionCall> {
     // Check for trim mode keywords: LEADING, TRAILING, BOTH
     let trim_mode = match remaining.first() {
         Some(Token::Leading) => {
-            remaining = &remaining[1..];
+            remaining = remaining.skip(1);
             Some("LEADING")
         }
         Some(Token::Trailing) => {
-            remaining = &remaining[1..];
+            remaining = remaining.skip(1);
             Some("TRAILING")
         }
         Some(Token::Both) => {
-            remaining = &remaining[1..];
+            remaining = remaining.skip(1);
             Some("BOTH")
         }
         _ => None,
@@ -2230,7 +2771,7 @@ fn trim_function_call(tokens: &[Token]) -> IResult<&[Token], FunctionCall> {
         // Check if next token is FROM (no trim character specified) or an expression (trim character)
         if matches!(remaining.first(), Some(Token::From)) {
             // TRIM(MODE FROM string) - no trim character, use default whitespace
-            remaining = &remaining[1..]; // consume FROM
+            remaining = remaining.skip(1); // consume FROM
             arguments.push(Expression::Literal(Literal::String(" ".to_string())));
         // default trim char
         } else {
@@ -2251,7 +2792,7 @@ fn trim_function_call(tokens: &[Token]) -> IResult<&[Token], FunctionCall> {
     } else {
         // Check if this is TRIM(FROM string) - equivalent to TRIM(BOTH FROM string)
         if matches!(remaining.first(), Some(Token::From)) {
-            remaining = &remaining[1..]; // consume FROM
+            remaining = remaining.skip(1); // consume FROM
             arguments.push(Expression::Literal(Literal::String("BOTH".to_string()))); // mode
             arguments.push(Expression::Literal(Literal::String(" ".to_string()))); // default trim char
 
@@ -2277,19 +2818,20 @@ fn trim_function_call(tokens: &[Token]) -> IResult<&[Token], FunctionCall> {
             name: "TRIM".to_string(),
             distinct: DistinctQualifier::None,
             arguments,
+            order_by: Vec::new(),
             location: Location::default(),
         },
     ))
 }
 
 /// Parse property access: object.property
-fn property_access(tokens: &[Token]) -> IResult<&[Token], PropertyAccess> {
+fn property_access(tokens: Tokens<'_>) -> IResult<Tokens<'_>, PropertyAccess> {
     map(
         tuple((
             identifier,
             expect_token(Token::Dot),
             identifier,
-            many0(tuple((expect_token(Token::Dot), identifier))),
+            many0(tuple((expect_token(Token::Dot), property_path_segment))),
         )),
         |(object, _, first_property, additional_properties)| {
             // Build the full property path: object.property1.property2...
@@ -2307,11 +2849,11 @@ fn property_access(tokens: &[Token]) -> IResult<&[Token], PropertyAccess> {
 }
 
 /// Parse property access that starts with a PropertyAccess token: PropertyAccess.Dot.Identifier...
-fn property_access_continued(tokens: &[Token]) -> IResult<&[Token], PropertyAccess> {
+fn property_access_continued(tokens: Tokens<'_>) -> IResult<Tokens<'_>, PropertyAccess> {
     map(
         tuple((
             property_access_token,
-            many0(tuple((expect_token(Token::Dot), identifier))),
+            many0(tuple((expect_token(Token::Dot), property_path_segment))),
         )),
         |(base_access, additional_properties)| {
             // Build the full property path by extending the base property access
@@ -2328,8 +2870,19 @@ fn property_access_continued(tokens: &[Token]) -> IResult<&[Token], PropertyAcce
     )(tokens)
 }
 
+/// Parse one step of a nested property path after a dot
+///
+/// The lexer reads `inner.value` in `n.attrs.inner.value` as a single
+/// PropertyAccess token, so such a token continues the path by two steps.
+fn property_path_segment(tokens: Tokens<'_>) -> IResult<Tokens<'_>, String> {
+    match tokens.first() {
+        Some(Token::PropertyAccess(path)) => Ok((tokens.skip(1), path.clone())),
+        _ => identifier(tokens),
+    }
+}
+
 /// Parse variable reference: identifier (bound in MATCH/LET clauses)
-fn variable(tokens: &[Token]) -> IResult<&[Token], Variable> {
+fn variable(tokens: Tokens<'_>) -> IResult<Tokens<'_>, Variable> {
     map(identifier, |name| Variable {
         name,
         location: Location::default(),
@@ -2337,13 +2890,13 @@ fn variable(tokens: &[Token]) -> IResult<&[Token], Variable> {
 }
 
 /// Parse property access token: object.property
-fn property_access_token(tokens: &[Token]) -> IResult<&[Token], PropertyAccess> {
+fn property_access_token(tokens: Tokens<'_>) -> IResult<Tokens<'_>, PropertyAccess> {
     if let Some(Token::PropertyAccess(s)) = tokens.first() {
         // Parse the property access string "object.property"
         let parts: Vec<&str> = s.split('.').collect();
         if parts.len() == 2 {
             Ok((
-                &tokens[1..],
+                tokens.skip(1),
                 PropertyAccess {
                     object: parts[0].to_string(),
                     property: parts[1].to_string(),
@@ -2365,7 +2918,7 @@ fn property_access_token(tokens: &[Token]) -> IResult<&[Token], PropertyAccess>
 }
 
 /// Parse identifier (does NOT accept string literals - use identifier_or_quoted for that)
-fn identifier(tokens: &[Token]) -> IResult<&[Token], String> {
+fn identifier(tokens: Tokens<'_>) -> IResult<Tokens<'_>, String> {
     if let Some(token) = tokens.first() {
         let identifier_str = match token {
             Token::Identifier(s) => Some(s.clone()),
@@ -2401,7 +2954,7 @@ fn identifier(tokens: &[Token]) -> IResult<&[Token], String> {
         };
 
         if let Some(s) = identifier_str {
-            Ok((&tokens[1..], s))
+            Ok((tokens.skip(1), s))
         } else {
             Err(nom::Err::Error(nom::error::Error::new(
                 tokens,
@@ -2417,7 +2970,7 @@ fn identifier(tokens: &[Token]) -> IResult<&[Token], String> {
 }
 
 /// Parse edge direction
-fn edge_direction(tokens: &[Token]) -> IResult<&[Token], EdgeDirection> {
+fn edge_direction(tokens: Tokens<'_>) -> IResult<Tokens<'_>, EdgeDirection> {
     alt((
         value(EdgeDirection::Both, expect_token(Token::ArrowBoth)),
         value(EdgeDirection::Outgoing, expect_token(Token::Arrow)),
@@ -2427,7 +2980,7 @@ fn edge_direction(tokens: &[Token]) -> IResult<&[Token], EdgeDirection> {
 }
 
 /// Parse comparison operator
-fn comparison_operator(tokens: &[Token]) -> IResult<&[Token], Operator> {
+fn comparison_operator(tokens: Tokens<'_>) -> IResult<Tokens<'_>, Operator> {
     alt((
         value(Operator::Equal, expect_token(Token::Equal)),
         value(Operator::NotEqual, expect_token(Token::NotEqual)),
@@ -2451,7 +3004,7 @@ fn comparison_operator(tokens: &[Token]) -> IResult<&[Token], Operator> {
 }
 
 /// Parse quantifier
-fn quantifier(tokens: &[Token]) -> IResult<&[Token], Quantifier> {
+fn quantifier(tokens: Tokens<'_>) -> IResult<Tokens<'_>, Quantifier> {
     alt((
         value(Quantifier::All, expect_token(Token::All)),
         value(Quantifier::Any, expect_token(Token::Any)),
@@ -2460,7 +3013,7 @@ fn quantifier(tokens: &[Token]) -> IResult<&[Token], Quantifier> {
 }
 
 /// Parse literals
-fn literal(tokens: &[Token]) -> IResult<&[Token], Literal> {
+fn literal(tokens: Tokens<'_>) -> IResult<Tokens<'_>, Literal> {
     alt((
         map(string_literal, Literal::String),
         map(integer_literal, Literal::Integer),
@@ -2474,7 +3027,7 @@ fn literal(tokens: &[Token]) -> IResult<&[Token], Literal> {
 /// Parse identifier or quoted identifier (ISO GQL compliant)
 /// Accepts regular identifiers, backtick-delimited identifiers, and keywords as identifiers
 /// Examples: myId, `My-Id`, `Property Name`, type, value
-fn identifier_or_quoted(tokens: &[Token]) -> IResult<&[Token], String> {
+fn identifier_or_quoted(tokens: Tokens<'_>) -> IResult<Tokens<'_>, String> {
     if let Some(token) = tokens.first() {
         let identifier_str = match token {
             Token::Identifier(s) => Some(s.clone()),
@@ -2514,7 +3067,7 @@ fn identifier_or_quoted(tokens: &[Token]) -> IResult<&[Token], String> {
                     nom::error::ErrorKind::Verify,
                 )));
             }
-            Ok((&tokens[1..], s))
+            Ok((tokens.skip(1), s))
         } else {
             Err(nom::Err::Error(nom::error::Error::new(
                 tokens,
@@ -2530,9 +3083,9 @@ fn identifier_or_quoted(tokens: &[Token]) -> IResult<&[Token], String> {
 }
 
 /// Parse string literal
-fn string_literal(tokens: &[Token]) -> IResult<&[Token], String> {
+fn string_literal(tokens: Tokens<'_>) -> IResult<Tokens<'_>, String> {
     if let Some(Token::String(s)) = tokens.first() {
-        Ok((&tokens[1..], s.clone()))
+        Ok((tokens.skip(1), s.clone()))
     } else {
         Err(nom::Err::Error(nom::error::Error::new(
             tokens,
@@ -2542,9 +3095,9 @@ fn string_literal(tokens: &[Token]) -> IResult<&[Token], String> {
 }
 
 /// Parse integer literal
-fn integer_literal(tokens: &[Token]) -> IResult<&[Token], i64> {
+fn integer_literal(tokens: Tokens<'_>) -> IResult<Tokens<'_>, i64> {
     if let Some(Token::Integer(n)) = tokens.first() {
-        Ok((&tokens[1..], *n))
+        Ok((tokens.skip(1), *n))
     } else {
         Err(nom::Err::Error(nom::error::Error::new(
             tokens,
@@ -2554,9 +3107,9 @@ fn integer_literal(tokens: &[Token]) -> IResult<&[Token], i64> {
 }
 
 /// Parse float literal
-fn float_literal(tokens: &[Token]) -> IResult<&[Token], f64> {
+fn float_literal(tokens: Tokens<'_>) -> IResult<Tokens<'_>, f64> {
     if let Some(Token::Float(f)) = tokens.first() {
-        Ok((&tokens[1..], *f))
+        Ok((tokens.skip(1), *f))
     } else {
         Err(nom::Err::Error(nom::error::Error::new(
             tokens,
@@ -2566,9 +3119,9 @@ fn float_literal(tokens: &[Token]) -> IResult<&[Token], f64> {
 }
 
 /// Parse boolean literal
-fn boolean_literal(tokens: &[Token]) -> IResult<&[Token], bool> {
+fn boolean_literal(tokens: Tokens<'_>) -> IResult<Tokens<'_>, bool> {
     if let Some(Token::Boolean(b)) = tokens.first() {
-        Ok((&tokens[1..], *b))
+        Ok((tokens.skip(1), *b))
     } else {
         Err(nom::Err::Error(nom::error::Error::new(
             tokens,
@@ -2578,14 +3131,14 @@ fn boolean_literal(tokens: &[Token]) -> IResult<&[Token], bool> {
 }
 
 /// Parse null literal
-fn null_literal(tokens: &[Token]) -> IResult<&[Token], ()> {
+fn null_literal(tokens: Tokens<'_>) -> IResult<Tokens<'_>, ()> {
     value((), expect_token(Token::Null))(tokens)
 }
 
 /// Parse vector literal
-fn vector_literal(tokens: &[Token]) -> IResult<&[Token], Vec<f64>> {
+fn vector_literal(tokens: Tokens<'_>) -> IResult<Tokens<'_>, Vec<f64>> {
     if let Some(Token::Vector(v)) = tokens.first() {
-        Ok((&tokens[1..], v.clone()))
+        Ok((tokens.skip(1), v.clone()))
     } else {
         Err(nom::Err::Error(nom::error::Error::new(
             tokens,
@@ -2595,7 +3148,7 @@ fn vector_literal(tokens: &[Token]) -> IResult<&[Token], Vec<f64>> {
 }
 
 /// Parse catalog statement (DDL operations)
-fn catalog_statement(tokens: &[Token]) -> IResult<&[Token], CatalogStatement> {
+fn catalog_statement(tokens: Tokens<'_>) -> IResult<Tokens<'_>, CatalogStatement> {
     alt((
         map(create_schema_statement, CatalogStatement::CreateSchema),
         map(drop_schema_statement, CatalogStatement::DropSchema),
@@ -2611,6 +3164,7 @@ fn catalog_statement(tokens: &[Token]) -> IResult<&[Token], CatalogStatement> {
         map(drop_graph_statement, CatalogStatement::DropGraph),
         map(truncate_graph_statement, CatalogStatement::TruncateGraph),
         map(clear_graph_statement, CatalogStatement::ClearGraph),
+        map(copy_graph_statement, CatalogStatement::CopyGraph),
         map(create_user_statement, CatalogStatement::CreateUser),
         map(drop_user_statement, CatalogStatement::DropUser),
         map(create_role_statement, CatalogStatement::CreateRole),
@@ -2626,7 +3180,7 @@ fn catalog_statement(tokens: &[Token]) -> IResult<&[Token], CatalogStatement> {
 }
 
 /// Parse and validate schema name (catalog path with validation for schema names)
-fn validated_schema_name(tokens: &[Token]) -> IResult<&[Token], CatalogPath> {
+fn validated_schema_name(tokens: Tokens<'_>) -> IResult<Tokens<'_>, CatalogPath> {
     // First parse as a regular catalog path
     let (remaining, schema_path) = catalog_path(tokens)?;
 
@@ -2643,7 +3197,7 @@ fn validated_schema_name(tokens: &[Token]) -> IResult<&[Token], CatalogPath> {
 }
 
 /// Parse CREATE SCHEMA statement
-fn create_schema_statement(tokens: &[Token]) -> IResult<&[Token], CreateSchemaStatement> {
+fn create_schema_statement(tokens: Tokens<'_>) -> IResult<Tokens<'_>, CreateSchemaStatement> {
     map(
         tuple((
             expect_token(Token::Create),
@@ -2664,7 +3218,7 @@ fn create_schema_statement(tokens: &[Token]) -> IResult<&[Token], CreateSchemaSt
 }
 
 /// Parse DROP SCHEMA statement  
-fn drop_schema_statement(tokens: &[Token]) -> IResult<&[Token], DropSchemaStatement> {
+fn drop_schema_statement(tokens: Tokens<'_>) -> IResult<Tokens<'_>, DropSchemaStatement> {
     map(
         tuple((
             expect_token(Token::Drop),
@@ -2689,7 +3243,7 @@ fn drop_schema_statement(tokens: &[Token]) -> IResult<&[Token], DropSchemaStatem
 }
 
 /// Parse CREATE GRAPH statement
-fn create_graph_statement(tokens: &[Token]) -> IResult<&[Token], CreateGraphStatement> {
+fn create_graph_statement(tokens: Tokens<'_>) -> IResult<Tokens<'_>, CreateGraphStatement> {
     // Check if next token is Type - if so, fail early to let CREATE GRAPH TYPE parse it
     if !tokens.is_empty() {
         // Skip CREATE [OR REPLACE] [PROPERTY] GRAPH tokens
@@ -2727,15 +3281,17 @@ fn create_graph_statement(tokens: &[Token]) -> IResult<&[Token], CreateGraphStat
             ))),
             catalog_path,
             opt(graph_type_spec),
+            opt(preceded(expect_token(Token::With), index_options)),
             opt(tuple((expect_token(Token::As), query))),
         )),
-        |(_, or_replace, _, _, if_not_exists, graph_path, graph_type_spec, as_query)| {
+        |(_, or_replace, _, _, if_not_exists, graph_path, graph_type_spec, options, as_query)| {
             CreateGraphStatement {
                 graph_path,
                 graph_type_spec,
                 if_not_exists: if_not_exists.is_some(),
                 or_replace: or_replace.is_some(),
                 as_query: as_query.map(|(_, query)| Box::new(query)),
+                options,
                 location: Location::default(),
             }
         },
@@ -2743,7 +3299,7 @@ fn create_graph_statement(tokens: &[Token]) -> IResult<&[Token], CreateGraphStat
 }
 
 /// Parse DROP GRAPH statement
-fn drop_graph_statement(tokens: &[Token]) -> IResult<&[Token], DropGraphStatement> {
+fn drop_graph_statement(tokens: Tokens<'_>) -> IResult<Tokens<'_>, DropGraphStatement> {
     // Check if next token is Type - if so, fail early to let DROP GRAPH TYPE parse it
     if !tokens.is_empty() {
         // Skip DROP [PROPERTY] GRAPH tokens
@@ -2784,7 +3340,7 @@ fn drop_graph_statement(tokens: &[Token]) -> IResult<&[Token], DropGraphStatemen
 }
 
 /// Parse TRUNCATE GRAPH statement
-fn truncate_graph_statement(tokens: &[Token]) -> IResult<&[Token], TruncateGraphStatement> {
+fn truncate_graph_statement(tokens: Tokens<'_>) -> IResult<Tokens<'_>, TruncateGraphStatement> {
     map(
         tuple((
             expect_token(Token::Truncate),
@@ -2799,7 +3355,7 @@ fn truncate_graph_statement(tokens: &[Token]) -> IResult<&[Token], TruncateGraph
 }
 
 /// Parse CLEAR GRAPH statement
-fn clear_graph_statement(tokens: &[Token]) -> IResult<&[Token], ClearGraphStatement> {
+fn clear_graph_statement(tokens: Tokens<'_>) -> IResult<Tokens<'_>, ClearGraphStatement> {
     map(
         tuple((
             expect_token(Token::Clear),
@@ -2813,8 +3369,28 @@ fn clear_graph_statement(tokens: &[Token]) -> IResult<&[Token], ClearGraphStatem
     )(tokens)
 }
 
+/// Parse COPY GRAPH statement
+fn copy_graph_statement(tokens: Tokens<'_>) -> IResult<Tokens<'_>, CopyGraphStatement> {
+    map(
+        tuple((
+            expect_token(Token::Copy),
+            expect_token(Token::Graph),
+            catalog_path,
+            expect_token(Token::To),
+            catalog_path,
+        )),
+        |(_, _, source_path, _, destination_path)| CopyGraphStatement {
+            source_path,
+            destination_path,
+            location: Location::default(),
+        },
+    )(tokens)
+}
+
 /// Parse CREATE GRAPH TYPE statement
-fn create_graph_type_statement(tokens: &[Token]) -> IResult<&[Token], CreateGraphTypeStatement> {
+fn create_graph_type_statement(
+    tokens: Tokens<'_>,
+) -> IResult<Tokens<'_>, CreateGraphTypeStatement> {
     map(
         tuple((
             expect_token(Token::Create),
@@ -2852,7 +3428,7 @@ fn create_graph_type_statement(tokens: &[Token]) -> IResult<&[Token], CreateGrap
 }
 
 /// Parse DROP GRAPH TYPE statement
-fn drop_graph_type_statement(tokens: &[Token]) -> IResult<&[Token], DropGraphTypeStatement> {
+fn drop_graph_type_statement(tokens: Tokens<'_>) -> IResult<Tokens<'_>, DropGraphTypeStatement> {
     map(
         tuple((
             expect_token(Token::Drop),
@@ -2879,7 +3455,7 @@ fn drop_graph_type_statement(tokens: &[Token]) -> IResult<&[Token], DropGraphTyp
 }
 
 /// Parse ALTER GRAPH TYPE statement
-fn alter_graph_type_statement(tokens: &[Token]) -> IResult<&[Token], AlterGraphTypeStatement> {
+fn alter_graph_type_statement(tokens: Tokens<'_>) -> IResult<Tokens<'_>, AlterGraphTypeStatement> {
     map(
         tuple((
             expect_token(Token::Alter),
@@ -2896,7 +3472,7 @@ fn alter_graph_type_statement(tokens: &[Token]) -> IResult<&[Token], AlterGraphT
 
 /// Parse catalog path: /segment1/segment2/...
 /// Supports ISO GQL delimited identifiers: /`My-Schema`/`My-Graph`
-fn catalog_path(tokens: &[Token]) -> IResult<&[Token], CatalogPath> {
+fn catalog_path(tokens: Tokens<'_>) -> IResult<Tokens<'_>, CatalogPath> {
     map(
         tuple((
             // Optional leading slash
@@ -2911,7 +3487,7 @@ fn catalog_path(tokens: &[Token]) -> IResult<&[Token], CatalogPath> {
 }
 
 /// Parse graph type specification: (VERTEX TYPE ... EDGE TYPE ...)
-fn graph_type_spec(tokens: &[Token]) -> IResult<&[Token], GraphTypeSpec> {
+fn graph_type_spec(tokens: Tokens<'_>) -> IResult<Tokens<'_>, GraphTypeSpec> {
     map(
         delimited(
             expect_token(Token::LeftParen),
@@ -2927,7 +3503,7 @@ fn graph_type_spec(tokens: &[Token]) -> IResult<&[Token], GraphTypeSpec> {
 }
 
 /// Parse vertex types clause: VERTEX TYPE vertex_type, vertex_type, ...
-fn vertex_types_clause(tokens: &[Token]) -> IResult<&[Token], Vec<VertexTypeSpec>> {
+fn vertex_types_clause(tokens: Tokens<'_>) -> IResult<Tokens<'_>, Vec<VertexTypeSpec>> {
     map(
         tuple((
             alt((expect_token(Token::Vertex), expect_token(Token::Node))),
@@ -2944,7 +3520,7 @@ fn vertex_types_clause(tokens: &[Token]) -> IResult<&[Token], Vec<VertexTypeSpec
 }
 
 /// Parse edge types clause: EDGE TYPE edge_type, edge_type, ...
-fn edge_types_clause(tokens: &[Token]) -> IResult<&[Token], Vec<EdgeTypeSpec>> {
+fn edge_types_clause(tokens: Tokens<'_>) -> IResult<Tokens<'_>, Vec<EdgeTypeSpec>> {
     map(
         tuple((
             expect_token(Token::Edge),
@@ -2961,7 +3537,7 @@ fn edge_types_clause(tokens: &[Token]) -> IResult<&[Token], Vec<EdgeTypeSpec>> {
 }
 
 /// Parse vertex type specification
-fn vertex_type_spec(tokens: &[Token]) -> IResult<&[Token], VertexTypeSpec> {
+fn vertex_type_spec(tokens: Tokens<'_>) -> IResult<Tokens<'_>, VertexTypeSpec> {
     map(
         tuple((
             opt(identifier),
@@ -2981,7 +3557,7 @@ fn vertex_type_spec(tokens: &[Token]) -> IResult<&[Token], VertexTypeSpec> {
 }
 
 /// Parse edge type specification
-fn edge_type_spec(tokens: &[Token]) -> IResult<&[Token], EdgeTypeSpec> {
+fn edge_type_spec(tokens: Tokens<'_>) -> IResult<Tokens<'_>, EdgeTypeSpec> {
     map(
         tuple((
             opt(identifier),
@@ -2992,20 +3568,29 @@ fn edge_type_spec(tokens: &[Token]) -> IResult<&[Token], EdgeTypeSpec> {
             opt(property_type_list),
             opt(tuple((expect_token(Token::Source), identifier))),
             opt(tuple((expect_token(Token::Destination), identifier))),
+            opt(preceded(
+                tuple((expect_token(Token::On), expect_token(Token::Delete))),
+                alt((
+                    value(EdgeDeleteAction::Cascade, expect_token(Token::Cascade)),
+                    value(EdgeDeleteAction::Restrict, expect_token(Token::Restrict)),
+                )),
+            )),
         )),
-        |(identifier, labels, properties, source, destination)| EdgeTypeSpec {
+        |(identifier, labels, properties, source, destination, on_delete)| EdgeTypeSpec {
             identifier,
             labels: labels.map(|(_, expr)| expr),
             properties,
             source_vertex: source.map(|(_, id)| id),
             destination_vertex: destination.map(|(_, id)| id),
+            on_delete,
             location: Location::default(),
         },
     )(tokens)
 }
 
 /// Parse label expression: term1 | term2 | ...
-fn label_expression(tokens: &[Token]) -> IResult<&[Token], LabelExpression> {
+fn label_expression(tokens: Tokens<'_>) -> IResult<Tokens<'_>, LabelExpression> {
+    let _nesting = NestingGuard::enter(tokens)?;
     map(
         tuple((
             label_term,
@@ -3022,12 +3607,14 @@ fn label_expression(tokens: &[Token]) -> IResult<&[Token], LabelExpression> {
     )(tokens)
 }
 
-/// Parse label term: factor1 ! factor2 ! ... or :label1:label2:...
-fn label_term(tokens: &[Token]) -> IResult<&[Token], LabelTerm> {
+/// Parse label term: factor1 & factor2 & ..., factor1 ! factor2 ! ... or :label1:label2:...
+fn label_term(tokens: Tokens<'_>) -> IResult<Tokens<'_>, LabelTerm> {
     map(
         tuple((
             label_factor,
             many0(alt((
+                // Conjunction: Person&Employee
+                tuple((expect_token(Token::Ampersand), label_factor)),
                 // Traditional NOT-separated factors
                 tuple((expect_token(Token::Not), label_factor)),
                 // Consecutive colon-separated labels (for syntax like :Manager:TeamLead or :`Special-Label`)
@@ -3050,7 +3637,7 @@ fn label_term(tokens: &[Token]) -> IResult<&[Token], LabelTerm> {
 
 /// Parse label factor: identifier | :identifier | % | (label_expression)
 /// Supports ISO GQL delimited identifiers: `My-Label`, :`Special-Label`
-fn label_factor(tokens: &[Token]) -> IResult<&[Token], LabelFactor> {
+fn label_factor(tokens: Tokens<'_>) -> IResult<Tokens<'_>, LabelFactor> {
     alt((
         // Colon-prefixed identifier: :LABEL_NAME or :`Special-Label`
         map(
@@ -3096,7 +3683,7 @@ fn label_expression_to_strings(label_expr: LabelExpression) -> Vec<String> {
 
 /// Parse property type list: (prop1 type1, prop2 type2, ...)
 /// ISO GQL uses parentheses, not braces, for property lists in graph types
-fn property_type_list(tokens: &[Token]) -> IResult<&[Token], PropertyTypeList> {
+fn property_type_list(tokens: Tokens<'_>) -> IResult<Tokens<'_>, PropertyTypeList> {
     map(
         delimited(
             expect_token(Token::LeftParen),
@@ -3118,7 +3705,7 @@ fn property_type_list(tokens: &[Token]) -> IResult<&[Token], PropertyTypeList> {
 }
 
 /// Parse property type declaration: name type_spec
-fn property_type_decl(tokens: &[Token]) -> IResult<&[Token], PropertyTypeDecl> {
+fn property_type_decl(tokens: Tokens<'_>) -> IResult<Tokens<'_>, PropertyTypeDecl> {
     map(tuple((identifier, type_spec)), |(name, type_spec)| {
         PropertyTypeDecl {
             name,
@@ -3129,7 +3716,7 @@ fn property_type_decl(tokens: &[Token]) -> IResult<&[Token], PropertyTypeDecl> {
 }
 
 /// Parse type specification (simplified for now)
-fn type_spec(tokens: &[Token]) -> IResult<&[Token], TypeSpec> {
+fn type_spec(tokens: Tokens<'_>) -> IResult<Tokens<'_>, TypeSpec> {
     alt((
         value(TypeSpec::Boolean, expect_token(Token::BooleanType)),
         value(TypeSpec::Integer, expect_token(Token::IntegerType)),
@@ -3161,7 +3748,7 @@ fn type_spec(tokens: &[Token]) -> IResult<&[Token], TypeSpec> {
 }
 
 /// Parse session statement: SESSION SET/RESET/CLOSE
-fn session_statement(tokens: &[Token]) -> IResult<&[Token], SessionStatement> {
+fn session_statement(tokens: Tokens<'_>) -> IResult<Tokens<'_>, SessionStatement> {
     alt((
         map(session_set_statement, SessionStatement::Set),
         map(session_reset_statement, SessionStatement::Reset),
@@ -3170,7 +3757,7 @@ fn session_statement(tokens: &[Token]) -> IResult<&[Token], SessionStatement> {
 }
 
 /// Parse SESSION SET statement
-fn session_set_statement(tokens: &[Token]) -> IResult<&[Token], SessionSetStatement> {
+fn session_set_statement(tokens: Tokens<'_>) -> IResult<Tokens<'_>, SessionSetStatement> {
     map(
         tuple((
             expect_token(Token::Session),
@@ -3185,7 +3772,7 @@ fn session_set_statement(tokens: &[Token]) -> IResult<&[Token], SessionSetStatem
 }
 
 /// Parse SESSION SET clauses
-fn session_set_clause(tokens: &[Token]) -> IResult<&[Token], SessionSetClause> {
+fn session_set_clause(tokens: Tokens<'_>) -> IResult<Tokens<'_>, SessionSetClause> {
     alt((
         // SESSION SET SCHEMA schema_reference
         map(
@@ -3210,6 +3797,22 @@ fn session_set_clause(tokens: &[Token]) -> IResult<&[Token], SessionSetClause> {
             )),
             |(_, _, time_zone)| SessionSetClause::TimeZone { time_zone },
         ),
+        // SESSION SET TIMEZONE time_zone_string
+        map(
+            tuple((
+                verify(identifier, |id: &str| id.eq_ignore_ascii_case("TIMEZONE")),
+                string_literal,
+            )),
+            |(_, time_zone)| SessionSetClause::TimeZone { time_zone },
+        ),
+        // SESSION SET COLLATION collation_string
+        map(
+            tuple((
+                verify(identifier, |id: &str| id.eq_ignore_ascii_case("COLLATION")),
+                string_literal,
+            )),
+            |(_, collation)| SessionSetClause::Collation { collation },
+        ),
         // SESSION SET [PROPERTY] GRAPH [IF NOT EXISTS] parameter graph_initializer
         map(
             tuple((
@@ -3274,7 +3877,7 @@ fn session_set_clause(tokens: &[Token]) -> IResult<&[Token], SessionSetClause> {
 }
 
 /// Parse SESSION RESET statement
-fn session_reset_statement(tokens: &[Token]) -> IResult<&[Token], SessionResetStatement> {
+fn session_reset_statement(tokens: Tokens<'_>) -> IResult<Tokens<'_>, SessionResetStatement> {
     map(
         tuple((
             expect_token(Token::Session),
@@ -3289,7 +3892,7 @@ fn session_reset_statement(tokens: &[Token]) -> IResult<&[Token], SessionResetSt
 }
 
 /// Parse SESSION RESET arguments
-fn session_reset_args(tokens: &[Token]) -> IResult<&[Token], SessionResetArgs> {
+fn session_reset_args(tokens: Tokens<'_>) -> IResult<Tokens<'_>, SessionResetArgs> {
     alt((
         // [ALL] (PARAMETERS | CHARACTERISTICS)
         map(
@@ -3334,7 +3937,7 @@ fn session_reset_args(tokens: &[Token]) -> IResult<&[Token], SessionResetArgs> {
 }
 
 /// Parse SESSION CLOSE statement
-fn session_close_statement(tokens: &[Token]) -> IResult<&[Token], SessionCloseStatement> {
+fn session_close_statement(tokens: Tokens<'_>) -> IResult<Tokens<'_>, SessionCloseStatement> {
     map(
         tuple((expect_token(Token::Session), expect_token(Token::Close))),
         |(_, _)| SessionCloseStatement {
@@ -3344,14 +3947,14 @@ fn session_close_statement(tokens: &[Token]) -> IResult<&[Token], SessionCloseSt
 }
 
 /// Parse graph expression for session context
-fn graph_expression(tokens: &[Token]) -> IResult<&[Token], GraphExpression> {
+fn graph_expression(tokens: Tokens<'_>) -> IResult<Tokens<'_>, GraphExpression> {
     log::debug!(
         "graph_expression called with tokens: {:?}",
         tokens.get(0..3).unwrap_or(&[])
     );
 
     // Parse union operations with precedence
-    fn union_expr(tokens: &[Token]) -> IResult<&[Token], GraphExpression> {
+    fn union_expr(tokens: Tokens<'_>) -> IResult<Tokens<'_>, GraphExpression> {
         let (tokens, left) = primary_graph_expr(tokens)?;
 
         // Check for UNION operators
@@ -3375,7 +3978,7 @@ fn graph_expression(tokens: &[Token]) -> IResult<&[Token], GraphExpression> {
     }
 
     // Parse primary graph expressions (non-union)
-    fn primary_graph_expr(tokens: &[Token]) -> IResult<&[Token], GraphExpression> {
+    fn primary_graph_expr(tokens: Tokens<'_>) -> IResult<Tokens<'_>, GraphExpression> {
         alt((
             // Parenthesized expressions
             delimited(
@@ -3399,18 +4002,21 @@ fn graph_expression(tokens: &[Token]) -> IResult<&[Token], GraphExpression> {
 }
 
 /// Parse parameter: $identifier (returns Parameter struct for expressions)
-fn parameter(tokens: &[Token]) -> IResult<&[Token], Parameter> {
-    map(
-        tuple((expect_token(Token::Dollar), identifier)),
-        |(_, name)| Parameter {
-            name,
-            location: Location::default(),
-        },
-    )(tokens)
+fn parameter(tokens: Tokens<'_>) -> IResult<Tokens<'_>, Parameter> {
+    map(parameter_name, |name| Parameter {
+        name,
+        location: Location::default(),
+    })(tokens)
 }
 
 /// Parse parameter name: $identifier (returns String for session contexts)
-fn parameter_name(tokens: &[Token]) -> IResult<&[Token], String> {
+///
+/// The lexer emits `$name` as a single `Token::Variable`; a separated
+/// `Dollar` + identifier pair is accepted as well.
+fn parameter_name(tokens: Tokens<'_>) -> IResult<Tokens<'_>, String> {
+    if let Some(Token::Variable(name)) = tokens.first() {
+        return Ok((tokens.skip(1), name.clone()));
+    }
     map(
         tuple((expect_token(Token::Dollar), identifier)),
         |(_, name)| name,
@@ -3418,7 +4024,7 @@ fn parameter_name(tokens: &[Token]) -> IResult<&[Token], String> {
 }
 
 /// Parse graph initializer: = graph_expression
-fn graph_initializer(tokens: &[Token]) -> IResult<&[Token], GraphExpression> {
+fn graph_initializer(tokens: Tokens<'_>) -> IResult<Tokens<'_>, GraphExpression> {
     map(
         tuple((expect_token(Token::Equal), graph_expression)),
         |(_, graph_expr)| graph_expr,
@@ -3426,14 +4032,14 @@ fn graph_initializer(tokens: &[Token]) -> IResult<&[Token], GraphExpression> {
 }
 
 /// Parse binding table initializer: = query_statement
-fn binding_table_initializer(tokens: &[Token]) -> IResult<&[Token], Box<Query>> {
+fn binding_table_initializer(tokens: Tokens<'_>) -> IResult<Tokens<'_>, Box<Query>> {
     map(tuple((expect_token(Token::Equal), query)), |(_, query)| {
         Box::new(query)
     })(tokens)
 }
 
 /// Parse value initializer: = expression
-fn value_initializer(tokens: &[Token]) -> IResult<&[Token], Expression> {
+fn value_initializer(tokens: Tokens<'_>) -> IResult<Tokens<'_>, Expression> {
     map(
         tuple((expect_token(Token::Equal), expression)),
         |(_, expr)| expr,
@@ -3441,85 +4047,67 @@ fn value_initializer(tokens: &[Token]) -> IResult<&[Token], Expression> {
 }
 
 /// Parse parameter or catalog path (helper function)
-fn parameter_or_catalog_path(tokens: &[Token]) -> IResult<&[Token], CatalogPath> {
+fn parameter_or_catalog_path(tokens: Tokens<'_>) -> IResult<Tokens<'_>, CatalogPath> {
     // For simplicity, just parse as catalog path for now
     catalog_path(tokens)
 }
 
 /// Expect a specific token
-fn expect_token(expected: Token) -> impl Fn(&[Token]) -> IResult<&[Token], Token> {
-    move |tokens: &[Token]| {
+fn expect_token(expected: Token) -> impl Fn(Tokens<'_>) -> IResult<Tokens<'_>, Token> {
+    move |tokens: Tokens<'_>| {
         if let Some(token) = tokens.first() {
             if std::mem::discriminant(token) == std::mem::discriminant(&expected) {
-                Ok((&tokens[1..], token.clone()))
+                Ok((tokens.skip(1), token.clone()))
             } else {
-                Err(nom::Err::Error(nom::error::Error::new(
-                    tokens,
-                    nom::error::ErrorKind::Tag,
-                )))
+                Err(mismatch(tokens))
             }
         } else {
-            Err(nom::Err::Error(nom::error::Error::new(
-                tokens,
-                nom::error::ErrorKind::Tag,
-            )))
+            Err(mismatch(tokens))
         }
     }
 }
 
 // Helper function for tag_token (alias for expect_token for compatibility)
-fn tag_token(expected: Token) -> impl Fn(&[Token]) -> IResult<&[Token], Token> {
+fn tag_token(expected: Token) -> impl Fn(Tokens<'_>) -> IResult<Tokens<'_>, Token> {
     expect_token(expected)
 }
 
 /// Expect a token that matches a predicate
-fn expect_token_variant<F>(predicate: &F) -> impl Fn(&[Token]) -> IResult<&[Token], Token> + '_
+fn expect_token_variant<F>(predicate: &F) -> impl Fn(Tokens<'_>) -> IResult<Tokens<'_>, Token> + '_
 where
     F: Fn(&Token) -> bool,
 {
-    move |tokens: &[Token]| {
+    move |tokens: Tokens<'_>| {
         if let Some(token) = tokens.first() {
             if predicate(token) {
-                Ok((&tokens[1..], token.clone()))
+                Ok((tokens.skip(1), token.clone()))
             } else {
-                Err(nom::Err::Error(nom::error::Error::new(
-                    tokens,
-                    nom::error::ErrorKind::Tag,
-                )))
+                Err(mismatch(tokens))
             }
         } else {
-            Err(nom::Err::Error(nom::error::Error::new(
-                tokens,
-                nom::error::ErrorKind::Tag,
-            )))
+            Err(mismatch(tokens))
         }
     }
 }
 
 /// Helper function to expect a specific identifier (case-insensitive)
-fn expect_identifier(name: &str) -> impl Fn(&[Token]) -> IResult<&[Token], Token> + '_ {
-    move |tokens: &[Token]| {
+fn expect_identifier(name: &str) -> impl Fn(Tokens<'_>) -> IResult<Tokens<'_>, Token> + '_ {
+    move |tokens: Tokens<'_>| {
         if let Some(token) = tokens.first() {
             match token {
                 Token::Identifier(id) if id.eq_ignore_ascii_case(name) => {
-                    Ok((&tokens[1..], token.clone()))
+                    Ok((tokens.skip(1), token.clone()))
                 }
-                _ => Err(nom::Err::Error(nom::error::Error::new(
-                    tokens,
-                    nom::error::ErrorKind::Tag,
-                ))),
+                _ => Err(mismatch(tokens)),
             }
         } else {
-            Err(nom::Err::Error(nom::error::Error::new(
-                tokens,
-                nom::error::ErrorKind::Tag,
-            )))
+            Err(mismatch(tokens))
         }
     }
 }
 
 /// Parse CREATE USER statement: CREATE USER username PASSWORD password [ROLES (role1, role2, ...)]
-fn create_user_statement(tokens: &[Token]) -> IResult<&[Token], CreateUserStatement> {
+fn create_user_statement(tokens: Tokens<'_>) -> IResult<Tokens<'_>, CreateUserStatement> {
     map(
         tuple((
             expect_token(Token::Create),
@@ -3545,7 +4133,7 @@ fn create_user_statement(tokens: &[Token]) -> IResult<&[Token], CreateUserStatem
 }
 
 /// Parse DROP USER statement: DROP USER [IF EXISTS] username
-fn drop_user_statement(tokens: &[Token]) -> IResult<&[Token], DropUserStatement> {
+fn drop_user_statement(tokens: Tokens<'_>) -> IResult<Tokens<'_>, DropUserStatement> {
     map(
         tuple((
             expect_token(Token::Drop),
@@ -3565,7 +4153,7 @@ fn drop_user_statement(tokens: &[Token]) -> IResult<&[Token], DropUserStatement>
 }
 
 /// Parse CREATE ROLE statement: CREATE ROLE role_name [DESCRIPTION 'description']
-fn create_role_statement(tokens: &[Token]) -> IResult<&[Token], CreateRoleStatement> {
+fn create_role_statement(tokens: Tokens<'_>) -> IResult<Tokens<'_>, CreateRoleStatement> {
     map(
         tuple((
             expect_token(Token::Create),
@@ -3584,7 +4172,7 @@ fn create_role_statement(tokens: &[Token]) -> IResult<&[Token], CreateRoleStatem
 }
 
 /// Parse DROP ROLE statement: DROP ROLE [IF EXISTS] role_name
-fn drop_role_statement(tokens: &[Token]) -> IResult<&[Token], DropRoleStatement> {
+fn drop_role_statement(tokens: Tokens<'_>) -> IResult<Tokens<'_>, DropRoleStatement> {
     map(
         tuple((
             expect_token(Token::Drop),
@@ -3604,7 +4192,7 @@ fn drop_role_statement(tokens: &[Token]) -> IResult<&[Token], DropRoleStatement>
 }
 
 /// Parse GRANT ROLE statement: GRANT ROLE role_name TO username
-fn grant_role_statement(tokens: &[Token]) -> IResult<&[Token], GrantRoleStatement> {
+fn grant_role_statement(tokens: Tokens<'_>) -> IResult<Tokens<'_>, GrantRoleStatement> {
     map(
         tuple((
             expect_token(Token::Grant),
@@ -3622,7 +4210,7 @@ fn grant_role_statement(tokens: &[Token]) -> IResult<&[Token], GrantRoleStatemen
 }
 
 /// Parse REVOKE ROLE statement: REVOKE ROLE role_name FROM username
-fn revoke_role_statement(tokens: &[Token]) -> IResult<&[Token], RevokeRoleStatement> {
+fn revoke_role_statement(tokens: Tokens<'_>) -> IResult<Tokens<'_>, RevokeRoleStatement> {
     map(
         tuple((
             expect_token(Token::Revoke),
@@ -3641,7 +4229,7 @@ fn revoke_role_statement(tokens: &[Token]) -> IResult<&[Token], RevokeRoleStatem
 
 /// Parse CREATE PROCEDURE statement
 /// Syntax: CREATE [OR REPLACE] PROCEDURE [IF NOT EXISTS] procedure_name ([params]) procedure_body
-fn create_procedure_statement(tokens: &[Token]) -> IResult<&[Token], CreateProcedureStatement> {
+fn create_procedure_statement(tokens: Tokens<'_>) -> IResult<Tokens<'_>, CreateProcedureStatement> {
     map(
         tuple((
             expect_token(Token::Create),
@@ -3674,7 +4262,7 @@ fn create_procedure_statement(tokens: &[Token]) -> IResult<&[Token], CreateProce
 
 /// Parse DROP PROCEDURE statement
 /// Syntax: DROP PROCEDURE [IF EXISTS] procedure_name
-fn drop_procedure_statement(tokens: &[Token]) -> IResult<&[Token], DropProcedureStatement> {
+fn drop_procedure_statement(tokens: Tokens<'_>) -> IResult<Tokens<'_>, DropProcedureStatement> {
     map(
         tuple((
             expect_token(Token::Drop),
@@ -3694,7 +4282,7 @@ fn drop_procedure_statement(tokens: &[Token]) -> IResult<&[Token], DropProcedure
 }
 
 /// Parse procedure parameters: (param1 type1 [= default1], param2 type2, ...)
-fn procedure_parameters(tokens: &[Token]) -> IResult<&[Token], Vec<ProcedureParameter>> {
+fn procedure_parameters(tokens: Tokens<'_>) -> IResult<Tokens<'_>, Vec<ProcedureParameter>> {
     delimited(
         expect_token(Token::LeftParen),
         opt(map(
@@ -3717,7 +4305,7 @@ fn procedure_parameters(tokens: &[Token]) -> IResult<&[Token], Vec<ProcedurePara
 }
 
 /// Parse single procedure parameter: name type_spec [= default_value]
-fn procedure_parameter(tokens: &[Token]) -> IResult<&[Token], ProcedureParameter> {
+fn procedure_parameter(tokens: Tokens<'_>) -> IResult<Tokens<'_>, ProcedureParameter> {
     map(
         tuple((
             identifier_or_quoted,                                  // parameter name
@@ -3733,11 +4321,10 @@ fn procedure_parameter(tokens: &[Token]) -> IResult<&[Token], ProcedureParameter
     )(tokens)
 }
 
-/// Parse IS predicate: expression IS [NOT] predicate_type [target]
-fn is_predicate(tokens: &[Token]) -> IResult<&[Token], Expression> {
+/// Parse the rest of an IS predicate, starting at IS: IS [NOT] predicate_type [target]
+fn is_predicate<'a>(subject: &Expression, tokens: Tokens<'a>) -> IResult<Tokens<'a>, Expression> {
     map(
         tuple((
-            additive_expression,
             expect_token(Token::Is),
             opt(expect_token(Token::Not)),
             alt((
@@ -3798,9 +4385,9 @@ fn is_predicate(tokens: &[Token]) -> IResult<&[Token], Expression> {
                 }),
             )),
         )),
-        |(subject, _, not_token, (predicate_type, target, type_spec))| {
+        |(_, not_token, (predicate_type, target, type_spec))| {
             Expression::IsPredicate(IsPredicateExpression {
-                subject: Box::new(subject),
+                subject: Box::new(subject.clone()),
                 predicate_type,
                 negated: not_token.is_some(),
                 target,
@@ -3812,7 +4399,7 @@ fn is_predicate(tokens: &[Token]) -> IResult<&[Token], Expression> {
 }
 
 /// Parse CASE expression: CASE [expr] WHEN ... THEN ... [ELSE ...] END
-fn case_expression(tokens: &[Token]) -> IResult<&[Token], CaseExpression> {
+fn case_expression(tokens: Tokens<'_>) -> IResult<Tokens<'_>, CaseExpression> {
     alt((
         map(simple_case_expression, |simple| CaseExpression {
             case_type: CaseType::Simple(simple),
@@ -3826,7 +4413,7 @@ fn case_expression(tokens: &[Token]) -> IResult<&[Token], CaseExpression> {
 }
 
 /// Parse CAST expression: CAST(expr AS type-spec)
-fn cast_expression(tokens: &[Token]) -> IResult<&[Token], CastExpression> {
+fn cast_expression(tokens: Tokens<'_>) -> IResult<Tokens<'_>, CastExpression> {
     map(
         tuple((
             expect_token(Token::Cast),
@@ -3845,7 +4432,7 @@ fn cast_expression(tokens: &[Token]) -> IResult<&[Token], CastExpression> {
 }
 
 /// Parse simple CASE: CASE expr WHEN value1 [, value2] THEN result1 ... [ELSE default] END
-fn simple_case_expression(tokens: &[Token]) -> IResult<&[Token], SimpleCaseExpression> {
+fn simple_case_expression(tokens: Tokens<'_>) -> IResult<Tokens<'_>, SimpleCaseExpression> {
     map(
         tuple((
             expect_token(Token::Case),
@@ -3863,7 +4450,7 @@ fn simple_case_expression(tokens: &[Token]) -> IResult<&[Token], SimpleCaseExpre
 }
 
 /// Parse searched CASE: CASE WHEN condition1 THEN result1 ... [ELSE default] END
-fn searched_case_expression(tokens: &[Token]) -> IResult<&[Token], SearchedCaseExpression> {
+fn searched_case_expression(tokens: Tokens<'_>) -> IResult<Tokens<'_>, SearchedCaseExpression> {
     map(
         tuple((
             expect_token(Token::Case),
@@ -3879,7 +4466,7 @@ fn searched_case_expression(tokens: &[Token]) -> IResult<&[Token], SearchedCaseE
 }
 
 /// Parse WHEN branch for simple CASE: WHEN value1 [, value2, ...] THEN result
-fn simple_when_branch(tokens: &[Token]) -> IResult<&[Token], SimpleWhenBranch> {
+fn simple_when_branch(tokens: Tokens<'_>) -> IResult<Tokens<'_>, SimpleWhenBranch> {
     map(
         tuple((
             expect_token(Token::When),
@@ -3896,7 +4483,7 @@ fn simple_when_branch(tokens: &[Token]) -> IResult<&[Token], SimpleWhenBranch> {
 }
 
 /// Parse WHEN branch for searched CASE: WHEN condition THEN result
-fn searched_when_branch(tokens: &[Token]) -> IResult<&[Token], SearchedWhenBranch> {
+fn searched_when_branch(tokens: Tokens<'_>) -> IResult<Tokens<'_>, SearchedWhenBranch> {
     map(
         tuple((
             expect_token(Token::When),
@@ -3913,7 +4500,7 @@ fn searched_when_branch(tokens: &[Token]) -> IResult<&[Token], SearchedWhenBranc
 }
 
 /// Parse data modification statements (INSERT, MATCH INSERT, SET, REMOVE, DELETE)
-fn data_statement(tokens: &[Token]) -> IResult<&[Token], DataStatement> {
+fn data_statement(tokens: Tokens<'_>) -> IResult<Tokens<'_>, DataStatement> {
     log::debug!(
         "data_statement called with tokens: {:?}",
         tokens.get(0..3).unwrap_or(&[])
@@ -3937,7 +4524,7 @@ fn data_statement(tokens: &[Token]) -> IResult<&[Token], DataStatement> {
 }
 
 /// Parse MATCH INSERT statement: MATCH pattern... [WITH ...] [WHERE condition] INSERT graph_pattern [, graph_pattern]*
-fn match_insert_statement(tokens: &[Token]) -> IResult<&[Token], MatchInsertStatement> {
+fn match_insert_statement(tokens: Tokens<'_>) -> IResult<Tokens<'_>, MatchInsertStatement> {
     log::debug!(
         "match_insert_statement called with tokens: {:?}",
         tokens.get(0..5).unwrap_or(&[])
@@ -3950,11 +4537,11 @@ fn match_insert_statement(tokens: &[Token]) -> IResult<&[Token], MatchInsertStat
             expect_token(Token::Insert),
             separated_list1(expect_token(Token::Comma), graph_pattern),
         )),
-        |(match_clause, with_clause_opt, where_clause_opt, _, insert_graph_patterns)| {
+        |(mut match_clause, with_clause_opt, where_clause_opt, _, insert_graph_patterns)| {
             MatchInsertStatement {
-                match_clause,
                 with_clause: with_clause_opt,
-                where_clause: where_clause_opt,
+                where_clause: hoist_inline_predicates(&mut match_clause, where_clause_opt),
+                match_clause,
                 insert_graph_patterns,
                 location: Location::default(),
             }
@@ -3969,8 +4556,8 @@ fn match_insert_statement(tokens: &[Token]) -> IResult<&[Token], MatchInsertStat
     result
 }
 
-/// Parse MATCH SET statement: MATCH pattern... [WITH ...] [WHERE condition] SET item, item
-fn match_set_statement(tokens: &[Token]) -> IResult<&[Token], MatchSetStatement> {
+/// Parse MATCH SET statement: MATCH pattern... [WITH ...] [WHERE condition] SET item, item [REMOVE item, item]
+fn match_set_statement(tokens: Tokens<'_>) -> IResult<Tokens<'_>, MatchSetStatement> {
     log::debug!(
         "PARSER: match_set_statement called with first 5 tokens: {:?}",
         tokens.get(0..5).unwrap_or(&[])
@@ -3982,17 +4569,22 @@ fn match_set_statement(tokens: &[Token]) -> IResult<&[Token], MatchSetStatement>
             opt(where_clause),
             expect_token(Token::Set),
             separated_list1(expect_token(Token::Comma), set_item),
+            opt(preceded(
+                expect_token(Token::Remove),
+                separated_list1(expect_token(Token::Comma), remove_item),
+            )),
         )),
-        |(match_clause, with_clause_opt, where_clause_opt, _, items)| {
+        |(mut match_clause, with_clause_opt, where_clause_opt, _, items, remove_items)| {
             log::debug!(
                 "PARSER: Successfully parsed MatchSetStatement with WITH clause: {}",
                 with_clause_opt.is_some()
             );
             MatchSetStatement {
-                match_clause,
                 with_clause: with_clause_opt,
-                where_clause: where_clause_opt,
+                where_clause: hoist_inline_predicates(&mut match_clause, where_clause_opt),
+                match_clause,
                 items,
+                remove_items: remove_items.unwrap_or_default(),
                 location: Location::default(),
             }
         },
@@ -4010,7 +4602,7 @@ fn match_set_statement(tokens: &[Token]) -> IResult<&[Token], MatchSetStatement>
 }
 
 /// Parse MATCH REMOVE statement: MATCH pattern... [WITH ...] [WHERE condition] REMOVE item, item
-fn match_remove_statement(tokens: &[Token]) -> IResult<&[Token], MatchRemoveStatement> {
+fn match_remove_statement(tokens: Tokens<'_>) -> IResult<Tokens<'_>, MatchRemoveStatement> {
     map(
         tuple((
             match_clause,
@@ -4019,18 +4611,18 @@ fn match_remove_statement(tokens: &[Token]) -> IResult<&[Token], MatchRemoveStat
             expect_token(Token::Remove),
             separated_list1(expect_token(Token::Comma), remove_item),
         )),
-        |(match_clause, with_clause_opt, where_clause_opt, _, items)| MatchRemoveStatement {
-            match_clause,
+        |(mut match_clause, with_clause_opt, where_clause_opt, _, items)| MatchRemoveStatement {
             with_clause: with_clause_opt,
-            where_clause: where_clause_opt,
+            where_clause: hoist_inline_predicates(&mut match_clause, where_clause_opt),
+            match_clause,
             items,
             location: Location::default(),
         },
     )(tokens)
 }
 
-/// Parse MATCH DELETE statement: MATCH pattern... [WITH ...] [WHERE condition] [DETACH] DELETE expression, expression
-fn match_delete_statement(tokens: &[Token]) -> IResult<&[Token], MatchDeleteStatement> {
+/// Parse MATCH DELETE statement: MATCH pattern... [WITH ...] [WHERE condition] [DETACH] DELETE expression, expression [RETURN ...]
+fn match_delete_statement(tokens: Tokens<'_>) -> IResult<Tokens<'_>, MatchDeleteStatement> {
     map(
         tuple((
             match_clause,
@@ -4042,12 +4634,21 @@ fn match_delete_statement(tokens: &[Token]) -> IResult<&[Token], MatchDeleteStat
             ))),
             expect_token(Token::Delete),
             separated_list1(expect_token(Token::Comma), expression),
+            opt(return_clause),
         )),
-        |(match_clause, with_clause_opt, where_clause_opt, detach_mode, _, expressions)| {
+        |(
+            mut match_clause,
+            with_clause_opt,
+            where_clause_opt,
+            detach_mode,
+            _,
+            expressions,
+            return_clause_opt,
+        )| {
             MatchDeleteStatement {
-                match_clause,
                 with_clause: with_clause_opt,
-                where_clause: where_clause_opt,
+                where_clause: hoist_inline_predicates(&mut match_clause, where_clause_opt),
+                match_clause,
                 expressions,
                 detach: match detach_mode {
                     Some(Token::Detach) => true,
@@ -4055,28 +4656,105 @@ fn match_delete_statement(tokens: &[Token]) -> IResult<&[Token], MatchDeleteStat
                     None => false,
                     _ => false, // Default to false for any other token
                 },
+                return_clause: return_clause_opt,
                 location: Location::default(),
             }
         },
     )(tokens)
 }
 
+/// Move inline `WHERE` predicates out of MATCH patterns and AND them into the
+/// statement's WHERE clause. Write statements match patterns themselves rather
+/// than through the planner, so they only see the trailing condition.
+fn hoist_inline_predicates(
+    match_clause: &mut MatchClause,
+    where_clause: Option<WhereClause>,
+) -> Option<WhereClause> {
+    let mut conditions = Vec::new();
+    for pattern in &mut match_clause.patterns {
+        for element in &mut pattern.elements {
+            let predicate = match element {
+                PatternElement::Node(node) => node.predicate.take(),
+                PatternElement::Edge(edge) => edge.predicate.take(),
+            };
+            conditions.extend(predicate);
+        }
+    }
+    conditions.extend(where_clause.map(|clause| clause.condition));
+
+    conditions
+        .into_iter()
+        .reduce(|left, right| {
+            Expression::Binary(BinaryExpression {
+                left: Box::new(left),
+                operator: Operator::And,
+                right: Box::new(right),
+                location: Location::default(),
+            })
+        })
+        .map(|condition| WhereClause {
+            condition,
+            location: Location::default(),
+        })
+}
+
 /// Parse INSERT statement: INSERT graph_pattern
-fn insert_statement(tokens: &[Token]) -> IResult<&[Token], InsertStatement> {
+fn insert_statement(tokens: Tokens<'_>) -> IResult<Tokens<'_>, InsertStatement> {
     map(
         tuple((
             alt((expect_token(Token::Insert), expect_token(Token::Create))),
             separated_list1(expect_token(Token::Comma), graph_pattern),
+            opt(on_conflict_clause),
         )),
-        |(_, graph_patterns)| InsertStatement {
+        |(_, graph_patterns, on_conflict)| InsertStatement {
             graph_patterns,
+            on_conflict,
+            location: Location::default(),
+        },
+    )(tokens)
+}
+
+/// Parse ON CONFLICT clause: ON CONFLICT DO NOTHING | ON CONFLICT DO UPDATE SET key = value, ...
+fn on_conflict_clause(tokens: Tokens<'_>) -> IResult<Tokens<'_>, ConflictAction> {
+    // CONFLICT, DO, NOTHING and UPDATE are not reserved, so they lex as identifiers
+    let keyword =
+        |word: &'static str| verify(identifier, move |id: &str| id.eq_ignore_ascii_case(word));
+    preceded(
+        tuple((expect_token(Token::On), keyword("CONFLICT"), keyword("DO"))),
+        alt((
+            value(ConflictAction::DoNothing, keyword("NOTHING")),
+            map(
+                preceded(
+                    pair(keyword("UPDATE"), expect_token(Token::Set)),
+                    separated_list1(expect_token(Token::Comma), conflict_assignment),
+                ),
+                ConflictAction::DoUpdate,
+            ),
+        )),
+    )(tokens)
+}
+
+/// Parse an ON CONFLICT DO UPDATE assignment: [variable.]key = value
+fn conflict_assignment(tokens: Tokens<'_>) -> IResult<Tokens<'_>, Property> {
+    map(
+        tuple((
+            alt((
+                map(property_access_token, |access| access.property),
+                identifier,
+            )),
+            expect_token(Token::Equal),
+            expression,
+        )),
+        |(key, _, value)| Property {
+            key,
+            value,
             location: Location::default(),
         },
     )(tokens)
 }
 
 /// Parse graph pattern - a single node or path pattern for INSERT
-fn graph_pattern(tokens: &[Token]) -> IResult<&[Token], PathPattern> {
+fn graph_pattern(tokens: Tokens<'_>) -> IResult<Tokens<'_>, PathPattern> {
     alt((
         // Try full path pattern first (node-edge-node sequences)
         path_pattern,
@@ -4084,6 +4762,7 @@ fn graph_pattern(tokens: &[Token]) -> IResult<&[Token], PathPattern> {
         map(node_pattern, |node| PathPattern {
             assignment: None,
             path_type: None,
+            graph: None,
             elements: vec![PatternElement::Node(node)],
             location: Location::default(),
         }),
@@ -4091,7 +4770,7 @@ fn graph_pattern(tokens: &[Token]) -> IResult<&[Token], PathPattern> {
 }
 
 /// Parse SET statement: SET set_item [, set_item]*
-fn set_statement(tokens: &[Token]) -> IResult<&[Token], SetStatement> {
+fn set_statement(tokens: Tokens<'_>) -> IResult<Tokens<'_>, SetStatement> {
     log::debug!(
         "set_statement called with tokens: {:?}",
         tokens.get(0..10).unwrap_or(&[])
@@ -4115,8 +4794,8 @@ fn set_statement(tokens: &[Token]) -> IResult<&[Token], SetStatement> {
     result
 }
 
-/// Parse SET item: property = value | variable = value | variable:label
-fn set_item(tokens: &[Token]) -> IResult<&[Token], SetItem> {
+/// Parse SET item: property = value | variable = value | variable += value | variable:label
+fn set_item(tokens: Tokens<'_>) -> IResult<Tokens<'_>, SetItem> {
     alt((
         // Property assignment: object.property = value
         map(
@@ -4141,11 +4820,16 @@ fn set_item(tokens: &[Token]) -> IResult<&[Token], SetItem> {
             tuple((identifier, expect_token(Token::Equal), expression)),
             |(variable, _, value)| SetItem::Variable { variable, value },
         ),
+        // Property merge: variable += value
+        map(
+            tuple((identifier, expect_token(Token::PlusEqual), expression)),
+            |(variable, _, value)| SetItem::MergeProperties { variable, value },
+        ),
     ))(tokens)
 }
 
 /// Parse REMOVE statement: REMOVE remove_item [, remove_item]*
-fn remove_statement(tokens: &[Token]) -> IResult<&[Token], RemoveStatement> {
+fn remove_statement(tokens: Tokens<'_>) -> IResult<Tokens<'_>, RemoveStatement> {
     log::debug!(
         "remove_statement called with tokens: {:?}",
         tokens.get(0..10).unwrap_or(&[])
@@ -4167,7 +4851,7 @@ fn remove_statement(tokens: &[Token]) -> IResult<&[Token], RemoveStatement> {
 }
 
 /// Parse REMOVE item: property | variable:label | variable
-fn remove_item(tokens: &[Token]) -> IResult<&[Token], RemoveItem> {
+fn remove_item(tokens: Tokens<'_>) -> IResult<Tokens<'_>, RemoveItem> {
     alt((
         // Property removal: object.property
         map(property_access_token, RemoveItem::Property),
@@ -4186,7 +4870,7 @@ fn remove_item(tokens: &[Token]) -> IResult<&[Token], RemoveItem> {
 }
 
 /// Parse DELETE statement: [DETACH | NODETACH] DELETE expression [, expression]*
-fn delete_statement(tokens: &[Token]) -> IResult<&[Token], DeleteStatement> {
+fn delete_statement(tokens: Tokens<'_>) -> IResult<Tokens<'_>, DeleteStatement> {
     map(
         tuple((
             opt(alt((
@@ -4210,7 +4894,7 @@ fn delete_statement(tokens: &[Token]) -> IResult<&[Token], DeleteStatement> {
 }
 
 /// Parse EXISTS subquery: EXISTS(subquery)
-fn exists_subquery(tokens: &[Token]) -> IResult<&[Token], ExistsSubqueryExpression> {
+fn exists_subquery(tokens: Tokens<'_>) -> IResult<Tokens<'_>, ExistsSubqueryExpression> {
     map(
         tuple((
             expect_token(Token::Exists),
@@ -4226,7 +4910,7 @@ fn exists_subquery(tokens: &[Token]) -> IResult<&[Token], ExistsSubqueryExpressi
 }
 
 /// Parse NOT EXISTS subquery: NOT EXISTS(subquery)
-fn not_exists_subquery(tokens: &[Token]) -> IResult<&[Token], NotExistsSubqueryExpression> {
+fn not_exists_subquery(tokens: Tokens<'_>) -> IResult<Tokens<'_>, NotExistsSubqueryExpression> {
     map(
         tuple((
             expect_token(Token::Not),
@@ -4243,7 +4927,7 @@ fn not_exists_subquery(tokens: &[Token]) -> IResult<&[Token], NotExistsSubqueryE
 }
 
 /// Parse general subquery: (subquery)
-fn subquery_expression(tokens: &[Token]) -> IResult<&[Token], SubqueryExpression> {
+fn subquery_expression(tokens: Tokens<'_>) -> IResult<Tokens<'_>, SubqueryExpression> {
     map(
         tuple((
             expect_token(Token::LeftParen),
@@ -4258,7 +4942,7 @@ fn subquery_expression(tokens: &[Token]) -> IResult<&[Token], SubqueryExpression
 }
 
 /// Parse DECLARE statement: DECLARE variable type [= initial_value] [, variable type [= initial_value]]*
-fn declare_statement(tokens: &[Token]) -> IResult<&[Token], DeclareStatement> {
+fn declare_statement(tokens: Tokens<'_>) -> IResult<Tokens<'_>, DeclareStatement> {
     map(
         tuple((
             expect_token(Token::Declare),
@@ -4272,7 +4956,7 @@ fn declare_statement(tokens: &[Token]) -> IResult<&[Token], DeclareStatement> {
 }
 
 /// Parse variable declaration: variable_name type_spec [= initial_value]
-fn variable_declaration(tokens: &[Token]) -> IResult<&[Token], VariableDeclaration> {
+fn variable_declaration(tokens: Tokens<'_>) -> IResult<Tokens<'_>, VariableDeclaration> {
     map(
         tuple((
             identifier,
@@ -4293,7 +4977,7 @@ fn variable_declaration(tokens: &[Token]) -> IResult<&[Token], VariableDeclarati
 
 /// Parse procedure body: [variable_definition+] statement (NEXT [yield_clause] statement)*
 /// This handles the specific case where MATCH clauses don't require RETURN when followed by NEXT
-fn procedure_body_statement(tokens: &[Token]) -> IResult<&[Token], ProcedureBodyStatement> {
+fn procedure_body_statement(tokens: Tokens<'_>) -> IResult<Tokens<'_>, ProcedureBodyStatement> {
     // A procedure body must have either:
     // 1. Variable definitions at the start, OR
     // 2. At least one NEXT statement (for chaining)
@@ -4378,8 +5062,8 @@ fn procedure_body_statement(tokens: &[Token]) -> IResult<&[Token], ProcedureBody
 
 /// Parse variable declaration for procedure body: [<type-spec>] <identifier> ["=" <expression>]
 fn variable_declaration_for_procedure_body(
-    tokens: &[Token],
-) -> IResult<&[Token], VariableDeclaration> {
+    tokens: Tokens<'_>,
+) -> IResult<Tokens<'_>, VariableDeclaration> {
     map(
         tuple((
             // Optional type specification
@@ -4430,7 +5114,7 @@ fn variable_declaration_for_procedure_body(
 /// - `procedure_body_statement()` - Will use this when enhanced
 /// - ROADMAP.md - Full AT/NEXT implementation roadmap
 #[allow(dead_code)] // Reserved for ISO GQL AT/NEXT full implementation (ROADMAP.md v0.3.0)
-fn match_clause_for_procedure_body(tokens: &[Token]) -> IResult<&[Token], MatchClause> {
+fn match_clause_for_procedure_body(tokens: Tokens<'_>) -> IResult<Tokens<'_>, MatchClause> {
     // Find where WHERE starts or NEXT if no WHERE
     let stop_pos = tokens
         .iter()
@@ -4440,7 +5124,7 @@ fn match_clause_for_procedure_body(tokens: &[Token]) -> IResult<&[Token], MatchC
         })?;
 
     // Parse just the MATCH part
-    let match_tokens = &tokens[..stop_pos];
+    let match_tokens = tokens.take(stop_pos);
 
     // Parse the MATCH clause
     let (remaining, match_clause) = match_clause(match_tokens)?;
@@ -4454,7 +5138,7 @@ fn match_clause_for_procedure_body(tokens: &[Token]) -> IResult<&[Token], MatchC
     }
 
     // Return the remaining tokens starting from WHERE/NEXT
-    Ok((&tokens[stop_pos..], match_clause))
+    Ok((tokens.skip(stop_pos), match_clause))
 }
 
 /// Parse optional WHERE clause for procedure body (stopping at NEXT)
@@ -4494,7 +5178,7 @@ fn match_clause_for_procedure_body(tokens: &[Token]) -> IResult<&[Token], MatchC
 /// - `procedure_body_statement()` - Will use this when enhanced
 /// - ROADMAP.md - Full AT/NEXT implementation roadmap
 #[allow(dead_code)] // Reserved for ISO GQL AT/NEXT full implementation (ROADMAP.md v0.3.0)
-fn where_clause_for_procedure_body(tokens: &[Token]) -> IResult<&[Token], Option<WhereClause>> {
+fn where_clause_for_procedure_body(tokens: Tokens<'_>) -> IResult<Tokens<'_>, Option<WhereClause>> {
     // Check if we start with WHERE
     if tokens.is_empty() || !matches!(tokens[0], Token::Where) {
         return Ok((tokens, None));
@@ -4509,7 +5193,7 @@ fn where_clause_for_procedure_body(tokens: &[Token]) -> IResult<&[Token], Option
         })?;
 
     // Parse WHERE clause tokens
-    let where_tokens = &tokens[..next_pos];
+    let where_tokens = tokens.take(next_pos);
 
     // Parse the WHERE clause
     let (remaining, where_clause) = where_clause(where_tokens)?;
@@ -4523,11 +5207,11 @@ fn where_clause_for_procedure_body(tokens: &[Token]) -> IResult<&[Token], Option
     }
 
     // Return the remaining tokens starting from NEXT
-    Ok((&tokens[next_pos..], Some(where_clause)))
+    Ok((tokens.skip(next_pos), Some(where_clause)))
 }
 
 /// Parse AT location statement: AT location_path statements*
-fn at_location_statement(tokens: &[Token]) -> IResult<&[Token], AtLocationStatement> {
+fn at_location_statement(tokens: Tokens<'_>) -> IResult<Tokens<'_>, AtLocationStatement> {
     map(
         tuple((
             expect_token(Token::At),
@@ -4557,7 +5241,7 @@ fn at_location_statement(tokens: &[Token]) -> IResult<&[Token], AtLocationStatem
 }
 
 /// Parse transaction statement
-fn transaction_statement(tokens: &[Token]) -> IResult<&[Token], TransactionStatement> {
+fn transaction_statement(tokens: Tokens<'_>) -> IResult<Tokens<'_>, TransactionStatement> {
     alt((
         map(
             start_transaction_statement,
@@ -4573,7 +5257,9 @@ fn transaction_statement(tokens: &[Token]) -> IResult<&[Token], TransactionState
 }
 
 /// Parse START TRANSACTION [characteristics]
-fn start_transaction_statement(tokens: &[Token]) -> IResult<&[Token], StartTransactionStatement> {
+fn start_transaction_statement(
+    tokens: Tokens<'_>,
+) -> IResult<Tokens<'_>, StartTransactionStatement> {
     alt((
         // START TRANSACTION
         map(
@@ -4599,7 +5285,7 @@ fn start_transaction_statement(tokens: &[Token]) -> IResult<&[Token], StartTrans
 }
 
 /// Parse COMMIT [WORK]
-fn commit_statement(tokens: &[Token]) -> IResult<&[Token], CommitStatement> {
+fn commit_statement(tokens: Tokens<'_>) -> IResult<Tokens<'_>, CommitStatement> {
     map(
         tuple((expect_token(Token::Commit), opt(expect_token(Token::Work)))),
         |(_, work)| CommitStatement {
@@ -4610,7 +5296,7 @@ fn commit_statement(tokens: &[Token]) -> IResult<&[Token], CommitStatement> {
 }
 
 /// Parse ROLLBACK [WORK]
-fn rollback_statement(tokens: &[Token]) -> IResult<&[Token], RollbackStatement> {
+fn rollback_statement(tokens: Tokens<'_>) -> IResult<Tokens<'_>, RollbackStatement> {
     map(
         tuple((
             expect_token(Token::Rollback),
@@ -4625,8 +5311,8 @@ fn rollback_statement(tokens: &[Token]) -> IResult<&[Token], RollbackStatement>
 
 /// Parse SET TRANSACTION characteristics
 fn set_transaction_characteristics_statement(
-    tokens: &[Token],
-) -> IResult<&[Token], SetTransactionCharacteristicsStatement> {
+    tokens: Tokens<'_>,
+) -> IResult<Tokens<'_>, SetTransactionCharacteristicsStatement> {
     map(
         tuple((
             expect_token(Token::Set),
@@ -4641,7 +5327,9 @@ fn set_transaction_characteristics_statement(
 }
 
 /// Parse transaction characteristics
-fn transaction_characteristics(tokens: &[Token]) -> IResult<&[Token], TransactionCharacteristics> {
+fn transaction_characteristics(
+    tokens: Tokens<'_>,
+) -> IResult<Tokens<'_>, TransactionCharacteristics> {
     map(
         tuple((opt(isolation_level_clause), opt(access_mode_clause))),
         |(isolation_level, access_mode)| TransactionCharacteristics {
@@ -4653,7 +5341,7 @@ fn transaction_characteristics(tokens: &[Token]) -> IResult<&[Token], Transactio
 }
 
 /// Parse isolation level: ISOLATION LEVEL (READ UNCOMMITTED | READ COMMITTED | REPEATABLE READ | SERIALIZABLE)
-fn isolation_level_clause(tokens: &[Token]) -> IResult<&[Token], IsolationLevel> {
+fn isolation_level_clause(tokens: Tokens<'_>) -> IResult<Tokens<'_>, IsolationLevel> {
     map(
         tuple((
             expect_token(Token::Isolation),
@@ -4681,7 +5369,7 @@ fn isolation_level_clause(tokens: &[Token]) -> IResult<&[Token], IsolationLevel>
 }
 
 /// Parse access mode: READ ONLY | READ WRITE
-fn access_mode_clause(tokens: &[Token]) -> IResult<&[Token], AccessMode> {
+fn access_mode_clause(tokens: Tokens<'_>) -> IResult<Tokens<'_>, AccessMode> {
     alt((
         map(
             tuple((expect_token(Token::Read), expect_token(Token::Only))),
@@ -4695,7 +5383,7 @@ fn access_mode_clause(tokens: &[Token]) -> IResult<&[Token], AccessMode> {
 }
 
 /// Parse pattern expression for WHERE clauses: (node)-[edge]->(node)
-fn pattern_expression(tokens: &[Token]) -> IResult<&[Token], PatternExpression> {
+fn pattern_expression(tokens: Tokens<'_>) -> IResult<Tokens<'_>, PatternExpression> {
     // Look ahead to see if this starts with a node pattern (open paren followed by identifier or label)
     // This helps distinguish patterns from regular parenthesized expressions
     if let Some(&Token::LeftParen) = tokens.first() {
@@ -4760,20 +5448,26 @@ fn pattern_expression(tokens: &[Token]) -> IResult<&[Token], PatternExpression>
 // INDEX DDL STATEMENT PARSERS
 // =============================================================================
 
-/// Parse index statement (CREATE INDEX, DROP INDEX, ALTER INDEX, OPTIMIZE INDEX, REINDEX)
-fn index_statement(tokens: &[Token]) -> IResult<&[Token], IndexStatement> {
+/// Parse index statement (CREATE INDEX, DROP INDEX, ALTER INDEX, OPTIMIZE INDEX, REINDEX,
+/// CREATE CONSTRAINT, DROP CONSTRAINT)
+fn index_statement(tokens: Tokens<'_>) -> IResult<Tokens<'_>, IndexStatement> {
     alt((
         map(create_index_statement, IndexStatement::Create),
         map(drop_index_statement, IndexStatement::Drop),
         map(alter_index_statement, IndexStatement::Alter),
         map(optimize_index_statement, IndexStatement::Optimize),
         map(reindex_statement, IndexStatement::Reindex),
+        map(
+            create_constraint_statement,
+            IndexStatement::CreateConstraint,
+        ),
+        map(drop_constraint_statement, IndexStatement::DropConstraint),
     ))(tokens)
 }
 
 /// Parse index name - lenient parser that accepts various token types for better error messages
 /// This allows invalid names to be parsed so we can provide meaningful validation errors
-fn parse_index_name(tokens: &[Token]) -> IResult<&[Token], String> {
+fn parse_index_name(tokens: Tokens<'_>) -> IResult<Tokens<'_>, String> {
     if let Some(token) = tokens.first() {
         let name = match token {
             // Valid identifier tokens
@@ -4802,7 +5496,7 @@ fn parse_index_name(tokens: &[Token]) -> IResult<&[Token], String> {
         };
 
         if let Some(mut name_str) = name {
-            let mut remaining = &tokens[1..];
+            let mut remaining = tokens.skip(1);
 
             // Check if next token is a minus sign followed by identifier (e.g., "invalid-name")
             // Consume the pattern to give better error message later
@@ -4810,7 +5504,7 @@ fn parse_index_name(tokens: &[Token]) -> IResult<&[Token], String> {
                 if let Some(Token::Identifier(suffix)) = remaining.get(1) {
                     name_str.push('-');
                     name_str.push_str(suffix);
-                    remaining = &remaining[2..];
+                    remaining = remaining.skip(2);
                 } else {
                     break;
                 }
@@ -4822,7 +5516,7 @@ fn parse_index_name(tokens: &[Token]) -> IResult<&[Token], String> {
                 while let Some(Token::Identifier(suffix)) = remaining.first() {
                     if suffix.starts_with('_') {
                         name_str.push_str(suffix);
-                        remaining = &remaining[1..];
+                        remaining = remaining.skip(1);
                     } else {
                         break;
                     }
@@ -4840,7 +5534,7 @@ fn parse_index_name(tokens: &[Token]) -> IResult<&[Token], String> {
 }
 
 /// Parse CREATE GRAPH INDEX [IF NOT EXISTS] statement
-fn create_index_statement(tokens: &[Token]) -> IResult<&[Token], CreateIndexStatement> {
+fn create_index_statement(tokens: Tokens<'_>) -> IResult<Tokens<'_>, CreateIndexStatement> {
     let (tokens, _) = expect_token(Token::Create)(tokens)?;
 
     // Parse index type specifier
@@ -4916,7 +5610,7 @@ fn create_index_statement(tokens: &[Token]) -> IResult<&[Token], CreateIndexStat
 }
 
 /// Parse DROP INDEX statement
-fn drop_index_statement(tokens: &[Token]) -> IResult<&[Token], DropIndexStatement> {
+fn drop_index_statement(tokens: Tokens<'_>) -> IResult<Tokens<'_>, DropIndexStatement> {
     let (tokens, _) = expect_token(Token::Drop)(tokens)?;
     let (tokens, _) = expect_identifier("INDEX")(tokens)?;
 
@@ -4940,7 +5634,7 @@ fn drop_index_statement(tokens: &[Token]) -> IResult<&[Token], DropIndexStatemen
 }
 
 /// Parse ALTER INDEX statement
-fn alter_index_statement(tokens: &[Token]) -> IResult<&[Token], AlterIndexStatement> {
+fn alter_index_statement(tokens: Tokens<'_>) -> IResult<Tokens<'_>, AlterIndexStatement> {
     let (tokens, _) = expect_token(Token::Alter)(tokens)?;
     let (tokens, _) = expect_identifier("INDEX")(tokens)?;
 
@@ -4978,7 +5672,7 @@ fn alter_index_statement(tokens: &[Token]) -> IResult<&[Token], AlterIndexStatem
 }
 
 /// Parse OPTIMIZE INDEX statement
-fn optimize_index_statement(tokens: &[Token]) -> IResult<&[Token], OptimizeIndexStatement> {
+fn optimize_index_statement(tokens: Tokens<'_>) -> IResult<Tokens<'_>, OptimizeIndexStatement> {
     let (tokens, _) = expect_identifier("OPTIMIZE")(tokens)?;
     let (tokens, _) = expect_identifier("INDEX")(tokens)?;
 
@@ -4996,7 +5690,7 @@ fn optimize_index_statement(tokens: &[Token]) -> IResult<&[Token], OptimizeIndex
 
 /// Parse REINDEX statement
 /// Syntax: REINDEX index_name
-fn reindex_statement(tokens: &[Token]) -> IResult<&[Token], ReindexStatement> {
+fn reindex_statement(tokens: Tokens<'_>) -> IResult<Tokens<'_>, ReindexStatement> {
     let (tokens, _) = expect_identifier("REINDEX")(tokens)?;
 
     // Parse index name
@@ -5011,8 +5705,76 @@ fn reindex_statement(tokens: &[Token]) -> IResult<&[Token], ReindexStatement> {
     ))
 }
 
+/// Parse CREATE CONSTRAINT statement
+/// Syntax: CREATE CONSTRAINT [IF NOT EXISTS] [name] ON Label ASSERT property IS UNIQUE | IS NOT NULL
+fn create_constraint_statement(
+    tokens: Tokens<'_>,
+) -> IResult<Tokens<'_>, CreateConstraintStatement> {
+    let (tokens, _) = expect_token(Token::Create)(tokens)?;
+    let (tokens, _) = expect_identifier("CONSTRAINT")(tokens)?;
+
+    // Parse optional IF NOT EXISTS
+    let (tokens, if_not_exists) = opt(tuple((
+        expect_token(Token::If),
+        expect_token(Token::Not),
+        expect_token(Token::Exists),
+    )))(tokens)?;
+
+    // The name is optional; one is derived from the assertion when omitted
+    let (tokens, name) = opt(parse_index_name)(tokens)?;
+
+    let (tokens, _) = expect_token(Token::On)(tokens)?;
+    let (tokens, label) = identifier(tokens)?;
+
+    let (tokens, _) = expect_identifier("ASSERT")(tokens)?;
+    let (tokens, property) = identifier(tokens)?;
+    let (tokens, _) = expect_token(Token::Is)(tokens)?;
+    let (tokens, assertion) = alt((
+        map(expect_identifier("UNIQUE"), |_| ConstraintAssertion::Unique),
+        map(
+            pair(expect_token(Token::Not), expect_token(Token::Null)),
+            |_| ConstraintAssertion::NotNull,
+        ),
+    ))(tokens)?;
+
+    Ok((
+        tokens,
+        CreateConstraintStatement {
+            name,
+            label,
+            property,
+            assertion,
+            if_not_exists: if_not_exists.is_some(),
+            location: Location::default(),
+        },
+    ))
+}
+
+/// Parse DROP CONSTRAINT statement
+fn drop_constraint_statement(tokens: Tokens<'_>) -> IResult<Tokens<'_>, DropConstraintStatement> {
+    let (tokens, _) = expect_token(Token::Drop)(tokens)?;
+    let (tokens, _) = expect_identifier("CONSTRAINT")(tokens)?;
+
+    // Parse optional IF EXISTS
+    let (tokens, if_exists) = opt(preceded(
+        expect_token(Token::If),
+        expect_token(Token::Exists),
+    ))(tokens)?;
+
+    let (tokens, name) = parse_index_name(tokens)?;
+
+    Ok((
+        tokens,
+        DropConstraintStatement {
+            name,
+            if_exists: if_exists.is_some(),
+            location: Location::default(),
+        },
+    ))
+}
+
 /// Parse graph index type specifier
-fn graph_index_type(tokens: &[Token]) -> IResult<&[Token], GraphIndexTypeSpecifier> {
+fn graph_index_type(tokens: Tokens<'_>) -> IResult<Tokens<'_>, GraphIndexTypeSpecifier> {
     alt((
         map(expect_identifier("ADJACENCY_LIST"), |_| {
             GraphIndexTypeSpecifier::AdjacencyList
@@ -5030,7 +5792,7 @@ fn graph_index_type(tokens: &[Token]) -> IResult<&[Token], GraphIndexTypeSpecifi
 }
 
 /// Parse index options (parameter=value, ...)
-fn index_options(tokens: &[Token]) -> IResult<&[Token], IndexOptions> {
+fn index_options(tokens: Tokens<'_>) -> IResult<Tokens<'_>, IndexOptions> {
     let (tokens, params) = delimited(
         expect_token(Token::LeftParen),
         separated_list1(
@@ -5055,7 +5817,7 @@ fn index_options(tokens: &[Token]) -> IResult<&[Token], IndexOptions> {
 }
 
 /// Parse value for index parameters
-fn parse_value(tokens: &[Token]) -> IResult<&[Token], Value> {
+fn parse_value(tokens: Tokens<'_>) -> IResult<Tokens<'_>, Value> {
     alt((
         map(parse_string_literal, Value::String),
         map(parse_number, Value::Number),
@@ -5067,9 +5829,9 @@ fn parse_value(tokens: &[Token]) -> IResult<&[Token], Value> {
 }
 
 /// Parse string literal
-fn parse_string_literal(tokens: &[Token]) -> IResult<&[Token], String> {
-    if let Some((Token::String(s), rest)) = tokens.split_first() {
-        Ok((rest, s.clone()))
+fn parse_string_literal(tokens: Tokens<'_>) -> IResult<Tokens<'_>, String> {
+    if let Some(Token::String(s)) = tokens.first() {
+        Ok((tokens.skip(1), s.clone()))
     } else {
         Err(nom::Err::Error(nom::error::Error::new(
             tokens,
@@ -5079,11 +5841,11 @@ fn parse_string_literal(tokens: &[Token]) -> IResult<&[Token], String> {
 }
 
 /// Parse number (float)
-fn parse_number(tokens: &[Token]) -> IResult<&[Token], f64> {
-    if let Some((token, rest)) = tokens.split_first() {
+fn parse_number(tokens: Tokens<'_>) -> IResult<Tokens<'_>, f64> {
+    if let Some(token) = tokens.first() {
         match token {
-            Token::Integer(n) => Ok((rest, *n as f64)),
-            Token::Float(f) => Ok((rest, *f)),
+            Token::Integer(n) => Ok((tokens.skip(1), *n as f64)),
+            Token::Float(f) => Ok((tokens.skip(1), *f)),
             _ => Err(nom::Err::Error(nom::error::Error::new(
                 tokens,
                 nom::error::ErrorKind::Tag,
@@ -5098,9 +5860,9 @@ fn parse_number(tokens: &[Token]) -> IResult<&[Token], f64> {
 }
 
 /// Parse integer
-fn parse_integer(tokens: &[Token]) -> IResult<&[Token], i64> {
-    if let Some((Token::Integer(i), rest)) = tokens.split_first() {
-        Ok((rest, *i))
+fn parse_integer(tokens: Tokens<'_>) -> IResult<Tokens<'_>, i64> {
+    if let Some(Token::Integer(i)) = tokens.first() {
+        Ok((tokens.skip(1), *i))
     } else {
         Err(nom::Err::Error(nom::error::Error::new(
             tokens,
@@ -5110,7 +5872,7 @@ fn parse_integer(tokens: &[Token]) -> IResult<&[Token], i64> {
 }
 
 /// Parse table name
-fn parse_table_name(tokens: &[Token]) -> IResult<&[Token], String> {
+fn parse_table_name(tokens: Tokens<'_>) -> IResult<Tokens<'_>, String> {
     identifier(tokens)
 }
 
@@ -5562,3 +6324,131 @@ fn test_parser_with_then_match() {
         result.err()
     );
 }
+
+#[test]
+fn test_parser_abbreviated_edge_patterns() {
+    for (pattern, direction) in [
+        ("-->", EdgeDirection::Outgoing),
+        ("<--", EdgeDirection::Incoming),
+        ("<-->", EdgeDirection::Both),
+        ("--", EdgeDirection::Undirected),
+        ("-[]->", EdgeDirection::Outgoing),
+        ("<-[]-", EdgeDirection::Incoming),
+    ] {
+        let tokens = crate::ast::lexer::tokenize(pattern).unwrap();
+        let (_, edge) = abbreviated_edge_pattern(Tokens::new(&tokens, &[]))
+            .unwrap_or_else(|e| panic!("{} should parse: {:?}", pattern, e));
+        assert_eq!(edge.direction, direction, "{}", pattern);
+        assert!(edge.labels.is_empty() && edge.identifier.is_none());
+    }
+
+    for query in [
+        "MATCH (a:Person)-->(b) RETURN b",
+        "MATCH (a)<--(b)-->(c) RETURN c",
+        "MATCH (a)--(b) RETURN b",
+        "MATCH (a)-[]->(b) RETURN b",
+    ] {
+        let result = parse_query(query);
+        assert!(result.is_ok(), "{} should parse: {:?}", query, result.err());
+    }
+}
+
+#[test]
+fn test_parser_rejects_deeply_nested_input() {
+    let depth = 5000;
+    for query in [
+        format!("RETURN {}1{}", "(".repeat(depth), ")".repeat(depth)),
+        format!("RETURN {}1{}", "[".repeat(depth), "]".repeat(depth)),
+        format!(
+            "MATCH (n:{}Person{}) RETURN n",
+            "(".repeat(depth),
+            ")".repeat(depth)
+        ),
+    ] {
+        let err = parse_query(&query).expect_err("deeply nested query should not parse");
+        assert!(
+            matches!(err, ParserError::NestingTooDeep { max_depth } if max_depth == DEFAULT_MAX_NESTING_DEPTH),
+            "expected NestingTooDeep, got {:?}",
+            err
+        );
+        assert!(err.to_string().contains("expression nesting too deep"));
+    }
+
+    // Shallow nesting is unaffected, and a later parse starts from depth zero
+    assert!(parse_query("RETURN ((1 + 2)) * 3").is_ok());
+
+    // Reaching the depth limit takes thousands of tokens, so long input must lex
+    let items = vec!["1"; 2000].join(", ");
+    assert!(parse_query(&format!("RETURN [{}]", items)).is_ok());
+}
+
+#[test]
+fn test_token_ranges_carry_their_own_locations() {
+    let located = tokenize_with_locations("MATCH (n)\nRETURN n").unwrap();
+    let (tokens, locations) = filter_sql_comments(located);
+    let return_at = tokens
+        .iter()
+        .position(|t| matches!(t, Token::Return))
+        .unwrap();
+
+    // A copy of the stream, or a sub-range of it, still knows where its
+    // tokens came from
+    let (copied, copied_locations) = (tokens.clone(), locations.clone());
+    let range = Tokens::new(&copied, &copied_locations)
+        .take(return_at + 1)
+        .skip(return_at);
+    assert_eq!((range.len(), range.offset), (1, return_at));
+    assert_eq!((range.location().line, range.location().column), (2, 1));
+
+    // Parsing again in the middle of a parse leaves the outer input intact
+    let outer = Tokens::new(&tokens, &locations).skip(return_at);
+    parse_query("MATCH (a)-->(b) RETURN b").unwrap();
+    assert_eq!((outer.location().line, outer.location().column), (2, 1));
+
+    match parse_query("MATCH (n)\nRETURN )") {
+        Err(ParserError::UnexpectedToken { location, .. }) => {
+            assert_eq!((location.line, location.column), (2, 8))
+        }
+        other => panic!("Expected an unexpected-token error, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_nested_parentheses_parse_in_linear_time() {
+    // Every form of comparison used to re-parse its left operand, so each
+    // level of parentheses multiplied the parse time
+    let depth = 12;
+    let (open, close) = ("(".repeat(depth), ")".repeat(depth));
+    let started = std::time::Instant::now();
+    for query in [
+        format!("RETURN {}1{}", open, close),
+        format!(
+            "MATCH (n) WHERE {}n.x BETWEEN 1 AND 2{} RETURN n",
+            open, close
+        ),
+        format!(
+            "MATCH (n) WHERE {}NOT n.x IN [1, 2]{} RETURN n",
+            open, close
+        ),
+        format!("MATCH (n) WHERE {}n.x IS NOT NULL{} RETURN n", open, close),
+    ] {
+        let result = parse_query(&query);
+        assert!(result.is_ok(), "{} should parse: {:?}", query, result.err());
+    }
+    assert!(
+        started.elapsed() < std::time::Duration::from_secs(2),
+        "nested queries took {:?} to parse",
+        started.elapsed()
+    );
+
+    // Each form still applies after the shared left operand
+    for query in [
+        "MATCH (n) WHERE n.x NOT BETWEEN 1 AND 2 RETURN n",
+        "MATCH (n) WHERE n.x > ALL (MATCH (m) RETURN m.x) RETURN n",
+        "MATCH (n) WHERE n.x NOT IN (MATCH (m) RETURN m.x) RETURN n",
+        "MATCH (n) WHERE n:Person AND n.age >= 18 RETURN n",
+    ] {
+        let result = parse_query(query);
+        assert!(result.is_ok(), "{} should parse: {:?}", query, result.err());
+    }
+}
@@ -112,6 +112,22 @@ fn print_statement(statement: &Statement, indent: usize) {
                 crate::ast::IndexStatement::Reindex(reindex) => {
                     debug!("{}REINDEX: {}", get_indent(indent + 1), reindex.name);
                 }
+                crate::ast::IndexStatement::CreateConstraint(create_constraint) => {
+                    debug!(
+                        "{}CREATE CONSTRAINT ON {} ASSERT {} {:?}",
+                        get_indent(indent + 1),
+                        create_constraint.label,
+                        create_constraint.property,
+                        create_constraint.assertion
+                    );
+                }
+                crate::ast::IndexStatement::DropConstraint(drop_constraint) => {
+                    debug!(
+                        "{}DROP CONSTRAINT: {}",
+                        get_indent(indent + 1),
+                        drop_constraint.name
+                    );
+                }
             }
         }
         Statement::Let(let_stmt) => {
@@ -347,6 +363,11 @@ fn print_node(node: &Node, indent: usize) {
         debug!("{}Properties", get_indent(indent));
         print_property_map(properties, indent + 1);
     }
+
+    if let Some(predicate) = &node.predicate {
+        debug!("{}Where", get_indent(indent));
+        print_expression(predicate, indent + 1);
+    }
 }
 
 fn print_edge(edge: &Edge, indent: usize) {
@@ -364,6 +385,11 @@ fn print_edge(edge: &Edge, indent: usize) {
         debug!("{}Properties", get_indent(indent));
         print_property_map(properties, indent + 1);
     }
+
+    if let Some(predicate) = &edge.predicate {
+        debug!("{}Where", get_indent(indent));
+        print_expression(predicate, indent + 1);
+    }
 }
 
 fn print_property_map(prop_map: &PropertyMap, indent: usize) {
@@ -456,6 +482,7 @@ fn print_expression(expr: &Expression, indent: usize) {
                 Literal::TimeWindow(tw) => format!("Literal: TimeWindow({})", tw),
                 Literal::Vector(vec) => format!("Literal: Vector({:?})", vec),
                 Literal::List(list) => format!("Literal: List({:?})", list),
+                Literal::Map(entries) => format!("Literal: Map({:?})", entries),
             };
             debug!("{}{}", get_indent(indent), literal_str);
         }
@@ -519,6 +546,20 @@ fn print_expression(expr: &Expression, indent: usize) {
                 print_expression(element, indent + 3);
             }
         }
+        Expression::ListConstructor(list_constructor) => {
+            debug!("{}ListConstructor", get_indent(indent));
+            for (i, element) in list_constructor.elements.iter().enumerate() {
+                debug!("{}Element {}:", get_indent(indent + 1), i);
+                print_expression(element, indent + 2);
+            }
+        }
+        Expression::MapConstructor(map_constructor) => {
+            debug!("{}MapConstructor", get_indent(indent));
+            for (key, value) in &map_constructor.entries {
+                debug!("{}Key {}:", get_indent(indent + 1), key);
+                print_expression(value, indent + 2);
+            }
+        }
         Expression::Cast(cast_expr) => {
             debug!("{}CastExpression", get_indent(indent));
             debug!("{}Expression:", get_indent(indent + 1));
@@ -596,6 +637,30 @@ fn print_expression(expr: &Expression, indent: usize) {
                 debug!("{}Type Spec: {:?}", get_indent(indent + 1), type_spec);
             }
         }
+        Expression::Window(window) => {
+            debug!(
+                "{}WindowFunction: {}",
+                get_indent(indent),
+                window.function.name
+            );
+            for (i, arg) in window.function.arguments.iter().enumerate() {
+                debug!("{}Arg {}", get_indent(indent + 1), i);
+                print_expression(arg, indent + 2);
+            }
+            for (i, expr) in window.partition_by.iter().enumerate() {
+                debug!("{}Partition By {}", get_indent(indent + 1), i);
+                print_expression(expr, indent + 2);
+            }
+            for (i, item) in window.order_by.iter().enumerate() {
+                debug!(
+                    "{}Order By {} ({:?})",
+                    get_indent(indent + 1),
+                    i,
+                    item.direction
+                );
+                print_expression(&item.expression, indent + 2);
+            }
+        }
         Expression::ArrayIndex(array_index) => {
             debug!("{}ArrayIndexExpression", get_indent(indent));
             debug!("{}Array:", get_indent(indent + 1));
@@ -603,6 +668,19 @@ fn print_expression(expr: &Expression, indent: usize) {
             debug!("{}Index:", get_indent(indent + 1));
             print_expression(&array_index.index, indent + 2);
         }
+        Expression::ArraySlice(array_slice) => {
+            debug!("{}ArraySliceExpression", get_indent(indent));
+            debug!("{}Array:", get_indent(indent + 1));
+            print_expression(&array_slice.array, indent + 2);
+            if let Some(start) = &array_slice.start {
+                debug!("{}Start:", get_indent(indent + 1));
+                print_expression(start, indent + 2);
+            }
+            if let Some(end) = &array_slice.end {
+                debug!("{}End:", get_indent(indent + 1));
+                print_expression(end, indent + 2);
+            }
+        }
         Expression::Parameter(parameter) => {
             debug!("{}Parameter: ${}", get_indent(indent), parameter.name);
         }
@@ -740,7 +818,7 @@ fn print_order_item(order_item: &OrderItem, indent: usize) {
 
 fn print_limit_clause(limit_clause: &LimitClause, indent: usize) {
     debug!("{}Count: {}", get_indent(indent), limit_clause.count);
-    if let Some(offset) = limit_clause.offset {
+    if let Some(offset) = &limit_clause.offset {
         debug!("{}Offset: {}", get_indent(indent), offset);
     }
 }
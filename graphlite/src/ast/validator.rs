@@ -100,15 +100,13 @@ use std::collections::{HashMap, HashSet};
 /// Validation error with context
 #[derive(Debug, Clone)]
 pub struct ValidationError {
-    #[allow(dead_code)] // Used by Debug derive for error display
     pub message: String,
-    #[allow(dead_code)] // Used by Debug derive for error display
+    /// Where in the query text the problem starts, when known
     pub location: Option<Location>,
-    #[allow(dead_code)] // Used by Debug derive for error display
     pub error_type: ValidationErrorType,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum ValidationErrorType {
     Structural,
     Semantic,
@@ -342,6 +340,18 @@ impl ValidationContext {
             },
         );
 
+        // Cypher-style aliases for UPPER/LOWER
+        for name in ["TOUPPER", "TOLOWER"] {
+            self.function_signatures.insert(
+                name.to_string(),
+                FunctionSignature {
+                    argument_types: vec![GqlType::String { max_length: None }],
+                    return_type: GqlType::String { max_length: None },
+                    variadic: false,
+                },
+            );
+        }
+
         self.function_signatures.insert(
             "ROUND".to_string(),
             FunctionSignature {
@@ -515,6 +525,18 @@ impl ValidationContext {
             },
         );
 
+        // DEGREE, IN_DEGREE and OUT_DEGREE - count incident edges, optionally by edge label
+        for name in ["DEGREE", "IN_DEGREE", "OUT_DEGREE"] {
+            self.function_signatures.insert(
+                name.to_string(),
+                FunctionSignature {
+                    argument_types: vec![GqlType::Reference { target_type: None }], // Takes a node reference
+                    return_type: GqlType::BigInt,
+                    variadic: true, // Optional edge label argument
+                },
+            );
+        }
+
         // SIZE function - returns the size/length of collections, vectors, or strings
         self.function_signatures.insert(
             "SIZE".to_string(),
@@ -595,6 +617,51 @@ impl ValidationContext {
             },
         );
 
+        self.function_signatures.insert(
+            "FT_REGEX".to_string(),
+            FunctionSignature {
+                argument_types: vec![
+                    GqlType::String { max_length: None },
+                    GqlType::String { max_length: None },
+                ],
+                return_type: GqlType::Boolean,
+                variadic: false,
+            },
+        );
+
+        self.function_signatures.insert(
+            "SIMILARITY_SCORE".to_string(),
+            FunctionSignature {
+                argument_types: vec![
+                    GqlType::String { max_length: None },
+                    GqlType::String { max_length: None },
+                ],
+                return_type: GqlType::Double,
+                variadic: false,
+            },
+        );
+
+        // Vector functions - vectors are checked at runtime
+        for name in ["COSINE_SIMILARITY", "EUCLIDEAN_DISTANCE", "DOT_PRODUCT"] {
+            self.function_signatures.insert(
+                name.to_string(),
+                FunctionSignature {
+                    argument_types: vec![
+                        GqlType::List {
+                            element_type: Box::new(GqlType::Double),
+                            max_length: None,
+                        },
+                        GqlType::List {
+                            element_type: Box::new(GqlType::Double),
+                            max_length: None,
+                        },
+                    ],
+                    return_type: GqlType::Double,
+                    variadic: false,
+                },
+            );
+        }
+
         // Timezone functions
         self.function_signatures.insert(
             "GET_TIMEZONE_NAME".to_string(),
@@ -766,6 +833,26 @@ pub fn validate_query(
                         });
                     }
                 }
+                crate::ast::IndexStatement::CreateConstraint(create_constraint) => {
+                    // Validate the constrained label and property are not empty
+                    if create_constraint.label.is_empty() || create_constraint.property.is_empty() {
+                        errors.push(ValidationError {
+                            message: "Constraint label and property cannot be empty".to_string(),
+                            location: Some(create_constraint.location.clone()),
+                            error_type: ValidationErrorType::Semantic,
+                        });
+                    }
+                }
+                crate::ast::IndexStatement::DropConstraint(drop_constraint) => {
+                    // Validate constraint name is not empty
+                    if drop_constraint.name.is_empty() {
+                        errors.push(ValidationError {
+                            message: "Constraint name cannot be empty".to_string(),
+                            location: Some(drop_constraint.location.clone()),
+                            error_type: ValidationErrorType::Semantic,
+                        });
+                    }
+                }
             }
         }
         Statement::Let(let_stmt) => {
@@ -779,10 +866,52 @@ pub fn validate_query(
     if errors.is_empty() {
         Ok(())
     } else {
+        // The same expression can be visited by several passes; report each problem once,
+        // in source order
+        let mut seen = HashSet::new();
+        errors.retain(|error| {
+            seen.insert((
+                error.message.clone(),
+                error.location.as_ref().map(|location| location.offset),
+            ))
+        });
+        errors.sort_by_key(|error| {
+            error
+                .location
+                .as_ref()
+                .map_or(usize::MAX, |location| location.offset)
+        });
         Err(errors)
     }
 }
 
+/// Location of an AST node, if the parser recorded one
+fn known_location(location: &Location) -> Option<Location> {
+    if *location == Location::default() {
+        None
+    } else {
+        Some(location.clone())
+    }
+}
+
+/// Location of the first located node within an expression
+fn expression_location(expr: &Expression) -> Option<Location> {
+    match expr {
+        Expression::Variable(variable) => known_location(&variable.location),
+        Expression::FunctionCall(func_call) => known_location(&func_call.location),
+        Expression::Window(window) => known_location(&window.location),
+        Expression::PropertyAccess(prop_access) => known_location(&prop_access.location),
+        Expression::Parameter(parameter) => known_location(&parameter.location),
+        Expression::Binary(binary) => known_location(&binary.location)
+            .or_else(|| expression_location(&binary.left))
+            .or_else(|| expression_location(&binary.right)),
+        Expression::Unary(unary) => {
+            known_location(&unary.location).or_else(|| expression_location(&unary.expression))
+        }
+        _ => None,
+    }
+}
+
 /// Validate query structure (required clauses, order)
 fn validate_query_structure(query: &Query, errors: &mut Vec<ValidationError>) {
     match query {
@@ -838,6 +967,111 @@ fn validate_basic_query_structure(query: &BasicQuery, errors: &mut Vec<Validatio
             error_type: ValidationErrorType::Structural,
         });
     }
+
+    validate_basic_query_grouping(query, errors);
+}
+
+/// Check GROUP BY / HAVING usage against the RETURN items
+///
+/// Without GROUP BY, aggregates group implicitly by the non-aggregated RETURN
+/// items, so mixing them is only an error when an explicit GROUP BY leaves a
+/// RETURN item out.
+fn validate_basic_query_grouping(query: &BasicQuery, errors: &mut Vec<ValidationError>) {
+    let group_clause = match &query.group_clause {
+        Some(group_clause) => group_clause,
+        None => {
+            if let Some(having_clause) = &query.having_clause {
+                errors.push(ValidationError {
+                    message: "HAVING requires a GROUP BY clause".to_string(),
+                    location: known_location(&having_clause.location),
+                    error_type: ValidationErrorType::Structural,
+                });
+            }
+            return;
+        }
+    };
+
+    for item in &query.return_clause.items {
+        if contains_aggregate(&item.expression) {
+            continue;
+        }
+
+        let grouped = group_clause.expressions.iter().any(|group_expr| {
+            same_column(group_expr, &item.expression)
+                || matches!((group_expr, &item.alias),
+                    (Expression::Variable(variable), Some(alias)) if &variable.name == alias)
+        });
+
+        // Only simple column references can be compared reliably
+        let is_column = matches!(
+            item.expression,
+            Expression::Variable(_) | Expression::PropertyAccess(_)
+        );
+
+        if is_column && !grouped {
+            errors.push(ValidationError {
+                message: format!(
+                    "RETURN item '{}' must appear in GROUP BY or be used in an aggregate function",
+                    column_name(&item.expression)
+                ),
+                location: expression_location(&item.expression)
+                    .or_else(|| known_location(&item.location)),
+                error_type: ValidationErrorType::Semantic,
+            });
+        }
+    }
+}
+
+/// Whether an expression contains an aggregate function call
+fn contains_aggregate(expr: &Expression) -> bool {
+    match expr {
+        Expression::FunctionCall(func_call) => {
+            is_aggregate_function(&func_call.name)
+                || func_call.arguments.iter().any(contains_aggregate)
+        }
+        Expression::Binary(binary) => {
+            contains_aggregate(&binary.left) || contains_aggregate(&binary.right)
+        }
+        Expression::Unary(unary) => contains_aggregate(&unary.expression),
+        _ => false,
+    }
+}
+
+fn is_aggregate_function(name: &str) -> bool {
+    matches!(
+        name.to_uppercase().as_str(),
+        "COUNT"
+            | "SUM"
+            | "AVG"
+            | "MIN"
+            | "MAX"
+            | "COLLECT"
+            | "APPROX_COUNT_DISTINCT"
+            | "FIRST"
+            | "LAST"
+            | "ARRAY_AGG"
+    )
+}
+
+/// Whether two expressions reference the same variable or property
+fn same_column(left: &Expression, right: &Expression) -> bool {
+    match (left, right) {
+        (Expression::Variable(a), Expression::Variable(b)) => a.name == b.name,
+        (Expression::PropertyAccess(a), Expression::PropertyAccess(b)) => {
+            a.object == b.object && a.property == b.property
+        }
+        _ => false,
+    }
+}
+
+fn column_name(expr: &Expression) -> String {
+    match expr {
+        Expression::Variable(variable) => variable.name.clone(),
+        Expression::PropertyAccess(prop_access) => {
+            format!("{}.{}", prop_access.object, prop_access.property)
+        }
+        _ => String::new(),
+    }
 }
 
 fn validate_return_query_structure(query: &ReturnQuery, errors: &mut Vec<ValidationError>) {
@@ -1137,6 +1371,16 @@ fn validate_expression(
         Expression::PathConstructor(path_constructor) => {
             validate_path_constructor(path_constructor, ctx, errors);
         }
+        Expression::ListConstructor(list_constructor) => {
+            for element in &list_constructor.elements {
+                validate_expression(element, ctx, errors);
+            }
+        }
+        Expression::MapConstructor(map_constructor) => {
+            for (_, value) in &map_constructor.entries {
+                validate_expression(value, ctx, errors);
+            }
+        }
         Expression::Cast(cast_expr) => {
             validate_cast_expression(cast_expr, ctx, errors);
         }
@@ -1190,12 +1434,21 @@ fn validate_expression(
                 }
             }
         }
+        Expression::Window(window) => {
+            validate_window_function(window, ctx, errors);
+        }
         Expression::ArrayIndex(array_index) => {
             // Validate array expression
             validate_expression(&array_index.array, ctx, errors);
             // Validate index expression - should be numeric
             validate_expression(&array_index.index, ctx, errors);
         }
+        Expression::ArraySlice(array_slice) => {
+            validate_expression(&array_slice.array, ctx, errors);
+            for bound in [&array_slice.start, &array_slice.end].into_iter().flatten() {
+                validate_expression(bound, ctx, errors);
+            }
+        }
         Expression::Parameter(parameter) => {
             // Parameters are valid and will be resolved at execution time
             // For now, just validate the parameter name is valid
@@ -1420,6 +1673,7 @@ fn infer_expression_type(
                         })
                     }
                 }
+                Literal::Map(_) => Ok(GqlType::Record),
             }
         }
         Expression::Variable(var) => ctx
@@ -1447,6 +1701,18 @@ fn infer_expression_type(
                 .ok_or_else(|| format!("Unknown function: {}", func.name))
         }
         Expression::PathConstructor(_) => Ok(GqlType::Path),
+        Expression::ListConstructor(list_constructor) => {
+            // Use the type of the first element, as for list literals
+            let element_type = match list_constructor.elements.first() {
+                Some(first) => infer_expression_type(first, ctx)?,
+                None => GqlType::String { max_length: None },
+            };
+            Ok(GqlType::List {
+                element_type: Box::new(element_type),
+                max_length: None,
+            })
+        }
+        Expression::MapConstructor(_) => Ok(GqlType::Record),
         Expression::Cast(cast_expr) => Ok(cast_expr.target_type.clone()),
         Expression::Subquery(_) => {
             // Subqueries can return various types - for now default to string
@@ -1531,6 +1797,43 @@ fn infer_expression_type(
     }
 }
 
+/// Validate a window function: ROW_NUMBER(), RANK() or COUNT(...) OVER (...)
+fn validate_window_function(
+    window: &WindowFunction,
+    ctx: &mut ValidationContext,
+    errors: &mut Vec<ValidationError>,
+) {
+    match window.function.name.to_uppercase().as_str() {
+        "ROW_NUMBER" | "RANK" => {
+            if !window.function.arguments.is_empty() {
+                errors.push(ValidationError {
+                    message: format!(
+                        "Window function '{}' takes no arguments",
+                        window.function.name
+                    ),
+                    location: known_location(&window.location),
+                    error_type: ValidationErrorType::Type,
+                });
+            }
+        }
+        "COUNT" => validate_function_call(&window.function, ctx, errors),
+        _ => {
+            errors.push(ValidationError {
+                message: format!("Unsupported window function '{}'", window.function.name),
+                location: known_location(&window.location),
+                error_type: ValidationErrorType::Semantic,
+            });
+        }
+    }
+
+    for expr in &window.partition_by {
+        validate_expression(expr, ctx, errors);
+    }
+    for item in &window.order_by {
+        validate_expression(&item.expression, ctx, errors);
+    }
+}
+
 /// Validate function calls using TypeSpec and TypeValidator
 fn validate_function_call(
     func_call: &FunctionCall,
@@ -1546,7 +1849,7 @@ fn validate_function_call(
         None => {
             errors.push(ValidationError {
                 message: format!("Unknown function '{}'", func_call.name),
-                location: None,
+                location: known_location(&func_call.location),
                 error_type: ValidationErrorType::Semantic,
             });
             return;
@@ -1569,7 +1872,7 @@ fn validate_function_call(
                         func_call.name,
                         func_call.arguments.len()
                     ),
-                    location: None,
+                    location: known_location(&func_call.location),
                     error_type: ValidationErrorType::Type,
                 });
                 return;
@@ -1584,7 +1887,7 @@ fn validate_function_call(
                     signature.argument_types.len(),
                     func_call.arguments.len()
                 ),
-                location: None,
+                location: known_location(&func_call.location),
                 error_type: ValidationErrorType::Type,
             });
         }
@@ -1609,7 +1912,7 @@ fn validate_function_call(
                         "Function 'ROUND' expects 1 or 2 arguments, got {}",
                         func_call.arguments.len()
                     ),
-                    location: None,
+                    location: known_location(&func_call.location),
                     error_type: ValidationErrorType::Type,
                 });
             }
@@ -1641,7 +1944,7 @@ fn validate_function_call(
                         "Function 'TRIM' expects 1 to 3 arguments, got {}",
                         func_call.arguments.len()
                     ),
-                    location: None,
+                    location: known_location(&func_call.location),
                     error_type: ValidationErrorType::Type,
                 });
             }
@@ -1657,7 +1960,7 @@ fn validate_function_call(
                     "Function 'REPLACE' expects 3 arguments, got {}",
                     func_call.arguments.len()
                 ),
-                location: None,
+                location: known_location(&func_call.location),
                 error_type: ValidationErrorType::Type,
             });
         } else {
@@ -1689,7 +1992,7 @@ fn validate_function_call(
                         "Function 'SUBSTRING' expects 2 or 3 arguments, got {}",
                         func_call.arguments.len()
                     ),
-                    location: None,
+                    location: known_location(&func_call.location),
                     error_type: ValidationErrorType::Type,
                 });
             }
@@ -1715,7 +2018,7 @@ fn validate_function_call(
                         "Function 'ROUND' expects 1 or 2 arguments, got {}",
                         func_call.arguments.len()
                     ),
-                    location: None,
+                    location: known_location(&func_call.location),
                     error_type: ValidationErrorType::Type,
                 });
             }
@@ -1747,7 +2050,7 @@ fn validate_function_call(
                         "Function 'TRIM' expects 1 to 3 arguments, got {}",
                         func_call.arguments.len()
                     ),
-                    location: None,
+                    location: known_location(&func_call.location),
                     error_type: ValidationErrorType::Type,
                 });
             }
@@ -1763,7 +2066,7 @@ fn validate_function_call(
                     "Function 'REPLACE' expects 3 arguments, got {}",
                     func_call.arguments.len()
                 ),
-                location: None,
+                location: known_location(&func_call.location),
                 error_type: ValidationErrorType::Type,
             });
         } else {
@@ -1795,7 +2098,7 @@ fn validate_function_call(
                         "Function 'SUBSTRING' expects 2 or 3 arguments, got {}",
                         func_call.arguments.len()
                     ),
-                    location: None,
+                    location: known_location(&func_call.location),
                     error_type: ValidationErrorType::Type,
                 });
             }
@@ -1817,7 +2120,7 @@ fn validate_function_call(
                     signature.argument_types.len(),
                     func_call.arguments.len()
                 ),
-                location: None,
+                location: known_location(&func_call.location),
                 error_type: ValidationErrorType::Type,
             });
         }
@@ -1829,7 +2132,7 @@ fn validate_function_call(
     } else {
         errors.push(ValidationError {
             message: format!("Unknown function '{}'", func_call.name),
-            location: None,
+            location: known_location(&func_call.location),
             error_type: ValidationErrorType::Type,
         });
     }
@@ -1842,7 +2145,7 @@ fn validate_function_call(
             Err(err) => {
                 errors.push(ValidationError {
                     message: format!("Type inference error: {}", err),
-                    location: None,
+                    location: known_location(&func_call.location),
                     error_type: ValidationErrorType::Type,
                 });
                 return;
@@ -1854,11 +2157,23 @@ fn validate_function_call(
     // Skip strict type validation for aggregation functions to allow runtime coercion
     let is_aggregation_function = matches!(
         func_name_upper.as_str(),
-        "SUM" | "AVG" | "MIN" | "MAX" | "COUNT" | "COLLECT"
+        "SUM"
+            | "AVG"
+            | "MIN"
+            | "MAX"
+            | "COUNT"
+            | "COLLECT"
+            | "APPROX_COUNT_DISTINCT"
+            | "FIRST"
+            | "LAST"
+            | "ARRAY_AGG"
     );
 
     // Functions that can handle any type and should skip strict validation
-    let is_flexible_function = matches!(func_name_upper.as_str(), "TYPE" | "SIZE");
+    let is_flexible_function = matches!(
+        func_name_upper.as_str(),
+        "TYPE" | "SIZE" | "LENGTH" | "COSINE_SIMILARITY" | "EUCLIDEAN_DISTANCE" | "DOT_PRODUCT"
+    );
 
     // Skip strict type validation for functions that can handle type coercion at runtime
     if !is_aggregation_function && !is_flexible_function {
@@ -1870,7 +2185,7 @@ fn validate_function_call(
         ) {
             errors.push(ValidationError {
                 message: format!("Function validation error: {}", type_error),
-                location: None,
+                location: known_location(&func_call.location),
                 error_type: ValidationErrorType::Type,
             });
         }
@@ -1890,7 +2205,7 @@ fn validate_property_access(
         if !ctx.has_graph_context {
             errors.push(ValidationError {
                 message: format!("Undefined variable '{}'", prop_access.object),
-                location: None,
+                location: known_location(&prop_access.location),
                 error_type: ValidationErrorType::Semantic,
             });
         }
@@ -1912,13 +2227,18 @@ fn validate_variable_usage(
     ctx: &mut ValidationContext,
     errors: &mut Vec<ValidationError>,
 ) {
+    // `*` stands for all bound variables in RETURN * and COUNT(*)
+    if variable.name == "*" {
+        return;
+    }
+
     if !ctx.declared_variables.contains(&variable.name) {
         // If we have graph context (from session or FROM clause),
         // variables will be resolved at runtime with the graph
         if !ctx.has_graph_context {
             errors.push(ValidationError {
                 message: format!("Undefined variable '{}'", variable.name),
-                location: None,
+                location: known_location(&variable.location),
                 error_type: ValidationErrorType::Semantic,
             });
         }
@@ -1974,7 +2294,24 @@ fn validate_binary_operation(
         | Operator::Slash
         | Operator::Percent
         | Operator::Caret => {
-            // These require numeric types
+            // These require numeric types; `+` is excluded since it also concatenates
+            if !matches!(operator, Operator::Plus) {
+                for operand in [left, right] {
+                    let literal_kind = match operand {
+                        Expression::Literal(Literal::String(_)) => "string",
+                        Expression::Literal(Literal::Boolean(_)) => "boolean",
+                        _ => continue,
+                    };
+                    errors.push(ValidationError {
+                        message: format!(
+                            "Arithmetic operator {:?} requires numeric operands, got a {} literal",
+                            operator, literal_kind
+                        ),
+                        location: expression_location(left).or_else(|| expression_location(right)),
+                        error_type: ValidationErrorType::Type,
+                    });
+                }
+            }
         }
         // Comparison operators
         Operator::Equal
@@ -2064,10 +2401,12 @@ fn validate_datetime_format(dt: &str, errors: &mut Vec<ValidationError>) {
 
 /// Validate duration format
 fn validate_duration_format(dur: &str, errors: &mut Vec<ValidationError>) {
-    // Basic ISO duration format validation
-    if !dur.starts_with('P') {
+    if let Err(e) = crate::storage::DurationValue::parse_iso8601(dur) {
         errors.push(ValidationError {
-            message: "Duration must be in ISO 8601 format (P1Y2M3DT4H5M6S)".to_string(),
+            message: format!(
+                "Duration must be in ISO 8601 format (P1Y2M3DT4H5M6S): {}",
+                e
+            ),
             location: None,
             error_type: ValidationErrorType::Syntax,
         });
@@ -2181,7 +2520,9 @@ fn validate_call_statement(
     errors: &mut Vec<ValidationError>,
 ) {
     // Validate procedure name (should be valid system procedure)
-    if !crate::catalog::system_procedures::is_system_procedure(&call_stmt.procedure_name) {
+    if !crate::catalog::system_procedures::is_system_procedure(&call_stmt.procedure_name)
+        && !crate::exec::graph_procedures::is_graph_procedure(&call_stmt.procedure_name)
+    {
         errors.push(ValidationError {
             message: format!("Unknown system procedure: {}", call_stmt.procedure_name),
             location: Some(call_stmt.location.clone()),
@@ -2282,6 +2623,17 @@ fn extract_variable_references(
                 extract_variable_references(arg, variables);
             }
         }
+        Expression::Window(window) => {
+            for arg in &window.function.arguments {
+                extract_variable_references(arg, variables);
+            }
+            for expr in &window.partition_by {
+                extract_variable_references(expr, variables);
+            }
+            for item in &window.order_by {
+                extract_variable_references(&item.expression, variables);
+            }
+        }
         Expression::Case(case_expr) => match &case_expr.case_type {
             crate::ast::CaseType::Simple(simple) => {
                 extract_variable_references(&simple.test_expression, variables);
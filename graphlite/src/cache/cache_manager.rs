@@ -225,7 +225,7 @@ impl CacheManager {
         }
 
         let graph_version = *self.graph_version.read().unwrap();
-        let key = create_query_cache_key(query, parameters, graph_version, user_context);
+        let key = create_query_cache_key(query, parameters, graph_version, user_context, None);
 
         if let Some(cache_hit) = self.result_cache.get(&key) {
             self.record_event(CacheEvent::ResultCacheHit {
@@ -235,10 +235,7 @@ impl CacheManager {
                 timestamp: Instant::now(),
             });
 
-            // Get the actual result from the cache
-            // Note: This is a simplified version - in practice we'd need to extract the result
-            // from the cache hit or modify the cache to return the result directly
-            None // Placeholder - would return actual cached result
+            Some((cache_hit.result.clone(), cache_hit))
         } else {
             self.record_event(CacheEvent::ResultCacheMiss {
                 key,
@@ -263,7 +260,7 @@ impl CacheManager {
         }
 
         let graph_version = *self.graph_version.read().unwrap();
-        let key = create_query_cache_key(query, parameters, graph_version, user_context);
+        let key = create_query_cache_key(query, parameters, graph_version, user_context, None);
 
         self.result_cache
             .insert(key, result, execution_time, plan_hash);
@@ -357,6 +354,7 @@ impl CacheManager {
                     parameters: vec![], // Subqueries don't have parameters in the same way
                     graph_version: key.graph_version,
                     user_context: None,
+                    graph: None,
                 },
                 level: CacheLevel::L1,
                 saved_time_ms: 0, // Would need to track execution time
@@ -19,6 +19,7 @@ pub struct QueryCacheKey {
     pub parameters: Vec<CacheParameter>,
     pub graph_version: u64,           // For invalidation when graph changes
     pub user_context: Option<String>, // For row-level security
+    pub graph: Option<String>,        // For invalidating the writes to one graph
 }
 
 /// Cached parameter value
@@ -31,6 +32,20 @@ pub enum CacheParameter {
     Null,
 }
 
+impl CacheParameter {
+    /// Convert a scalar parameter value; other values cannot be part of a key
+    pub fn from_value(value: &crate::storage::Value) -> Option<Self> {
+        use crate::storage::Value;
+        match value {
+            Value::String(s) => Some(CacheParameter::String(s.clone())),
+            Value::Number(n) => Some(CacheParameter::Float(n.to_bits())),
+            Value::Boolean(b) => Some(CacheParameter::Boolean(*b)),
+            Value::Null => Some(CacheParameter::Null),
+            _ => None,
+        }
+    }
+}
+
 impl CacheKey for QueryCacheKey {
     fn cache_key(&self) -> String {
         format!(
@@ -51,6 +66,10 @@ impl CacheKey for QueryCacheKey {
             tags.push(format!("user:{}", user));
         }
 
+        if let Some(graph) = &self.graph {
+            tags.push(format!("graph:{}", graph));
+        }
+
         tags
     }
 }
@@ -91,6 +110,7 @@ impl CacheValue for QueryResultEntry {
 #[derive(Debug, Clone)]
 pub struct CacheHit {
     pub key: QueryCacheKey,
+    pub result: QueryResult,
     pub hit_level: CacheLevel,
     pub access_time: Instant,
     pub saved_execution_time: Duration,
@@ -125,6 +145,13 @@ impl<K: Clone + Eq + Hash> LRUTracker<K> {
         }
     }
 
+    fn remove(&mut self, key: &K) {
+        if self.positions.remove(key).is_some() {
+            self.order.retain(|k| k != key);
+            self.update_positions();
+        }
+    }
+
     fn remove_lru(&mut self) -> Option<K> {
         if let Some(key) = self.order.pop_back() {
             self.positions.remove(&key);
@@ -159,6 +186,9 @@ pub struct ResultCache {
     l2_max_memory: usize,
     l2_current_memory: Arc<RwLock<usize>>,
 
+    // Lifetime given to new entries
+    default_ttl: Option<Duration>,
+
     // Cache statistics
     stats: Arc<RwLock<CacheStats>>,
     _eviction_policy: EvictionPolicy,
@@ -215,11 +245,24 @@ impl ResultCache {
             l2_max_memory,
             l2_current_memory: Arc::new(RwLock::new(0)),
 
+            default_ttl: None,
+
             stats: Arc::new(RwLock::new(CacheStats::default())),
             _eviction_policy,
         }
     }
 
+    /// Create a single-level cache holding at most `max_entries` results
+    ///
+    /// Entries expire `ttl` after they are inserted, if given. Used for the
+    /// coordinator's read-through cache of MATCH query results.
+    pub fn with_capacity(max_entries: usize, ttl: Option<Duration>) -> Self {
+        let l1_max_memory = super::CacheConfig::default().l1_config.max_memory_bytes;
+        let mut cache = Self::new(max_entries.max(1), l1_max_memory, 0, 0, EvictionPolicy::Lru);
+        cache.default_ttl = ttl;
+        cache
+    }
+
     /// Get cached result if available
     pub fn get(&self, key: &QueryCacheKey) -> Option<CacheHit> {
         {
@@ -241,6 +284,7 @@ impl ResultCache {
 
                     return Some(CacheHit {
                         key: key.clone(),
+                        result: entry.result.clone(),
                         hit_level: CacheLevel::L1,
                         access_time: Instant::now(),
                         saved_execution_time: entry.execution_time,
@@ -271,6 +315,7 @@ impl ResultCache {
 
                         cache_hit = Some(CacheHit {
                             key: key.clone(),
+                            result: entry.result.clone(),
                             hit_level: CacheLevel::L2,
                             access_time: Instant::now(),
                             saved_execution_time: entry.execution_time,
@@ -295,8 +340,6 @@ impl ResultCache {
                         // Remove expired entry
                         l2_cache.remove(key);
                     }
-                } else {
-                    return None;
                 }
             }
         }
@@ -318,22 +361,31 @@ impl ResultCache {
         execution_time: Duration,
         plan_hash: u64,
     ) {
+        let mut metadata = CacheEntryMetadata::new(0, CacheLevel::L1); // Size calculated in CacheValue impl
+        if let Some(ttl) = self.default_ttl {
+            metadata = metadata.with_ttl(ttl);
+        }
         let entry = QueryResultEntry {
             result,
             execution_time,
             plan_hash,
-            metadata: CacheEntryMetadata::new(0, CacheLevel::L1), // Size calculated in CacheValue impl
+            metadata,
             compression_ratio: None,
         };
 
         let size = entry.size_bytes();
 
+        // Replacing an entry must not count its old size twice
+        self.remove_matching(|cached| cached == &key);
+
         // Try L1 first
         if size <= self.l1_max_memory {
             self.insert_l1(key, entry);
-        } else {
+        } else if self.l2_max_entries > 0 {
             // Fall back to L2
             self.insert_l2(key, entry);
+        } else {
+            return;
         }
 
         let mut stats = self.stats.write().unwrap();
@@ -404,7 +456,7 @@ impl ResultCache {
                     }
 
                     // Demote to L2 if still valid
-                    if evicted_entry.is_valid() {
+                    if self.l2_max_entries > 0 && evicted_entry.is_valid() {
                         self.insert_l2(key_to_evict, evicted_entry);
                     }
 
@@ -515,19 +567,51 @@ impl ResultCache {
         }
     }
 
+    /// Invalidate every entry cached for queries against `graph`
+    ///
+    /// Returns the number of entries removed.
+    pub fn invalidate_graph(&self, graph: &str) -> usize {
+        self.remove_matching(|key| key.graph.as_deref() == Some(graph))
+    }
+
+    /// Remove the entries whose key matches, from both levels
+    fn remove_matching(&self, matches: impl Fn(&QueryCacheKey) -> bool) -> usize {
+        let mut removed = 0;
+        for (cache, lru, current_memory) in [
+            (&self.l1_cache, &self.l1_lru, &self.l1_current_memory),
+            (&self.l2_cache, &self.l2_lru, &self.l2_current_memory),
+        ] {
+            let mut lru = lru.write().unwrap();
+            let mut cache = cache.write().unwrap();
+            let keys: Vec<QueryCacheKey> =
+                cache.keys().filter(|key| matches(key)).cloned().collect();
+            let mut current_memory = current_memory.write().unwrap();
+            for key in keys {
+                if let Some(entry) = cache.remove(&key) {
+                    *current_memory = current_memory.saturating_sub(entry.size_bytes());
+                    removed += 1;
+                }
+                lru.remove(&key);
+            }
+        }
+        removed
+    }
+
     /// Get cache statistics
     pub fn stats(&self) -> CacheStats {
         self.stats.read().unwrap().clone()
     }
 
-    /// Clear all cached results
-    pub fn clear(&self) {
+    /// Clear all cached results, returning how many there were
+    pub fn clear(&self) -> usize {
+        let removed = self.l1_cache.read().unwrap().len() + self.l2_cache.read().unwrap().len();
         self.l1_cache.write().unwrap().clear();
         self.l2_cache.write().unwrap().clear();
         *self.l1_current_memory.write().unwrap() = 0;
         *self.l2_current_memory.write().unwrap() = 0;
         *self.l1_lru.write().unwrap() = LRUTracker::new();
         *self.l2_lru.write().unwrap() = LRUTracker::new();
+        removed
     }
 }
 
@@ -537,6 +621,7 @@ pub fn create_query_cache_key(
     parameters: Vec<CacheParameter>,
     graph_version: u64,
     user_context: Option<String>,
+    graph: Option<String>,
 ) -> QueryCacheKey {
     use std::collections::hash_map::DefaultHasher;
 
@@ -549,5 +634,40 @@ pub fn create_query_cache_key(
         parameters,
         graph_version,
         user_context,
+        graph,
+    }
+}
+
+/// Normalize query text for use as a cache key
+///
+/// Runs of whitespace outside quoted strings and identifiers collapse to a
+/// single space, so queries differing only in layout share an entry.
+pub fn normalize_query_text(query: &str) -> String {
+    let mut normalized = String::with_capacity(query.len());
+    let mut quote: Option<char> = None;
+    let mut pending_space = false;
+
+    for c in query.trim().chars() {
+        match quote {
+            Some(q) => {
+                normalized.push(c);
+                if c == q {
+                    quote = None;
+                }
+            }
+            None if c.is_whitespace() => pending_space = true,
+            None => {
+                if pending_space {
+                    normalized.push(' ');
+                    pending_space = false;
+                }
+                if matches!(c, '\'' | '"' | '`') {
+                    quote = Some(c);
+                }
+                normalized.push(c);
+            }
+        }
     }
+
+    normalized
 }
@@ -106,7 +106,7 @@ impl CacheValue for SubqueryResult {
 }
 
 /// Cache key for subquery results
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct SubqueryCacheKey {
     /// Hash of the subquery AST structure (normalized)
     pub subquery_hash: u64,
@@ -120,156 +120,8 @@ pub struct SubqueryCacheKey {
     pub subquery_type: SubqueryType,
 }
 
-impl PartialEq for SubqueryCacheKey {
-    fn eq(&self, other: &Self) -> bool {
-        self.subquery_hash == other.subquery_hash
-            && self.graph_version == other.graph_version
-            && self.schema_version == other.schema_version
-            && self.subquery_type == other.subquery_type
-            && self.outer_variables.len() == other.outer_variables.len()
-            && self
-                .outer_variables
-                .iter()
-                .zip(&other.outer_variables)
-                .all(|(a, b)| a.0 == b.0 && values_equal(&a.1, &b.1))
-    }
-}
-
-impl Eq for SubqueryCacheKey {}
-
-impl Hash for SubqueryCacheKey {
-    fn hash<H: Hasher>(&self, state: &mut H) {
-        self.subquery_hash.hash(state);
-        self.graph_version.hash(state);
-        self.schema_version.hash(state);
-        self.subquery_type.hash(state);
-
-        for (name, value) in &self.outer_variables {
-            name.hash(state);
-            hash_value(value, state);
-        }
-    }
-}
-
-// Helper function to compare Values (since Value doesn't implement Eq)
-fn values_equal(a: &Value, b: &Value) -> bool {
-    match (a, b) {
-        (Value::String(a), Value::String(b)) => a == b,
-        (Value::Number(a), Value::Number(b)) => (a - b).abs() < f64::EPSILON,
-        (Value::Boolean(a), Value::Boolean(b)) => a == b,
-        (Value::Null, Value::Null) => true,
-        (Value::DateTime(a), Value::DateTime(b)) => a == b,
-        (Value::DateTimeWithFixedOffset(a), Value::DateTimeWithFixedOffset(b)) => a == b,
-        (Value::DateTimeWithNamedTz(tz_a, dt_a), Value::DateTimeWithNamedTz(tz_b, dt_b)) => {
-            tz_a == tz_b && dt_a == dt_b
-        }
-        (Value::TimeWindow(a), Value::TimeWindow(b)) => a == b,
-        (Value::Array(a), Value::Array(b)) => {
-            a.len() == b.len() && a.iter().zip(b).all(|(x, y)| values_equal(x, y))
-        }
-        (Value::Vector(a), Value::Vector(b)) => a == b,
-        (Value::Path(a), Value::Path(b)) => a == b,
-        _ => false,
-    }
-}
-
-// Helper function to hash Values (since Value doesn't implement Hash)
-fn hash_value<H: Hasher>(value: &Value, state: &mut H) {
-    match value {
-        Value::String(s) => {
-            0u8.hash(state);
-            s.hash(state);
-        }
-        Value::Number(n) => {
-            1u8.hash(state);
-            n.to_bits().hash(state);
-        }
-        Value::Boolean(b) => {
-            2u8.hash(state);
-            b.hash(state);
-        }
-        Value::Null => {
-            3u8.hash(state);
-        }
-        Value::DateTime(dt) => {
-            4u8.hash(state);
-            dt.timestamp().hash(state);
-            dt.timestamp_subsec_nanos().hash(state);
-        }
-        Value::DateTimeWithFixedOffset(dt) => {
-            5u8.hash(state);
-            dt.timestamp().hash(state);
-            dt.timestamp_subsec_nanos().hash(state);
-            dt.offset().local_minus_utc().hash(state);
-        }
-        Value::DateTimeWithNamedTz(tz, dt) => {
-            6u8.hash(state);
-            tz.hash(state);
-            dt.timestamp().hash(state);
-            dt.timestamp_subsec_nanos().hash(state);
-        }
-        Value::TimeWindow(tw) => {
-            7u8.hash(state);
-            tw.hash(state);
-        }
-        Value::Array(arr) => {
-            8u8.hash(state);
-            arr.len().hash(state);
-            for item in arr {
-                hash_value(item, state);
-            }
-        }
-        Value::Vector(vec) => {
-            9u8.hash(state);
-            vec.len().hash(state);
-            for &val in vec {
-                val.to_bits().hash(state);
-            }
-        }
-        Value::Path(path) => {
-            10u8.hash(state);
-            // PathValue doesn't implement Hash, so hash its string representation
-            format!("{:?}", path).hash(state);
-        }
-        Value::List(list) => {
-            11u8.hash(state);
-            list.len().hash(state);
-            for item in list {
-                hash_value(item, state);
-            }
-        }
-        Value::Node(node) => {
-            12u8.hash(state);
-            node.id.hash(state);
-            node.labels.hash(state);
-            node.properties.len().hash(state);
-            for (key, value) in &node.properties {
-                key.hash(state);
-                hash_value(value, state);
-            }
-        }
-        Value::Edge(edge) => {
-            13u8.hash(state);
-            edge.id.hash(state);
-            edge.from_node.hash(state);
-            edge.to_node.hash(state);
-            edge.label.hash(state);
-            edge.properties.len().hash(state);
-            for (key, value) in &edge.properties {
-                key.hash(state);
-                hash_value(value, state);
-            }
-        }
-        Value::Temporal(temporal) => {
-            14u8.hash(state);
-            // Hash the temporal value - we'll hash its debug representation for now
-            format!("{:?}", temporal).hash(state);
-        }
-    }
-}
-
 /// Types of subquery operations
-#[derive(Debug, Clone, PartialEq, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum SubqueryType {
     Exists,
     NotExists,
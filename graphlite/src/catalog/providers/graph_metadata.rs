@@ -136,6 +136,41 @@ impl Graph {
     pub fn get_property(&self, key: &str) -> Option<&GraphProperty> {
         self.properties.get(key)
     }
+
+    /// Node expiry rule set with `CREATE GRAPH ... WITH (ttl_label = .., ttl_property = ..)`
+    pub fn ttl_policy(&self) -> Option<TtlPolicy> {
+        match (
+            self.properties.get(TTL_LABEL_PROPERTY),
+            self.properties.get(TTL_PROPERTY_PROPERTY),
+        ) {
+            (Some(GraphProperty::String(label)), Some(GraphProperty::String(property))) => {
+                Some(TtlPolicy {
+                    label: label.clone(),
+                    property: property.clone(),
+                })
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Graph property holding the label whose nodes expire
+pub const TTL_LABEL_PROPERTY: &str = "ttl_label";
+
+/// Graph property holding the node property with the expiry timestamp
+pub const TTL_PROPERTY_PROPERTY: &str = "ttl_property";
+
+/// Per-label node expiry rule of a graph
+///
+/// Nodes carrying `label` whose `property` holds a timestamp at or before
+/// the current time are expired: queries no longer see them, and
+/// [`QueryCoordinator::purge_expired_nodes`] deletes them.
+///
+/// [`QueryCoordinator::purge_expired_nodes`]: crate::QueryCoordinator::purge_expired_nodes
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TtlPolicy {
+    pub label: String,
+    pub property: String,
 }
 
 /// Graph-level properties  
@@ -723,6 +758,18 @@ impl CatalogProvider for GraphMetadataCatalog {
             } => match entity_type {
                 EntityType::Graph => {
                     let graph = Graph::from_params(name.clone(), &params);
+                    let if_not_exists = params
+                        .get("if_not_exists")
+                        .and_then(|v| v.as_bool())
+                        .unwrap_or(false);
+                    let qualified_key =
+                        Self::qualified_graph_key(&graph.id.schema_name, &graph.id.name);
+                    if if_not_exists && self.graphs.contains_key(&qualified_key) {
+                        // Leave the existing graph (and its storage) untouched
+                        return Ok(CatalogResponse::Error {
+                            message: "Already exists".to_string(),
+                        });
+                    }
                     self.add_graph(graph)?;
                     Ok(CatalogResponse::Success {
                         data: Some(json!({ "message": format!("Graph '{}' created", name) })),
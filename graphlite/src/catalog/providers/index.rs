@@ -1,51 +1,223 @@
 // Copyright (c) 2024-2025 DeepGraph Inc.
 // SPDX-License-Identifier: Apache-2.0
 //
-//! Index Catalog Provider - Stub implementation
+//! Index catalog provider implementation
+//!
+//! Records the indexes created with CREATE INDEX so that they survive a
+//! restart. When the catalog is loaded, each index is registered again with
+//! the storage's index manager.
 
 use crate::catalog::error::{CatalogError, CatalogResult};
-use crate::catalog::operations::{CatalogOperation, CatalogResponse};
+use crate::catalog::operations::{CatalogOperation, CatalogResponse, EntityType, QueryType};
 use crate::catalog::traits::{CatalogProvider, CatalogSchema};
+use crate::storage::indexes::PropertyIndexTarget;
 use crate::storage::StorageManager;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::collections::HashMap;
 use std::sync::Arc;
 
-pub struct IndexCatalog {}
+/// Index definition as recorded by CREATE INDEX
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IndexDefinition {
+    pub name: String,
+    pub graph_path: String,
+    pub label: String,
+    pub properties: Vec<String>,
+}
+
+impl IndexDefinition {
+    /// Create from parameters
+    pub fn from_params(name: String, params: &Value) -> CatalogResult<Self> {
+        let text = |key: &str| {
+            params
+                .get(key)
+                .and_then(|v| v.as_str())
+                .map(|s| s.to_string())
+                .ok_or_else(|| CatalogError::InvalidParameters(format!("Index {} required", key)))
+        };
+        let properties = params
+            .get("properties")
+            .and_then(|v| v.as_array())
+            .map(|items| {
+                items
+                    .iter()
+                    .filter_map(|item| item.as_str().map(|s| s.to_string()))
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        Ok(Self {
+            name,
+            graph_path: text("graph_name")?,
+            label: text("label")?,
+            properties,
+        })
+    }
+
+    /// What the index covers, for an index on a single property
+    pub fn target(&self) -> Option<PropertyIndexTarget> {
+        self.properties.first().map(|property| PropertyIndexTarget {
+            graph_path: self.graph_path.clone(),
+            label: self.label.clone(),
+            property: property.clone(),
+        })
+    }
+}
+
+/// Persistent state of the index catalog
+#[derive(Debug, Serialize, Deserialize)]
+struct IndexCatalogState {
+    indexes: HashMap<String, IndexDefinition>,
+}
+
+pub struct IndexCatalog {
+    indexes: HashMap<String, IndexDefinition>,
+}
 
 impl IndexCatalog {
     pub fn new() -> Box<Self> {
-        Box::new(Self {})
+        Box::new(Self {
+            indexes: HashMap::new(),
+        })
+    }
+
+    fn list(&self) -> CatalogResult<CatalogResponse> {
+        let indexes: Vec<_> = self.indexes.values().collect();
+        let results = serde_json::to_value(indexes)?;
+        Ok(CatalogResponse::Query { results })
     }
 }
 
 impl CatalogProvider for IndexCatalog {
-    fn init(&mut self, _storage: Arc<StorageManager>) -> CatalogResult<()> {
+    fn init(&mut self, storage: Arc<StorageManager>) -> CatalogResult<()> {
+        match storage.load_catalog_provider("index") {
+            Ok(Some(data)) => match self.load(&data) {
+                Ok(_) => {
+                    log::info!(
+                        "Index catalog loaded from storage with {} indexes",
+                        self.indexes.len()
+                    );
+                }
+                Err(e) => {
+                    log::warn!(
+                        "Failed to deserialize index catalog from storage: {}. Using defaults.",
+                        e
+                    );
+                }
+            },
+            Ok(None) => {
+                log::debug!("No persisted index catalog found. Using default initialization.");
+            }
+            Err(e) => {
+                log::warn!(
+                    "Error loading index catalog: {}. Using default initialization.",
+                    e
+                );
+            }
+        }
+
+        // The index manager starts empty, so hand it the recorded indexes
+        if let Some(index_manager) = storage.get_index_manager() {
+            for definition in self.indexes.values() {
+                if let Some(target) = definition.target() {
+                    index_manager.restore_index(definition.name.clone(), target);
+                }
+            }
+        }
         Ok(())
     }
 
-    fn execute(&mut self, _op: CatalogOperation) -> CatalogResult<CatalogResponse> {
-        Err(CatalogError::NotSupported(
-            "Index catalog not yet implemented".to_string(),
-        ))
+    fn execute(&mut self, op: CatalogOperation) -> CatalogResult<CatalogResponse> {
+        match op {
+            CatalogOperation::Create {
+                entity_type: EntityType::Index,
+                name,
+                params,
+            } => {
+                if self.indexes.contains_key(&name) {
+                    return Err(CatalogError::DuplicateEntry(format!(
+                        "Index '{}' already exists",
+                        name
+                    )));
+                }
+                let definition = IndexDefinition::from_params(name.clone(), &params)?;
+                self.indexes.insert(name.clone(), definition);
+                Ok(CatalogResponse::Success {
+                    data: Some(json!({ "message": format!("Index '{}' created", name) })),
+                })
+            }
+            CatalogOperation::Drop {
+                entity_type: EntityType::Index,
+                name,
+                ..
+            } => match self.indexes.remove(&name) {
+                Some(_) => Ok(CatalogResponse::Success {
+                    data: Some(json!({ "message": format!("Index '{}' dropped", name) })),
+                }),
+                None => Err(CatalogError::EntityNotFound(format!(
+                    "Index '{}' not found",
+                    name
+                ))),
+            },
+            CatalogOperation::Query {
+                query_type: QueryType::List,
+                ..
+            } => self.list(),
+            _ => Ok(CatalogResponse::NotSupported),
+        }
+    }
+
+    fn execute_read_only(&self, op: CatalogOperation) -> CatalogResult<CatalogResponse> {
+        match op {
+            CatalogOperation::Query {
+                query_type: QueryType::List,
+                ..
+            } => self.list(),
+            _ => Err(CatalogError::NotSupported(
+                "Only index listing is supported in read-only mode".to_string(),
+            )),
+        }
     }
 
     fn save(&self) -> CatalogResult<Vec<u8>> {
-        Ok(Vec::new())
+        let state = IndexCatalogState {
+            indexes: self.indexes.clone(),
+        };
+
+        let data = bincode::serialize(&state)
+            .map_err(|e| CatalogError::SerializationError(e.to_string()))?;
+        Ok(data)
     }
 
-    fn load(&mut self, _data: &[u8]) -> CatalogResult<()> {
+    fn load(&mut self, data: &[u8]) -> CatalogResult<()> {
+        // Earlier versions saved nothing for this catalog
+        if data.is_empty() {
+            self.indexes.clear();
+            return Ok(());
+        }
+
+        let state: IndexCatalogState = bincode::deserialize(data)
+            .map_err(|e| CatalogError::DeserializationError(e.to_string()))?;
+
+        self.indexes = state.indexes;
         Ok(())
     }
 
     fn schema(&self) -> CatalogSchema {
         CatalogSchema {
             name: "index".to_string(),
-            version: "0.1.0".to_string(),
-            entities: vec![],
-            operations: vec![],
+            version: "1.0.0".to_string(),
+            entities: vec![EntityType::Index.to_string()],
+            operations: self.supported_operations(),
         }
     }
 
     fn supported_operations(&self) -> Vec<String> {
-        vec![]
+        vec![
+            "create_index".to_string(),
+            "drop_index".to_string(),
+            "list_indexes".to_string(),
+        ]
     }
 }
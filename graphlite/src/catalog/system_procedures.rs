@@ -13,10 +13,16 @@
 //! - CALL gql.show_session() YIELD session_id, user_name, schema_name, graph_name
 //! - CALL gql.cache_stats() YIELD cache_type, entries, hit_rate, memory_bytes
 //! - CALL gql.clear_cache([cache_type]) YIELD status, cleared_caches
+//!
+//! Database navigation procedures list the catalog with a few cheap statistics:
+//! - CALL db.schemas() YIELD schema_name, schema_path, graph_count
+//! - CALL db.graphs([schema_name]) YIELD schema_name, graph_name, graph_path, node_count, edge_count
+//! - CALL db.procedures() YIELD name, signature, min_args, max_args, description
 
 use super::manager::CatalogManager;
 use super::operations::{CatalogOperation, CatalogResponse, EntityType, QueryType};
 use crate::exec::error::ExecutionError;
+use crate::exec::graph_procedures::GRAPH_PROCEDURES;
 use crate::exec::result::{QueryResult, Row};
 use crate::session::SessionProvider;
 use crate::storage::Value;
@@ -24,6 +30,112 @@ use serde_json::json;
 use std::collections::HashMap;
 use std::sync::Arc;
 
+/// Description of a procedure callable with CALL
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ProcedureInfo {
+    pub name: &'static str,
+    /// Argument list as written in a call, optional arguments in brackets
+    pub signature: &'static str,
+    pub min_args: usize,
+    pub max_args: usize,
+    pub description: &'static str,
+}
+
+/// Every system procedure, in the order CALL db.procedures() lists them
+pub const SYSTEM_PROCEDURES: &[ProcedureInfo] = &[
+    ProcedureInfo {
+        name: "gql.list_schemas",
+        signature: "()",
+        min_args: 0,
+        max_args: 0,
+        description: "List the schemas in the catalog",
+    },
+    ProcedureInfo {
+        name: "gql.list_graphs",
+        signature: "()",
+        min_args: 0,
+        max_args: 0,
+        description: "List the graphs in the catalog",
+    },
+    ProcedureInfo {
+        name: "gql.list_graph_types",
+        signature: "()",
+        min_args: 0,
+        max_args: 0,
+        description: "List the graph types in the catalog",
+    },
+    ProcedureInfo {
+        name: "gql.list_functions",
+        signature: "()",
+        min_args: 0,
+        max_args: 0,
+        description: "List the built-in functions",
+    },
+    ProcedureInfo {
+        name: "gql.list_roles",
+        signature: "()",
+        min_args: 0,
+        max_args: 0,
+        description: "List the security roles",
+    },
+    ProcedureInfo {
+        name: "gql.list_users",
+        signature: "()",
+        min_args: 0,
+        max_args: 0,
+        description: "List the users",
+    },
+    ProcedureInfo {
+        name: "gql.authenticate_user",
+        signature: "(username :: STRING, password :: STRING)",
+        min_args: 2,
+        max_args: 2,
+        description: "Check a user's credentials",
+    },
+    ProcedureInfo {
+        name: "gql.show_session",
+        signature: "()",
+        min_args: 0,
+        max_args: 0,
+        description: "Show the current session's user, schema and graph",
+    },
+    ProcedureInfo {
+        name: "gql.cache_stats",
+        signature: "()",
+        min_args: 0,
+        max_args: 0,
+        description: "Show entries, hit rate and memory use of each cache",
+    },
+    ProcedureInfo {
+        name: "gql.clear_cache",
+        signature: "([cache_type :: STRING])",
+        min_args: 0,
+        max_args: 1,
+        description: "Clear one cache, or all caches",
+    },
+    ProcedureInfo {
+        name: "db.schemas",
+        signature: "()",
+        min_args: 0,
+        max_args: 0,
+        description: "List the schemas with their graph counts",
+    },
+    ProcedureInfo {
+        name: "db.graphs",
+        signature: "([schema_name :: STRING])",
+        min_args: 0,
+        max_args: 1,
+        description: "List the graphs with their node and edge counts",
+    },
+    ProcedureInfo {
+        name: "db.procedures",
+        signature: "()",
+        min_args: 0,
+        max_args: 0,
+        description: "List the procedures that can be called",
+    },
+];
+
 /// System catalog procedures registry (vendor-specific system procedures)
 pub struct SystemProcedures {
     catalog_manager: Arc<std::sync::RwLock<CatalogManager>>,
@@ -63,8 +175,8 @@ impl SystemProcedures {
         args: Vec<Value>,
         session_id: Option<&str>,
     ) -> Result<QueryResult, ExecutionError> {
-        // Validate that only gql.* namespace is used
-        if !procedure_name.starts_with("gql.") {
+        // Validate that only gql.* namespace (and the db.* catalog listings) is used
+        if !procedure_name.starts_with("gql.") && !is_catalog_listing(procedure_name) {
             return Err(ExecutionError::UnsupportedOperator(format!(
                 "Invalid procedure namespace: '{}'. System procedures must use 'gql.' prefix. Example: CALL gql.list_graphs()",
                 procedure_name
@@ -81,17 +193,25 @@ impl SystemProcedures {
             "gql.list_roles" => self.list_roles(args),
             "gql.list_users" => self.list_users(args),
             "gql.authenticate_user" => self.authenticate_user(args),
-            "gql.show_session" => {
-                match session_id {
-                    Some(id) => self.show_session(args, id),
-                    None => Err(ExecutionError::RuntimeError("show_session requires an active session".to_string()))
-                }
+            "gql.show_session" => match session_id {
+                Some(id) => self.show_session(args, id),
+                None => Err(ExecutionError::RuntimeError(
+                    "show_session requires an active session".to_string(),
+                )),
             },
             "gql.cache_stats" => self.cache_stats(args),
             "gql.clear_cache" => self.clear_cache(args),
+            "db.schemas" => self.db_schemas(args),
+            "db.graphs" => self.db_graphs(args),
+            "db.procedures" => self.db_procedures(args),
             _ => Err(ExecutionError::UnsupportedOperator(format!(
-                "System procedure not found or not supported: {}. Available system procedures: list_schemas, list_graphs, list_graph_types, list_functions, list_roles, list_users, authenticate_user, show_session, cache_stats, clear_cache",
-                procedure_name
+                "System procedure not found or not supported: {}. Available system procedures: {}",
+                procedure_name,
+                SYSTEM_PROCEDURES
+                    .iter()
+                    .map(|procedure| procedure.name)
+                    .collect::<Vec<_>>()
+                    .join(", ")
             ))),
         }
     }
@@ -99,25 +219,7 @@ impl SystemProcedures {
     /// Check if a procedure name is a valid system procedure
     #[allow(dead_code)] // ROADMAP v0.4.0 - System procedure validation (see ROADMAP.md §4)
     pub fn is_valid_procedure(&self, procedure_name: &str) -> bool {
-        let normalized_name = if procedure_name.starts_with("system.") {
-            procedure_name.replace("system.", "gql.")
-        } else {
-            procedure_name.to_string()
-        };
-
-        matches!(
-            normalized_name.as_str(),
-            "gql.list_schemas"
-                | "gql.list_graphs"
-                | "gql.list_graph_types"
-                | "gql.list_functions"
-                | "gql.list_roles"
-                | "gql.list_users"
-                | "gql.authenticate_user"
-                | "gql.show_session"
-                | "gql.cache_stats"
-                | "gql.clear_cache"
-        )
+        is_system_procedure(procedure_name)
     }
 
     /// CALL gql.list_schemas() YIELD schema_name, schema_path, created_at, modified_at, description
@@ -1079,6 +1181,219 @@ impl SystemProcedures {
             ))
         }
     }
+
+    /// All schemas as (name, path), sorted by name
+    fn catalog_schemas(&self) -> Result<Vec<(String, String)>, ExecutionError> {
+        let mut catalog_manager = self.catalog_manager.write().map_err(|_| {
+            ExecutionError::RuntimeError("Failed to acquire catalog manager lock".to_string())
+        })?;
+        let response = catalog_manager
+            .execute(
+                "schema",
+                CatalogOperation::List {
+                    entity_type: EntityType::Schema,
+                    filters: None,
+                },
+            )
+            .map_err(|e| ExecutionError::CatalogError(format!("Failed to list schemas: {}", e)))?;
+
+        let mut schemas: Vec<(String, String)> = match response {
+            CatalogResponse::List { items } => items
+                .iter()
+                .filter_map(|item| {
+                    let id = item.get("id")?;
+                    let name = id.get("name")?.as_str()?.to_string();
+                    let path = id
+                        .get("path")
+                        .and_then(|v| v.as_str())
+                        .map(|s| s.to_string())
+                        .unwrap_or_else(|| format!("/{}", name));
+                    Some((name, path))
+                })
+                .collect(),
+            _ => Vec::new(),
+        };
+        schemas.sort();
+        Ok(schemas)
+    }
+
+    /// All graphs as (schema name, graph name), sorted by schema then graph
+    fn catalog_graphs(&self) -> Result<Vec<(String, String)>, ExecutionError> {
+        let mut catalog_manager = self.catalog_manager.write().map_err(|_| {
+            ExecutionError::RuntimeError("Failed to acquire catalog manager lock".to_string())
+        })?;
+        let response = catalog_manager
+            .execute(
+                "graph_metadata",
+                CatalogOperation::List {
+                    entity_type: EntityType::Graph,
+                    filters: None,
+                },
+            )
+            .map_err(|e| ExecutionError::CatalogError(format!("Failed to list graphs: {}", e)))?;
+
+        let mut graphs: Vec<(String, String)> = match response {
+            CatalogResponse::List { items } => items
+                .iter()
+                .filter_map(|item| {
+                    let id = item.get("id")?;
+                    Some((
+                        id.get("schema_name")?.as_str()?.to_string(),
+                        id.get("name")?.as_str()?.to_string(),
+                    ))
+                })
+                .collect(),
+            _ => Vec::new(),
+        };
+        graphs.sort();
+        Ok(graphs)
+    }
+
+    /// CALL db.schemas() YIELD schema_name, schema_path, graph_count
+    fn db_schemas(&self, _args: Vec<Value>) -> Result<QueryResult, ExecutionError> {
+        let schemas = self.catalog_schemas()?;
+        let graphs = self.catalog_graphs()?;
+
+        let rows: Vec<Row> = schemas
+            .into_iter()
+            .map(|(name, path)| {
+                let graph_count = graphs.iter().filter(|(schema, _)| *schema == name).count();
+                Row::from_values(HashMap::from([
+                    ("schema_name".to_string(), Value::String(name)),
+                    ("schema_path".to_string(), Value::String(path)),
+                    ("graph_count".to_string(), Value::Number(graph_count as f64)),
+                ]))
+            })
+            .collect();
+
+        Ok(QueryResult {
+            rows_affected: rows.len(),
+            session_result: None,
+            warnings: Vec::new(),
+            rows,
+            variables: vec![
+                "schema_name".to_string(),
+                "schema_path".to_string(),
+                "graph_count".to_string(),
+            ],
+            execution_time_ms: 0,
+        })
+    }
+
+    /// CALL db.procedures() YIELD name, signature, min_args, max_args, description
+    ///
+    /// Lists the system procedures followed by the graph algorithm procedures.
+    fn db_procedures(&self, args: Vec<Value>) -> Result<QueryResult, ExecutionError> {
+        if !args.is_empty() {
+            return Err(ExecutionError::RuntimeError(
+                "db.procedures expects no arguments".to_string(),
+            ));
+        }
+
+        let rows: Vec<Row> = SYSTEM_PROCEDURES
+            .iter()
+            .chain(GRAPH_PROCEDURES)
+            .map(|procedure| {
+                Row::from_values(HashMap::from([
+                    (
+                        "name".to_string(),
+                        Value::String(procedure.name.to_string()),
+                    ),
+                    (
+                        "signature".to_string(),
+                        Value::String(procedure.signature.to_string()),
+                    ),
+                    (
+                        "min_args".to_string(),
+                        Value::Number(procedure.min_args as f64),
+                    ),
+                    (
+                        "max_args".to_string(),
+                        Value::Number(procedure.max_args as f64),
+                    ),
+                    (
+                        "description".to_string(),
+                        Value::String(procedure.description.to_string()),
+                    ),
+                ]))
+            })
+            .collect();
+
+        Ok(QueryResult {
+            rows_affected: rows.len(),
+            session_result: None,
+            warnings: Vec::new(),
+            rows,
+            variables: vec![
+                "name".to_string(),
+                "signature".to_string(),
+                "min_args".to_string(),
+                "max_args".to_string(),
+                "description".to_string(),
+            ],
+            execution_time_ms: 0,
+        })
+    }
+
+    /// CALL db.graphs([schema_name]) YIELD schema_name, graph_name, graph_path, node_count, edge_count
+    ///
+    /// Counts come from the storage cache and are NULL for graphs that cannot be loaded.
+    fn db_graphs(&self, args: Vec<Value>) -> Result<QueryResult, ExecutionError> {
+        let schema_filter = match args.as_slice() {
+            [] | [Value::Null] => None,
+            [Value::String(schema)] => Some(schema.trim_start_matches('/').to_string()),
+            _ => {
+                return Err(ExecutionError::RuntimeError(
+                    "db.graphs expects at most 1 string argument: schema name".to_string(),
+                ))
+            }
+        };
+
+        let rows: Vec<Row> = self
+            .catalog_graphs()?
+            .into_iter()
+            .filter(|(schema, _)| schema_filter.as_ref().is_none_or(|filter| schema == filter))
+            .map(|(schema, graph)| {
+                let path = format!("/{}/{}", schema, graph);
+                let stats = self
+                    .storage
+                    .get_graph(&path)
+                    .ok()
+                    .flatten()
+                    .map(|graph| graph.stats());
+                let count =
+                    |count: Option<usize>| count.map_or(Value::Null, |n| Value::Number(n as f64));
+                Row::from_values(HashMap::from([
+                    ("schema_name".to_string(), Value::String(schema)),
+                    ("graph_name".to_string(), Value::String(graph)),
+                    (
+                        "node_count".to_string(),
+                        count(stats.as_ref().map(|stats| stats.node_count)),
+                    ),
+                    (
+                        "edge_count".to_string(),
+                        count(stats.as_ref().map(|stats| stats.edge_count)),
+                    ),
+                    ("graph_path".to_string(), Value::String(path)),
+                ]))
+            })
+            .collect();
+
+        Ok(QueryResult {
+            rows_affected: rows.len(),
+            session_result: None,
+            warnings: Vec::new(),
+            rows,
+            variables: vec![
+                "schema_name".to_string(),
+                "graph_name".to_string(),
+                "graph_path".to_string(),
+                "node_count".to_string(),
+                "edge_count".to_string(),
+            ],
+            execution_time_ms: 0,
+        })
+    }
 }
 
 /// Check if a procedure name is a valid system procedure
@@ -1089,17 +1404,37 @@ pub fn is_system_procedure(procedure_name: &str) -> bool {
         procedure_name.to_string()
     };
 
+    SYSTEM_PROCEDURES
+        .iter()
+        .any(|procedure| procedure.name == normalized)
+}
+
+/// Check if a procedure is one of the db.* catalog listings
+///
+/// These live in the db.* namespace next to the graph algorithm procedures but,
+/// like the gql.* procedures, only read the catalog.
+pub fn is_catalog_listing(procedure_name: &str) -> bool {
+    matches!(procedure_name, "db.schemas" | "db.graphs" | "db.procedures")
+}
+
+/// Check if a procedure only reads the catalog, the session or the current graph
+///
+/// Procedures that authenticate, clear caches or manage models are left out,
+/// so read-only queries cannot reach them.
+pub fn is_read_only_procedure(procedure_name: &str) -> bool {
+    let normalized = match procedure_name.strip_prefix("system.") {
+        Some(name) => format!("gql.{}", name),
+        None => procedure_name.to_string(),
+    };
     matches!(
         normalized.as_str(),
         "gql.list_schemas"
             | "gql.list_graphs"
             | "gql.list_graph_types"
             | "gql.list_functions"
-            | "gql.list_roles"
-            | "gql.list_users"
-            | "gql.authenticate_user"
             | "gql.show_session"
             | "gql.cache_stats"
-            | "gql.clear_cache"
-    )
+            | "db.graph_stats"
+            | "db.connected_components"
+    ) || is_catalog_listing(&normalized)
 }
@@ -0,0 +1,83 @@
+// Copyright (c) 2024-2025 DeepGraph Inc.
+// SPDX-License-Identifier: Apache-2.0
+//
+//! Admission control for concurrent query execution
+//!
+//! Caps how many queries run at once. Queries beyond the cap wait in a
+//! bounded queue until a running query finishes; once the queue is full,
+//! further queries are rejected with an `Overloaded` error instead of
+//! piling up and exhausting memory.
+
+use crate::exec::ExecutionError;
+use std::sync::{Condvar, Mutex};
+
+/// Concurrency limit shared by all queries of a coordinator
+pub(crate) struct QueryAdmission {
+    max_running: usize,
+    max_queued: usize,
+    state: Mutex<AdmissionState>,
+    slot_freed: Condvar,
+}
+
+#[derive(Default)]
+struct AdmissionState {
+    running: usize,
+    queued: usize,
+}
+
+/// A running query's slot, given back when dropped
+pub(crate) struct AdmissionPermit<'a> {
+    admission: &'a QueryAdmission,
+}
+
+impl QueryAdmission {
+    /// Allow `max_running` queries at once (at least one) and up to
+    /// `max_queued` more waiting for a slot
+    pub(crate) fn new(max_running: usize, max_queued: usize) -> Self {
+        Self {
+            max_running: max_running.max(1),
+            max_queued,
+            state: Mutex::new(AdmissionState::default()),
+            slot_freed: Condvar::new(),
+        }
+    }
+
+    /// Take a slot, waiting in the queue if all slots are busy
+    ///
+    /// Fails with `ExecutionError::Overloaded` without waiting if the queue is
+    /// already full.
+    pub(crate) fn admit(&self) -> Result<AdmissionPermit<'_>, ExecutionError> {
+        let mut state = self
+            .state
+            .lock()
+            .map_err(|_| ExecutionError::RuntimeError("Admission lock poisoned".to_string()))?;
+
+        if state.running >= self.max_running {
+            if state.queued >= self.max_queued {
+                return Err(ExecutionError::Overloaded(format!(
+                    "{} queries running and {} queued",
+                    state.running, state.queued
+                )));
+            }
+            state.queued += 1;
+            while state.running >= self.max_running {
+                state = self.slot_freed.wait(state).map_err(|_| {
+                    ExecutionError::RuntimeError("Admission lock poisoned".to_string())
+                })?;
+            }
+            state.queued -= 1;
+        }
+
+        state.running += 1;
+        Ok(AdmissionPermit { admission: self })
+    }
+}
+
+impl Drop for AdmissionPermit<'_> {
+    fn drop(&mut self) {
+        if let Ok(mut state) = self.admission.state.lock() {
+            state.running -= 1;
+        }
+        self.admission.slot_freed.notify_one();
+    }
+}
@@ -0,0 +1,126 @@
+// Copyright (c) 2024-2025 DeepGraph Inc.
+// SPDX-License-Identifier: Apache-2.0
+//
+//! Errors returned by the coordinator's query entry points
+//!
+//! The message is the text the coordinator has always reported; the kind
+//! tells callers what failed without parsing that text.
+
+use crate::exec::ExecutionError;
+use std::fmt;
+
+/// What went wrong in a [`QueryError`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum QueryErrorKind {
+    /// The query text could not be parsed
+    Parse,
+    /// The session does not exist, or was closed or expired
+    SessionNotFound,
+    /// The open transaction was idle past the timeout and has been rolled back
+    TransactionTimedOut,
+    /// The query returned more rows than its row limit allows
+    ResultTooLarge,
+    /// A sandboxed query produced more data than its memory limit
+    MemoryLimitExceeded,
+    /// A sandboxed query ran longer than its time limit
+    QueryTimedOut,
+    /// A read-only query tried to write data or change the catalog
+    ReadOnlyViolation,
+    /// The operation collided with a concurrent one: a busy lock, or a
+    /// deadlock between lock waits
+    Conflict,
+    /// A graph lock could not be acquired before the timeout
+    LockTimeout,
+    /// The concurrency limit and its queue were both full
+    Overloaded,
+    /// The session's user went over their rate limit
+    RateLimited,
+    /// The statement needs a graph, but the session has none and the query
+    /// names none; `hint` says how to choose one
+    NoCurrentGraph { hint: String },
+    /// Any other failure
+    Other,
+}
+
+/// Error of a query run through the [`QueryCoordinator`]
+///
+/// Displays as its message.
+///
+/// [`QueryCoordinator`]: super::QueryCoordinator
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QueryError {
+    kind: QueryErrorKind,
+    message: String,
+}
+
+impl QueryError {
+    pub(crate) fn new(kind: QueryErrorKind, message: impl Into<String>) -> Self {
+        Self {
+            kind,
+            message: message.into(),
+        }
+    }
+
+    pub(crate) fn parse(error: impl fmt::Debug) -> Self {
+        Self::new(QueryErrorKind::Parse, format!("Parse error: {:?}", error))
+    }
+
+    pub(crate) fn session_not_found(session_id: &str) -> Self {
+        Self::new(
+            QueryErrorKind::SessionNotFound,
+            format!("Session not found: {}", session_id),
+        )
+    }
+
+    /// What kind of failure this is
+    pub fn kind(&self) -> &QueryErrorKind {
+        &self.kind
+    }
+
+    /// The error message
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl fmt::Display for QueryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl std::error::Error for QueryError {}
+
+impl From<ExecutionError> for QueryError {
+    fn from(error: ExecutionError) -> Self {
+        let kind = match &error {
+            ExecutionError::TransactionTimedOut(_) => QueryErrorKind::TransactionTimedOut,
+            ExecutionError::ResultTooLarge { .. } => QueryErrorKind::ResultTooLarge,
+            ExecutionError::MemoryLimitExceeded { .. } => QueryErrorKind::MemoryLimitExceeded,
+            ExecutionError::QueryTimedOut { .. } => QueryErrorKind::QueryTimedOut,
+            ExecutionError::ReadOnlyViolation(_) => QueryErrorKind::ReadOnlyViolation,
+            ExecutionError::Conflict(_) => QueryErrorKind::Conflict,
+            ExecutionError::LockTimeout(_) => QueryErrorKind::LockTimeout,
+            ExecutionError::Overloaded(_) => QueryErrorKind::Overloaded,
+            ExecutionError::RateLimited(_) => QueryErrorKind::RateLimited,
+            ExecutionError::NoCurrentGraph { hint } => {
+                QueryErrorKind::NoCurrentGraph { hint: hint.clone() }
+            }
+            _ => QueryErrorKind::Other,
+        };
+        Self::new(kind, format!("Execution error: {:?}", error))
+    }
+}
+
+impl From<String> for QueryError {
+    fn from(message: String) -> Self {
+        Self::new(QueryErrorKind::Other, message)
+    }
+}
+
+impl From<QueryError> for String {
+    fn from(error: QueryError) -> Self {
+        error.message
+    }
+}
@@ -0,0 +1,169 @@
+// Copyright (c) 2024-2025 DeepGraph Inc.
+// SPDX-License-Identifier: Apache-2.0
+//
+//! Health checks for liveness and readiness probes
+//!
+//! Each check exercises one component the way a query would depend on it and
+//! times the result. Checks never modify user data: the storage probe writes a
+//! reserved key and removes it again.
+
+use crate::catalog::manager::CatalogManager;
+use crate::session::SessionProvider;
+use crate::storage::StorageManager;
+use std::sync::RwLock;
+use std::time::{Duration, Instant};
+
+/// Name of the storage component in a [`HealthReport`]
+pub const STORAGE_COMPONENT: &str = "storage";
+/// Name of the catalog component in a [`HealthReport`]
+pub const CATALOG_COMPONENT: &str = "catalog";
+/// Name of the session manager component in a [`HealthReport`]
+pub const SESSIONS_COMPONENT: &str = "sessions";
+
+/// Outcome of a single component check
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HealthStatus {
+    Ok,
+    Unhealthy,
+}
+
+/// Health of one database component
+#[derive(Debug, Clone)]
+pub struct ComponentHealth {
+    /// Component name, e.g. "storage"
+    pub name: &'static str,
+    pub status: HealthStatus,
+    /// Time the check took
+    pub latency: Duration,
+    /// Failure reason, or extra detail for healthy components
+    pub message: Option<String>,
+}
+
+/// Result of [`QueryCoordinator::health_check`](crate::QueryCoordinator::health_check)
+#[derive(Debug, Clone)]
+pub struct HealthReport {
+    pub components: Vec<ComponentHealth>,
+}
+
+impl HealthReport {
+    /// Whether every component reported OK
+    pub fn is_healthy(&self) -> bool {
+        self.components
+            .iter()
+            .all(|component| component.status == HealthStatus::Ok)
+    }
+
+    /// Look up a component's health by name
+    pub fn component(&self, name: &str) -> Option<&ComponentHealth> {
+        self.components
+            .iter()
+            .find(|component| component.name == name)
+    }
+}
+
+/// Run a check and record its status and latency
+fn timed(
+    name: &'static str,
+    check: impl FnOnce() -> Result<Option<String>, String>,
+) -> ComponentHealth {
+    let started = Instant::now();
+    let outcome = check();
+    let latency = started.elapsed();
+    match outcome {
+        Ok(message) => ComponentHealth {
+            name,
+            status: HealthStatus::Ok,
+            latency,
+            message,
+        },
+        Err(reason) => ComponentHealth {
+            name,
+            status: HealthStatus::Unhealthy,
+            latency,
+            message: Some(reason),
+        },
+    }
+}
+
+/// Round-trip a reserved key through the storage driver
+pub(crate) fn check_storage(storage: &StorageManager) -> ComponentHealth {
+    timed(STORAGE_COMPONENT, || {
+        storage.probe().map(|_| None).map_err(|e| e.to_string())
+    })
+}
+
+/// Confirm the catalog is registered and its persisted state is readable
+pub(crate) fn check_catalog(
+    catalog: &RwLock<CatalogManager>,
+    storage: &StorageManager,
+) -> ComponentHealth {
+    timed(CATALOG_COMPONENT, || {
+        let catalog = catalog
+            .read()
+            .map_err(|_| "Catalog lock is poisoned".to_string())?;
+        if catalog.catalog_count() == 0 {
+            return Err("No catalogs are registered".to_string());
+        }
+        storage
+            .list_catalog_providers()
+            .map_err(|e| e.to_string())?;
+        Ok(None)
+    })
+}
+
+/// Confirm the session provider answers
+pub(crate) fn check_sessions(provider: &dyn SessionProvider) -> ComponentHealth {
+    timed(SESSIONS_COMPONENT, || {
+        Ok(Some(format!(
+            "active sessions: {}",
+            provider.session_count()
+        )))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::QueryCoordinator;
+    use std::sync::atomic::Ordering;
+
+    #[test]
+    fn test_healthy_database_reports_ok() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let coordinator = QueryCoordinator::from_path(temp_dir.path()).unwrap();
+        let _session = coordinator.create_simple_session("admin").unwrap();
+
+        let report = coordinator.health_check();
+        assert!(report.is_healthy(), "unexpected report: {:?}", report);
+        for name in [STORAGE_COMPONENT, CATALOG_COMPONENT, SESSIONS_COMPONENT] {
+            let component = report.component(name).unwrap();
+            assert_eq!(component.status, HealthStatus::Ok, "{}", name);
+        }
+        assert_eq!(
+            report
+                .component(SESSIONS_COMPONENT)
+                .unwrap()
+                .message
+                .as_deref(),
+            Some("active sessions: 1")
+        );
+    }
+
+    #[test]
+    fn test_storage_failure_reports_unhealthy() {
+        let (storage, failing) = StorageManager::with_faulty_driver();
+
+        assert_eq!(check_storage(&storage).status, HealthStatus::Ok);
+        let metadata = storage
+            .get_storage_driver()
+            .unwrap()
+            .open_tree("metadata")
+            .unwrap();
+        assert!(metadata.is_empty().unwrap(), "probe left data behind");
+
+        failing.store(true, Ordering::SeqCst);
+        let health = check_storage(&storage);
+        assert_eq!(health.status, HealthStatus::Unhealthy);
+        assert!(health.message.unwrap().contains("injected storage failure"));
+    }
+}
@@ -0,0 +1,140 @@
+// Copyright (c) 2024-2025 DeepGraph Inc.
+// SPDX-License-Identifier: Apache-2.0
+//
+//! Cumulative query counters for monitoring
+//!
+//! Every query the coordinator runs is counted once it finishes, whether it
+//! succeeded or not. Queries that fail before they parse (rate limiting, a
+//! full admission queue, syntax errors) count towards the totals but not
+//! towards any query type.
+
+use super::query_coordinator::QueryType;
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// Snapshot of the counters returned by [`QueryCoordinator::metrics`]
+///
+/// [`QueryCoordinator::metrics`]: super::QueryCoordinator::metrics
+#[derive(Debug, Clone, Default)]
+pub struct QueryMetrics {
+    /// Queries run, successful or not
+    pub total_queries: u64,
+    /// Queries that returned an error
+    pub total_errors: u64,
+    /// Queries run per statement type, for queries that parsed
+    pub queries_by_type: HashMap<QueryType, u64>,
+    /// Summed wall-clock time of all queries
+    pub total_latency: Duration,
+    /// Slowest single query
+    pub max_latency: Duration,
+    /// MATCH queries answered from the result cache
+    pub result_cache_hits: u64,
+    /// MATCH queries looked up in the result cache and executed
+    pub result_cache_misses: u64,
+    /// Cached results dropped because a statement changed the data
+    pub result_cache_invalidations: u64,
+    /// Statements turned into a physical plan; a batch run through
+    /// `execute_many` is planned once
+    pub statements_planned: u64,
+}
+
+impl QueryMetrics {
+    /// Number of queries run of the given type
+    pub fn count(&self, query_type: &QueryType) -> u64 {
+        self.queries_by_type.get(query_type).copied().unwrap_or(0)
+    }
+
+    /// Mean wall-clock time per query, zero before any query has run
+    pub fn average_latency(&self) -> Duration {
+        if self.total_queries == 0 {
+            return Duration::ZERO;
+        }
+        self.total_latency.div_f64(self.total_queries as f64)
+    }
+}
+
+/// Shared counters updated by every query
+#[derive(Default)]
+pub(crate) struct MetricsRecorder {
+    metrics: Mutex<QueryMetrics>,
+}
+
+impl MetricsRecorder {
+    /// Start timing one query; it is recorded when the returned guard drops
+    pub(crate) fn begin(&self) -> QueryRecording<'_> {
+        QueryRecording {
+            metrics: &self.metrics,
+            started: Instant::now(),
+            query_type: None,
+            cache_hit: None,
+            succeeded: false,
+        }
+    }
+
+    pub(crate) fn snapshot(&self) -> QueryMetrics {
+        self.metrics
+            .lock()
+            .map(|metrics| metrics.clone())
+            .unwrap_or_default()
+    }
+
+    /// Count cached results dropped after a write
+    pub(crate) fn record_cache_invalidations(&self, entries: usize) {
+        if let Ok(mut metrics) = self.metrics.lock() {
+            metrics.result_cache_invalidations += entries as u64;
+        }
+    }
+
+    pub(crate) fn reset(&self) {
+        if let Ok(mut metrics) = self.metrics.lock() {
+            *metrics = QueryMetrics::default();
+        }
+    }
+}
+
+/// One running query; counted as an error unless marked as succeeded
+pub(crate) struct QueryRecording<'a> {
+    metrics: &'a Mutex<QueryMetrics>,
+    started: Instant,
+    query_type: Option<QueryType>,
+    cache_hit: Option<bool>,
+    succeeded: bool,
+}
+
+impl QueryRecording<'_> {
+    pub(crate) fn set_query_type(&mut self, query_type: QueryType) {
+        self.query_type = Some(query_type);
+    }
+
+    /// Note whether the result cache answered the query
+    pub(crate) fn set_cache_hit(&mut self, hit: bool) {
+        self.cache_hit = Some(hit);
+    }
+
+    pub(crate) fn succeeded(&mut self) {
+        self.succeeded = true;
+    }
+}
+
+impl Drop for QueryRecording<'_> {
+    fn drop(&mut self) {
+        let elapsed = self.started.elapsed();
+        if let Ok(mut metrics) = self.metrics.lock() {
+            metrics.total_queries += 1;
+            if !self.succeeded {
+                metrics.total_errors += 1;
+            }
+            if let Some(query_type) = self.query_type.take() {
+                *metrics.queries_by_type.entry(query_type).or_insert(0) += 1;
+            }
+            match self.cache_hit {
+                Some(true) => metrics.result_cache_hits += 1,
+                Some(false) => metrics.result_cache_misses += 1,
+                None => {}
+            }
+            metrics.total_latency += elapsed;
+            metrics.max_latency = metrics.max_latency.max(elapsed);
+        }
+    }
+}
@@ -6,9 +6,20 @@
 //! The QueryCoordinator provides a unified entry point for query execution,
 //! properly coordinating all database components (session, storage, catalog, execution).
 
+mod admission;
+mod error;
+pub mod health;
+mod metrics;
 pub mod query_coordinator;
+mod rate_limit;
 
-pub use query_coordinator::{QueryCoordinator, QueryInfo, QueryPlan, QueryType};
+pub use error::{QueryError, QueryErrorKind};
+pub use health::{ComponentHealth, HealthReport, HealthStatus};
+pub use metrics::QueryMetrics;
+pub use query_coordinator::{
+    AnalyzedQueryPlan, DryRunReport, QueryCoordinator, QueryInfo, QueryPlan, QueryType, SlowQuery,
+};
 
 // Re-export types needed for the public API
 pub use crate::exec::{QueryResult, Row};
+pub use crate::plan::trace::{AnalyzedNode, EstimatedNode};
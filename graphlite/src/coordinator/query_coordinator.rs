@@ -6,19 +6,42 @@
 //! This provides a clean API that wraps the session manager and properly
 //! coordinates query execution through the standard GraphLite components.
 
-use crate::ast::parser::parse_query;
-use crate::cache::CacheManager;
+use super::admission::QueryAdmission;
+use super::error::QueryError;
+use super::health::{self, HealthReport};
+use super::metrics::{MetricsRecorder, QueryMetrics};
+use super::rate_limit::RateLimiter;
+use crate::ast::lexer::{tokenize, Token};
+use crate::ast::parser::{parse_query_with_max_depth, ParserError, DEFAULT_MAX_NESTING_DEPTH};
+use crate::cache::result_cache::{
+    create_query_cache_key, normalize_query_text, CacheParameter, QueryCacheKey,
+};
+use crate::cache::{CacheManager, ResultCache};
 use crate::catalog::manager::CatalogManager;
-use crate::exec::{ExecutionRequest, QueryExecutor, QueryResult};
+use crate::exec::lock_tracker::{GraphLockGuard, LockMode};
+use crate::exec::progress::QueryProgress;
+use crate::exec::query_limits::{QueryGuard, QueryLimits};
+use crate::exec::{ExecutionError, ExecutionRequest, QueryExecutor, QueryResult};
+use crate::plan::trace::{AnalyzedNode, EstimatedNode, ExecutionTracer};
 use crate::session::{
     GlobalSessionProvider, InstanceSessionProvider, SessionManager, SessionMode, SessionProvider,
+    UserSession,
+};
+use crate::storage::{
+    CompactionReport, FlushPolicy, StorageManager, StorageMethod, StorageType, Value,
 };
-use crate::storage::{StorageManager, StorageMethod, StorageType};
-use crate::txn::TransactionManager;
+use crate::txn::{ChangeEvent, SubscriptionId, TransactionManager};
+use std::collections::HashMap;
 use std::panic::{RefUnwindSafe, UnwindSafe};
 use std::path::Path;
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::Arc;
-use std::sync::RwLock;
+use std::sync::{Mutex, RwLock};
+use std::time::{Duration, Instant};
+
+/// Functions whose result differs between runs of the same query, which keep
+/// a query out of the result cache
+const NON_DETERMINISTIC_FUNCTIONS: &[&str] = &["RAND", "NOW", "CURRENT_DATE", "CURRENT_TIME"];
 
 /// Query Coordinator - Orchestrates query execution with proper session management
 ///
@@ -32,6 +55,43 @@ pub struct QueryCoordinator {
     session_provider: Arc<dyn SessionProvider>,
     /// Query executor
     executor: Arc<QueryExecutor>,
+    /// Callback for queries slower than a threshold
+    slow_query_hook: RwLock<Option<SlowQueryHook>>,
+    /// Cap on concurrently running queries, if any
+    admission: RwLock<Option<Arc<QueryAdmission>>>,
+    /// Per-user query rate limits
+    rate_limiter: RateLimiter,
+    /// Cumulative query counters
+    metrics: MetricsRecorder,
+    /// Read-through cache of MATCH query results, if enabled
+    result_cache: RwLock<Option<Arc<ResultCache>>>,
+    /// How deeply queries parsed by this coordinator may nest
+    max_nesting_depth: AtomicUsize,
+}
+
+/// Callback registered with [`QueryCoordinator::on_slow_query`]
+type SlowQueryCallback = Arc<dyn Fn(&SlowQuery) + Send + Sync>;
+
+struct SlowQueryHook {
+    threshold: Duration,
+    callback: SlowQueryCallback,
+}
+
+/// A completed query that took at least the slow-query threshold
+#[derive(Debug, Clone)]
+pub struct SlowQuery {
+    /// The query text as submitted
+    pub query_text: String,
+    /// Session that ran the query
+    pub session_id: String,
+    /// Statement type and read-only flag
+    pub info: QueryInfo,
+    /// Wall-clock time from parsing to the end of execution
+    pub elapsed: Duration,
+    /// Number of rows returned
+    pub row_count: usize,
+    /// Number of rows affected by a data modification
+    pub rows_affected: usize,
 }
 
 // Explicitly mark QueryCoordinator as UnwindSafe for FFI panic handling.
@@ -167,8 +227,48 @@ impl QueryCoordinator {
                 .map_err(|e| format!("Failed to initialize storage: {}", e))?,
         );
 
-        // Initialize catalog manager
-        let catalog_manager = Arc::new(RwLock::new(CatalogManager::new(storage.clone())));
+        // Initialize transaction manager with database path
+        let transaction_manager = Arc::new(
+            TransactionManager::new(path.clone())
+                .map_err(|e| format!("Failed to initialize transaction manager: {}", e))?,
+        );
+
+        Self::from_components(storage, transaction_manager, mode)
+    }
+
+    /// Create a QueryCoordinator that flushes graph writes per `flush_policy`
+    ///
+    /// [`FlushPolicy::OnCommit`] gives the behavior of [`QueryCoordinator::from_path`].
+    /// The other policies trade durability for write throughput: writes not
+    /// yet flushed survive a clean close but may be lost if the process
+    /// crashes. Under [`FlushPolicy::Manual`], call [`QueryCoordinator::flush`]
+    /// to make writes durable. Catalog changes are always flushed immediately.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use graphlite::{FlushPolicy, QueryCoordinator};
+    ///
+    /// let coordinator = QueryCoordinator::from_path_with_flush_policy("./mydb", FlushPolicy::Manual)?;
+    /// // ... bulk load ...
+    /// coordinator.flush()?;
+    /// # Ok::<(), String>(())
+    /// ```
+    pub fn from_path_with_flush_policy(
+        db_path: impl AsRef<Path>,
+        flush_policy: FlushPolicy,
+    ) -> Result<Arc<Self>, String> {
+        let path = db_path.as_ref().to_path_buf();
+
+        // Initialize storage
+        let storage = Arc::new(
+            StorageManager::new_with_flush_policy(
+                path.clone(),
+                StorageMethod::DiskOnly,
+                StorageType::Sled,
+                flush_policy,
+            )
+            .map_err(|e| format!("Failed to initialize storage: {}", e))?,
+        );
 
         // Initialize transaction manager with database path
         let transaction_manager = Arc::new(
@@ -176,6 +276,44 @@ impl QueryCoordinator {
                 .map_err(|e| format!("Failed to initialize transaction manager: {}", e))?,
         );
 
+        Self::from_components(storage, transaction_manager, SessionMode::Instance)
+    }
+
+    /// Create a QueryCoordinator whose data lives only in memory
+    ///
+    /// Graphs, the catalog and the transaction log are kept in RAM and nothing
+    /// is written to disk, so everything is lost when the coordinator is dropped.
+    /// Intended for tests and short-lived scratch databases.
+    ///
+    /// # Example
+    /// ```
+    /// use graphlite::QueryCoordinator;
+    ///
+    /// let coordinator = QueryCoordinator::in_memory()?;
+    /// let session_id = coordinator.create_simple_session("user")?;
+    /// # Ok::<(), String>(())
+    /// ```
+    pub fn in_memory() -> Result<Arc<Self>, String> {
+        // The memory driver ignores the path
+        let storage = Arc::new(
+            StorageManager::new("", StorageMethod::DiskOnly, StorageType::Memory)
+                .map_err(|e| format!("Failed to initialize storage: {}", e))?,
+        );
+        let transaction_manager = Arc::new(TransactionManager::in_memory());
+
+        Self::from_components(storage, transaction_manager, SessionMode::Instance)
+    }
+
+    /// Wire up the catalog, caches, sessions and executor around a storage
+    /// manager and transaction manager
+    fn from_components(
+        storage: Arc<StorageManager>,
+        transaction_manager: Arc<TransactionManager>,
+        mode: SessionMode,
+    ) -> Result<Arc<Self>, String> {
+        // Initialize catalog manager
+        let catalog_manager = Arc::new(RwLock::new(CatalogManager::new(storage.clone())));
+
         // Initialize cache manager
         let cache_config = crate::cache::CacheConfig::default();
         let cache_manager =
@@ -230,6 +368,12 @@ impl QueryCoordinator {
         Self {
             session_provider,
             executor,
+            slow_query_hook: RwLock::new(None),
+            admission: RwLock::new(None),
+            rate_limiter: RateLimiter::default(),
+            metrics: MetricsRecorder::default(),
+            result_cache: RwLock::new(None),
+            max_nesting_depth: AtomicUsize::new(DEFAULT_MAX_NESTING_DEPTH),
         }
     }
 
@@ -237,39 +381,1013 @@ impl QueryCoordinator {
     ///
     /// This is the main entry point for query execution.
     ///
+    /// With a `tracing` subscriber installed, each query is recorded as a
+    /// `query` span whose children time its phases: `parse`, then `plan` and
+    /// `optimize` for statements that go through the planner, then `execute`.
+    ///
     /// # Arguments
     /// * `query_text` - The GQL query string to execute
     /// * `session_id` - Session ID for the query
     ///
     /// # Returns
     /// * `Ok(QueryResult)` - Query result on success
-    /// * `Err(String)` - Error message on failure
-    pub fn process_query(&self, query_text: &str, session_id: &str) -> Result<QueryResult, String> {
-        // Parse query
-        let document = parse_query(query_text).map_err(|e| format!("Parse error: {:?}", e))?;
+    /// * `Err(QueryError)` - What failed, with its error message
+    pub fn process_query(
+        &self,
+        query_text: &str,
+        session_id: &str,
+    ) -> Result<QueryResult, QueryError> {
+        self.run_query(query_text, session_id, None, None, None)
+    }
+
+    /// Execute a query, reporting progress while it runs
+    ///
+    /// `callback` receives the number of rows the query's scans and
+    /// expansions have produced so far. It is called on the executing thread
+    /// after every `every_rows` new rows, or once `every` has passed since the
+    /// previous call and new rows arrived, so the counts it sees strictly
+    /// increase. The final result is returned as with
+    /// [`QueryCoordinator::process_query`].
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use graphlite::QueryCoordinator;
+    /// # use std::time::Duration;
+    /// # let coordinator = QueryCoordinator::from_path("./mydb").unwrap();
+    /// # let session_id = coordinator.create_simple_session("user").unwrap();
+    /// let result = coordinator.process_query_with_progress(
+    ///     "MATCH (n) RETURN n",
+    ///     &session_id,
+    ///     1000,
+    ///     Duration::from_millis(100),
+    ///     |rows| eprintln!("{} rows so far", rows),
+    /// );
+    /// ```
+    pub fn process_query_with_progress<F>(
+        &self,
+        query_text: &str,
+        session_id: &str,
+        every_rows: usize,
+        every: Duration,
+        callback: F,
+    ) -> Result<QueryResult, QueryError>
+    where
+        F: FnMut(usize) + Send + 'static,
+    {
+        let progress = QueryProgress::new(every_rows, every, Box::new(callback));
+        self.run_query(query_text, session_id, Some(Arc::new(progress)), None, None)
+    }
+
+    /// Execute an untrusted query within resource limits
+    ///
+    /// With [`QueryLimits::read_only`] set, statements that write data or
+    /// change the catalog are rejected before they run. Otherwise the query
+    /// is stopped as soon as its scans and expansions go over the memory or
+    /// time limit, and fails if it returns more rows than the row limit. The
+    /// error names the limit hit: `ReadOnlyViolation`, `MemoryLimitExceeded`,
+    /// `QueryTimedOut` or `ResultTooLarge`. Sandboxed queries always execute,
+    /// bypassing the result cache.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use graphlite::{QueryCoordinator, QueryLimits};
+    /// # use std::time::Duration;
+    /// # let coordinator = QueryCoordinator::from_path("./mydb").unwrap();
+    /// # let session_id = coordinator.create_simple_session("user").unwrap();
+    /// let limits = QueryLimits::new()
+    ///     .max_rows(1000)
+    ///     .timeout(Duration::from_secs(1))
+    ///     .read_only(true);
+    /// let result =
+    ///     coordinator.process_query_with_limits("MATCH (n) RETURN n", &session_id, &limits);
+    /// ```
+    pub fn process_query_with_limits(
+        &self,
+        query_text: &str,
+        session_id: &str,
+        limits: &QueryLimits,
+    ) -> Result<QueryResult, QueryError> {
+        self.run_query(query_text, session_id, None, Some(limits), None)
+    }
+
+    /// Execute one page of a read-only query
+    ///
+    /// The page is pushed into the query as SKIP and LIMIT, composed with any
+    /// the query already has, so rows past the end of the page are never
+    /// returned. Queries without ORDER BY run with
+    /// [`set_deterministic_order`](Self::set_deterministic_order) in effect,
+    /// so consecutive pages line up. Paged queries bypass the result cache.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use graphlite::QueryCoordinator;
+    /// # let coordinator = QueryCoordinator::from_path("./mydb").unwrap();
+    /// # let session_id = coordinator.create_simple_session("user").unwrap();
+    /// // Rows 21 to 30
+    /// let page = coordinator.process_query_page(
+    ///     "MATCH (p:Person) RETURN p.name ORDER BY p.name",
+    ///     &session_id,
+    ///     20,
+    ///     10,
+    /// );
+    /// ```
+    pub fn process_query_page(
+        &self,
+        query_text: &str,
+        session_id: &str,
+        offset: usize,
+        count: usize,
+    ) -> Result<QueryResult, QueryError> {
+        self.run_query(query_text, session_id, None, None, Some((offset, count)))
+    }
+
+    fn run_query(
+        &self,
+        query_text: &str,
+        session_id: &str,
+        progress: Option<Arc<QueryProgress>>,
+        limits: Option<&QueryLimits>,
+        page: Option<(usize, usize)>,
+    ) -> Result<QueryResult, QueryError> {
+        let mut recording = self.metrics.begin();
 
         // Get session
-        let session = self.session_provider.get_session(session_id);
+        let session = self
+            .session_provider
+            .get_session(session_id)
+            .ok_or_else(|| QueryError::session_not_found(session_id))?;
+
+        // Turn the query away before it queues if its user is over their rate
+        if let Ok(session) = session.read() {
+            self.rate_limiter
+                .check(&session.username)
+                .map_err(QueryError::from)?;
+        }
+
+        // Wait for a free slot if concurrency is capped
+        let admission = self
+            .admission
+            .read()
+            .ok()
+            .and_then(|admission| admission.clone());
+        let _permit = admission
+            .as_deref()
+            .map(QueryAdmission::admit)
+            .transpose()
+            .map_err(QueryError::from)?;
+
+        let started = Instant::now();
+        let _query_span = tracing::info_span!("query", session_id).entered();
+
+        // Parse query
+        let mut document = tracing::info_span!("parse")
+            .in_scope(|| self.parse(query_text))
+            .map_err(QueryError::parse)?;
+
+        let query_info = Self::analyze_statement(&document.statement);
+        let query_type = query_info.query_type;
+        recording.set_query_type(query_type.clone());
+
+        if limits.is_some_and(QueryLimits::is_read_only) && !query_info.is_read_only {
+            let error = ExecutionError::ReadOnlyViolation(format!(
+                "{:?} statements are not allowed in a read-only query",
+                query_type
+            ));
+            return Err(error.into());
+        }
+        if let Some((offset, count)) = page {
+            if !query_info.is_read_only {
+                return Err(format!(
+                    "{:?} statements cannot be paginated, only read-only queries",
+                    query_type
+                )
+                .into());
+            }
+            Self::limit_to_page(&mut document.statement, offset, count)?;
+        }
+
+        // Answer repeated MATCH queries from the result cache
+        let result_cache = self
+            .result_cache
+            .read()
+            .ok()
+            .and_then(|cache| cache.clone());
+        let (cache_key, current_graph) = match session.read() {
+            Ok(session) => (
+                result_cache
+                    .as_ref()
+                    .filter(|_| {
+                        query_type == QueryType::Match && limits.is_none() && page.is_none()
+                    })
+                    .and_then(|_| Self::result_cache_key(query_text, &session)),
+                session.current_graph.clone(),
+            ),
+            Err(_) => (None, None),
+        };
+        // Nodes expire without any write, so results of graphs with an
+        // expiry rule are never cached
+        let cache_key = cache_key.filter(|_| {
+            current_graph
+                .as_deref()
+                .is_none_or(|graph| !self.executor.has_ttl_policy(graph))
+        });
+        if let (Some(cache), Some(key)) = (&result_cache, &cache_key) {
+            if let Some(hit) = cache.get(key) {
+                recording.set_cache_hit(true);
+                recording.succeeded();
+                return Ok(hit.result);
+            }
+            recording.set_cache_hit(false);
+        }
+
+        let slow_query_hook = self.slow_query_listener(&document.statement);
 
         // Create execution request
-        let request = ExecutionRequest::new(document.statement)
-            .with_session(session)
+        let mut request = ExecutionRequest::new(document.statement)
+            .with_session(Some(session))
             .with_query_text(Some(query_text.to_string()));
+        if let Some(progress) = progress {
+            request = request.with_progress(progress);
+        }
+        if let Some(limits) = limits {
+            request = request.with_query_guard(Arc::new(QueryGuard::new(*limits)));
+        }
+        if page.is_some() && !query_info.is_ordered {
+            request = request.with_deterministic_order();
+        }
 
         // Execute query
         let result = self
             .executor
             .execute_query(request)
-            .map_err(|e| format!("Execution error: {:?}", e))?;
+            .map_err(QueryError::from);
+
+        // A failed write may still have changed data, so invalidate either way
+        if let Some(cache) = &result_cache {
+            self.invalidate_cached_results(cache, &query_type, current_graph.as_deref());
+        }
+        let result = result?;
+        if let (Some(cache), Some(key)) = (&result_cache, cache_key) {
+            cache.insert(key, result.clone(), started.elapsed(), 0);
+        }
 
         // Process any session results (SET GRAPH, SET SCHEMA, etc.)
         if let Some(ref session_result) = result.session_result {
             self.handle_session_result(session_result, session_id)?;
         }
 
+        if let Some((threshold, callback, info)) = slow_query_hook {
+            let elapsed = started.elapsed();
+            if elapsed >= threshold {
+                callback(&SlowQuery {
+                    query_text: query_text.to_string(),
+                    session_id: session_id.to_string(),
+                    info,
+                    elapsed,
+                    row_count: result.rows.len(),
+                    rows_affected: result.rows_affected,
+                });
+            }
+        }
+
+        recording.succeeded();
         Ok(result)
     }
 
+    /// Narrow a query to `count` rows starting at `offset` of its result
+    fn limit_to_page(
+        statement: &mut crate::ast::Statement,
+        offset: usize,
+        count: usize,
+    ) -> Result<(), String> {
+        use crate::ast::{LimitClause, LimitValue, Location, Query};
+
+        let crate::ast::Statement::Query(query) = statement else {
+            return Err("Only queries can be paginated".to_string());
+        };
+        if !matches!(query, Query::Basic(_) | Query::Limited { .. }) {
+            *query = Query::Limited {
+                query: Box::new(query.clone()),
+                order_clause: None,
+                limit_clause: None,
+            };
+        }
+        let limit_clause = match query {
+            Query::Basic(basic) => &mut basic.limit_clause,
+            Query::Limited { limit_clause, .. } => limit_clause,
+            _ => unreachable!("wrapped in Query::Limited above"),
+        };
+
+        let literal = |value: &LimitValue| match value {
+            LimitValue::Literal(rows) => Ok(*rows),
+            LimitValue::Parameter(_) => {
+                Err("Queries with a parameter in SKIP or LIMIT cannot be paginated".to_string())
+            }
+        };
+        let (skip, limit) = match limit_clause.as_ref() {
+            Some(clause) => (
+                clause
+                    .offset
+                    .as_ref()
+                    .map(literal)
+                    .transpose()?
+                    .unwrap_or(0),
+                Some(literal(&clause.count)?),
+            ),
+            None => (0, None),
+        };
+        let count = limit.map_or(count, |limit| count.min(limit.saturating_sub(offset)));
+        *limit_clause = Some(LimitClause {
+            count: LimitValue::Literal(count),
+            offset: Some(LimitValue::Literal(skip.saturating_add(offset))),
+            location: Location::default(),
+        });
+        Ok(())
+    }
+
+    /// Execute one statement once per parameter set, parsing and planning it only once
+    ///
+    /// Each set is bound for its run only, so the statement refers to its
+    /// values as `$name`; a set's values take precedence over session
+    /// parameters of the same name, which are left untouched. Queries whose
+    /// LIMIT or SKIP takes a parameter are planned per run. The runs share
+    /// the session, so inside a transaction they commit or roll back
+    /// together. Execution stops at the first run that fails. The slow-query
+    /// hook and the metrics count the batch as one query.
+    ///
+    /// # Returns
+    /// * `Ok(Vec<QueryResult>)` - One result per parameter set, in order
+    /// * `Err(QueryError)` - Error of the first failing run
+    pub fn execute_many(
+        &self,
+        query_text: &str,
+        session_id: &str,
+        parameter_sets: &[HashMap<String, Value>],
+    ) -> Result<Vec<QueryResult>, QueryError> {
+        let mut recording = self.metrics.begin();
+        let session = self
+            .session_provider
+            .get_session(session_id)
+            .ok_or_else(|| QueryError::session_not_found(session_id))?;
+
+        if let Ok(session) = session.read() {
+            self.rate_limiter
+                .check(&session.username)
+                .map_err(QueryError::from)?;
+        }
+
+        let admission = self
+            .admission
+            .read()
+            .ok()
+            .and_then(|admission| admission.clone());
+        let _permit = admission
+            .as_deref()
+            .map(QueryAdmission::admit)
+            .transpose()
+            .map_err(QueryError::from)?;
+
+        let started = Instant::now();
+        let document = self.parse(query_text).map_err(QueryError::parse)?;
+        let query_type = Self::analyze_statement(&document.statement).query_type;
+        recording.set_query_type(query_type.clone());
+        let slow_query_hook = self.slow_query_listener(&document.statement);
+
+        let current_graph = session
+            .read()
+            .ok()
+            .and_then(|session| session.current_graph.clone());
+        let plan = self
+            .executor
+            .prepare_plan(&document.statement, current_graph)
+            .map_err(QueryError::from)?;
+
+        let mut results = Vec::with_capacity(parameter_sets.len());
+        let mut outcome = Ok(());
+        for parameters in parameter_sets {
+            let parameters = parameters
+                .iter()
+                .map(|(name, value)| (name.trim_start_matches('$').to_string(), value.clone()))
+                .collect();
+            let request = ExecutionRequest::new(document.statement.clone())
+                .with_session(Some(session.clone()))
+                .with_query_text(Some(query_text.to_string()))
+                .with_parameters(Arc::new(parameters))
+                .with_prepared_plan(plan.clone());
+            let result = match self.executor.execute_query(request) {
+                Ok(result) => result,
+                Err(e) => {
+                    outcome = Err(QueryError::from(e));
+                    break;
+                }
+            };
+            if let Some(ref session_result) = result.session_result {
+                if let Err(e) = self.handle_session_result(session_result, session_id) {
+                    outcome = Err(e.into());
+                    break;
+                }
+            }
+            results.push(result);
+        }
+
+        if let Some(cache) = self
+            .result_cache
+            .read()
+            .ok()
+            .and_then(|cache| cache.clone())
+        {
+            let current_graph = session
+                .read()
+                .ok()
+                .and_then(|session| session.current_graph.clone());
+            self.invalidate_cached_results(&cache, &query_type, current_graph.as_deref());
+        }
+        outcome?;
+
+        if let Some((threshold, callback, info)) = slow_query_hook {
+            let elapsed = started.elapsed();
+            if elapsed >= threshold {
+                callback(&SlowQuery {
+                    query_text: query_text.to_string(),
+                    session_id: session_id.to_string(),
+                    info,
+                    elapsed,
+                    row_count: results.iter().map(|result| result.rows.len()).sum(),
+                    rows_affected: results.iter().map(|result| result.rows_affected).sum(),
+                });
+            }
+        }
+
+        recording.succeeded();
+        Ok(results)
+    }
+
+    /// The slow-query callback and the analyzed statement, if a callback is set
+    ///
+    /// The statement is only analyzed when someone is listening.
+    fn slow_query_listener(
+        &self,
+        statement: &crate::ast::Statement,
+    ) -> Option<(Duration, SlowQueryCallback, QueryInfo)> {
+        self.slow_query_hook
+            .read()
+            .ok()
+            .and_then(|hook| {
+                hook.as_ref()
+                    .map(|hook| (hook.threshold, hook.callback.clone()))
+            })
+            .map(|(threshold, callback)| (threshold, callback, Self::analyze_statement(statement)))
+    }
+
+    /// Register a callback for queries that take at least `threshold`
+    ///
+    /// The callback runs on the thread that executed the query, after the
+    /// query has completed and before its result is returned, so it should
+    /// return quickly. Queries that fail are not reported. Registering a new
+    /// callback replaces the previous one.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use graphlite::QueryCoordinator;
+    /// # use std::time::Duration;
+    /// # let coordinator = QueryCoordinator::from_path("./mydb").unwrap();
+    /// coordinator.on_slow_query(Duration::from_millis(500), |slow| {
+    ///     eprintln!("slow query ({:?}): {}", slow.elapsed, slow.query_text);
+    /// });
+    /// ```
+    pub fn on_slow_query<F>(&self, threshold: Duration, callback: F)
+    where
+        F: Fn(&SlowQuery) + Send + Sync + 'static,
+    {
+        if let Ok(mut hook) = self.slow_query_hook.write() {
+            *hook = Some(SlowQueryHook {
+                threshold,
+                callback: Arc::new(callback),
+            });
+        }
+    }
+
+    /// Remove the callback registered with [`QueryCoordinator::on_slow_query`]
+    pub fn clear_slow_query_hook(&self) {
+        if let Ok(mut hook) = self.slow_query_hook.write() {
+            *hook = None;
+        }
+    }
+
+    /// Call `callback` for every node and edge a committed write changes in
+    /// the graph at `graph_path` (e.g. `/schema/graph`)
+    ///
+    /// Changes are published when their transaction commits, or right after
+    /// the statement in auto-commit mode, from every session. The callback
+    /// runs on a background thread of its own and sees changes in commit
+    /// order; delivery is best-effort. Fails if the delivery thread cannot be
+    /// started.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use graphlite::QueryCoordinator;
+    /// # let coordinator = QueryCoordinator::from_path("./mydb").unwrap();
+    /// let subscription = coordinator
+    ///     .subscribe("/social/people", |change| {
+    ///         println!("{:?} {:?} {}", change.kind, change.entity, change.entity_id);
+    ///     })
+    ///     .unwrap();
+    /// coordinator.unsubscribe(subscription);
+    /// ```
+    pub fn subscribe<F>(&self, graph_path: &str, callback: F) -> Result<SubscriptionId, String>
+    where
+        F: Fn(&ChangeEvent) + Send + 'static,
+    {
+        self.executor
+            .transaction_manager()
+            .change_feed()
+            .subscribe(graph_path, callback)
+            .map_err(|e| format!("Failed to subscribe: {}", e))
+    }
+
+    /// Remove a subscription made with [`QueryCoordinator::subscribe`]
+    ///
+    /// Returns whether the subscription existed.
+    pub fn unsubscribe(&self, subscription: SubscriptionId) -> bool {
+        self.executor
+            .transaction_manager()
+            .change_feed()
+            .unsubscribe(subscription)
+    }
+
+    /// Cumulative counters for every query run since startup or the last reset
+    ///
+    /// Counts queries run through [`QueryCoordinator::process_query`] and
+    /// its variants, including the ones that failed, with their latency.
+    pub fn metrics(&self) -> QueryMetrics {
+        QueryMetrics {
+            statements_planned: self.executor.statements_planned(),
+            ..self.metrics.snapshot()
+        }
+    }
+
+    /// Set every counter reported by [`QueryCoordinator::metrics`] back to zero
+    pub fn reset_metrics(&self) {
+        self.metrics.reset();
+        self.executor.reset_statements_planned();
+    }
+
+    /// Cap how many queries run at once, queuing the excess
+    ///
+    /// At most `max_concurrent` queries execute at a time (at least one);
+    /// up to `max_queued` more wait for a slot in [`QueryCoordinator::process_query`].
+    /// A query arriving when the queue is full fails immediately with an
+    /// `Overloaded` error. Replaces any previous limit.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use graphlite::QueryCoordinator;
+    /// let coordinator = QueryCoordinator::from_path("./mydb")
+    ///     .unwrap()
+    ///     .with_max_concurrency(4, 64);
+    /// ```
+    pub fn with_max_concurrency(
+        self: Arc<Self>,
+        max_concurrent: usize,
+        max_queued: usize,
+    ) -> Arc<Self> {
+        self.set_max_concurrency(Some((max_concurrent, max_queued)));
+        self
+    }
+
+    /// Change or remove the concurrency cap set by
+    /// [`QueryCoordinator::with_max_concurrency`]
+    ///
+    /// `limit` is `(max_concurrent, max_queued)`; `None` lets every query run
+    /// immediately (the default). Queries already running or queued keep the
+    /// limit they were admitted under.
+    pub fn set_max_concurrency(&self, limit: Option<(usize, usize)>) {
+        if let Ok(mut admission) = self.admission.write() {
+            *admission = limit.map(|(max_concurrent, max_queued)| {
+                Arc::new(QueryAdmission::new(max_concurrent, max_queued))
+            });
+        }
+    }
+
+    /// Limit how many queries per second a user may run
+    ///
+    /// Each user gets an independent token bucket that refills at
+    /// `queries_per_sec` and holds at most `burst` queries (at least one), so
+    /// short bursts are allowed while the average rate is capped. A query that
+    /// finds its user's bucket empty fails with a `RateLimited` error before
+    /// it runs. Replaces any previous limit for the user.
+    pub fn set_rate_limit(&self, username: &str, queries_per_sec: f64, burst: u32) {
+        self.rate_limiter
+            .set_limit(username, queries_per_sec, burst);
+    }
+
+    /// Remove the rate limit set for a user by [`QueryCoordinator::set_rate_limit`]
+    pub fn clear_rate_limit(&self, username: &str) {
+        self.rate_limiter.clear_limit(username);
+    }
+
+    /// Answer repeated MATCH queries from a cache of their result rows
+    ///
+    /// A query is answered from the cache when the same text (up to
+    /// whitespace) was run before by the same user, against the same graph,
+    /// with the same session parameters and settings. At most `capacity`
+    /// results are kept, evicting the least recently used, each for at most
+    /// `ttl` if given. Queries with list, map or other non-scalar parameters,
+    /// queries naming graphs with `FROM`, queries of graphs created with a
+    /// node expiry rule, queries calling a non-deterministic function such as
+    /// `rand()` or `now()` and queries returning no rows are never cached.
+    ///
+    /// Data modifications drop the cached results of the session's current
+    /// graph; every other statement that may change data, such as DDL,
+    /// procedure calls and `ROLLBACK`, drops them all. Hits and misses are
+    /// counted in [`QueryCoordinator::metrics`]. Replaces any previous cache
+    /// and its contents; caching is off by default.
+    pub fn set_result_cache(&self, capacity: usize, ttl: Option<Duration>) {
+        if let Ok(mut cache) = self.result_cache.write() {
+            *cache = Some(Arc::new(ResultCache::with_capacity(capacity, ttl)));
+        }
+    }
+
+    /// Turn off the cache enabled by [`QueryCoordinator::set_result_cache`]
+    pub fn disable_result_cache(&self) {
+        if let Ok(mut cache) = self.result_cache.write() {
+            *cache = None;
+        }
+    }
+
+    /// Limit how deeply expressions, label expressions and parenthesized
+    /// queries may nest in queries run through this coordinator
+    ///
+    /// A query nested deeper fails with an "expression nesting too deep"
+    /// parse error instead of overflowing the stack. The default is
+    /// [`DEFAULT_MAX_NESTING_DEPTH`], which fits a 2 MiB thread stack; raise
+    /// it only for threads with correspondingly larger stacks.
+    pub fn set_max_nesting_depth(&self, max_depth: usize) {
+        self.max_nesting_depth.store(max_depth, Ordering::Relaxed);
+    }
+
+    /// Nesting depth currently allowed by [`QueryCoordinator::set_max_nesting_depth`]
+    pub fn max_nesting_depth(&self) -> usize {
+        self.max_nesting_depth.load(Ordering::Relaxed)
+    }
+
+    /// Parse a query under this coordinator's nesting limit
+    fn parse(&self, query_text: &str) -> Result<crate::ast::Document, ParserError> {
+        parse_query_with_max_depth(query_text, self.max_nesting_depth())
+    }
+
+    /// Result cache key for a MATCH query run in `session`
+    ///
+    /// `None` if a session parameter cannot be part of a key, if the query
+    /// reads graphs other than the current one, whose writes would not
+    /// invalidate it, or if it calls a function whose result changes from
+    /// one run to the next.
+    fn result_cache_key(query_text: &str, session: &UserSession) -> Option<QueryCacheKey> {
+        let tokens = tokenize(query_text).ok()?;
+        if tokens.contains(&Token::From) {
+            return None;
+        }
+        let calls_non_deterministic = tokens.windows(2).any(|pair| match pair {
+            [Token::Identifier(name), Token::LeftParen] => NON_DETERMINISTIC_FUNCTIONS
+                .iter()
+                .any(|function| name.eq_ignore_ascii_case(function)),
+            _ => false,
+        });
+        if calls_non_deterministic {
+            return None;
+        }
+
+        let mut names: Vec<&String> = session.parameters.keys().collect();
+        names.sort();
+        let mut parameters = Vec::with_capacity(names.len() * 2);
+        for name in names {
+            parameters.push(CacheParameter::String(name.clone()));
+            parameters.push(CacheParameter::from_value(&session.parameters[name])?);
+        }
+
+        // Settings that change what a query returns
+        let user_context = format!(
+            "{}|{:?}|{:?}|{:?}|{}",
+            session.username,
+            session.current_timezone,
+            session.collation,
+            session.max_result_rows,
+            session.deterministic_order
+        );
+
+        Some(create_query_cache_key(
+            &normalize_query_text(query_text),
+            parameters,
+            0,
+            Some(user_context),
+            session.current_graph.clone(),
+        ))
+    }
+
+    /// Drop the cached results a statement may have made stale
+    fn invalidate_cached_results(
+        &self,
+        cache: &ResultCache,
+        query_type: &QueryType,
+        current_graph: Option<&str>,
+    ) {
+        let invalidated = match query_type {
+            QueryType::Match
+            | QueryType::Select
+            | QueryType::SessionSet
+            | QueryType::SessionSetGraph
+            | QueryType::SessionSetSchema
+            | QueryType::SessionReset
+            | QueryType::SessionClose
+            | QueryType::StartTransaction
+            | QueryType::Commit
+            | QueryType::SetTransactionCharacteristics => return,
+            QueryType::Insert
+            | QueryType::Set
+            | QueryType::Remove
+            | QueryType::Delete
+            | QueryType::MatchInsert
+            | QueryType::MatchSet
+            | QueryType::MatchRemove
+            | QueryType::MatchDelete => match current_graph {
+                Some(graph) => cache.invalidate_graph(graph),
+                None => cache.clear(),
+            },
+            _ => cache.clear(),
+        };
+        self.metrics.record_cache_invalidations(invalidated);
+    }
+
+    /// Roll back transactions that run no statement for `timeout`
+    ///
+    /// An idle transaction is rolled back the next time its session runs a
+    /// statement, which then fails with a timeout error. Transactions in
+    /// abandoned sessions are only rolled back by
+    /// [`QueryCoordinator::rollback_idle_transactions`]. Pass `None` to
+    /// disable the timeout (the default).
+    pub fn set_transaction_timeout(&self, timeout: Option<Duration>) {
+        self.session_provider
+            .get_transaction_manager()
+            .set_idle_timeout(timeout);
+    }
+
+    /// Roll back every open transaction that has exceeded the idle timeout
+    ///
+    /// Returns the number of transactions rolled back. Intended to be called
+    /// periodically so abandoned sessions do not hold on to transactions.
+    pub fn rollback_idle_transactions(&self) -> usize {
+        let storage = self.session_provider.get_storage_manager();
+        let catalog = self.session_provider.get_catalog_manager();
+        self.session_provider
+            .list_sessions()
+            .iter()
+            .filter_map(|session_id| self.session_provider.get_session(session_id))
+            .filter_map(|session| {
+                session
+                    .read()
+                    .ok()
+                    .map(|session| session.transaction_state.clone())
+            })
+            .filter(|transaction_state| {
+                transaction_state
+                    .rollback_if_idle(&storage, Some(&catalog))
+                    .unwrap_or_else(|e| {
+                        log::warn!("Failed to roll back idle transaction: {}", e);
+                        false
+                    })
+            })
+            .count()
+    }
+
+    /// Delete expired nodes from graphs created with a node expiry rule
+    ///
+    /// A graph created with `WITH (ttl_label = '...', ttl_property = '...')`
+    /// hides expired nodes from queries straight away; this removes them and
+    /// their edges from storage. Returns the number of nodes deleted. Intended
+    /// to be called periodically, like [`Self::rollback_idle_transactions`].
+    pub fn purge_expired_nodes(&self) -> Result<usize, String> {
+        self.executor
+            .purge_expired_nodes()
+            .map_err(|e| e.to_string())
+    }
+
+    /// Limit how many rows a query in this session may return
+    ///
+    /// A query whose result exceeds the limit fails with a `ResultTooLarge`
+    /// error instead of returning rows. Unlike LIMIT this does not change what
+    /// a query means; it only guards against unexpectedly large results. Pass
+    /// `None` to remove the limit (the default).
+    pub fn set_max_result_rows(
+        &self,
+        session_id: &str,
+        max_rows: Option<usize>,
+    ) -> Result<(), String> {
+        let session = self
+            .session_provider
+            .get_session(session_id)
+            .ok_or_else(|| format!("Session not found: {}", session_id))?;
+        let mut session = session
+            .write()
+            .map_err(|e| format!("Failed to acquire session write lock: {}", e))?;
+        session.max_result_rows = max_rows;
+        Ok(())
+    }
+
+    /// Keep INSERTed nodes that are identical to an existing node
+    ///
+    /// Nodes are identified by a hash of their labels and properties, so by
+    /// default inserting the same node twice keeps only one copy and reports
+    /// a duplicate warning. With this enabled, the second copy is stored
+    /// under the content hash plus a unique suffix. ON CONFLICT clauses still
+    /// take precedence. Off by default.
+    pub fn set_allow_duplicate_nodes(&self, session_id: &str, enabled: bool) -> Result<(), String> {
+        let session = self
+            .session_provider
+            .get_session(session_id)
+            .ok_or_else(|| format!("Session not found: {}", session_id))?;
+        let mut session = session
+            .write()
+            .map_err(|e| format!("Failed to acquire session write lock: {}", e))?;
+        session.allow_duplicate_nodes = enabled;
+        Ok(())
+    }
+
+    /// Return rows in a reproducible order for queries without ORDER BY
+    ///
+    /// Storage is iterated in an unspecified order that can change when the
+    /// database is reopened. With this enabled, node scans and edge expansions
+    /// run in ID order and aggregation groups are emitted in key order, so the
+    /// same query over the same data returns its rows in the same order. This
+    /// costs a sort per scan and is meant for tests and debugging. Off by
+    /// default.
+    pub fn set_deterministic_order(&self, session_id: &str, enabled: bool) -> Result<(), String> {
+        let session = self
+            .session_provider
+            .get_session(session_id)
+            .ok_or_else(|| format!("Session not found: {}", session_id))?;
+        let mut session = session
+            .write()
+            .map_err(|e| format!("Failed to acquire session write lock: {}", e))?;
+        session.deterministic_order = enabled;
+        Ok(())
+    }
+
+    /// Lock a graph for coarse-grained coordination
+    ///
+    /// A shared lock blocks writes from other threads; an exclusive lock also
+    /// blocks their reads. Statements on the locking thread are not blocked.
+    /// Waits up to `timeout` (forever if `None`) and fails with a
+    /// `LockTimeout` error if the lock could not be taken in time. A zero
+    /// timeout fails with a `Conflict` error if the graph is busy, as does a
+    /// wait that would deadlock with another thread. The lock is released
+    /// when the returned guard is dropped.
+    pub fn lock_graph(
+        &self,
+        graph_path: &str,
+        mode: LockMode,
+        timeout: Option<Duration>,
+    ) -> Result<GraphLockGuard, QueryError> {
+        self.executor
+            .graph_locks()
+            .lock(graph_path, mode, timeout)
+            .map_err(QueryError::from)
+    }
+
+    /// Flush every pending write to disk
+    ///
+    /// Required for durability under [`FlushPolicy::Manual`]; under the other
+    /// policies it only makes writes durable sooner.
+    pub fn flush(&self) -> Result<(), String> {
+        self.session_provider
+            .get_storage_manager()
+            .flush()
+            .map_err(|e| format!("Failed to flush storage: {}", e))
+    }
+
+    /// Compact storage, dropping what deleted graphs left behind
+    ///
+    /// Safe to run while queries execute. Reports the bytes on disk before
+    /// and after, and how many were reclaimed.
+    pub fn compact(&self) -> Result<CompactionReport, String> {
+        self.session_provider
+            .get_storage_manager()
+            .compact()
+            .map_err(|e| format!("Failed to compact storage: {}", e))
+    }
+
+    /// Get the application-defined schema version of this database
+    ///
+    /// The version is an opaque number owned by the application, for example
+    /// the index of the last migration it applied. It is 0 until first set.
+    pub fn schema_version(&self) -> Result<u64, String> {
+        self.session_provider
+            .get_storage_manager()
+            .schema_version()
+            .map_err(|e| format!("Failed to read schema version: {}", e))
+    }
+
+    /// Persist the application-defined schema version of this database
+    ///
+    /// The new version is flushed to storage before this returns, so it
+    /// survives a restart.
+    pub fn set_schema_version(&self, version: u64) -> Result<(), String> {
+        self.session_provider
+            .get_storage_manager()
+            .set_schema_version(version)
+            .map_err(|e| format!("Failed to set schema version: {}", e))
+    }
+
+    /// Turn auto-commit on or off for a session
+    ///
+    /// With auto-commit off, the first write opens an implicit transaction
+    /// that later statements join until COMMIT or ROLLBACK. Turning auto-commit
+    /// back on commits any transaction that is still open.
+    pub fn set_autocommit(&self, session_id: &str, enabled: bool) -> Result<(), String> {
+        let transaction_state = self.transaction_state(session_id)?;
+        transaction_state
+            .set_auto_commit(enabled)
+            .map_err(|e| e.to_string())?;
+        if enabled
+            && transaction_state
+                .has_active_transaction()
+                .map_err(|e| e.to_string())?
+        {
+            transaction_state
+                .commit_transaction()
+                .map_err(|e| format!("Failed to commit open transaction: {}", e))?;
+        }
+        Ok(())
+    }
+
+    /// Choose whether a failed statement rolls back the session's open transaction
+    ///
+    /// Off by default: the failed statement changes nothing and the
+    /// transaction stays open, so the caller can retry or roll back.
+    pub fn set_rollback_on_error(&self, session_id: &str, enabled: bool) -> Result<(), String> {
+        self.transaction_state(session_id)?
+            .set_rollback_on_error(enabled)
+            .map_err(|e| e.to_string())
+    }
+
+    /// Check whether a session still exists and has not expired
+    ///
+    /// A session stops being valid once it is closed, invalidated or cleaned
+    /// up after being idle for too long.
+    pub fn is_session_valid(&self, session_id: &str) -> bool {
+        self.session_provider
+            .get_session(session_id)
+            .and_then(|session| {
+                session
+                    .read()
+                    .ok()
+                    .map(|session| session.active && !session.is_expired())
+            })
+            .unwrap_or(false)
+    }
+
+    /// Get the graph a session is using, as set by `SESSION SET GRAPH`
+    ///
+    /// Returns the full `/schema/graph` path, or `None` if no graph is set.
+    pub fn current_graph(&self, session_id: &str) -> Result<Option<String>, String> {
+        let session = self
+            .session_provider
+            .get_session(session_id)
+            .ok_or_else(|| format!("Session not found: {}", session_id))?;
+        let session = session
+            .read()
+            .map_err(|e| format!("Failed to acquire session read lock: {}", e))?;
+        Ok(session.current_graph.clone())
+    }
+
+    /// Get the schema a session is using, as set by `SESSION SET SCHEMA`
+    ///
+    /// Returns the `/schema` path, or `None` if no schema is set.
+    pub fn current_schema(&self, session_id: &str) -> Result<Option<String>, String> {
+        let session = self
+            .session_provider
+            .get_session(session_id)
+            .ok_or_else(|| format!("Session not found: {}", session_id))?;
+        let session = session
+            .read()
+            .map_err(|e| format!("Failed to acquire session read lock: {}", e))?;
+        Ok(session.current_schema.clone())
+    }
+
+    /// Get the transaction state of a session
+    fn transaction_state(
+        &self,
+        session_id: &str,
+    ) -> Result<Arc<crate::session::SessionTransactionState>, String> {
+        let session = self
+            .session_provider
+            .get_session(session_id)
+            .ok_or_else(|| format!("Session not found: {}", session_id))?;
+        let session = session
+            .read()
+            .map_err(|e| format!("Failed to acquire session read lock: {}", e))?;
+        Ok(session.transaction_state.clone())
+    }
+
     /// Handle session-modifying results (SET GRAPH, SET SCHEMA)
     fn handle_session_result(
         &self,
@@ -360,6 +1478,59 @@ impl QueryCoordinator {
 
                 Ok(())
             }
+            crate::exec::SessionResult::SetCollation { name, collation } => {
+                let session_arc = self
+                    .session_provider
+                    .get_session(session_id)
+                    .ok_or_else(|| format!("Session not found: {}", session_id))?;
+
+                let mut session = session_arc
+                    .write()
+                    .map_err(|e| format!("Failed to acquire session write lock: {}", e))?;
+
+                session.collation = *collation;
+                log::debug!("Session {} collation set to: {}", session_id, name);
+
+                Ok(())
+            }
+            crate::exec::SessionResult::SetTimeZone { timezone } => {
+                let session_arc = self
+                    .session_provider
+                    .get_session(session_id)
+                    .ok_or_else(|| format!("Session not found: {}", session_id))?;
+
+                let mut session = session_arc
+                    .write()
+                    .map_err(|e| format!("Failed to acquire session write lock: {}", e))?;
+
+                session.set_current_timezone(Some(timezone.clone()));
+                log::debug!("Session {} timezone set to: {}", session_id, timezone);
+
+                Ok(())
+            }
+            crate::exec::SessionResult::SetParameter {
+                name,
+                value,
+                if_not_exists,
+            } => {
+                let session_arc = self
+                    .session_provider
+                    .get_session(session_id)
+                    .ok_or_else(|| format!("Session not found: {}", session_id))?;
+
+                let mut session = session_arc
+                    .write()
+                    .map_err(|e| format!("Failed to acquire session write lock: {}", e))?;
+
+                if *if_not_exists && session.get_parameter(name).is_some() {
+                    return Ok(());
+                }
+
+                session.set_parameter(name.clone(), value.clone());
+                log::debug!("Session {} parameter ${} set", session_id, name);
+
+                Ok(())
+            }
             _ => Ok(()), // Other session results don't need special handling
         }
     }
@@ -574,6 +1745,22 @@ impl QueryCoordinator {
         }
     }
 
+    /// Check that storage, catalog and session management are responsive
+    ///
+    /// Cheap enough to call from a liveness or readiness probe. The storage
+    /// check writes and removes a reserved key; nothing else is modified.
+    pub fn health_check(&self) -> HealthReport {
+        let storage = self.session_provider.get_storage_manager();
+        let catalog = self.session_provider.get_catalog_manager();
+        HealthReport {
+            components: vec![
+                health::check_storage(&storage),
+                health::check_catalog(&catalog, &storage),
+                health::check_sessions(self.session_provider.as_ref()),
+            ],
+        }
+    }
+
     /// Get the executor reference
     pub fn executor(&self) -> &Arc<QueryExecutor> {
         &self.executor
@@ -603,7 +1790,9 @@ impl QueryCoordinator {
     /// ```
     pub fn validate_query(&self, query: &str) -> Result<(), String> {
         // Parse the query
-        let document = parse_query(query).map_err(|e| format!("Parse error: {:?}", e))?;
+        let document = self
+            .parse(query)
+            .map_err(|e| format!("Parse error: {:?}", e))?;
 
         // Validate the parsed query (pass false for has_graph_context since we're just validating syntax)
         crate::ast::validator::validate_query(&document, false)
@@ -612,6 +1801,41 @@ impl QueryCoordinator {
         Ok(())
     }
 
+    /// Validate a query and report every problem found, without executing it
+    ///
+    /// Unlike `validate_query`, which stops at the first failure, this collects
+    /// all validation errors (unknown variables, GROUP BY / HAVING misuse, type
+    /// errors) with the line and column where each starts. A query that fails
+    /// to parse yields a single syntax error.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use graphlite::QueryCoordinator;
+    /// # let coordinator = QueryCoordinator::from_path("./mydb").unwrap();
+    /// for error in coordinator.validate("MATCH (n) RETURN m") {
+    ///     if let Some(location) = &error.location {
+    ///         println!("{}:{}: {}", location.line, location.column, error.message);
+    ///     }
+    /// }
+    /// ```
+    pub fn validate(&self, query: &str) -> Vec<crate::ValidationError> {
+        let document = match self.parse(query) {
+            Ok(document) => document,
+            Err(e) => {
+                return vec![crate::ValidationError {
+                    message: format!("Parse error: {}", e),
+                    location: e.location().cloned(),
+                    error_type: crate::ValidationErrorType::Syntax,
+                }]
+            }
+        };
+
+        match crate::ast::validator::validate_query(&document, false) {
+            Ok(()) => Vec::new(),
+            Err(errors) => errors,
+        }
+    }
+
     /// Check if a query is syntactically valid
     ///
     /// This is a convenience method that returns a boolean instead of an error.
@@ -659,10 +1883,17 @@ impl QueryCoordinator {
     /// ```
     pub fn analyze_query(&self, query: &str) -> Result<QueryInfo, String> {
         // Parse the query
-        let document = parse_query(query).map_err(|e| format!("Parse error: {:?}", e))?;
+        let document = self
+            .parse(query)
+            .map_err(|e| format!("Parse error: {:?}", e))?;
+
+        Ok(Self::analyze_statement(&document.statement))
+    }
 
+    /// Derive query metadata from a parsed statement
+    fn analyze_statement(statement: &crate::ast::Statement) -> QueryInfo {
         // Analyze the statement type
-        let query_type = match &document.statement {
+        let query_type = match statement {
             crate::ast::Statement::Query(_) => QueryType::Match,
             crate::ast::Statement::Select(_) => QueryType::Select,
             crate::ast::Statement::Call(_) => QueryType::Call,
@@ -686,6 +1917,7 @@ impl QueryCoordinator {
                     CatalogStatement::RevokeRole { .. } => QueryType::RevokeRole,
                     CatalogStatement::ClearGraph { .. } => QueryType::ClearGraph,
                     CatalogStatement::TruncateGraph { .. } => QueryType::TruncateGraph,
+                    CatalogStatement::CopyGraph(_) => QueryType::CopyGraph,
                 }
             }
             crate::ast::Statement::DataStatement(data) => {
@@ -732,6 +1964,8 @@ impl QueryCoordinator {
                     IndexStatement::Alter(_) => QueryType::AlterIndex,
                     IndexStatement::Optimize(_) => QueryType::OptimizeIndex,
                     IndexStatement::Reindex(_) => QueryType::ReindexIndex,
+                    IndexStatement::CreateConstraint(_) => QueryType::CreateConstraint,
+                    IndexStatement::DropConstraint(_) => QueryType::DropConstraint,
                 }
             }
             crate::ast::Statement::Declare(_) => QueryType::Declare,
@@ -741,21 +1975,43 @@ impl QueryCoordinator {
             crate::ast::Statement::ProcedureBody(_) => QueryType::ProcedureBody,
         };
 
-        // Determine if query is read-only
-        let is_read_only = matches!(
-            query_type,
-            QueryType::Match
-                | QueryType::Select
-                | QueryType::Call
-                | QueryType::SessionSetGraph
-                | QueryType::SessionSetSchema
-                | QueryType::SessionReset
-        );
+        // Determine if query is read-only; session statements change the
+        // session, and only allowlisted procedures may be called
+        let is_read_only = match statement {
+            crate::ast::Statement::Call(call) => {
+                crate::catalog::system_procedures::is_read_only_procedure(&call.procedure_name)
+            }
+            _ => matches!(query_type, QueryType::Match | QueryType::Select),
+        };
+
+        let is_ordered = match statement {
+            crate::ast::Statement::Query(query) => Self::has_final_order_by(query),
+            crate::ast::Statement::Select(select) => select.order_clause.is_some(),
+            _ => false,
+        };
 
-        Ok(QueryInfo {
+        QueryInfo {
             query_type,
             is_read_only,
-        })
+            is_ordered,
+        }
+    }
+
+    /// Check whether a query orders its final result rows
+    fn has_final_order_by(query: &crate::ast::Query) -> bool {
+        use crate::ast::Query;
+        match query {
+            Query::Basic(basic) => basic.order_clause.is_some(),
+            Query::SetOperation(set_op) => set_op.order_clause.is_some(),
+            Query::Limited {
+                query,
+                order_clause,
+                ..
+            } => order_clause.is_some() || Self::has_final_order_by(query),
+            Query::WithQuery(with_query) => with_query.order_clause.is_some(),
+            Query::Return(return_query) => return_query.order_clause.is_some(),
+            _ => false,
+        }
     }
 
     /// Explain the query execution plan without executing the query
@@ -768,7 +2024,7 @@ impl QueryCoordinator {
     ///
     /// # Returns
     /// * `Ok(QueryPlan)` - Detailed query execution plan
-    /// * `Err(String)` - Error if query cannot be planned
+    /// * `Err(QueryError)` - Error if query cannot be planned
     ///
     /// # Example
     /// ```no_run
@@ -780,9 +2036,34 @@ impl QueryCoordinator {
     /// println!("Query Plan:\n{}", plan.format_tree());
     /// println!("Estimated cost: {}", plan.estimated_cost);
     /// ```
-    pub fn explain_query(&self, query: &str) -> Result<QueryPlan, String> {
+    ///
+    /// Without a session there is no current graph, so `USING INDEX` hints
+    /// find no index; use [`explain_query_in_session`](Self::explain_query_in_session)
+    /// to plan against a session's graph.
+    pub fn explain_query(&self, query: &str) -> Result<QueryPlan, QueryError> {
+        self.explain_query_with_graph(query, None)
+    }
+
+    /// Explain the query execution plan as a session would run it
+    ///
+    /// Like [`explain_query`](Self::explain_query), but `USING INDEX` hints are
+    /// checked against the indexes of the session's current graph.
+    pub fn explain_query_in_session(
+        &self,
+        query: &str,
+        session_id: &str,
+    ) -> Result<QueryPlan, QueryError> {
+        let graph_path = self.session_graph(session_id)?;
+        self.explain_query_with_graph(query, graph_path)
+    }
+
+    fn explain_query_with_graph(
+        &self,
+        query: &str,
+        graph_path: Option<String>,
+    ) -> Result<QueryPlan, QueryError> {
         // Parse the query
-        let document = parse_query(query).map_err(|e| format!("Parse error: {:?}", e))?;
+        let document = self.parse(query).map_err(QueryError::parse)?;
 
         // Only MATCH/SELECT queries can be explained (not DDL/DML)
         match &document.statement {
@@ -790,21 +2071,275 @@ impl QueryCoordinator {
                 // Good - these can be explained
             }
             _ => {
-                return Err("EXPLAIN is only supported for MATCH and SELECT queries".to_string());
+                return Err("EXPLAIN is only supported for MATCH and SELECT queries"
+                    .to_string()
+                    .into());
             }
         }
 
-        // Create a query planner
-        let mut planner = crate::plan::optimizer::QueryPlanner::new();
+        Ok(self.plan_with_trace(&document, graph_path)?)
+    }
+
+    /// Current graph of a session
+    fn session_graph(&self, session_id: &str) -> Result<Option<String>, QueryError> {
+        let session = self
+            .session_provider
+            .get_session(session_id)
+            .ok_or_else(|| QueryError::session_not_found(session_id))?;
+        let current_graph = session
+            .read()
+            .ok()
+            .and_then(|session| session.current_graph.clone());
+        Ok(current_graph)
+    }
+
+    /// Execute a query and measure every physical operator in its plan
+    ///
+    /// Unlike [`explain_query`](Self::explain_query), this runs the query in the
+    /// given session, so it has the same side effects and cost as running it
+    /// normally. Each operator reports its wall-clock time and actual output row
+    /// count next to the planner's estimates.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use graphlite::QueryCoordinator;
+    /// # let coordinator = QueryCoordinator::from_path("./mydb").unwrap();
+    /// # let session_id = coordinator.create_simple_session("admin").unwrap();
+    /// let analyzed = coordinator
+    ///     .explain_analyze("MATCH (n:Person) WHERE n.age > 30 RETURN n.name", &session_id)
+    ///     .expect("Failed to analyze query");
+    ///
+    /// println!("{}", analyzed.format_tree());
+    /// ```
+    pub fn explain_analyze(
+        &self,
+        query: &str,
+        session_id: &str,
+    ) -> Result<AnalyzedQueryPlan, QueryError> {
+        let document = self.parse(query).map_err(QueryError::parse)?;
+
+        // Only queries go through the physical planner, so only they can be measured
+        if !matches!(document.statement, crate::ast::Statement::Query(_)) {
+            return Err("EXPLAIN ANALYZE is only supported for MATCH queries"
+                .to_string()
+                .into());
+        }
+
+        let plan = self.plan_with_trace(&document, self.session_graph(session_id)?)?;
+
+        let tracer = Arc::new(Mutex::new(ExecutionTracer::new()));
+        let request = ExecutionRequest::new(document.statement)
+            .with_session(self.session_provider.get_session(session_id))
+            .with_query_text(Some(query.to_string()))
+            .with_physical_plan(Some(plan.physical_plan.clone()))
+            .with_execution_tracer(tracer.clone());
+
+        let started = Instant::now();
+        let result = self
+            .executor
+            .execute_query(request)
+            .map_err(QueryError::from)?;
+        let total_execution_time = started.elapsed();
+
+        let root = tracer
+            .lock()
+            .map_err(|_| "Failed to acquire execution tracer lock".to_string())?
+            .take_root()
+            .ok_or_else(|| "Query did not execute any plan operators".to_string())?;
+
+        Ok(AnalyzedQueryPlan {
+            plan,
+            root,
+            total_execution_time,
+            row_count: result.rows.len(),
+        })
+    }
+
+    /// Report what a MATCH ... DELETE, SET or REMOVE statement would change, without changing it
+    ///
+    /// Runs the statement's MATCH and WHERE as a read query in the given
+    /// session and counts the distinct nodes and edges its write targets bind
+    /// to. Nothing is written. Edges that DETACH DELETE would remove along
+    /// with a node are not counted.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use graphlite::QueryCoordinator;
+    /// # let coordinator = QueryCoordinator::from_path("./mydb").unwrap();
+    /// # let session_id = coordinator.create_simple_session("admin").unwrap();
+    /// let report = coordinator
+    ///     .dry_run("MATCH (n:Temp) DELETE n", &session_id)
+    ///     .expect("Failed to dry-run statement");
+    ///
+    /// println!("Would delete {} nodes", report.affected);
+    /// ```
+    pub fn dry_run(&self, query: &str, session_id: &str) -> Result<DryRunReport, QueryError> {
+        use crate::ast::{DataStatement, Expression, RemoveItem, SetItem, Statement};
+
+        let document = self.parse(query).map_err(QueryError::parse)?;
+
+        let (match_clause, with_clause, where_clause, targets) = match document.statement {
+            Statement::DataStatement(DataStatement::MatchDelete(stmt)) => {
+                let targets = stmt
+                    .expressions
+                    .iter()
+                    .filter_map(|expr| match expr {
+                        Expression::Variable(var) => Some(var.name.clone()),
+                        _ => None,
+                    })
+                    .collect::<Vec<_>>();
+                (
+                    stmt.match_clause,
+                    stmt.with_clause,
+                    stmt.where_clause,
+                    targets,
+                )
+            }
+            Statement::DataStatement(DataStatement::MatchSet(stmt)) => {
+                let targets = stmt
+                    .items
+                    .iter()
+                    .map(|item| match item {
+                        SetItem::Property { property, .. } => property.object.clone(),
+                        SetItem::Variable { variable, .. }
+                        | SetItem::MergeProperties { variable, .. }
+                        | SetItem::Label { variable, .. } => variable.clone(),
+                    })
+                    .collect::<Vec<_>>();
+                (
+                    stmt.match_clause,
+                    stmt.with_clause,
+                    stmt.where_clause,
+                    targets,
+                )
+            }
+            Statement::DataStatement(DataStatement::MatchRemove(stmt)) => {
+                let targets = stmt
+                    .items
+                    .iter()
+                    .map(|item| match item {
+                        RemoveItem::Property(property) => property.object.clone(),
+                        RemoveItem::Label { variable, .. } | RemoveItem::Variable(variable) => {
+                            variable.clone()
+                        }
+                    })
+                    .collect::<Vec<_>>();
+                (
+                    stmt.match_clause,
+                    stmt.with_clause,
+                    stmt.where_clause,
+                    targets,
+                )
+            }
+            _ => {
+                return Err(
+                    "Dry run is only supported for MATCH ... DELETE, SET and REMOVE statements"
+                        .to_string()
+                        .into(),
+                )
+            }
+        };
+
+        if with_clause.is_some() {
+            return Err("Dry run does not support WITH in write statements"
+                .to_string()
+                .into());
+        }
+
+        let mut target_names: Vec<String> = Vec::new();
+        for target in targets {
+            if !target_names.contains(&target) {
+                target_names.push(target);
+            }
+        }
+        if target_names.is_empty() {
+            return Err("Statement has no variables to write to".to_string().into());
+        }
+
+        // Return the write targets of every match instead of writing to them
+        let location = crate::ast::Location::default();
+        let read_query = crate::ast::BasicQuery {
+            match_clause,
+            where_clause,
+            return_clause: crate::ast::ReturnClause {
+                distinct: crate::ast::DistinctQualifier::None,
+                items: target_names
+                    .iter()
+                    .map(|name| crate::ast::ReturnItem {
+                        expression: Expression::Variable(crate::ast::Variable {
+                            name: name.clone(),
+                            location: location.clone(),
+                        }),
+                        alias: None,
+                        location: location.clone(),
+                    })
+                    .collect(),
+                location: location.clone(),
+            },
+            group_clause: None,
+            having_clause: None,
+            order_clause: None,
+            limit_clause: None,
+            location,
+        };
+
+        let request = ExecutionRequest::new(Statement::Query(crate::ast::Query::Basic(Box::new(
+            read_query,
+        ))))
+        .with_session(self.session_provider.get_session(session_id));
+        let result = self
+            .executor
+            .execute_query(request)
+            .map_err(QueryError::from)?;
+
+        // A node or edge matched by several rows is only written once
+        let mut entities = std::collections::HashSet::new();
+        for row in &result.rows {
+            for name in &target_names {
+                match row.values.get(name) {
+                    Some(crate::storage::Value::Node(node)) => {
+                        entities.insert((false, node.id.clone()));
+                    }
+                    Some(crate::storage::Value::Edge(edge)) => {
+                        entities.insert((true, edge.id.clone()));
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        Ok(DryRunReport {
+            matched_rows: result.rows.len(),
+            affected: entities.len(),
+        })
+    }
+
+    /// Plan a parsed query, recording the planning steps
+    fn plan_with_trace(
+        &self,
+        document: &crate::ast::Document,
+        graph_path: Option<String>,
+    ) -> Result<QueryPlan, String> {
+        // Create a query planner that can check hinted indexes of the graph
+        let mut planner = crate::plan::optimizer::QueryPlanner::new().with_graph(graph_path);
+        if let Some(index_manager) = self.executor.storage().get_index_manager() {
+            planner = planner.with_index_manager(index_manager.clone());
+        }
 
         // Plan the query with tracing
         let trace = planner
-            .plan_query_with_trace(&document)
+            .plan_query_with_trace(document)
             .map_err(|e| format!("Planning error: {:?}", e))?;
 
         // Use the cost and row estimates from the physical plan
         let estimated_cost = trace.physical_plan.estimated_cost;
         let estimated_rows = trace.physical_plan.estimated_rows;
+        let operators = planner
+            .get_cost_model()
+            .explain_node(&trace.physical_plan.root, planner.get_statistics());
+        for warning in planner.warnings() {
+            log::warn!("{}", warning);
+        }
 
         Ok(QueryPlan {
             logical_plan: trace.logical_plan,
@@ -813,6 +2348,8 @@ impl QueryCoordinator {
             total_planning_time_ms: trace.total_duration.as_millis() as u64,
             estimated_cost,
             estimated_rows,
+            operators,
+            warnings: planner.warnings().to_vec(),
         })
     }
 }
@@ -832,12 +2369,26 @@ pub struct QueryPlan {
     pub estimated_cost: f64,
     /// Estimated number of rows returned
     pub estimated_rows: usize,
+    /// Operator tree annotated with the cost model's estimates
+    pub operators: EstimatedNode,
+    /// Planner warnings, such as a USING INDEX hint naming a missing index
+    pub warnings: Vec<String>,
 }
 
 impl QueryPlan {
-    /// Format the physical plan as a tree for display
+    /// Format the operator tree, with each operator's estimated rows and cost
     pub fn format_tree(&self) -> String {
-        format!("{:#?}", self.physical_plan.root)
+        self.operators.format_tree()
+    }
+
+    /// Find the first operator with the given name, e.g. "Filter"
+    pub fn operator(&self, name: &str) -> Option<&EstimatedNode> {
+        self.operators.find(name)
+    }
+
+    /// Render the operator tree, with the cost model's estimates, as Graphviz DOT
+    pub fn to_dot(&self) -> String {
+        crate::plan::trace::plan_to_dot(&self.physical_plan.root, Some(&self.operators))
     }
 
     /// Get a summary of the plan
@@ -849,17 +2400,58 @@ impl QueryPlan {
     }
 }
 
+/// What a dry-run write statement would have changed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DryRunReport {
+    /// Number of rows the statement's MATCH produced
+    pub matched_rows: usize,
+    /// Number of distinct nodes and edges the statement would write to
+    pub affected: usize,
+}
+
+/// Query plan with measured per-operator execution statistics
+#[derive(Debug, Clone)]
+pub struct AnalyzedQueryPlan {
+    /// The plan that was executed, with the planner's estimates
+    pub plan: QueryPlan,
+    /// Measured operator tree, rooted at the plan's top operator
+    pub root: AnalyzedNode,
+    /// Total time spent executing the query
+    pub total_execution_time: Duration,
+    /// Number of rows the query returned
+    pub row_count: usize,
+}
+
+impl AnalyzedQueryPlan {
+    /// Find the first measured operator with the given name, e.g. "Filter"
+    pub fn operator(&self, name: &str) -> Option<&AnalyzedNode> {
+        self.root.find(name)
+    }
+
+    /// Format the measured operator tree for display
+    pub fn format_tree(&self) -> String {
+        format!(
+            "{}\nExecution time: {:.3}ms | Rows: {}\n",
+            self.root.format_tree(),
+            self.total_execution_time.as_secs_f64() * 1000.0,
+            self.row_count
+        )
+    }
+}
+
 /// Information about a parsed query
 #[derive(Debug, Clone)]
 pub struct QueryInfo {
     /// The type of query operation
     pub query_type: QueryType,
-    /// Whether the query only reads data (no modifications)
+    /// Whether the query only reads data (no data, catalog or session changes)
     pub is_read_only: bool,
+    /// Whether the query's final result has an ORDER BY, so its row order is defined
+    pub is_ordered: bool,
 }
 
 /// Types of query operations
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum QueryType {
     // Read operations
     Match,
@@ -884,11 +2476,14 @@ pub enum QueryType {
     RevokeRole,
     ClearGraph,
     TruncateGraph,
+    CopyGraph,
     CreateIndex,
     DropIndex,
     AlterIndex,
     OptimizeIndex,
     ReindexIndex,
+    CreateConstraint,
+    DropConstraint,
 
     // DML operations
     Insert,
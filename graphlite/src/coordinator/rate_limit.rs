@@ -0,0 +1,99 @@
+// Copyright (c) 2024-2025 DeepGraph Inc.
+// SPDX-License-Identifier: Apache-2.0
+//
+//! Per-user query rate limiting
+//!
+//! Each limited user has a token bucket that refills at a steady rate up to a
+//! burst size. Every query takes one token; a query that finds the bucket
+//! empty is rejected with a `RateLimited` error. Users without a configured
+//! limit are never throttled, and users never share a bucket.
+
+use crate::exec::ExecutionError;
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::Instant;
+
+/// Token buckets keyed by username
+#[derive(Default)]
+pub(crate) struct RateLimiter {
+    buckets: Mutex<HashMap<String, TokenBucket>>,
+}
+
+struct TokenBucket {
+    /// Tokens added per second
+    rate: f64,
+    /// Maximum number of tokens the bucket holds
+    burst: f64,
+    tokens: f64,
+    refilled_at: Instant,
+}
+
+impl TokenBucket {
+    fn new(rate: f64, burst: u32) -> Self {
+        let burst = f64::from(burst.max(1));
+        Self {
+            rate,
+            burst,
+            tokens: burst,
+            refilled_at: Instant::now(),
+        }
+    }
+
+    /// Refill for the time elapsed since the last call, then take one token
+    fn try_take(&mut self, now: Instant) -> bool {
+        let elapsed = now.saturating_duration_since(self.refilled_at);
+        self.tokens = (self.tokens + elapsed.as_secs_f64() * self.rate).min(self.burst);
+        self.refilled_at = now;
+
+        if self.tokens >= 1.0 {
+            self.tokens -= 1.0;
+            true
+        } else {
+            false
+        }
+    }
+}
+
+impl RateLimiter {
+    /// Limit `username` to `queries_per_sec` on average, allowing bursts of
+    /// up to `burst` queries (at least one). Starts with a full bucket and
+    /// replaces any previous limit for the user.
+    pub(crate) fn set_limit(&self, username: &str, queries_per_sec: f64, burst: u32) {
+        if let Ok(mut buckets) = self.buckets.lock() {
+            buckets.insert(
+                username.to_string(),
+                TokenBucket::new(queries_per_sec.max(0.0), burst),
+            );
+        }
+    }
+
+    /// Remove the limit for `username`
+    pub(crate) fn clear_limit(&self, username: &str) {
+        if let Ok(mut buckets) = self.buckets.lock() {
+            buckets.remove(username);
+        }
+    }
+
+    /// Take a token for one query by `username`
+    ///
+    /// Fails with `ExecutionError::RateLimited` if the user's bucket is empty.
+    pub(crate) fn check(&self, username: &str) -> Result<(), ExecutionError> {
+        let mut buckets = self
+            .buckets
+            .lock()
+            .map_err(|_| ExecutionError::RuntimeError("Rate limiter lock poisoned".to_string()))?;
+        match buckets.get_mut(username) {
+            Some(bucket) => {
+                if bucket.try_take(Instant::now()) {
+                    Ok(())
+                } else {
+                    Err(ExecutionError::RateLimited(format!(
+                        "user '{}' exceeded {} queries per second",
+                        username, bucket.rate
+                    )))
+                }
+            }
+            None => Ok(()),
+        }
+    }
+}
@@ -0,0 +1,104 @@
+// Copyright (c) 2024-2025 DeepGraph Inc.
+// SPDX-License-Identifier: Apache-2.0
+//
+//! String collations for ORDER BY and comparisons
+//!
+//! A session compares strings by Unicode codepoint unless it selects another
+//! collation with `SESSION SET COLLATION '<name>'`. Any collation name ending in
+//! `_ci` (for example `en_US_ci`) selects case- and accent-insensitive
+//! comparison: strings are compared after lowercasing and removing accents, so
+//! "apple", "Äpple" and "APPLE" compare equal.
+
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+use std::cmp::Ordering;
+
+/// How strings are compared when sorting and evaluating comparisons
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum Collation {
+    /// Compare by Unicode codepoint (the default)
+    #[default]
+    Codepoint,
+    /// Ignore case and accents
+    CaseAccentInsensitive,
+}
+
+impl Collation {
+    /// Look up a collation by name
+    ///
+    /// `binary`, `codepoint` and `ucs_basic` select codepoint order; names ending
+    /// in `_ci` select case- and accent-insensitive comparison.
+    pub fn from_name(name: &str) -> Option<Self> {
+        let lower = name.to_ascii_lowercase();
+        match lower.as_str() {
+            "binary" | "codepoint" | "ucs_basic" => Some(Collation::Codepoint),
+            _ if lower.ends_with("_ci") => Some(Collation::CaseAccentInsensitive),
+            _ => None,
+        }
+    }
+
+    /// Whether strings are compared as they are
+    pub fn is_codepoint(&self) -> bool {
+        *self == Collation::Codepoint
+    }
+
+    /// The form of a string that this collation compares
+    pub fn sort_key<'a>(&self, s: &'a str) -> Cow<'a, str> {
+        match self {
+            Collation::Codepoint => Cow::Borrowed(s),
+            Collation::CaseAccentInsensitive => Cow::Owned(fold_case_and_accents(s)),
+        }
+    }
+
+    /// Compare two strings under this collation
+    pub fn compare(&self, a: &str, b: &str) -> Ordering {
+        match self {
+            Collation::Codepoint => a.cmp(b),
+            Collation::CaseAccentInsensitive => self.sort_key(a).cmp(&self.sort_key(b)),
+        }
+    }
+}
+
+/// Lowercase a string and strip accents from Latin letters
+fn fold_case_and_accents(s: &str) -> String {
+    let mut folded = String::with_capacity(s.len());
+    for c in s.chars() {
+        // Combining diacritical marks left over from decomposed input
+        if ('\u{0300}'..='\u{036F}').contains(&c) {
+            continue;
+        }
+        match c {
+            'ß' => folded.push_str("ss"),
+            'Æ' | 'æ' => folded.push_str("ae"),
+            'Œ' | 'œ' => folded.push_str("oe"),
+            _ => folded.extend(strip_accent(c).to_lowercase()),
+        }
+    }
+    folded
+}
+
+/// Map an accented Latin-1 or Latin Extended-A letter to its base letter
+fn strip_accent(c: char) -> char {
+    match c {
+        'À'..='Å' | 'à'..='å' | '\u{0100}'..='\u{0105}' => 'a',
+        'Ç' | 'ç' | '\u{0106}'..='\u{010D}' => 'c',
+        '\u{010E}'..='\u{0111}' => 'd',
+        'È'..='Ë' | 'è'..='ë' | '\u{0112}'..='\u{011B}' => 'e',
+        '\u{011C}'..='\u{0123}' => 'g',
+        '\u{0124}'..='\u{0127}' => 'h',
+        'Ì'..='Ï' | 'ì'..='ï' | '\u{0128}'..='\u{0131}' => 'i',
+        '\u{0134}' | '\u{0135}' => 'j',
+        '\u{0136}' | '\u{0137}' => 'k',
+        '\u{0139}'..='\u{0142}' => 'l',
+        'Ñ' | 'ñ' | '\u{0143}'..='\u{0148}' => 'n',
+        'Ò'..='Ö' | 'Ø' | 'ò'..='ö' | 'ø' | '\u{014C}'..='\u{0151}' => 'o',
+        '\u{0154}'..='\u{0159}' => 'r',
+        '\u{015A}'..='\u{0161}' => 's',
+        '\u{0162}'..='\u{0167}' => 't',
+        'Ù'..='Ü' | 'ù'..='ü' | '\u{0168}'..='\u{0173}' => 'u',
+        '\u{0174}' | '\u{0175}' => 'w',
+        'Ý' | 'ý' | 'ÿ' | '\u{0176}'..='\u{0178}' => 'y',
+        '\u{0179}'..='\u{017E}' => 'z',
+        _ => c,
+    }
+}
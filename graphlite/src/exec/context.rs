@@ -3,13 +3,21 @@
 //
 //! Execution context for variable management and session lookup
 
-use crate::functions::FunctionRegistry;
+use crate::catalog::manager::CatalogManager;
+use crate::exec::collation::Collation;
+use crate::exec::error::ExecutionError;
+use crate::exec::progress::QueryProgress;
+use crate::exec::query_limits::QueryGuard;
+use crate::exec::Row;
+use crate::functions::{FunctionRegistry, RegexCache, TimezoneType};
+use crate::plan::physical::PhysicalPlan;
+use crate::plan::trace::ExecutionTracer;
 use crate::session::models::{Session, UserSession};
 use crate::session::SessionProvider;
 use crate::storage::{StorageManager, Value};
 use crate::types::GqlType;
 use std::collections::HashMap;
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
 
 #[derive(Clone)]
 pub struct ExecutionContext {
@@ -32,6 +40,8 @@ pub struct ExecutionContext {
     pub function_registry: Option<Arc<FunctionRegistry>>,
     /// Cache manager for invalidation on schema/data changes
     pub cache_manager: Option<Arc<crate::cache::CacheManager>>,
+    /// Catalog manager for looking up graph type definitions
+    pub catalog_manager: Option<Arc<std::sync::RwLock<CatalogManager>>>,
     /// Current user for metadata tracking
     pub current_user: Option<String>,
     /// Current transaction ID for transaction metadata tracking (planned feature)
@@ -39,6 +49,28 @@ pub struct ExecutionContext {
     pub current_transaction: Option<String>,
     /// Warnings generated during execution (e.g., duplicate insert detection)
     pub warnings: Vec<String>,
+    /// Compiled regexes shared by every row evaluated in this execution
+    pub regex_cache: RegexCache,
+    /// Per-operator measurements for EXPLAIN ANALYZE, when requested
+    pub execution_tracer: Option<Arc<Mutex<ExecutionTracer>>>,
+    /// Row counter reported to a progress callback, when one is registered
+    pub progress: Option<Arc<QueryProgress>>,
+    /// Memory and time limits of a sandboxed query
+    pub(crate) query_guard: Option<Arc<QueryGuard>>,
+    /// Row cap the projection producing the final result enforces row by row
+    pub(crate) result_row_limit: Option<usize>,
+    /// Scan in ID order regardless of the session setting
+    pub(crate) deterministic_order: bool,
+    /// `$name` values bound for this request only, looked up before the session's
+    pub(crate) parameters: Option<Arc<HashMap<String, Value>>>,
+    /// Plan prepared ahead of the statement, used instead of planning it again
+    pub(crate) prepared_plan: Option<Arc<PhysicalPlan>>,
+    /// String collation of the session, captured when the context is created
+    pub collation: Collation,
+    /// Time zone of the session for datetime components and results; UTC when unset
+    pub time_zone: Option<TimezoneType>,
+    /// Columns and rows a data statement returns in place of its status row
+    pub returned_rows: Option<(Vec<String>, Vec<Row>)>,
 }
 
 // Manual Debug implementation to avoid SessionProvider Debug requirement
@@ -63,9 +95,30 @@ impl std::fmt::Debug for ExecutionContext {
                 "cache_manager",
                 &self.cache_manager.as_ref().map(|_| "Some(CacheManager)"),
             )
+            .field(
+                "catalog_manager",
+                &self
+                    .catalog_manager
+                    .as_ref()
+                    .map(|_| "Some(CatalogManager)"),
+            )
             .field("current_user", &self.current_user)
             .field("current_transaction", &self.current_transaction)
             .field("warnings", &self.warnings)
+            .field("regex_cache", &self.regex_cache)
+            .field("execution_tracer", &self.execution_tracer)
+            .field("progress", &self.progress)
+            .field("query_guard", &self.query_guard)
+            .field("result_row_limit", &self.result_row_limit)
+            .field("deterministic_order", &self.deterministic_order)
+            .field("parameters", &self.parameters)
+            .field(
+                "prepared_plan",
+                &self.prepared_plan.as_ref().map(|_| "Some(PhysicalPlan)"),
+            )
+            .field("collation", &self.collation)
+            .field("time_zone", &self.time_zone)
+            .field("returned_rows", &self.returned_rows)
             .finish()
     }
 }
@@ -83,9 +136,21 @@ impl ExecutionContext {
             storage_manager: Some(storage_manager),
             function_registry: None,
             cache_manager: None,
+            catalog_manager: None,
             current_user: None,
             current_transaction: None,
             warnings: Vec::new(),
+            regex_cache: RegexCache::new(),
+            execution_tracer: None,
+            progress: None,
+            query_guard: None,
+            result_row_limit: None,
+            deterministic_order: false,
+            parameters: None,
+            prepared_plan: None,
+            collation: Collation::default(),
+            time_zone: None,
+            returned_rows: None,
         }
     }
 
@@ -117,6 +182,15 @@ impl ExecutionContext {
         self.warnings.clear();
     }
 
+    /// Set the catalog manager
+    pub fn with_catalog_manager(
+        mut self,
+        catalog_manager: Arc<std::sync::RwLock<CatalogManager>>,
+    ) -> Self {
+        self.catalog_manager = Some(catalog_manager);
+        self
+    }
+
     /// Set the function registry
     pub fn with_function_registry(mut self, function_registry: Arc<FunctionRegistry>) -> Self {
         self.function_registry = Some(function_registry);
@@ -130,9 +204,16 @@ impl ExecutionContext {
             .get_session(&self.session_id)
     }
 
-    /// Get a variable value, checking session parameters first, then local variables
+    /// Get a variable value, checking request and session parameters first, then local variables
     pub fn get_variable(&self, name: &str) -> Option<Value> {
-        // First check session parameters
+        // First check request and session parameters
+        if let Some(value) = self
+            .parameters
+            .as_ref()
+            .and_then(|parameters| parameters.get(name))
+        {
+            return Some(value.clone());
+        }
         if let Some(session_arc) = self.get_session() {
             if let Ok(user_session) = session_arc.read() {
                 if let Some(value) = user_session.parameters.get(name) {
@@ -144,6 +225,21 @@ impl ExecutionContext {
         self.variables.get(name).cloned()
     }
 
+    /// Get a `$name` query parameter bound for this request or in the session,
+    /// ignoring local variables
+    pub fn get_parameter(&self, name: &str) -> Option<Value> {
+        if let Some(value) = self
+            .parameters
+            .as_ref()
+            .and_then(|parameters| parameters.get(name))
+        {
+            return Some(value.clone());
+        }
+        let session_arc = self.get_session()?;
+        let user_session = session_arc.read().ok()?;
+        user_session.get_parameter(name).cloned()
+    }
+
     /// Set a local variable with type information
     pub fn set_variable(&mut self, name: String, value: Value) {
         self.variables.insert(name, value);
@@ -176,6 +272,47 @@ impl ExecutionContext {
         user_session.current_graph.clone()
     }
 
+    /// Count rows produced by a scan or expansion toward query progress
+    pub fn record_progress(&self, rows: usize) {
+        if let Some(progress) = &self.progress {
+            progress.record_rows(rows);
+        }
+    }
+
+    /// Report the most rows the running operator held at once, for EXPLAIN ANALYZE
+    pub fn record_rows_held(&self, rows: usize) {
+        if let Some(tracer) = &self.execution_tracer {
+            if let Ok(mut tracer) = tracer.lock() {
+                tracer.record_rows_held(rows);
+            }
+        }
+    }
+
+    /// Charge a row produced by a scan or expansion against the query's
+    /// memory and time limits, if it has any
+    pub fn charge_row(&self, row: &Row) -> Result<(), ExecutionError> {
+        match &self.query_guard {
+            Some(guard) => guard.charge_row(row),
+            None => Ok(()),
+        }
+    }
+
+    /// Whether the session keeps INSERTed nodes identical to existing ones
+    pub fn allow_duplicate_nodes(&self) -> bool {
+        self.get_session()
+            .and_then(|session| session.read().ok().map(|s| s.allow_duplicate_nodes))
+            .unwrap_or(false)
+    }
+
+    /// Whether the request or session wants scans and groups in a reproducible order
+    pub fn deterministic_order(&self) -> bool {
+        self.deterministic_order
+            || self
+                .get_session()
+                .and_then(|session| session.read().ok().map(|s| s.deterministic_order))
+                .unwrap_or(false)
+    }
+
     /// Get current schema from session
     pub fn get_current_schema(&self) -> Option<String> {
         let session_arc = self.get_session()?;
@@ -259,9 +396,7 @@ impl ExecutionContext {
                 ))
             }
         } else {
-            Err(crate::exec::error::ExecutionError::RuntimeError(
-                "No graph context available. Use SESSION SET GRAPH with full path /<schema-name>/<graph-name> format.".to_string()
-            ))
+            Err(crate::exec::error::ExecutionError::no_current_graph())
         }
     }
 
@@ -335,7 +470,9 @@ impl ExecutionContext {
                     self.storage_manager.clone(),
                     self.current_graph.clone(),
                     self.get_current_graph_name(),
-                );
+                )
+                .with_regex_cache(self.regex_cache.clone())
+                .with_time_zone(self.time_zone.clone());
 
                 // Execute the function
                 function.execute(&function_context).map_err(|e| {
@@ -356,6 +493,46 @@ impl ExecutionContext {
                 })
             }
 
+            Expression::Parameter(parameter) => {
+                self.get_parameter(&parameter.name).ok_or_else(|| {
+                    crate::exec::error::ExecutionError::ExpressionError(format!(
+                        "Parameter '${}' is not bound",
+                        parameter.name
+                    ))
+                })
+            }
+
+            Expression::Binary(binary) => {
+                use crate::ast::Operator;
+
+                let left = self.evaluate_simple_expression(&binary.left)?;
+                let right = self.evaluate_simple_expression(&binary.right)?;
+                match (&binary.operator, left, right) {
+                    (_, Value::Null, _) | (_, _, Value::Null) => Ok(Value::Null),
+                    (Operator::Plus, Value::Number(l), Value::Number(r)) => {
+                        Ok(Value::Number(l + r))
+                    }
+                    (Operator::Minus, Value::Number(l), Value::Number(r)) => {
+                        Ok(Value::Number(l - r))
+                    }
+                    (Operator::Star, Value::Number(l), Value::Number(r)) => {
+                        Ok(Value::Number(l * r))
+                    }
+                    (Operator::Slash, Value::Number(l), Value::Number(r)) if r != 0.0 => {
+                        Ok(Value::Number(l / r))
+                    }
+                    (Operator::Percent, Value::Number(l), Value::Number(r)) if r != 0.0 => {
+                        Ok(Value::Number(l.rem_euclid(r)))
+                    }
+                    _ => Err(crate::exec::error::ExecutionError::ExpressionError(
+                        format!(
+                            "Expression type not supported in simple evaluation: {:?}",
+                            expr
+                        ),
+                    )),
+                }
+            }
+
             _ => {
                 // For other expression types, return an error
                 Err(crate::exec::error::ExecutionError::ExpressionError(
@@ -377,7 +554,7 @@ impl ExecutionContext {
             crate::ast::Literal::Boolean(b) => Value::Boolean(*b),
             crate::ast::Literal::Null => Value::Null,
             crate::ast::Literal::DateTime(dt) => Value::String(dt.clone()),
-            crate::ast::Literal::Duration(dur) => Value::String(dur.clone()),
+            crate::ast::Literal::Duration(dur) => Value::from_duration_literal(dur),
             crate::ast::Literal::TimeWindow(tw) => Value::String(tw.clone()),
             crate::ast::Literal::Vector(vec) => {
                 Value::Vector(vec.iter().map(|&f| f as f32).collect())
@@ -386,6 +563,12 @@ impl ExecutionContext {
                 let converted: Vec<Value> = list.iter().map(Self::literal_to_value).collect();
                 Value::List(converted)
             }
+            crate::ast::Literal::Map(entries) => Value::Map(
+                entries
+                    .iter()
+                    .map(|(key, value)| (key.clone(), Self::literal_to_value(value)))
+                    .collect(),
+            ),
         }
     }
 }
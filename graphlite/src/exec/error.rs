@@ -4,6 +4,7 @@
 //! Execution error types
 
 use crate::storage::StorageError;
+use std::time::Duration;
 use thiserror::Error;
 
 /// Execution errors
@@ -42,11 +43,53 @@ pub enum ExecutionError {
     #[error("Schema validation error: {0}")]
     SchemaValidation(String),
 
+    #[error("Constraint violation: {0}")]
+    ConstraintViolation(String),
+
     #[error("Not found: {0}")]
     NotFound(String),
 
     #[error("Memory limit exceeded: requested {requested} bytes, limit {limit} bytes")]
     MemoryLimitExceeded { limit: usize, requested: usize },
+
+    #[error("Transaction timed out: {0}")]
+    TransactionTimedOut(String),
+
+    #[error("Conflict with a concurrent operation: {0}")]
+    Conflict(String),
+
+    #[error("Lock timeout: {0}")]
+    LockTimeout(String),
+
+    #[error("Too many queries: {0}")]
+    Overloaded(String),
+
+    #[error("Rate limit exceeded: {0}")]
+    RateLimited(String),
+
+    #[error("Result too large: query returned more than {limit} rows")]
+    ResultTooLarge { limit: usize },
+
+    #[error("No current graph: {hint}")]
+    NoCurrentGraph { hint: String },
+
+    #[error("Query timed out: exceeded time limit of {limit:?}")]
+    QueryTimedOut { limit: Duration },
+
+    #[error("Read-only violation: {0}")]
+    ReadOnlyViolation(String),
+}
+
+impl ExecutionError {
+    /// The statement needs a graph, but the session has none and the query
+    /// names none
+    pub fn no_current_graph() -> Self {
+        ExecutionError::NoCurrentGraph {
+            hint: "Use SESSION SET GRAPH /<schema-name>/<graph-name> to choose a graph, \
+                   or name one with FROM"
+                .to_string(),
+        }
+    }
 }
 
 impl From<StorageError> for ExecutionError {
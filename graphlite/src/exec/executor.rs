@@ -4,11 +4,14 @@
 //! Main query executor implementation
 
 use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
 
 use crate::catalog::system_procedures::{is_system_procedure, SystemProcedures};
+use crate::exec::graph_procedures::{execute_graph_procedure, is_graph_procedure};
 use crate::plan::logical::PathElement;
 use crate::plan::physical::{PhysicalNode, PhysicalPlan, ProjectionItem, SortItem};
+use crate::plan::trace::ExecutionTracer;
 
 use crate::ast::{
     AtLocationStatement, BasicQuery, CaseType, CatalogPath, CatalogStatement, DeclareStatement,
@@ -18,26 +21,35 @@ use crate::ast::{
     TransactionStatement, TypeSpec, Variable, WhereClause, WithClause, WithQuery,
 };
 use crate::cache::CacheManager;
-use crate::storage::{GraphCache, StorageManager, Value};
+use crate::storage::{DurationValue, GraphCache, StorageManager, Value};
 use crate::txn::{TransactionId, TransactionLog, TransactionManager, UndoOperation};
 
 use crate::catalog::manager::CatalogManager;
 use crate::catalog::operations::{CatalogOperation, CatalogResponse, QueryType};
-use crate::functions::{FunctionContext, FunctionRegistry};
+use crate::catalog::providers::graph_metadata::{Graph as CatalogGraph, TtlPolicy};
+use crate::functions::{parse_timezone, FunctionContext, FunctionRegistry, TimezoneType};
 use crate::types::{
     CoercionStrategy, GqlType, TypeCaster, TypeCoercion, TypeInference, TypeValidator,
 };
 use serde_json::json;
 
+use super::collation::Collation;
 use super::context::ExecutionContext;
 use super::error::ExecutionError;
+use super::lock_tracker::{GraphLockGuard, GraphLockTable};
+use super::progress::QueryProgress;
+use super::query_limits::QueryGuard;
 use super::result::{QueryResult, Row};
+use super::streaming_topk::{compare_sort_keys, OrderedTopK, VectorTopK};
 use crate::session::models::UserSession;
-use crate::session::SessionProvider;
+use crate::session::{SessionProvider, SessionTransactionState};
 
 // Executor is now fully synchronous - no runtime management needed
 // All DDL and catalog operations are now sync, eliminating runtime nesting issues
 
+/// Consumer of rows handed over one at a time by a streaming operator
+type RowSink<'a> = dyn FnMut(Row, &mut ExecutionContext) -> Result<(), ExecutionError> + 'a;
+
 /// Unified execution request containing all necessary context for query execution
 #[derive(Clone)]
 pub struct ExecutionRequest {
@@ -53,6 +65,18 @@ pub struct ExecutionRequest {
     pub physical_plan: Option<PhysicalPlan>,
     /// Whether this query requires graph context (from validator)
     pub requires_graph_context: Option<bool>,
+    /// Collects per-operator timings when running EXPLAIN ANALYZE
+    pub execution_tracer: Option<Arc<std::sync::Mutex<ExecutionTracer>>>,
+    /// Counts produced rows for a progress callback
+    pub progress: Option<Arc<QueryProgress>>,
+    /// Memory, row and time limits of a sandboxed query
+    pub(crate) query_guard: Option<Arc<QueryGuard>>,
+    /// Scan in ID order even if the session does not ask for it
+    pub(crate) deterministic_order: bool,
+    /// `$name` values bound for this request only
+    pub(crate) parameters: Option<Arc<HashMap<String, Value>>>,
+    /// Plan of the statement from [`QueryExecutor::prepare_plan`], reused across runs
+    pub(crate) prepared_plan: Option<Arc<PhysicalPlan>>,
 }
 
 impl ExecutionRequest {
@@ -65,6 +89,12 @@ impl ExecutionRequest {
             query_text: None,
             physical_plan: None,
             requires_graph_context: None,
+            execution_tracer: None,
+            progress: None,
+            query_guard: None,
+            deterministic_order: false,
+            parameters: None,
+            prepared_plan: None,
         }
     }
 
@@ -97,6 +127,43 @@ impl ExecutionRequest {
         self.requires_graph_context = Some(requires_graph_context);
         self
     }
+
+    /// Measure each physical operator while the plan executes
+    pub fn with_execution_tracer(mut self, tracer: Arc<std::sync::Mutex<ExecutionTracer>>) -> Self {
+        self.execution_tracer = Some(tracer);
+        self
+    }
+
+    /// Report rows produced by scans and expansions as the query runs
+    pub fn with_progress(mut self, progress: Arc<QueryProgress>) -> Self {
+        self.progress = Some(progress);
+        self
+    }
+
+    /// Enforce memory, row and time limits while the query runs
+    pub(crate) fn with_query_guard(mut self, guard: Arc<QueryGuard>) -> Self {
+        self.query_guard = Some(guard);
+        self
+    }
+
+    /// Return rows in a reproducible order, as with the session's
+    /// `deterministic_order`, for this request only
+    pub(crate) fn with_deterministic_order(mut self) -> Self {
+        self.deterministic_order = true;
+        self
+    }
+
+    /// Bind `$name` values for this request only, ahead of session parameters
+    pub(crate) fn with_parameters(mut self, parameters: Arc<HashMap<String, Value>>) -> Self {
+        self.parameters = Some(parameters);
+        self
+    }
+
+    /// Run a plan prepared by [`QueryExecutor::prepare_plan`] instead of planning again
+    pub(crate) fn with_prepared_plan(mut self, plan: Option<Arc<PhysicalPlan>>) -> Self {
+        self.prepared_plan = plan;
+        self
+    }
 }
 
 /// Main query executor focused purely on execution
@@ -121,6 +188,12 @@ pub struct QueryExecutor {
     transaction_logs:
         Arc<std::sync::RwLock<std::collections::HashMap<TransactionId, TransactionLog>>>,
 
+    // Graph-level locks taken by statements and by explicit lock requests
+    graph_locks: Arc<GraphLockTable>,
+
+    // Statements turned into physical plans, for the coordinator's metrics
+    statements_planned: AtomicU64,
+
     // Type system components
     #[allow(dead_code)]
     // FALSE POSITIVE - Used via self.type_inference in methods (lines 7053, 7075). Compiler limitation with self.field access detection.
@@ -147,14 +220,111 @@ impl QueryExecutor {
         self.transaction_manager.clone()
     }
 
+    pub fn graph_locks(&self) -> Arc<GraphLockTable> {
+        self.graph_locks.clone()
+    }
+
+    /// Number of statements planned since the executor was created or reset
+    pub fn statements_planned(&self) -> u64 {
+        self.statements_planned.load(Ordering::Relaxed)
+    }
+
+    pub(crate) fn reset_statements_planned(&self) {
+        self.statements_planned.store(0, Ordering::Relaxed);
+    }
+
+    /// Plan a statement once so that it can run with many sets of parameters
+    ///
+    /// Basic queries and INSERT statements are planned here; the plan goes to
+    /// each run through [`ExecutionRequest::with_prepared_plan`]. Any other
+    /// statement, and a query whose LIMIT or SKIP takes a parameter, yields
+    /// `None` and is planned as it runs. `graph_path` is the graph whose
+    /// indexes `USING INDEX` hints may use.
+    pub(crate) fn prepare_plan(
+        &self,
+        statement: &Statement,
+        graph_path: Option<String>,
+    ) -> Result<Option<Arc<PhysicalPlan>>, ExecutionError> {
+        match statement {
+            Statement::Query(crate::ast::Query::Basic(basic_query)) => {
+                // Without any parameters bound, LIMIT and SKIP only bind if they are literals
+                let mut literal_limits = statement.clone();
+                let unbound = ExecutionContext::new(String::new(), self.storage.clone());
+                if self
+                    .bind_limit_parameters(&mut literal_limits, &unbound)
+                    .is_err()
+                {
+                    return Ok(None);
+                }
+                self.plan_basic_query(basic_query, graph_path)
+                    .map(|plan| Some(Arc::new(plan)))
+            }
+            Statement::DataStatement(crate::ast::DataStatement::Insert(insert_stmt)) => self
+                .plan_insert(insert_stmt)
+                .map(|plan| Some(Arc::new(plan))),
+            _ => Ok(None),
+        }
+    }
+
+    /// Plan a basic query into its physical plan
+    fn plan_basic_query(
+        &self,
+        basic_query: &BasicQuery,
+        graph_path: Option<String>,
+    ) -> Result<PhysicalPlan, ExecutionError> {
+        let document = crate::ast::Document {
+            statement: Statement::Query(crate::ast::Query::Basic(Box::new(basic_query.clone()))),
+            location: Location {
+                line: 1,
+                column: 1,
+                offset: 0,
+            },
+        };
+        let mut planner = self.query_planner(graph_path);
+        planner
+            .plan_query(&document)
+            .map_err(|e| ExecutionError::RuntimeError(format!("Planning error: {}", e)))
+    }
+
+    /// Plan an INSERT statement into the physical plan its executor runs
+    fn plan_insert(
+        &self,
+        insert_stmt: &crate::ast::InsertStatement,
+    ) -> Result<PhysicalPlan, ExecutionError> {
+        self.statements_planned.fetch_add(1, Ordering::Relaxed);
+        let logical_plan = crate::plan::insert_planner::InsertPlanner::new()
+            .plan_insert(insert_stmt)
+            .map_err(|e| ExecutionError::RuntimeError(format!("Logical planning error: {}", e)))?;
+        Ok(PhysicalPlan::from_logical(&logical_plan))
+    }
+
     /// Unified execution entry point - all queries flow through here
-    pub fn execute_query(&self, request: ExecutionRequest) -> Result<QueryResult, ExecutionError> {
+    pub fn execute_query(
+        &self,
+        mut request: ExecutionRequest,
+    ) -> Result<QueryResult, ExecutionError> {
         log::debug!(
             "EXECUTE_QUERY: Statement type: {:?}",
             std::mem::discriminant(&request.statement)
         );
         let start_time = std::time::Instant::now();
 
+        // Roll back the session's transaction if it sat idle past the timeout
+        let transaction_state = request.session.as_ref().and_then(|session_lock| {
+            session_lock
+                .read()
+                .ok()
+                .map(|session| session.transaction_state.clone())
+        });
+        if let Some(transaction_state) = &transaction_state {
+            transaction_state.check_idle_timeout(&self.storage, Some(&self.catalog_manager))?;
+
+            // With auto-commit off, writes accumulate in one implicit transaction
+            if matches!(request.statement, Statement::DataStatement(_)) {
+                transaction_state.begin_implicit_transaction()?;
+            }
+        }
+
         // PHASE 1: Check if this is an UNWIND query that needs preprocessing
         if let Some(ref query_text) = request.query_text {
             if crate::exec::unwind_preprocessor::UnwindPreprocessor::is_unwind_query(query_text) {
@@ -178,19 +348,31 @@ impl QueryExecutor {
                             query_text: Some(query.to_string()),
                             physical_plan: None,
                             requires_graph_context: request.requires_graph_context,
+                            execution_tracer: None,
+                            progress: request.progress.clone(),
+                            query_guard: request.query_guard.clone(),
+                            deterministic_order: request.deterministic_order,
+                            parameters: request.parameters.clone(),
+                            prepared_plan: None,
                         };
 
                         // Execute the individual query normally
                         self.execute_query(new_request)
                     };
 
-                return crate::exec::unwind_preprocessor::UnwindPreprocessor::execute_unwind_query(
-                    query_text,
-                    executor_fn,
-                );
+                let result =
+                    crate::exec::unwind_preprocessor::UnwindPreprocessor::execute_unwind_query(
+                        query_text,
+                        executor_fn,
+                    )?;
+                Self::check_result_size(&request, &result)?;
+                return Ok(result);
             }
         }
 
+        // Wait out conflicting graph locks before reading the graph
+        let _graph_lock = self.lock_graph_for_statement(&request)?;
+
         // Step 1: Resolve execution context based on session and graph requirements
         let needs_graph = if let Some(requires_graph) = request.requires_graph_context {
             // Use the flag from validator if available (preferred)
@@ -212,14 +394,43 @@ impl QueryExecutor {
 
         // Step 3: Create execution context with session information
         let mut context = self.create_execution_context_from_session(request.session.as_ref());
+        context.parameters = request.parameters.clone();
+        context.prepared_plan = request.prepared_plan.clone();
+
+        // Bind LIMIT/SKIP parameters so the planner sees literal counts
+        self.bind_limit_parameters(&mut request.statement, &context)?;
 
         // Set the resolved graph in context if available
         if let Some(graph) = &resolved_graph {
             context.current_graph = Some(graph.clone());
         }
+        context.execution_tracer = request.execution_tracer.clone();
+        context.progress = request.progress.clone();
+        context.query_guard = request.query_guard.clone();
+        context.deterministic_order = request.deterministic_order;
+        // Only a plain query's root projection produces the rows it returns
+        if matches!(
+            request.statement,
+            Statement::Query(crate::ast::Query::Basic(_)) | Statement::Select(_)
+        ) {
+            context.result_row_limit = Self::result_row_limit(&request);
+        }
 
         // Step 4: Route to appropriate execution path based on statement type
-        let result = self.route_and_execute(&request, &mut context, resolved_graph.as_ref())?;
+        let result = match self.route_and_execute(&request, &mut context, resolved_graph.as_ref()) {
+            Ok(result) => result,
+            Err(e) => {
+                if let Some(transaction_state) = &transaction_state {
+                    self.rollback_failed_transaction(transaction_state);
+                }
+                return Err(e);
+            }
+        };
+        if let Some(guard) = &request.query_guard {
+            guard.check_deadline()?;
+        }
+        Self::check_result_size(&request, &result)?;
+        let result = Self::localize_datetimes(result, context.time_zone.as_ref());
 
         // Step 5: Audit if enabled and query text provided
         if let Some(query_text) = &request.query_text {
@@ -238,6 +449,104 @@ impl QueryExecutor {
         Ok(result)
     }
 
+    /// Register a statement with the graph lock table of the session's graph
+    ///
+    /// Reads wait for exclusive locks held by other threads; writes also wait
+    /// for shared ones.
+    fn lock_graph_for_statement(
+        &self,
+        request: &ExecutionRequest,
+    ) -> Result<Option<GraphLockGuard>, ExecutionError> {
+        let write = match &request.statement {
+            Statement::DataStatement(_) | Statement::ProcedureBody(_) => true,
+            Statement::Query(_) | Statement::Select(_) | Statement::Call(_) => false,
+            _ => return Ok(None),
+        };
+        let graph_path = request
+            .session
+            .as_ref()
+            .and_then(|session_lock| session_lock.read().ok())
+            .and_then(|session| session.current_graph.clone());
+        match graph_path {
+            Some(graph_path) => self
+                .graph_locks
+                .enter_statement(&graph_path, write)
+                .map(Some),
+            None => Ok(None),
+        }
+    }
+
+    /// Roll back the open transaction after a failed statement, if the session
+    /// asked for that; otherwise the transaction stays open for a retry
+    fn rollback_failed_transaction(&self, transaction_state: &SessionTransactionState) {
+        let should_rollback = transaction_state.rolls_back_on_error().unwrap_or(false)
+            && transaction_state.has_active_transaction().unwrap_or(false);
+        if !should_rollback {
+            return;
+        }
+        if let Err(e) = transaction_state
+            .rollback_transaction_with_catalog(Some(&self.storage), Some(&self.catalog_manager))
+        {
+            log::error!(
+                "Failed to roll back transaction after statement error: {}",
+                e
+            );
+        }
+    }
+
+    /// Fail if the result has more rows than the session's `max_result_rows`
+    /// or the query's own row limit
+    fn check_result_size(
+        request: &ExecutionRequest,
+        result: &QueryResult,
+    ) -> Result<(), ExecutionError> {
+        match Self::result_row_limit(request) {
+            Some(limit) if result.rows.len() > limit => {
+                Err(ExecutionError::ResultTooLarge { limit })
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// The smaller of the session's `max_result_rows` and the query's own row limit
+    fn result_row_limit(request: &ExecutionRequest) -> Option<usize> {
+        let session_max_rows = request
+            .session
+            .as_ref()
+            .and_then(|session_lock| session_lock.read().ok())
+            .and_then(|session| session.max_result_rows);
+        let query_max_rows = request
+            .query_guard
+            .as_ref()
+            .and_then(|guard| guard.row_limit());
+        match (session_max_rows, query_max_rows) {
+            (Some(session), Some(query)) => Some(session.min(query)),
+            (session, query) => session.or(query),
+        }
+    }
+
+    /// Render UTC timestamps in the result in the session's time zone
+    fn localize_datetimes(
+        mut result: QueryResult,
+        time_zone: Option<&TimezoneType>,
+    ) -> QueryResult {
+        let Some(time_zone) = time_zone else {
+            return result;
+        };
+        for row in &mut result.rows {
+            for value in row
+                .values
+                .values_mut()
+                .chain(row.positional_values.iter_mut())
+            {
+                if let Value::DateTime(dt) = value {
+                    *value = time_zone.convert_from_utc(dt);
+                }
+            }
+        }
+        result
+    }
+
     /// Resolve graph for execution based on precedence rules
     fn resolve_graph_for_execution(
         &self,
@@ -253,7 +562,9 @@ impl QueryExecutor {
             if let Ok(session) = session_lock.read() {
                 if let Some(current_graph_path) = &session.current_graph {
                     match self.storage.get_graph(current_graph_path)? {
-                        Some(graph) => return Ok(Arc::new(graph)),
+                        Some(graph) => {
+                            return Ok(Arc::new(self.hide_expired_nodes(current_graph_path, graph)))
+                        }
                         None => {
                             return Err(ExecutionError::RuntimeError(format!(
                                 "Session graph '{}' not found",
@@ -265,10 +576,154 @@ impl QueryExecutor {
             }
         }
 
+        // Priority 3: A MATCH whose patterns all name their own graph
+        if let Some(graph_path) = Self::pattern_scoped_graph(&request.statement) {
+            let path = format!("/{}", graph_path.segments.join("/"));
+            if let Some(graph) = self.storage.get_graph(&path)? {
+                return Ok(Arc::new(self.hide_expired_nodes(&path, graph)));
+            }
+        }
+
         // No graph available
-        Err(ExecutionError::RuntimeError(
-            "No graph context available. Use SESSION SET GRAPH or specify FROM clause.".to_string(),
-        ))
+        Err(ExecutionError::no_current_graph())
+    }
+
+    /// Node expiry rule of the graph at `graph_path`, if it was created with one
+    fn ttl_policy(&self, graph_path: &str) -> Option<TtlPolicy> {
+        let mut catalog_manager = self.catalog_manager.write().ok()?;
+        match catalog_manager.execute(
+            "graph_metadata",
+            CatalogOperation::Query {
+                query_type: QueryType::GetGraph,
+                params: json!({ "name": graph_path.trim_start_matches('/') }),
+            },
+        ) {
+            Ok(CatalogResponse::Query { results }) => {
+                serde_json::from_value::<CatalogGraph>(results)
+                    .ok()?
+                    .ttl_policy()
+            }
+            _ => None,
+        }
+    }
+
+    /// Whether the graph at `graph_path` hides nodes as they expire, so its
+    /// query results go stale with time alone
+    pub(crate) fn has_ttl_policy(&self, graph_path: &str) -> bool {
+        self.ttl_policy(graph_path).is_some()
+    }
+
+    /// Drop expired nodes from a graph loaded for a query
+    ///
+    /// Only the query's copy changes; [`Self::purge_expired_nodes`] deletes
+    /// them from storage.
+    fn hide_expired_nodes(&self, graph_path: &str, mut graph: GraphCache) -> GraphCache {
+        if let Some(policy) = self.ttl_policy(graph_path) {
+            graph.remove_expired_nodes(&policy.label, &policy.property, chrono::Utc::now());
+        }
+        graph
+    }
+
+    /// Delete expired nodes from every graph that has a node expiry rule
+    ///
+    /// Returns the number of nodes deleted.
+    pub fn purge_expired_nodes(&self) -> Result<usize, ExecutionError> {
+        let mut purged = 0;
+        for graph_path in self.storage.get_graph_names()? {
+            let Some(policy) = self.ttl_policy(&graph_path) else {
+                continue;
+            };
+            let _graph_lock = self.graph_locks.enter_statement(&graph_path, true)?;
+            let Some(mut graph) = self.storage.get_graph(&graph_path)? else {
+                continue;
+            };
+            let removed =
+                graph.remove_expired_nodes(&policy.label, &policy.property, chrono::Utc::now());
+            if removed > 0 {
+                self.storage.save_graph(&graph_path, graph)?;
+                if let Some(cache_manager) = &self.cache_manager {
+                    cache_manager
+                        .invalidate_on_data_change(Some(graph_path.clone()), removed as u64);
+                }
+                purged += removed;
+            }
+        }
+        Ok(purged)
+    }
+
+    /// First pattern graph of a query whose MATCH patterns all carry `FROM <graph>`
+    fn pattern_scoped_graph(statement: &Statement) -> Option<CatalogPath> {
+        let mut query = match statement {
+            Statement::Query(query) => query,
+            _ => return None,
+        };
+        while let crate::ast::Query::Limited { query: inner, .. } = query {
+            query = inner;
+        }
+        let basic = match query {
+            crate::ast::Query::Basic(basic) => basic,
+            _ => return None,
+        };
+        let patterns = &basic.match_clause.patterns;
+        if patterns.is_empty() || patterns.iter().any(|p| p.graph.is_none()) {
+            return None;
+        }
+        patterns[0].graph.clone()
+    }
+
+    /// Load the graph named by a `MATCH ... FROM <graph>` pattern
+    fn load_scoped_graph(
+        &self,
+        graph_path: &CatalogPath,
+        context: &ExecutionContext,
+    ) -> Result<Arc<GraphCache>, ExecutionError> {
+        let full_path = match graph_path.segments.len() {
+            2 => format!("/{}", graph_path.segments.join("/")),
+            1 => match context.get_current_schema() {
+                Some(schema) => format!(
+                    "/{}/{}",
+                    schema.trim_start_matches('/'),
+                    graph_path.segments[0]
+                ),
+                None => {
+                    return Err(ExecutionError::RuntimeError(format!(
+                        "Cannot match in graph '{}': no current schema set. Use a full path /schema_name/graph_name",
+                        graph_path.segments[0]
+                    )))
+                }
+            },
+            _ => {
+                return Err(ExecutionError::RuntimeError(format!(
+                    "Invalid graph path '/{}': expected /schema_name/graph_name",
+                    graph_path.segments.join("/")
+                )))
+            }
+        };
+
+        // Storage hands out an empty graph for any path, so ask the catalog
+        let in_catalog = self
+            .catalog_manager
+            .write()
+            .is_ok_and(|mut catalog_manager| {
+                matches!(
+                    catalog_manager.execute(
+                        "graph_metadata",
+                        CatalogOperation::Query {
+                            query_type: QueryType::GetGraph,
+                            params: json!({ "name": full_path.trim_start_matches('/') }),
+                        },
+                    ),
+                    Ok(CatalogResponse::Query { .. })
+                )
+            });
+
+        match self.storage.get_graph(&full_path)? {
+            Some(graph) if in_catalog => Ok(Arc::new(self.hide_expired_nodes(&full_path, graph))),
+            _ => Err(ExecutionError::RuntimeError(format!(
+                "Graph '{}' not found",
+                full_path
+            ))),
+        }
     }
 
     /// Create execution context from user session
@@ -277,18 +732,27 @@ impl QueryExecutor {
         session: Option<&Arc<std::sync::RwLock<UserSession>>>,
     ) -> ExecutionContext {
         let context = if let Some(session_arc) = session {
-            // Extract session ID from session
-            let session_id = if let Ok(user_session) = session_arc.read() {
-                user_session.session_id.clone()
+            // Extract session ID and collation from session
+            let (session_id, collation, time_zone) = if let Ok(user_session) = session_arc.read() {
+                (
+                    user_session.session_id.clone(),
+                    user_session.collation,
+                    user_session.current_timezone.clone(),
+                )
             } else {
-                "unknown_session".to_string()
+                ("unknown_session".to_string(), Collation::default(), None)
             };
-            ExecutionContext::new(session_id, self.storage.clone())
+            let mut context = ExecutionContext::new(session_id, self.storage.clone());
+            context.collation = collation;
+            context.time_zone = time_zone
+                .and_then(|name| parse_timezone(&name).ok())
+                .filter(|time_zone| !time_zone.is_utc());
+            context
         } else {
             ExecutionContext::new("anonymous_session".to_string(), self.storage.clone())
         };
 
-        // Set function registry, cache manager, and session provider
+        // Set function registry, cache manager, session provider, and catalog manager
         let context = if let Some(cache_mgr) = &self.cache_manager {
             context.with_cache_manager(cache_mgr.clone())
         } else {
@@ -298,6 +762,7 @@ impl QueryExecutor {
         context
             .with_function_registry(self.function_registry.clone())
             .with_session_provider(self.session_provider.clone())
+            .with_catalog_manager(self.catalog_manager.clone())
     }
 
     /// Route and execute based on statement type
@@ -312,6 +777,7 @@ impl QueryExecutor {
             Statement::Query(_query) if request.physical_plan.is_some() => {
                 // If we have a pre-computed physical plan, use it
                 let plan = request.physical_plan.as_ref().unwrap();
+                let _execute_span = tracing::info_span!("execute").entered();
                 if let Some(graph) = graph {
                     self.execute_physical_plan_with_context(plan, context, graph)
                 } else {
@@ -319,6 +785,13 @@ impl QueryExecutor {
                 }
             }
             _ => {
+                // Planned queries open their execute span once planning is done
+                let _execute_span = (!matches!(
+                    request.statement,
+                    Statement::Query(_) | Statement::Select(_)
+                ))
+                .then(|| tracing::info_span!("execute").entered());
+
                 // Execute statement directly within the route_and_execute flow
                 self.execute_statement(
                     &request.statement,
@@ -337,6 +810,7 @@ impl QueryExecutor {
         context: &mut ExecutionContext,
         graph: &Arc<GraphCache>,
     ) -> Result<QueryResult, ExecutionError> {
+        Self::scope_result_row_limit(plan, context);
         let rows = self.execute_node_with_graph(&plan.root, context, graph)?;
 
         // Extract variable names from the physical plan or from the first row as fallback
@@ -352,6 +826,23 @@ impl QueryExecutor {
         })
     }
 
+    /// Hand the row limit to the plan's root projection, which stops as soon as
+    /// it passes the limit; other plans are checked on the final result
+    fn scope_result_row_limit(plan: &PhysicalPlan, context: &mut ExecutionContext) {
+        if !Self::projects_result(&plan.root) {
+            context.result_row_limit = None;
+        }
+    }
+
+    /// Check whether the plan's result rows come straight from a projection
+    fn projects_result(node: &PhysicalNode) -> bool {
+        match node {
+            PhysicalNode::Project { .. } => true,
+            PhysicalNode::GraphScope { input, .. } => Self::projects_result(input),
+            _ => false,
+        }
+    }
+
     /// Execute physical plan without graph
     fn execute_physical_plan_without_graph(
         &self,
@@ -442,6 +933,8 @@ impl QueryExecutor {
             transaction_manager,
             current_transaction: Arc::new(std::sync::RwLock::new(None)),
             transaction_logs: Arc::new(std::sync::RwLock::new(std::collections::HashMap::new())),
+            graph_locks: Arc::new(GraphLockTable::new()),
+            statements_planned: AtomicU64::new(0),
             // Initialize type system components
             type_inference: TypeInference::new(),
             type_validator: TypeValidator,
@@ -516,9 +1009,7 @@ impl QueryExecutor {
                     }
                 }
                 // No session or no current graph set
-                Err(ExecutionError::RuntimeError(
-                    "CurrentGraph reference requires session with SET GRAPH. Use SESSION SET GRAPH <path> first.".to_string()
-                ))
+                Err(ExecutionError::no_current_graph())
             }
             Some(GraphExpression::Union {
                 left,
@@ -726,25 +1217,15 @@ impl QueryExecutor {
                         // Resolve graph with proper session context
                         let graph = self.resolve_graph_for_execution(&mini_request)?;
 
-                        // Create physical plan
-                        use crate::ast::{Document, Query, Statement as AstStatement};
-                        use crate::plan::optimizer::QueryPlanner;
-
-                        let query = Query::Basic(basic_query.clone());
-                        let statement_ast = AstStatement::Query(query);
-                        let document = Document {
-                            statement: statement_ast,
-                            location: crate::ast::Location {
-                                line: 1,
-                                column: 1,
-                                offset: 0,
-                            },
-                        };
-
-                        let mut planner = QueryPlanner::new();
-                        let planned_query = planner.plan_query(&document).map_err(|e| {
-                            ExecutionError::RuntimeError(format!("Planning error: {}", e))
-                        })?;
+                        // Run the prepared plan, or plan the query now
+                        let planned_query =
+                            match context.prepared_plan.take() {
+                                Some(plan) => plan,
+                                None => Arc::new(self.plan_basic_query(
+                                    basic_query,
+                                    context.get_current_graph_name(),
+                                )?),
+                            };
                         self.execute_with_provided_graph_and_audit(&planned_query, &graph, context)
                     }
                     crate::ast::Query::SetOperation(set_op) => {
@@ -765,7 +1246,7 @@ impl QueryExecutor {
 
                         // Apply LIMIT if present
                         if let Some(limit) = limit_clause {
-                            result = self.apply_limit(result, limit)?;
+                            result = self.apply_limit(result, limit, context)?;
                         }
 
                         Ok(result)
@@ -835,10 +1316,7 @@ impl QueryExecutor {
                                         }
                                     }
                                 } else {
-                                    return Err(ExecutionError::RuntimeError(
-                                        "No current graph set in session. Use SESSION SET GRAPH."
-                                            .to_string(),
-                                    ));
+                                    return Err(ExecutionError::no_current_graph());
                                 }
                             } else {
                                 return Err(ExecutionError::RuntimeError(
@@ -923,6 +1401,11 @@ impl QueryExecutor {
                     graph_expr
                 );
                 if let Some(session) = session {
+                    if let crate::ast::DataStatement::Insert(insert_stmt) = data_stmt {
+                        if context.prepared_plan.is_none() {
+                            context.prepared_plan = Some(Arc::new(self.plan_insert(insert_stmt)?));
+                        }
+                    }
                     log::debug!(
                         "EXECUTOR: Calling DataStatementCoordinator::execute_data_statement"
                     );
@@ -940,7 +1423,7 @@ impl QueryExecutor {
                 }
             }
             Statement::SessionStatement(session_stmt) => {
-                self.execute_session_statement(session_stmt)
+                self.execute_session_statement(session_stmt, context)
             }
             Statement::Declare(declare_stmt) => {
                 // Execute DECLARE statement to define local variables
@@ -979,13 +1462,12 @@ impl QueryExecutor {
         context: &mut ExecutionContext,
     ) -> Result<QueryResult, ExecutionError> {
         use crate::ast::{Document, Query, Statement};
-        use crate::plan::optimizer::QueryPlanner;
 
         // Resolve graph expression to actual graph
         let graph = self.resolve_graph_expression(graph_expr)?;
 
         // Create a Document and Statement wrapper for the planner
-        let query = Query::Basic(basic_query.clone());
+        let query = Query::Basic(Box::new(basic_query.clone()));
         let statement = Statement::Query(query);
         let document = Document {
             statement,
@@ -997,7 +1479,7 @@ impl QueryExecutor {
         };
 
         // Use the query planner to create a physical plan
-        let mut planner = QueryPlanner::new();
+        let mut planner = self.query_planner(context.get_current_graph_name());
         let planned_query = planner
             .plan_query(&document)
             .map_err(|e| ExecutionError::RuntimeError(format!("Planning error: {}", e)))?;
@@ -1006,6 +1488,16 @@ impl QueryExecutor {
         self.execute_with_graph(&planned_query, &graph, context)
     }
 
+    /// Create a query planner that checks USING INDEX hints against the indexes of a graph
+    fn query_planner(&self, graph_path: Option<String>) -> crate::plan::optimizer::QueryPlanner {
+        self.statements_planned.fetch_add(1, Ordering::Relaxed);
+        let planner = crate::plan::optimizer::QueryPlanner::new().with_graph(graph_path);
+        match self.storage.get_index_manager() {
+            Some(index_manager) => planner.with_index_manager(index_manager.clone()),
+            None => planner,
+        }
+    }
+
     /// Execute a basic query with access to outer context variables (for correlated subqueries)
     fn execute_basic_query_with_context(
         &self,
@@ -1013,7 +1505,6 @@ impl QueryExecutor {
         outer_context: &ExecutionContext,
     ) -> Result<QueryResult, ExecutionError> {
         use crate::ast::{Document, Query, Statement};
-        use crate::plan::optimizer::QueryPlanner;
 
         log::debug!(
             "execute_basic_query_with_context: BasicQuery has GROUP BY: {}",
@@ -1026,7 +1517,7 @@ impl QueryExecutor {
         })?;
 
         // Create a Document and Statement wrapper for the planner
-        let query = Query::Basic(basic_query.clone());
+        let query = Query::Basic(Box::new(basic_query.clone()));
         let statement = Statement::Query(query);
         let document = Document {
             statement,
@@ -1039,7 +1530,7 @@ impl QueryExecutor {
 
         // Use the query planner to create a physical plan
         log::debug!("Calling QueryPlanner::plan_query");
-        let mut planner = QueryPlanner::new();
+        let mut planner = self.query_planner(outer_context.get_current_graph_name());
         let planned_query = planner
             .plan_query(&document)
             .map_err(|e| ExecutionError::RuntimeError(format!("Planning error: {}", e)))?;
@@ -1130,7 +1621,7 @@ impl QueryExecutor {
 
                 // Apply LIMIT if present
                 if let Some(limit) = limit_clause {
-                    result = self.apply_limit(result, limit)?;
+                    result = self.apply_limit(result, limit, outer_context)?;
                 }
 
                 Ok(result)
@@ -1276,7 +1767,7 @@ impl QueryExecutor {
 
         // Apply LIMIT if present
         if let Some(limit_clause) = &with_query.limit_clause {
-            result = self.apply_limit(result, limit_clause)?;
+            result = self.apply_limit(result, limit_clause, &current_context)?;
         }
 
         Ok(result)
@@ -2124,7 +2615,7 @@ impl QueryExecutor {
                                             {
                                                 // Only count non-null values
                                                 if !matches!(value, Value::Null) {
-                                                    unique_values.insert(format!("{:?}", value));
+                                                    unique_values.insert(value);
                                                 }
                                             }
                                         }
@@ -2299,11 +2790,10 @@ impl QueryExecutor {
                                             if let Ok(value) = self
                                                 .evaluate_expression_in_row(arg_expr, row, context)
                                             {
-                                                if !matches!(value, Value::Null) {
-                                                    let value_key = format!("{:?}", value);
-                                                    if unique_values.insert(value_key) {
-                                                        unique_list.push(value);
-                                                    }
+                                                if !matches!(value, Value::Null)
+                                                    && unique_values.insert(value.clone())
+                                                {
+                                                    unique_list.push(value);
                                                 }
                                             }
                                         }
@@ -2698,8 +3188,11 @@ impl QueryExecutor {
         session: Option<&Arc<std::sync::RwLock<crate::session::models::UserSession>>>,
         session_id: Option<&str>,
     ) -> Result<QueryResult, ExecutionError> {
-        // Validate procedure namespace - only gql.* is supported for system procedures
-        if !call_stmt.procedure_name.starts_with("gql.") {
+        // Validate procedure namespace - gql.* for system procedures, db.* for graph procedures
+        if !call_stmt.procedure_name.starts_with("gql.")
+            && !is_graph_procedure(&call_stmt.procedure_name)
+            && !is_system_procedure(&call_stmt.procedure_name)
+        {
             return Err(ExecutionError::UnsupportedOperator(format!(
                 "Invalid procedure namespace: '{}'. System procedures must use 'gql.' prefix. Example: CALL gql.list_graphs()",
                 call_stmt.procedure_name
@@ -2710,8 +3203,12 @@ impl QueryExecutor {
         let needs_graph_context = self.procedure_needs_graph_context(&call_stmt.procedure_name);
 
         if needs_graph_context {
-            // Resolve the graph for procedures that need it, using session for CurrentGraph support
-            let graph = self.resolve_graph_reference(graph_expr, session)?;
+            // Resolve the graph for procedures that need it, falling back to the graph
+            // already resolved for this request (e.g. the session graph)
+            let graph = match (graph_expr, context.current_graph.clone()) {
+                (None, Some(graph)) => graph,
+                _ => self.resolve_graph_reference(graph_expr, session)?,
+            };
             self.execute_call_statement_with_graph(call_stmt, context, &graph, session_id)
         } else {
             // For graph-independent procedures, use the passed context
@@ -2838,6 +3335,9 @@ impl QueryExecutor {
             // Session-dependent procedures that don't need graph context
             "gql.show_session" => false,
 
+            // Graph algorithm procedures run against the current graph
+            name if is_graph_procedure(name) => true,
+
             // All other procedures (unknown gql.* or non-gql.*)
             // Route to execute_call_statement_without_graph where proper errors will be raised
             _ => false,
@@ -2989,6 +3489,14 @@ impl QueryExecutor {
                 }
                 false
             }
+            Expression::ListConstructor(list_constructor) => list_constructor
+                .elements
+                .iter()
+                .any(|element| self.expression_needs_graph_context(element)),
+            Expression::MapConstructor(map_constructor) => map_constructor
+                .entries
+                .iter()
+                .any(|(_, value)| self.expression_needs_graph_context(value)),
             Expression::Cast(cast_expr) => {
                 self.expression_needs_graph_context(&cast_expr.expression)
             }
@@ -3024,6 +3532,20 @@ impl QueryExecutor {
                 self.expression_needs_graph_context(&array_index.array)
                     || self.expression_needs_graph_context(&array_index.index)
             }
+            Expression::ArraySlice(array_slice) => {
+                self.expression_needs_graph_context(&array_slice.array)
+                    || [&array_slice.start, &array_slice.end]
+                        .into_iter()
+                        .flatten()
+                        .any(|bound| self.expression_needs_graph_context(bound))
+            }
+            Expression::Window(window) => window
+                .function
+                .arguments
+                .iter()
+                .chain(window.partition_by.iter())
+                .chain(window.order_by.iter().map(|item| &item.expression))
+                .any(|expr| self.expression_needs_graph_context(expr)),
             Expression::Parameter(_) => false, // Parameters are external values, no graph context needed
             Expression::Pattern(_) => true,    // Patterns always need graph context
         }
@@ -3047,12 +3569,22 @@ impl QueryExecutor {
         }
 
         // Execute the system procedure with the actual session_id
-        let mut result = self.system_procedures.execute_procedure(
+        let result = self.system_procedures.execute_procedure(
             &call_stmt.procedure_name,
             evaluated_args,
             session_id,
         )?;
 
+        self.apply_call_yield_and_where(call_stmt, result, context)
+    }
+
+    /// Apply a CALL statement's YIELD projection and WHERE filter to the procedure result
+    fn apply_call_yield_and_where(
+        &self,
+        call_stmt: &crate::ast::CallStatement,
+        mut result: QueryResult,
+        context: &ExecutionContext,
+    ) -> Result<QueryResult, ExecutionError> {
         // If there's a YIELD clause, filter the columns
         if let Some(yield_clause) = &call_stmt.yield_clause {
             for row in &mut result.rows {
@@ -3122,11 +3654,21 @@ impl QueryExecutor {
         &self,
         call_stmt: &crate::ast::CallStatement,
         context: &mut ExecutionContext,
-        _graph: &Arc<GraphCache>,
+        graph: &Arc<GraphCache>,
         session_id: Option<&str>,
     ) -> Result<QueryResult, ExecutionError> {
-        // Use the passed context and delegate to the graph-independent version
-        self.execute_call_statement_without_graph(call_stmt, context, session_id)
+        if !is_graph_procedure(&call_stmt.procedure_name) {
+            // Use the passed context and delegate to the graph-independent version
+            return self.execute_call_statement_without_graph(call_stmt, context, session_id);
+        }
+
+        let mut evaluated_args = Vec::new();
+        for arg in &call_stmt.arguments {
+            evaluated_args.push(self.evaluate_expression(arg, context)?);
+        }
+
+        let result = execute_graph_procedure(&call_stmt.procedure_name, evaluated_args, graph)?;
+        self.apply_call_yield_and_where(call_stmt, result, context)
     }
 
     /// Execute a SELECT statement with specific graph
@@ -3164,20 +3706,24 @@ impl QueryExecutor {
                 patterns: vec![crate::ast::PathPattern {
                     assignment: None, // No path assignment
                     path_type: None,  // Default path type
+                    graph: None,
                     elements: vec![crate::ast::PatternElement::Node(crate::ast::Node {
                         identifier: Some("n".to_string()),
                         labels: vec![],
+                        label_expression: None,
                         properties: None,
+                        predicate: None,
                         location: crate::ast::Location::default(),
                     })],
                     location: crate::ast::Location::default(),
                 }],
+                hints: vec![],
                 location: crate::ast::Location::default(),
             }
         });
 
         // Create a Query from the SELECT statement components
-        let query = crate::ast::Query::Basic(crate::ast::BasicQuery {
+        let query = crate::ast::Query::Basic(Box::new(crate::ast::BasicQuery {
             match_clause,
             where_clause: select_stmt.where_clause.clone(),
             return_clause: crate::ast::ReturnClause {
@@ -3190,7 +3736,7 @@ impl QueryExecutor {
             order_clause: select_stmt.order_clause.clone(),
             limit_clause: select_stmt.limit_clause.clone(),
             location: crate::ast::Location::default(),
-        });
+        }));
 
         // Create a document and plan the query
         let document = crate::ast::Document {
@@ -3199,7 +3745,7 @@ impl QueryExecutor {
         };
 
         // Use the planner to create a physical plan
-        let mut planner = crate::plan::optimizer::QueryPlanner::new();
+        let mut planner = self.query_planner(context.get_current_graph_name());
         let plan = planner.plan_query(&document).map_err(|e| {
             ExecutionError::PlanningError(format!("Failed to plan SELECT query: {}", e))
         })?;
@@ -3308,6 +3854,9 @@ impl QueryExecutor {
             CatalogStatement::ClearGraph(_) => {
                 Err(ExecutionError::UnsupportedOperator("CLEAR GRAPH is now handled by dedicated executor structs via DDLStatementCoordinator".to_string()))
             },
+            CatalogStatement::CopyGraph(_) => {
+                Err(ExecutionError::UnsupportedOperator("COPY GRAPH is now handled by dedicated executor structs via DDLStatementCoordinator".to_string()))
+            },
             CatalogStatement::CreateGraphType(_) => {
                 Err(ExecutionError::UnsupportedOperator("CREATE GRAPH TYPE is now handled by dedicated executor structs via DDLStatementCoordinator".to_string()))
             },
@@ -3386,11 +3935,13 @@ impl QueryExecutor {
         context: &mut ExecutionContext,
     ) -> Result<QueryResult, ExecutionError> {
         let start_time = std::time::Instant::now();
+        let _execute_span = tracing::info_span!("execute").entered();
 
         // Use the provided context and set the current graph
         context.set_current_graph(graph.clone());
 
         // Execute the root operator with the resolved graph
+        Self::scope_result_row_limit(plan, context);
         let execute_result = self.execute_node_with_graph(&plan.root, context, graph);
 
         let execution_time = start_time.elapsed().as_millis() as u64;
@@ -3423,6 +3974,19 @@ impl QueryExecutor {
         &self,
         node: &PhysicalNode,
         context: &mut ExecutionContext,
+    ) -> Result<Vec<Row>, ExecutionError> {
+        match context.execution_tracer.clone() {
+            Some(tracer) => Self::trace_operator(&tracer, node, || {
+                self.execute_operator_without_graph(node, context)
+            }),
+            None => self.execute_operator_without_graph(node, context),
+        }
+    }
+
+    fn execute_operator_without_graph(
+        &self,
+        node: &PhysicalNode,
+        context: &mut ExecutionContext,
     ) -> Result<Vec<Row>, ExecutionError> {
         match node {
             // Graph-independent operations that work with scalar values
@@ -3452,27 +4016,224 @@ impl QueryExecutor {
         context: &mut ExecutionContext,
         graph: &Arc<GraphCache>,
     ) -> Result<Vec<Row>, ExecutionError> {
+        match context.execution_tracer.clone() {
+            Some(tracer) => Self::trace_operator(&tracer, node, || {
+                self.execute_operator_with_graph(node, context, graph)
+            }),
+            None => self.execute_operator_with_graph(node, context, graph),
+        }
+    }
+
+    /// Hand the rows of a plan subtree to `sink` one at a time
+    ///
+    /// Scans, filters and plain projections pass each row on as soon as it is
+    /// produced, so a consumer that keeps only some of them never holds the
+    /// whole input. Other operators run to completion first and their rows are
+    /// handed over and dropped one by one. Returns how many rows were
+    /// materialized before the first one was handed over.
+    fn stream_node_with_graph(
+        &self,
+        node: &PhysicalNode,
+        context: &mut ExecutionContext,
+        graph: &Arc<GraphCache>,
+        sink: &mut RowSink<'_>,
+    ) -> Result<usize, ExecutionError> {
+        if !self.streams_rows(node) {
+            let rows = self.execute_node_with_graph(node, context, graph)?;
+            let materialized = rows.len();
+            for row in rows {
+                sink(row, context)?;
+            }
+            return Ok(materialized);
+        }
+
+        let Some(tracer) = context.execution_tracer.clone() else {
+            return self.stream_operator_with_graph(node, context, graph, sink);
+        };
+        // Pipelined operators run interleaved, so each one's time includes the
+        // work of the operators it feeds
+        if let Ok(mut tracer) = tracer.lock() {
+            tracer.enter_operator();
+        }
+        let started = std::time::Instant::now();
+        let mut produced = 0;
+        let result = self.stream_operator_with_graph(node, context, graph, &mut |row, context| {
+            produced += 1;
+            sink(row, context)
+        });
+        if let Ok(mut tracer) = tracer.lock() {
+            tracer.exit_operator(node, started.elapsed(), produced);
+        }
+        result
+    }
+
+    /// Check whether an operator can pass its rows on one at a time
+    fn streams_rows(&self, node: &PhysicalNode) -> bool {
+        match node {
+            PhysicalNode::NodeSeqScan { .. }
+            | PhysicalNode::NodeIndexScan { .. }
+            | PhysicalNode::Filter { .. }
+            | PhysicalNode::GraphScope { .. } => true,
+            PhysicalNode::Project { expressions, .. } => expressions.iter().all(|item| {
+                !matches!(item.expression, Expression::Window(_))
+                    && !self.is_aggregate_function(&item.expression)
+            }),
+            _ => false,
+        }
+    }
+
+    fn stream_operator_with_graph(
+        &self,
+        node: &PhysicalNode,
+        context: &mut ExecutionContext,
+        graph: &Arc<GraphCache>,
+        sink: &mut RowSink<'_>,
+    ) -> Result<usize, ExecutionError> {
         match node {
             PhysicalNode::NodeSeqScan {
                 variable,
                 labels,
                 properties,
                 ..
-            } => self.execute_node_seq_scan_with_graph(
+            } => {
+                self.scan_nodes_with_graph(
+                    variable,
+                    labels,
+                    properties.as_ref(),
+                    context,
+                    graph,
+                    sink,
+                )?;
+                Ok(0)
+            }
+            // The scan looks up one equality on a property that has an index in
+            // the graph, taken from the pattern's property map or from the
+            // equalities the planner found in the filter above it. Without such
+            // an equality it reads the label's nodes like a sequential scan.
+            PhysicalNode::NodeIndexScan {
                 variable,
                 labels,
-                properties.as_ref(),
-                context,
-                graph,
-            ),
-
-            PhysicalNode::Filter {
-                condition, input, ..
+                properties,
+                equalities,
+                ..
             } => {
-                let input_rows = self.execute_node_with_graph(input, context, graph)?;
-                self.execute_filter(condition, input_rows, context)
+                let properties = properties.as_ref();
+                match self.index_lookup(labels, properties, equalities, context, graph)? {
+                    Some(node_ids) => {
+                        let nodes = node_ids
+                            .iter()
+                            .filter_map(|id| graph.get_node(id))
+                            .collect();
+                        self.emit_node_rows(variable, nodes, properties, context, sink)?;
+                    }
+                    None => {
+                        self.scan_nodes_with_graph(
+                            variable, labels, properties, context, graph, sink,
+                        )?;
+                    }
+                }
+                Ok(0)
             }
-
+            PhysicalNode::GraphScope {
+                graph: graph_path,
+                input,
+                ..
+            } => {
+                let scoped_graph = self.load_scoped_graph(graph_path, context)?;
+                self.stream_node_with_graph(input, context, &scoped_graph, sink)
+            }
+            PhysicalNode::Filter {
+                condition, input, ..
+            } => self.stream_node_with_graph(input, context, graph, &mut |row, context| {
+                if self.row_passes_filter(condition, &row, context)? {
+                    sink(row, context)?;
+                }
+                Ok(())
+            }),
+            PhysicalNode::Project {
+                expressions, input, ..
+            } => self.stream_node_with_graph(input, context, graph, &mut |row, context| {
+                let projected = self.project_row(expressions, &row, context)?;
+                drop(row);
+                sink(projected, context)
+            }),
+            _ => Err(ExecutionError::UnsupportedOperator(format!(
+                "{} cannot stream its rows",
+                node.operator_name()
+            ))),
+        }
+    }
+
+    /// Run one operator, recording its time and output row count for EXPLAIN ANALYZE
+    fn trace_operator(
+        tracer: &std::sync::Mutex<ExecutionTracer>,
+        node: &PhysicalNode,
+        execute: impl FnOnce() -> Result<Vec<Row>, ExecutionError>,
+    ) -> Result<Vec<Row>, ExecutionError> {
+        if let Ok(mut tracer) = tracer.lock() {
+            tracer.enter_operator();
+        }
+        let started = std::time::Instant::now();
+        let result = execute();
+        let elapsed = started.elapsed();
+        if let Ok(mut tracer) = tracer.lock() {
+            tracer.exit_operator(node, elapsed, result.as_ref().map_or(0, |rows| rows.len()));
+        }
+        result
+    }
+
+    fn execute_operator_with_graph(
+        &self,
+        node: &PhysicalNode,
+        context: &mut ExecutionContext,
+        graph: &Arc<GraphCache>,
+    ) -> Result<Vec<Row>, ExecutionError> {
+        match node {
+            PhysicalNode::NodeSeqScan {
+                variable,
+                labels,
+                properties,
+                ..
+            } => self.execute_node_seq_scan_with_graph(
+                variable,
+                labels,
+                properties.as_ref(),
+                context,
+                graph,
+            ),
+
+            PhysicalNode::NodeIndexScan { .. } => {
+                let mut rows = Vec::new();
+                self.stream_operator_with_graph(node, context, graph, &mut |row, _| {
+                    rows.push(row);
+                    Ok(())
+                })?;
+                Ok(rows)
+            }
+
+            PhysicalNode::IndexDistinctScan {
+                variable,
+                label,
+                property,
+                ..
+            } => self.execute_index_distinct_scan(variable, label, property, graph),
+
+            PhysicalNode::GraphScope {
+                graph: graph_path,
+                input,
+                ..
+            } => {
+                let scoped_graph = self.load_scoped_graph(graph_path, context)?;
+                self.execute_node_with_graph(input, context, &scoped_graph)
+            }
+
+            PhysicalNode::Filter {
+                condition, input, ..
+            } => {
+                let input_rows = self.execute_node_with_graph(input, context, graph)?;
+                self.execute_filter(condition, input_rows, context)
+            }
+
             PhysicalNode::Having {
                 condition, input, ..
             } => {
@@ -3483,8 +4244,9 @@ impl QueryExecutor {
             PhysicalNode::Project {
                 expressions, input, ..
             } => {
+                let row_limit = context.result_row_limit.take();
                 let input_rows = self.execute_node_with_graph(input, context, graph)?;
-                self.execute_project(expressions, input_rows, context)
+                self.execute_project(expressions, input_rows, row_limit, context)
             }
 
             PhysicalNode::GenericFunction {
@@ -3591,6 +4353,25 @@ impl QueryExecutor {
                 self.execute_in_memory_sort(expressions, input_rows, context)
             }
 
+            PhysicalNode::TopK {
+                expressions,
+                count,
+                offset,
+                input,
+                ..
+            } => self.execute_top_k(expressions, *count, *offset, input, context, graph),
+
+            PhysicalNode::VectorTopK {
+                score,
+                ascending,
+                count,
+                offset,
+                input,
+                ..
+            } => {
+                self.execute_vector_top_k(score, *ascending, *count, *offset, input, context, graph)
+            }
+
             PhysicalNode::Distinct { input, .. } => {
                 let input_rows = self.execute_node_with_graph(input, context, graph)?;
                 self.execute_distinct(input_rows)
@@ -3884,7 +4665,7 @@ impl QueryExecutor {
             crate::ast::Literal::Boolean(b) => Value::Boolean(*b),
             crate::ast::Literal::Null => Value::Null,
             crate::ast::Literal::DateTime(dt) => Value::String(dt.clone()),
-            crate::ast::Literal::Duration(dur) => Value::String(dur.clone()),
+            crate::ast::Literal::Duration(dur) => Value::from_duration_literal(dur),
             crate::ast::Literal::TimeWindow(tw) => Value::String(tw.clone()),
             crate::ast::Literal::Vector(vec) => {
                 Value::Vector(vec.iter().map(|&f| f as f32).collect())
@@ -3894,6 +4675,12 @@ impl QueryExecutor {
                     list.iter().map(|lit| self.literal_to_value(lit)).collect();
                 Value::List(converted)
             }
+            crate::ast::Literal::Map(entries) => Value::Map(
+                entries
+                    .iter()
+                    .map(|(key, value)| (key.clone(), self.literal_to_value(value)))
+                    .collect(),
+            ),
         }
     }
 
@@ -3903,11 +4690,34 @@ impl QueryExecutor {
         variable: &str,
         labels: &[String],
         properties: Option<&HashMap<String, Expression>>,
-        _context: &mut ExecutionContext,
+        context: &mut ExecutionContext,
         graph: &Arc<GraphCache>,
     ) -> Result<Vec<Row>, ExecutionError> {
         let mut rows = Vec::new();
+        self.scan_nodes_with_graph(
+            variable,
+            labels,
+            properties,
+            context,
+            graph,
+            &mut |row, _| {
+                rows.push(row);
+                Ok(())
+            },
+        )?;
+        Ok(rows)
+    }
 
+    /// Hand a row for each node with the given labels and properties to `sink`
+    fn scan_nodes_with_graph(
+        &self,
+        variable: &str,
+        labels: &[String],
+        properties: Option<&HashMap<String, Expression>>,
+        context: &mut ExecutionContext,
+        graph: &Arc<GraphCache>,
+        sink: &mut RowSink<'_>,
+    ) -> Result<(), ExecutionError> {
         // Get nodes by label (if label specified, otherwise all nodes)
         let nodes = if labels.is_empty() {
             graph.get_all_nodes()
@@ -3915,6 +4725,21 @@ impl QueryExecutor {
             // For simplicity, just use the first label
             graph.get_nodes_by_label(&labels[0])
         };
+        self.emit_node_rows(variable, nodes, properties, context, sink)
+    }
+
+    /// Hand a row for each of `nodes` that has the given properties to `sink`
+    fn emit_node_rows(
+        &self,
+        variable: &str,
+        mut nodes: Vec<&crate::storage::Node>,
+        properties: Option<&HashMap<String, Expression>>,
+        context: &mut ExecutionContext,
+        sink: &mut RowSink<'_>,
+    ) -> Result<(), ExecutionError> {
+        if context.deterministic_order() {
+            nodes.sort_by(|a, b| a.id.cmp(&b.id));
+        }
 
         // Create a row for each node that matches property filters
         for node in nodes {
@@ -3988,10 +4813,98 @@ impl QueryExecutor {
                 }
             }
 
-            rows.push(row);
+            context.charge_row(&row)?;
+            context.record_progress(1);
+            sink(row, context)?;
         }
 
-        Ok(rows)
+        Ok(())
+    }
+
+    /// Look up the IDs of the nodes an index scan reads
+    ///
+    /// Returns `None` when no index covers an equality of the scan, so the
+    /// label's nodes have to be read instead.
+    fn index_lookup(
+        &self,
+        labels: &[String],
+        properties: Option<&HashMap<String, Expression>>,
+        equalities: &[(String, Expression)],
+        context: &ExecutionContext,
+        graph: &GraphCache,
+    ) -> Result<Option<Vec<String>>, ExecutionError> {
+        let Some(label) = labels.first() else {
+            return Ok(None);
+        };
+
+        // Inline properties match by value; a condition compares strings by collation
+        let mut lookups = Vec::new();
+        if let Some(properties) = properties {
+            for (property, expression) in properties {
+                if let Expression::Literal(literal) = expression {
+                    lookups.push((property.clone(), self.literal_to_value(literal)));
+                }
+            }
+        }
+        for (property, expression) in equalities {
+            let value = self.evaluate_expression(expression, context)?;
+            if matches!(value, Value::String(_)) && !context.collation.is_codepoint() {
+                continue;
+            }
+            lookups.push((property.clone(), value));
+        }
+
+        for (property, value) in lookups {
+            if value.is_null() {
+                continue;
+            }
+            if let Some(node_ids) = graph.lookup_property_index(label, &property, &value) {
+                return Ok(Some(node_ids));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Rows holding each distinct value of a node property, in the
+    /// `variable.property` column the projection above reads
+    ///
+    /// The values come from the property's index in the graph. A plan
+    /// prepared before the index was dropped reads the label's nodes instead.
+    fn execute_index_distinct_scan(
+        &self,
+        variable: &str,
+        label: &str,
+        property: &str,
+        graph: &GraphCache,
+    ) -> Result<Vec<Row>, ExecutionError> {
+        let values = match graph.property_index_values(label, property) {
+            Some(values) => values,
+            None => {
+                let mut nodes = graph.get_nodes_by_label(label);
+                nodes.sort_by(|a, b| a.id.cmp(&b.id));
+                let mut seen = std::collections::HashSet::new();
+                nodes
+                    .into_iter()
+                    .map(|node| {
+                        node.properties
+                            .get(property)
+                            .cloned()
+                            .unwrap_or(Value::Null)
+                    })
+                    .filter(|value| seen.insert(value.clone()))
+                    .collect()
+            }
+        };
+
+        let column = format!("{}.{}", variable, property);
+        Ok(values
+            .into_iter()
+            .map(|value| {
+                let mut row = Row::new();
+                row.add_value(column.clone(), value);
+                row
+            })
+            .collect())
     }
 
     /// Execute a filter operation
@@ -4001,24 +4914,10 @@ impl QueryExecutor {
         input_rows: Vec<Row>,
         context: &mut ExecutionContext,
     ) -> Result<Vec<Row>, ExecutionError> {
-        let _input_count = input_rows.len();
         let mut filtered_rows = Vec::new();
 
         for row in input_rows {
-            // Clear local variables from previous row to prevent variable leakage
-            context.clear_locals();
-
-            // Set row values in context for expression evaluation
-            for (name, value) in &row.values {
-                context.set_variable(name.clone(), value.clone());
-            }
-
-            // Evaluate the condition
-            if self
-                .evaluate_expression(condition, context)?
-                .as_boolean()
-                .unwrap_or(false)
-            {
+            if self.row_passes_filter(condition, &row, context)? {
                 filtered_rows.push(row);
             }
         }
@@ -4026,6 +4925,28 @@ impl QueryExecutor {
         Ok(filtered_rows)
     }
 
+    /// Check whether a row satisfies a filter condition
+    fn row_passes_filter(
+        &self,
+        condition: &Expression,
+        row: &Row,
+        context: &mut ExecutionContext,
+    ) -> Result<bool, ExecutionError> {
+        // Clear local variables from previous row to prevent variable leakage
+        context.clear_locals();
+
+        // Set row values in context for expression evaluation
+        for (name, value) in &row.values {
+            context.set_variable(name.clone(), value.clone());
+        }
+
+        // Evaluate the condition
+        Ok(self
+            .evaluate_expression(condition, context)?
+            .as_boolean()
+            .unwrap_or(false))
+    }
+
     /// Execute a HAVING clause filter
     /// For now, use the same logic as regular filter but with better error handling
     fn execute_having(
@@ -4138,8 +5059,13 @@ impl QueryExecutor {
         &self,
         expressions: &[ProjectionItem],
         input_rows: Vec<Row>,
+        row_limit: Option<usize>,
         context: &mut ExecutionContext,
     ) -> Result<Vec<Row>, ExecutionError> {
+        // Window functions see every input row, so compute them before projecting
+        let mut input_rows = input_rows;
+        self.apply_window_functions(expressions, &mut input_rows, context)?;
+
         // Check if any expressions are aggregate functions
         let has_aggregates = expressions
             .iter()
@@ -4164,92 +5090,105 @@ impl QueryExecutor {
         let mut projected_rows = Vec::new();
 
         for row in input_rows {
-            let mut new_row = Row::new();
+            if let Some(limit) = row_limit.filter(|limit| projected_rows.len() >= *limit) {
+                return Err(ExecutionError::ResultTooLarge { limit });
+            }
+            projected_rows.push(self.project_row(expressions, &row, context)?);
+        }
 
-            // Clear local variables from previous row to prevent variable leakage
-            context.clear_locals();
+        Ok(projected_rows)
+    }
 
-            // Set row values in context for expression evaluation
-            for (name, value) in &row.values {
-                context.set_variable(name.clone(), value.clone());
-            }
+    /// Evaluate a plain projection (no aggregates or window functions) for one row
+    fn project_row(
+        &self,
+        expressions: &[ProjectionItem],
+        row: &Row,
+        context: &mut ExecutionContext,
+    ) -> Result<Row, ExecutionError> {
+        let mut new_row = Row::new();
 
-            // Evaluate each projection expression
-            for proj_item in expressions {
-                let column_name = proj_item.alias.clone().unwrap_or_else(|| {
-                    // Generate a default name from the expression
-                    self.expression_to_string(&proj_item.expression)
-                });
+        // Clear local variables from previous row to prevent variable leakage
+        context.clear_locals();
 
-                // Check if this is a post-aggregation projection where we should map existing columns
-                // instead of re-evaluating expressions
-                let raw_expression_name = self.expression_to_string(&proj_item.expression);
-                let value = if let Some(existing_value) = row.values.get(&raw_expression_name) {
-                    // If the row already contains a column with the raw expression name,
-                    // use that value instead of re-evaluating (this happens after aggregation)
-                    existing_value.clone()
-                } else {
-                    // Normal case: evaluate the expression
-                    self.evaluate_expression(&proj_item.expression, context)?
-                };
+        // Set row values in context for expression evaluation
+        for (name, value) in &row.values {
+            context.set_variable(name.clone(), value.clone());
+        }
 
-                // Track entity if this is a direct variable reference
-                if let Expression::Variable(var) = &proj_item.expression {
-                    // Check if this variable refers to a node or edge
-                    if let Some(entity_value) = context.get_variable(&var.name) {
-                        // Track the entity with the source variable name
-                        new_row.with_entity(&var.name, &entity_value);
-                    }
+        // Evaluate each projection expression
+        for proj_item in expressions {
+            let column_name = proj_item.alias.clone().unwrap_or_else(|| {
+                // Generate a default name from the expression
+                self.expression_to_string(&proj_item.expression)
+            });
 
-                    // Also check if the original row had this entity tracked
-                    if let Some(entity_id) = row.source_entities.get(&var.name) {
-                        new_row
-                            .source_entities
-                            .insert(var.name.clone(), entity_id.clone());
-                    }
-                }
-                // For property access, track the source entity
-                else if let Expression::PropertyAccess(prop_access) = &proj_item.expression {
-                    // prop_access.object is the variable name (e.g., "p" in "p.name")
-                    let var_name = &prop_access.object;
-                    // Track the source entity for property projections
-                    if let Some(entity_id) = row.source_entities.get(var_name) {
-                        // Use the variable name as the key for property projections too
-                        new_row
-                            .source_entities
-                            .insert(var_name.clone(), entity_id.clone());
-                    }
+            // Check if this is a post-aggregation projection where we should map existing columns
+            // instead of re-evaluating expressions
+            let raw_expression_name = self.expression_to_string(&proj_item.expression);
+            let value = if let Some(existing_value) = row.values.get(&raw_expression_name) {
+                // If the row already contains a column with the raw expression name,
+                // use that value instead of re-evaluating (this happens after aggregation)
+                existing_value.clone()
+            } else {
+                // Normal case: evaluate the expression
+                self.evaluate_expression(&proj_item.expression, context)?
+            };
+
+            // Track entity if this is a direct variable reference
+            if let Expression::Variable(var) = &proj_item.expression {
+                // Check if this variable refers to a node or edge
+                if let Some(entity_value) = context.get_variable(&var.name) {
+                    // Track the entity with the source variable name
+                    new_row.with_entity(&var.name, &entity_value);
                 }
 
-                new_row.values.insert(column_name, value);
+                // Also check if the original row had this entity tracked
+                if let Some(entity_id) = row.source_entities.get(&var.name) {
+                    new_row
+                        .source_entities
+                        .insert(var.name.clone(), entity_id.clone());
+                }
             }
-
-            // Preserve any existing entity tracking from the input row
-            // This ensures entities are carried through the projection
-            for (var_name, entity_id) in &row.source_entities {
-                if !new_row.source_entities.contains_key(var_name) {
+            // For property access, track the source entity
+            else if let Expression::PropertyAccess(prop_access) = &proj_item.expression {
+                // prop_access.object is the variable name (e.g., "p" in "p.name")
+                let var_name = &prop_access.object;
+                // Track the source entity for property projections
+                if let Some(entity_id) = row.source_entities.get(var_name) {
+                    // Use the variable name as the key for property projections too
                     new_row
                         .source_entities
                         .insert(var_name.clone(), entity_id.clone());
                 }
             }
 
-            // Preserve text search metadata from input row (Week 6.3)
-            if let Some(score) = row.get_text_score() {
-                new_row.set_text_score(score);
-                // Also preserve TEXT_SCORE() pseudo-column for ORDER BY support
+            new_row.values.insert(column_name, value);
+        }
+
+        // Preserve any existing entity tracking from the input row
+        // This ensures entities are carried through the projection
+        for (var_name, entity_id) in &row.source_entities {
+            if !new_row.source_entities.contains_key(var_name) {
                 new_row
-                    .values
-                    .insert("TEXT_SCORE()".to_string(), Value::Number(score));
-            }
-            if let Some(snippet) = row.get_highlight_snippet() {
-                new_row.set_highlight_snippet(snippet.to_string());
+                    .source_entities
+                    .insert(var_name.clone(), entity_id.clone());
             }
+        }
 
-            projected_rows.push(new_row);
+        // Preserve text search metadata from input row (Week 6.3)
+        if let Some(score) = row.get_text_score() {
+            new_row.set_text_score(score);
+            // Also preserve TEXT_SCORE() pseudo-column for ORDER BY support
+            new_row
+                .values
+                .insert("TEXT_SCORE()".to_string(), Value::Number(score));
+        }
+        if let Some(snippet) = row.get_highlight_snippet() {
+            new_row.set_highlight_snippet(snippet.to_string());
         }
 
-        Ok(projected_rows)
+        Ok(new_row)
     }
 
     /// Evaluate an expression with type information
@@ -4289,6 +5228,12 @@ impl QueryExecutor {
                     self.apply_coercion(&left_val, &left_type, &right_val, &right_type)?;
 
                 // Evaluate with proper types
+                let (coerced_left, coerced_right) = Self::collate_comparison_operands(
+                    &binary.operator,
+                    coerced_left,
+                    coerced_right,
+                    context.collation,
+                );
                 let result_val =
                     self.evaluate_binary_op(&binary.operator, coerced_left, coerced_right)?;
                 Ok((result_val, result_type))
@@ -4394,6 +5339,12 @@ impl QueryExecutor {
                 Ok((result_val, path_type))
             }
 
+            Expression::ListConstructor(_) | Expression::MapConstructor(_) => {
+                let result_val = self.evaluate_expression(expr, context)?;
+                let inferred_type = self.infer_value_type(&result_val);
+                Ok((result_val, inferred_type))
+            }
+
             Expression::Cast(cast_expr) => {
                 let result_val = self.evaluate_cast_expression(cast_expr, context)?;
                 // CAST returns the target type
@@ -4523,18 +5474,19 @@ impl QueryExecutor {
                 Ok((result, GqlType::Boolean))
             }
             Expression::Parameter(parameter) => {
-                // TODO: Implement parameter resolution from execution context
-                // For now, return an error indicating parameters need to be bound
-                Err(ExecutionError::ExpressionError(format!(
-                    "Parameter '{}' is not bound. Parameter binding not yet implemented.",
-                    parameter.name
-                )))
+                // Parameters are bound per session via SESSION SET VALUE
+                let value = self.resolve_parameter(parameter, context)?;
+                let value_type = self.infer_value_type(&value);
+                Ok((value, value_type))
             }
             Expression::Pattern(pattern_expr) => {
                 // Pattern expressions return boolean (true if pattern matches)
                 let result = self.evaluate_pattern_expression(pattern_expr, context)?;
                 Ok((result, GqlType::Boolean))
             }
+            Expression::Window(_) => Err(ExecutionError::InvalidQuery(
+                "Window functions are only allowed as RETURN items".to_string(),
+            )),
             Expression::ArrayIndex(array_index) => {
                 // Evaluate array indexing - type depends on the element type
                 let (array_value, _array_type) =
@@ -4542,9 +5494,28 @@ impl QueryExecutor {
                 let (index_value, _index_type) =
                     self.evaluate_expression_with_types(&array_index.index, context)?;
 
-                // Extract index as integer
+                // Map access by key: missing keys and NULL maps yield NULL
+                match (&array_value, &index_value) {
+                    (Value::Null, _) => {
+                        return Ok((Value::Null, GqlType::String { max_length: None }))
+                    }
+                    (Value::Map(map), Value::String(key)) => {
+                        let element = map.get(key).cloned().unwrap_or(Value::Null);
+                        let element_type = self.infer_value_type(&element);
+                        return Ok((element, element_type));
+                    }
+                    (Value::Map(_), _) => {
+                        return Err(ExecutionError::ExpressionError(format!(
+                            "Map key must be a string, got: {:?}",
+                            index_value
+                        )))
+                    }
+                    _ => {}
+                }
+
+                // Extract index as a number; negative indices count from the end
                 let index = match index_value {
-                    Value::Number(n) => n as usize,
+                    Value::Number(n) => n,
                     _ => {
                         return Err(ExecutionError::ExpressionError(format!(
                             "Array index must be a number, got: {:?}",
@@ -4555,34 +5526,81 @@ impl QueryExecutor {
 
                 // Access the array element
                 match array_value {
-                    Value::List(list) => {
-                        if index < list.len() {
-                            let element = list[index].clone();
+                    Value::List(list) => match Self::resolve_list_index(index, list.len()) {
+                        Some(position) => {
+                            let element = list[position].clone();
                             // Infer type from the element value
                             let element_type = self.infer_value_type(&element);
                             Ok((element, element_type))
-                        } else {
-                            Ok((Value::Null, GqlType::String { max_length: None }))
-                            // Out of bounds returns NULL
-                        }
-                    }
-                    Value::Vector(vec) => {
-                        if index < vec.len() {
-                            Ok((Value::Number(vec[index] as f64), GqlType::Real))
-                        } else {
-                            Ok((Value::Null, GqlType::String { max_length: None }))
-                            // Out of bounds returns NULL
                         }
-                    }
+                        // Out of bounds returns NULL
+                        None => Ok((Value::Null, GqlType::String { max_length: None })),
+                    },
+                    Value::Vector(vec) => match Self::resolve_list_index(index, vec.len()) {
+                        Some(position) => Ok((Value::Number(vec[position] as f64), GqlType::Real)),
+                        // Out of bounds returns NULL
+                        None => Ok((Value::Null, GqlType::String { max_length: None })),
+                    },
                     _ => Err(ExecutionError::ExpressionError(format!(
                         "Cannot index non-array value: {:?}",
                         array_value
                     ))),
                 }
             }
+            Expression::ArraySlice(array_slice) => {
+                let (array_value, _array_type) =
+                    self.evaluate_expression_with_types(&array_slice.array, context)?;
+                let start = array_slice
+                    .start
+                    .as_ref()
+                    .map(|start| self.evaluate_expression_with_types(start, context))
+                    .transpose()?
+                    .map(|(value, _)| value);
+                let end = array_slice
+                    .end
+                    .as_ref()
+                    .map(|end| self.evaluate_expression_with_types(end, context))
+                    .transpose()?
+                    .map(|(value, _)| value);
+
+                let sliced = Self::slice_array_value(array_value, start, end)?;
+                let sliced_type = self.infer_value_type(&sliced);
+                Ok((sliced, sliced_type))
+            }
         }
     }
 
+    /// Look up a property by name, treating a dotted name as a path into nested maps
+    ///
+    /// A property literally named `attrs.inner` wins; otherwise `attrs` is read and
+    /// `inner` is looked up in its map. Missing keys yield `None`.
+    fn lookup_property_path(properties: &HashMap<String, Value>, path: &str) -> Option<Value> {
+        if let Some(value) = properties.get(path) {
+            return Some(value.clone());
+        }
+
+        let mut segments = path.split('.');
+        let mut current = properties.get(segments.next()?)?;
+        for segment in segments {
+            current = current.as_map()?.get(segment)?;
+        }
+        Some(current.clone())
+    }
+
+    /// Resolve a `$name` parameter from the values bound to the current session
+    fn resolve_parameter(
+        &self,
+        parameter: &crate::ast::Parameter,
+        context: &ExecutionContext,
+    ) -> Result<Value, ExecutionError> {
+        context.get_parameter(&parameter.name).ok_or_else(|| {
+            ExecutionError::ExpressionError(format!(
+                "Parameter '${}' is not bound. Use SESSION SET VALUE ${} = <value> to bind it.",
+                parameter.name, parameter.name
+            ))
+        })
+    }
+
     /// Evaluate an expression in the given context (legacy method)
     fn evaluate_expression(
         &self,
@@ -4599,13 +5617,18 @@ impl QueryExecutor {
                     return Ok(value);
                 }
 
-                // If not found, try to access property from the node variable directly
-                if let Some(crate::storage::Value::Node(node)) =
-                    context.get_variable(&prop_access.object).as_ref()
-                {
-                    if let Some(prop_value) = node.properties.get(&prop_access.property) {
-                        return Ok(prop_value.clone());
-                    }
+                // If not found, read the property from the bound node, edge, or map,
+                // descending into nested maps for dotted paths like n.attrs.inner
+                let properties = match context.get_variable(&prop_access.object) {
+                    Some(Value::Node(node)) => Some(node.properties),
+                    Some(Value::Edge(edge)) => Some(edge.properties),
+                    Some(Value::Map(map)) => Some(map),
+                    _ => None,
+                };
+                if let Some(prop_value) = properties.and_then(|properties| {
+                    Self::lookup_property_path(&properties, &prop_access.property)
+                }) {
+                    return Ok(prop_value);
                 }
 
                 // Return NULL if property doesn't exist (SQL standard behavior)
@@ -4615,6 +5638,12 @@ impl QueryExecutor {
             Expression::Binary(binary) => {
                 let left_val = self.evaluate_expression(&binary.left, context)?;
                 let right_val = self.evaluate_expression(&binary.right, context)?;
+                let (left_val, right_val) = Self::collate_comparison_operands(
+                    &binary.operator,
+                    left_val,
+                    right_val,
+                    context.collation,
+                );
                 self.evaluate_binary_op(&binary.operator, left_val, right_val)
             }
 
@@ -4646,6 +5675,24 @@ impl QueryExecutor {
                 self.evaluate_path_constructor(path_constructor, context)
             }
 
+            Expression::ListConstructor(list_constructor) => Ok(Value::List(
+                list_constructor
+                    .elements
+                    .iter()
+                    .map(|element| self.evaluate_expression(element, context))
+                    .collect::<Result<_, _>>()?,
+            )),
+
+            Expression::MapConstructor(map_constructor) => Ok(Value::Map(
+                map_constructor
+                    .entries
+                    .iter()
+                    .map(|(key, value)| {
+                        Ok((key.clone(), self.evaluate_expression(value, context)?))
+                    })
+                    .collect::<Result<_, ExecutionError>>()?,
+            )),
+
             Expression::Cast(cast_expr) => self.evaluate_cast_expression(cast_expr, context),
 
             Expression::Subquery(subquery_expr) => {
@@ -4753,27 +5800,38 @@ impl QueryExecutor {
             Expression::IsPredicate(is_predicate) => {
                 self.evaluate_is_predicate(is_predicate, context)
             }
-            Expression::Parameter(parameter) => {
-                // TODO: Implement parameter resolution from execution context
-                // For now, return an error indicating parameters need to be bound
-                Err(ExecutionError::ExpressionError(format!(
-                    "Parameter '{}' is not bound. Parameter binding not yet implemented.",
-                    parameter.name
-                )))
-            }
+            Expression::Parameter(parameter) => self.resolve_parameter(parameter, context),
             Expression::Pattern(pattern_expr) => {
                 // Pattern expressions return boolean (true if pattern matches)
                 self.evaluate_pattern_expression(pattern_expr, context)
             }
+            Expression::Window(_) => Err(ExecutionError::InvalidQuery(
+                "Window functions are only allowed as RETURN items".to_string(),
+            )),
             Expression::ArrayIndex(array_index) => {
                 // Evaluate the array expression
                 let array_value = self.evaluate_expression(&array_index.array, context)?;
                 // Evaluate the index expression
                 let index_value = self.evaluate_expression(&array_index.index, context)?;
 
-                // Extract index as integer
+                // Map access by key: missing keys and NULL maps yield NULL
+                match (&array_value, &index_value) {
+                    (Value::Null, _) => return Ok(Value::Null),
+                    (Value::Map(map), Value::String(key)) => {
+                        return Ok(map.get(key).cloned().unwrap_or(Value::Null))
+                    }
+                    (Value::Map(_), _) => {
+                        return Err(ExecutionError::ExpressionError(format!(
+                            "Map key must be a string, got: {:?}",
+                            index_value
+                        )))
+                    }
+                    _ => {}
+                }
+
+                // Extract index as a number; negative indices count from the end
                 let index = match index_value {
-                    Value::Number(n) => n as usize,
+                    Value::Number(n) => n,
                     _ => {
                         return Err(ExecutionError::ExpressionError(format!(
                             "Array index must be a number, got: {:?}",
@@ -4782,31 +5840,92 @@ impl QueryExecutor {
                     }
                 };
 
-                // Access the array element
+                // Access the array element; out of bounds returns NULL
                 match array_value {
-                    Value::List(list) => {
-                        if index < list.len() {
-                            Ok(list[index].clone())
-                        } else {
-                            Ok(Value::Null) // Out of bounds returns NULL
-                        }
-                    }
-                    Value::Vector(vec) => {
-                        if index < vec.len() {
-                            Ok(Value::Number(vec[index] as f64))
-                        } else {
-                            Ok(Value::Null) // Out of bounds returns NULL
-                        }
-                    }
+                    Value::List(list) => Ok(Self::resolve_list_index(index, list.len())
+                        .map_or(Value::Null, |position| list[position].clone())),
+                    Value::Vector(vec) => Ok(Self::resolve_list_index(index, vec.len())
+                        .map_or(Value::Null, |position| Value::Number(vec[position] as f64))),
                     _ => Err(ExecutionError::ExpressionError(format!(
                         "Cannot index non-array value: {:?}",
                         array_value
                     ))),
                 }
             }
+            Expression::ArraySlice(array_slice) => {
+                let array_value = self.evaluate_expression(&array_slice.array, context)?;
+                let start = array_slice
+                    .start
+                    .as_ref()
+                    .map(|start| self.evaluate_expression(start, context))
+                    .transpose()?;
+                let end = array_slice
+                    .end
+                    .as_ref()
+                    .map(|end| self.evaluate_expression(end, context))
+                    .transpose()?;
+                Self::slice_array_value(array_value, start, end)
+            }
         }
     }
 
+    /// Resolve a list index against a list of `len` elements
+    ///
+    /// Negative indices count from the end, so -1 is the last element. Returns
+    /// `None` when the index falls outside the list.
+    fn resolve_list_index(index: f64, len: usize) -> Option<usize> {
+        let index = index as i64;
+        let position = if index < 0 { len as i64 + index } else { index };
+        (0..len as i64)
+            .contains(&position)
+            .then_some(position as usize)
+    }
+
+    /// Evaluate `array[start..end]`: bounds may be negative and are clamped to the list
+    fn slice_array_value(
+        array_value: Value,
+        start: Option<Value>,
+        end: Option<Value>,
+    ) -> Result<Value, ExecutionError> {
+        let bound = |value: Option<Value>, len: usize, default: usize| match value {
+            None => Ok(Some(default)),
+            Some(Value::Null) => Ok(None),
+            Some(Value::Number(n)) => {
+                let n = n as i64;
+                let position = if n < 0 { len as i64 + n } else { n };
+                Ok(Some(position.clamp(0, len as i64) as usize))
+            }
+            Some(other) => Err(ExecutionError::ExpressionError(format!(
+                "Slice bound must be a number, got: {:?}",
+                other
+            ))),
+        };
+
+        let len = match &array_value {
+            Value::Null => return Ok(Value::Null),
+            Value::List(list) => list.len(),
+            Value::Vector(vec) => vec.len(),
+            _ => {
+                return Err(ExecutionError::ExpressionError(format!(
+                    "Cannot slice non-array value: {:?}",
+                    array_value
+                )))
+            }
+        };
+
+        // A NULL bound makes the whole slice NULL
+        let (start, end) = match (bound(start, len, 0)?, bound(end, len, len)?) {
+            (Some(start), Some(end)) => (start, end.max(start)),
+            _ => return Ok(Value::Null),
+        };
+
+        Ok(match array_value {
+            Value::List(list) => Value::List(list[start..end].to_vec()),
+            Value::Vector(vec) => Value::Vector(vec[start..end].to_vec()),
+            _ => Value::Null,
+        })
+    }
+
     /// Evaluate a CASE expression
     fn evaluate_case_expression(
         &self,
@@ -5153,7 +6272,7 @@ impl QueryExecutor {
             crate::ast::Literal::Boolean(b) => Ok(Value::Boolean(*b)),
             crate::ast::Literal::Null => Ok(Value::Null),
             crate::ast::Literal::DateTime(dt) => Ok(Value::String(dt.clone())),
-            crate::ast::Literal::Duration(dur) => Ok(Value::String(dur.clone())),
+            crate::ast::Literal::Duration(dur) => Ok(Value::from_duration_literal(dur)),
             crate::ast::Literal::TimeWindow(tw) => Ok(Value::String(tw.clone())),
             crate::ast::Literal::Vector(vec) => {
                 Ok(Value::Vector(vec.iter().map(|&f| f as f32).collect()))
@@ -5165,6 +6284,43 @@ impl QueryExecutor {
                     .collect::<Result<Vec<_>, _>>()?;
                 Ok(Value::List(converted))
             }
+            crate::ast::Literal::Map(entries) => {
+                let converted = entries
+                    .iter()
+                    .map(|(key, value)| Ok((key.clone(), self.evaluate_literal(value)?)))
+                    .collect::<Result<HashMap<_, _>, ExecutionError>>()?;
+                Ok(Value::Map(converted))
+            }
+        }
+    }
+
+    /// Replace the string operands of a comparison with their collation sort keys
+    ///
+    /// The comparison result is a boolean, so comparing the folded strings gives
+    /// the collation's answer without threading it through every operator.
+    fn collate_comparison_operands(
+        op: &crate::ast::Operator,
+        left: Value,
+        right: Value,
+        collation: Collation,
+    ) -> (Value, Value) {
+        use crate::ast::Operator;
+
+        let is_comparison = matches!(
+            op,
+            Operator::Equal
+                | Operator::NotEqual
+                | Operator::LessThan
+                | Operator::LessEqual
+                | Operator::GreaterThan
+                | Operator::GreaterEqual
+        );
+        match (&left, &right) {
+            (Value::String(l), Value::String(r)) if is_comparison && !collation.is_codepoint() => (
+                Value::String(collation.sort_key(l).into_owned()),
+                Value::String(collation.sort_key(r).into_owned()),
+            ),
+            _ => (left, right),
         }
     }
 
@@ -5197,6 +6353,37 @@ impl QueryExecutor {
                 }
             }
 
+            // Duration arithmetic: durations combine with each other and shift datetimes
+            (Operator::Plus, Value::Duration(l), Value::Duration(r)) => l
+                .checked_add(r)
+                .map(Value::Duration)
+                .ok_or_else(|| ExecutionError::RuntimeError("Duration overflow".to_string())),
+            (Operator::Minus, Value::Duration(l), Value::Duration(r)) => l
+                .checked_sub(r)
+                .map(Value::Duration)
+                .ok_or_else(|| ExecutionError::RuntimeError("Duration overflow".to_string())),
+            (Operator::Plus, datetime, Value::Duration(d))
+            | (Operator::Plus, Value::Duration(d), datetime)
+                if datetime.as_datetime_utc().is_some() =>
+            {
+                Self::shift_datetime(datetime, d.to_chrono())
+            }
+            (Operator::Minus, datetime, Value::Duration(d))
+                if datetime.as_datetime_utc().is_some() =>
+            {
+                Self::shift_datetime(datetime, -d.to_chrono())
+            }
+            (Operator::Minus, l, r)
+                if l.as_datetime_utc().is_some() && r.as_datetime_utc().is_some() =>
+            {
+                let (l, r) = (l.as_datetime_utc().unwrap(), r.as_datetime_utc().unwrap());
+                let elapsed = l.signed_duration_since(r);
+                Ok(Value::Duration(DurationValue::new(
+                    elapsed.num_seconds(),
+                    elapsed.subsec_nanos() as i64,
+                )))
+            }
+
             // COMPARISON OPERATORS - ISO SQL/GQL Three-Valued Logic Implementation
             //
             // According to ISO SQL:2016 Section 8.2 and ISO GQL:2024 Section 12.3.2:
@@ -5248,6 +6435,16 @@ impl QueryExecutor {
                         };
                         Ok(Value::Boolean(result))
                     }
+                    (Value::Duration(l), Value::Duration(r)) => {
+                        let result = match op {
+                            Operator::GreaterThan => l > r,
+                            Operator::LessThan => l < r,
+                            Operator::GreaterEqual => l >= r,
+                            Operator::LessEqual => l <= r,
+                            _ => unreachable!(),
+                        };
+                        Ok(Value::Boolean(result))
+                    }
                     // Try to compare booleans (for completeness)
                     (Value::Boolean(l), Value::Boolean(r)) => {
                         let result = match op {
@@ -5444,6 +6641,29 @@ impl QueryExecutor {
         }
     }
 
+    /// Shift a datetime value by a signed duration, keeping its timezone representation
+    fn shift_datetime(datetime: &Value, delta: chrono::Duration) -> Result<Value, ExecutionError> {
+        let overflow = || ExecutionError::RuntimeError("Datetime out of range".to_string());
+        match datetime {
+            Value::DateTime(dt) => dt
+                .checked_add_signed(delta)
+                .map(Value::DateTime)
+                .ok_or_else(overflow),
+            Value::DateTimeWithFixedOffset(dt) => dt
+                .checked_add_signed(delta)
+                .map(Value::DateTimeWithFixedOffset)
+                .ok_or_else(overflow),
+            Value::DateTimeWithNamedTz(tz_name, dt) => dt
+                .checked_add_signed(delta)
+                .map(|shifted| Value::DateTimeWithNamedTz(tz_name.clone(), shifted))
+                .ok_or_else(overflow),
+            other => Err(ExecutionError::TypeError(format!(
+                "Cannot add a duration to {}",
+                other.type_name()
+            ))),
+        }
+    }
+
     /// Convert a Value to a string representation for concatenation
     fn value_to_string(&self, value: &Value) -> Result<String, ExecutionError> {
         match value {
@@ -5599,6 +6819,25 @@ impl QueryExecutor {
                     self.expression_to_string(&binary.right)
                 )
             }
+            Expression::Window(window) => {
+                let mut keys: Vec<String> = window
+                    .partition_by
+                    .iter()
+                    .map(|expr| self.expression_to_string(expr))
+                    .collect();
+                keys.extend(window.order_by.iter().map(|item| {
+                    format!(
+                        "{}_{:?}",
+                        self.expression_to_string(&item.expression),
+                        item.direction
+                    )
+                }));
+                format!(
+                    "{}_over({})",
+                    self.expression_to_string(&Expression::FunctionCall(window.function.clone())),
+                    keys.join(",")
+                )
+            }
             Expression::Literal(_) => "literal".to_string(),
             _ => "expression".to_string(),
         }
@@ -5661,7 +6900,9 @@ impl QueryExecutor {
             context.storage_manager.clone(),
             context.current_graph.clone(),
             context.get_current_graph_name(),
-        );
+        )
+        .with_regex_cache(context.regex_cache.clone())
+        .with_time_zone(context.time_zone.clone());
 
         // Execute the function
         let result = function.execute(&function_context).map_err(|e| {
@@ -5700,7 +6941,8 @@ impl QueryExecutor {
             context.storage_manager.clone(),
             context.current_graph.clone(),
             context.get_current_graph_name(),
-        );
+        )
+        .with_time_zone(context.time_zone.clone());
 
         // Execute the function
         let result = function.execute(&function_context).map_err(|e| {
@@ -5757,9 +6999,8 @@ impl QueryExecutor {
             );
         }
 
-        // Group rows by the group_by expressions
-        let mut groups: HashMap<String, Vec<Row>> = HashMap::new();
-        let mut group_key_to_values: HashMap<String, Vec<Value>> = HashMap::new();
+        // Group rows by the values of the group_by expressions
+        let mut groups: HashMap<Vec<Value>, Vec<Row>> = HashMap::new();
 
         for row in input_rows {
             // Clear local variables from previous row to prevent variable leakage
@@ -5771,8 +7012,7 @@ impl QueryExecutor {
             }
 
             // Create group key from group_by expressions
-            let mut group_key_values = Vec::new();
-            let mut group_key_strings = Vec::new();
+            let mut group_key = Vec::new();
             for expr in group_by {
                 let value = self.evaluate_expression(expr, context)?;
                 log::debug!(
@@ -5780,14 +7020,8 @@ impl QueryExecutor {
                     expr,
                     value
                 );
-                group_key_values.push(value.clone());
-                group_key_strings.push(value.to_string());
+                group_key.push(value);
             }
-            let group_key = group_key_strings.join("|");
-            log::debug!("AGGREGATE DEBUG: Group key: '{}'", group_key);
-
-            // Store the mapping from key to actual values for later use
-            group_key_to_values.insert(group_key.clone(), group_key_values);
 
             // Add row to appropriate group
             groups.entry(group_key).or_default().push(row);
@@ -5799,13 +7033,22 @@ impl QueryExecutor {
         // Debug: Show what groups were created
         log::debug!("AGGREGATE DEBUG: Created {} groups", groups.len());
         for (key, rows) in &groups {
-            log::debug!("  Group '{}': {} rows", key, rows.len());
+            log::debug!("  Group {:?}: {} rows", key, rows.len());
         }
 
         // Special case: if no groups and no GROUP BY expressions, create a single group for aggregation
         if groups.is_empty() && group_by.is_empty() {
             // Create empty group for pure aggregation (like COUNT(*) with no matching rows)
-            groups.insert("".to_string(), Vec::new());
+            groups.insert(Vec::new(), Vec::new());
+        }
+
+        let mut groups: Vec<_> = groups.into_iter().collect();
+        if context.deterministic_order() {
+            groups.sort_by_cached_key(|(key, _)| {
+                key.iter()
+                    .map(|value| value.debug_quoted().to_string())
+                    .collect::<Vec<_>>()
+            });
         }
 
         for (group_key, group_rows) in groups {
@@ -5815,14 +7058,10 @@ impl QueryExecutor {
             let mut group_by_values = HashMap::new();
             let mut aggregate_values = HashMap::new();
 
-            // Compute group-by values using the preserved value types
-            if let Some(actual_values) = group_key_to_values.get(&group_key) {
-                for (i, expr) in group_by.iter().enumerate() {
-                    let column_name = self.expression_to_string(expr);
-                    if let Some(value) = actual_values.get(i) {
-                        group_by_values.insert(column_name, value.clone());
-                    }
-                }
+            // Compute group-by values from the group key
+            for (expr, value) in group_by.iter().zip(&group_key) {
+                let column_name = self.expression_to_string(expr);
+                group_by_values.insert(column_name, value.clone());
             }
 
             // Process aggregates for this group using the function registry
@@ -5834,6 +7073,12 @@ impl QueryExecutor {
                     crate::plan::logical::AggregateFunction::Min => "MIN",
                     crate::plan::logical::AggregateFunction::Max => "MAX",
                     crate::plan::logical::AggregateFunction::Collect => "COLLECT",
+                    crate::plan::logical::AggregateFunction::ApproxCountDistinct => {
+                        "APPROX_COUNT_DISTINCT"
+                    }
+                    crate::plan::logical::AggregateFunction::First => "FIRST",
+                    crate::plan::logical::AggregateFunction::Last => "LAST",
+                    crate::plan::logical::AggregateFunction::ArrayAgg => "ARRAY_AGG",
                 };
 
                 // Evaluate the aggregate expression arguments
@@ -5867,9 +7112,26 @@ impl QueryExecutor {
                         }
                     }
 
+                    // DISTINCT aggregates see each argument value once
+                    let mut function_rows = match evaluated_args.first() {
+                        Some(Value::String(column)) if aggregate.distinct && column != "*" => {
+                            Self::distinct_rows_by_column(&group_rows, column)
+                        }
+                        _ => group_rows.clone(),
+                    };
+
+                    // Order-sensitive aggregates see the rows in their ORDER BY order
+                    if !aggregate.order_by.is_empty() {
+                        function_rows = self.execute_in_memory_sort(
+                            &aggregate.order_by,
+                            function_rows,
+                            context,
+                        )?;
+                    }
+
                     // Create function context for this group with storage access
                     let function_context = FunctionContext::with_storage(
-                        group_rows.clone(),
+                        function_rows,
                         context.variables.clone(),
                         evaluated_args.clone(),
                         context.storage_manager.clone(),
@@ -5919,6 +7181,12 @@ impl QueryExecutor {
                         crate::plan::logical::AggregateFunction::Min => "MIN",
                         crate::plan::logical::AggregateFunction::Max => "MAX",
                         crate::plan::logical::AggregateFunction::Collect => "COLLECT",
+                        crate::plan::logical::AggregateFunction::ApproxCountDistinct => {
+                            "APPROX_COUNT_DISTINCT"
+                        }
+                        crate::plan::logical::AggregateFunction::First => "FIRST",
+                        crate::plan::logical::AggregateFunction::Last => "LAST",
+                        crate::plan::logical::AggregateFunction::ArrayAgg => "ARRAY_AGG",
                     };
                     format!(
                         "{}_{}",
@@ -5937,19 +7205,153 @@ impl QueryExecutor {
         Ok(result_rows)
     }
 
+    /// Keep the first row for each distinct value of `column`
+    ///
+    /// Rows without the column are kept; aggregate functions skip them anyway.
+    fn distinct_rows_by_column(rows: &[Row], column: &str) -> Vec<Row> {
+        let mut seen = std::collections::HashSet::new();
+        rows.iter()
+            .filter(|row| match row.values.get(column) {
+                Some(value) => seen.insert(value),
+                None => true,
+            })
+            .cloned()
+            .collect()
+    }
+
     /// Check if an expression is an aggregate function
     fn is_aggregate_function(&self, expr: &Expression) -> bool {
         match expr {
             Expression::FunctionCall(func_call) => {
                 matches!(
                     func_call.name.to_uppercase().as_str(),
-                    "COUNT" | "SUM" | "AVG" | "AVERAGE" | "MIN" | "MAX" | "COLLECT"
+                    "COUNT"
+                        | "SUM"
+                        | "AVG"
+                        | "AVERAGE"
+                        | "MIN"
+                        | "MAX"
+                        | "COLLECT"
+                        | "APPROX_COUNT_DISTINCT"
+                        | "FIRST"
+                        | "LAST"
+                        | "ARRAY_AGG"
                 )
             }
             _ => false,
         }
     }
 
+    /// Compute window function items (ROW_NUMBER, RANK, COUNT ... OVER) of a projection.
+    /// Each result is stored in the row under the expression's column name, which
+    /// the projection then picks up instead of re-evaluating the expression.
+    fn apply_window_functions(
+        &self,
+        expressions: &[ProjectionItem],
+        rows: &mut [Row],
+        context: &ExecutionContext,
+    ) -> Result<(), ExecutionError> {
+        for proj_item in expressions {
+            let window = match &proj_item.expression {
+                Expression::Window(window) => window,
+                _ => continue,
+            };
+            let function_name = window.function.name.to_uppercase();
+            if !matches!(function_name.as_str(), "ROW_NUMBER" | "RANK" | "COUNT") {
+                return Err(ExecutionError::UnsupportedOperator(format!(
+                    "window function {}",
+                    window.function.name
+                )));
+            }
+            let column_name = self.expression_to_string(&proj_item.expression);
+
+            // Group rows by partition key, keeping each row's ORDER BY values and
+            // whether it contributes to COUNT
+            let mut partitions: HashMap<String, Vec<(usize, Vec<Value>, bool)>> = HashMap::new();
+            for (index, row) in rows.iter().enumerate() {
+                let mut row_context = context.clone();
+                for (k, v) in &row.values {
+                    row_context.set_variable(k.clone(), v.clone());
+                }
+
+                let mut partition_key = String::new();
+                for expr in &window.partition_by {
+                    let value = self.evaluate_expression(expr, &row_context)?;
+                    partition_key.push_str(&format!("{:?}|", value));
+                }
+                let order_values = window
+                    .order_by
+                    .iter()
+                    .map(|item| self.evaluate_expression(&item.expression, &row_context))
+                    .collect::<Result<Vec<_>, _>>()?;
+                let counted = match window.function.arguments.first() {
+                    Some(Expression::Variable(var)) if var.name == "*" => true,
+                    Some(arg) => {
+                        !matches!(self.evaluate_expression(arg, &row_context)?, Value::Null)
+                    }
+                    None => true,
+                };
+
+                partitions
+                    .entry(partition_key)
+                    .or_default()
+                    .push((index, order_values, counted));
+            }
+
+            let compare = |a: &[Value], b: &[Value]| {
+                for (item, (a_val, b_val)) in window.order_by.iter().zip(a.iter().zip(b.iter())) {
+                    let ordering = compare_sort_keys(
+                        a_val,
+                        b_val,
+                        item.is_ascending(),
+                        item.nulls_first(),
+                        context.collation,
+                    );
+                    if ordering != std::cmp::Ordering::Equal {
+                        return ordering;
+                    }
+                }
+                std::cmp::Ordering::Equal
+            };
+
+            for members in partitions.values_mut() {
+                members.sort_by(|a, b| compare(&a.1, &b.1));
+
+                // Walk peer groups (rows with equal ORDER BY values); without ORDER BY
+                // the whole partition is one peer group
+                let mut running_count = 0;
+                let mut group_start = 0;
+                while group_start < members.len() {
+                    let mut group_end = group_start + 1;
+                    while group_end < members.len()
+                        && compare(&members[group_start].1, &members[group_end].1)
+                            == std::cmp::Ordering::Equal
+                    {
+                        group_end += 1;
+                    }
+                    running_count += members[group_start..group_end]
+                        .iter()
+                        .filter(|member| member.2)
+                        .count();
+
+                    for (offset, member) in members[group_start..group_end].iter().enumerate() {
+                        let value = match function_name.as_str() {
+                            "ROW_NUMBER" => group_start + offset + 1,
+                            "RANK" => group_start + 1,
+                            _ => running_count,
+                        };
+                        rows[member.0]
+                            .values
+                            .insert(column_name.clone(), Value::Number(value as f64));
+                    }
+                    group_start = group_end;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
     /// Execute projection with mixed aggregate and non-aggregate expressions
     /// Returns one row per input row with aggregates evaluated per row (typically COUNT=1)
     fn execute_mixed_aggregate_projection(
@@ -6011,7 +7413,8 @@ impl QueryExecutor {
                             context.storage_manager.clone(),
                             context.current_graph.clone(),
                             context.get_current_graph_name(),
-                        );
+                        )
+                        .with_time_zone(context.time_zone.clone());
 
                         function.execute(&function_context).map_err(|e| {
                             ExecutionError::UnsupportedOperator(format!(
@@ -6097,7 +7500,8 @@ impl QueryExecutor {
                     context.storage_manager.clone(),
                     context.current_graph.clone(),
                     context.get_current_graph_name(),
-                );
+                )
+                .with_time_zone(context.time_zone.clone());
 
                 // Execute the function
                 function.execute(&function_context).map_err(|e| {
@@ -6140,10 +7544,10 @@ impl QueryExecutor {
         Ok(limited_rows)
     }
 
-    /// Execute full-text search operation
-    fn node_matches_properties(
+    /// Check whether a node or edge property map satisfies inline property constraints
+    fn properties_match(
         &self,
-        node: &crate::storage::Node,
+        properties: &HashMap<String, Value>,
         property_filters: &HashMap<String, Expression>,
     ) -> Result<bool, ExecutionError> {
         for (prop_name, expected_expr) in property_filters {
@@ -6161,15 +7565,15 @@ impl QueryExecutor {
                 }
             };
 
-            // Check if the node has this property with the expected value
-            match node.properties.get(prop_name) {
+            // Check if the entity has this property with the expected value
+            match properties.get(prop_name) {
                 Some(actual_value) => {
                     if actual_value != &expected_value {
                         return Ok(false);
                     }
                 }
                 None => {
-                    // Node doesn't have this property
+                    // Entity doesn't have this property
                     return Ok(false);
                 }
             }
@@ -6188,10 +7592,11 @@ impl QueryExecutor {
         direction: &EdgeDirection,
         properties: Option<&HashMap<String, Expression>>,
         input_rows: Vec<Row>,
-        _context: &mut ExecutionContext,
+        context: &mut ExecutionContext,
         graph: &Arc<GraphCache>,
     ) -> Result<Vec<Row>, ExecutionError> {
         let mut result_rows = Vec::new();
+        let deterministic_order = context.deterministic_order();
 
         for input_row in input_rows.iter() {
             // Get the from_variable node ID from the input row
@@ -6225,15 +7630,24 @@ impl QueryExecutor {
                     }
                 };
 
-                // Filter edges by labels if specified
-                let filtered_edges: Vec<_> = if edge_labels.is_empty() {
-                    edges
-                } else {
-                    edges
-                        .into_iter()
-                        .filter(|edge| edge_labels.iter().any(|label| &edge.label == label))
-                        .collect()
-                };
+                // Filter edges by labels and inline property constraints if specified
+                let mut filtered_edges = Vec::new();
+                for edge in edges {
+                    if !edge_labels.is_empty()
+                        && !edge_labels.iter().any(|label| &edge.label == label)
+                    {
+                        continue;
+                    }
+                    if let Some(prop_constraints) = properties {
+                        if !self.properties_match(&edge.properties, prop_constraints)? {
+                            continue;
+                        }
+                    }
+                    filtered_edges.push(edge);
+                }
+                if deterministic_order {
+                    filtered_edges.sort_by(|a, b| a.id.cmp(&b.id));
+                }
 
                 // Create result rows for each matching edge
                 for edge in filtered_edges {
@@ -6270,34 +7684,26 @@ impl QueryExecutor {
 
                     // Get the target node and add its properties
                     if let Some(to_node) = graph.get_node(to_node_id) {
-                        // Check if target node matches property constraints
-                        let node_matches = if let Some(prop_constraints) = properties {
-                            self.node_matches_properties(to_node, prop_constraints)?
-                        } else {
-                            true // No constraints, all nodes match
-                        };
-
-                        if node_matches {
-                            // Store the node itself as the variable value (consistent with NodeSeqScan)
-                            let to_node_value = Value::Node(to_node.clone());
-                            result_row.set_value(to_variable.to_string(), to_node_value.clone());
-
-                            // IMPORTANT: Track the target node entity for identity-based set operations
-                            result_row.with_entity(to_variable, &to_node_value);
+                        // Store the node itself as the variable value (consistent with NodeSeqScan)
+                        let to_node_value = Value::Node(to_node.clone());
+                        result_row.set_value(to_variable.to_string(), to_node_value.clone());
 
-                            // Add the node ID as a special .id property
-                            let id_property_name = format!("{}.id", to_variable);
-                            result_row
-                                .set_value(id_property_name, Value::String(to_node.id.clone()));
+                        // IMPORTANT: Track the target node entity for identity-based set operations
+                        result_row.with_entity(to_variable, &to_node_value);
 
-                            // Add target node properties
-                            for (prop_name, prop_value) in &to_node.properties {
-                                let qualified_prop = format!("{}.{}", to_variable, prop_name);
-                                result_row.set_value(qualified_prop, prop_value.clone());
-                            }
+                        // Add the node ID as a special .id property
+                        let id_property_name = format!("{}.id", to_variable);
+                        result_row.set_value(id_property_name, Value::String(to_node.id.clone()));
 
-                            result_rows.push(result_row);
+                        // Add target node properties
+                        for (prop_name, prop_value) in &to_node.properties {
+                            let qualified_prop = format!("{}.{}", to_variable, prop_name);
+                            result_row.set_value(qualified_prop, prop_value.clone());
                         }
+
+                        context.charge_row(&result_row)?;
+                        result_rows.push(result_row);
+                        context.record_progress(1);
                     }
                 }
             } // Close the block introduced by the node ID extraction fix
@@ -6686,17 +8092,15 @@ impl QueryExecutor {
 
                 match (val_a, val_b) {
                     (Ok(a_val), Ok(b_val)) => {
-                        let cmp = self.compare_values(&a_val, &b_val, sort_item.nulls_first);
-                        match cmp {
-                            Some(std::cmp::Ordering::Equal) => continue, // Try next sort key
-                            Some(ordering) => {
-                                return if sort_item.ascending {
-                                    ordering
-                                } else {
-                                    ordering.reverse()
-                                };
-                            }
-                            None => continue, // Values not comparable, try next sort key
+                        match compare_sort_keys(
+                            &a_val,
+                            &b_val,
+                            sort_item.ascending,
+                            sort_item.nulls_first,
+                            context.collation,
+                        ) {
+                            std::cmp::Ordering::Equal => continue, // Try next sort key
+                            ordering => return ordering,
                         }
                     }
                     _ => continue, // Error evaluating, try next sort key
@@ -6708,40 +8112,88 @@ impl QueryExecutor {
         Ok(input_rows)
     }
 
-    /// Compare two values for sorting with NULLS ordering support
-    fn compare_values(
+    /// Execute ORDER BY + LIMIT with a bounded heap
+    ///
+    /// Returns the same rows, in the same order, as `execute_in_memory_sort`
+    /// followed by `execute_limit`, but never holds more than `offset + count`
+    /// sorted rows. Input rows are fed to the heap as they are produced, so
+    /// only inputs that cannot stream are held in full.
+    fn execute_top_k(
         &self,
-        a: &Value,
-        b: &Value,
-        nulls_first: bool,
-    ) -> Option<std::cmp::Ordering> {
-        use crate::storage::Value;
-        use std::cmp::Ordering;
+        sort_expressions: &[SortItem],
+        count: usize,
+        offset: Option<usize>,
+        input: &PhysicalNode,
+        context: &mut ExecutionContext,
+        graph: &Arc<GraphCache>,
+    ) -> Result<Vec<Row>, ExecutionError> {
+        let offset_val = offset.unwrap_or(0);
+        let mut top_k = OrderedTopK::new(sort_expressions, count.saturating_add(offset_val))
+            .with_collation(context.collation);
 
-        match (a, b) {
-            (Value::Number(a), Value::Number(b)) => {
-                Some(a.partial_cmp(b).unwrap_or(Ordering::Equal))
-            }
-            (Value::String(a), Value::String(b)) => Some(a.cmp(b)),
-            (Value::Boolean(a), Value::Boolean(b)) => Some(a.cmp(b)),
-            (Value::Null, Value::Null) => Some(Ordering::Equal),
-            (Value::Null, _) => Some(if nulls_first {
-                Ordering::Less
-            } else {
-                Ordering::Greater
-            }),
-            (_, Value::Null) => Some(if nulls_first {
-                Ordering::Greater
-            } else {
-                Ordering::Less
-            }),
-            // For different types, convert to string for comparison
-            _ => {
-                let a_str = format!("{:?}", a);
-                let b_str = format!("{:?}", b);
-                Some(a_str.cmp(&b_str))
+        let materialized =
+            self.stream_node_with_graph(input, context, graph, &mut |row, context| {
+                context.clear_locals();
+                for (k, v) in &row.values {
+                    context.set_variable(k.clone(), v.clone());
+                }
+                // Sort keys that fail to evaluate compare as equal, as in a full sort
+                let keys = sort_expressions
+                    .iter()
+                    .map(|sort_item| {
+                        self.evaluate_expression(&sort_item.expression, context)
+                            .ok()
+                    })
+                    .collect();
+                top_k.add(row, keys);
+                Ok(())
+            })?;
+        context.record_rows_held(materialized + top_k.peak_len());
+
+        Ok(top_k
+            .into_sorted_rows()
+            .into_iter()
+            .skip(offset_val)
+            .collect())
+    }
+
+    /// Execute ORDER BY <similarity function> + LIMIT with a score min-heap
+    ///
+    /// Each row is scored once and only the best `offset + count` numeric scores
+    /// are kept. Rows whose score is NULL or fails to evaluate are ordered like
+    /// NULLs in `execute_in_memory_sort`, so results match a full sort.
+    #[allow(clippy::too_many_arguments)]
+    fn execute_vector_top_k(
+        &self,
+        score: &Expression,
+        ascending: bool,
+        count: usize,
+        offset: Option<usize>,
+        input: &PhysicalNode,
+        context: &mut ExecutionContext,
+        graph: &Arc<GraphCache>,
+    ) -> Result<Vec<Row>, ExecutionError> {
+        let offset_val = offset.unwrap_or(0);
+        let mut top_k = VectorTopK::new(ascending, count.saturating_add(offset_val));
+
+        self.stream_node_with_graph(input, context, graph, &mut |row, context| {
+            context.clear_locals();
+            for (k, v) in &row.values {
+                context.set_variable(k.clone(), v.clone());
             }
-        }
+            let value = match self.evaluate_expression(score, context) {
+                Ok(Value::Number(n)) => Some(n),
+                _ => None,
+            };
+            top_k.add(row, value);
+            Ok(())
+        })?;
+
+        Ok(top_k
+            .into_sorted_rows()
+            .into_iter()
+            .skip(offset_val)
+            .collect())
     }
 
     /// Execute DISTINCT operation to remove duplicate rows
@@ -6752,22 +8204,14 @@ impl QueryExecutor {
         let mut unique_rows = Vec::new();
 
         for row in input_rows {
-            // Create a unique key from all column values in the row
-            let mut row_key = String::new();
-
-            // Sort the keys to ensure consistent ordering for comparison
-            let mut sorted_keys: Vec<_> = row.values.keys().collect();
-            sorted_keys.sort();
-
-            for key in sorted_keys {
-                if let Some(value) = row.values.get(key) {
-                    // Append key and value to create unique row signature
-                    row_key.push_str(key);
-                    row_key.push(':');
-                    row_key.push_str(&format!("{:?}", value));
-                    row_key.push('|');
-                }
-            }
+            // Key each row by its columns in name order, so equal rows match
+            // however their values were inserted
+            let mut row_key: Vec<(String, Value)> = row
+                .values
+                .iter()
+                .map(|(name, value)| (name.clone(), value.clone()))
+                .collect();
+            row_key.sort_by(|(a, _), (b, _)| a.cmp(b));
 
             // Only include row if we haven't seen this exact combination before
             if seen_rows.insert(row_key) {
@@ -6783,6 +8227,7 @@ impl QueryExecutor {
     fn execute_session_statement(
         &self,
         stmt: &SessionStatement,
+        context: &mut ExecutionContext,
     ) -> Result<QueryResult, ExecutionError> {
         use crate::ast::SessionSetClause;
         use crate::exec::result::SessionResult;
@@ -6835,11 +8280,44 @@ impl QueryExecutor {
                         Ok(QueryResult::for_session(session_result))
                     }
                     SessionSetClause::TimeZone { time_zone } => {
+                        parse_timezone(time_zone).map_err(|_| {
+                            ExecutionError::InvalidQuery(format!(
+                                "Unknown time zone '{}': use a name such as 'America/New_York' or an offset such as '-05:00'",
+                                time_zone
+                            ))
+                        })?;
                         let session_result = SessionResult::SetTimeZone {
                             timezone: time_zone.clone(),
                         };
                         Ok(QueryResult::for_session(session_result))
                     }
+                    SessionSetClause::Collation { collation } => {
+                        let resolved = Collation::from_name(collation).ok_or_else(|| {
+                            ExecutionError::InvalidQuery(format!(
+                                "Unknown collation '{}': use 'codepoint' or a case-insensitive collation such as 'en_US_ci'",
+                                collation
+                            ))
+                        })?;
+                        let session_result = SessionResult::SetCollation {
+                            name: collation.clone(),
+                            collation: resolved,
+                        };
+                        Ok(QueryResult::for_session(session_result))
+                    }
+                    SessionSetClause::ValueParameter {
+                        parameter,
+                        value_initializer,
+                        if_not_exists,
+                    } => {
+                        // Evaluate the initializer now so the session stores a plain value
+                        let value = self.evaluate_expression(value_initializer, context)?;
+                        let session_result = SessionResult::SetParameter {
+                            name: parameter.clone(),
+                            value,
+                            if_not_exists: *if_not_exists,
+                        };
+                        Ok(QueryResult::for_session(session_result))
+                    }
                     _ => {
                         // Other session parameter types not yet supported
                         Err(ExecutionError::UnsupportedOperator(format!(
@@ -6905,14 +8383,9 @@ impl QueryExecutor {
 
         // Apply LIMIT if present
         if let Some(ref limit_clause) = set_op.limit_clause {
-            let offset = limit_clause.offset.unwrap_or(0);
+            let (count, offset) = self.resolve_limit_clause(limit_clause, context)?;
 
-            result.rows = result
-                .rows
-                .into_iter()
-                .skip(offset)
-                .take(limit_clause.count)
-                .collect();
+            result.rows = result.rows.into_iter().skip(offset).take(count).collect();
         }
 
         Ok(result)
@@ -6937,7 +8410,7 @@ impl QueryExecutor {
                 };
 
                 // Use the planner to create a physical plan
-                let mut planner = crate::plan::optimizer::QueryPlanner::new();
+                let mut planner = self.query_planner(context.get_current_graph_name());
                 let plan = planner.plan_query(&document).map_err(|e| {
                     ExecutionError::PlanningError(format!("Failed to plan query: {}", e))
                 })?;
@@ -6977,7 +8450,7 @@ impl QueryExecutor {
 
                 // Apply LIMIT if present
                 if let Some(limit) = limit_clause {
-                    result = self.apply_limit(result, limit)?;
+                    result = self.apply_limit(result, limit, context)?;
                 }
 
                 Ok(result)
@@ -6996,7 +8469,10 @@ impl QueryExecutor {
                         limit_clause: with_query.limit_clause.clone(),
                         location: with_query.location.clone(),
                     };
-                    self.execute_query_recursive(&crate::ast::Query::Basic(basic_query), context)
+                    self.execute_query_recursive(
+                        &crate::ast::Query::Basic(Box::new(basic_query)),
+                        context,
+                    )
                 } else {
                     Err(ExecutionError::InvalidQuery(
                         "WITH query has no segments".to_string(),
@@ -7378,7 +8854,7 @@ impl QueryExecutor {
                 .ok_or_else(|| ExecutionError::RuntimeError("Graph not found".to_string()))?;
 
             // Modify the graph
-            if let Some(node) = graph.get_node_mut(node_id) {
+            if let Some(mut node) = graph.get_node_mut(node_id) {
                 node.set_property(property.property.clone(), new_value.clone());
                 log::debug!(
                     "SET {}.{} = {:?} (node_id: {})",
@@ -7399,7 +8875,7 @@ impl QueryExecutor {
                     "Node {} not found in graph",
                     node_id
                 )));
-            }
+            };
         } else {
             return Err(ExecutionError::RuntimeError(
                 "No storage manager available for property assignment".to_string(),
@@ -7430,9 +8906,10 @@ impl QueryExecutor {
             // Evaluate the UNWIND expression in the context of this row
             let list_value = self.evaluate_expression(&unwind_clause.expression, &row_context)?;
 
-            // The expression should evaluate to a list
+            // The expression should evaluate to a list; NULL unwinds to no rows
             let items = match list_value {
-                Value::List(items) => items,
+                Value::List(items) | Value::Array(items) => items,
+                Value::Null => continue,
                 _ => {
                     return Err(ExecutionError::RuntimeError(format!(
                         "UNWIND expression must evaluate to a list, got: {:?}",
@@ -8011,6 +9488,7 @@ impl QueryExecutor {
                 element_type: Box::new(GqlType::String { max_length: None }),
                 max_length: None,
             }),
+            crate::ast::Literal::Map(_) => Ok(GqlType::Record),
         }
     }
 
@@ -8025,6 +9503,7 @@ impl QueryExecutor {
             Value::DateTimeWithFixedOffset(_) => GqlType::ZonedDateTime { precision: None },
             Value::DateTimeWithNamedTz(_, _) => GqlType::ZonedDateTime { precision: None },
             Value::TimeWindow(_) => GqlType::Duration { precision: None },
+            Value::Duration(_) => GqlType::Duration { precision: None },
             Value::Array(_) => GqlType::List {
                 element_type: Box::new(GqlType::String { max_length: None }),
                 max_length: None,
@@ -8054,6 +9533,7 @@ impl QueryExecutor {
             Value::Node(_) => GqlType::String { max_length: None }, // Nodes are complex objects, use String for now
             Value::Edge(_) => GqlType::String { max_length: None }, // Edges are complex objects, use String for now
             Value::Temporal(_) => GqlType::String { max_length: None }, // Temporal values are complex, use String for now
+            Value::Map(_) => GqlType::Record,
         }
     }
 
@@ -8240,7 +9720,7 @@ impl QueryExecutor {
         // For now, return a default type based on known functions
         // In a full implementation, we'd look up function signatures
         match func_name.to_uppercase().as_str() {
-            "COUNT" => Ok(GqlType::BigInt),
+            "COUNT" | "APPROX_COUNT_DISTINCT" => Ok(GqlType::BigInt),
             "SUM" | "AVG" | "MIN" | "MAX" => Ok(GqlType::Double),
             "NOW" | "DATETIME" => Ok(GqlType::ZonedDateTime { precision: None }),
             "DURATION" => Ok(GqlType::Duration { precision: None }),
@@ -8294,7 +9774,8 @@ impl QueryExecutor {
             context.storage_manager.clone(),
             context.current_graph.clone(),
             context.get_current_graph_name(),
-        );
+        )
+        .with_time_zone(context.time_zone.clone());
 
         // DEBUG: Verify what we passed to function context
         log::debug!("  FunctionContext created with:");
@@ -8329,6 +9810,8 @@ impl QueryExecutor {
         match operator {
             crate::ast::Operator::Not => match operand {
                 Value::Boolean(b) => Ok(Value::Boolean(!b)),
+                // Unknown stays unknown, e.g. NOT BETWEEN on a missing property
+                Value::Null => Ok(Value::Null),
                 _ => Err(ExecutionError::RuntimeError(
                     "NOT operator requires boolean operand".to_string(),
                 )),
@@ -8390,22 +9873,17 @@ impl QueryExecutor {
                 let val_a = self.evaluate_expression(&order_item.expression, &context_a);
                 let val_b = self.evaluate_expression(&order_item.expression, &context_b);
 
-                let ordering = match (val_a, val_b) {
-                    (Ok(Value::String(s1)), Ok(Value::String(s2))) => s1.cmp(&s2),
-                    (Ok(Value::Number(n1)), Ok(Value::Number(n2))) => {
-                        n1.partial_cmp(&n2).unwrap_or(Ordering::Equal)
-                    }
-                    (Ok(Value::Null), Ok(Value::Null)) => Ordering::Equal,
-                    (Ok(Value::Null), _) => Ordering::Less,
-                    (_, Ok(Value::Null)) => Ordering::Greater,
+                let final_ordering = match (val_a, val_b) {
+                    (Ok(a_val), Ok(b_val)) => compare_sort_keys(
+                        &a_val,
+                        &b_val,
+                        order_item.is_ascending(),
+                        order_item.nulls_first(),
+                        context.collation,
+                    ),
                     _ => Ordering::Equal,
                 };
 
-                let final_ordering = match order_item.direction {
-                    crate::ast::OrderDirection::Ascending => ordering,
-                    crate::ast::OrderDirection::Descending => ordering.reverse(),
-                };
-
                 if final_ordering != Ordering::Equal {
                     return final_ordering;
                 }
@@ -8421,9 +9899,9 @@ impl QueryExecutor {
         &self,
         mut result: QueryResult,
         limit_clause: &crate::ast::LimitClause,
+        context: &ExecutionContext,
     ) -> Result<QueryResult, ExecutionError> {
-        let offset = limit_clause.offset.unwrap_or(0);
-        let count = limit_clause.count;
+        let (count, offset) = self.resolve_limit_clause(limit_clause, context)?;
 
         // Apply offset
         if offset > 0 {
@@ -8442,6 +9920,119 @@ impl QueryExecutor {
         Ok(result)
     }
 
+    /// Resolve the count and offset of a LIMIT clause to row counts
+    fn resolve_limit_clause(
+        &self,
+        limit_clause: &crate::ast::LimitClause,
+        context: &ExecutionContext,
+    ) -> Result<(usize, usize), ExecutionError> {
+        let count = self.resolve_limit_value(&limit_clause.count, "LIMIT", context)?;
+        let offset = match &limit_clause.offset {
+            Some(offset) => self.resolve_limit_value(offset, "SKIP", context)?,
+            None => 0,
+        };
+        Ok((count, offset))
+    }
+
+    /// Resolve a LIMIT or SKIP count, binding parameters from the context
+    fn resolve_limit_value(
+        &self,
+        value: &crate::ast::LimitValue,
+        clause: &str,
+        context: &ExecutionContext,
+    ) -> Result<usize, ExecutionError> {
+        let parameter = match value {
+            crate::ast::LimitValue::Literal(count) => return Ok(*count),
+            crate::ast::LimitValue::Parameter(parameter) => parameter,
+        };
+        match self.resolve_parameter(parameter, context)? {
+            Value::Number(n) if n >= 0.0 && n.fract() == 0.0 => Ok(n as usize),
+            Value::Number(n) if n < 0.0 => Err(ExecutionError::RuntimeError(format!(
+                "{} parameter ${} must not be negative, got {}",
+                clause, parameter.name, n
+            ))),
+            Value::Null => Err(ExecutionError::RuntimeError(format!(
+                "{} parameter ${} must not be null",
+                clause, parameter.name
+            ))),
+            other => Err(ExecutionError::RuntimeError(format!(
+                "{} parameter ${} must be a non-negative integer, got {:?}",
+                clause, parameter.name, other
+            ))),
+        }
+    }
+
+    /// Replace LIMIT/SKIP parameters in a statement with their bound values
+    ///
+    /// Runs before planning, since query plans only carry literal counts.
+    fn bind_limit_parameters(
+        &self,
+        statement: &mut Statement,
+        context: &ExecutionContext,
+    ) -> Result<(), ExecutionError> {
+        match statement {
+            Statement::Query(query) => self.bind_query_limit_parameters(query, context),
+            Statement::Select(select_stmt) => {
+                self.bind_limit_clause(&mut select_stmt.limit_clause, context)
+            }
+            _ => Ok(()),
+        }
+    }
+
+    fn bind_query_limit_parameters(
+        &self,
+        query: &mut crate::ast::Query,
+        context: &ExecutionContext,
+    ) -> Result<(), ExecutionError> {
+        use crate::ast::Query;
+
+        match query {
+            Query::Basic(basic_query) => {
+                self.bind_limit_clause(&mut basic_query.limit_clause, context)
+            }
+            Query::SetOperation(set_op) => {
+                self.bind_query_limit_parameters(&mut set_op.left, context)?;
+                self.bind_query_limit_parameters(&mut set_op.right, context)?;
+                self.bind_limit_clause(&mut set_op.limit_clause, context)
+            }
+            Query::Limited {
+                query,
+                limit_clause,
+                ..
+            } => {
+                self.bind_query_limit_parameters(query, context)?;
+                self.bind_limit_clause(limit_clause, context)
+            }
+            Query::WithQuery(with_query) => {
+                for segment in &mut with_query.segments {
+                    if let Some(with_clause) = &mut segment.with_clause {
+                        self.bind_limit_clause(&mut with_clause.limit_clause, context)?;
+                    }
+                }
+                self.bind_limit_clause(&mut with_query.limit_clause, context)
+            }
+            Query::Return(return_query) => {
+                self.bind_limit_clause(&mut return_query.limit_clause, context)
+            }
+            _ => Ok(()),
+        }
+    }
+
+    fn bind_limit_clause(
+        &self,
+        limit_clause: &mut Option<crate::ast::LimitClause>,
+        context: &ExecutionContext,
+    ) -> Result<(), ExecutionError> {
+        if let Some(limit_clause) = limit_clause {
+            let (count, offset) = self.resolve_limit_clause(limit_clause, context)?;
+            limit_clause.count = crate::ast::LimitValue::Literal(count);
+            if limit_clause.offset.is_some() {
+                limit_clause.offset = Some(crate::ast::LimitValue::Literal(offset));
+            }
+        }
+        Ok(())
+    }
+
     /// Expand SELECT items, handling wildcard (*) by creating return items for all node properties
     fn expand_select_items(
         &self,
@@ -8828,37 +10419,11 @@ impl QueryExecutor {
                 }
             }
 
-            crate::ast::IsPredicateType::Label(label_expr) => {
-                // Check if the subject value is a node and has the specified label
-                if let Some(node) = subject_value.as_node() {
-                    // For simple case, check if any label term matches
-                    // This is a simplified implementation - full ISO GQL would need proper label expression evaluation
-                    for term in &label_expr.terms {
-                        for factor in &term.factors {
-                            match factor {
-                                crate::ast::LabelFactor::Identifier(label_name) => {
-                                    if node.has_label(label_name) {
-                                        return Ok(Value::Boolean(!predicate.negated));
-                                    }
-                                }
-                                crate::ast::LabelFactor::Wildcard => {
-                                    // Wildcard matches any node with at least one label
-                                    if !node.labels.is_empty() {
-                                        return Ok(Value::Boolean(!predicate.negated));
-                                    }
-                                }
-                                _ => {
-                                    // For other label factor types, return false for now
-                                }
-                            }
-                        }
-                    }
-                    false
-                } else {
-                    // If the subject is not a node, it cannot have a label
-                    false
-                }
-            }
+            crate::ast::IsPredicateType::Label(label_expr) => match &subject_value {
+                Value::Node(node) => label_expr.matches(&node.labels),
+                // Only nodes carry labels
+                _ => false,
+            },
         };
 
         let final_result = if predicate.negated { !result } else { result };
@@ -9020,6 +10585,14 @@ impl QueryExecutor {
             Expression::Parameter(_) => true, // Parameters are scalar values
             Expression::PropertyAccess(_) => false,
             Expression::PathConstructor(_) => false,
+            Expression::ListConstructor(list_constructor) => list_constructor
+                .elements
+                .iter()
+                .all(|element| self.is_scalar_expression(element)),
+            Expression::MapConstructor(map_constructor) => map_constructor
+                .entries
+                .iter()
+                .all(|(_, value)| self.is_scalar_expression(value)),
             Expression::Subquery(_) => false,
             Expression::ExistsSubquery(_) => false,
             Expression::NotExistsSubquery(_) => false,
@@ -9027,11 +10600,19 @@ impl QueryExecutor {
             Expression::NotInSubquery(_) => false,
             Expression::QuantifiedComparison(_) => false,
             Expression::IsPredicate(_) => false,
+            Expression::Window(_) => false, // Window functions span multiple rows
             Expression::ArrayIndex(array_index) => {
                 // Array indexing is scalar if both array and index are scalar
                 self.is_scalar_expression(&array_index.array)
                     && self.is_scalar_expression(&array_index.index)
             }
+            Expression::ArraySlice(array_slice) => {
+                self.is_scalar_expression(&array_slice.array)
+                    && [&array_slice.start, &array_slice.end]
+                        .into_iter()
+                        .flatten()
+                        .all(|bound| self.is_scalar_expression(bound))
+            }
             Expression::Pattern(_) => false, // Patterns are graph-dependent
         }
     }
@@ -9288,6 +10869,12 @@ impl QueryExecutor {
                             crate::plan::logical::AggregateFunction::Min => "MIN",
                             crate::plan::logical::AggregateFunction::Max => "MAX",
                             crate::plan::logical::AggregateFunction::Collect => "COLLECT",
+                            crate::plan::logical::AggregateFunction::ApproxCountDistinct => {
+                                "APPROX_COUNT_DISTINCT"
+                            }
+                            crate::plan::logical::AggregateFunction::First => "FIRST",
+                            crate::plan::logical::AggregateFunction::Last => "LAST",
+                            crate::plan::logical::AggregateFunction::ArrayAgg => "ARRAY_AGG",
                         };
                         format!(
                             "{}_{}",
@@ -9305,14 +10892,15 @@ impl QueryExecutor {
             PhysicalNode::Limit { input, .. } => self.extract_variables_from_node(input),
 
             // For Sort nodes, check the input node
-            PhysicalNode::ExternalSort { input, .. } | PhysicalNode::InMemorySort { input, .. } => {
-                self.extract_variables_from_node(input)
-            }
-
-            // For Filter and Having nodes, check the input node
-            PhysicalNode::Filter { input, .. } | PhysicalNode::Having { input, .. } => {
-                self.extract_variables_from_node(input)
-            }
+            PhysicalNode::ExternalSort { input, .. }
+            | PhysicalNode::InMemorySort { input, .. }
+            | PhysicalNode::TopK { input, .. }
+            | PhysicalNode::VectorTopK { input, .. } => self.extract_variables_from_node(input),
+
+            // For Filter, Having and GraphScope nodes, check the input node
+            PhysicalNode::Filter { input, .. }
+            | PhysicalNode::Having { input, .. }
+            | PhysicalNode::GraphScope { input, .. } => self.extract_variables_from_node(input),
 
             // For other nodes, we don't have explicit variable information
             _ => None,
@@ -0,0 +1,242 @@
+// Copyright (c) 2024-2025 DeepGraph Inc.
+// SPDX-License-Identifier: Apache-2.0
+//
+//! Graph algorithm procedures
+//!
+//! These db.* procedures run against the current graph, unlike the gql.* system
+//! procedures and the db.schemas/db.graphs listings, which only consult the
+//! catalog and session state.
+
+use std::collections::HashMap;
+
+use super::error::ExecutionError;
+use super::result::{QueryResult, Row};
+use crate::catalog::system_procedures::ProcedureInfo;
+use crate::storage::{GraphCache, Value};
+
+/// Every graph algorithm procedure, as listed by CALL db.procedures()
+pub const GRAPH_PROCEDURES: &[ProcedureInfo] = &[
+    ProcedureInfo {
+        name: "db.connected_components",
+        signature: "(node_label :: STRING, edge_label :: STRING)",
+        min_args: 2,
+        max_args: 2,
+        description: "Number the connected components of the current graph",
+    },
+    ProcedureInfo {
+        name: "db.graph_stats",
+        signature: "()",
+        min_args: 0,
+        max_args: 0,
+        description: "Report the size, density and degree distribution of the current graph",
+    },
+];
+
+/// Check if a procedure name is a graph algorithm procedure
+pub fn is_graph_procedure(procedure_name: &str) -> bool {
+    GRAPH_PROCEDURES
+        .iter()
+        .any(|procedure| procedure.name == procedure_name)
+}
+
+/// Execute a graph algorithm procedure against a graph
+pub fn execute_graph_procedure(
+    procedure_name: &str,
+    args: Vec<Value>,
+    graph: &GraphCache,
+) -> Result<QueryResult, ExecutionError> {
+    match procedure_name {
+        "db.connected_components" => connected_components(args, graph),
+        "db.graph_stats" => graph_stats(args, graph),
+        _ => Err(ExecutionError::UnsupportedOperator(format!(
+            "Graph procedure not found or not supported: {}. Available graph procedures: connected_components, graph_stats",
+            procedure_name
+        ))),
+    }
+}
+
+/// CALL db.connected_components(node_label, edge_label) YIELD node_id, component_id
+///
+/// Edges of the given label are treated as undirected. Components are numbered
+/// from 0 in order of their smallest node id, so the numbering is stable.
+fn connected_components(
+    args: Vec<Value>,
+    graph: &GraphCache,
+) -> Result<QueryResult, ExecutionError> {
+    let (node_label, edge_label) = match args.as_slice() {
+        [Value::String(node_label), Value::String(edge_label)] => (node_label, edge_label),
+        _ => {
+            return Err(ExecutionError::RuntimeError(
+                "connected_components expects 2 string arguments: node label and edge label"
+                    .to_string(),
+            ))
+        }
+    };
+
+    let mut node_ids: Vec<&str> = graph
+        .get_nodes_by_label(node_label)
+        .into_iter()
+        .map(|node| node.id.as_str())
+        .collect();
+    node_ids.sort_unstable();
+
+    let positions: HashMap<&str, usize> = node_ids
+        .iter()
+        .enumerate()
+        .map(|(position, id)| (*id, position))
+        .collect();
+
+    let mut components = UnionFind::new(node_ids.len());
+    for edge in graph.get_edges_by_label(edge_label) {
+        if let (Some(&from), Some(&to)) = (
+            positions.get(edge.from_node.as_str()),
+            positions.get(edge.to_node.as_str()),
+        ) {
+            components.union(from, to);
+        }
+    }
+
+    let mut component_ids: HashMap<usize, usize> = HashMap::new();
+    let mut rows = Vec::with_capacity(node_ids.len());
+    for (position, node_id) in node_ids.iter().enumerate() {
+        let root = components.find(position);
+        let next_id = component_ids.len();
+        let component_id = *component_ids.entry(root).or_insert(next_id);
+
+        let mut row_values = HashMap::new();
+        row_values.insert("node_id".to_string(), Value::String(node_id.to_string()));
+        row_values.insert(
+            "component_id".to_string(),
+            Value::Number(component_id as f64),
+        );
+        rows.push(Row::from_values(row_values));
+    }
+
+    Ok(QueryResult {
+        rows_affected: rows.len(),
+        session_result: None,
+        warnings: Vec::new(),
+        rows,
+        variables: vec!["node_id".to_string(), "component_id".to_string()],
+        execution_time_ms: 0,
+    })
+}
+
+/// CALL db.graph_stats() YIELD node_count, edge_count, average_degree, max_degree, density, degree_histogram
+///
+/// A node's degree counts its incoming and outgoing edges, so a self loop
+/// counts twice. Density is the share of the possible directed edges between
+/// distinct nodes that exist. The histogram maps power-of-two degree buckets
+/// ("0", "1", "2-3", "4-7", ...) up to the one holding the max degree to their
+/// node counts.
+fn graph_stats(args: Vec<Value>, graph: &GraphCache) -> Result<QueryResult, ExecutionError> {
+    if !args.is_empty() {
+        return Err(ExecutionError::RuntimeError(
+            "graph_stats expects no arguments".to_string(),
+        ));
+    }
+
+    let nodes = graph.get_all_nodes();
+    let node_count = nodes.len();
+    let edge_count = graph.get_all_edges().len();
+
+    let mut max_degree = 0;
+    let mut bucket_counts: Vec<usize> = vec![0];
+    for node in &nodes {
+        let degree =
+            graph.get_outgoing_edges(&node.id).len() + graph.get_incoming_edges(&node.id).len();
+        max_degree = max_degree.max(degree);
+
+        // Bucket 0 holds degree 0, bucket b holds degrees 2^(b-1) to 2^b - 1
+        let bucket = (usize::BITS - degree.leading_zeros()) as usize;
+        if bucket_counts.len() <= bucket {
+            bucket_counts.resize(bucket + 1, 0);
+        }
+        bucket_counts[bucket] += 1;
+    }
+
+    let degree_histogram: HashMap<String, Value> = bucket_counts
+        .iter()
+        .enumerate()
+        .map(|(bucket, count)| {
+            let range = match bucket {
+                0 => "0".to_string(),
+                1 => "1".to_string(),
+                _ => format!("{}-{}", 1usize << (bucket - 1), (1usize << bucket) - 1),
+            };
+            (range, Value::Number(*count as f64))
+        })
+        .collect();
+
+    let (average_degree, density) = if node_count == 0 {
+        (0.0, 0.0)
+    } else {
+        let possible_edges = node_count * (node_count - 1);
+        let density = if possible_edges == 0 {
+            0.0
+        } else {
+            edge_count as f64 / possible_edges as f64
+        };
+        (2.0 * edge_count as f64 / node_count as f64, density)
+    };
+
+    let row = Row::from_values(HashMap::from([
+        ("node_count".to_string(), Value::Number(node_count as f64)),
+        ("edge_count".to_string(), Value::Number(edge_count as f64)),
+        ("average_degree".to_string(), Value::Number(average_degree)),
+        ("max_degree".to_string(), Value::Number(max_degree as f64)),
+        ("density".to_string(), Value::Number(density)),
+        ("degree_histogram".to_string(), Value::Map(degree_histogram)),
+    ]));
+
+    Ok(QueryResult {
+        rows_affected: 1,
+        session_result: None,
+        warnings: Vec::new(),
+        rows: vec![row],
+        variables: vec![
+            "node_count".to_string(),
+            "edge_count".to_string(),
+            "average_degree".to_string(),
+            "max_degree".to_string(),
+            "density".to_string(),
+            "degree_histogram".to_string(),
+        ],
+        execution_time_ms: 0,
+    })
+}
+
+/// Disjoint-set forest with path halving and union by size
+struct UnionFind {
+    parent: Vec<usize>,
+    size: Vec<usize>,
+}
+
+impl UnionFind {
+    fn new(len: usize) -> Self {
+        Self {
+            parent: (0..len).collect(),
+            size: vec![1; len],
+        }
+    }
+
+    fn find(&mut self, mut element: usize) -> usize {
+        while self.parent[element] != element {
+            self.parent[element] = self.parent[self.parent[element]];
+            element = self.parent[element];
+        }
+        element
+    }
+
+    fn union(&mut self, a: usize, b: usize) {
+        let (mut a, mut b) = (self.find(a), self.find(b));
+        if a == b {
+            return;
+        }
+        if self.size[a] < self.size[b] {
+            std::mem::swap(&mut a, &mut b);
+        }
+        self.parent[b] = a;
+        self.size[a] += self.size[b];
+    }
+}
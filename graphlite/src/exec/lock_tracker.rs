@@ -1,10 +1,13 @@
 // Copyright (c) 2024-2025 DeepGraph Inc.
 // SPDX-License-Identifier: Apache-2.0
 //
-//! Lock wait time tracking for query execution
+//! Lock wait time tracking for query execution, and graph-level locks
 
-use std::sync::{Mutex, RwLock};
-use std::time::Duration;
+use super::error::ExecutionError;
+use std::collections::HashMap;
+use std::sync::{Arc, Condvar, Mutex, RwLock};
+use std::thread::{self, ThreadId};
+use std::time::{Duration, Instant};
 
 /// Lock tracker
 #[derive(Debug, Clone)]
@@ -51,3 +54,247 @@ impl Default for LockTracker {
 pub type TrackedLock<T> = Mutex<T>;
 #[allow(dead_code)] // ROADMAP v0.6.0 - RwLock wrapper with read/write lock metrics
 pub type TrackedRwLock<T> = RwLock<T>;
+
+/// Mode of a graph lock taken with [`GraphLockTable::lock`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LockMode {
+    /// Blocks writes from other threads; reads continue
+    Shared,
+    /// Blocks reads and writes from other threads
+    Exclusive,
+}
+
+/// Kind of access a holder has to a graph
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum GraphAccess {
+    /// A statement reading the graph
+    Read,
+    /// A statement writing the graph
+    Write,
+    /// An explicit lock
+    Lock(LockMode),
+}
+
+impl GraphAccess {
+    fn conflicts_with(self, other: GraphAccess) -> bool {
+        use GraphAccess::*;
+        matches!(
+            (self, other),
+            (Lock(LockMode::Exclusive), _)
+                | (_, Lock(LockMode::Exclusive))
+                | (Lock(LockMode::Shared), Write)
+                | (Write, Lock(LockMode::Shared))
+        )
+    }
+}
+
+/// Graph-level locks for coarse-grained coordination
+///
+/// Explicit locks are owned by the thread that took them: statements run on
+/// that thread are never blocked by its own locks, so a maintenance job can
+/// lock a graph and still modify it. A wait that would close a cycle of
+/// threads waiting on each other fails with `Conflict` instead of blocking.
+#[derive(Debug, Default)]
+pub struct GraphLockTable {
+    state: Mutex<LockState>,
+    released: Condvar,
+}
+
+#[derive(Debug, Default)]
+struct LockState {
+    /// Accesses held on each graph
+    holders: HashMap<String, Vec<(ThreadId, GraphAccess)>>,
+    /// Graph and access each blocked thread is waiting for
+    waiting: HashMap<ThreadId, (String, GraphAccess)>,
+}
+
+impl LockState {
+    /// Threads other than `thread_id` holding an access to the graph that conflicts with `access`
+    fn blockers(
+        &self,
+        graph_path: &str,
+        thread_id: ThreadId,
+        access: GraphAccess,
+    ) -> impl Iterator<Item = ThreadId> + '_ {
+        self.holders
+            .get(graph_path)
+            .into_iter()
+            .flatten()
+            .filter(move |(holder, held)| *holder != thread_id && held.conflicts_with(access))
+            .map(|(holder, _)| *holder)
+    }
+
+    /// Whether waiting for the graph would leave `thread_id` waiting, through
+    /// other waiting threads, on itself
+    fn would_deadlock(&self, graph_path: &str, thread_id: ThreadId, access: GraphAccess) -> bool {
+        let mut pending: Vec<ThreadId> = self.blockers(graph_path, thread_id, access).collect();
+        let mut visited = Vec::new();
+        while let Some(blocker) = pending.pop() {
+            if blocker == thread_id {
+                return true;
+            }
+            if visited.contains(&blocker) {
+                continue;
+            }
+            visited.push(blocker);
+            if let Some((graph_path, access)) = self.waiting.get(&blocker) {
+                pending.extend(self.blockers(graph_path, blocker, *access));
+            }
+        }
+        false
+    }
+}
+
+impl GraphLockTable {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Lock a graph, waiting up to `timeout` (forever if `None`)
+    ///
+    /// Fails with `LockTimeout` if a conflicting lock is still held when the
+    /// timeout expires, and with `Conflict` if the timeout is zero and the
+    /// graph is busy. The lock is released when the guard is dropped.
+    pub fn lock(
+        self: &Arc<Self>,
+        graph_path: &str,
+        mode: LockMode,
+        timeout: Option<Duration>,
+    ) -> Result<GraphLockGuard, ExecutionError> {
+        self.acquire(graph_path, GraphAccess::Lock(mode), timeout)
+    }
+
+    /// Wait until a statement may read or write a graph, holding off
+    /// conflicting locks until the returned guard is dropped
+    pub fn enter_statement(
+        self: &Arc<Self>,
+        graph_path: &str,
+        write: bool,
+    ) -> Result<GraphLockGuard, ExecutionError> {
+        let access = if write {
+            GraphAccess::Write
+        } else {
+            GraphAccess::Read
+        };
+        self.acquire(graph_path, access, None)
+    }
+
+    fn acquire(
+        self: &Arc<Self>,
+        graph_path: &str,
+        access: GraphAccess,
+        timeout: Option<Duration>,
+    ) -> Result<GraphLockGuard, ExecutionError> {
+        let thread_id = thread::current().id();
+        let deadline = timeout.map(|timeout| Instant::now() + timeout);
+        let mut state = self
+            .state
+            .lock()
+            .map_err(|_| ExecutionError::RuntimeError("Graph lock table poisoned".to_string()))?;
+
+        let waited = loop {
+            if state
+                .blockers(graph_path, thread_id, access)
+                .next()
+                .is_none()
+            {
+                break Ok(());
+            }
+            if timeout.is_some_and(|timeout| timeout.is_zero()) {
+                break Err(ExecutionError::Conflict(format!(
+                    "graph '{}' is locked by another operation",
+                    graph_path
+                )));
+            }
+            if state.would_deadlock(graph_path, thread_id, access) {
+                break Err(ExecutionError::Conflict(format!(
+                    "waiting for graph '{}' would deadlock with another operation",
+                    graph_path
+                )));
+            }
+            state
+                .waiting
+                .insert(thread_id, (graph_path.to_string(), access));
+
+            state = match deadline {
+                None => self.released.wait(state).map_err(|_| {
+                    ExecutionError::RuntimeError("Graph lock table poisoned".to_string())
+                })?,
+                Some(deadline) => {
+                    let remaining = deadline.saturating_duration_since(Instant::now());
+                    if remaining.is_zero() {
+                        break Err(ExecutionError::LockTimeout(format!(
+                            "could not lock graph '{}' within {:?}",
+                            graph_path,
+                            timeout.unwrap_or_default()
+                        )));
+                    }
+                    self.released
+                        .wait_timeout(state, remaining)
+                        .map_err(|_| {
+                            ExecutionError::RuntimeError("Graph lock table poisoned".to_string())
+                        })?
+                        .0
+                }
+            };
+        };
+        state.waiting.remove(&thread_id);
+        waited?;
+
+        state
+            .holders
+            .entry(graph_path.to_string())
+            .or_default()
+            .push((thread_id, access));
+
+        Ok(GraphLockGuard {
+            table: Arc::clone(self),
+            graph_path: graph_path.to_string(),
+            thread_id,
+            access,
+        })
+    }
+
+    fn release(&self, graph_path: &str, thread_id: ThreadId, access: GraphAccess) {
+        let mut state = match self.state.lock() {
+            Ok(state) => state,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+        let holders = &mut state.holders;
+        if let Some(entries) = holders.get_mut(graph_path) {
+            if let Some(position) = entries
+                .iter()
+                .position(|entry| *entry == (thread_id, access))
+            {
+                entries.swap_remove(position);
+            }
+            if entries.is_empty() {
+                holders.remove(graph_path);
+            }
+        }
+        self.released.notify_all();
+    }
+}
+
+/// Holds a graph lock until dropped
+#[derive(Debug)]
+pub struct GraphLockGuard {
+    table: Arc<GraphLockTable>,
+    graph_path: String,
+    thread_id: ThreadId,
+    access: GraphAccess,
+}
+
+impl GraphLockGuard {
+    /// Path of the locked graph
+    pub fn graph_path(&self) -> &str {
+        &self.graph_path
+    }
+}
+
+impl Drop for GraphLockGuard {
+    fn drop(&mut self) {
+        self.table
+            .release(&self.graph_path, self.thread_id, self.access);
+    }
+}
@@ -73,7 +73,6 @@ impl MemoryBudget {
     /// - Development: 100MB (100 * 1024 * 1024)
     /// - Production: 1GB (1024 * 1024 * 1024)
     /// - High-memory queries: 5GB (5 * 1024 * 1024 * 1024)
-    #[allow(dead_code)] // ROADMAP v0.5.0 - Memory budget enforcement for resource control
     pub fn new(limit: usize) -> Self {
         Self {
             limit,
@@ -103,7 +102,6 @@ impl MemoryBudget {
     /// let row_size = std::mem::size_of::<Row>();
     /// budget.allocate(1000 * row_size)?;
     /// ```
-    #[allow(dead_code)] // ROADMAP v0.2.0 - Memory budget enforcement for large queries (see ROADMAP.md §1)
     pub fn allocate(&self, bytes: usize) -> Result<(), ExecutionError> {
         let current = self.allocated.fetch_add(bytes, Ordering::SeqCst);
         let new_total = current + bytes;
@@ -6,10 +6,14 @@
 //! This module provides the execution engine that takes physical query plans
 //! and executes them against graph storage to produce query results.
 
+pub mod collation;
 pub mod context;
 pub mod error;
 pub mod executor;
+pub mod graph_procedures;
 pub mod lock_tracker;
+pub mod progress;
+pub mod query_limits;
 pub mod result;
 pub mod row_iterator;
 pub mod write_stmt; // Phase 4: Week 6.5 - Memory Optimization
@@ -0,0 +1,100 @@
+// Copyright (c) 2024-2025 DeepGraph Inc.
+// SPDX-License-Identifier: Apache-2.0
+//
+//! Progress reporting for long-running queries
+//!
+//! Scans and expansions count the rows they produce into a [`QueryProgress`],
+//! which hands the running total to a callback at most once every N rows or
+//! T of wall time, whichever comes first.
+
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// Callback receiving the number of rows produced so far
+pub type ProgressCallback = Box<dyn FnMut(usize) + Send>;
+
+/// Throttled row counter shared by the operators of one query
+pub struct QueryProgress {
+    every_rows: usize,
+    every: Duration,
+    state: Mutex<ProgressState>,
+}
+
+struct ProgressState {
+    rows: usize,
+    reported_rows: usize,
+    reported_at: Instant,
+    callback: ProgressCallback,
+}
+
+impl QueryProgress {
+    /// Report after every `every_rows` new rows, or once `every` has passed
+    /// since the last report and at least one new row arrived
+    pub fn new(every_rows: usize, every: Duration, callback: ProgressCallback) -> Self {
+        Self {
+            every_rows: every_rows.max(1),
+            every,
+            state: Mutex::new(ProgressState {
+                rows: 0,
+                reported_rows: 0,
+                reported_at: Instant::now(),
+                callback,
+            }),
+        }
+    }
+
+    /// Count `count` more rows, invoking the callback if a report is due
+    pub fn record_rows(&self, count: usize) {
+        let Ok(mut state) = self.state.lock() else {
+            return;
+        };
+        state.rows += count;
+        let pending = state.rows - state.reported_rows;
+        if pending >= self.every_rows || (pending > 0 && state.reported_at.elapsed() >= self.every)
+        {
+            let rows = state.rows;
+            state.reported_rows = rows;
+            state.reported_at = Instant::now();
+            (state.callback)(rows);
+        }
+    }
+
+    /// Rows counted so far
+    pub fn rows(&self) -> usize {
+        self.state.lock().map(|state| state.rows).unwrap_or(0)
+    }
+}
+
+impl std::fmt::Debug for QueryProgress {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("QueryProgress")
+            .field("every_rows", &self.every_rows)
+            .field("every", &self.every)
+            .field("rows", &self.rows())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+
+    #[test]
+    fn test_reports_every_n_rows() {
+        let reports = Arc::new(Mutex::new(Vec::new()));
+        let sink = reports.clone();
+        let progress = QueryProgress::new(
+            10,
+            Duration::from_secs(3600),
+            Box::new(move |rows| sink.lock().unwrap().push(rows)),
+        );
+
+        for _ in 0..35 {
+            progress.record_rows(1);
+        }
+
+        assert_eq!(*reports.lock().unwrap(), vec![10, 20, 30]);
+        assert_eq!(progress.rows(), 35);
+    }
+}
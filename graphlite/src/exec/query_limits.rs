@@ -0,0 +1,180 @@
+// Copyright (c) 2024-2025 DeepGraph Inc.
+// SPDX-License-Identifier: Apache-2.0
+//
+//! Resource limits for running untrusted queries
+//!
+//! [`QueryLimits`] bundles the guards a caller wants applied to one query.
+//! Read-only enforcement happens before the query runs: only reads and an
+//! allowlist of catalog and graph procedures pass, and session statements are
+//! rejected. The row limit is checked as the root projection produces rows,
+//! and on the final result of plans that end in another operator. Memory and
+//! time are charged through a [`QueryGuard`] as scans and expansions produce
+//! rows, so a runaway query stops early instead of completing first.
+
+use std::time::{Duration, Instant};
+
+use crate::exec::error::ExecutionError;
+use crate::exec::memory_budget::MemoryBudget;
+use crate::exec::Row;
+use crate::txn::log::estimate_value_size;
+
+/// Limits for running a single query
+///
+/// Every limit is off by default.
+///
+/// # Example
+/// ```
+/// use graphlite::QueryLimits;
+/// use std::time::Duration;
+///
+/// let limits = QueryLimits::new()
+///     .max_memory_bytes(64 * 1024 * 1024)
+///     .max_rows(10_000)
+///     .timeout(Duration::from_secs(2))
+///     .read_only(true);
+/// assert_eq!(limits.row_limit(), Some(10_000));
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct QueryLimits {
+    max_memory_bytes: Option<usize>,
+    max_rows: Option<usize>,
+    timeout: Option<Duration>,
+    read_only: bool,
+}
+
+impl QueryLimits {
+    /// Limits with every guard off
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Cap the estimated bytes of the rows the query's scans and expansions
+    /// produce, counted over the whole query
+    pub fn max_memory_bytes(mut self, bytes: usize) -> Self {
+        self.max_memory_bytes = Some(bytes);
+        self
+    }
+
+    /// Cap the number of rows the query may return
+    pub fn max_rows(mut self, rows: usize) -> Self {
+        self.max_rows = Some(rows);
+        self
+    }
+
+    /// Cap the wall-clock time the query may run
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Reject statements that write data or change the catalog or session
+    pub fn read_only(mut self, read_only: bool) -> Self {
+        self.read_only = read_only;
+        self
+    }
+
+    /// The memory cap in bytes, if set
+    pub fn memory_limit(&self) -> Option<usize> {
+        self.max_memory_bytes
+    }
+
+    /// The row cap, if set
+    pub fn row_limit(&self) -> Option<usize> {
+        self.max_rows
+    }
+
+    /// The time cap, if set
+    pub fn time_limit(&self) -> Option<Duration> {
+        self.timeout
+    }
+
+    /// Whether writes are rejected
+    pub fn is_read_only(&self) -> bool {
+        self.read_only
+    }
+}
+
+/// Runtime state of the limits of one executing query
+#[derive(Debug)]
+pub(crate) struct QueryGuard {
+    limits: QueryLimits,
+    started: Instant,
+    memory: Option<MemoryBudget>,
+}
+
+impl QueryGuard {
+    /// Start enforcing `limits`; the time limit counts from now
+    pub fn new(limits: QueryLimits) -> Self {
+        Self {
+            limits,
+            started: Instant::now(),
+            memory: limits.max_memory_bytes.map(MemoryBudget::new),
+        }
+    }
+
+    /// The row cap of the result, if set
+    pub fn row_limit(&self) -> Option<usize> {
+        self.limits.max_rows
+    }
+
+    /// Fail once the query has run past its time limit
+    pub fn check_deadline(&self) -> Result<(), ExecutionError> {
+        match self.limits.timeout {
+            Some(limit) if self.started.elapsed() > limit => {
+                Err(ExecutionError::QueryTimedOut { limit })
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Charge a row produced by a scan or expansion against the memory and
+    /// time limits
+    pub fn charge_row(&self, row: &Row) -> Result<(), ExecutionError> {
+        if let Some(memory) = &self.memory {
+            let bytes = row
+                .values
+                .iter()
+                .map(|(column, value)| column.len() + estimate_value_size(value))
+                .sum();
+            memory.allocate(bytes)?;
+        }
+        self.check_deadline()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::Value;
+    use std::collections::HashMap;
+
+    fn row(name: &str) -> Row {
+        Row::from_values(HashMap::from([(
+            "p.name".to_string(),
+            Value::String(name.to_string()),
+        )]))
+    }
+
+    #[test]
+    fn test_guard_charges_rows_against_memory_limit() {
+        let guard = QueryGuard::new(QueryLimits::new().max_memory_bytes(16));
+        assert!(guard.charge_row(&row("Alice")).is_ok());
+        assert!(matches!(
+            guard.charge_row(&row("Bob")),
+            Err(ExecutionError::MemoryLimitExceeded { limit: 16, .. })
+        ));
+    }
+
+    #[test]
+    fn test_guard_reports_time_limit() {
+        let guard = QueryGuard::new(QueryLimits::new().timeout(Duration::ZERO));
+        std::thread::sleep(Duration::from_millis(1));
+        assert!(matches!(
+            guard.check_deadline(),
+            Err(ExecutionError::QueryTimedOut { .. })
+        ));
+        assert!(QueryGuard::new(QueryLimits::new())
+            .charge_row(&row("Alice"))
+            .is_ok());
+    }
+}
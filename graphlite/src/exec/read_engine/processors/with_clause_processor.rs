@@ -893,19 +893,7 @@ impl WithClauseProcessor {
                     Ok(Value::Null)
                 }
             }
-            "COLLECT" => {
-                if let Some(Expression::Variable(var)) = func_call.arguments.first() {
-                    let mut collected = Vec::new();
-                    if let Some(nodes) = group_nodes.get(&var.name) {
-                        for node in nodes {
-                            collected.push(Value::String(node.id.clone()));
-                        }
-                    }
-                    Ok(Value::String(format!("{:?}", collected)))
-                } else {
-                    Ok(Value::String("[]".to_string()))
-                }
-            }
+            "COLLECT" => Ok(Self::collect_values(func_call, group_nodes, group_edges)),
             _ => {
                 log::warn!("Unsupported group aggregation function: {}", func_call.name);
                 Ok(Value::Null)
@@ -1137,20 +1125,7 @@ impl WithClauseProcessor {
                     Ok(Value::Null)
                 }
             }
-            "COLLECT" => {
-                if let Some(Expression::Variable(var)) = func_call.arguments.first() {
-                    let mut collected = Vec::new();
-                    if let Some(nodes) = variable_bindings.get(&var.name) {
-                        for node in nodes {
-                            collected.push(Value::String(node.id.clone()));
-                        }
-                    }
-                    // For now, return as a string representation
-                    Ok(Value::String(format!("{:?}", collected)))
-                } else {
-                    Ok(Value::String("[]".to_string()))
-                }
-            }
+            "COLLECT" => Ok(Self::collect_values(func_call, variable_bindings, edges)),
             _ => {
                 log::warn!("Unsupported aggregation function: {}", func_call.name);
                 Ok(Value::Null)
@@ -1158,6 +1133,57 @@ impl WithClauseProcessor {
         }
     }
 
+    /// Evaluate COLLECT(x) into a list
+    ///
+    /// `COLLECT(n)` gathers the bound nodes and `COLLECT(n.prop)` their property
+    /// values, falling back to edge properties when `n` is not a node variable.
+    /// NULLs are skipped, so collecting over no rows yields an empty list.
+    fn collect_values(
+        func_call: &FunctionCall,
+        variable_bindings: &HashMap<String, Vec<Node>>,
+        edges: &[Edge],
+    ) -> Value {
+        let mut collected = Vec::new();
+        match func_call.arguments.first() {
+            Some(Expression::Variable(var)) => {
+                if let Some(nodes) = variable_bindings.get(&var.name) {
+                    collected.extend(nodes.iter().cloned().map(Value::Node));
+                }
+            }
+            Some(Expression::PropertyAccess(prop_access)) => {
+                match variable_bindings.get(&prop_access.object) {
+                    Some(nodes) => collected.extend(
+                        nodes
+                            .iter()
+                            .filter_map(|node| node.properties.get(&prop_access.property))
+                            .cloned(),
+                    ),
+                    None => collected.extend(
+                        edges
+                            .iter()
+                            .filter_map(|edge| edge.properties.get(&prop_access.property))
+                            .cloned(),
+                    ),
+                }
+            }
+            other => {
+                log::warn!("Unsupported COLLECT argument: {:?}", other);
+            }
+        }
+        collected.retain(|value| !matches!(value, Value::Null));
+
+        if func_call.distinct == DistinctQualifier::Distinct {
+            let mut distinct: Vec<Value> = Vec::with_capacity(collected.len());
+            for value in collected {
+                if !distinct.contains(&value) {
+                    distinct.push(value);
+                }
+            }
+            collected = distinct;
+        }
+        Value::List(collected)
+    }
+
     /// Check if an expression involves aggregation
     fn is_aggregation_expression(expr: &Expression) -> bool {
         match expr {
@@ -1192,13 +1218,19 @@ impl WithClauseProcessor {
             Literal::Boolean(b) => Value::Boolean(*b),
             Literal::Null => Value::Null,
             Literal::DateTime(dt) => Value::String(dt.clone()),
-            Literal::Duration(dur) => Value::String(dur.clone()),
+            Literal::Duration(dur) => Value::from_duration_literal(dur),
             Literal::TimeWindow(tw) => Value::String(tw.clone()),
             Literal::Vector(vec) => Value::String(format!("{:?}", vec)),
             Literal::List(list) => {
                 let converted: Vec<Value> = list.iter().map(Self::literal_to_value).collect();
                 Value::List(converted)
             }
+            Literal::Map(entries) => Value::Map(
+                entries
+                    .iter()
+                    .map(|(key, value)| (key.clone(), Self::literal_to_value(value)))
+                    .collect(),
+            ),
         }
     }
 
@@ -7,6 +7,7 @@ use crate::ast::{CatalogPath, GraphExpression};
 use crate::storage::Value;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::fmt;
 use std::hash::{Hash, Hasher};
 
 /// Entity identifier for tracking graph element identities in set operations
@@ -34,6 +35,17 @@ pub enum SessionResult {
     },
     /// Set session timezone
     SetTimeZone { timezone: String },
+    /// Set the session's string collation
+    SetCollation {
+        name: String,
+        collation: crate::exec::collation::Collation,
+    },
+    /// Bind a session value parameter (`SESSION SET VALUE $name = expr`)
+    SetParameter {
+        name: String,
+        value: Value,
+        if_not_exists: bool,
+    },
     /// Reset session to defaults
     Reset,
     /// Close session
@@ -139,6 +151,12 @@ impl QueryResult {
             SessionResult::SetTimeZone { timezone } => {
                 format!("Session timezone set to: {}", timezone)
             }
+            SessionResult::SetCollation { name, .. } => {
+                format!("Session collation set to: {}", name)
+            }
+            SessionResult::SetParameter { name, value, .. } => {
+                format!("Session parameter ${} set to: {}", name, value)
+            }
             SessionResult::Reset => "Session reset to defaults".to_string(),
             SessionResult::Close => "Session closed".to_string(),
         })
@@ -293,6 +311,28 @@ impl Default for Row {
     }
 }
 
+impl fmt::Display for Row {
+    /// Tab-separated column values, in positional order when available and
+    /// otherwise sorted by column name
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let values: Vec<&Value> = if self.positional_values.is_empty() {
+            let mut columns: Vec<_> = self.values.iter().collect();
+            columns.sort_by_key(|(name, _)| *name);
+            columns.into_iter().map(|(_, value)| value).collect()
+        } else {
+            self.positional_values.iter().collect()
+        };
+
+        for (i, value) in values.into_iter().enumerate() {
+            if i > 0 {
+                write!(f, "\t")?;
+            }
+            write!(f, "{}", value)?;
+        }
+        Ok(())
+    }
+}
+
 impl PartialEq for Row {
     fn eq(&self, other: &Self) -> bool {
         // For set operations, compare positionally if both have positional values
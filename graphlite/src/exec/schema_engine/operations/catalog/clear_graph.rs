@@ -65,28 +65,28 @@ impl DDLStatementExecutor for ClearGraphExecutor {
         };
 
         // Step 1: Clear all graph data from storage FIRST
-        // CLEAR removes all nodes and edges but keeps the graph and its schema
-        let empty_graph = crate::storage::GraphCache::new();
-        storage.save_graph(&full_path, empty_graph).map_err(|e| {
+        // CLEAR removes all nodes and edges but keeps the graph and its schema.
+        // The graph is reset in one storage operation, without per-element undo records
+        let removed = storage.truncate_graph(&full_path).map_err(|e| {
             ExecutionError::StorageError(format!(
                 "Failed to clear graph data for '{}': {}",
                 full_path, e
             ))
         })?;
+        let removed_count = removed.node_count + removed.edge_count;
+
+        // Cached query results over the old data are stale now
+        if let Some(cache_mgr) = &context.cache_manager {
+            cache_mgr.invalidate_on_data_change(Some(full_path.clone()), removed_count as u64);
+        }
 
         // Step 2: Update catalog metadata if needed (CLEAR typically doesn't change metadata)
         // Use CatalogManager to handle clear operation
-        let simple_graph_name = if let Some(ref catalog_path) = self.statement.graph_path {
-            catalog_path
-                .segments
-                .last()
-                .map_or("unknown".to_string(), |v| v.clone())
-        } else {
-            "session_graph".to_string()
-        };
+        // Graphs are keyed by their schema-qualified name in the catalog
+        let qualified_graph_name = full_path.trim_start_matches('/').to_string();
         let clear_op = CatalogOperation::Update {
             entity_type: EntityType::Graph,
-            name: simple_graph_name.clone(),
+            name: qualified_graph_name,
             updates: serde_json::json!({"operation": "clear"}),
         };
 
@@ -101,8 +101,11 @@ impl DDLStatementExecutor for ClearGraphExecutor {
                             log::error!("Failed to persist graph_metadata catalog: {}", e);
                         }
 
-                        let message = format!("Graph '{}' cleared", full_path);
-                        Ok((message, 1))
+                        let message = format!(
+                            "Graph '{}' cleared: removed {} nodes and {} edges",
+                            full_path, removed.node_count, removed.edge_count
+                        );
+                        Ok((message, removed_count))
                     }
                     crate::catalog::operations::CatalogResponse::Error { message } => {
                         Err(ExecutionError::CatalogError(format!(
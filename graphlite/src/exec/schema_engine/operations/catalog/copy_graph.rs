@@ -0,0 +1,179 @@
+// Copyright (c) 2024-2025 DeepGraph Inc.
+// SPDX-License-Identifier: Apache-2.0
+//
+use crate::ast::{
+    CatalogPath, CopyGraphStatement, CreateGraphStatement, DropGraphStatement, Location,
+};
+use crate::catalog::manager::CatalogManager;
+use crate::catalog::operations::{CatalogOperation, CatalogResponse, QueryType};
+use crate::exec::schema_engine::operations::catalog::{CreateGraphExecutor, DropGraphExecutor};
+use crate::exec::schema_engine::operations::DDLStatementExecutor;
+use crate::exec::write_stmt::{ExecutionContext, StatementExecutor};
+use crate::exec::ExecutionError;
+use crate::storage::StorageManager;
+use crate::txn::state::OperationType;
+
+/// Executor for COPY GRAPH statements
+///
+/// Creates the destination graph through the CREATE GRAPH path and then
+/// writes a clone of the source graph's nodes and edges into it. If writing
+/// the data fails, the destination is dropped again so no half-copied graph
+/// is left behind.
+pub struct CopyGraphExecutor {
+    statement: CopyGraphStatement,
+}
+
+impl CopyGraphExecutor {
+    /// Create a new CopyGraphExecutor
+    pub fn new(statement: CopyGraphStatement) -> Self {
+        Self { statement }
+    }
+
+    /// Resolve a graph path to (schema, graph), using the session schema for
+    /// relative paths
+    fn resolve_path(
+        path: &CatalogPath,
+        context: &ExecutionContext,
+    ) -> Result<(String, String), ExecutionError> {
+        match path.segments.len() {
+            2 => Ok((path.segments[0].clone(), path.segments[1].clone())),
+            1 => match context.get_current_schema() {
+                Some(session_schema) => {
+                    let schema_name = session_schema
+                        .strip_prefix('/')
+                        .unwrap_or(&session_schema)
+                        .to_string();
+                    Ok((schema_name, path.segments[0].clone()))
+                }
+                None => Err(ExecutionError::RuntimeError(
+                    "Cannot copy graph with relative path: no current schema set. Use 'SESSION SET SCHEMA schema_name' or provide full path '/schema_name/graph_name'".to_string()
+                )),
+            },
+            _ => Err(ExecutionError::RuntimeError(
+                "Invalid graph path: must specify either graph name (when schema is set) or full path /schema_name/graph_name".to_string()
+            )),
+        }
+    }
+}
+
+impl StatementExecutor for CopyGraphExecutor {
+    fn operation_type(&self) -> OperationType {
+        OperationType::CreateGraph
+    }
+
+    fn operation_description(&self, _context: &ExecutionContext) -> String {
+        format!(
+            "COPY GRAPH /{} TO /{}",
+            self.statement.source_path.segments.join("/"),
+            self.statement.destination_path.segments.join("/")
+        )
+    }
+}
+
+impl DDLStatementExecutor for CopyGraphExecutor {
+    fn execute_ddl_operation(
+        &self,
+        context: &ExecutionContext,
+        catalog_manager: &mut CatalogManager,
+        storage: &StorageManager,
+    ) -> Result<(String, usize), ExecutionError> {
+        let (source_schema, source_graph) =
+            Self::resolve_path(&self.statement.source_path, context)?;
+        let (dest_schema, dest_graph) =
+            Self::resolve_path(&self.statement.destination_path, context)?;
+        let source_full_path = format!("/{}/{}", source_schema, source_graph);
+        let dest_full_path = format!("/{}/{}", dest_schema, dest_graph);
+
+        if source_full_path == dest_full_path {
+            return Err(ExecutionError::RuntimeError(format!(
+                "Cannot copy graph '{}' onto itself",
+                source_full_path
+            )));
+        }
+
+        // Step 1: Snapshot the source before anything is created. Storage hands
+        // out an empty graph for any path, so existence is checked in the catalog
+        let source_in_catalog = matches!(
+            catalog_manager.execute(
+                "graph_metadata",
+                CatalogOperation::Query {
+                    query_type: QueryType::GetGraph,
+                    params: serde_json::json!({
+                        "name": format!("{}/{}", source_schema, source_graph)
+                    }),
+                },
+            ),
+            Ok(CatalogResponse::Query { .. })
+        );
+        if !source_in_catalog {
+            return Err(ExecutionError::CatalogError(format!(
+                "Graph '{}' not found",
+                source_full_path
+            )));
+        }
+        let source = storage
+            .get_graph(&source_full_path)
+            .map_err(|e| {
+                ExecutionError::StorageError(format!(
+                    "Failed to read graph '{}': {}",
+                    source_full_path, e
+                ))
+            })?
+            .ok_or_else(|| {
+                ExecutionError::CatalogError(format!("Graph '{}' not found", source_full_path))
+            })?;
+        let stats = source.stats();
+
+        // Step 2: Create the destination; this validates the schema, rejects
+        // an existing graph and records the undo entry for ROLLBACK
+        let dest_path = CatalogPath::new(
+            vec![dest_schema.clone(), dest_graph.clone()],
+            Location::default(),
+        );
+        CreateGraphExecutor::new(CreateGraphStatement {
+            graph_path: dest_path.clone(),
+            graph_type_spec: None,
+            if_not_exists: false,
+            or_replace: false,
+            as_query: None,
+            options: None,
+            location: Location::default(),
+        })
+        .execute_ddl_operation(context, catalog_manager, storage)?;
+
+        // Step 3: Fill it with the copied data, dropping the destination again on failure
+        if let Err(e) = storage.save_graph(&dest_full_path, source) {
+            let drop_result = DropGraphExecutor::new(DropGraphStatement {
+                graph_path: dest_path,
+                if_exists: true,
+                cascade: true,
+                location: Location::default(),
+            })
+            .execute_ddl_operation(context, catalog_manager, storage);
+            if let Err(drop_err) = drop_result {
+                log::error!(
+                    "Failed to remove partially copied graph '{}': {}",
+                    dest_full_path,
+                    drop_err
+                );
+            }
+            return Err(ExecutionError::StorageError(format!(
+                "Failed to copy graph '{}' to '{}': {}",
+                source_full_path, dest_full_path, e
+            )));
+        }
+
+        if let Some(cache_mgr) = &context.cache_manager {
+            cache_mgr.invalidate_on_data_change(
+                Some(dest_full_path.clone()),
+                (stats.node_count + stats.edge_count) as u64,
+            );
+        }
+
+        let message = format!(
+            "Graph '{}' copied to '{}': {} nodes and {} edges",
+            source_full_path, dest_full_path, stats.node_count, stats.edge_count
+        );
+        Ok((message, stats.node_count + stats.edge_count))
+    }
+}
@@ -1,14 +1,18 @@
 // Copyright (c) 2024-2025 DeepGraph Inc.
 // SPDX-License-Identifier: Apache-2.0
 //
-use crate::ast::CreateGraphStatement;
+use crate::ast::{CreateGraphStatement, Value};
 use crate::catalog::manager::CatalogManager;
 use crate::catalog::operations::{CatalogOperation, CatalogResponse, EntityType, QueryType};
+use crate::catalog::providers::graph_metadata::{
+    GraphProperty, TTL_LABEL_PROPERTY, TTL_PROPERTY_PROPERTY,
+};
 use crate::exec::schema_engine::operations::DDLStatementExecutor;
 use crate::exec::write_stmt::{ExecutionContext, StatementExecutor};
 use crate::exec::ExecutionError;
 use crate::storage::StorageManager;
 use crate::txn::state::OperationType;
+use crate::txn::UndoOperation;
 use log::{info, warn};
 
 /// Executor for CREATE GRAPH statements
@@ -21,6 +25,48 @@ impl CreateGraphExecutor {
     pub fn new(statement: CreateGraphStatement) -> Self {
         Self { statement }
     }
+
+    /// Graph properties for the options of the `WITH (...)` clause
+    ///
+    /// Only the node expiry options `ttl_label` and `ttl_property` are
+    /// supported, and they must be given together.
+    fn option_properties(&self) -> Result<serde_json::Value, ExecutionError> {
+        let mut properties = serde_json::Map::new();
+        let Some(options) = &self.statement.options else {
+            return Ok(serde_json::Value::Object(properties));
+        };
+
+        for (key, value) in &options.parameters {
+            let key = key.to_lowercase();
+            if key != TTL_LABEL_PROPERTY && key != TTL_PROPERTY_PROPERTY {
+                return Err(ExecutionError::ValidationError(format!(
+                    "Unknown graph option '{}'",
+                    key
+                )));
+            }
+            let Value::String(text) = value else {
+                return Err(ExecutionError::ValidationError(format!(
+                    "Graph option '{}' must be a string",
+                    key
+                )));
+            };
+            properties.insert(
+                key,
+                serde_json::to_value(GraphProperty::String(text.clone()))
+                    .map_err(|e| ExecutionError::CatalogError(e.to_string()))?,
+            );
+        }
+
+        if properties.contains_key(TTL_LABEL_PROPERTY)
+            != properties.contains_key(TTL_PROPERTY_PROPERTY)
+        {
+            return Err(ExecutionError::ValidationError(format!(
+                "Graph options '{}' and '{}' must be given together",
+                TTL_LABEL_PROPERTY, TTL_PROPERTY_PROPERTY
+            )));
+        }
+        Ok(serde_json::Value::Object(properties))
+    }
 }
 
 impl StatementExecutor for CreateGraphExecutor {
@@ -170,6 +216,8 @@ impl DDLStatementExecutor for CreateGraphExecutor {
             None
         };
 
+        let properties = self.option_properties()?;
+
         // Step 3: Create catalog entry first (DDL operations typically create metadata first)
         let create_op = CatalogOperation::Create {
             entity_type: EntityType::Graph,
@@ -180,7 +228,8 @@ impl DDLStatementExecutor for CreateGraphExecutor {
                 "graph_type": graph_type_name,
                 "if_not_exists": self.statement.if_not_exists,
                 "or_replace": self.statement.or_replace,
-                "description": None::<String>
+                "description": None::<String>,
+                "properties": properties
             }),
         };
 
@@ -219,6 +268,19 @@ impl DDLStatementExecutor for CreateGraphExecutor {
                             );
                         }
 
+                        // Record the undo operation so ROLLBACK drops the graph again.
+                        // A replaced graph cannot be restored, so OR REPLACE is not undoable.
+                        if !self.statement.or_replace {
+                            if let Some(transaction_state) = context.transaction_state() {
+                                transaction_state.log_transaction_operation(
+                                    UndoOperation::CreateGraph {
+                                        schema_name: schema_name.clone(),
+                                        graph_name: graph_name.clone(),
+                                    },
+                                )?;
+                            }
+                        }
+
                         let message = if self.statement.if_not_exists {
                             format!("Graph '{}' created (if not exists)", graph_name)
                         } else if self.statement.or_replace {
@@ -9,6 +9,7 @@ use crate::exec::write_stmt::{ExecutionContext, StatementExecutor};
 use crate::exec::ExecutionError;
 use crate::storage::StorageManager;
 use crate::txn::state::OperationType;
+use crate::txn::UndoOperation;
 
 /// Executor for CREATE SCHEMA statements
 pub struct CreateSchemaExecutor {
@@ -98,6 +99,15 @@ impl DDLStatementExecutor for CreateSchemaExecutor {
                         );
                     }
 
+                    // Record the undo operation so ROLLBACK drops the schema again
+                    if let Some(transaction_state) = context.transaction_state() {
+                        transaction_state.log_transaction_operation(
+                            UndoOperation::CreateSchema {
+                                schema_name: schema_name.clone(),
+                            },
+                        )?;
+                    }
+
                     let message = if self.statement.if_not_exists {
                         format!("Schema '{}' created (if not exists)", schema_name)
                     } else {
@@ -1,9 +1,10 @@
 // Copyright (c) 2024-2025 DeepGraph Inc.
 // SPDX-License-Identifier: Apache-2.0
 //
-//! Catalog operations (CREATE GRAPH, DROP GRAPH, CLEAR, TRUNCATE, SCHEMA)
+//! Catalog operations (CREATE GRAPH, DROP GRAPH, CLEAR, TRUNCATE, COPY, SCHEMA)
 
 pub mod clear_graph;
+pub mod copy_graph;
 pub mod create_graph;
 pub mod create_schema;
 pub mod drop_graph;
@@ -11,6 +12,7 @@ pub mod drop_schema;
 pub mod truncate_graph;
 
 pub use clear_graph::*;
+pub use copy_graph::*;
 pub use create_graph::*;
 pub use create_schema::*;
 pub use drop_graph::*;
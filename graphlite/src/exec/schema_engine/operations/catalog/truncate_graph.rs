@@ -1,7 +1,6 @@
 // Copyright (c) 2024-2025 DeepGraph Inc.
 // SPDX-License-Identifier: Apache-2.0
 //
-use crate::ast::CatalogPath;
 use crate::ast::TruncateGraphStatement;
 use crate::catalog::manager::CatalogManager;
 use crate::catalog::operations::{CatalogOperation, EntityType};
@@ -39,18 +38,10 @@ impl StatementExecutor for TruncateGraphExecutor {
 impl DDLStatementExecutor for TruncateGraphExecutor {
     fn execute_ddl_operation(
         &self,
-        _context: &ExecutionContext,
+        context: &ExecutionContext,
         catalog_manager: &mut CatalogManager,
         storage: &StorageManager,
     ) -> Result<(String, usize), ExecutionError> {
-        let catalog_path = CatalogPath::new(
-            self.statement.graph_path.segments.clone(),
-            crate::ast::Location {
-                line: 0,
-                column: 0,
-                offset: 0,
-            },
-        );
         // Use full path format for storage operations (must be /<schema>/<graph>)
         let full_path = if self.statement.graph_path.segments.len() >= 2 {
             format!("/{}", self.statement.graph_path.segments.join("/"))
@@ -62,23 +53,28 @@ impl DDLStatementExecutor for TruncateGraphExecutor {
         };
 
         // Step 1: Clear all graph data from storage FIRST
-        // TRUNCATE removes all nodes and edges but keeps the graph schema
-        let empty_graph = crate::storage::GraphCache::new();
-        storage.save_graph(&full_path, empty_graph).map_err(|e| {
+        // TRUNCATE removes all nodes and edges but keeps the graph schema.
+        // The graph is reset in one storage operation, without per-element undo records
+        let removed = storage.truncate_graph(&full_path).map_err(|e| {
             ExecutionError::StorageError(format!(
                 "Failed to truncate graph data for '{}': {}",
                 full_path, e
             ))
         })?;
+        let removed_count = removed.node_count + removed.edge_count;
+
+        // Cached query results over the old data are stale now
+        if let Some(cache_mgr) = &context.cache_manager {
+            cache_mgr.invalidate_on_data_change(Some(full_path.clone()), removed_count as u64);
+        }
 
         // Step 2: Update catalog metadata if needed (TRUNCATE typically doesn't change metadata)
         // Use CatalogManager to handle truncate operation
-        let simple_graph_name = catalog_path
-            .name()
-            .map_or("unknown".to_string(), |v| v.clone());
+        // Graphs are keyed by their schema-qualified name in the catalog
+        let qualified_graph_name = full_path.trim_start_matches('/').to_string();
         let truncate_op = CatalogOperation::Update {
             entity_type: EntityType::Graph,
-            name: simple_graph_name.clone(),
+            name: qualified_graph_name,
             updates: serde_json::json!({"operation": "truncate"}),
         };
 
@@ -93,8 +89,11 @@ impl DDLStatementExecutor for TruncateGraphExecutor {
                             log::error!("Failed to persist graph_metadata catalog: {}", e);
                         }
 
-                        let message = format!("Graph '{}' truncated", full_path);
-                        Ok((message, 1))
+                        let message = format!(
+                            "Graph '{}' truncated: removed {} nodes and {} edges",
+                            full_path, removed.node_count, removed.edge_count
+                        );
+                        Ok((message, removed_count))
                     }
                     crate::catalog::operations::CatalogResponse::Error { message } => {
                         Err(ExecutionError::CatalogError(format!(
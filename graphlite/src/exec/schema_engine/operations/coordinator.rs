@@ -89,6 +89,10 @@ impl DDLStatementCoordinator {
                 let stmt_executor = ClearGraphExecutor::new(clear_graph.clone());
                 stmt_executor.execute(context, catalog_manager, &storage)
             }
+            CatalogStatement::CopyGraph(copy_graph) => {
+                let stmt_executor = CopyGraphExecutor::new(copy_graph.clone());
+                stmt_executor.execute(context, catalog_manager, &storage)
+            }
             CatalogStatement::CreateUser(create_user) => {
                 let stmt_executor = CreateUserExecutor::new(create_user.clone());
                 stmt_executor.execute(context, catalog_manager, &storage)
@@ -152,6 +156,7 @@ impl DDLStatementCoordinator {
                     }
                     CatalogStatement::CreateGraph(_)
                     | CatalogStatement::DropGraph(_)
+                    | CatalogStatement::CopyGraph(_)
                     | CatalogStatement::CreateGraphType(_)
                     | CatalogStatement::DropGraphType(_)
                     | CatalogStatement::AlterGraphType(_) => {
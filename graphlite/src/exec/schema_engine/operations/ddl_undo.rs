@@ -0,0 +1,77 @@
+// Copyright (c) 2024-2025 DeepGraph Inc.
+// SPDX-License-Identifier: Apache-2.0
+//
+//! Undo support for transactional DDL
+//!
+//! CREATE SCHEMA, CREATE GRAPH and CREATE INDEX log an undo operation when run
+//! inside a transaction. On rollback the matching DROP is executed directly
+//! against the catalog, bypassing the transaction log.
+
+use std::sync::{Arc, RwLock};
+
+use crate::ast::{
+    CatalogPath, DropGraphStatement, DropIndexStatement, DropSchemaStatement, Location,
+};
+use crate::catalog::manager::CatalogManager;
+use crate::exec::schema_engine::operations::catalog::{DropGraphExecutor, DropSchemaExecutor};
+use crate::exec::schema_engine::operations::types::DropIndexExecutor;
+use crate::exec::schema_engine::operations::DDLStatementExecutor;
+use crate::exec::write_stmt::ExecutionContext;
+use crate::exec::ExecutionError;
+use crate::storage::StorageManager;
+use crate::txn::UndoOperation;
+
+/// Reverse a DDL undo operation by dropping the object it created
+pub fn undo_ddl_operation(
+    operation: &UndoOperation,
+    catalog_manager: &Arc<RwLock<CatalogManager>>,
+    storage: &Arc<StorageManager>,
+) -> Result<(), ExecutionError> {
+    let context = ExecutionContext::new("rollback".to_string(), storage.clone());
+    let mut catalog = catalog_manager.write().map_err(|_| {
+        ExecutionError::RuntimeError("Failed to acquire catalog manager write lock".to_string())
+    })?;
+
+    match operation {
+        UndoOperation::CreateSchema { schema_name } => {
+            let executor = DropSchemaExecutor::new(DropSchemaStatement {
+                schema_path: CatalogPath::new(vec![schema_name.clone()], Location::default()),
+                if_exists: true,
+                cascade: false,
+                location: Location::default(),
+            });
+            executor.execute_ddl_operation(&context, &mut catalog, storage)?;
+        }
+        UndoOperation::CreateGraph {
+            schema_name,
+            graph_name,
+        } => {
+            let executor = DropGraphExecutor::new(DropGraphStatement {
+                graph_path: CatalogPath::new(
+                    vec![schema_name.clone(), graph_name.clone()],
+                    Location::default(),
+                ),
+                if_exists: true,
+                cascade: true,
+                location: Location::default(),
+            });
+            executor.execute_ddl_operation(&context, &mut catalog, storage)?;
+        }
+        UndoOperation::CreateIndex { index_name } => {
+            let executor = DropIndexExecutor::new(DropIndexStatement {
+                name: index_name.clone(),
+                if_exists: true,
+                location: Location::default(),
+            });
+            executor.execute_ddl_operation(&context, &mut catalog, storage)?;
+        }
+        other => {
+            return Err(ExecutionError::RuntimeError(format!(
+                "Not a DDL undo operation: {:?}",
+                other
+            )));
+        }
+    }
+
+    Ok(())
+}
@@ -10,6 +10,8 @@ pub mod types;
 // Base types
 pub mod coordinator;
 pub mod ddl_statement_base;
+pub mod ddl_undo;
 
 pub use coordinator::*;
 pub use ddl_statement_base::*;
+pub use ddl_undo::*;
@@ -174,8 +174,9 @@ impl CreateGraphTypeExecutor {
         &self,
         spec: &crate::ast::GraphTypeSpec,
     ) -> Vec<crate::schema::types::EdgeTypeDefinition> {
+        use crate::ast::EdgeDeleteAction;
         use crate::schema::types::{
-            DataType, EdgeCardinality, EdgeTypeDefinition, PropertyDefinition,
+            DataType, EdgeCardinality, EdgeTypeDefinition, ForeignKeyAction, PropertyDefinition,
         };
 
         spec.edge_types
@@ -232,6 +233,10 @@ impl CreateGraphTypeExecutor {
                     constraints: vec![],
                     description: None,
                     cardinality: EdgeCardinality::default(), // Default (no constraints)
+                    on_delete: edge_spec.on_delete.map(|action| match action {
+                        EdgeDeleteAction::Cascade => ForeignKeyAction::Cascade,
+                        EdgeDeleteAction::Restrict => ForeignKeyAction::Restrict,
+                    }),
                 }
             })
             .collect()
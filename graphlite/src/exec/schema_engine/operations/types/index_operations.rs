@@ -17,16 +17,20 @@ thread_local! {
 }
 
 use crate::ast::{
-    AlterIndexOperation, AlterIndexStatement, CreateIndexStatement, DropIndexStatement,
-    GraphIndexTypeSpecifier, IndexStatement, IndexTypeSpecifier, OptimizeIndexStatement,
-    ReindexStatement, Value,
+    AlterIndexOperation, AlterIndexStatement, ConstraintAssertion, CreateConstraintStatement,
+    CreateIndexStatement, DropConstraintStatement, DropIndexStatement, GraphIndexTypeSpecifier,
+    IndexStatement, IndexTypeSpecifier, OptimizeIndexStatement, ReindexStatement, Value,
 };
 use crate::catalog::manager::CatalogManager;
 use crate::exec::schema_engine::operations::DDLStatementExecutor;
 use crate::exec::write_stmt::{ExecutionContext, StatementExecutor};
 use crate::exec::{ExecutionError, QueryResult};
 use crate::schema::integration::index_validator::IndexSchemaValidator;
-use crate::storage::indexes::{GraphIndexType, IndexConfig, IndexError, IndexManager, IndexType};
+use crate::schema::integration::runtime_validator::RuntimeValidator;
+use crate::storage::indexes::{
+    ConstraintKind, GraphIndexType, IndexConfig, IndexError, IndexManager, IndexType,
+    PropertyConstraint,
+};
 use crate::storage::StorageManager;
 
 /// Coordinator for index DDL statement execution
@@ -82,6 +86,14 @@ impl IndexStatementCoordinator {
                 let stmt_executor = ReindexExecutor::new(reindex.clone());
                 stmt_executor.execute(context, catalog_manager, &storage)?
             }
+            IndexStatement::CreateConstraint(create_constraint) => {
+                let stmt_executor = CreateConstraintExecutor::new(create_constraint.clone());
+                stmt_executor.execute(context, catalog_manager, &storage)?
+            }
+            IndexStatement::DropConstraint(drop_constraint) => {
+                let stmt_executor = DropConstraintExecutor::new(drop_constraint.clone());
+                stmt_executor.execute(context, catalog_manager, &storage)?
+            }
         };
 
         let elapsed = start_time.elapsed();
@@ -382,8 +394,13 @@ impl DDLStatementExecutor for CreateIndexExecutor {
         // Convert parameters
         let mut parameters = self.convert_parameters()?;
 
-        // Add label and property metadata to parameters for index lookup
+        // Add graph, label and property metadata to parameters for index lookup
         // This allows the executor to find indexes by label+property at query time
+        let graph_path = context.get_graph_name()?;
+        parameters.insert(
+            "__graph__".to_string(),
+            crate::storage::Value::String(graph_path.clone()),
+        );
         parameters.insert(
             "__label__".to_string(),
             crate::storage::Value::String(self.statement.table.clone()),
@@ -474,7 +491,7 @@ impl DDLStatementExecutor for CreateIndexExecutor {
 
         let catalog_params = serde_json::json!({
             "schema_name": context.get_current_schema().unwrap_or_else(|| "default".to_string()),
-            "graph_name": context.get_current_graph_name(),
+            "graph_name": graph_path,
             "index_type": index_type_str,
             "entity_type": "node",
             "label": self.statement.table.clone(),  // Add label for query filtering
@@ -507,6 +524,15 @@ impl DDLStatementExecutor for CreateIndexExecutor {
             }
         }
 
+        // Record the undo operation so ROLLBACK drops the index again
+        if let Some(transaction_state) = context.transaction_state() {
+            transaction_state.log_transaction_operation(
+                crate::txn::UndoOperation::CreateIndex {
+                    index_name: self.statement.name.clone(),
+                },
+            )?;
+        }
+
         debug!(
             "Successfully created index '{}' of type {:?}",
             self.statement.name, index_type
@@ -566,16 +592,9 @@ impl DDLStatementExecutor for DropIndexExecutor {
     ) -> Result<(String, usize), ExecutionError> {
         info!("Dropping index '{}'", self.statement.name);
 
-        // Check if index exists in Catalog (single source of truth)
-        let index_exists = _catalog_manager
-            .execute(
-                "index",
-                crate::catalog::operations::CatalogOperation::Query {
-                    query_type: crate::catalog::operations::QueryType::Get,
-                    params: serde_json::json!({ "name": self.statement.name.clone() }),
-                },
-            )
-            .is_ok();
+        // IndexManager is the authoritative source for index existence, as in CREATE INDEX
+        let index_manager = self.get_index_manager(storage)?;
+        let index_exists = index_manager.index_exists(&self.statement.name);
 
         if !index_exists {
             if self.statement.if_exists {
@@ -592,39 +611,24 @@ impl DDLStatementExecutor for DropIndexExecutor {
             }
         }
 
-        // Get index manager to drop from IndexManager as well
-        let index_manager = self.get_index_manager(storage)?;
-        let existing_indexes = index_manager.list_indexes();
-
-        // Drop the index from IndexManager (if it exists there)
-        if existing_indexes.contains(&self.statement.name) {
-            let delete_result = tokio::runtime::Handle::try_current()
-                .map(|_| {
-                    // We're in an async context
-                    Err(ExecutionError::RuntimeError(
-                        "Cannot delete index from async context - use dedicated async API"
-                            .to_string(),
-                    ))
+        // Drop the index from IndexManager
+        let delete_result = tokio::runtime::Handle::try_current()
+            .map(|_| {
+                // We're in an async context
+                Err(ExecutionError::RuntimeError(
+                    "Cannot delete index from async context - use dedicated async API".to_string(),
+                ))
+            })
+            .unwrap_or_else(|_| {
+                // We're in sync context, use shared runtime
+                INDEX_RUNTIME.with(|rt| {
+                    rt.block_on(index_manager.delete_index(&self.statement.name))
+                        .map_err(|e| {
+                            ExecutionError::RuntimeError(format!("Failed to drop index: {:?}", e))
+                        })
                 })
-                .unwrap_or_else(|_| {
-                    // We're in sync context, use shared runtime
-                    INDEX_RUNTIME.with(|rt| {
-                        rt.block_on(index_manager.delete_index(&self.statement.name))
-                            .map_err(|e| {
-                                ExecutionError::RuntimeError(format!(
-                                    "Failed to drop index: {:?}",
-                                    e
-                                ))
-                            })
-                    })
-                });
-            delete_result?;
-        } else {
-            debug!(
-                "Index '{}' not found in IndexManager, only removing from catalog",
-                self.statement.name
-            );
-        }
+            });
+        delete_result?;
 
         // Remove from catalog
         let catalog_result = _catalog_manager.execute(
@@ -1107,3 +1111,204 @@ impl DDLStatementExecutor for ReindexExecutor {
         Ok((message, indexed_count))
     }
 }
+
+// =============================================================================
+// CREATE CONSTRAINT EXECUTOR
+// =============================================================================
+
+/// Executor for CREATE CONSTRAINT statements
+pub struct CreateConstraintExecutor {
+    statement: CreateConstraintStatement,
+}
+
+impl CreateConstraintExecutor {
+    pub fn new(statement: CreateConstraintStatement) -> Self {
+        Self { statement }
+    }
+
+    /// Constraint name, derived from the assertion when none was given
+    fn constraint_name(&self) -> String {
+        self.statement.name.clone().unwrap_or_else(|| {
+            let suffix = match self.statement.assertion {
+                ConstraintAssertion::Unique => "unique",
+                ConstraintAssertion::NotNull => "not_null",
+            };
+            format!(
+                "{}_{}_{}",
+                self.statement.label, self.statement.property, suffix
+            )
+        })
+    }
+}
+
+impl StatementExecutor for CreateConstraintExecutor {
+    fn operation_type(&self) -> crate::txn::state::OperationType {
+        crate::txn::state::OperationType::CreateTable
+    }
+
+    fn operation_description(&self, _context: &ExecutionContext) -> String {
+        format!(
+            "CREATE CONSTRAINT {}{} ON {} ASSERT {} {}",
+            if self.statement.if_not_exists {
+                "IF NOT EXISTS "
+            } else {
+                ""
+            },
+            self.constraint_name(),
+            self.statement.label,
+            self.statement.property,
+            match self.statement.assertion {
+                ConstraintAssertion::Unique => "IS UNIQUE",
+                ConstraintAssertion::NotNull => "IS NOT NULL",
+            }
+        )
+    }
+}
+
+impl DDLStatementExecutor for CreateConstraintExecutor {
+    fn execute_ddl_operation(
+        &self,
+        context: &ExecutionContext,
+        _catalog_manager: &mut CatalogManager,
+        storage: &StorageManager,
+    ) -> Result<(String, usize), ExecutionError> {
+        let name = self.constraint_name();
+        CreateIndexExecutor::validate_index_name(&name)?;
+
+        let index_manager = storage.get_index_manager().cloned().ok_or_else(|| {
+            ExecutionError::StorageError("IndexManager not initialized in storage".to_string())
+        })?;
+
+        if index_manager.constraint_exists(&name) {
+            if self.statement.if_not_exists {
+                let message = format!(
+                    "Constraint '{}' already exists (skipped due to IF NOT EXISTS)",
+                    name
+                );
+                return Ok((message, 0));
+            }
+            return Err(ExecutionError::InvalidQuery(format!(
+                "Constraint '{}' already exists",
+                name
+            )));
+        }
+
+        let graph_path = context.get_graph_name()?;
+        let constraint = PropertyConstraint {
+            name: name.clone(),
+            graph_path: graph_path.clone(),
+            label: self.statement.label.clone(),
+            property: self.statement.property.clone(),
+            kind: match self.statement.assertion {
+                ConstraintAssertion::Unique => ConstraintKind::Unique,
+                ConstraintAssertion::NotNull => ConstraintKind::NotNull,
+            },
+        };
+
+        // IF NOT EXISTS also skips a constraint declared under another name
+        if self.statement.if_not_exists {
+            if let Some(existing) = index_manager
+                .constraints_for_graph(&graph_path)
+                .into_iter()
+                .find(|existing| {
+                    existing.label == constraint.label
+                        && existing.property == constraint.property
+                        && existing.kind == constraint.kind
+                })
+            {
+                let message = format!(
+                    "Constraint '{}' already asserts {} (skipped due to IF NOT EXISTS)",
+                    existing.name,
+                    existing.describe()
+                );
+                return Ok((message, 0));
+            }
+        }
+
+        // Existing data must already satisfy the constraint
+        let graph = storage
+            .get_graph(&graph_path)
+            .map_err(|e| ExecutionError::StorageError(format!("Failed to get graph: {}", e)))?
+            .ok_or_else(|| {
+                ExecutionError::StorageError(format!("Graph '{}' not found", graph_path))
+            })?;
+        RuntimeValidator::validate_constraint_on_graph(&graph, &constraint)?;
+
+        let description = constraint.describe();
+        index_manager
+            .create_constraint(constraint)
+            .map_err(|e| match e {
+                IndexError::AlreadyExists(name) => {
+                    ExecutionError::InvalidQuery(format!("Constraint '{}' already exists", name))
+                }
+                _ => ExecutionError::RuntimeError(format!("Failed to create constraint: {:?}", e)),
+            })?;
+
+        let message = format!("Constraint '{}' created: {}", name, description);
+        Ok((message, 1))
+    }
+}
+
+// =============================================================================
+// DROP CONSTRAINT EXECUTOR
+// =============================================================================
+
+/// Executor for DROP CONSTRAINT statements
+pub struct DropConstraintExecutor {
+    statement: DropConstraintStatement,
+}
+
+impl DropConstraintExecutor {
+    pub fn new(statement: DropConstraintStatement) -> Self {
+        Self { statement }
+    }
+}
+
+impl StatementExecutor for DropConstraintExecutor {
+    fn operation_type(&self) -> crate::txn::state::OperationType {
+        crate::txn::state::OperationType::DropTable
+    }
+
+    fn operation_description(&self, _context: &ExecutionContext) -> String {
+        if self.statement.if_exists {
+            format!("DROP CONSTRAINT IF EXISTS {}", self.statement.name)
+        } else {
+            format!("DROP CONSTRAINT {}", self.statement.name)
+        }
+    }
+}
+
+impl DDLStatementExecutor for DropConstraintExecutor {
+    fn execute_ddl_operation(
+        &self,
+        _context: &ExecutionContext,
+        _catalog_manager: &mut CatalogManager,
+        storage: &StorageManager,
+    ) -> Result<(String, usize), ExecutionError> {
+        let index_manager = storage.get_index_manager().cloned().ok_or_else(|| {
+            ExecutionError::StorageError("IndexManager not initialized in storage".to_string())
+        })?;
+
+        match index_manager.drop_constraint(&self.statement.name) {
+            Ok(_) => {
+                let message = format!("Constraint '{}' dropped successfully", self.statement.name);
+                Ok((message, 1))
+            }
+            Err(IndexError::NotFound(_)) if self.statement.if_exists => {
+                let message = format!(
+                    "Constraint '{}' does not exist (skipped due to IF EXISTS)",
+                    self.statement.name
+                );
+                Ok((message, 0))
+            }
+            Err(IndexError::NotFound(_)) => Err(ExecutionError::InvalidQuery(format!(
+                "Constraint '{}' does not exist",
+                self.statement.name
+            ))),
+            Err(e) => Err(ExecutionError::RuntimeError(format!(
+                "Failed to drop constraint: {:?}",
+                e
+            ))),
+        }
+    }
+}
@@ -4,16 +4,91 @@
 //! Streaming Top-K - Memory-efficient ORDER BY + LIMIT (Phase 4: Week 6.5)
 //!
 //! Maintains only top-K results using a min-heap instead of sorting entire result set.
+//!
+//! [`OrderedTopK`] applies the same idea to ORDER BY ... LIMIT over arbitrary sort
+//! keys and is used by the `TopK` physical operator. [`VectorTopK`] specializes
+//! [`StreamingTopK`] for similarity scores and backs the `VectorTopK` operator.
 
+use crate::exec::collation::Collation;
 use crate::exec::result::Row;
+use crate::plan::physical::SortItem;
+use crate::storage::Value;
 use std::cmp::Ordering;
 use std::collections::BinaryHeap;
+use std::sync::Arc;
 
-/// Streaming top-K results using min-heap
+/// Total order on numbers for sorting: NaN sorts after every other number,
+/// including infinity, and all NaNs are equal to each other
 ///
-/// **Planned Feature** - Memory-efficient ORDER BY + LIMIT implementation
-/// See ROADMAP.md: "Streaming Top-K Operations"
-/// Target: v0.2.0 (High Priority)
+/// A plain `partial_cmp` treats NaN as equal to everything, which is not a
+/// consistent order and can make sorting panic.
+pub fn compare_numbers(a: f64, b: f64) -> Ordering {
+    match (a.is_nan(), b.is_nan()) {
+        (true, true) => Ordering::Equal,
+        (true, false) => Ordering::Greater,
+        (false, true) => Ordering::Less,
+        (false, false) => a.partial_cmp(&b).unwrap_or(Ordering::Equal),
+    }
+}
+
+/// Compare two values for ORDER BY
+///
+/// Numbers and booleans compare naturally, strings compare under `collation`,
+/// NULLs are placed according to `nulls_first`, and values of different types
+/// fall back to comparing their debug representation so that the ordering is
+/// total.
+pub fn compare_sort_values(
+    a: &Value,
+    b: &Value,
+    nulls_first: bool,
+    collation: Collation,
+) -> Ordering {
+    match (a, b) {
+        (Value::Number(a), Value::Number(b)) => compare_numbers(*a, *b),
+        (Value::String(a), Value::String(b)) => collation.compare(a, b),
+        (Value::Boolean(a), Value::Boolean(b)) => a.cmp(b),
+        (Value::Duration(a), Value::Duration(b)) => a.cmp(b),
+        (Value::Null, Value::Null) => Ordering::Equal,
+        (Value::Null, _) => {
+            if nulls_first {
+                Ordering::Less
+            } else {
+                Ordering::Greater
+            }
+        }
+        (_, Value::Null) => {
+            if nulls_first {
+                Ordering::Greater
+            } else {
+                Ordering::Less
+            }
+        }
+        // For different types, convert to string for comparison
+        _ => format!("{:?}", a).cmp(&format!("{:?}", b)),
+    }
+}
+
+/// Compare two ORDER BY keys in the requested direction
+///
+/// Non-null values are reversed for descending order, but NULL placement is
+/// absolute: with `nulls_first` NULLs come before every other value in both
+/// directions, otherwise after.
+pub fn compare_sort_keys(
+    a: &Value,
+    b: &Value,
+    ascending: bool,
+    nulls_first: bool,
+    collation: Collation,
+) -> Ordering {
+    let ordering = compare_sort_values(a, b, nulls_first, collation);
+    if ascending || a.is_null() || b.is_null() {
+        ordering
+    } else {
+        ordering.reverse()
+    }
+}
+
+/// Streaming top-K results using min-heap
 ///
 /// This structure maintains only the K highest-scoring results without
 /// materializing the entire result set in memory.
@@ -54,7 +129,9 @@ use std::collections::BinaryHeap;
 /// For N=1M, K=100:
 /// - Traditional sort: ~20M comparisons, 1M rows in memory
 /// - StreamingTopK: ~20M comparisons, 100 rows in memory (10,000x less)
-#[allow(dead_code)]
+///
+/// Rows with equal scores keep their arrival order, both in which rows are
+/// kept and in the order they are returned.
 pub struct StreamingTopK {
     /// Min-heap to maintain top-K results
     /// Root contains the minimum score in the heap
@@ -71,21 +148,19 @@ pub struct StreamingTopK {
 }
 
 /// Row with associated score for heap ordering
-#[allow(dead_code)]
 #[derive(Clone)]
 struct ScoredRow {
     row: Row,
     score: f64,
+    sequence: usize,
 }
 
 impl Ord for ScoredRow {
     fn cmp(&self, other: &Self) -> Ordering {
         // Reverse ordering for min-heap
         // (BinaryHeap is max-heap by default, we want min-heap)
-        other
-            .score
-            .partial_cmp(&self.score)
-            .unwrap_or(Ordering::Equal)
+        // Among equal scores the latest arrival is the first to be evicted
+        compare_numbers(other.score, self.score).then(self.sequence.cmp(&other.sequence))
     }
 }
 
@@ -99,7 +174,7 @@ impl Eq for ScoredRow {}
 
 impl PartialEq for ScoredRow {
     fn eq(&self, other: &Self) -> bool {
-        self.score == other.score
+        self.cmp(other) == Ordering::Equal
     }
 }
 
@@ -117,10 +192,9 @@ impl StreamingTopK {
     /// }
     /// let top_10 = topk.into_results();
     /// ```
-    #[allow(dead_code)] // ROADMAP v0.5.0 - Streaming top-K for memory-efficient result limiting
     pub fn new(k: usize) -> Self {
         Self {
-            heap: BinaryHeap::with_capacity(k + 1), // +1 for temporary overflow
+            heap: BinaryHeap::with_capacity(k.saturating_add(1).min(1024)), // +1 for temporary overflow
             k,
             processed_count: 0,
             would_keep_count: 0,
@@ -139,23 +213,26 @@ impl StreamingTopK {
     /// # Time Complexity
     /// - Best case: O(1) if score below minimum
     /// - Worst case: O(log K) for heap operations
-    #[allow(dead_code)] // ROADMAP v0.5.0 - Add rows to top-K heap for streaming filtering
     pub fn add(&mut self, row: Row, score: f64) {
+        let candidate = ScoredRow {
+            row,
+            score,
+            sequence: self.processed_count,
+        };
         self.processed_count += 1;
 
         // If heap not full, always add
         if self.heap.len() < self.k {
-            self.heap.push(ScoredRow { row, score });
+            self.heap.push(candidate);
             self.would_keep_count += 1;
             return;
         }
 
         // Heap is full - check if this score beats minimum
-        if let Some(min_item) = self.heap.peek() {
-            if score > min_item.score {
+        if let Some(mut min_item) = self.heap.peek_mut() {
+            if compare_numbers(score, min_item.score) == Ordering::Greater {
                 // This row is better than current minimum
-                self.heap.push(ScoredRow { row, score });
-                self.heap.pop(); // Remove minimum
+                *min_item = candidate;
                 self.would_keep_count += 1;
             }
             // else: score <= min, discard this row
@@ -200,12 +277,9 @@ impl StreamingTopK {
     ///
     /// # Time Complexity
     /// O(K log K) for sorting K results
-    #[allow(dead_code)] // ROADMAP v0.5.0 - Streaming TopK result extraction
     pub fn into_results(self) -> Vec<Row> {
-        let mut results: Vec<ScoredRow> = self.heap.into_iter().collect();
-
-        // Sort descending by score
-        results.sort_by(|a, b| b.score.partial_cmp(&a.score).unwrap_or(Ordering::Equal));
+        // Ascending heap order is descending by score, earliest arrival first
+        let results = self.heap.into_sorted_vec();
 
         // Extract rows
         results.into_iter().map(|sr| sr.row).collect()
@@ -216,7 +290,7 @@ impl StreamingTopK {
     pub fn results(&self) -> Vec<Row> {
         let mut results: Vec<ScoredRow> = self.heap.iter().cloned().collect();
 
-        results.sort_by(|a, b| b.score.partial_cmp(&a.score).unwrap_or(Ordering::Equal));
+        results.sort();
 
         results.into_iter().map(|sr| sr.row).collect()
     }
@@ -264,10 +338,213 @@ impl TopKStats {
     }
 }
 
+/// Bounded top-K for ORDER BY <similarity> ... LIMIT
+///
+/// Wraps [`StreamingTopK`] for a single numeric score such as
+/// `COSINE_SIMILARITY(d.embedding, $q)`. Ascending order is handled by negating
+/// the score. Rows whose score is NULL (or not a number) and rows whose score is
+/// NaN bypass the heap and are placed where a full sort puts them: before all
+/// numbers when descending, after them when ascending. The result is identical
+/// to a stable full sort followed by truncation to K rows.
+pub struct VectorTopK {
+    heap: StreamingTopK,
+    ascending: bool,
+    k: usize,
+    /// Rows without a numeric score, in arrival order
+    unscored: Vec<Row>,
+    /// Rows scored NaN, in arrival order
+    nan_scored: Vec<Row>,
+}
+
+impl VectorTopK {
+    /// Create a top-K that keeps the first `k` rows ordered by score
+    pub fn new(ascending: bool, k: usize) -> Self {
+        Self {
+            heap: StreamingTopK::new(k),
+            ascending,
+            k,
+            unscored: Vec::new(),
+            nan_scored: Vec::new(),
+        }
+    }
+
+    /// Offer a row with its score, `None` if it has no numeric score
+    pub fn add(&mut self, row: Row, score: Option<f64>) {
+        match score {
+            Some(score) if score.is_nan() => {
+                if self.nan_scored.len() < self.k {
+                    self.nan_scored.push(row);
+                }
+            }
+            Some(score) => {
+                let score = if self.ascending { -score } else { score };
+                self.heap.add(row, score);
+            }
+            None => {
+                if self.unscored.len() < self.k {
+                    self.unscored.push(row);
+                }
+            }
+        }
+    }
+
+    /// Consume the top-K and return at most `k` rows in ORDER BY order
+    pub fn into_sorted_rows(self) -> Vec<Row> {
+        let mut rows = Vec::with_capacity(self.k.min(1024));
+        if self.ascending {
+            rows.extend(self.heap.into_results());
+            rows.extend(self.nan_scored);
+            rows.extend(self.unscored);
+        } else {
+            rows.extend(self.unscored);
+            rows.extend(self.nan_scored);
+            rows.extend(self.heap.into_results());
+        }
+        rows.truncate(self.k);
+        rows
+    }
+}
+
+/// Bounded top-K for ORDER BY ... LIMIT over arbitrary sort keys
+///
+/// Keeps the first K rows of the requested ordering in a max-heap whose root is
+/// the worst row kept so far. Rows with equal sort keys keep their arrival
+/// order, so the result is identical to a stable full sort followed by
+/// truncation to K rows.
+pub struct OrderedTopK {
+    heap: BinaryHeap<OrderedRow>,
+    /// (ascending, nulls_first) for each sort key
+    directions: Arc<[(bool, bool)]>,
+    collation: Collation,
+    k: usize,
+    next_sequence: usize,
+    peak_len: usize,
+}
+
+/// Row with its evaluated sort keys and arrival position
+///
+/// A key is `None` when its expression failed to evaluate; such keys compare
+/// equal to everything, as they do in a full sort.
+struct OrderedRow {
+    keys: Vec<Option<Value>>,
+    directions: Arc<[(bool, bool)]>,
+    collation: Collation,
+    sequence: usize,
+    row: Row,
+}
+
+impl Ord for OrderedRow {
+    fn cmp(&self, other: &Self) -> Ordering {
+        for ((a, b), (ascending, nulls_first)) in self
+            .keys
+            .iter()
+            .zip(&other.keys)
+            .zip(self.directions.iter())
+        {
+            let ordering = match (a, b) {
+                (Some(a), Some(b)) => {
+                    compare_sort_keys(a, b, *ascending, *nulls_first, self.collation)
+                }
+                _ => Ordering::Equal,
+            };
+            if ordering != Ordering::Equal {
+                return ordering;
+            }
+        }
+        self.sequence.cmp(&other.sequence)
+    }
+}
+
+impl PartialOrd for OrderedRow {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Eq for OrderedRow {}
+
+impl PartialEq for OrderedRow {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl OrderedTopK {
+    /// Create a top-K that keeps the first `k` rows ordered by `sort_items`
+    pub fn new(sort_items: &[SortItem], k: usize) -> Self {
+        Self {
+            heap: BinaryHeap::with_capacity(k.saturating_add(1).min(1024)),
+            directions: sort_items
+                .iter()
+                .map(|item| (item.ascending, item.nulls_first))
+                .collect::<Vec<_>>()
+                .into(),
+            collation: Collation::default(),
+            k,
+            next_sequence: 0,
+            peak_len: 0,
+        }
+    }
+
+    /// Compare string sort keys under `collation` instead of codepoint order
+    pub fn with_collation(mut self, collation: Collation) -> Self {
+        self.collation = collation;
+        self
+    }
+
+    /// Offer a row with its sort key values, one per sort item
+    pub fn add(&mut self, row: Row, keys: Vec<Option<Value>>) {
+        let candidate = OrderedRow {
+            keys,
+            directions: Arc::clone(&self.directions),
+            collation: self.collation,
+            sequence: self.next_sequence,
+            row,
+        };
+        self.next_sequence += 1;
+
+        if self.heap.len() < self.k {
+            self.heap.push(candidate);
+        } else if let Some(mut worst) = self.heap.peek_mut() {
+            // Ties lose to rows already kept because they arrived later
+            if candidate < *worst {
+                *worst = candidate;
+            }
+        }
+        self.peak_len = self.peak_len.max(self.heap.len());
+    }
+
+    /// Number of rows currently kept
+    #[allow(dead_code)] // Used by top-K tests
+    pub fn len(&self) -> usize {
+        self.heap.len()
+    }
+
+    /// Check if no rows are kept
+    #[allow(dead_code)] // Used by top-K tests
+    pub fn is_empty(&self) -> bool {
+        self.heap.is_empty()
+    }
+
+    /// Largest number of rows held at any point
+    pub fn peak_len(&self) -> usize {
+        self.peak_len
+    }
+
+    /// Consume the top-K and return its rows in ORDER BY order
+    pub fn into_sorted_rows(self) -> Vec<Row> {
+        self.heap
+            .into_sorted_vec()
+            .into_iter()
+            .map(|entry| entry.row)
+            .collect()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::storage::Value;
+    use crate::ast::{Expression, Location, Variable};
     use std::collections::HashMap;
 
     fn create_test_row(id: usize, score: f64) -> Row {
@@ -419,4 +696,166 @@ mod tests {
         assert_eq!(scores[1], 10.0);
         assert_eq!(scores[2], 10.0);
     }
+
+    fn sort_item(ascending: bool) -> SortItem {
+        SortItem {
+            expression: Expression::Variable(Variable {
+                name: "score".to_string(),
+                location: Location::default(),
+            }),
+            ascending,
+            nulls_first: !ascending,
+        }
+    }
+
+    fn ids(rows: &[Row]) -> Vec<f64> {
+        rows.iter()
+            .map(|row| row.values["id"].as_number().unwrap())
+            .collect()
+    }
+
+    /// Reference result: stable full sort, then offset and limit
+    fn full_sort(rows: &[Row], ascending: bool, offset: usize, count: usize) -> Vec<Row> {
+        let mut sorted = rows.to_vec();
+        sorted.sort_by(|a, b| {
+            compare_sort_keys(
+                &a.values["score"],
+                &b.values["score"],
+                ascending,
+                !ascending,
+                Collation::default(),
+            )
+        });
+        sorted.into_iter().skip(offset).take(count).collect()
+    }
+
+    fn top_k(rows: &[Row], ascending: bool, offset: usize, count: usize) -> (Vec<Row>, usize) {
+        let mut top_k = OrderedTopK::new(&[sort_item(ascending)], offset + count);
+        for row in rows {
+            top_k.add(row.clone(), vec![Some(row.values["score"].clone())]);
+        }
+        let peak = top_k.peak_len();
+        let rows = top_k.into_sorted_rows().into_iter().skip(offset).collect();
+        (rows, peak)
+    }
+
+    #[test]
+    fn test_ordered_topk_matches_full_sort_over_many_rows() {
+        // Scores repeat every 1000 rows, so the top rows are full of ties
+        let rows: Vec<Row> = (0..100_000)
+            .map(|i| create_test_row(i, ((i * 7919) % 1000) as f64))
+            .collect();
+
+        let (descending, peak) = top_k(&rows, false, 0, 10);
+        assert_eq!(ids(&descending), ids(&full_sort(&rows, false, 0, 10)));
+        assert!(peak <= 10, "top-K held {} rows", peak);
+
+        let (ascending, peak) = top_k(&rows, true, 5, 10);
+        assert_eq!(ids(&ascending), ids(&full_sort(&rows, true, 5, 10)));
+        assert!(peak <= 15, "top-K held {} rows", peak);
+    }
+
+    #[test]
+    fn test_ordered_topk_nulls_and_limit_zero() {
+        let mut rows = vec![create_test_row(1, 3.0), create_test_row(2, 1.0)];
+        rows[1].values.insert("score".to_string(), Value::Null);
+        rows.push(create_test_row(3, 2.0));
+
+        // NULLs sort last ascending, as in the full sort
+        let (ascending, _) = top_k(&rows, true, 0, 3);
+        assert_eq!(ids(&ascending), vec![3.0, 1.0, 2.0]);
+
+        let (none, peak) = top_k(&rows, true, 0, 0);
+        assert!(none.is_empty());
+        assert_eq!(peak, 0);
+    }
+
+    #[test]
+    fn test_nan_sorts_last_deterministically() {
+        let scores = [
+            2.0,
+            f64::NAN,
+            f64::INFINITY,
+            -1.0,
+            f64::NAN,
+            f64::NEG_INFINITY,
+        ];
+        let rows: Vec<Row> = scores
+            .iter()
+            .enumerate()
+            .map(|(i, score)| create_test_row(i, *score))
+            .collect();
+
+        // NaN sorts after infinity; equal NaNs keep their arrival order
+        let ascending = full_sort(&rows, true, 0, rows.len());
+        assert_eq!(ids(&ascending), vec![5.0, 3.0, 0.0, 2.0, 1.0, 4.0]);
+        let (top, _) = top_k(&rows, true, 0, rows.len());
+        assert_eq!(ids(&top), ids(&ascending));
+
+        let (descending, _) = top_k(&rows, false, 0, 3);
+        assert_eq!(ids(&descending), vec![1.0, 4.0, 2.0]);
+
+        assert_eq!(compare_numbers(f64::NAN, f64::NAN), Ordering::Equal);
+        assert_eq!(compare_numbers(f64::NAN, f64::INFINITY), Ordering::Greater);
+        assert_eq!(compare_numbers(-0.0, 0.0), Ordering::Equal);
+    }
+
+    fn cosine_similarity(a: &[f64], b: &[f64]) -> f64 {
+        let dot: f64 = a.iter().zip(b).map(|(x, y)| x * y).sum();
+        let norm_a = a.iter().map(|x| x * x).sum::<f64>().sqrt();
+        let norm_b = b.iter().map(|x| x * x).sum::<f64>().sqrt();
+        dot / (norm_a * norm_b)
+    }
+
+    fn vector_top_k(rows: &[Row], ascending: bool, k: usize) -> Vec<Row> {
+        let mut top_k = VectorTopK::new(ascending, k);
+        for row in rows {
+            top_k.add(row.clone(), row.values["score"].as_number());
+        }
+        top_k.into_sorted_rows()
+    }
+
+    #[test]
+    fn test_vector_topk_matches_brute_force() {
+        // Deterministic pseudo-random vectors in [-1, 1)
+        let mut seed: u64 = 0x2545_f491_4f6c_dd1d;
+        let mut next = move || {
+            seed = seed
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            (seed >> 11) as f64 / (1u64 << 53) as f64 * 2.0 - 1.0
+        };
+        let query: Vec<f64> = (0..32).map(|_| next()).collect();
+        let rows: Vec<Row> = (0..10_000)
+            .map(|i| {
+                let vector: Vec<f64> = (0..32).map(|_| next()).collect();
+                create_test_row(i, cosine_similarity(&vector, &query))
+            })
+            .collect();
+
+        let heap = vector_top_k(&rows, false, 10);
+        assert_eq!(heap.len(), 10);
+        assert_eq!(ids(&heap), ids(&full_sort(&rows, false, 0, 10)));
+
+        let heap = vector_top_k(&rows, true, 10);
+        assert_eq!(ids(&heap), ids(&full_sort(&rows, true, 0, 10)));
+    }
+
+    #[test]
+    fn test_vector_topk_unscored_rows_follow_full_sort() {
+        let mut rows: Vec<Row> = [0.5, 0.9, f64::NAN, 0.1, 0.9, 0.0]
+            .iter()
+            .enumerate()
+            .map(|(i, score)| create_test_row(i, *score))
+            .collect();
+        rows[5].values.insert("score".to_string(), Value::Null);
+
+        // NULL, then NaN, then ties in arrival order when descending
+        let descending = vector_top_k(&rows, false, 4);
+        assert_eq!(ids(&descending), vec![5.0, 2.0, 1.0, 4.0]);
+        assert_eq!(ids(&descending), ids(&full_sort(&rows, false, 0, 4)));
+
+        let ascending = vector_top_k(&rows, true, rows.len());
+        assert_eq!(ids(&ascending), ids(&full_sort(&rows, true, 0, rows.len())));
+    }
 }
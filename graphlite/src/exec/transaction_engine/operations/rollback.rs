@@ -50,7 +50,14 @@ impl TransactionStatementExecutor for RollbackExecutor {
             ExecutionError::RuntimeError("No storage manager available".to_string())
         })?;
 
-        transaction_state.rollback_transaction_with_storage(Some(storage_manager))?;
+        // DDL undo operations also need the catalog
+        let catalog_manager = context
+            .session_provider
+            .as_ref()
+            .map(|provider| provider.get_catalog_manager());
+
+        transaction_state
+            .rollback_transaction_with_catalog(Some(storage_manager), catalog_manager.as_ref())?;
 
         let message = "Transaction rolled back successfully";
 
@@ -171,6 +178,15 @@ impl RollbackExecutor {
             | UndoOperation::InsertEdge { graph_path, .. }
             | UndoOperation::UpdateEdge { graph_path, .. }
             | UndoOperation::DeleteEdge { graph_path, .. } => graph_path,
+            UndoOperation::CreateSchema { .. }
+            | UndoOperation::CreateGraph { .. }
+            | UndoOperation::CreateIndex { .. } => {
+                log::warn!(
+                    "ROLLBACK: DDL undo requires the catalog, skipping {:?}",
+                    undo_op
+                );
+                return Ok(());
+            }
             UndoOperation::Batch { .. } => unreachable!("Batch handled above"),
         };
 
@@ -248,7 +264,7 @@ impl RollbackExecutor {
                 ..
             } => {
                 // Undo: restore the old properties and labels
-                if let Some(node) = graph.get_node_mut(node_id) {
+                if let Some(mut node) = graph.get_node_mut(node_id) {
                     node.properties = old_properties.clone();
                     node.labels = old_labels.clone();
                     log::debug!(
@@ -315,6 +331,14 @@ impl RollbackExecutor {
                 // Batch operations are handled above with early return, this should never be reached
                 unreachable!("Batch operations should be handled before this match statement");
             }
+            UndoOperation::CreateSchema { .. }
+            | UndoOperation::CreateGraph { .. }
+            | UndoOperation::CreateIndex { .. } => {
+                return Err(ExecutionError::RuntimeError(format!(
+                    "DDL undo operation cannot be applied to a graph: {:?}",
+                    undo_op
+                )));
+            }
         }
 
         Ok(())
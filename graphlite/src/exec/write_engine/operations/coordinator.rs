@@ -45,7 +45,10 @@ impl DataStatementCoordinator {
         let stmt_executor: Box<dyn DataStatementExecutor> = match stmt {
             DataStatement::Insert(insert_stmt) => {
                 // Use planned execution for INSERT statements
-                Box::new(PlannedInsertExecutor::new(insert_stmt.clone()))
+                let plan = context.prepared_plan.take().ok_or_else(|| {
+                    ExecutionError::RuntimeError("INSERT statement was not planned".to_string())
+                })?;
+                Box::new(PlannedInsertExecutor::new(insert_stmt.clone(), plan))
             }
             DataStatement::MatchInsert(match_insert_stmt) => {
                 Box::new(MatchInsertExecutor::new(match_insert_stmt.clone()))
@@ -80,6 +83,18 @@ impl DataStatementCoordinator {
                 // Collect warnings from execution context
                 let warnings = context.get_warnings().to_vec();
 
+                // DELETE ... RETURN replaces the status row with the projected rows
+                if let Some((variables, rows)) = context.returned_rows.take() {
+                    return Ok(QueryResult {
+                        rows_affected,
+                        session_result: None,
+                        rows,
+                        variables,
+                        execution_time_ms: execution_time,
+                        warnings,
+                    });
+                }
+
                 let result = QueryResult {
                     rows_affected,
                     session_result: None,
@@ -3,6 +3,7 @@
 //
 use crate::exec::write_stmt::{ExecutionContext, StatementExecutor};
 use crate::exec::ExecutionError;
+use crate::schema::integration::runtime_validator::RuntimeValidator;
 use crate::storage::GraphCache;
 use crate::txn::UndoOperation;
 
@@ -83,7 +84,33 @@ pub trait DataStatementExecutor: StatementExecutor {
         *rows_affected_clone.lock().unwrap() = affected;
         log::debug!("Executed modification for graph '{}'", graph_name);
 
-        // Step 4: Log undo operation for transaction rollback
+        // Step 3b: Enforce property constraints on the nodes this statement wrote.
+        // On failure the modified graph is dropped without being saved.
+        if let Some(index_manager) = storage.get_index_manager() {
+            let constraints = index_manager.constraints_for_graph(graph_name);
+            if !constraints.is_empty() {
+                let mut written_node_ids = Vec::new();
+                collect_written_node_ids(&undo_op, &mut written_node_ids);
+                RuntimeValidator::validate_property_constraints(
+                    &graph,
+                    &constraints,
+                    &written_node_ids,
+                )?;
+            }
+        }
+
+        // Step 4: Log undo operation for transaction rollback. Without an open
+        // transaction the statement commits once saved, so keep its changes
+        // for subscribers.
+        let auto_committed = match context.transaction_state() {
+            Some(transaction_state)
+                if transaction_state.manager().change_feed().has_subscribers()
+                    && !transaction_state.has_active_transaction()? =>
+            {
+                Some((transaction_state, undo_op.clone()))
+            }
+            _ => None,
+        };
         context.log_transaction_operation(undo_op)?;
 
         // Step 5: Update the graph in unified storage (this now automatically handles persistence)
@@ -102,11 +129,34 @@ pub trait DataStatementExecutor: StatementExecutor {
             graph_name
         );
 
+        // Step 6: Tell subscribers what an auto-committed statement changed
+        if let Some((transaction_state, undo_op)) = auto_committed {
+            transaction_state
+                .manager()
+                .change_feed()
+                .publish(std::slice::from_ref(&undo_op));
+        }
+
         let affected = *rows_affected.lock().unwrap();
         Ok(affected)
     }
 }
 
+/// Collect the ids of nodes inserted or updated by a data modification
+fn collect_written_node_ids(undo_op: &UndoOperation, node_ids: &mut Vec<String>) {
+    match undo_op {
+        UndoOperation::InsertNode { node_id, .. } | UndoOperation::UpdateNode { node_id, .. } => {
+            node_ids.push(node_id.clone());
+        }
+        UndoOperation::Batch { operations } => {
+            for operation in operations {
+                collect_written_node_ids(operation, node_ids);
+            }
+        }
+        _ => {}
+    }
+}
+
 /// Enum for different data statement types
 #[derive(Debug, Clone, Copy)]
 #[allow(dead_code)] // ROADMAP v0.5.0 - Statement type classification for optimization and monitoring
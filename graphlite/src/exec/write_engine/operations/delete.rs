@@ -4,6 +4,9 @@
 use std::collections::HashMap;
 
 use crate::ast::{DeleteStatement, Expression};
+use crate::exec::write_engine::operations::edge_delete_policy::{
+    check_connected_edges, edge_delete_policies,
+};
 use crate::exec::write_engine::operations::DataStatementExecutor;
 use crate::exec::write_stmt::{ExecutionContext, StatementExecutor};
 use crate::exec::ExecutionError;
@@ -99,6 +102,13 @@ impl DataStatementExecutor for DeleteExecutor {
         let mut undo_operations = Vec::new();
         let mut deleted_count = 0;
 
+        // ON DELETE policies decide whether a plain DELETE may take connected edges along
+        let edge_delete_policies = if self.statement.detach {
+            HashMap::new()
+        } else {
+            edge_delete_policies(context)
+        };
+
         // Process each expression in DELETE statement
         for expr in &self.statement.expressions {
             match expr {
@@ -138,45 +148,47 @@ impl DataStatementExecutor for DeleteExecutor {
                                     .map(|edge| edge.id.clone())
                                     .collect();
 
-                                if self.statement.detach {
-                                    // DETACH DELETE: remove all connected edges first
-                                    for edge_id in connected_edge_ids {
-                                        // Get edge data before deleting for undo
-                                        let edge = if let Some(edge) = graph.get_edge(&edge_id) {
-                                            edge.clone()
-                                        } else {
-                                            log::error!("Edge {} not found for deletion", edge_id);
-                                            continue;
-                                        };
-
-                                        if let Err(e) = graph.remove_edge(&edge.id) {
-                                            log::error!(
-                                                "Failed to remove edge {} during DETACH DELETE: {}",
-                                                edge.id,
-                                                e
-                                            );
-                                            continue;
-                                        }
-
-                                        log::debug!(
-                                            "Removed edge {} during DETACH DELETE of node {}",
+                                if !self.statement.detach && !connected_edge_ids.is_empty() {
+                                    // Regular DELETE: only edges of ON DELETE CASCADE types go with the node
+                                    check_connected_edges(
+                                        graph,
+                                        &node_id,
+                                        &connected_edge_ids,
+                                        &edge_delete_policies,
+                                    )?;
+                                }
+
+                                // DETACH DELETE or cascading DELETE: remove all connected edges first
+                                for edge_id in connected_edge_ids {
+                                    // Get edge data before deleting for undo
+                                    let edge = if let Some(edge) = graph.get_edge(&edge_id) {
+                                        edge.clone()
+                                    } else {
+                                        log::error!("Edge {} not found for deletion", edge_id);
+                                        continue;
+                                    };
+
+                                    if let Err(e) = graph.remove_edge(&edge.id) {
+                                        log::error!(
+                                            "Failed to remove edge {} during DELETE: {}",
                                             edge.id,
-                                            node_id
+                                            e
                                         );
-
-                                        // Add undo operation for edge
-                                        undo_operations.push(UndoOperation::DeleteEdge {
-                                            graph_path: graph_name.clone(),
-                                            edge_id: edge.id.clone(),
-                                            deleted_edge: edge,
-                                        });
+                                        continue;
                                     }
-                                } else if !connected_edge_ids.is_empty() {
-                                    // Regular DELETE: cannot delete node with relationships
-                                    return Err(ExecutionError::RuntimeError(format!(
-                                        "Cannot delete node {} with relationships. Use DETACH DELETE to remove relationships first.",
+
+                                    log::debug!(
+                                        "Removed edge {} during DELETE of node {}",
+                                        edge.id,
                                         node_id
-                                    )));
+                                    );
+
+                                    // Add undo operation for edge
+                                    undo_operations.push(UndoOperation::DeleteEdge {
+                                        graph_path: graph_name.clone(),
+                                        edge_id: edge.id.clone(),
+                                        deleted_edge: edge,
+                                    });
                                 }
 
                                 // Delete the node
@@ -234,45 +246,47 @@ impl DataStatementExecutor for DeleteExecutor {
                             .map(|edge| edge.id.clone())
                             .collect();
 
-                        if self.statement.detach {
-                            // DETACH DELETE: remove all connected edges first
-                            for edge_id in connected_edge_ids {
-                                // Get edge data before deleting for undo
-                                let edge = if let Some(edge) = graph.get_edge(&edge_id) {
-                                    edge.clone()
-                                } else {
-                                    log::error!("Edge {} not found for deletion", edge_id);
-                                    continue;
-                                };
-
-                                if let Err(e) = graph.remove_edge(&edge.id) {
-                                    log::error!(
-                                        "Failed to remove edge {} during DETACH DELETE: {}",
-                                        edge.id,
-                                        e
-                                    );
-                                    continue;
-                                }
+                        if !self.statement.detach && !connected_edge_ids.is_empty() {
+                            // Regular DELETE: only edges of ON DELETE CASCADE types go with the node
+                            check_connected_edges(
+                                graph,
+                                &node_id,
+                                &connected_edge_ids,
+                                &edge_delete_policies,
+                            )?;
+                        }
 
-                                log::debug!(
-                                    "Removed edge {} during DETACH DELETE of node {}",
+                        // DETACH DELETE or cascading DELETE: remove all connected edges first
+                        for edge_id in connected_edge_ids {
+                            // Get edge data before deleting for undo
+                            let edge = if let Some(edge) = graph.get_edge(&edge_id) {
+                                edge.clone()
+                            } else {
+                                log::error!("Edge {} not found for deletion", edge_id);
+                                continue;
+                            };
+
+                            if let Err(e) = graph.remove_edge(&edge.id) {
+                                log::error!(
+                                    "Failed to remove edge {} during DELETE: {}",
                                     edge.id,
-                                    node_id
+                                    e
                                 );
-
-                                // Add undo operation for edge
-                                undo_operations.push(UndoOperation::DeleteEdge {
-                                    graph_path: graph_name.clone(),
-                                    edge_id: edge.id.clone(),
-                                    deleted_edge: edge,
-                                });
+                                continue;
                             }
-                        } else if !connected_edge_ids.is_empty() {
-                            // Regular DELETE: cannot delete node with relationships
-                            return Err(ExecutionError::RuntimeError(format!(
-                                "Cannot delete node {} with relationships. Use DETACH DELETE to remove relationships first.",
+
+                            log::debug!(
+                                "Removed edge {} during DELETE of node {}",
+                                edge.id,
                                 node_id
-                            )));
+                            );
+
+                            // Add undo operation for edge
+                            undo_operations.push(UndoOperation::DeleteEdge {
+                                graph_path: graph_name.clone(),
+                                edge_id: edge.id.clone(),
+                                deleted_edge: edge,
+                            });
                         }
 
                         // Delete the node
@@ -382,7 +396,9 @@ mod tests {
         let pattern = crate::ast::Node {
             identifier: None,
             labels: vec![],
+            label_expression: None,
             properties: None,
+            predicate: None,
             location: dummy_location(),
         };
 
@@ -401,7 +417,9 @@ mod tests {
         let pattern = crate::ast::Node {
             identifier: None,
             labels: vec!["Person".to_string()],
+            label_expression: None,
             properties: None,
+            predicate: None,
             location: dummy_location(),
         };
 
@@ -420,7 +438,9 @@ mod tests {
         let pattern = crate::ast::Node {
             identifier: None,
             labels: vec!["Company".to_string()],
+            label_expression: None,
             properties: None,
+            predicate: None,
             location: dummy_location(),
         };
 
@@ -439,7 +459,9 @@ mod tests {
         let pattern = crate::ast::Node {
             identifier: None,
             labels: vec!["Person".to_string(), "Employee".to_string()],
+            label_expression: None,
             properties: None,
+            predicate: None,
             location: dummy_location(),
         };
 
@@ -462,7 +484,9 @@ mod tests {
         let pattern = crate::ast::Node {
             identifier: None,
             labels: vec!["Person".to_string(), "Employee".to_string()],
+            label_expression: None,
             properties: None,
+            predicate: None,
             location: dummy_location(),
         };
 
@@ -484,6 +508,7 @@ mod tests {
         let pattern = crate::ast::Node {
             identifier: None,
             labels: vec!["Person".to_string()],
+            label_expression: None,
             properties: Some(PropertyMap {
                 properties: vec![Property {
                     key: "name".to_string(),
@@ -492,6 +517,7 @@ mod tests {
                 }],
                 location: dummy_location(),
             }),
+            predicate: None,
             location: dummy_location(),
         };
 
@@ -513,6 +539,7 @@ mod tests {
         let pattern = crate::ast::Node {
             identifier: None,
             labels: vec!["Person".to_string()],
+            label_expression: None,
             properties: Some(PropertyMap {
                 properties: vec![Property {
                     key: "name".to_string(),
@@ -521,6 +548,7 @@ mod tests {
                 }],
                 location: dummy_location(),
             }),
+            predicate: None,
             location: dummy_location(),
         };
 
@@ -542,6 +570,7 @@ mod tests {
         let pattern = crate::ast::Node {
             identifier: None,
             labels: vec!["Person".to_string()],
+            label_expression: None,
             properties: Some(PropertyMap {
                 properties: vec![Property {
                     key: "age".to_string(),
@@ -550,6 +579,7 @@ mod tests {
                 }],
                 location: dummy_location(),
             }),
+            predicate: None,
             location: dummy_location(),
         };
 
@@ -571,6 +601,7 @@ mod tests {
         let pattern = crate::ast::Node {
             identifier: None,
             labels: vec!["Student".to_string()],
+            label_expression: None,
             properties: Some(PropertyMap {
                 properties: vec![Property {
                     key: "score".to_string(),
@@ -579,6 +610,7 @@ mod tests {
                 }],
                 location: dummy_location(),
             }),
+            predicate: None,
             location: dummy_location(),
         };
 
@@ -600,6 +632,7 @@ mod tests {
         let pattern = crate::ast::Node {
             identifier: None,
             labels: vec!["User".to_string()],
+            label_expression: None,
             properties: Some(PropertyMap {
                 properties: vec![Property {
                     key: "active".to_string(),
@@ -608,6 +641,7 @@ mod tests {
                 }],
                 location: dummy_location(),
             }),
+            predicate: None,
             location: dummy_location(),
         };
 
@@ -629,6 +663,7 @@ mod tests {
         let pattern = crate::ast::Node {
             identifier: None,
             labels: vec!["Person".to_string()],
+            label_expression: None,
             properties: Some(PropertyMap {
                 properties: vec![Property {
                     key: "middle_name".to_string(),
@@ -637,6 +672,7 @@ mod tests {
                 }],
                 location: dummy_location(),
             }),
+            predicate: None,
             location: dummy_location(),
         };
 
@@ -655,6 +691,7 @@ mod tests {
         let pattern = crate::ast::Node {
             identifier: None,
             labels: vec!["Person".to_string()],
+            label_expression: None,
             properties: Some(PropertyMap {
                 properties: vec![Property {
                     key: "name".to_string(),
@@ -663,6 +700,7 @@ mod tests {
                 }],
                 location: dummy_location(),
             }),
+            predicate: None,
             location: dummy_location(),
         };
 
@@ -685,6 +723,7 @@ mod tests {
         let pattern = crate::ast::Node {
             identifier: None,
             labels: vec!["Person".to_string()],
+            label_expression: None,
             properties: Some(PropertyMap {
                 properties: vec![
                     Property {
@@ -700,6 +739,7 @@ mod tests {
                 ],
                 location: dummy_location(),
             }),
+            predicate: None,
             location: dummy_location(),
         };
 
@@ -721,6 +761,7 @@ mod tests {
         let pattern = crate::ast::Node {
             identifier: None,
             labels: vec!["Person".to_string()],
+            label_expression: None,
             properties: Some(PropertyMap {
                 properties: vec![Property {
                     key: "name".to_string(),
@@ -729,6 +770,7 @@ mod tests {
                 }],
                 location: dummy_location(),
             }),
+            predicate: None,
             location: dummy_location(),
         };
 
@@ -0,0 +1,101 @@
+// Copyright (c) 2024-2025 DeepGraph Inc.
+// SPDX-License-Identifier: Apache-2.0
+//
+//! ON DELETE policies of edge types
+//!
+//! A graph type may declare `ON DELETE CASCADE` or `ON DELETE RESTRICT` on an
+//! edge type. A plain DELETE of a node whose connected edges all cascade
+//! removes those edges with the node; any other connected edge blocks it.
+
+use std::collections::HashMap;
+
+use serde_json::json;
+
+use crate::catalog::operations::QueryType;
+use crate::exec::write_stmt::ExecutionContext;
+use crate::exec::ExecutionError;
+use crate::schema::types::{ForeignKeyAction, GraphTypeDefinition};
+use crate::storage::GraphCache;
+
+/// Collect the ON DELETE actions declared by the catalog's graph types, keyed by edge label
+pub fn edge_delete_policies(context: &ExecutionContext) -> HashMap<String, ForeignKeyAction> {
+    let mut policies = HashMap::new();
+
+    let Some(catalog_manager) = context.catalog_manager.as_ref() else {
+        return policies;
+    };
+    let Ok(catalog_manager) = catalog_manager.read() else {
+        log::warn!("Failed to acquire catalog lock for edge delete policies");
+        return policies;
+    };
+
+    let Ok(response) = catalog_manager.query_read_only("graph_type", QueryType::List, json!({}))
+    else {
+        return policies;
+    };
+
+    for item in response.items().unwrap_or_default() {
+        let Some(name) = item.get("name").and_then(|v| v.as_str()) else {
+            continue;
+        };
+        let Ok(type_response) = catalog_manager.query_read_only(
+            "graph_type",
+            QueryType::GetGraphType,
+            json!({ "name": name }),
+        ) else {
+            continue;
+        };
+        let Some(graph_type) = type_response
+            .data()
+            .and_then(|data| serde_json::from_value::<GraphTypeDefinition>(data.clone()).ok())
+        else {
+            continue;
+        };
+
+        for edge_type in graph_type.edge_types {
+            if let Some(action) = edge_type.on_delete {
+                policies.insert(edge_type.type_name, action);
+            }
+        }
+    }
+
+    policies
+}
+
+/// Check that a plain DELETE may remove a node together with its connected edges
+///
+/// Succeeds only when every connected edge belongs to an ON DELETE CASCADE edge type.
+pub fn check_connected_edges(
+    graph: &GraphCache,
+    node_id: &str,
+    connected_edge_ids: &[String],
+    policies: &HashMap<String, ForeignKeyAction>,
+) -> Result<(), ExecutionError> {
+    let labels: Vec<&str> = connected_edge_ids
+        .iter()
+        .filter_map(|edge_id| graph.get_edge(edge_id))
+        .map(|edge| edge.label.as_str())
+        .collect();
+
+    if let Some(label) = labels
+        .iter()
+        .find(|label| matches!(policies.get(**label), Some(ForeignKeyAction::Restrict)))
+    {
+        return Err(ExecutionError::RuntimeError(format!(
+            "Cannot delete node {}: edge type {} is declared ON DELETE RESTRICT",
+            node_id, label
+        )));
+    }
+
+    if labels
+        .iter()
+        .any(|label| !matches!(policies.get(*label), Some(ForeignKeyAction::Cascade)))
+    {
+        return Err(ExecutionError::RuntimeError(format!(
+            "Cannot delete node {} with relationships. Use DETACH DELETE to remove relationships first.",
+            node_id
+        )));
+    }
+
+    Ok(())
+}
@@ -53,7 +53,7 @@ impl InsertExecutor {
             crate::ast::Literal::Boolean(b) => Value::Boolean(*b),
             crate::ast::Literal::Null => Value::Null,
             crate::ast::Literal::DateTime(dt) => Value::String(dt.clone()),
-            crate::ast::Literal::Duration(dur) => Value::String(dur.clone()),
+            crate::ast::Literal::Duration(dur) => Value::from_duration_literal(dur),
             crate::ast::Literal::TimeWindow(tw) => Value::String(tw.clone()),
             crate::ast::Literal::Vector(vec) => {
                 Value::Vector(vec.iter().map(|&f| f as f32).collect())
@@ -62,6 +62,12 @@ impl InsertExecutor {
                 let converted: Vec<Value> = list.iter().map(Self::literal_to_value).collect();
                 Value::List(converted)
             }
+            crate::ast::Literal::Map(entries) => Value::Map(
+                entries
+                    .iter()
+                    .map(|(key, value)| (key.clone(), Self::literal_to_value(value)))
+                    .collect(),
+            ),
         }
     }
 
@@ -138,6 +144,8 @@ impl InsertExecutor {
                         format!("{:?}", item).hash(&mut hasher);
                     }
                 }
+                // Value's Hash sorts map entries, so key order never changes the ID
+                Value::Map(_) => value.hash(&mut hasher),
                 // Catch-all for any other Value types
                 _ => format!("{:?}", value).hash(&mut hasher),
             }
@@ -203,6 +211,8 @@ impl InsertExecutor {
                         format!("{:?}", item).hash(&mut hasher);
                     }
                 }
+                // Value's Hash sorts map entries, so key order never changes the ID
+                Value::Map(_) => value.hash(&mut hasher),
                 // Catch-all for any other Value types
                 _ => format!("{:?}", value).hash(&mut hasher),
             }
@@ -297,6 +307,10 @@ impl DataStatementExecutor for InsertExecutor {
                                         Value::Boolean(b) => serde_json::Value::Bool(*b),
                                         Value::Null => serde_json::Value::Null,
                                         Value::Vector(vec) => serde_json::json!(vec),
+                                        Value::Map(map) => serde_json::json!(map),
+                                        Value::Duration(d) => {
+                                            serde_json::Value::String(d.to_string())
+                                        }
                                         Value::List(list) => {
                                             // Convert list recursively (simplified for now)
                                             serde_json::json!(list)
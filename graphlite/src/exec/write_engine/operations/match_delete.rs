@@ -4,15 +4,23 @@
 use std::collections::HashMap;
 use std::sync::Arc;
 
-use crate::ast::{Expression, Literal, MatchDeleteStatement, PatternElement};
+use crate::ast::{
+    DistinctQualifier, Expression, Literal, MatchDeleteStatement, PatternElement, ReturnClause,
+};
 use crate::exec::with_clause_processor::WithClauseProcessor;
+use crate::exec::write_engine::operations::edge_delete_policy::{
+    check_connected_edges, edge_delete_policies,
+};
 use crate::exec::write_engine::operations::DataStatementExecutor;
 use crate::exec::write_stmt::{ExecutionContext, StatementExecutor};
-use crate::exec::ExecutionError;
+use crate::exec::{ExecutionError, Row};
 use crate::functions::FunctionRegistry;
 use crate::storage::{Edge, GraphCache, Node, Value};
 use crate::txn::{state::OperationType, UndoOperation};
 
+/// Node and edge bindings of one match
+type Combination = (HashMap<String, Node>, HashMap<String, Edge>);
+
 /// Executor for MATCH DELETE statements
 pub struct MatchDeleteExecutor {
     statement: MatchDeleteStatement,
@@ -33,13 +41,19 @@ impl MatchDeleteExecutor {
             Literal::Boolean(b) => Value::Boolean(*b),
             Literal::Null => Value::Null,
             Literal::DateTime(dt) => Value::String(dt.clone()),
-            Literal::Duration(dur) => Value::String(dur.clone()),
+            Literal::Duration(dur) => Value::from_duration_literal(dur),
             Literal::TimeWindow(tw) => Value::String(tw.clone()),
             Literal::Vector(vec) => Value::Vector(vec.iter().map(|&f| f as f32).collect()),
             Literal::List(list) => {
                 let converted: Vec<Value> = list.iter().map(Self::literal_to_value).collect();
                 Value::List(converted)
             }
+            Literal::Map(entries) => Value::Map(
+                entries
+                    .iter()
+                    .map(|(key, value)| (key.clone(), Self::literal_to_value(value)))
+                    .collect(),
+            ),
         }
     }
 
@@ -306,6 +320,66 @@ impl MatchDeleteExecutor {
             _ => None,
         }
     }
+
+    /// Project the RETURN items from each matched combination, before anything is deleted
+    ///
+    /// A bare variable returns the whole node or edge; other items are
+    /// evaluated against the combination, with NULL for missing properties.
+    fn project_return_rows(
+        return_clause: &ReturnClause,
+        combinations: &[Combination],
+    ) -> (Vec<String>, Vec<Row>) {
+        let columns: Vec<String> = return_clause
+            .items
+            .iter()
+            .enumerate()
+            .map(|(index, item)| {
+                item.alias
+                    .clone()
+                    .unwrap_or_else(|| match &item.expression {
+                        Expression::Variable(var) => var.name.clone(),
+                        Expression::PropertyAccess(prop_access) => {
+                            format!("{}.{}", prop_access.object, prop_access.property)
+                        }
+                        _ => format!("column_{}", index + 1),
+                    })
+            })
+            .collect();
+
+        let mut rows: Vec<Row> = Vec::new();
+        for (node_combination, edge_combination) in combinations {
+            let values = return_clause
+                .items
+                .iter()
+                .map(|item| match &item.expression {
+                    Expression::Variable(var) => node_combination
+                        .get(&var.name)
+                        .map(|node| Value::Node(node.clone()))
+                        .or_else(|| {
+                            edge_combination
+                                .get(&var.name)
+                                .map(|edge| Value::Edge(edge.clone()))
+                        })
+                        .unwrap_or(Value::Null),
+                    expr => Self::evaluate_expression_on_combination(
+                        node_combination,
+                        edge_combination,
+                        expr,
+                    )
+                    .unwrap_or(Value::Null),
+                })
+                .collect::<Vec<_>>();
+
+            if matches!(return_clause.distinct, DistinctQualifier::Distinct)
+                && rows.iter().any(|row| row.positional_values == values)
+            {
+                continue;
+            }
+            rows.push(Row::from_positional(values, &columns));
+        }
+
+        (columns, rows)
+    }
 }
 
 impl StatementExecutor for MatchDeleteExecutor {
@@ -432,6 +506,9 @@ impl DataStatementExecutor for MatchDeleteExecutor {
 
         if combined_matches.is_empty() {
             log::debug!("MATCH-DELETE: No matches found");
+            if let Some(ref return_clause) = self.statement.return_clause {
+                context.returned_rows = Some(Self::project_return_rows(return_clause, &[]));
+            }
             return Ok((
                 UndoOperation::DeleteNode {
                     graph_path: graph_name,
@@ -588,6 +665,19 @@ impl DataStatementExecutor for MatchDeleteExecutor {
             filtered_combined.len()
         );
 
+        // Step 4: Capture RETURN items while the matched entities still exist
+        if let Some(ref return_clause) = self.statement.return_clause {
+            context.returned_rows =
+                Some(Self::project_return_rows(return_clause, &filtered_combined));
+        }
+
+        // ON DELETE policies decide whether a plain DELETE may take connected edges along
+        let edge_delete_policies = if self.statement.detach {
+            HashMap::new()
+        } else {
+            edge_delete_policies(context)
+        };
+
         // Step 5: Process DELETE expressions on filtered combinations
         for (node_combination, edge_combination) in &filtered_combined {
             for expr in &self.statement.expressions {
@@ -653,40 +743,47 @@ impl DataStatementExecutor for MatchDeleteExecutor {
                                 .map(|edge| edge.id.clone())
                                 .collect();
 
-                            if self.statement.detach {
-                                // DETACH DELETE: remove all connected edges first
-                                for edge_id in connected_edge_ids {
-                                    // Get edge data before deleting for undo
-                                    let edge = if let Some(edge) = graph.get_edge(&edge_id) {
-                                        edge.clone()
-                                    } else {
-                                        log::debug!(
-                                            "Edge {} already deleted or not found",
-                                            edge_id
-                                        );
-                                        continue;
-                                    };
-
-                                    if let Err(e) = graph.remove_edge(&edge.id) {
-                                        log::error!("Failed to remove edge {} during MATCH DETACH DELETE: {}", edge.id, e);
-                                        continue;
-                                    }
-
-                                    log::debug!("MATCH DETACH DELETE: Removed edge {} during deletion of node {}", edge.id, node_id);
-
-                                    // Add undo operation for edge
-                                    undo_operations.push(UndoOperation::DeleteEdge {
-                                        graph_path: graph_name.clone(),
-                                        edge_id: edge.id.clone(),
-                                        deleted_edge: edge,
-                                    });
+                            if !self.statement.detach && !connected_edge_ids.is_empty() {
+                                // Regular DELETE: only edges of ON DELETE CASCADE types go with the node
+                                check_connected_edges(
+                                    graph,
+                                    node_id,
+                                    &connected_edge_ids,
+                                    &edge_delete_policies,
+                                )?;
+                            }
+
+                            // DETACH DELETE or cascading DELETE: remove all connected edges first
+                            for edge_id in connected_edge_ids {
+                                // Get edge data before deleting for undo
+                                let edge = if let Some(edge) = graph.get_edge(&edge_id) {
+                                    edge.clone()
+                                } else {
+                                    log::debug!("Edge {} already deleted or not found", edge_id);
+                                    continue;
+                                };
+
+                                if let Err(e) = graph.remove_edge(&edge.id) {
+                                    log::error!(
+                                        "Failed to remove edge {} during MATCH DELETE: {}",
+                                        edge.id,
+                                        e
+                                    );
+                                    continue;
                                 }
-                            } else if !connected_edge_ids.is_empty() {
-                                // Regular DELETE: cannot delete node with relationships
-                                return Err(ExecutionError::RuntimeError(format!(
-                                    "Cannot delete node {} with relationships. Use DETACH DELETE to remove relationships first.",
+
+                                log::debug!(
+                                    "MATCH DELETE: Removed edge {} during deletion of node {}",
+                                    edge.id,
                                     node_id
-                                )));
+                                );
+
+                                // Add undo operation for edge
+                                undo_operations.push(UndoOperation::DeleteEdge {
+                                    graph_path: graph_name.clone(),
+                                    edge_id: edge.id.clone(),
+                                    deleted_edge: edge,
+                                });
                             }
 
                             // Delete the node
@@ -826,7 +923,9 @@ mod tests {
         let pattern = crate::ast::Node {
             identifier: None,
             labels: vec![],
+            label_expression: None,
             properties: None,
+            predicate: None,
             location: dummy_location(),
         };
 
@@ -845,7 +944,9 @@ mod tests {
         let pattern = crate::ast::Node {
             identifier: None,
             labels: vec!["Person".to_string()],
+            label_expression: None,
             properties: None,
+            predicate: None,
             location: dummy_location(),
         };
 
@@ -864,7 +965,9 @@ mod tests {
         let pattern = crate::ast::Node {
             identifier: None,
             labels: vec!["Company".to_string()],
+            label_expression: None,
             properties: None,
+            predicate: None,
             location: dummy_location(),
         };
 
@@ -883,7 +986,9 @@ mod tests {
         let pattern = crate::ast::Node {
             identifier: None,
             labels: vec!["Person".to_string()],
+            label_expression: None,
             properties: None,
+            predicate: None,
             location: dummy_location(),
         };
 
@@ -905,6 +1010,7 @@ mod tests {
         let pattern = crate::ast::Node {
             identifier: None,
             labels: vec!["Person".to_string()],
+            label_expression: None,
             properties: Some(PropertyMap {
                 properties: vec![Property {
                     key: "name".to_string(),
@@ -913,6 +1019,7 @@ mod tests {
                 }],
                 location: dummy_location(),
             }),
+            predicate: None,
             location: dummy_location(),
         };
 
@@ -934,6 +1041,7 @@ mod tests {
         let pattern = crate::ast::Node {
             identifier: None,
             labels: vec!["Person".to_string()],
+            label_expression: None,
             properties: Some(PropertyMap {
                 properties: vec![Property {
                     key: "name".to_string(),
@@ -942,6 +1050,7 @@ mod tests {
                 }],
                 location: dummy_location(),
             }),
+            predicate: None,
             location: dummy_location(),
         };
 
@@ -960,6 +1069,7 @@ mod tests {
         let pattern = crate::ast::Node {
             identifier: None,
             labels: vec!["Person".to_string()],
+            label_expression: None,
             properties: Some(PropertyMap {
                 properties: vec![Property {
                     key: "name".to_string(),
@@ -968,6 +1078,7 @@ mod tests {
                 }],
                 location: dummy_location(),
             }),
+            predicate: None,
             location: dummy_location(),
         };
 
@@ -990,6 +1101,7 @@ mod tests {
         let pattern = crate::ast::Node {
             identifier: None,
             labels: vec!["Person".to_string()],
+            label_expression: None,
             properties: Some(PropertyMap {
                 properties: vec![
                     Property {
@@ -1005,6 +1117,7 @@ mod tests {
                 ],
                 location: dummy_location(),
             }),
+            predicate: None,
             location: dummy_location(),
         };
 
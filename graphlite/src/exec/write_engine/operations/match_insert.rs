@@ -34,13 +34,19 @@ impl MatchInsertExecutor {
             Literal::Boolean(b) => Value::Boolean(*b),
             Literal::Null => Value::Null,
             Literal::DateTime(dt) => Value::String(dt.clone()),
-            Literal::Duration(dur) => Value::String(dur.clone()),
+            Literal::Duration(dur) => Value::from_duration_literal(dur),
             Literal::TimeWindow(tw) => Value::String(tw.clone()),
             Literal::Vector(vec) => Value::Vector(vec.iter().map(|&f| f as f32).collect()),
             Literal::List(list) => {
                 let converted: Vec<Value> = list.iter().map(Self::literal_to_value).collect();
                 Value::List(converted)
             }
+            Literal::Map(entries) => Value::Map(
+                entries
+                    .iter()
+                    .map(|(key, value)| (key.clone(), Self::literal_to_value(value)))
+                    .collect(),
+            ),
         }
     }
 
@@ -233,21 +239,30 @@ impl MatchInsertExecutor {
     fn evaluate_where_clause_on_combination(
         combination: &HashMap<String, Node>,
         where_clause: &crate::ast::WhereClause,
+        context: &ExecutionContext,
     ) -> bool {
-        Self::evaluate_where_expression_on_combination(combination, &where_clause.condition)
+        Self::evaluate_where_expression_on_combination(
+            combination,
+            &where_clause.condition,
+            context,
+        )
     }
 
     /// Evaluate WHERE expression against a variable combination
     fn evaluate_where_expression_on_combination(
         combination: &HashMap<String, Node>,
         expr: &crate::ast::Expression,
+        context: &ExecutionContext,
     ) -> bool {
         match expr {
             crate::ast::Expression::Binary(binary_op) => {
                 let left_val =
-                    Self::evaluate_expression_on_combination(combination, &binary_op.left);
-                let right_val =
-                    Self::evaluate_expression_on_combination(combination, &binary_op.right);
+                    Self::evaluate_expression_on_combination(combination, &binary_op.left, context);
+                let right_val = Self::evaluate_expression_on_combination(
+                    combination,
+                    &binary_op.right,
+                    context,
+                );
 
                 match &binary_op.operator {
                     crate::ast::Operator::Equal => left_val == right_val,
@@ -267,18 +282,26 @@ impl MatchInsertExecutor {
                         }
                     }
                     crate::ast::Operator::And => {
-                        Self::evaluate_where_expression_on_combination(combination, &binary_op.left)
-                            && Self::evaluate_where_expression_on_combination(
-                                combination,
-                                &binary_op.right,
-                            )
+                        Self::evaluate_where_expression_on_combination(
+                            combination,
+                            &binary_op.left,
+                            context,
+                        ) && Self::evaluate_where_expression_on_combination(
+                            combination,
+                            &binary_op.right,
+                            context,
+                        )
                     }
                     crate::ast::Operator::Or => {
-                        Self::evaluate_where_expression_on_combination(combination, &binary_op.left)
-                            || Self::evaluate_where_expression_on_combination(
-                                combination,
-                                &binary_op.right,
-                            )
+                        Self::evaluate_where_expression_on_combination(
+                            combination,
+                            &binary_op.left,
+                            context,
+                        ) || Self::evaluate_where_expression_on_combination(
+                            combination,
+                            &binary_op.right,
+                            context,
+                        )
                     }
                     _ => {
                         log::warn!(
@@ -300,6 +323,7 @@ impl MatchInsertExecutor {
     fn evaluate_expression_on_combination(
         combination: &HashMap<String, Node>,
         expr: &crate::ast::Expression,
+        context: &ExecutionContext,
     ) -> Value {
         match expr {
             crate::ast::Expression::Variable(var) => combination
@@ -340,6 +364,12 @@ impl MatchInsertExecutor {
                 }
             }
             crate::ast::Expression::Literal(literal) => Self::literal_to_value(literal),
+            crate::ast::Expression::Parameter(parameter) => {
+                context.get_parameter(&parameter.name).unwrap_or_else(|| {
+                    log::warn!("Parameter '${}' is not bound", parameter.name);
+                    Value::Null
+                })
+            }
             _ => {
                 log::warn!("Unsupported expression type in combination evaluation");
                 Value::Null
@@ -597,7 +627,7 @@ impl DataStatementExecutor for MatchInsertExecutor {
                 .into_iter()
                 .filter(|combination| {
                     // Check WHERE clause against the combination
-                    Self::evaluate_where_clause_on_combination(combination, where_clause)
+                    Self::evaluate_where_clause_on_combination(combination, where_clause, context)
                 })
                 .collect();
             log::debug!(
@@ -1117,7 +1147,9 @@ mod tests {
         let pattern = crate::ast::Node {
             identifier: None,
             labels: vec![],
+            label_expression: None,
             properties: None,
+            predicate: None,
             location: dummy_location(),
         };
 
@@ -1136,7 +1168,9 @@ mod tests {
         let pattern = crate::ast::Node {
             identifier: None,
             labels: vec!["Person".to_string()],
+            label_expression: None,
             properties: None,
+            predicate: None,
             location: dummy_location(),
         };
 
@@ -1155,7 +1189,9 @@ mod tests {
         let pattern = crate::ast::Node {
             identifier: None,
             labels: vec!["Company".to_string()],
+            label_expression: None,
             properties: None,
+            predicate: None,
             location: dummy_location(),
         };
 
@@ -1174,7 +1210,9 @@ mod tests {
         let pattern = crate::ast::Node {
             identifier: None,
             labels: vec!["Person".to_string()],
+            label_expression: None,
             properties: None,
+            predicate: None,
             location: dummy_location(),
         };
 
@@ -1196,6 +1234,7 @@ mod tests {
         let pattern = crate::ast::Node {
             identifier: None,
             labels: vec!["Person".to_string()],
+            label_expression: None,
             properties: Some(PropertyMap {
                 properties: vec![Property {
                     key: "name".to_string(),
@@ -1204,6 +1243,7 @@ mod tests {
                 }],
                 location: dummy_location(),
             }),
+            predicate: None,
             location: dummy_location(),
         };
 
@@ -1225,6 +1265,7 @@ mod tests {
         let pattern = crate::ast::Node {
             identifier: None,
             labels: vec!["Person".to_string()],
+            label_expression: None,
             properties: Some(PropertyMap {
                 properties: vec![Property {
                     key: "name".to_string(),
@@ -1233,6 +1274,7 @@ mod tests {
                 }],
                 location: dummy_location(),
             }),
+            predicate: None,
             location: dummy_location(),
         };
 
@@ -1251,6 +1293,7 @@ mod tests {
         let pattern = crate::ast::Node {
             identifier: None,
             labels: vec!["Person".to_string()],
+            label_expression: None,
             properties: Some(PropertyMap {
                 properties: vec![Property {
                     key: "name".to_string(),
@@ -1259,6 +1302,7 @@ mod tests {
                 }],
                 location: dummy_location(),
             }),
+            predicate: None,
             location: dummy_location(),
         };
 
@@ -1281,6 +1325,7 @@ mod tests {
         let pattern = crate::ast::Node {
             identifier: None,
             labels: vec!["Person".to_string()],
+            label_expression: None,
             properties: Some(PropertyMap {
                 properties: vec![
                     Property {
@@ -1296,6 +1341,7 @@ mod tests {
                 ],
                 location: dummy_location(),
             }),
+            predicate: None,
             location: dummy_location(),
         };
 
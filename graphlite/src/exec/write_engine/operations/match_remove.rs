@@ -35,13 +35,19 @@ impl MatchRemoveExecutor {
             Literal::Boolean(b) => Value::Boolean(*b),
             Literal::Null => Value::Null,
             Literal::DateTime(dt) => Value::String(dt.clone()),
-            Literal::Duration(dur) => Value::String(dur.clone()),
+            Literal::Duration(dur) => Value::from_duration_literal(dur),
             Literal::TimeWindow(tw) => Value::String(tw.clone()),
             Literal::Vector(vec) => Value::Vector(vec.iter().map(|&f| f as f32).collect()),
             Literal::List(list) => {
                 let converted: Vec<Value> = list.iter().map(Self::literal_to_value).collect();
                 Value::List(converted)
             }
+            Literal::Map(entries) => Value::Map(
+                entries
+                    .iter()
+                    .map(|(key, value)| (key.clone(), Self::literal_to_value(value)))
+                    .collect(),
+            ),
         }
     }
 
@@ -515,7 +521,8 @@ impl DataStatementExecutor for MatchRemoveExecutor {
 
                                 if has_property {
                                     // Remove the property
-                                    if let Some(node_mut) = graph.get_node_mut(&matched_node.id) {
+                                    if let Some(mut node_mut) = graph.get_node_mut(&matched_node.id)
+                                    {
                                         node_mut.remove_property(&property_access.property);
                                         log::debug!("DEBUG: MATCH REMOVE: Removed property {} from node {} (name: {:?})", 
                                            property_access.property, matched_node.id, matched_node.properties.get("name"));
@@ -565,7 +572,7 @@ impl DataStatementExecutor for MatchRemoveExecutor {
                                 for term in &labels.terms {
                                     for factor in &term.factors {
                                         if let LabelFactor::Identifier(label_name) = factor {
-                                            if let Some(node_mut) =
+                                            if let Some(mut node_mut) =
                                                 graph.get_node_mut(&matched_node.id)
                                             {
                                                 let original_len = node_mut.labels.len();
@@ -704,7 +711,9 @@ mod tests {
         let pattern = crate::ast::Node {
             identifier: None,
             labels: vec![],
+            label_expression: None,
             properties: None,
+            predicate: None,
             location: dummy_location(),
         };
 
@@ -723,7 +732,9 @@ mod tests {
         let pattern = crate::ast::Node {
             identifier: None,
             labels: vec!["Person".to_string()],
+            label_expression: None,
             properties: None,
+            predicate: None,
             location: dummy_location(),
         };
 
@@ -742,7 +753,9 @@ mod tests {
         let pattern = crate::ast::Node {
             identifier: None,
             labels: vec!["Company".to_string()],
+            label_expression: None,
             properties: None,
+            predicate: None,
             location: dummy_location(),
         };
 
@@ -764,6 +777,7 @@ mod tests {
         let pattern = crate::ast::Node {
             identifier: None,
             labels: vec!["Person".to_string()],
+            label_expression: None,
             properties: Some(PropertyMap {
                 properties: vec![Property {
                     key: "name".to_string(),
@@ -772,6 +786,7 @@ mod tests {
                 }],
                 location: dummy_location(),
             }),
+            predicate: None,
             location: dummy_location(),
         };
 
@@ -793,6 +808,7 @@ mod tests {
         let pattern = crate::ast::Node {
             identifier: None,
             labels: vec!["Person".to_string()],
+            label_expression: None,
             properties: Some(PropertyMap {
                 properties: vec![Property {
                     key: "name".to_string(),
@@ -801,6 +817,7 @@ mod tests {
                 }],
                 location: dummy_location(),
             }),
+            predicate: None,
             location: dummy_location(),
         };
 
@@ -4,8 +4,12 @@
 use std::collections::HashMap;
 use std::sync::Arc;
 
-use crate::ast::{Expression, MatchSetStatement, PatternElement, SetItem};
+use crate::ast::{
+    Expression, LabelExpression, LabelFactor, MatchSetStatement, PatternElement, RemoveItem,
+    SetItem,
+};
 use crate::exec::with_clause_processor::WithClauseProcessor;
+use crate::exec::write_engine::operations::planned_insert::PlannedInsertExecutor;
 use crate::exec::write_engine::operations::DataStatementExecutor;
 use crate::exec::write_stmt::{ExecutionContext, StatementExecutor};
 use crate::exec::ExecutionError;
@@ -24,6 +28,105 @@ impl MatchSetExecutor {
         Self { statement }
     }
 
+    /// Record a node's state from before this statement, once per node
+    fn record_node_undo(
+        undo_operations: &mut Vec<UndoOperation>,
+        node_old_states: &HashMap<String, (HashMap<String, Value>, Vec<String>)>,
+        graph_name: &str,
+        node_id: &str,
+    ) {
+        let already_recorded = undo_operations.iter().any(|op| {
+            matches!(op, UndoOperation::UpdateNode { node_id: recorded, .. } if recorded == node_id)
+        });
+        if already_recorded {
+            return;
+        }
+        if let Some((old_properties, old_labels)) = node_old_states.get(node_id) {
+            undo_operations.push(UndoOperation::UpdateNode {
+                graph_path: graph_name.to_string(),
+                node_id: node_id.to_string(),
+                old_properties: old_properties.clone(),
+                old_labels: old_labels.clone(),
+            });
+        }
+    }
+
+    /// Replace the label of a matched edge with the single label in `labels`
+    ///
+    /// The label is part of a content-addressed edge ID, so such an edge is
+    /// re-added under the ID its new label gives it; other IDs are kept.
+    /// Returns whether the edge changed. An edge an earlier combination
+    /// already retyped is left alone.
+    fn retype_edge(
+        graph: &mut GraphCache,
+        graph_name: &str,
+        variable: &str,
+        matched_edge: &Edge,
+        labels: &LabelExpression,
+        undo_operations: &mut Vec<UndoOperation>,
+    ) -> Result<bool, ExecutionError> {
+        let new_label = match labels.terms.as_slice() {
+            [term] => match term.factors.as_slice() {
+                [LabelFactor::Identifier(label)] => Some(label.clone()),
+                _ => None,
+            },
+            _ => None,
+        }
+        .ok_or_else(|| {
+            ExecutionError::RuntimeError(format!(
+                "An edge has exactly one label, so SET {}:... must name a single label",
+                variable
+            ))
+        })?;
+        let Some(current) = graph.get_edge(&matched_edge.id).cloned() else {
+            return Ok(false);
+        };
+        if current.label == new_label {
+            return Ok(false);
+        }
+
+        let new_id = if current.id.starts_with("edge_") {
+            PlannedInsertExecutor::content_id(
+                "edge",
+                &[
+                    current.from_node.clone(),
+                    current.to_node.clone(),
+                    new_label.clone(),
+                ],
+                &current.properties,
+            )
+        } else {
+            current.id.clone()
+        };
+        graph
+            .remove_edge(&current.id)
+            .map_err(|e| ExecutionError::StorageError(e.to_string()))?;
+        graph
+            .add_edge(Edge {
+                id: new_id.clone(),
+                label: new_label,
+                ..current
+            })
+            .map_err(|e| {
+                ExecutionError::StorageError(format!(
+                    "Cannot retype edge '{}': {}",
+                    matched_edge.id, e
+                ))
+            })?;
+
+        // Undo runs in order: drop the retyped edge, then restore it as matched
+        undo_operations.push(UndoOperation::InsertEdge {
+            graph_path: graph_name.to_string(),
+            edge_id: new_id,
+        });
+        undo_operations.push(UndoOperation::DeleteEdge {
+            graph_path: graph_name.to_string(),
+            edge_id: matched_edge.id.clone(),
+            deleted_edge: matched_edge.clone(),
+        });
+        Ok(true)
+    }
+
     /// Evaluate an expression to a value, with access to matched node properties
     fn evaluate_expression(
         expr: &Expression,
@@ -76,7 +179,7 @@ impl MatchSetExecutor {
             crate::ast::Literal::Boolean(b) => Value::Boolean(*b),
             crate::ast::Literal::Null => Value::Null,
             crate::ast::Literal::DateTime(dt) => Value::String(dt.clone()),
-            crate::ast::Literal::Duration(dur) => Value::String(dur.clone()),
+            crate::ast::Literal::Duration(dur) => Value::from_duration_literal(dur),
             crate::ast::Literal::TimeWindow(tw) => Value::String(tw.clone()),
             crate::ast::Literal::Vector(vec) => {
                 Value::Vector(vec.iter().map(|&f| f as f32).collect())
@@ -85,6 +188,12 @@ impl MatchSetExecutor {
                 let converted: Vec<Value> = list.iter().map(Self::literal_to_value).collect();
                 Value::List(converted)
             }
+            crate::ast::Literal::Map(entries) => Value::Map(
+                entries
+                    .iter()
+                    .map(|(key, value)| (key.clone(), Self::literal_to_value(value)))
+                    .collect(),
+            ),
         }
     }
 
@@ -378,6 +487,54 @@ impl MatchSetExecutor {
                     }
                 }
             }
+            Expression::Case(case_expr) => {
+                // Pick the first matching branch, evaluated against the node's current properties
+                use crate::ast::CaseType;
+                let (branch, else_expression) = match &case_expr.case_type {
+                    CaseType::Simple(simple_case) => {
+                        let test_val = Self::evaluate_expression_on_combination(
+                            combination,
+                            &simple_case.test_expression,
+                        )?;
+                        let mut matched = None;
+                        for branch in &simple_case.when_branches {
+                            for when_value in &branch.when_values {
+                                let value = Self::evaluate_expression_on_combination(
+                                    combination,
+                                    when_value,
+                                )?;
+                                if value == test_val {
+                                    matched = Some(&branch.then_expression);
+                                    break;
+                                }
+                            }
+                            if matched.is_some() {
+                                break;
+                            }
+                        }
+                        (matched, &simple_case.else_expression)
+                    }
+                    CaseType::Searched(searched_case) => {
+                        let mut matched = None;
+                        for branch in &searched_case.when_branches {
+                            let condition = Self::evaluate_expression_on_combination(
+                                combination,
+                                &branch.condition,
+                            )?;
+                            if condition == Value::Boolean(true) {
+                                matched = Some(&branch.then_expression);
+                                break;
+                            }
+                        }
+                        (matched, &searched_case.else_expression)
+                    }
+                };
+
+                match branch.or(else_expression.as_ref()) {
+                    Some(result) => Self::evaluate_expression_on_combination(combination, result),
+                    None => Some(Value::Null),
+                }
+            }
             _ => None,
         }
     }
@@ -662,25 +819,55 @@ impl DataStatementExecutor for MatchSetExecutor {
             // TRANSACTIONAL GUARANTEE: Pre-evaluate ALL property expressions for this combination
             // This ensures atomicity - if any expression fails, we abort before making ANY changes
             let mut evaluated_items = Vec::new();
+            let mut evaluated_maps = Vec::new();
             for item in &self.statement.items {
-                if let SetItem::Property { property, value } = item {
-                    log::debug!(
-                        "MATCH-SET: SET property assignment: {}.{} = {:?}",
-                        property.object,
-                        property.property,
-                        value
-                    );
+                // Get computed values from WITH clause result
+                let computed_values = with_result.as_ref().map(|wr| &wr.computed_values);
 
-                    // Get computed values from WITH clause result
-                    let computed_values = with_result.as_ref().map(|wr| &wr.computed_values);
+                match item {
+                    SetItem::Property { property, value } => {
+                        log::debug!(
+                            "MATCH-SET: SET property assignment: {}.{} = {:?}",
+                            property.object,
+                            property.property,
+                            value
+                        );
 
-                    // Evaluate the new value - fail immediately if invalid (no partial updates!)
-                    let new_value = Self::evaluate_expression(value, computed_values, combination, context)
-                        .ok_or_else(|| ExecutionError::ExpressionError(
-                            format!("Failed to evaluate MATCH SET property '{}': expression evaluation failed. Transaction aborted.", property.property)
-                        ))?;
+                        // Evaluate the new value - fail immediately if invalid (no partial updates!)
+                        let new_value = Self::evaluate_expression(value, computed_values, combination, context)
+                            .ok_or_else(|| ExecutionError::ExpressionError(
+                                format!("Failed to evaluate MATCH SET property '{}': expression evaluation failed. Transaction aborted.", property.property)
+                            ))?;
 
-                    evaluated_items.push((property.clone(), new_value));
+                        evaluated_items.push((property.clone(), new_value));
+                    }
+                    SetItem::Variable { variable, value }
+                    | SetItem::MergeProperties { variable, value } => {
+                        let properties = match Self::evaluate_expression(
+                            value,
+                            computed_values,
+                            combination,
+                            context,
+                        ) {
+                            Some(Value::Map(properties)) => properties,
+                            Some(other) => {
+                                return Err(ExecutionError::ExpressionError(format!(
+                                    "SET on '{}' requires a map of properties, got {}",
+                                    variable,
+                                    other.type_name()
+                                )))
+                            }
+                            None => {
+                                return Err(ExecutionError::ExpressionError(format!(
+                                    "Failed to evaluate MATCH SET properties for '{}': expression evaluation failed. Transaction aborted.",
+                                    variable
+                                )))
+                            }
+                        };
+                        let replace = matches!(item, SetItem::Variable { .. });
+                        evaluated_maps.push((variable.clone(), properties, replace));
+                    }
+                    SetItem::Label { .. } => {}
                 }
             }
 
@@ -724,7 +911,7 @@ impl DataStatementExecutor for MatchSetExecutor {
 
                     if node_property_matches {
                         // Update the node
-                        if let Some(node_mut) = graph.get_node_mut(&matched_node.id) {
+                        if let Some(mut node_mut) = graph.get_node_mut(&matched_node.id) {
                             node_mut.set_property(property.property.clone(), new_value.clone());
                             updated_count += 1;
                             property_applied = true;
@@ -819,20 +1006,101 @@ impl DataStatementExecutor for MatchSetExecutor {
                 }
             }
 
+            // Apply whole-map assignments: `n = {..}` replaces every property and
+            // `n += {..}` merges the listed ones. A null value removes the key.
+            for (variable, properties, replace) in evaluated_maps {
+                if let Some(matched_node) = combination.get(&variable) {
+                    if let Some(mut node_mut) = graph.get_node_mut(&matched_node.id) {
+                        if replace {
+                            node_mut.properties.clear();
+                        }
+                        for (key, value) in properties {
+                            if value.is_null() {
+                                node_mut.remove_property(&key);
+                            } else {
+                                node_mut.set_property(key, value);
+                            }
+                        }
+                        updated_count += 1;
+
+                        if let Some((old_properties, old_labels)) =
+                            node_old_states.get(&matched_node.id)
+                        {
+                            if !undo_operations.iter().any(|op| {
+                                if let UndoOperation::UpdateNode { node_id, .. } = op {
+                                    node_id == &matched_node.id
+                                } else {
+                                    false
+                                }
+                            }) {
+                                undo_operations.push(UndoOperation::UpdateNode {
+                                    graph_path: graph_name.clone(),
+                                    node_id: matched_node.id.clone(),
+                                    old_properties: old_properties.clone(),
+                                    old_labels: old_labels.clone(),
+                                });
+                            }
+                        }
+                    }
+                } else if let Some(matched_edge) = edge_combination.get(&variable) {
+                    // Edge rollback restores the whole property map, so keep all of it
+                    let old_properties = graph
+                        .get_edge(&matched_edge.id)
+                        .map(|edge| edge.properties.clone());
+                    if let (Some(old_properties), Some(edge_mut)) =
+                        (old_properties, graph.get_edge_mut(&matched_edge.id))
+                    {
+                        if replace {
+                            edge_mut.properties.clear();
+                        }
+                        for (key, value) in properties {
+                            if value.is_null() {
+                                edge_mut.remove_property(&key);
+                            } else {
+                                edge_mut.set_property(key, value);
+                            }
+                        }
+                        updated_count += 1;
+
+                        undo_operations.push(UndoOperation::UpdateEdge {
+                            graph_path: graph_name.clone(),
+                            edge_id: matched_edge.id.clone(),
+                            old_properties,
+                            old_label: matched_edge.label.clone(),
+                        });
+                    }
+                } else {
+                    log::warn!(
+                        "MATCH-SET: Property map assignment to '{}' did not match any variables",
+                        variable
+                    );
+                }
+            }
+
             // Handle other SET item types (TODO: these should also be transactional)
             for item in &self.statement.items {
                 match item {
-                    SetItem::Property { .. } => {
+                    SetItem::Property { .. }
+                    | SetItem::Variable { .. }
+                    | SetItem::MergeProperties { .. } => {
                         // Already handled above
                     }
-                    SetItem::Variable { variable, value } => {
-                        log::warn!(
-                            "Variable assignment in MATCH SET not yet fully supported: {} = {:?}",
-                            variable,
-                            value
-                        );
-                    }
                     SetItem::Label { variable, labels } => {
+                        // An edge has exactly one label, so SET on an edge retypes it
+                        if let Some(matched_edge) = edge_combination.get(variable) {
+                            if Self::retype_edge(
+                                graph,
+                                &graph_name,
+                                variable,
+                                matched_edge,
+                                labels,
+                                &mut undo_operations,
+                            )? {
+                                updated_count += 1;
+                            }
+                            continue;
+                        }
+
                         // Handle label assignment for matched nodes
                         for (var_name, matched_node) in combination {
                             if self.statement.match_clause.patterns.iter().any(|p| {
@@ -845,7 +1113,7 @@ impl DataStatementExecutor for MatchSetExecutor {
                             }) && var_name == variable
                             {
                                 // Add new labels to the node
-                                if let Some(node_mut) = graph.get_node_mut(&matched_node.id) {
+                                if let Some(mut node_mut) = graph.get_node_mut(&matched_node.id) {
                                     // Extract labels from LabelExpression
                                     for term in &labels.terms {
                                         for factor in &term.factors {
@@ -891,6 +1159,84 @@ impl DataStatementExecutor for MatchSetExecutor {
                     }
                 }
             }
+
+            // Apply REMOVE items last, so SET expressions above still saw the
+            // values they remove (`SET n.full_name = n.name REMOVE n.name`)
+            for item in &self.statement.remove_items {
+                match item {
+                    RemoveItem::Property(property) => {
+                        if let Some(matched_node) = combination.get(&property.object) {
+                            let removed = graph
+                                .get_node_mut(&matched_node.id)
+                                .and_then(|mut node_mut| {
+                                    node_mut.remove_property(&property.property)
+                                })
+                                .is_some();
+                            if removed {
+                                updated_count += 1;
+                                Self::record_node_undo(
+                                    &mut undo_operations,
+                                    &node_old_states,
+                                    &graph_name,
+                                    &matched_node.id,
+                                );
+                            }
+                        } else if let Some(matched_edge) = edge_combination.get(&property.object) {
+                            let removed = graph
+                                .get_edge_mut(&matched_edge.id)
+                                .and_then(|edge_mut| edge_mut.remove_property(&property.property))
+                                .is_some();
+                            if removed {
+                                updated_count += 1;
+                                // Edge rollback restores the whole property map, so
+                                // use the edge as matched, before this statement
+                                undo_operations.push(UndoOperation::UpdateEdge {
+                                    graph_path: graph_name.clone(),
+                                    edge_id: matched_edge.id.clone(),
+                                    old_properties: matched_edge.properties.clone(),
+                                    old_label: matched_edge.label.clone(),
+                                });
+                            }
+                        } else {
+                            log::warn!(
+                                "MATCH-SET: REMOVE {}.{} did not match any variables",
+                                property.object,
+                                property.property
+                            );
+                        }
+                    }
+                    RemoveItem::Label { variable, labels } => {
+                        let Some(matched_node) = combination.get(variable) else {
+                            continue;
+                        };
+                        if let Some(mut node_mut) = graph.get_node_mut(&matched_node.id) {
+                            let original_len = node_mut.labels.len();
+                            for term in &labels.terms {
+                                for factor in &term.factors {
+                                    if let LabelFactor::Identifier(label_name) = factor {
+                                        node_mut.labels.retain(|l| l != label_name);
+                                    }
+                                }
+                            }
+                            if node_mut.labels.len() < original_len {
+                                updated_count += 1;
+                                Self::record_node_undo(
+                                    &mut undo_operations,
+                                    &node_old_states,
+                                    &graph_name,
+                                    &matched_node.id,
+                                );
+                            }
+                        }
+                    }
+                    RemoveItem::Variable(variable) => {
+                        log::warn!(
+                            "Variable removal in MATCH SET ... REMOVE not supported: {}",
+                            variable
+                        );
+                    }
+                }
+            }
         }
 
         // Return all undo operations as a batch for transactional rollback
@@ -998,7 +1344,9 @@ mod tests {
         let pattern = crate::ast::Node {
             identifier: None,
             labels: vec![],
+            label_expression: None,
             properties: None,
+            predicate: None,
             location: dummy_location(),
         };
 
@@ -1017,7 +1365,9 @@ mod tests {
         let pattern = crate::ast::Node {
             identifier: None,
             labels: vec!["Person".to_string()],
+            label_expression: None,
             properties: None,
+            predicate: None,
             location: dummy_location(),
         };
 
@@ -1036,7 +1386,9 @@ mod tests {
         let pattern = crate::ast::Node {
             identifier: None,
             labels: vec!["Company".to_string()],
+            label_expression: None,
             properties: None,
+            predicate: None,
             location: dummy_location(),
         };
 
@@ -1058,6 +1410,7 @@ mod tests {
         let pattern = crate::ast::Node {
             identifier: None,
             labels: vec!["Person".to_string()],
+            label_expression: None,
             properties: Some(PropertyMap {
                 properties: vec![Property {
                     key: "name".to_string(),
@@ -1066,6 +1419,7 @@ mod tests {
                 }],
                 location: dummy_location(),
             }),
+            predicate: None,
             location: dummy_location(),
         };
 
@@ -1087,6 +1441,7 @@ mod tests {
         let pattern = crate::ast::Node {
             identifier: None,
             labels: vec!["Person".to_string()],
+            label_expression: None,
             properties: Some(PropertyMap {
                 properties: vec![Property {
                     key: "name".to_string(),
@@ -1095,6 +1450,7 @@ mod tests {
                 }],
                 location: dummy_location(),
             }),
+            predicate: None,
             location: dummy_location(),
         };
 
@@ -7,6 +7,7 @@
 pub mod coordinator;
 pub mod data_statement_base;
 pub mod delete;
+pub mod edge_delete_policy;
 pub mod insert;
 pub mod match_delete;
 pub mod match_insert;
@@ -1,13 +1,15 @@
 // Copyright (c) 2024-2025 DeepGraph Inc.
 // SPDX-License-Identifier: Apache-2.0
 //
-use std::collections::HashMap;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{HashMap, HashSet};
+use std::hash::{Hash, Hasher};
+use std::sync::Arc;
 
-use crate::ast::InsertStatement;
+use crate::ast::{ConflictAction, Expression, InsertStatement, Property};
 use crate::exec::write_engine::operations::DataStatementExecutor;
 use crate::exec::write_stmt::{ExecutionContext, StatementExecutor};
 use crate::exec::ExecutionError;
-use crate::plan::insert_planner::InsertPlanner;
 use crate::plan::physical::PhysicalPlan;
 use crate::storage::GraphCache;
 use crate::txn::UndoOperation;
@@ -15,12 +17,16 @@ use crate::txn::UndoOperation;
 /// Executor for INSERT statements using planned execution
 pub struct PlannedInsertExecutor {
     statement: InsertStatement,
+    physical_plan: Arc<PhysicalPlan>,
 }
 
 impl PlannedInsertExecutor {
-    /// Create a new PlannedInsertExecutor
-    pub fn new(statement: InsertStatement) -> Self {
-        Self { statement }
+    /// Create a new PlannedInsertExecutor running the statement's physical plan
+    pub fn new(statement: InsertStatement, physical_plan: Arc<PhysicalPlan>) -> Self {
+        Self {
+            statement,
+            physical_plan,
+        }
     }
 
     /// Update text indexes for a newly inserted node (automatic indexing)
@@ -65,6 +71,60 @@ impl PlannedInsertExecutor {
     }
 }
 
+impl PlannedInsertExecutor {
+    /// Whether any property is a `$parameter`
+    ///
+    /// Planned storage IDs hash the property expressions, so every binding of
+    /// a parameter would get the same ID; these are re-hashed from the values.
+    fn has_parameters(properties: &HashMap<String, Expression>) -> bool {
+        properties
+            .values()
+            .any(|expr| matches!(expr, Expression::Parameter(_)))
+    }
+
+    /// Content-based storage ID computed from evaluated property values
+    pub(crate) fn content_id(
+        prefix: &str,
+        keys: &[String],
+        properties: &HashMap<String, crate::storage::Value>,
+    ) -> String {
+        let mut hasher = DefaultHasher::new();
+        keys.hash(&mut hasher);
+        let mut sorted_properties: Vec<_> = properties.iter().collect();
+        sorted_properties.sort_by_key(|(k, _)| *k);
+        for (key, value) in sorted_properties {
+            key.hash(&mut hasher);
+            match value {
+                // Value's Hash sorts map entries, so key order never changes the ID
+                crate::storage::Value::Map(_) => value.hash(&mut hasher),
+                _ => format!("{:?}", value).hash(&mut hasher),
+            }
+        }
+        format!("{}_{:x}", prefix, hasher.finish())
+    }
+
+    /// Evaluate the assignments of an ON CONFLICT DO UPDATE clause
+    fn evaluate_conflict_updates(
+        assignments: &[Property],
+        context: &ExecutionContext,
+    ) -> Result<Vec<(String, crate::storage::Value)>, ExecutionError> {
+        assignments
+            .iter()
+            .map(|assignment| {
+                context
+                    .evaluate_simple_expression(&assignment.value)
+                    .map(|value| (assignment.key.clone(), value))
+                    .map_err(|e| {
+                        ExecutionError::ExpressionError(format!(
+                            "Failed to evaluate ON CONFLICT update of '{}': {}",
+                            assignment.key, e
+                        ))
+                    })
+            })
+            .collect()
+    }
+}
+
 impl StatementExecutor for PlannedInsertExecutor {
     fn operation_type(&self) -> crate::txn::state::OperationType {
         crate::txn::state::OperationType::Insert
@@ -105,30 +165,24 @@ impl DataStatementExecutor for PlannedInsertExecutor {
         graph: &mut GraphCache,
         context: &mut ExecutionContext,
     ) -> Result<(UndoOperation, usize), ExecutionError> {
-        // Step 1: Logical planning
-        let mut logical_planner = InsertPlanner::new();
-        let logical_plan = logical_planner
-            .plan_insert(&self.statement)
-            .map_err(|e| ExecutionError::RuntimeError(format!("Logical planning error: {}", e)))?;
-
-        log::debug!(
-            "Logical plan created with {} variables",
-            logical_plan.variables.len()
-        );
-
-        // Step 2: Physical planning
-        let physical_plan = PhysicalPlan::from_logical(&logical_plan);
-
-        log::debug!("Physical plan created");
-
-        // Step 3: Execute physical plan
+        // Execute the physical plan
         let mut rows_affected = 0usize;
         let mut undo_operations = Vec::new();
 
         // Get the graph path for undo operations
         let graph_path = context.get_graph_name().unwrap_or_else(|_| String::new()); // Fall back to empty string if no graph context
 
-        match &physical_plan.root {
+        // Duplicates of existing nodes get a unique ID instead of being skipped;
+        // edges of this statement follow their endpoints to the new IDs
+        let allow_duplicate_nodes =
+            self.statement.on_conflict.is_none() && context.allow_duplicate_nodes();
+        // Planned node IDs mapped to the IDs actually stored
+        let mut renamed_node_ids: HashMap<String, String> = HashMap::new();
+        // Elements this statement stored; a pattern repeating one refers to it
+        // rather than conflicting with it
+        let mut inserted_ids: HashSet<String> = HashSet::new();
+
+        match &self.physical_plan.root {
             crate::plan::physical::PhysicalNode::Insert {
                 node_creations,
                 edge_creations,
@@ -150,14 +204,26 @@ impl DataStatementExecutor for PlannedInsertExecutor {
                         }
                     }
 
+                    let mut node_id = node_creation.storage_id.clone();
+                    if Self::has_parameters(&node_creation.properties) {
+                        let mut labels = node_creation.labels.clone();
+                        labels.sort();
+                        node_id = Self::content_id("node", &labels, &properties);
+                    }
+                    if allow_duplicate_nodes && graph.contains_node(&node_id) {
+                        node_id = format!("{}_{}", node_id, uuid::Uuid::new_v4().simple());
+                    }
+                    if node_id != node_creation.storage_id {
+                        renamed_node_ids.insert(node_creation.storage_id.clone(), node_id.clone());
+                    }
+
                     let node = crate::storage::Node {
-                        id: node_creation.storage_id.clone(),
+                        id: node_id.clone(),
                         labels: node_creation.labels.clone(),
                         properties,
                     };
 
                     // Add node to graph
-                    let node_id = node_creation.storage_id.clone();
                     let node_labels = node_creation.labels.clone();
                     let node_props = node.properties.clone();
 
@@ -165,6 +231,7 @@ impl DataStatementExecutor for PlannedInsertExecutor {
                         Ok(_) => {
                             log::debug!("Successfully added node '{}' to graph", node_id);
                             rows_affected += 1;
+                            inserted_ids.insert(node_id.clone());
 
                             // AUTOMATIC INDEXING: Update text indexes for this node
                             Self::update_text_indexes_for_node(
@@ -180,19 +247,44 @@ impl DataStatementExecutor for PlannedInsertExecutor {
                                 node_id,
                             });
                         }
+                        Err(crate::storage::types::GraphError::NodeAlreadyExists(_))
+                            if inserted_ids.contains(&node_id) => {}
                         Err(crate::storage::types::GraphError::NodeAlreadyExists(_)) => {
-                            log::info!(
-                                "Node '{}' already exists, skipping duplicate",
-                                node_creation.storage_id
-                            );
-                            // Add warning about duplicate insertion
-                            let warning_msg = format!("Duplicate node detected: Node with identical properties already exists (node_id: {})", node_creation.storage_id);
-                            context.add_warning(warning_msg);
+                            match &self.statement.on_conflict {
+                                None => {
+                                    return Err(ExecutionError::ConstraintViolation(format!(
+                                        "Node '{}' already exists; add ON CONFLICT DO NOTHING or ON CONFLICT DO UPDATE to the INSERT to handle duplicates",
+                                        node_id
+                                    )));
+                                }
+                                Some(ConflictAction::DoNothing) => {
+                                    log::debug!(
+                                        "Node '{}' already exists, ON CONFLICT DO NOTHING",
+                                        node_id
+                                    );
+                                }
+                                Some(ConflictAction::DoUpdate(assignments)) => {
+                                    let updates =
+                                        Self::evaluate_conflict_updates(assignments, context)?;
+                                    if let Some(mut existing) = graph.get_node_mut(&node_id) {
+                                        undo_operations.push(UndoOperation::UpdateNode {
+                                            graph_path: graph_path.clone(),
+                                            node_id: node_id.clone(),
+                                            old_properties: existing.properties.clone(),
+                                            old_labels: existing.labels.clone(),
+                                        });
+                                        for (key, value) in updates {
+                                            existing.set_property(key, value);
+                                        }
+                                        rows_affected += 1;
+                                    }
+                                }
+                            }
                         }
                         Err(e) => {
                             return Err(ExecutionError::RuntimeError(format!(
                                 "Failed to add node '{}': {}",
-                                node_creation.storage_id, e
+                                node_id, e
                             )));
                         }
                     }
@@ -214,10 +306,37 @@ impl DataStatementExecutor for PlannedInsertExecutor {
                         }
                     }
 
+                    let from_node = renamed_node_ids
+                        .get(&edge_creation.from_node_id)
+                        .unwrap_or(&edge_creation.from_node_id)
+                        .clone();
+                    let to_node = renamed_node_ids
+                        .get(&edge_creation.to_node_id)
+                        .unwrap_or(&edge_creation.to_node_id)
+                        .clone();
+                    // Edges follow their endpoints, so an edge to a renamed node gets
+                    // an ID of its own
+                    let edge_id = if from_node != edge_creation.from_node_id
+                        || to_node != edge_creation.to_node_id
+                        || Self::has_parameters(&edge_creation.properties)
+                    {
+                        Self::content_id(
+                            "edge",
+                            &[
+                                from_node.clone(),
+                                to_node.clone(),
+                                edge_creation.label.clone(),
+                            ],
+                            &properties,
+                        )
+                    } else {
+                        edge_creation.storage_id.clone()
+                    };
+
                     let edge = crate::storage::Edge {
-                        id: edge_creation.storage_id.clone(),
-                        from_node: edge_creation.from_node_id.clone(),
-                        to_node: edge_creation.to_node_id.clone(),
+                        id: edge_id.clone(),
+                        from_node,
+                        to_node,
                         label: edge_creation.label.clone(),
                         properties,
                     };
@@ -225,28 +344,54 @@ impl DataStatementExecutor for PlannedInsertExecutor {
                     // Add edge to graph
                     match graph.add_edge(edge) {
                         Ok(_) => {
-                            log::debug!(
-                                "Successfully added edge '{}' to graph",
-                                edge_creation.storage_id
-                            );
+                            log::debug!("Successfully added edge '{}' to graph", edge_id);
                             rows_affected += 1;
+                            inserted_ids.insert(edge_id.clone());
 
                             // Add undo operation for transaction management
                             undo_operations.push(UndoOperation::InsertEdge {
                                 graph_path: graph_path.clone(),
-                                edge_id: edge_creation.storage_id.clone(),
+                                edge_id,
                             });
                         }
+                        Err(crate::storage::types::GraphError::EdgeAlreadyExists(_))
+                            if inserted_ids.contains(&edge_id) => {}
                         Err(crate::storage::types::GraphError::EdgeAlreadyExists(_)) => {
-                            log::info!(
-                                "Edge '{}' already exists, skipping duplicate",
-                                edge_creation.storage_id
-                            );
+                            match &self.statement.on_conflict {
+                                None => {
+                                    return Err(ExecutionError::ConstraintViolation(format!(
+                                    "Edge '{}' already exists; add ON CONFLICT DO NOTHING or ON CONFLICT DO UPDATE to the INSERT to handle duplicates",
+                                    edge_id
+                                )));
+                                }
+                                Some(ConflictAction::DoNothing) => {
+                                    log::debug!(
+                                        "Edge '{}' already exists, ON CONFLICT DO NOTHING",
+                                        edge_id
+                                    );
+                                }
+                                Some(ConflictAction::DoUpdate(assignments)) => {
+                                    let updates =
+                                        Self::evaluate_conflict_updates(assignments, context)?;
+                                    if let Some(existing) = graph.get_edge_mut(&edge_id) {
+                                        undo_operations.push(UndoOperation::UpdateEdge {
+                                            graph_path: graph_path.clone(),
+                                            edge_id: edge_id.clone(),
+                                            old_properties: existing.properties.clone(),
+                                            old_label: existing.label.clone(),
+                                        });
+                                        for (key, value) in updates {
+                                            existing.set_property(key, value);
+                                        }
+                                        rows_affected += 1;
+                                    }
+                                }
+                            }
                         }
                         Err(e) => {
                             return Err(ExecutionError::RuntimeError(format!(
                                 "Failed to add edge '{}': {}",
-                                edge_creation.storage_id, e
+                                edge_id, e
                             )));
                         }
                     }
@@ -272,9 +417,13 @@ impl DataStatementExecutor for PlannedInsertExecutor {
                 graph_path: graph_path.clone(),
                 node_id: "dummy".to_string(),
             }
-        } else {
-            // Return the first operation (the framework handles multiple operations via transaction logs)
+        } else if undo_operations.len() == 1 {
             undo_operations.into_iter().next().unwrap()
+        } else {
+            // Multiple operations - return as batch so rollback undoes all of them
+            UndoOperation::Batch {
+                operations: undo_operations,
+            }
         };
 
         Ok((composite_undo, rows_affected))
@@ -81,7 +81,7 @@ impl DataStatementExecutor for RemoveExecutor {
 
                         if has_property {
                             // Remove the property
-                            if let Some(node_mut) = graph.get_node_mut(&node_id) {
+                            if let Some(mut node_mut) = graph.get_node_mut(&node_id) {
                                 node_mut.remove_property(&property_access.property);
                                 log::debug!(
                                     "Removed property {} from node {}",
@@ -124,7 +124,7 @@ impl DataStatementExecutor for RemoveExecutor {
                         for term in &labels.terms {
                             for factor in &term.factors {
                                 if let LabelFactor::Identifier(label_name) = factor {
-                                    if let Some(node_mut) = graph.get_node_mut(&node_id) {
+                                    if let Some(mut node_mut) = graph.get_node_mut(&node_id) {
                                         let original_len = node_mut.labels.len();
                                         node_mut.labels.retain(|l| l != label_name);
                                         if node_mut.labels.len() < original_len {
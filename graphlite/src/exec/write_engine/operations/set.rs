@@ -83,7 +83,7 @@ impl DataStatementExecutor for SetExecutor {
                 };
 
                 // Update the node
-                if let Some(node_mut) = graph.get_node_mut(&node_id) {
+                if let Some(mut node_mut) = graph.get_node_mut(&node_id) {
                     node_mut.set_property(property.property.clone(), new_value.clone());
                     log::debug!(
                         "Set property {} on node {} to {:?}",
@@ -117,6 +117,13 @@ impl DataStatementExecutor for SetExecutor {
                         value
                     );
                 }
+                SetItem::MergeProperties { variable, value } => {
+                    log::warn!(
+                        "Property merge in SET not yet fully supported: {} += {:?}",
+                        variable,
+                        value
+                    );
+                }
                 SetItem::Label { variable, labels } => {
                     log::warn!(
                         "Label assignment in SET not yet fully supported: {} {:?}",
@@ -9,8 +9,12 @@
 //! - SUM: Calculates sum of numeric values
 //! - MIN: Finds minimum value
 //! - MAX: Finds maximum value
+//! - COLLECT: Collects values into a list
+//! - APPROX_COUNT_DISTINCT: Estimates distinct values with a HyperLogLog sketch
+//! - FIRST / LAST: First or last non-null value in the group's row order
 
 use super::function_trait::{Function, FunctionContext, FunctionError, FunctionResult};
+use super::hyperloglog::HyperLogLog;
 use crate::storage::Value;
 
 // ==============================================================================
@@ -395,8 +399,10 @@ impl Function for CollectFunction {
 
         for row in &context.rows {
             if let Some(value) = row.values.get(column_name) {
-                // Include all values, including nulls, to match ISO GQL behavior
-                collected_values.push(value.clone());
+                // Nulls are skipped, as with the other aggregates
+                if !value.is_null() {
+                    collected_values.push(value.clone());
+                }
             }
         }
 
@@ -408,3 +414,153 @@ impl Function for CollectFunction {
         "List"
     }
 }
+
+// ==============================================================================
+// FIRST / LAST FUNCTIONS
+// ==============================================================================
+
+/// FIRST function - first non-null value in the order rows reach the aggregate
+///
+/// Without an ORDER BY inside the call, e.g. FIRST(x ORDER BY y), that is the
+/// order the rows were matched in.
+#[derive(Debug)]
+pub struct FirstFunction;
+
+impl FirstFunction {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Function for FirstFunction {
+    fn name(&self) -> &str {
+        "FIRST"
+    }
+
+    fn description(&self) -> &str {
+        "Returns the first non-null value of a column in the group"
+    }
+
+    fn argument_count(&self) -> usize {
+        1 // FIRST(column)
+    }
+
+    fn execute(&self, context: &FunctionContext) -> FunctionResult<Value> {
+        let column_name = context.get_argument(0)?.as_string().ok_or_else(|| {
+            FunctionError::InvalidArgumentType {
+                message: "FIRST argument must be a string column name".to_string(),
+            }
+        })?;
+
+        Ok(context
+            .rows
+            .iter()
+            .filter_map(|row| row.values.get(column_name))
+            .find(|value| !value.is_null())
+            .cloned()
+            .unwrap_or(Value::Null))
+    }
+
+    fn return_type(&self) -> &str {
+        "Any"
+    }
+}
+
+/// LAST function - last non-null value in the order rows reach the aggregate
+#[derive(Debug)]
+pub struct LastFunction;
+
+impl LastFunction {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Function for LastFunction {
+    fn name(&self) -> &str {
+        "LAST"
+    }
+
+    fn description(&self) -> &str {
+        "Returns the last non-null value of a column in the group"
+    }
+
+    fn argument_count(&self) -> usize {
+        1 // LAST(column)
+    }
+
+    fn execute(&self, context: &FunctionContext) -> FunctionResult<Value> {
+        let column_name = context.get_argument(0)?.as_string().ok_or_else(|| {
+            FunctionError::InvalidArgumentType {
+                message: "LAST argument must be a string column name".to_string(),
+            }
+        })?;
+
+        Ok(context
+            .rows
+            .iter()
+            .rev()
+            .filter_map(|row| row.values.get(column_name))
+            .find(|value| !value.is_null())
+            .cloned()
+            .unwrap_or(Value::Null))
+    }
+
+    fn return_type(&self) -> &str {
+        "Any"
+    }
+}
+
+// ==============================================================================
+// APPROX_COUNT_DISTINCT FUNCTION
+// ==============================================================================
+
+/// APPROX_COUNT_DISTINCT function - estimates distinct non-null values
+///
+/// Uses a fixed 4 KiB HyperLogLog sketch instead of remembering every value,
+/// so the result is approximate: the standard error is about 1.6%.
+#[derive(Debug)]
+pub struct ApproxCountDistinctFunction;
+
+impl ApproxCountDistinctFunction {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Function for ApproxCountDistinctFunction {
+    fn name(&self) -> &str {
+        "APPROX_COUNT_DISTINCT"
+    }
+
+    fn description(&self) -> &str {
+        "Estimates the number of distinct non-null values in a column (about 1.6% standard error)"
+    }
+
+    fn argument_count(&self) -> usize {
+        1 // APPROX_COUNT_DISTINCT(column)
+    }
+
+    fn execute(&self, context: &FunctionContext) -> FunctionResult<Value> {
+        let column_name = context.get_argument(0)?.as_string().ok_or_else(|| {
+            FunctionError::InvalidArgumentType {
+                message: "APPROX_COUNT_DISTINCT argument must be a string column name".to_string(),
+            }
+        })?;
+
+        // Values are told apart the same way as for COUNT(DISTINCT ...)
+        let mut sketch = HyperLogLog::new();
+        for row in &context.rows {
+            if let Some(value) = row.values.get(column_name) {
+                if !value.is_null() {
+                    sketch.insert(value);
+                }
+            }
+        }
+        Ok(Value::Number(sketch.estimate() as f64))
+    }
+
+    fn return_type(&self) -> &str {
+        "Number"
+    }
+}
@@ -0,0 +1,227 @@
+// Copyright (c) 2024-2025 DeepGraph Inc.
+// SPDX-License-Identifier: Apache-2.0
+//
+//! Comparison function implementations
+//!
+//! This module contains functions that pick one of their arguments by order:
+//! - GREATEST: Returns the largest non-NULL argument
+//! - LEAST: Returns the smallest non-NULL argument
+
+use super::function_trait::{Function, FunctionContext, FunctionError, FunctionResult};
+use crate::exec::streaming_topk::compare_numbers;
+use crate::storage::Value;
+use std::cmp::Ordering;
+
+/// Pick the argument that orders as `wanted` against all others, skipping NULLs
+///
+/// Numbers, strings, booleans, datetimes and durations compare with values of
+/// their own type; mixing types is an error. All-NULL arguments give NULL.
+fn pick_extreme(
+    function_name: &str,
+    context: &FunctionContext,
+    wanted: Ordering,
+) -> FunctionResult<Value> {
+    if context.argument_count() == 0 {
+        return Err(FunctionError::InvalidArgumentCount {
+            expected: 1,
+            actual: 0,
+        });
+    }
+
+    let mut extreme: Option<&Value> = None;
+    for value in &context.arguments {
+        if value.is_null() {
+            continue;
+        }
+        let current = match extreme {
+            Some(current) => current,
+            None => {
+                extreme = Some(value);
+                continue;
+            }
+        };
+
+        let ordering = match (value, current) {
+            (Value::Number(a), Value::Number(b)) => compare_numbers(*a, *b),
+            (Value::String(a), Value::String(b)) => a.cmp(b),
+            (Value::Boolean(a), Value::Boolean(b)) => a.cmp(b),
+            (Value::DateTime(a), Value::DateTime(b)) => a.cmp(b),
+            (Value::Duration(a), Value::Duration(b)) => a.cmp(b),
+            _ => {
+                return Err(FunctionError::InvalidArgumentType {
+                    message: format!(
+                        "{} cannot compare {} with {}",
+                        function_name,
+                        current.type_name(),
+                        value.type_name()
+                    ),
+                })
+            }
+        };
+        if ordering == wanted {
+            extreme = Some(value);
+        }
+    }
+
+    Ok(extreme.cloned().unwrap_or(Value::Null))
+}
+
+// ==============================================================================
+// GREATEST FUNCTION
+// ==============================================================================
+
+/// GREATEST function - returns the largest of its non-NULL arguments
+#[derive(Debug)]
+pub struct GreatestFunction;
+
+impl GreatestFunction {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Function for GreatestFunction {
+    fn name(&self) -> &str {
+        "GREATEST"
+    }
+
+    fn description(&self) -> &str {
+        "Returns the largest non-NULL argument"
+    }
+
+    fn argument_count(&self) -> usize {
+        0 // GREATEST accepts any number of arguments, but at least 1
+    }
+
+    fn execute(&self, context: &FunctionContext) -> FunctionResult<Value> {
+        pick_extreme("GREATEST", context, Ordering::Greater)
+    }
+
+    fn return_type(&self) -> &str {
+        "Any" // Returns the type of its arguments or NULL
+    }
+
+    fn graph_context_required(&self) -> bool {
+        false
+    }
+
+    fn is_variadic(&self) -> bool {
+        true
+    }
+}
+
+// ==============================================================================
+// LEAST FUNCTION
+// ==============================================================================
+
+/// LEAST function - returns the smallest of its non-NULL arguments
+#[derive(Debug)]
+pub struct LeastFunction;
+
+impl LeastFunction {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Function for LeastFunction {
+    fn name(&self) -> &str {
+        "LEAST"
+    }
+
+    fn description(&self) -> &str {
+        "Returns the smallest non-NULL argument"
+    }
+
+    fn argument_count(&self) -> usize {
+        0 // LEAST accepts any number of arguments, but at least 1
+    }
+
+    fn execute(&self, context: &FunctionContext) -> FunctionResult<Value> {
+        pick_extreme("LEAST", context, Ordering::Less)
+    }
+
+    fn return_type(&self) -> &str {
+        "Any" // Returns the type of its arguments or NULL
+    }
+
+    fn graph_context_required(&self) -> bool {
+        false
+    }
+
+    fn is_variadic(&self) -> bool {
+        true
+    }
+}
+
+// ==============================================================================
+// TESTS
+// ==============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn run(func: &dyn Function, arguments: Vec<Value>) -> FunctionResult<Value> {
+        func.execute(&FunctionContext::new(vec![], HashMap::new(), arguments))
+    }
+
+    #[test]
+    fn test_greatest_and_least_numbers() {
+        let numbers = vec![Value::Number(3.0), Value::Number(7.0), Value::Number(2.0)];
+        assert_eq!(
+            run(&GreatestFunction::new(), numbers.clone()).unwrap(),
+            Value::Number(7.0)
+        );
+        assert_eq!(
+            run(&LeastFunction::new(), numbers).unwrap(),
+            Value::Number(2.0)
+        );
+    }
+
+    #[test]
+    fn test_greatest_and_least_skip_nulls() {
+        let values = vec![
+            Value::Null,
+            Value::Number(3.0),
+            Value::Null,
+            Value::Number(1.0),
+        ];
+        assert_eq!(
+            run(&GreatestFunction::new(), values.clone()).unwrap(),
+            Value::Number(3.0)
+        );
+        assert_eq!(
+            run(&LeastFunction::new(), values).unwrap(),
+            Value::Number(1.0)
+        );
+        assert!(
+            run(&GreatestFunction::new(), vec![Value::Null, Value::Null])
+                .unwrap()
+                .is_null()
+        );
+    }
+
+    #[test]
+    fn test_greatest_strings() {
+        let values = vec![
+            Value::String("pear".to_string()),
+            Value::String("apple".to_string()),
+        ];
+        assert_eq!(
+            run(&GreatestFunction::new(), values).unwrap(),
+            Value::String("pear".to_string())
+        );
+    }
+
+    #[test]
+    fn test_mixed_types_rejected() {
+        let values = vec![Value::Number(1.0), Value::String("1".to_string())];
+        assert!(matches!(
+            run(&LeastFunction::new(), values),
+            Err(FunctionError::InvalidArgumentType { .. })
+        ));
+        assert!(run(&LeastFunction::new(), vec![]).is_err());
+    }
+}
@@ -6,6 +6,8 @@
 //! This module defines the core Function trait that all functions must implement.
 //! Functions can be anything - aggregate, scalar, or any other type.
 
+use super::regex_cache::RegexCache;
+use super::timezone_functions::TimezoneType;
 use crate::exec::result::Row;
 use crate::storage::Value;
 use std::collections::HashMap;
@@ -44,6 +46,10 @@ pub struct FunctionContext {
     pub current_graph: Option<Arc<crate::storage::GraphCache>>,
     /// Optional graph name
     pub graph_name: Option<String>,
+    /// Optional per-execution cache of compiled regexes
+    pub regex_cache: Option<RegexCache>,
+    /// Session time zone for datetime extraction; UTC when unset
+    pub time_zone: Option<TimezoneType>,
 }
 
 impl FunctionContext {
@@ -56,6 +62,8 @@ impl FunctionContext {
             storage_manager: None,
             current_graph: None,
             graph_name: None,
+            regex_cache: None,
+            time_zone: None,
         }
     }
 
@@ -75,9 +83,34 @@ impl FunctionContext {
             storage_manager,
             current_graph,
             graph_name,
+            regex_cache: None,
+            time_zone: None,
         }
     }
 
+    /// Share a regex cache with the function so patterns are compiled once per query
+    pub fn with_regex_cache(mut self, regex_cache: RegexCache) -> Self {
+        self.regex_cache = Some(regex_cache);
+        self
+    }
+
+    /// Evaluate datetime components in the session's time zone
+    pub fn with_time_zone(mut self, time_zone: Option<TimezoneType>) -> Self {
+        self.time_zone = time_zone;
+        self
+    }
+
+    /// Compile a regex pattern, reusing the shared cache when one is available
+    pub fn compile_regex(&self, pattern: &str) -> FunctionResult<Arc<regex::Regex>> {
+        let compiled = match &self.regex_cache {
+            Some(cache) => cache.get_or_compile(pattern),
+            None => regex::Regex::new(pattern).map(Arc::new),
+        };
+        compiled.map_err(|e| FunctionError::InvalidArgumentType {
+            message: format!("Invalid regex pattern '{}': {}", pattern, e),
+        })
+    }
+
     /// Get a specific argument by index
     pub fn get_argument(&self, index: usize) -> FunctionResult<&Value> {
         self.arguments
@@ -6,7 +6,25 @@
 //! This module implements standard GQL graph functions like LABELS, TYPE, ID, PROPERTIES
 
 use super::function_trait::{Function, FunctionContext, FunctionError, FunctionResult};
-use crate::storage::Value;
+use crate::storage::value::{PathElement, PathValue};
+use crate::storage::{GraphCache, Value};
+use std::cmp::{Ordering, Reverse};
+use std::collections::{BinaryHeap, HashMap, HashSet};
+use std::sync::Arc;
+
+/// The graph a function reads from: the executing graph, else the session graph
+fn function_graph(context: &FunctionContext) -> Option<Arc<GraphCache>> {
+    if let Some(graph) = &context.current_graph {
+        return Some(graph.clone());
+    }
+    match (&context.storage_manager, &context.graph_name) {
+        (Some(storage_manager), Some(graph_name)) => match storage_manager.get_graph(graph_name) {
+            Ok(Some(graph)) => Some(Arc::new(graph)),
+            _ => None,
+        },
+        _ => None,
+    }
+}
 
 /// LABELS function - returns list of node labels
 #[derive(Debug)]
@@ -219,8 +237,10 @@ impl Function for TypeFunction {
             Value::DateTimeWithFixedOffset(_) => Ok(Value::String("DATETIME".to_string())),
             Value::DateTimeWithNamedTz(_, _) => Ok(Value::String("DATETIME".to_string())),
             Value::TimeWindow(_) => Ok(Value::String("TIMEWINDOW".to_string())),
+            Value::Duration(_) => Ok(Value::String("DURATION".to_string())),
             Value::Path(_) => Ok(Value::String("PATH".to_string())),
             Value::Temporal(_) => Ok(Value::String("TEMPORAL".to_string())),
+            Value::Map(_) => Ok(Value::String("MAP".to_string())),
         }
     }
 }
@@ -588,7 +608,8 @@ impl Function for PropertiesFunction {
     }
 }
 
-/// SIZE function - returns the size/length of collections, vectors, or strings
+/// SIZE and LENGTH functions - return the number of characters in a string,
+/// elements in a list or vector, or entries in a map
 #[derive(Debug)]
 pub struct SizeFunction;
 
@@ -604,7 +625,7 @@ impl Function for SizeFunction {
     }
 
     fn description(&self) -> &str {
-        "Returns the size/length of a collection, vector, or string"
+        "Returns the number of characters in a string or entries in a list, vector, or map"
     }
 
     fn argument_count(&self) -> usize {
@@ -626,13 +647,421 @@ impl Function for SizeFunction {
         let value = &context.arguments[0];
 
         match value {
-            Value::String(s) => Ok(Value::Number(s.len() as f64)),
+            // Characters rather than bytes, so multi-byte text counts as written
+            Value::String(s) => Ok(Value::Number(s.chars().count() as f64)),
             Value::List(list) => Ok(Value::Number(list.len() as f64)),
             Value::Vector(vec) => Ok(Value::Number(vec.len() as f64)),
+            Value::Map(map) => Ok(Value::Number(map.len() as f64)),
             Value::Null => Ok(Value::Number(0.0)),
             _ => Err(FunctionError::InvalidArgumentType {
-                message: format!("Expected STRING, LIST, or VECTOR, got {:?}", value),
+                message: format!("Expected STRING, LIST, VECTOR, or MAP, got {:?}", value),
+            }),
+        }
+    }
+}
+
+/// Which incident edges a degree function counts
+#[derive(Debug, Clone, Copy)]
+pub enum DegreeDirection {
+    Incoming,
+    Outgoing,
+    Both,
+}
+
+/// DEGREE, IN_DEGREE and OUT_DEGREE functions - count a node's incident edges,
+/// optionally only those with a given label: DEGREE(n [, 'KNOWS'])
+#[derive(Debug)]
+pub struct DegreeFunction {
+    direction: DegreeDirection,
+}
+
+impl DegreeFunction {
+    pub fn new(direction: DegreeDirection) -> Self {
+        Self { direction }
+    }
+}
+
+impl Function for DegreeFunction {
+    fn name(&self) -> &str {
+        match self.direction {
+            DegreeDirection::Incoming => "IN_DEGREE",
+            DegreeDirection::Outgoing => "OUT_DEGREE",
+            DegreeDirection::Both => "DEGREE",
+        }
+    }
+
+    fn description(&self) -> &str {
+        match self.direction {
+            DegreeDirection::Incoming => "Returns the number of incoming edges of a node",
+            DegreeDirection::Outgoing => "Returns the number of outgoing edges of a node",
+            DegreeDirection::Both => "Returns the number of edges incident to a node",
+        }
+    }
+
+    fn argument_count(&self) -> usize {
+        1
+    }
+
+    fn return_type(&self) -> &str {
+        "NUMBER"
+    }
+
+    fn is_variadic(&self) -> bool {
+        true // Optional edge label argument
+    }
+
+    fn execute(&self, context: &FunctionContext) -> FunctionResult<Value> {
+        if context.arguments.is_empty() || context.arguments.len() > 2 {
+            return Err(FunctionError::InvalidArgumentCount {
+                expected: 1,
+                actual: context.arguments.len(),
+            });
+        }
+
+        let node_id = match &context.arguments[0] {
+            Value::Node(node) => node.id.clone(),
+            Value::String(id) => id.clone(),
+            Value::Null => return Ok(Value::Null),
+            other => {
+                return Err(FunctionError::InvalidArgumentType {
+                    message: format!("Expected NODE, got {:?}", other),
+                })
+            }
+        };
+
+        let edge_label = match context.arguments.get(1) {
+            None => None,
+            Some(Value::String(label)) => Some(label.as_str()),
+            Some(other) => {
+                return Err(FunctionError::InvalidArgumentType {
+                    message: format!("Expected edge label STRING, got {:?}", other),
+                })
+            }
+        };
+
+        let graph = match function_graph(context) {
+            Some(graph) => graph,
+            None => return Ok(Value::Null),
+        };
+
+        let edges = match self.direction {
+            DegreeDirection::Incoming => graph.get_incoming_edges(&node_id),
+            DegreeDirection::Outgoing => graph.get_outgoing_edges(&node_id),
+            DegreeDirection::Both => graph.get_connected_edges(&node_id),
+        };
+        let degree = edges
+            .iter()
+            .filter(|edge| edge_label.is_none_or(|label| edge.label == label))
+            .count();
+
+        Ok(Value::Number(degree as f64))
+    }
+}
+
+/// REACHABLE function - the distinct nodes reachable from a node by following
+/// outgoing edges with a given label for at most N hops:
+/// REACHABLE(n, 'KNOWS', 2). A NULL label follows edges of any label.
+///
+/// Nodes are listed in breadth-first order, nearest first, and the start node
+/// is never included, even when a cycle leads back to it.
+#[derive(Debug)]
+pub struct ReachableFunction;
+
+impl ReachableFunction {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Function for ReachableFunction {
+    fn name(&self) -> &str {
+        "REACHABLE"
+    }
+
+    fn description(&self) -> &str {
+        "Returns the nodes reachable from a node within a number of hops"
+    }
+
+    fn argument_count(&self) -> usize {
+        3
+    }
+
+    fn return_type(&self) -> &str {
+        "LIST"
+    }
+
+    fn execute(&self, context: &FunctionContext) -> FunctionResult<Value> {
+        if context.arguments.len() != 3 {
+            return Err(FunctionError::InvalidArgumentCount {
+                expected: 3,
+                actual: context.arguments.len(),
+            });
+        }
+
+        let start_id = match &context.arguments[0] {
+            Value::Node(node) => node.id.clone(),
+            Value::String(id) => id.clone(),
+            Value::Null => return Ok(Value::Null),
+            other => {
+                return Err(FunctionError::InvalidArgumentType {
+                    message: format!("Expected NODE, got {:?}", other),
+                })
+            }
+        };
+
+        let edge_label = match &context.arguments[1] {
+            Value::String(label) => Some(label.as_str()),
+            Value::Null => None,
+            other => {
+                return Err(FunctionError::InvalidArgumentType {
+                    message: format!("Expected edge label STRING, got {:?}", other),
+                })
+            }
+        };
+
+        let max_hops = match &context.arguments[2] {
+            Value::Number(n) if *n >= 0.0 && n.fract() == 0.0 => *n as usize,
+            other => {
+                return Err(FunctionError::InvalidArgumentType {
+                    message: format!("Expected a non-negative integer hop limit, got {:?}", other),
+                })
+            }
+        };
+
+        let graph = match function_graph(context) {
+            Some(graph) => graph,
+            None => return Ok(Value::Null),
+        };
+
+        let mut visited = HashSet::from([start_id.clone()]);
+        let mut frontier = vec![start_id];
+        let mut reachable = Vec::new();
+
+        for _ in 0..max_hops {
+            let mut next = Vec::new();
+            for node_id in std::mem::take(&mut frontier) {
+                for edge in graph.get_outgoing_edges(&node_id) {
+                    if edge_label.is_none_or(|label| edge.label == label)
+                        && visited.insert(edge.to_node.clone())
+                    {
+                        next.push(edge.to_node.clone());
+                    }
+                }
+            }
+            if next.is_empty() {
+                break;
+            }
+            // Keep each hop's nodes in a stable order
+            next.sort();
+            reachable.extend(
+                next.iter()
+                    .filter_map(|node_id| graph.get_node(node_id))
+                    .map(|node| Value::Node(node.clone())),
+            );
+            frontier = next;
+        }
+
+        Ok(Value::List(reachable))
+    }
+}
+
+/// SHORTEST_PATH function - the cheapest path along outgoing edges between two
+/// nodes: SHORTEST_PATH(a, b [, weight => 'distance'])
+///
+/// With a weight property every edge costs the value of that property, which
+/// must be a non-negative number; without one every edge costs 1. Returns a map
+/// with the `path` and its total `cost`, or NULL when `b` cannot be reached.
+#[derive(Debug)]
+pub struct ShortestPathFunction;
+
+impl ShortestPathFunction {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// The weight property, given as `weight => 'name'` or a plain string
+    fn weight_property(argument: Option<&Value>) -> FunctionResult<Option<String>> {
+        match argument {
+            None | Some(Value::Null) => Ok(None),
+            Some(Value::String(property)) => Ok(Some(property.clone())),
+            Some(Value::Map(options)) => {
+                if let Some(unknown) = options.keys().find(|key| key.as_str() != "weight") {
+                    return Err(FunctionError::InvalidArgumentType {
+                        message: format!("Unknown SHORTEST_PATH argument '{}'", unknown),
+                    });
+                }
+                match options.get("weight") {
+                    None | Some(Value::Null) => Ok(None),
+                    Some(Value::String(property)) => Ok(Some(property.clone())),
+                    Some(other) => Err(FunctionError::InvalidArgumentType {
+                        message: format!("Expected weight property STRING, got {:?}", other),
+                    }),
+                }
+            }
+            Some(other) => Err(FunctionError::InvalidArgumentType {
+                message: format!("Expected weight property STRING, got {:?}", other),
+            }),
+        }
+    }
+
+    fn node_id(argument: &Value) -> FunctionResult<Option<String>> {
+        match argument {
+            Value::Node(node) => Ok(Some(node.id.clone())),
+            Value::String(id) => Ok(Some(id.clone())),
+            Value::Null => Ok(None),
+            other => Err(FunctionError::InvalidArgumentType {
+                message: format!("Expected NODE, got {:?}", other),
             }),
         }
     }
 }
+
+/// A node waiting in Dijkstra's queue, ordered by cost
+#[derive(Debug, PartialEq)]
+struct QueuedNode {
+    cost: f64,
+    node_id: String,
+}
+
+impl Eq for QueuedNode {}
+
+impl Ord for QueuedNode {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.cost
+            .total_cmp(&other.cost)
+            .then_with(|| self.node_id.cmp(&other.node_id))
+    }
+}
+
+impl PartialOrd for QueuedNode {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Function for ShortestPathFunction {
+    fn name(&self) -> &str {
+        "SHORTEST_PATH"
+    }
+
+    fn description(&self) -> &str {
+        "Returns the lowest-cost path between two nodes and its cost"
+    }
+
+    fn argument_count(&self) -> usize {
+        2
+    }
+
+    fn return_type(&self) -> &str {
+        "MAP"
+    }
+
+    fn is_variadic(&self) -> bool {
+        true // Optional weight property
+    }
+
+    fn execute(&self, context: &FunctionContext) -> FunctionResult<Value> {
+        if context.arguments.len() < 2 || context.arguments.len() > 3 {
+            return Err(FunctionError::InvalidArgumentCount {
+                expected: 2,
+                actual: context.arguments.len(),
+            });
+        }
+
+        let (start_id, end_id) = match (
+            Self::node_id(&context.arguments[0])?,
+            Self::node_id(&context.arguments[1])?,
+        ) {
+            (Some(start_id), Some(end_id)) => (start_id, end_id),
+            _ => return Ok(Value::Null),
+        };
+        let weight_property = Self::weight_property(context.arguments.get(2))?;
+
+        let graph = match function_graph(context) {
+            Some(graph) => graph,
+            None => return Ok(Value::Null),
+        };
+
+        // Dijkstra: settle nodes cheapest first, remembering the edge each was reached by
+        let mut costs = HashMap::from([(start_id.clone(), 0.0)]);
+        let mut reached_by: HashMap<String, (String, String)> = HashMap::new();
+        let mut settled = HashSet::new();
+        let mut queue = BinaryHeap::from([Reverse(QueuedNode {
+            cost: 0.0,
+            node_id: start_id.clone(),
+        })]);
+
+        while let Some(Reverse(QueuedNode { cost, node_id })) = queue.pop() {
+            if node_id == end_id {
+                break;
+            }
+            if !settled.insert(node_id.clone()) {
+                continue;
+            }
+            for edge in graph.get_outgoing_edges(&node_id) {
+                let weight = match &weight_property {
+                    None => 1.0,
+                    Some(property) => match edge.properties.get(property) {
+                        Some(Value::Number(weight)) if *weight >= 0.0 => *weight,
+                        Some(Value::Number(weight)) => {
+                            return Err(FunctionError::ExecutionError {
+                                message: format!(
+                                    "SHORTEST_PATH cannot use negative weight {} on edge '{}'",
+                                    weight, edge.id
+                                ),
+                            })
+                        }
+                        other => {
+                            return Err(FunctionError::ExecutionError {
+                                message: format!(
+                                    "Edge '{}' has no numeric '{}' weight (found {:?})",
+                                    edge.id, property, other
+                                ),
+                            })
+                        }
+                    },
+                };
+                let next_cost = cost + weight;
+                if costs
+                    .get(&edge.to_node)
+                    .is_none_or(|&known| next_cost < known)
+                {
+                    costs.insert(edge.to_node.clone(), next_cost);
+                    reached_by.insert(edge.to_node.clone(), (node_id.clone(), edge.id.clone()));
+                    queue.push(Reverse(QueuedNode {
+                        cost: next_cost,
+                        node_id: edge.to_node.clone(),
+                    }));
+                }
+            }
+        }
+
+        let total_cost = match costs.get(&end_id) {
+            Some(&cost) => cost,
+            None => return Ok(Value::Null),
+        };
+
+        // Walk back from the end node to rebuild the path
+        let mut elements = vec![PathElement {
+            node_id: end_id.clone(),
+            edge_id: None,
+        }];
+        let mut current = end_id;
+        while let Some((previous, edge_id)) = reached_by.get(&current) {
+            elements.push(PathElement {
+                node_id: previous.clone(),
+                edge_id: Some(edge_id.clone()),
+            });
+            current = previous.clone();
+        }
+        elements.reverse();
+
+        Ok(Value::Map(HashMap::from([
+            (
+                "path".to_string(),
+                Value::Path(PathValue::from_elements(elements)),
+            ),
+            ("cost".to_string(), Value::Number(total_cost)),
+        ])))
+    }
+}
@@ -637,6 +637,49 @@ impl Function for PiFunction {
     }
 }
 
+// ==============================================================================
+// RAND FUNCTION
+// ==============================================================================
+
+/// RAND function - returns a random number in [0, 1)
+#[derive(Debug)]
+pub struct RandFunction;
+
+impl RandFunction {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Function for RandFunction {
+    fn name(&self) -> &str {
+        "RAND"
+    }
+
+    fn description(&self) -> &str {
+        "Returns a random number greater than or equal to 0 and less than 1"
+    }
+
+    fn argument_count(&self) -> usize {
+        0
+    }
+
+    fn return_type(&self) -> &str {
+        "Number"
+    }
+
+    fn graph_context_required(&self) -> bool {
+        false
+    }
+
+    fn execute(&self, context: &FunctionContext) -> FunctionResult<Value> {
+        context.validate_argument_count(0)?;
+        // The low 53 bits of a v4 UUID are all random
+        let bits = uuid::Uuid::new_v4().as_u128() as u64 & ((1 << 53) - 1);
+        Ok(Value::Number(bits as f64 / (1u64 << 53) as f64))
+    }
+}
+
 // ==============================================================================
 // SIGN FUNCTION
 // ==============================================================================
@@ -7,18 +7,24 @@
 //! functions by implementing the Function trait and registering them.
 
 mod aggregate_functions;
+mod comparison_functions;
 mod function_trait;
 mod graph_functions;
+mod hyperloglog;
 pub mod list_functions;
 mod mathematical_functions;
 mod null_functions;
 mod numeric_functions;
+mod regex_cache;
 mod special_functions;
 mod string_functions;
 mod temporal_functions;
 mod timezone_functions;
+mod vector_functions;
 
 pub use function_trait::{Function, FunctionContext};
+pub use regex_cache::RegexCache;
+pub use timezone_functions::{parse_timezone, TimezoneType};
 
 use std::collections::HashMap;
 
@@ -49,8 +55,20 @@ impl FunctionRegistry {
             "COLLECT",
             Box::new(aggregate_functions::CollectFunction::new()),
         );
+        registry.register(
+            "APPROX_COUNT_DISTINCT",
+            Box::new(aggregate_functions::ApproxCountDistinctFunction::new()),
+        );
+        registry.register("FIRST", Box::new(aggregate_functions::FirstFunction::new()));
+        registry.register("LAST", Box::new(aggregate_functions::LastFunction::new()));
+        registry.register(
+            "ARRAY_AGG",
+            Box::new(aggregate_functions::CollectFunction::new()),
+        ); // Alias; order comes from ARRAY_AGG(x ORDER BY y)
         registry.register("UPPER", Box::new(string_functions::UpperFunction::new()));
         registry.register("LOWER", Box::new(string_functions::LowerFunction::new()));
+        registry.register("TOUPPER", Box::new(string_functions::UpperFunction::new())); // Alias
+        registry.register("TOLOWER", Box::new(string_functions::LowerFunction::new())); // Alias
         registry.register("ROUND", Box::new(numeric_functions::RoundFunction::new()));
         registry.register("TRIM", Box::new(string_functions::TrimFunction::new()));
         registry.register(
@@ -65,6 +83,14 @@ impl FunctionRegistry {
             "REVERSE",
             Box::new(string_functions::ReverseFunction::new()),
         );
+        registry.register(
+            "FT_REGEX",
+            Box::new(string_functions::FtRegexFunction::new()),
+        );
+        registry.register(
+            "SIMILARITY_SCORE",
+            Box::new(string_functions::SimilarityScoreFunction::new()),
+        );
 
         // Register temporal functions
         registry.register(
@@ -98,6 +124,12 @@ impl FunctionRegistry {
             "EXTRACT",
             Box::new(temporal_functions::ExtractFunction::new()),
         );
+        for unit in ["YEAR", "MONTH", "DAY", "HOUR", "MINUTE", "SECOND"] {
+            registry.register(
+                unit,
+                Box::new(temporal_functions::DatePartFunction::new(unit)),
+            );
+        }
 
         // Register timezone functions
         registry.register(
@@ -191,6 +223,10 @@ impl FunctionRegistry {
         registry.register("COS", Box::new(mathematical_functions::CosFunction::new()));
         registry.register("TAN", Box::new(mathematical_functions::TanFunction::new()));
         registry.register("PI", Box::new(mathematical_functions::PiFunction::new()));
+        registry.register(
+            "RAND",
+            Box::new(mathematical_functions::RandFunction::new()),
+        );
         registry.register(
             "SIGN",
             Box::new(mathematical_functions::SignFunction::new()),
@@ -204,6 +240,16 @@ impl FunctionRegistry {
             Box::new(null_functions::CoalesceFunction::new()),
         );
 
+        // Register comparison functions
+        registry.register(
+            "GREATEST",
+            Box::new(comparison_functions::GreatestFunction::new()),
+        );
+        registry.register(
+            "LEAST",
+            Box::new(comparison_functions::LeastFunction::new()),
+        );
+
         // Register advanced list functions
         registry.register(
             "LIST_CONTAINS",
@@ -251,11 +297,52 @@ impl FunctionRegistry {
             Box::new(graph_functions::PropertiesFunction::new()),
         );
         registry.register("SIZE", Box::new(graph_functions::SizeFunction::new()));
+        registry.register("LENGTH", Box::new(graph_functions::SizeFunction::new())); // Alias
+        registry.register(
+            "DEGREE",
+            Box::new(graph_functions::DegreeFunction::new(
+                graph_functions::DegreeDirection::Both,
+            )),
+        );
+        registry.register(
+            "IN_DEGREE",
+            Box::new(graph_functions::DegreeFunction::new(
+                graph_functions::DegreeDirection::Incoming,
+            )),
+        );
+        registry.register(
+            "OUT_DEGREE",
+            Box::new(graph_functions::DegreeFunction::new(
+                graph_functions::DegreeDirection::Outgoing,
+            )),
+        );
+        registry.register(
+            "REACHABLE",
+            Box::new(graph_functions::ReachableFunction::new()),
+        );
+        registry.register(
+            "SHORTEST_PATH",
+            Box::new(graph_functions::ShortestPathFunction::new()),
+        );
         registry.register(
             "INFERRED_LABELS",
             Box::new(graph_functions::InferredLabelsFunction::new()),
         );
 
+        // Register vector similarity functions
+        registry.register(
+            "COSINE_SIMILARITY",
+            Box::new(vector_functions::CosineSimilarityFunction::new()),
+        );
+        registry.register(
+            "EUCLIDEAN_DISTANCE",
+            Box::new(vector_functions::EuclideanDistanceFunction::new()),
+        );
+        registry.register(
+            "DOT_PRODUCT",
+            Box::new(vector_functions::DotProductFunction::new()),
+        );
+
         registry
     }
 
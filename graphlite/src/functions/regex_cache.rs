@@ -0,0 +1,69 @@
+// Copyright (c) 2024-2025 DeepGraph Inc.
+// SPDX-License-Identifier: Apache-2.0
+//
+//! Per-execution cache of compiled regular expressions
+//!
+//! Regex functions such as FT_REGEX are evaluated once per row, but the pattern
+//! is almost always a constant. Compiling a regex costs far more than matching
+//! it, so patterns are compiled once per query and shared across all rows.
+
+use parking_lot::RwLock;
+use regex::Regex;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// Cache of compiled regexes keyed by their pattern string
+///
+/// Cloning the cache is cheap and all clones share the same entries, so the
+/// per-row copies of an `ExecutionContext` reuse patterns compiled earlier in
+/// the same query.
+#[derive(Debug, Clone, Default)]
+pub struct RegexCache {
+    patterns: Arc<RwLock<HashMap<String, Arc<Regex>>>>,
+}
+
+impl RegexCache {
+    /// Create an empty regex cache
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Get the compiled regex for a pattern, compiling and caching it on first use
+    pub fn get_or_compile(&self, pattern: &str) -> Result<Arc<Regex>, regex::Error> {
+        if let Some(regex) = self.patterns.read().get(pattern) {
+            return Ok(regex.clone());
+        }
+
+        let regex = Arc::new(Regex::new(pattern)?);
+        let mut patterns = self.patterns.write();
+        Ok(patterns.entry(pattern.to_string()).or_insert(regex).clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_same_pattern_is_compiled_once() {
+        let cache = RegexCache::new();
+        let first = cache.get_or_compile("^a+b$").unwrap();
+        let second = cache.get_or_compile("^a+b$").unwrap();
+        assert!(Arc::ptr_eq(&first, &second));
+    }
+
+    #[test]
+    fn test_clones_share_entries() {
+        let cache = RegexCache::new();
+        let first = cache.get_or_compile("[0-9]+").unwrap();
+        let second = cache.clone().get_or_compile("[0-9]+").unwrap();
+        assert!(Arc::ptr_eq(&first, &second));
+    }
+
+    #[test]
+    fn test_invalid_pattern_is_not_cached() {
+        let cache = RegexCache::new();
+        assert!(cache.get_or_compile("(unclosed").is_err());
+        assert!(cache.get_or_compile("(unclosed").is_err());
+    }
+}
@@ -11,7 +11,7 @@
 //! These functions are part of the official ISO GQL BNF grammar under <predicate> production rule.
 
 use super::function_trait::{Function, FunctionContext, FunctionError, FunctionResult};
-use crate::storage::Value;
+use crate::storage::{DurationValue, Value};
 use log::{debug, warn};
 use std::collections::HashSet;
 
@@ -249,6 +249,7 @@ fn value_to_comparable(value: &Value) -> FunctionResult<ComparableValue> {
         Value::DateTimeWithFixedOffset(dt) => Ok(ComparableValue::DateTime(dt.timestamp())),
         Value::DateTimeWithNamedTz(_, dt) => Ok(ComparableValue::DateTime(dt.timestamp())),
         Value::TimeWindow(tw) => Ok(ComparableValue::String(format!("{:?}", tw))), // Use debug format for comparison
+        Value::Duration(d) => Ok(ComparableValue::Duration(*d)),
         // Add more value types as needed
         _ => {
             warn!("Unsupported value type for comparison: {:?}", value);
@@ -267,6 +268,7 @@ enum ComparableValue {
     Number(u64), // Using bit representation of f64
     String(String),
     DateTime(i64), // Unix timestamp
+    Duration(DurationValue),
 }
 
 /// Check if a property exists based on a property path string
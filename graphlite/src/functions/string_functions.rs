@@ -10,6 +10,8 @@
 //! - SUBSTRING: Extracts substrings
 //! - REPLACE: Replaces substring occurrences
 //! - REVERSE: Reverses string characters
+//! - FT_REGEX: Tests a string against a regular expression
+//! - SIMILARITY_SCORE: Normalized edit-distance similarity between two strings
 
 use super::function_trait::{Function, FunctionContext, FunctionError, FunctionResult};
 use crate::storage::Value;
@@ -575,3 +577,331 @@ impl Function for ReverseFunction {
         false // String functions are pure scalar functions
     }
 }
+
+// ==============================================================================
+// FT_REGEX FUNCTION
+// ==============================================================================
+
+/// FT_REGEX function - tests whether a string matches a regular expression
+#[derive(Debug)]
+pub struct FtRegexFunction;
+
+impl FtRegexFunction {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Function for FtRegexFunction {
+    fn name(&self) -> &str {
+        "FT_REGEX"
+    }
+
+    fn description(&self) -> &str {
+        "Returns true if the string matches the regular expression pattern"
+    }
+
+    fn argument_count(&self) -> usize {
+        2 // FT_REGEX(string, pattern)
+    }
+
+    fn execute(&self, context: &FunctionContext) -> FunctionResult<Value> {
+        context.validate_argument_count(2)?;
+
+        let value = context.get_argument(0)?;
+        let pattern = context.get_argument(1)?;
+
+        if value.is_null() || pattern.is_null() {
+            return Ok(Value::Null);
+        }
+
+        let text = value
+            .as_string()
+            .ok_or_else(|| FunctionError::InvalidArgumentType {
+                message: "FT_REGEX expects a string as its first argument".to_string(),
+            })?;
+        let pattern = pattern
+            .as_string()
+            .ok_or_else(|| FunctionError::InvalidArgumentType {
+                message: "FT_REGEX expects a string pattern as its second argument".to_string(),
+            })?;
+
+        // Compiled patterns are shared across rows via the per-query regex cache
+        let regex = context.compile_regex(pattern)?;
+        Ok(Value::Boolean(regex.is_match(text)))
+    }
+
+    fn return_type(&self) -> &str {
+        "Boolean"
+    }
+
+    fn graph_context_required(&self) -> bool {
+        false // String functions are pure scalar functions
+    }
+}
+
+// ==============================================================================
+// SIMILARITY_SCORE FUNCTION
+// ==============================================================================
+
+/// SIMILARITY_SCORE function - normalized Levenshtein similarity between two strings
+///
+/// Defined as `1 - levenshtein(a, b) / max(len(a), len(b))` over Unicode characters.
+/// The score is always in `[0, 1]`, symmetric in its arguments, and exactly `1.0`
+/// for identical strings (including two empty strings).
+#[derive(Debug)]
+pub struct SimilarityScoreFunction;
+
+impl SimilarityScoreFunction {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Compute the similarity score for two strings
+    pub fn similarity(a: &str, b: &str) -> f64 {
+        let a: Vec<char> = a.chars().collect();
+        let b: Vec<char> = b.chars().collect();
+
+        let max_len = a.len().max(b.len());
+        if max_len == 0 {
+            return 1.0;
+        }
+
+        1.0 - Self::levenshtein(&a, &b) as f64 / max_len as f64
+    }
+
+    /// Levenshtein edit distance using a single rolling row
+    fn levenshtein(a: &[char], b: &[char]) -> usize {
+        let mut row: Vec<usize> = (0..=b.len()).collect();
+
+        for (i, ca) in a.iter().enumerate() {
+            let mut diagonal = row[0];
+            row[0] = i + 1;
+            for (j, cb) in b.iter().enumerate() {
+                let substitution = diagonal + usize::from(ca != cb);
+                diagonal = row[j + 1];
+                row[j + 1] = substitution.min(row[j] + 1).min(diagonal + 1);
+            }
+        }
+
+        row[b.len()]
+    }
+}
+
+impl Function for SimilarityScoreFunction {
+    fn name(&self) -> &str {
+        "SIMILARITY_SCORE"
+    }
+
+    fn description(&self) -> &str {
+        "Returns a normalized edit-distance similarity in [0, 1] between two strings"
+    }
+
+    fn argument_count(&self) -> usize {
+        2 // SIMILARITY_SCORE(string, string)
+    }
+
+    fn execute(&self, context: &FunctionContext) -> FunctionResult<Value> {
+        context.validate_argument_count(2)?;
+
+        let left = context.get_argument(0)?;
+        let right = context.get_argument(1)?;
+
+        if left.is_null() || right.is_null() {
+            return Ok(Value::Null);
+        }
+
+        let (left, right) = match (left.as_string(), right.as_string()) {
+            (Some(l), Some(r)) => (l, r),
+            _ => {
+                return Err(FunctionError::InvalidArgumentType {
+                    message: "SIMILARITY_SCORE expects two string arguments".to_string(),
+                })
+            }
+        };
+
+        Ok(Value::Number(Self::similarity(left, right)))
+    }
+
+    fn return_type(&self) -> &str {
+        "Number"
+    }
+
+    fn graph_context_required(&self) -> bool {
+        false // String functions are pure scalar functions
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::functions::RegexCache;
+    use std::collections::HashMap;
+
+    fn regex_context(args: Vec<Value>, cache: &RegexCache) -> FunctionContext {
+        FunctionContext::new(vec![], HashMap::new(), args).with_regex_cache(cache.clone())
+    }
+
+    #[test]
+    fn test_ft_regex_matches() {
+        let func = FtRegexFunction::new();
+        let cache = RegexCache::new();
+
+        let matched = func
+            .execute(&regex_context(
+                vec![
+                    Value::String("Node 12".to_string()),
+                    Value::String("^Node 1[0-9]$".to_string()),
+                ],
+                &cache,
+            ))
+            .unwrap();
+        assert_eq!(matched, Value::Boolean(true));
+
+        let unmatched = func
+            .execute(&regex_context(
+                vec![
+                    Value::String("Node 2".to_string()),
+                    Value::String("^Node 1[0-9]$".to_string()),
+                ],
+                &cache,
+            ))
+            .unwrap();
+        assert_eq!(unmatched, Value::Boolean(false));
+    }
+
+    #[test]
+    fn test_ft_regex_cached_and_uncached_agree() {
+        let func = FtRegexFunction::new();
+        let cache = RegexCache::new();
+
+        for i in 0..100 {
+            let args = vec![
+                Value::String(format!("item-{}", i)),
+                Value::String("^item-[0-9]*7$".to_string()),
+            ];
+            let cached = func.execute(&regex_context(args.clone(), &cache)).unwrap();
+            let uncached = func
+                .execute(&FunctionContext::new(vec![], HashMap::new(), args))
+                .unwrap();
+            assert_eq!(cached, uncached);
+        }
+    }
+
+    #[test]
+    fn test_ft_regex_null_and_invalid_pattern() {
+        let func = FtRegexFunction::new();
+        let cache = RegexCache::new();
+
+        let result = func
+            .execute(&regex_context(
+                vec![Value::Null, Value::String("a".to_string())],
+                &cache,
+            ))
+            .unwrap();
+        assert!(result.is_null());
+
+        let invalid = func.execute(&regex_context(
+            vec![
+                Value::String("abc".to_string()),
+                Value::String("(abc".to_string()),
+            ],
+            &cache,
+        ));
+        assert!(invalid.is_err());
+    }
+
+    fn similarity(a: &str, b: &str) -> Value {
+        SimilarityScoreFunction::new()
+            .execute(&FunctionContext::new(
+                vec![],
+                HashMap::new(),
+                vec![Value::String(a.to_string()), Value::String(b.to_string())],
+            ))
+            .unwrap()
+    }
+
+    #[test]
+    fn test_similarity_score_identical_is_one() {
+        assert_eq!(similarity("abc", "abc"), Value::Number(1.0));
+        assert_eq!(similarity("", ""), Value::Number(1.0));
+    }
+
+    #[test]
+    fn test_similarity_score_disjoint_is_zero() {
+        assert_eq!(similarity("abc", "xyz"), Value::Number(0.0));
+        assert_eq!(similarity("", "abc"), Value::Number(0.0));
+    }
+
+    #[test]
+    fn test_similarity_score_is_symmetric_and_bounded() {
+        let pairs = [
+            ("kitten", "sitting"),
+            ("graph", "giraffe"),
+            ("abc", "abcd"),
+            ("héllo", "hello"),
+        ];
+        for (a, b) in pairs {
+            let forward = similarity(a, b).as_number().unwrap();
+            let backward = similarity(b, a).as_number().unwrap();
+            assert_eq!(forward, backward, "{} vs {}", a, b);
+            assert!((0.0..=1.0).contains(&forward), "{} vs {}", a, b);
+        }
+
+        // kitten -> sitting takes 3 edits over 7 characters
+        let score = similarity("kitten", "sitting").as_number().unwrap();
+        assert!((score - (1.0 - 3.0 / 7.0)).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_similarity_score_null_argument() {
+        let result = SimilarityScoreFunction::new()
+            .execute(&FunctionContext::new(
+                vec![],
+                HashMap::new(),
+                vec![Value::Null, Value::String("abc".to_string())],
+            ))
+            .unwrap();
+        assert!(result.is_null());
+    }
+
+    #[test]
+    fn test_case_conversion_handles_multibyte_characters() {
+        let upper = UpperFunction::new()
+            .execute(&FunctionContext::new(
+                vec![],
+                HashMap::new(),
+                vec![Value::String("café".to_string())],
+            ))
+            .unwrap();
+        assert_eq!(upper, Value::String("CAFÉ".to_string()));
+
+        // Some characters change length when case-folded
+        let upper = UpperFunction::new()
+            .execute(&FunctionContext::new(
+                vec![],
+                HashMap::new(),
+                vec![Value::String("straße".to_string())],
+            ))
+            .unwrap();
+        assert_eq!(upper, Value::String("STRASSE".to_string()));
+
+        let lower = LowerFunction::new()
+            .execute(&FunctionContext::new(
+                vec![],
+                HashMap::new(),
+                vec![Value::String("ÉCOLE".to_string())],
+            ))
+            .unwrap();
+        assert_eq!(lower, Value::String("école".to_string()));
+
+        let null = LowerFunction::new()
+            .execute(&FunctionContext::new(
+                vec![],
+                HashMap::new(),
+                vec![Value::Null],
+            ))
+            .unwrap();
+        assert!(null.is_null());
+    }
+}
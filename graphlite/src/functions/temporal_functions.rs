@@ -23,7 +23,7 @@
 //! ## DURATION(string)
 //! Parses an ISO 8601 duration string into a Duration value.
 //! - Input: ISO 8601 duration string (e.g., "P1Y2M3DT4H5M6S", "PT1H", "P1D")
-//! - Output: Duration value (represented as seconds)
+//! - Output: Duration value with nanosecond precision
 //! - Error: Invalid duration format
 //!
 //! # Usage Examples
@@ -45,8 +45,9 @@
 //! ```
 
 use super::function_trait::{Function, FunctionContext, FunctionError, FunctionResult};
-use crate::storage::{TimeWindow, Value};
-use chrono::{DateTime, Datelike, FixedOffset, Offset, Timelike, Utc};
+use super::timezone_functions::TimezoneType;
+use crate::storage::{DurationValue, TimeWindow, Value};
+use chrono::{DateTime, Datelike, FixedOffset, NaiveDateTime, Offset, Timelike, Utc};
 use chrono_tz::Tz;
 use log::{debug, warn};
 
@@ -295,7 +296,7 @@ impl Function for DurationFunction {
     }
 
     fn description(&self) -> &str {
-        "Parse ISO 8601 duration string into Duration value (represented as seconds)"
+        "Parse ISO 8601 duration string into Duration value"
     }
 
     fn argument_count(&self) -> usize {
@@ -303,7 +304,7 @@ impl Function for DurationFunction {
     }
 
     fn return_type(&self) -> &str {
-        "Number"
+        "Duration"
     }
 
     fn execute(&self, context: &FunctionContext) -> FunctionResult<Value> {
@@ -315,6 +316,7 @@ impl Function for DurationFunction {
         // Get the duration string argument
         let duration_str = match context.get_argument(0)? {
             Value::String(s) => s,
+            Value::Duration(d) => return Ok(Value::Duration(*d)),
             _ => {
                 return Err(FunctionError::InvalidArgumentType {
                     message: "DURATION argument must be a string".to_string(),
@@ -323,15 +325,13 @@ impl Function for DurationFunction {
         };
 
         // Parse the ISO 8601 duration string
-        match parse_iso_duration(duration_str) {
-            Ok(duration_seconds) => {
+        match DurationValue::parse_iso8601(duration_str) {
+            Ok(duration) => {
                 debug!(
-                    "Successfully parsed duration: {} -> {} seconds",
-                    duration_str, duration_seconds
+                    "Successfully parsed duration: {} -> {}",
+                    duration_str, duration
                 );
-                // Return duration as number of seconds for now
-                // In the future, we might want a dedicated Duration value type
-                Ok(Value::Number(duration_seconds as f64))
+                Ok(Value::Duration(duration))
             }
             Err(e) => {
                 warn!("Failed to parse duration '{}': {}", duration_str, e);
@@ -367,7 +367,7 @@ impl Function for DurationNumericFunction {
     }
 
     fn return_type(&self) -> &str {
-        "Number"
+        "Duration"
     }
 
     fn execute(&self, context: &FunctionContext) -> FunctionResult<Value> {
@@ -403,7 +403,9 @@ impl Function for DurationNumericFunction {
                     "Successfully parsed numeric duration: {} {} -> {} seconds",
                     number, unit_str, duration_seconds
                 );
-                Ok(Value::Number(duration_seconds as f64))
+                Ok(Value::Duration(DurationValue::from_seconds(
+                    duration_seconds,
+                )))
             }
             Err(e) => {
                 warn!("Failed to parse numeric duration '{}': {}", unit_str, e);
@@ -566,23 +568,23 @@ impl Function for ExtractFunction {
             }
         };
 
+        // Calendar and clock components are read on the local wall clock
+        let local = local_datetime(timezone_value, &datetime, context.time_zone.as_ref());
+
         // Extract the requested component
         let result = match unit.as_str() {
-            "YEAR" => Value::Number(datetime.year() as f64),
-            "MONTH" => Value::Number(datetime.month() as f64),
-            "DAY" => Value::Number(datetime.day() as f64),
-            "HOUR" => Value::Number(datetime.hour() as f64),
-            "MINUTE" => Value::Number(datetime.minute() as f64),
-            "SECOND" => Value::Number(datetime.second() as f64),
-            "DOW" | "DAYOFWEEK" => Value::Number(datetime.weekday().num_days_from_sunday() as f64),
-            "DOY" | "DAYOFYEAR" => Value::Number(datetime.ordinal() as f64),
+            "YEAR" | "MONTH" | "DAY" | "HOUR" | "MINUTE" | "SECOND" => {
+                datetime_component(&unit, &local)
+            }
+            "DOW" | "DAYOFWEEK" => Value::Number(local.weekday().num_days_from_sunday() as f64),
+            "DOY" | "DAYOFYEAR" => Value::Number(local.ordinal() as f64),
             "WEEK" => {
                 // ISO week number
-                Value::Number(datetime.iso_week().week() as f64)
+                Value::Number(local.iso_week().week() as f64)
             }
             "QUARTER" => {
                 // Calculate quarter (1-4)
-                Value::Number(((datetime.month() - 1) / 3 + 1) as f64)
+                Value::Number(((local.month() - 1) / 3 + 1) as f64)
             }
             "EPOCH" => {
                 // Return Unix timestamp
@@ -658,6 +660,102 @@ impl Function for ExtractFunction {
     }
 }
 
+/// YEAR, MONTH, DAY, HOUR, MINUTE and SECOND functions - one datetime component
+///
+/// `HOUR(dt)` is shorthand for `EXTRACT('HOUR', dt)`.
+#[derive(Debug)]
+pub struct DatePartFunction {
+    unit: &'static str,
+}
+
+impl DatePartFunction {
+    pub fn new(unit: &'static str) -> Self {
+        Self { unit }
+    }
+}
+
+impl Function for DatePartFunction {
+    fn name(&self) -> &str {
+        self.unit
+    }
+
+    fn description(&self) -> &str {
+        "Extract one component of a datetime in the session time zone"
+    }
+
+    fn argument_count(&self) -> usize {
+        1
+    }
+
+    fn return_type(&self) -> &str {
+        "Number"
+    }
+
+    fn execute(&self, context: &FunctionContext) -> FunctionResult<Value> {
+        context.validate_argument_count(1)?;
+
+        let value = context.get_argument(0)?;
+        let datetime = match value {
+            Value::Null => return Ok(Value::Null),
+            Value::String(s) => {
+                parse_iso_datetime(s).map_err(|e| FunctionError::ExecutionError {
+                    message: format!("Invalid datetime string '{}': {}", s, e),
+                })?
+            }
+            other => other
+                .as_datetime_utc()
+                .ok_or_else(|| FunctionError::InvalidArgumentType {
+                    message: format!(
+                        "{} argument must be a DateTime or datetime string",
+                        self.unit
+                    ),
+                })?,
+        };
+
+        let local = local_datetime(value, &datetime, context.time_zone.as_ref());
+        Ok(datetime_component(self.unit, &local))
+    }
+
+    fn graph_context_required(&self) -> bool {
+        false
+    }
+}
+
+/// Wall-clock time of a datetime argument
+///
+/// Values that carry a time zone are read in it; plain UTC timestamps and
+/// datetime strings are read in the session time zone, or UTC when unset.
+fn local_datetime(
+    value: &Value,
+    datetime: &DateTime<Utc>,
+    session_time_zone: Option<&TimezoneType>,
+) -> NaiveDateTime {
+    match value {
+        Value::DateTimeWithFixedOffset(dt) => dt.naive_local(),
+        Value::DateTimeWithNamedTz(tz_name, dt) => match tz_name.parse::<Tz>() {
+            Ok(tz) => dt.with_timezone(&tz).naive_local(),
+            Err(_) => dt.naive_utc(),
+        },
+        _ => match session_time_zone {
+            Some(time_zone) => time_zone.local_datetime(datetime),
+            None => datetime.naive_utc(),
+        },
+    }
+}
+
+/// One calendar or clock component of a wall-clock time
+fn datetime_component(unit: &str, local: &NaiveDateTime) -> Value {
+    let component = match unit {
+        "YEAR" => local.year() as f64,
+        "MONTH" => local.month() as f64,
+        "DAY" => local.day() as f64,
+        "HOUR" => local.hour() as f64,
+        "MINUTE" => local.minute() as f64,
+        _ => local.second() as f64,
+    };
+    Value::Number(component)
+}
+
 /// DATE_ADD function - adds interval to date
 #[derive(Debug)]
 pub struct DateAddFunction;
@@ -1075,86 +1173,6 @@ fn parse_iso_datetime(datetime_str: &str) -> Result<DateTime<Utc>, String> {
     ))
 }
 
-/// Parse ISO 8601 duration string into total seconds
-///
-/// Supports formats like:
-/// - P1Y2M3DT4H5M6S (1 year, 2 months, 3 days, 4 hours, 5 minutes, 6 seconds)
-/// - PT1H (1 hour)
-/// - P1D (1 day)
-/// - PT30M (30 minutes)
-/// - PT45S (45 seconds)
-///
-/// Note: For months and years, we use approximations:
-/// - 1 month = 30 days = 2,592,000 seconds
-/// - 1 year = 365 days = 31,536,000 seconds
-fn parse_iso_duration(duration_str: &str) -> Result<i64, String> {
-    if !duration_str.starts_with('P') {
-        return Err("Duration must start with 'P'".to_string());
-    }
-
-    let mut total_seconds = 0i64;
-    let chars = duration_str[1..].chars().peekable(); // Skip the 'P'
-    let mut number_str = String::new();
-    let mut in_time_part = false;
-
-    for ch in chars {
-        match ch {
-            'T' => {
-                in_time_part = true;
-                continue;
-            }
-            '0'..='9' => {
-                number_str.push(ch);
-            }
-            'Y' => {
-                if let Ok(years) = number_str.parse::<i64>() {
-                    total_seconds += years * 365 * 24 * 3600; // Approximate: 1 year = 365 days
-                }
-                number_str.clear();
-            }
-            'M' if !in_time_part => {
-                if let Ok(months) = number_str.parse::<i64>() {
-                    total_seconds += months * 30 * 24 * 3600; // Approximate: 1 month = 30 days
-                }
-                number_str.clear();
-            }
-            'D' => {
-                if let Ok(days) = number_str.parse::<i64>() {
-                    total_seconds += days * 24 * 3600; // 1 day = 86,400 seconds
-                }
-                number_str.clear();
-            }
-            'H' => {
-                if let Ok(hours) = number_str.parse::<i64>() {
-                    total_seconds += hours * 3600; // 1 hour = 3,600 seconds
-                }
-                number_str.clear();
-            }
-            'M' if in_time_part => {
-                if let Ok(minutes) = number_str.parse::<i64>() {
-                    total_seconds += minutes * 60; // 1 minute = 60 seconds
-                }
-                number_str.clear();
-            }
-            'S' => {
-                if let Ok(seconds) = number_str.parse::<i64>() {
-                    total_seconds += seconds;
-                }
-                number_str.clear();
-            }
-            _ => {
-                return Err(format!("Invalid duration character: '{}'", ch));
-            }
-        }
-    }
-
-    if total_seconds == 0 {
-        return Err("Duration must specify at least one time component".to_string());
-    }
-
-    Ok(total_seconds)
-}
-
 /// Parse numeric duration with temporal unit into total seconds
 ///
 /// Supports units like:
@@ -1252,14 +1270,14 @@ mod tests {
         ];
 
         for (duration_str, expected_seconds) in test_cases {
-            let result = parse_iso_duration(duration_str);
+            let result = DurationValue::parse_iso8601(duration_str);
             assert!(
                 result.is_ok(),
                 "Failed to parse valid duration: {}",
                 duration_str
             );
             assert_eq!(
-                result.unwrap(),
+                result.unwrap().seconds,
                 expected_seconds,
                 "Wrong seconds for duration: {}",
                 duration_str
@@ -1275,7 +1293,7 @@ mod tests {
         ];
 
         for duration_str in invalid_cases {
-            let result = parse_iso_duration(duration_str);
+            let result = DurationValue::parse_iso8601(duration_str);
             assert!(
                 result.is_err(),
                 "Should have failed to parse invalid duration: {}",
@@ -1284,6 +1302,32 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_duration_value_display_round_trip() {
+        let cases = vec![
+            ("PT1H30M", "PT1H30M"),
+            ("P1DT2H", "P1DT2H"),
+            ("P2W", "P14D"),
+            ("PT1.5S", "PT1.5S"),
+            ("-PT90M", "-PT1H30M"),
+            ("PT0S", "PT0S"),
+        ];
+
+        for (input, expected) in cases {
+            let duration = DurationValue::parse_iso8601(input).unwrap();
+            assert_eq!(duration.to_string(), expected, "Display of {}", input);
+            assert_eq!(
+                DurationValue::parse_iso8601(&duration.to_string()).unwrap(),
+                duration
+            );
+        }
+
+        let negative = DurationValue::parse_iso8601("-PT1.5S").unwrap();
+        assert_eq!(negative, DurationValue::new(-1, -500_000_000));
+        assert!(negative < DurationValue::from_seconds(0));
+        assert!(DurationValue::new(0, 1_500_000_000) > DurationValue::from_seconds(1));
+    }
+
     #[test]
     fn test_datetime_function() {
         let datetime_func = DateTimeFunction::new();
@@ -1357,10 +1401,10 @@ mod tests {
         let result = duration_func.execute(&context);
         assert!(result.is_ok());
 
-        if let Ok(Value::Number(seconds)) = result {
-            assert_eq!(seconds, 3600.0); // 1 hour = 3600 seconds
+        if let Ok(Value::Duration(duration)) = result {
+            assert_eq!(duration, DurationValue::from_seconds(3600)); // 1 hour = 3600 seconds
         } else {
-            panic!("Expected Number value representing seconds");
+            panic!("Expected Duration value");
         }
 
         // Test invalid argument count
@@ -12,11 +12,11 @@
 
 use super::function_trait::{Function, FunctionContext, FunctionError, FunctionResult};
 use crate::storage::Value;
-use chrono::{DateTime, FixedOffset, Offset, Utc};
+use chrono::{DateTime, FixedOffset, NaiveDateTime, Offset, Utc};
 use chrono_tz::Tz;
 
 /// Parse timezone string into either a named timezone or fixed offset
-fn parse_timezone(tz_str: &str) -> Result<TimezoneType, String> {
+pub fn parse_timezone(tz_str: &str) -> Result<TimezoneType, String> {
     // Try parsing as named timezone first
     if let Ok(tz) = tz_str.parse::<Tz>() {
         return Ok(TimezoneType::Named(tz));
@@ -103,15 +103,15 @@ fn parse_fixed_offset(offset_str: &str) -> Result<FixedOffset, String> {
 }
 
 /// Timezone type enum for handling both named timezones and fixed offsets
-#[derive(Debug, Clone)]
-enum TimezoneType {
+#[derive(Debug, Clone, PartialEq)]
+pub enum TimezoneType {
     Named(Tz),
     Fixed(FixedOffset),
 }
 
 impl TimezoneType {
     /// Convert a UTC datetime to this timezone
-    fn convert_from_utc(&self, utc_dt: &DateTime<Utc>) -> Value {
+    pub fn convert_from_utc(&self, utc_dt: &DateTime<Utc>) -> Value {
         match self {
             TimezoneType::Named(tz) => {
                 let _tz_dt = utc_dt.with_timezone(tz);
@@ -124,8 +124,24 @@ impl TimezoneType {
         }
     }
 
+    /// Wall-clock time in this timezone of a UTC datetime
+    pub fn local_datetime(&self, utc_dt: &DateTime<Utc>) -> NaiveDateTime {
+        match self {
+            TimezoneType::Named(tz) => utc_dt.with_timezone(tz).naive_local(),
+            TimezoneType::Fixed(offset) => utc_dt.with_timezone(offset).naive_local(),
+        }
+    }
+
+    /// Whether this timezone is always UTC
+    pub fn is_utc(&self) -> bool {
+        match self {
+            TimezoneType::Named(tz) => *tz == Tz::UTC,
+            TimezoneType::Fixed(offset) => offset.local_minus_utc() == 0,
+        }
+    }
+
     /// Get timezone name/identifier
-    fn name(&self) -> String {
+    pub fn name(&self) -> String {
         match self {
             TimezoneType::Named(tz) => tz.to_string(),
             TimezoneType::Fixed(offset) => offset.to_string(),
@@ -0,0 +1,371 @@
+// Copyright (c) 2024-2025 DeepGraph Inc.
+// SPDX-License-Identifier: Apache-2.0
+//
+//! Vector function implementations for similarity search
+//!
+//! This module contains functions operating on embedding vectors:
+//! - COSINE_SIMILARITY: Cosine of the angle between two vectors
+//! - EUCLIDEAN_DISTANCE: Straight-line distance between two vectors
+//! - DOT_PRODUCT: Sum of the element-wise products of two vectors
+//!
+//! Arguments may be `Value::Vector` properties, vector literals such as
+//! `[0.1, 0.2]`, or lists of numbers (e.g. a bound `$query_vec` parameter).
+//! Similarity is computed by brute force over each row. NULL or non-vector
+//! arguments yield NULL; vectors of different dimensions are an error.
+
+use super::function_trait::{Function, FunctionContext, FunctionError, FunctionResult};
+use crate::storage::Value;
+
+/// Convert a function argument into a numeric vector
+///
+/// Returns `None` for NULL and for values that are not vectors, so callers
+/// yield NULL for them.
+fn vector_argument(value: &Value) -> Option<Vec<f64>> {
+    match value {
+        Value::Vector(v) => Some(v.iter().map(|x| *x as f64).collect()),
+        Value::List(items) | Value::Array(items) => {
+            items.iter().map(|item| item.as_number()).collect()
+        }
+        _ => None,
+    }
+}
+
+/// Fetch both vector arguments and check that their dimensions agree
+///
+/// Returns `Ok(None)` if either argument is NULL or not a vector.
+fn vector_pair(
+    function_name: &str,
+    context: &FunctionContext,
+) -> FunctionResult<Option<(Vec<f64>, Vec<f64>)>> {
+    context.validate_argument_count(2)?;
+
+    let left = vector_argument(context.get_argument(0)?);
+    let right = vector_argument(context.get_argument(1)?);
+
+    match (left, right) {
+        (Some(left), Some(right)) => {
+            if left.len() != right.len() {
+                return Err(FunctionError::InvalidArgumentType {
+                    message: format!(
+                        "{} requires vectors of the same dimension, got {} and {}",
+                        function_name,
+                        left.len(),
+                        right.len()
+                    ),
+                });
+            }
+            Ok(Some((left, right)))
+        }
+        _ => Ok(None),
+    }
+}
+
+// ==============================================================================
+// COSINE_SIMILARITY FUNCTION
+// ==============================================================================
+
+/// COSINE_SIMILARITY function - cosine of the angle between two vectors
+#[derive(Debug)]
+pub struct CosineSimilarityFunction;
+
+impl CosineSimilarityFunction {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Cosine similarity in [-1.0, 1.0], or None if either vector has zero magnitude
+    pub fn similarity(a: &[f64], b: &[f64]) -> Option<f64> {
+        let mut dot = 0.0;
+        let mut norm_a = 0.0;
+        let mut norm_b = 0.0;
+        for (x, y) in a.iter().zip(b) {
+            dot += x * y;
+            norm_a += x * x;
+            norm_b += y * y;
+        }
+
+        if norm_a == 0.0 || norm_b == 0.0 {
+            return None;
+        }
+
+        Some((dot / (norm_a.sqrt() * norm_b.sqrt())).clamp(-1.0, 1.0))
+    }
+}
+
+impl Function for CosineSimilarityFunction {
+    fn name(&self) -> &str {
+        "COSINE_SIMILARITY"
+    }
+
+    fn description(&self) -> &str {
+        "Returns the cosine similarity of two vectors of equal dimension"
+    }
+
+    fn argument_count(&self) -> usize {
+        2
+    }
+
+    fn return_type(&self) -> &str {
+        "Number"
+    }
+
+    fn graph_context_required(&self) -> bool {
+        false
+    }
+
+    fn execute(&self, context: &FunctionContext) -> FunctionResult<Value> {
+        let (left, right) = match vector_pair("COSINE_SIMILARITY", context)? {
+            Some(pair) => pair,
+            None => return Ok(Value::Null),
+        };
+
+        // Similarity to a zero vector is undefined
+        Ok(Self::similarity(&left, &right)
+            .map(Value::Number)
+            .unwrap_or(Value::Null))
+    }
+}
+
+// ==============================================================================
+// EUCLIDEAN_DISTANCE FUNCTION
+// ==============================================================================
+
+/// EUCLIDEAN_DISTANCE function - straight-line distance between two vectors
+#[derive(Debug)]
+pub struct EuclideanDistanceFunction;
+
+impl EuclideanDistanceFunction {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Function for EuclideanDistanceFunction {
+    fn name(&self) -> &str {
+        "EUCLIDEAN_DISTANCE"
+    }
+
+    fn description(&self) -> &str {
+        "Returns the Euclidean distance between two vectors of equal dimension"
+    }
+
+    fn argument_count(&self) -> usize {
+        2
+    }
+
+    fn return_type(&self) -> &str {
+        "Number"
+    }
+
+    fn graph_context_required(&self) -> bool {
+        false
+    }
+
+    fn execute(&self, context: &FunctionContext) -> FunctionResult<Value> {
+        let (left, right) = match vector_pair("EUCLIDEAN_DISTANCE", context)? {
+            Some(pair) => pair,
+            None => return Ok(Value::Null),
+        };
+
+        let sum_of_squares: f64 = left
+            .iter()
+            .zip(&right)
+            .map(|(x, y)| (x - y) * (x - y))
+            .sum();
+        Ok(Value::Number(sum_of_squares.sqrt()))
+    }
+}
+
+// ==============================================================================
+// DOT_PRODUCT FUNCTION
+// ==============================================================================
+
+/// DOT_PRODUCT function - sum of element-wise products of two vectors
+#[derive(Debug)]
+pub struct DotProductFunction;
+
+impl DotProductFunction {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Function for DotProductFunction {
+    fn name(&self) -> &str {
+        "DOT_PRODUCT"
+    }
+
+    fn description(&self) -> &str {
+        "Returns the dot product of two vectors of equal dimension"
+    }
+
+    fn argument_count(&self) -> usize {
+        2
+    }
+
+    fn return_type(&self) -> &str {
+        "Number"
+    }
+
+    fn graph_context_required(&self) -> bool {
+        false
+    }
+
+    fn execute(&self, context: &FunctionContext) -> FunctionResult<Value> {
+        let (left, right) = match vector_pair("DOT_PRODUCT", context)? {
+            Some(pair) => pair,
+            None => return Ok(Value::Null),
+        };
+
+        Ok(Value::Number(
+            left.iter().zip(&right).map(|(x, y)| x * y).sum(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn call(func: &dyn Function, args: Vec<Value>) -> FunctionResult<Value> {
+        func.execute(&FunctionContext::new(vec![], HashMap::new(), args))
+    }
+
+    #[test]
+    fn test_cosine_similarity_of_same_direction_is_one() {
+        let func = CosineSimilarityFunction::new();
+        let result = call(
+            &func,
+            vec![
+                Value::Vector(vec![1.0, 2.0, 3.0]),
+                Value::Vector(vec![2.0, 4.0, 6.0]),
+            ],
+        )
+        .unwrap();
+        assert!((result.as_number().unwrap() - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_cosine_similarity_of_orthogonal_and_opposite_vectors() {
+        let func = CosineSimilarityFunction::new();
+        let orthogonal = call(
+            &func,
+            vec![Value::Vector(vec![1.0, 0.0]), Value::Vector(vec![0.0, 1.0])],
+        )
+        .unwrap();
+        assert_eq!(orthogonal, Value::Number(0.0));
+
+        let opposite = call(
+            &func,
+            vec![
+                Value::Vector(vec![1.0, 1.0]),
+                Value::Vector(vec![-1.0, -1.0]),
+            ],
+        )
+        .unwrap();
+        assert!((opposite.as_number().unwrap() + 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_cosine_similarity_accepts_numeric_lists() {
+        let func = CosineSimilarityFunction::new();
+        let result = call(
+            &func,
+            vec![
+                Value::Vector(vec![3.0, 4.0]),
+                Value::List(vec![Value::Number(3.0), Value::Number(4.0)]),
+            ],
+        )
+        .unwrap();
+        assert!((result.as_number().unwrap() - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_cosine_similarity_dimension_mismatch() {
+        let func = CosineSimilarityFunction::new();
+        let err = call(
+            &func,
+            vec![
+                Value::Vector(vec![1.0, 2.0]),
+                Value::Vector(vec![1.0, 2.0, 3.0]),
+            ],
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("same dimension"));
+    }
+
+    #[test]
+    fn test_cosine_similarity_null_and_zero_vectors() {
+        let func = CosineSimilarityFunction::new();
+        assert_eq!(
+            call(&func, vec![Value::Null, Value::Vector(vec![1.0])]).unwrap(),
+            Value::Null
+        );
+        assert_eq!(
+            call(
+                &func,
+                vec![Value::Vector(vec![0.0, 0.0]), Value::Vector(vec![1.0, 0.0])]
+            )
+            .unwrap(),
+            Value::Null
+        );
+    }
+
+    #[test]
+    fn test_cosine_similarity_non_vectors_yield_null() {
+        let func = CosineSimilarityFunction::new();
+        assert_eq!(
+            call(
+                &func,
+                vec![Value::String("a".to_string()), Value::Vector(vec![1.0])]
+            )
+            .unwrap(),
+            Value::Null
+        );
+    }
+
+    #[test]
+    fn test_euclidean_distance() {
+        let func = EuclideanDistanceFunction::new();
+        let result = call(
+            &func,
+            vec![Value::Vector(vec![0.0, 0.0]), Value::Vector(vec![3.0, 4.0])],
+        )
+        .unwrap();
+        assert_eq!(result, Value::Number(5.0));
+
+        assert!(call(
+            &func,
+            vec![Value::Vector(vec![0.0]), Value::Vector(vec![3.0, 4.0])]
+        )
+        .is_err());
+        assert_eq!(
+            call(&func, vec![Value::Vector(vec![0.0]), Value::Null]).unwrap(),
+            Value::Null
+        );
+    }
+
+    #[test]
+    fn test_dot_product() {
+        let func = DotProductFunction::new();
+        let result = call(
+            &func,
+            vec![
+                Value::Vector(vec![1.0, 2.0, 3.0]),
+                Value::List(vec![
+                    Value::Number(4.0),
+                    Value::Number(-5.0),
+                    Value::Number(6.0),
+                ]),
+            ],
+        )
+        .unwrap();
+        assert_eq!(result, Value::Number(12.0));
+
+        assert_eq!(
+            call(&func, vec![Value::Number(1.0), Value::Vector(vec![1.0])]).unwrap(),
+            Value::Null
+        );
+    }
+}
@@ -50,13 +50,39 @@ pub(crate) mod txn;
 pub(crate) mod types;
 
 // Re-export the public API - QueryCoordinator is the only entry point
-pub use coordinator::{QueryCoordinator, QueryInfo, QueryPlan, QueryResult, QueryType, Row};
+pub use coordinator::{
+    AnalyzedNode, AnalyzedQueryPlan, ComponentHealth, DryRunReport, EstimatedNode, HealthReport,
+    HealthStatus, QueryCoordinator, QueryError, QueryErrorKind, QueryInfo, QueryMetrics, QueryPlan,
+    QueryResult, QueryType, Row, SlowQuery,
+};
 
 // Re-export session types for SessionMode configuration
 pub use session::SessionMode;
 
-// Re-export Value type (needed for inspecting query results in Row.values)
-pub use storage::Value;
+// Re-export graph lock types returned by QueryCoordinator::lock_graph
+pub use exec::lock_tracker::{GraphLockGuard, LockMode};
+
+// Re-export resource limits taken by QueryCoordinator::process_query_with_limits
+pub use exec::query_limits::QueryLimits;
+
+// Re-export Value types (needed for inspecting query results in Row.values)
+pub use storage::{DurationValue, Value};
+
+// Re-export storage types used to open and maintain a database
+pub use storage::{CompactionReport, FlushPolicy};
+
+// Re-export validation types returned by QueryCoordinator::validate
+pub use ast::validator::{ValidationError, ValidationErrorType};
+pub use ast::Location;
+
+// Re-export the parser nesting limit that guards against stack overflow
+pub use ast::parser::DEFAULT_MAX_NESTING_DEPTH;
+
+// Re-export change notification types used by QueryCoordinator::subscribe
+pub use txn::{ChangeEvent, ChangeKind, EntityKind, SubscriptionId};
+
+// Re-export query normalization for plan caching and log grouping
+pub use ast::lexer::normalize_query;
 
 /// GraphLite version
 pub const VERSION: &str = env!("CARGO_PKG_VERSION");
@@ -10,7 +10,7 @@ use std::collections::hash_map::DefaultHasher;
 use std::collections::{HashMap, HashSet};
 use std::hash::{Hash, Hasher};
 
-use crate::ast::{Expression, InsertStatement, PatternElement};
+use crate::ast::{Expression, InsertStatement, Literal, PatternElement};
 use crate::plan::logical::{
     EntityType, InsertPattern, LogicalNode, LogicalPlan, NodeIdentifier, VariableInfo,
 };
@@ -300,7 +300,7 @@ impl InsertPlanner {
         for (key, value) in sorted_properties {
             key.hash(&mut hasher);
             // Hash the expression in a consistent way
-            format!("{:?}", value).hash(&mut hasher);
+            Self::canonical_expression(value).hash(&mut hasher);
         }
 
         let hash = hasher.finish();
@@ -326,12 +326,38 @@ impl InsertPlanner {
         sorted_properties.sort_by_key(|(k, _)| *k);
         for (key, value) in sorted_properties {
             key.hash(&mut hasher);
-            format!("{:?}", value).hash(&mut hasher);
+            Self::canonical_expression(value).hash(&mut hasher);
         }
 
         let hash = hasher.finish();
         format!("edge_{:x}", hash)
     }
+
+    /// Debug form of a property expression, with map literal keys sorted so
+    /// that key order never changes the content ID
+    fn canonical_expression(expression: &Expression) -> String {
+        match expression {
+            Expression::Literal(literal @ Literal::Map(_)) => {
+                format!("{:?}", Expression::Literal(Self::sorted_literal(literal)))
+            }
+            _ => format!("{:?}", expression),
+        }
+    }
+
+    fn sorted_literal(literal: &Literal) -> Literal {
+        match literal {
+            Literal::Map(entries) => {
+                let mut entries: Vec<(String, Literal)> = entries
+                    .iter()
+                    .map(|(key, value)| (key.clone(), Self::sorted_literal(value)))
+                    .collect();
+                entries.sort_by(|(a, _), (b, _)| a.cmp(b));
+                Literal::Map(entries)
+            }
+            Literal::List(items) => Literal::List(items.iter().map(Self::sorted_literal).collect()),
+            other => other.clone(),
+        }
+    }
 }
 
 /// Planning errors specific to INSERT operations
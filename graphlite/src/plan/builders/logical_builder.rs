@@ -11,9 +11,9 @@
 use std::collections::HashMap;
 
 use crate::ast::{
-    BasicQuery, BinaryExpression, Expression, LetStatement, MatchClause, OrderClause,
-    OrderDirection, PathPattern, PatternElement, Query, ReturnClause, SetOperation,
-    SetOperationType, Variable,
+    BasicQuery, BinaryExpression, Expression, LetStatement, LimitClause, LimitValue, MatchClause,
+    OrderClause, PathPattern, PatternElement, Query, ReturnClause, SetOperation, SetOperationType,
+    Variable,
 };
 use crate::plan::logical::{
     EntityType, JoinType, LogicalNode, LogicalPlan, ProjectExpression, SortExpression, VariableInfo,
@@ -28,6 +28,8 @@ pub struct LogicalBuilder {}
 #[derive(Debug, Clone)]
 pub struct PlanningContext {
     pub variables: HashMap<String, VariableInfo>,
+    /// Pattern variable names in the order they are first bound (used by RETURN *)
+    pub bound_order: Vec<String>,
     pub _next_variable_id: usize,
 }
 
@@ -67,10 +69,8 @@ impl LogicalBuilder {
                         .iter()
                         .map(|item| SortExpression {
                             expression: item.expression.clone(),
-                            ascending: matches!(
-                                item.direction,
-                                crate::ast::OrderDirection::Ascending
-                            ),
+                            ascending: item.is_ascending(),
+                            nulls_first: item.nulls_first(),
                         })
                         .collect();
 
@@ -79,7 +79,8 @@ impl LogicalBuilder {
 
                 // Add LIMIT if present
                 if let Some(limit) = limit_clause {
-                    plan = plan.apply_limit(limit.count, limit.offset);
+                    let (count, offset) = limit_counts(limit)?;
+                    plan = plan.apply_limit(count, offset);
                 }
 
                 Ok(plan)
@@ -203,6 +204,7 @@ impl LogicalBuilder {
     ) -> Result<LogicalPlan, PlanningError> {
         let mut context = PlanningContext {
             variables: HashMap::new(),
+            bound_order: Vec::new(),
             _next_variable_id: 0,
         };
 
@@ -283,13 +285,15 @@ impl LogicalBuilder {
 
         // Process ORDER BY clause
         if let Some(order_clause) = &query.order_clause {
-            let sort_expressions = self.plan_order_clause(order_clause, &context)?;
+            let sort_expressions =
+                self.plan_order_clause(order_clause, &query.return_clause, &context)?;
             logical_plan = logical_plan.apply_sort(sort_expressions);
         }
 
         // Process LIMIT clause
         if let Some(limit_clause) = &query.limit_clause {
-            logical_plan = logical_plan.apply_limit(limit_clause.count, limit_clause.offset);
+            let (count, offset) = limit_counts(limit_clause)?;
+            logical_plan = logical_plan.apply_limit(count, offset);
         }
 
         // Add variable information to the plan
@@ -308,6 +312,7 @@ impl LogicalBuilder {
     ) -> Result<LogicalPlan, PlanningError> {
         let mut context = PlanningContext {
             variables: HashMap::new(),
+            bound_order: Vec::new(),
             _next_variable_id: 0,
         };
 
@@ -360,6 +365,7 @@ impl LogicalBuilder {
     ) -> Result<LogicalPlan, PlanningError> {
         let context = PlanningContext {
             variables: HashMap::new(),
+            bound_order: Vec::new(),
             _next_variable_id: 0,
         };
 
@@ -414,7 +420,8 @@ impl LogicalBuilder {
                 .iter()
                 .map(|item| SortExpression {
                     expression: item.expression.clone(),
-                    ascending: matches!(item.direction, crate::ast::OrderDirection::Ascending),
+                    ascending: item.is_ascending(),
+                    nulls_first: item.nulls_first(),
                 })
                 .collect();
             logical_plan = logical_plan.apply_sort(sort_expressions);
@@ -422,7 +429,8 @@ impl LogicalBuilder {
 
         // Process LIMIT clause if present
         if let Some(limit_clause) = &return_query.limit_clause {
-            logical_plan = logical_plan.apply_limit(limit_clause.count, limit_clause.offset);
+            let (count, offset) = limit_counts(limit_clause)?;
+            logical_plan = logical_plan.apply_limit(count, offset);
         }
 
         Ok(logical_plan)
@@ -455,7 +463,8 @@ impl LogicalBuilder {
                 .iter()
                 .map(|item| SortExpression {
                     expression: item.expression.clone(),
-                    ascending: matches!(item.direction, crate::ast::OrderDirection::Ascending),
+                    ascending: item.is_ascending(),
+                    nulls_first: item.nulls_first(),
                 })
                 .collect();
             plan = plan.apply_sort(sort_expressions);
@@ -463,7 +472,8 @@ impl LogicalBuilder {
 
         // Apply LIMIT if present
         if let Some(limit_clause) = &set_op.limit_clause {
-            plan = plan.apply_limit(limit_clause.count, limit_clause.offset);
+            let (count, offset) = limit_counts(limit_clause)?;
+            plan = plan.apply_limit(count, offset);
         }
 
         Ok(plan)
@@ -623,11 +633,30 @@ impl LogicalBuilder {
     fn plan_return_clause(
         &self,
         return_clause: &ReturnClause,
-        _context: &PlanningContext,
+        context: &PlanningContext,
     ) -> Result<Vec<ProjectExpression>, PlanningError> {
         let mut expressions = Vec::new();
 
         for item in &return_clause.items {
+            // RETURN * projects every bound pattern variable in pattern order
+            if matches!(&item.expression, Expression::Variable(var) if var.name == "*") {
+                if context.bound_order.is_empty() {
+                    return Err(PlanningError::InvalidQuery(
+                        "RETURN * requires at least one bound variable".to_string(),
+                    ));
+                }
+                for name in &context.bound_order {
+                    expressions.push(ProjectExpression {
+                        expression: Expression::Variable(Variable {
+                            name: name.clone(),
+                            location: item.location.clone(),
+                        }),
+                        alias: None,
+                    });
+                }
+                continue;
+            }
+
             expressions.push(ProjectExpression {
                 expression: item.expression.clone(),
                 alias: item.alias.clone(),
@@ -654,7 +683,7 @@ impl LogicalBuilder {
                             labels: node.labels.clone(),
                             required_properties: vec![], // TODO: Extract from properties
                         };
-                        context.variables.insert(identifier.clone(), var_info);
+                        context.bind_variable(identifier, var_info);
                     }
                 }
                 PatternElement::Edge(edge) => {
@@ -665,7 +694,7 @@ impl LogicalBuilder {
                             labels: edge.labels.clone(),
                             required_properties: vec![], // TODO: Extract from properties
                         };
-                        context.variables.insert(identifier.clone(), var_info);
+                        context.bind_variable(identifier, var_info);
                     }
                 }
             }
@@ -719,23 +748,49 @@ impl LogicalBuilder {
     fn plan_order_clause(
         &self,
         order_clause: &OrderClause,
+        return_clause: &ReturnClause,
         _context: &PlanningContext,
     ) -> Result<Vec<SortExpression>, PlanningError> {
         let mut sort_expressions = Vec::new();
 
         for item in &order_clause.items {
             sort_expressions.push(SortExpression {
-                expression: item.expression.clone(),
-                ascending: match item.direction {
-                    OrderDirection::Ascending => true,
-                    OrderDirection::Descending => false,
-                },
+                expression: self
+                    .resolve_order_expression_with_aliases(&item.expression, return_clause),
+                ascending: item.is_ascending(),
+                nulls_first: item.nulls_first(),
             });
         }
 
         Ok(sort_expressions)
     }
 
+    /// Resolve an ORDER BY property access to the alias it was returned under
+    ///
+    /// Sorting runs after projection, where `RETURN n.age AS age` leaves only
+    /// an `age` column, so `ORDER BY n.age` must sort on that column.
+    fn resolve_order_expression_with_aliases(
+        &self,
+        expr: &Expression,
+        return_clause: &ReturnClause,
+    ) -> Expression {
+        if let Expression::PropertyAccess(access) = expr {
+            for return_item in &return_clause.items {
+                if let (Expression::PropertyAccess(returned), Some(alias)) =
+                    (&return_item.expression, &return_item.alias)
+                {
+                    if returned.object == access.object && returned.property == access.property {
+                        return Expression::Variable(Variable {
+                            name: alias.clone(),
+                            location: crate::ast::Location::default(),
+                        });
+                    }
+                }
+            }
+        }
+        expr.clone()
+    }
+
     /// Check if expressions contain aggregate functions
     /// Originally: optimizer.rs line 2019
     fn contains_aggregate_functions(&self, expressions: &[ProjectExpression]) -> bool {
@@ -752,7 +807,17 @@ impl LogicalBuilder {
                 // Check if this is an aggregate function (case insensitive)
                 matches!(
                     func_call.name.to_uppercase().as_str(),
-                    "COUNT" | "SUM" | "AVG" | "AVERAGE" | "MIN" | "MAX" | "COLLECT"
+                    "COUNT"
+                        | "SUM"
+                        | "AVG"
+                        | "AVERAGE"
+                        | "MIN"
+                        | "MAX"
+                        | "COLLECT"
+                        | "APPROX_COUNT_DISTINCT"
+                        | "FIRST"
+                        | "LAST"
+                        | "ARRAY_AGG"
                 )
             }
             Expression::Binary(binary) => {
@@ -800,7 +865,17 @@ impl LogicalBuilder {
                 // If it's an aggregate function, don't add it to GROUP BY
                 if matches!(
                     func_call.name.to_uppercase().as_str(),
-                    "COUNT" | "SUM" | "AVG" | "AVERAGE" | "MIN" | "MAX" | "COLLECT"
+                    "COUNT"
+                        | "SUM"
+                        | "AVG"
+                        | "AVERAGE"
+                        | "MIN"
+                        | "MAX"
+                        | "COLLECT"
+                        | "APPROX_COUNT_DISTINCT"
+                        | "FIRST"
+                        | "LAST"
+                        | "ARRAY_AGG"
                 ) {
                     return;
                 }
@@ -818,7 +893,7 @@ impl LogicalBuilder {
                     self.collect_non_aggregate_subexpressions(&binary.right, group_expressions);
                 }
             }
-            Expression::ArrayIndex(_array_index) => {
+            Expression::ArrayIndex(_) | Expression::ArraySlice(_) => {
                 // Array indexing is typically non-aggregate
                 group_expressions.push(expr.clone());
             }
@@ -888,6 +963,7 @@ impl LogicalBuilder {
                     name: func_call.name.clone(),
                     arguments: resolved_args,
                     distinct: func_call.distinct.clone(),
+                    order_by: func_call.order_by.clone(),
                     location: func_call.location.clone(),
                 })
             }
@@ -929,9 +1005,18 @@ impl PlanningContext {
     pub fn new() -> Self {
         Self {
             variables: HashMap::new(),
+            bound_order: Vec::new(),
             _next_variable_id: 0,
         }
     }
+
+    /// Record a pattern variable, remembering the order of first binding
+    pub fn bind_variable(&mut self, name: &str, info: VariableInfo) {
+        if !self.variables.contains_key(name) {
+            self.bound_order.push(name.to_string());
+        }
+        self.variables.insert(name.to_string(), info);
+    }
 }
 
 impl Default for PlanningContext {
@@ -940,6 +1025,24 @@ impl Default for PlanningContext {
     }
 }
 
+/// Literal LIMIT count and offset of a clause
+///
+/// The executor binds LIMIT/SKIP parameters before planning, so a parameter
+/// reaching the planner was never bound.
+fn limit_counts(limit_clause: &LimitClause) -> Result<(usize, Option<usize>), PlanningError> {
+    let literal = |value: &LimitValue| {
+        value.literal().ok_or_else(|| {
+            PlanningError::InvalidQuery(format!(
+                "LIMIT/SKIP parameter {} must be bound before planning",
+                value
+            ))
+        })
+    };
+    let count = literal(&limit_clause.count)?;
+    let offset = limit_clause.offset.as_ref().map(literal).transpose()?;
+    Ok((count, offset))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -980,6 +1083,7 @@ mod tests {
                     location: dummy_location(),
                 })],
                 distinct: crate::ast::DistinctQualifier::None,
+                order_by: vec![],
                 location: dummy_location(),
             }),
             alias: Some("count".to_string()),
@@ -999,6 +1103,7 @@ mod tests {
                     location: dummy_location(),
                 })],
                 distinct: crate::ast::DistinctQualifier::None,
+                order_by: vec![],
                 location: dummy_location(),
             }),
             alias: Some("total".to_string()),
@@ -1028,6 +1133,7 @@ mod tests {
             name: "COUNT".to_string(),
             arguments: vec![],
             distinct: crate::ast::DistinctQualifier::None,
+            order_by: vec![],
             location: dummy_location(),
         });
 
@@ -1041,6 +1147,7 @@ mod tests {
             name: "AVG".to_string(),
             arguments: vec![],
             distinct: crate::ast::DistinctQualifier::None,
+            order_by: vec![],
             location: dummy_location(),
         });
 
@@ -1054,6 +1161,7 @@ mod tests {
             name: "MIN".to_string(),
             arguments: vec![],
             distinct: crate::ast::DistinctQualifier::None,
+            order_by: vec![],
             location: dummy_location(),
         });
 
@@ -1067,6 +1175,7 @@ mod tests {
             name: "MAX".to_string(),
             arguments: vec![],
             distinct: crate::ast::DistinctQualifier::None,
+            order_by: vec![],
             location: dummy_location(),
         });
 
@@ -1080,6 +1189,7 @@ mod tests {
             name: "COLLECT".to_string(),
             arguments: vec![],
             distinct: crate::ast::DistinctQualifier::None,
+            order_by: vec![],
             location: dummy_location(),
         });
 
@@ -1093,6 +1203,7 @@ mod tests {
             name: "count".to_string(), // lowercase
             arguments: vec![],
             distinct: crate::ast::DistinctQualifier::None,
+            order_by: vec![],
             location: dummy_location(),
         });
 
@@ -1106,6 +1217,7 @@ mod tests {
             name: "toUpper".to_string(),
             arguments: vec![],
             distinct: crate::ast::DistinctQualifier::None,
+            order_by: vec![],
             location: dummy_location(),
         });
 
@@ -1147,6 +1259,7 @@ mod tests {
                     name: "COUNT".to_string(),
                     arguments: vec![],
                     distinct: crate::ast::DistinctQualifier::None,
+                    order_by: vec![],
                     location: dummy_location(),
                 }),
                 alias: Some("count".to_string()),
@@ -1165,6 +1278,7 @@ mod tests {
                 name: "COUNT".to_string(),
                 arguments: vec![],
                 distinct: crate::ast::DistinctQualifier::None,
+                order_by: vec![],
                 location: dummy_location(),
             }),
             alias: Some("count".to_string()),
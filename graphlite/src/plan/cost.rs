@@ -6,7 +6,9 @@
 //! This module provides cost models and statistics collection for optimizing
 //! query execution plans based on data distribution and operator performance.
 
+use crate::ast::{Expression, Operator};
 use crate::plan::physical::PhysicalNode;
+use crate::plan::trace::EstimatedNode;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
@@ -155,6 +157,14 @@ impl CostModel {
                 ..
             } => self.estimate_scan_cost(*estimated_rows, labels, stats, false),
 
+            PhysicalNode::IndexDistinctScan {
+                label,
+                estimated_rows,
+                ..
+            } => {
+                self.estimate_scan_cost(*estimated_rows, std::slice::from_ref(label), stats, false)
+            }
+
             PhysicalNode::EdgeSeqScan {
                 labels,
                 estimated_rows,
@@ -268,6 +278,8 @@ impl CostModel {
                 cost
             }
 
+            PhysicalNode::GraphScope { input, .. } => self.estimate_node_cost(input, stats),
+
             PhysicalNode::InMemorySort {
                 input,
                 estimated_rows,
@@ -279,6 +291,35 @@ impl CostModel {
                 cost
             }
 
+            PhysicalNode::TopK {
+                input,
+                count,
+                offset,
+                ..
+            }
+            | PhysicalNode::VectorTopK {
+                input,
+                count,
+                offset,
+                ..
+            } => {
+                // Every input row is offered to a heap of at most offset + count rows
+                let mut cost = self.estimate_node_cost(input, stats);
+                let keep = count.saturating_add(offset.unwrap_or(0));
+                let cpu_cost = input.get_row_count() as f64
+                    * (keep.max(2) as f64).log2()
+                    * self.cpu_cost_per_row
+                    * 0.01; // Sort constant
+                cost.add(&CostEstimate {
+                    cpu_cost,
+                    io_cost: 0.0,
+                    memory_cost: (keep * 100) as f64 * self.memory_cost_per_byte * 2.0,
+                    network_cost: 0.0,
+                    total_time: cpu_cost,
+                });
+                cost
+            }
+
             PhysicalNode::Limit { input, count, .. } => {
                 // Limit can terminate early, so cost is proportional to limit
                 let input_cost = self.estimate_node_cost(input, stats);
@@ -343,6 +384,55 @@ impl CostModel {
         }
     }
 
+    /// Annotate every operator of a plan with its estimated rows and cost
+    ///
+    /// Each operator's cost includes the cost of its inputs, so the root
+    /// carries the estimate for the whole plan.
+    pub fn explain_node(&self, node: &PhysicalNode, stats: &Statistics) -> EstimatedNode {
+        EstimatedNode {
+            operator: node.operator_name().to_string(),
+            estimated_rows: node.get_row_count(),
+            estimated_cost: self.estimate_node_cost(node, stats).total_cost(),
+            children: node
+                .children()
+                .into_iter()
+                .map(|child| self.explain_node(child, stats))
+                .collect(),
+        }
+    }
+
+    /// Estimate the fraction of rows a filter condition keeps
+    ///
+    /// Equality keeps few rows, range comparisons about a third, and
+    /// conditions the model knows nothing about half.
+    pub fn estimate_selectivity(condition: &Expression) -> f64 {
+        match condition {
+            Expression::Binary(binary) => match binary.operator {
+                Operator::And => {
+                    Self::estimate_selectivity(&binary.left)
+                        * Self::estimate_selectivity(&binary.right)
+                }
+                Operator::Or => {
+                    let left = Self::estimate_selectivity(&binary.left);
+                    let right = Self::estimate_selectivity(&binary.right);
+                    left + right - left * right
+                }
+                Operator::Equal | Operator::In => 0.1,
+                Operator::NotEqual | Operator::NotIn => 0.9,
+                Operator::LessThan
+                | Operator::LessEqual
+                | Operator::GreaterThan
+                | Operator::GreaterEqual => 1.0 / 3.0,
+                Operator::Starts | Operator::Ends | Operator::Contains | Operator::Like => 0.25,
+                _ => 0.5,
+            },
+            Expression::Unary(unary) if matches!(unary.operator, Operator::Not) => {
+                1.0 - Self::estimate_selectivity(&unary.expression)
+            }
+            _ => 0.5,
+        }
+    }
+
     /// Estimate scan cost
     fn estimate_scan_cost(
         &self,
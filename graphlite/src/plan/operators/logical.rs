@@ -8,7 +8,8 @@
 //! logical equivalence transformations.
 
 use crate::ast::{
-    EdgeDirection, Expression, PathPattern, PathQuantifier, PathType, PatternElement,
+    CatalogPath, EdgeDirection, Expression, LabelExpression, PathPattern, PathQuantifier, PathType,
+    PatternElement,
 };
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
@@ -125,6 +126,12 @@ pub enum LogicalNode {
         input: Box<LogicalNode>,
     },
 
+    /// Match the input pattern in another graph than the session graph
+    GraphScope {
+        graph: CatalogPath,
+        input: Box<LogicalNode>,
+    },
+
     /// Filter rows based on condition
     Filter {
         condition: Expression,
@@ -295,6 +302,10 @@ pub struct AggregateExpression {
     pub function: AggregateFunction,
     pub expression: Expression,
     pub alias: Option<String>,
+    /// Aggregate over distinct argument values only, e.g. COLLECT(DISTINCT x)
+    pub distinct: bool,
+    /// Order the group's rows are fed in, e.g. ARRAY_AGG(x ORDER BY y)
+    pub order_by: Vec<SortExpression>,
 }
 
 /// Aggregate functions
@@ -306,6 +317,10 @@ pub enum AggregateFunction {
     Min,
     Max,
     Collect,
+    ApproxCountDistinct,
+    First,
+    Last,
+    ArrayAgg,
 }
 
 /// Sort expression with order
@@ -313,6 +328,7 @@ pub enum AggregateFunction {
 pub struct SortExpression {
     pub expression: Expression,
     pub ascending: bool,
+    pub nulls_first: bool,
 }
 
 impl LogicalPlan {
@@ -345,6 +361,18 @@ impl LogicalPlan {
             return Err("Empty path pattern".to_string());
         }
 
+        // A pattern matched in another graph is planned as usual, then scoped
+        if let Some(graph) = &pattern.graph {
+            let unscoped = PathPattern {
+                graph: None,
+                ..pattern.clone()
+            };
+            return Ok(LogicalNode::GraphScope {
+                graph: graph.clone(),
+                input: Box::new(Self::from_path_pattern(&unscoped)?),
+            });
+        }
+
         // If path type is specified and not WALK, use PathTraversal
         if let Some(path_type) = &pattern.path_type {
             if *path_type != PathType::Walk {
@@ -352,6 +380,10 @@ impl LogicalPlan {
             }
         }
 
+        if let Some(reversed) = Self::reverse_toward_anchor(pattern) {
+            return Self::from_path_pattern(&reversed);
+        }
+
         let mut current_node: Option<LogicalNode> = None;
         let mut i = 0;
 
@@ -363,9 +395,10 @@ impl LogicalPlan {
                         .clone()
                         .unwrap_or_else(|| format!("_node_{}", i));
 
+                    let (scan_labels, label_filter) = Self::label_constraint(node);
                     let node_scan = LogicalNode::NodeScan {
                         variable: variable.clone(),
-                        labels: node.labels.clone(),
+                        labels: scan_labels,
                         properties: node.properties.as_ref().map(|props| {
                             props
                                 .properties
@@ -376,7 +409,12 @@ impl LogicalPlan {
                     };
 
                     if current_node.is_none() {
-                        current_node = Some(node_scan);
+                        let scan = match label_filter {
+                            Some(labels) => Self::with_label_filter(node_scan, &variable, labels),
+                            None => node_scan,
+                        };
+                        current_node =
+                            Some(Self::with_inline_predicate(scan, node.predicate.as_ref()));
                     }
 
                     // Look ahead for edge pattern
@@ -409,6 +447,19 @@ impl LogicalPlan {
 
                                     current_node = Some(expand);
 
+                                    // Expansion reaches nodes of any label
+                                    if !next_node.labels.is_empty() {
+                                        let labels =
+                                            next_node.label_expression.clone().unwrap_or_else(
+                                                || LabelExpression::all_of(&next_node.labels),
+                                            );
+                                        current_node = Some(Self::with_label_filter(
+                                            current_node.unwrap(),
+                                            &to_variable,
+                                            labels,
+                                        ));
+                                    }
+
                                     // Add filter for target node properties if they exist
                                     if let Some(target_props) = &next_node.properties {
                                         for property in &target_props.properties {
@@ -436,6 +487,17 @@ impl LogicalPlan {
                                             current_node = Some(filter);
                                         }
                                     }
+
+                                    // Inline WHERE predicates filter right after the hop
+                                    // that binds their variables
+                                    let mut node = current_node.unwrap();
+                                    node =
+                                        Self::with_inline_predicate(node, edge.predicate.as_ref());
+                                    node = Self::with_inline_predicate(
+                                        node,
+                                        next_node.predicate.as_ref(),
+                                    );
+                                    current_node = Some(node);
                                     i += 1; // Move to edge position, will be incremented to target node at end of loop
                                 }
                             }
@@ -452,6 +514,52 @@ impl LogicalPlan {
         current_node.ok_or_else(|| "Failed to create logical plan from pattern".to_string())
     }
 
+    /// Reverse a single-hop pattern whose last node is anchored and first is not
+    ///
+    /// A node is anchored by inline properties or an inline predicate. Planning
+    /// starts at the first node, so reversing makes the scan find the few
+    /// anchored nodes and expand from them over the opposite adjacency index
+    /// (incoming edges for `(a)-[]->(b {..})`), instead of expanding from every
+    /// node the unanchored end matches. Undirected hops read both indexes
+    /// either way and only gain the smaller scan.
+    fn reverse_toward_anchor(pattern: &PathPattern) -> Option<PathPattern> {
+        if pattern.assignment.is_some() {
+            return None;
+        }
+        let (first, edge, last) = match pattern.elements.as_slice() {
+            [PatternElement::Node(first), PatternElement::Edge(edge), PatternElement::Node(last)] => {
+                (first, edge, last)
+            }
+            _ => return None,
+        };
+        let anchored = |node: &crate::ast::Node| {
+            node.predicate.is_some()
+                || node
+                    .properties
+                    .as_ref()
+                    .is_some_and(|props| !props.properties.is_empty())
+        };
+        if edge.quantifier.is_some() || anchored(first) || !anchored(last) {
+            return None;
+        }
+
+        let mut reversed_edge = edge.clone();
+        reversed_edge.direction = match edge.direction {
+            EdgeDirection::Outgoing => EdgeDirection::Incoming,
+            EdgeDirection::Incoming => EdgeDirection::Outgoing,
+            EdgeDirection::Both => EdgeDirection::Both,
+            EdgeDirection::Undirected => EdgeDirection::Undirected,
+        };
+        Some(PathPattern {
+            elements: vec![
+                PatternElement::Node(last.clone()),
+                PatternElement::Edge(reversed_edge),
+                PatternElement::Node(first.clone()),
+            ],
+            ..pattern.clone()
+        })
+    }
+
     /// Create a PathTraversal node for path types that need special handling
     fn create_path_traversal(
         pattern: &PathPattern,
@@ -487,13 +595,79 @@ impl LogicalPlan {
             .unwrap_or_else(|| "_end_node".to_string());
 
         // Create PathTraversal node
-        Ok(LogicalNode::PathTraversal {
+        let mut traversal = LogicalNode::PathTraversal {
             path_type,
             from_variable: start_variable,
             to_variable: end_variable,
             path_elements,
-            input: Box::new(node_scan),
-        })
+            input: Box::new(Self::with_inline_predicate(
+                node_scan,
+                start_node.predicate.as_ref(),
+            )),
+        };
+
+        // Remaining inline predicates can only be checked once the path is bound
+        for element in pattern.elements.iter().skip(1) {
+            let predicate = match element {
+                PatternElement::Node(node) => node.predicate.as_ref(),
+                PatternElement::Edge(edge) => edge.predicate.as_ref(),
+            };
+            traversal = Self::with_inline_predicate(traversal, predicate);
+        }
+
+        Ok(traversal)
+    }
+
+    /// Wrap a node in a filter for an inline `WHERE` predicate, if there is one
+    /// Labels a scan for `node` reads, and the label expression its rows
+    /// must still be filtered by
+    ///
+    /// A single label needs no filter. A conjunction such as
+    /// `:Person&Employee` scans its first label and checks the rest;
+    /// alternatives such as `:Person|Company` scan every node.
+    fn label_constraint(node: &crate::ast::Node) -> (Vec<String>, Option<LabelExpression>) {
+        let expression = match &node.label_expression {
+            Some(expression) => expression.clone(),
+            None if node.labels.len() > 1 => LabelExpression::all_of(&node.labels),
+            None => return (node.labels.clone(), None),
+        };
+        match expression.required_labels() {
+            Some(labels) if labels.len() <= 1 => (labels, None),
+            Some(labels) => (labels, Some(expression)),
+            None => (Vec::new(), Some(expression)),
+        }
+    }
+
+    /// Keep only the rows whose `variable` node satisfies `labels`
+    fn with_label_filter(
+        input: LogicalNode,
+        variable: &str,
+        labels: LabelExpression,
+    ) -> LogicalNode {
+        LogicalNode::Filter {
+            condition: Expression::IsPredicate(crate::ast::IsPredicateExpression {
+                subject: Box::new(Expression::Variable(crate::ast::Variable {
+                    name: variable.to_string(),
+                    location: crate::ast::Location::default(),
+                })),
+                predicate_type: crate::ast::IsPredicateType::Label(labels),
+                negated: false,
+                target: None,
+                type_spec: None,
+                location: crate::ast::Location::default(),
+            }),
+            input: Box::new(input),
+        }
+    }
+
+    fn with_inline_predicate(input: LogicalNode, predicate: Option<&Expression>) -> LogicalNode {
+        match predicate {
+            Some(condition) => LogicalNode::Filter {
+                condition: condition.clone(),
+                input: Box::new(input),
+            },
+            None => input,
+        }
     }
 
     /// Extract start and end nodes from pattern
@@ -585,6 +759,10 @@ impl LogicalPlan {
                     "MIN" => AggregateFunction::Min,
                     "MAX" => AggregateFunction::Max,
                     "COLLECT" => AggregateFunction::Collect,
+                    "APPROX_COUNT_DISTINCT" => AggregateFunction::ApproxCountDistinct,
+                    "FIRST" => AggregateFunction::First,
+                    "LAST" => AggregateFunction::Last,
+                    "ARRAY_AGG" => AggregateFunction::ArrayAgg,
                     _ => continue, // Skip non-aggregate functions
                 };
 
@@ -599,6 +777,16 @@ impl LogicalPlan {
                     function: aggregate_function,
                     expression: arg_expr,
                     alias: expr.alias.clone(),
+                    distinct: func_call.distinct == crate::ast::DistinctQualifier::Distinct,
+                    order_by: func_call
+                        .order_by
+                        .iter()
+                        .map(|item| SortExpression {
+                            expression: item.expression.clone(),
+                            ascending: item.is_ascending(),
+                            nulls_first: item.nulls_first(),
+                        })
+                        .collect(),
                 });
             }
             // For non-aggregate expressions in group context, they should be in GROUP BY
@@ -941,6 +1129,7 @@ impl LogicalNode {
                 }
                 vars
             }
+            LogicalNode::GraphScope { input, .. } => input.get_variables(),
             LogicalNode::Unwind {
                 variable, input, ..
             } => {
@@ -965,6 +1154,7 @@ impl LogicalNode {
             LogicalNode::NodeScan { .. } => 1000, // Default estimate
             LogicalNode::EdgeScan { .. } => 5000, // Default estimate
             LogicalNode::Expand { input, .. } => input.estimate_cardinality() * 5, // Average fanout
+            LogicalNode::GraphScope { input, .. } => input.estimate_cardinality(),
             LogicalNode::Filter { input, .. } => input.estimate_cardinality() / 2, // 50% selectivity
             LogicalNode::Project { input, .. } => input.estimate_cardinality(),
             LogicalNode::Join { left, right, .. } => {
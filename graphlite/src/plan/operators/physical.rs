@@ -6,7 +6,8 @@
 //! Physical plans represent the actual execution strategy with specific
 //! algorithms and data access methods chosen for optimal performance.
 
-use crate::ast::{EdgeDirection, Expression, PathType};
+use crate::ast::{CatalogPath, EdgeDirection, Expression, PathType};
+use crate::plan::cost::CostModel;
 use crate::plan::logical::{AggregateFunction, JoinType, LogicalNode, LogicalPlan, PathElement};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
@@ -91,6 +92,23 @@ pub enum PhysicalNode {
         variable: String,
         labels: Vec<String>,
         properties: Option<HashMap<String, Expression>>,
+        /// Equalities `variable.property = value` the filter above the scan
+        /// requires, as property and literal or parameter; a property index can
+        /// look them up
+        equalities: Vec<(String, Expression)>,
+        estimated_rows: usize,
+        estimated_cost: f64,
+    },
+
+    /// Distinct values of a node property, read from the property's index
+    /// without visiting the nodes
+    ///
+    /// Each row holds one value in the `variable.property` column.
+    IndexDistinctScan {
+        variable: String,
+        label: String,
+        property: String,
+        index_name: String,
         estimated_rows: usize,
         estimated_cost: f64,
     },
@@ -141,6 +159,14 @@ pub enum PhysicalNode {
         estimated_cost: f64,
     },
 
+    /// Run the input pattern against another graph than the session graph
+    GraphScope {
+        graph: CatalogPath,
+        input: Box<PhysicalNode>,
+        estimated_rows: usize,
+        estimated_cost: f64,
+    },
+
     /// Filter with predicate pushdown
     Filter {
         condition: Expression,
@@ -267,6 +293,29 @@ pub enum PhysicalNode {
         estimated_cost: f64,
     },
 
+    /// ORDER BY fused with LIMIT: keeps only the first `offset + count` rows
+    /// in a bounded heap instead of sorting the whole input
+    TopK {
+        expressions: Vec<SortItem>,
+        count: usize,
+        offset: Option<usize>,
+        input: Box<PhysicalNode>,
+        estimated_rows: usize,
+        estimated_cost: f64,
+    },
+
+    /// ORDER BY a vector similarity function fused with LIMIT: scores each
+    /// row once and keeps the best `offset + count` scores in a min-heap
+    VectorTopK {
+        score: Expression,
+        ascending: bool,
+        count: usize,
+        offset: Option<usize>,
+        input: Box<PhysicalNode>,
+        estimated_rows: usize,
+        estimated_cost: f64,
+    },
+
     /// Limit with early termination
     Limit {
         count: usize,
@@ -436,6 +485,10 @@ pub struct AggregateItem {
     pub expression: Expression,
     pub alias: Option<String>,
     pub output_type: OutputType,
+    /// Aggregate over distinct argument values only
+    pub distinct: bool,
+    /// Order the group's rows are fed in, e.g. ARRAY_AGG(x ORDER BY y)
+    pub order_by: Vec<SortItem>,
 }
 
 /// Sort item with ordering
@@ -471,6 +524,11 @@ impl PhysicalPlan {
         }
     }
 
+    /// Render the operator tree as a Graphviz DOT digraph
+    pub fn to_dot(&self) -> String {
+        crate::plan::trace::plan_to_dot(&self.root, None)
+    }
+
     /// Convert logical plan to physical plan
     pub fn from_logical(logical: &LogicalPlan) -> Self {
         let root = Self::convert_logical_node(&logical.root);
@@ -502,6 +560,7 @@ impl PhysicalPlan {
                         variable: variable.clone(),
                         labels: labels.clone(),
                         properties: properties.clone(),
+                        equalities: Vec::new(),
                         estimated_rows: estimated_rows / 10, // More selective
                         estimated_cost: estimated_cost * 0.5, // Cheaper with index
                     }
@@ -567,10 +626,20 @@ impl PhysicalPlan {
                 }
             }
 
+            LogicalNode::GraphScope { graph, input } => {
+                let input_physical = Box::new(Self::convert_logical_node(input));
+                PhysicalNode::GraphScope {
+                    graph: graph.clone(),
+                    estimated_rows: input_physical.get_row_count(),
+                    estimated_cost: input_physical.get_cost(),
+                    input: input_physical,
+                }
+            }
+
             LogicalNode::Filter { condition, input } => {
                 let input_physical = Box::new(Self::convert_logical_node(input));
                 let input_rows = input_physical.get_row_count();
-                let selectivity = 0.5; // Default selectivity
+                let selectivity = CostModel::estimate_selectivity(condition);
                 let estimated_rows = (input_rows as f64 * selectivity) as usize;
                 let estimated_cost = input_physical.get_cost() + (input_rows as f64 * 0.01);
 
@@ -614,7 +683,7 @@ impl PhysicalPlan {
                     .map(|expr| SortItem {
                         expression: expr.expression.clone(),
                         ascending: expr.ascending,
-                        nulls_first: false,
+                        nulls_first: expr.nulls_first,
                     })
                     .collect();
 
@@ -654,6 +723,57 @@ impl PhysicalPlan {
                 offset,
                 input,
             } => {
+                // ORDER BY immediately followed by LIMIT only needs the leading rows
+                if let LogicalNode::Sort {
+                    expressions,
+                    input: sort_input,
+                } = input.as_ref()
+                {
+                    let input_physical = Box::new(Self::convert_logical_node(sort_input));
+                    let input_rows = input_physical.get_row_count();
+                    let keep = count.saturating_add(offset.unwrap_or(0));
+                    let estimated_rows =
+                        (*count).min(input_rows.saturating_sub(offset.unwrap_or(0)));
+                    let estimated_cost = input_physical.get_cost()
+                        + (input_rows as f64 * (keep.max(2) as f64).log2() * 0.001);
+
+                    // A single similarity score only needs a heap of numbers; it
+                    // places unscored rows where NULLs go by default
+                    if let [sort_expr] = expressions.as_slice() {
+                        if is_vector_score(&sort_expr.expression)
+                            && sort_expr.nulls_first != sort_expr.ascending
+                        {
+                            return PhysicalNode::VectorTopK {
+                                score: sort_expr.expression.clone(),
+                                ascending: sort_expr.ascending,
+                                count: *count,
+                                offset: *offset,
+                                input: input_physical,
+                                estimated_rows,
+                                estimated_cost,
+                            };
+                        }
+                    }
+
+                    let sort_items: Vec<SortItem> = expressions
+                        .iter()
+                        .map(|expr| SortItem {
+                            expression: expr.expression.clone(),
+                            ascending: expr.ascending,
+                            nulls_first: expr.nulls_first,
+                        })
+                        .collect();
+
+                    return PhysicalNode::TopK {
+                        expressions: sort_items,
+                        count: *count,
+                        offset: *offset,
+                        input: input_physical,
+                        estimated_rows,
+                        estimated_cost,
+                    };
+                }
+
                 let input_physical = Box::new(Self::convert_logical_node(input));
                 let input_rows = input_physical.get_row_count();
                 let offset_val = offset.unwrap_or(0);
@@ -712,6 +832,16 @@ impl PhysicalPlan {
                             expression: agg.expression.clone(),
                             alias: agg.alias.clone(),
                             output_type: OutputType::Float, // Default to Float for most aggregates
+                            distinct: agg.distinct,
+                            order_by: agg
+                                .order_by
+                                .iter()
+                                .map(|expr| SortItem {
+                                    expression: expr.expression.clone(),
+                                    ascending: expr.ascending,
+                                    nulls_first: expr.nulls_first,
+                                })
+                                .collect(),
                         }
                     })
                     .collect();
@@ -1162,10 +1292,12 @@ impl PhysicalNode {
         match self {
             PhysicalNode::NodeSeqScan { estimated_cost, .. } => *estimated_cost,
             PhysicalNode::NodeIndexScan { estimated_cost, .. } => *estimated_cost,
+            PhysicalNode::IndexDistinctScan { estimated_cost, .. } => *estimated_cost,
             PhysicalNode::EdgeSeqScan { estimated_cost, .. } => *estimated_cost,
             PhysicalNode::IndexedExpand { estimated_cost, .. } => *estimated_cost,
             PhysicalNode::HashExpand { estimated_cost, .. } => *estimated_cost,
             PhysicalNode::PathTraversal { estimated_cost, .. } => *estimated_cost,
+            PhysicalNode::GraphScope { estimated_cost, .. } => *estimated_cost,
             PhysicalNode::Filter { estimated_cost, .. } => *estimated_cost,
             PhysicalNode::Project { estimated_cost, .. } => *estimated_cost,
             PhysicalNode::HashJoin { estimated_cost, .. } => *estimated_cost,
@@ -1180,6 +1312,8 @@ impl PhysicalNode {
             PhysicalNode::ExternalSort { estimated_cost, .. } => *estimated_cost,
             PhysicalNode::InMemorySort { estimated_cost, .. } => *estimated_cost,
             PhysicalNode::Distinct { estimated_cost, .. } => *estimated_cost,
+            PhysicalNode::TopK { estimated_cost, .. } => *estimated_cost,
+            PhysicalNode::VectorTopK { estimated_cost, .. } => *estimated_cost,
             PhysicalNode::Limit { estimated_cost, .. } => *estimated_cost,
             PhysicalNode::GenericFunction { estimated_cost, .. } => *estimated_cost,
             PhysicalNode::ExistsSubquery { estimated_cost, .. } => *estimated_cost,
@@ -1198,15 +1332,62 @@ impl PhysicalNode {
         }
     }
 
+    /// Get the name of this node's operator, as shown by EXPLAIN ANALYZE
+    pub fn operator_name(&self) -> &'static str {
+        match self {
+            PhysicalNode::NodeSeqScan { .. } => "NodeSeqScan",
+            PhysicalNode::NodeIndexScan { .. } => "NodeIndexScan",
+            PhysicalNode::IndexDistinctScan { .. } => "IndexDistinctScan",
+            PhysicalNode::EdgeSeqScan { .. } => "EdgeSeqScan",
+            PhysicalNode::IndexedExpand { .. } => "IndexedExpand",
+            PhysicalNode::HashExpand { .. } => "HashExpand",
+            PhysicalNode::PathTraversal { .. } => "PathTraversal",
+            PhysicalNode::GraphScope { .. } => "GraphScope",
+            PhysicalNode::Filter { .. } => "Filter",
+            PhysicalNode::Project { .. } => "Project",
+            PhysicalNode::HashJoin { .. } => "HashJoin",
+            PhysicalNode::NestedLoopJoin { .. } => "NestedLoopJoin",
+            PhysicalNode::SortMergeJoin { .. } => "SortMergeJoin",
+            PhysicalNode::UnionAll { .. } => "UnionAll",
+            PhysicalNode::Intersect { .. } => "Intersect",
+            PhysicalNode::Except { .. } => "Except",
+            PhysicalNode::HashAggregate { .. } => "HashAggregate",
+            PhysicalNode::SortAggregate { .. } => "SortAggregate",
+            PhysicalNode::Having { .. } => "Having",
+            PhysicalNode::ExternalSort { .. } => "ExternalSort",
+            PhysicalNode::InMemorySort { .. } => "InMemorySort",
+            PhysicalNode::Distinct { .. } => "Distinct",
+            PhysicalNode::TopK { .. } => "TopK",
+            PhysicalNode::VectorTopK { .. } => "VectorTopK",
+            PhysicalNode::Limit { .. } => "Limit",
+            PhysicalNode::GenericFunction { .. } => "GenericFunction",
+            PhysicalNode::ExistsSubquery { .. } => "ExistsSubquery",
+            PhysicalNode::NotExistsSubquery { .. } => "NotExistsSubquery",
+            PhysicalNode::InSubquery { .. } => "InSubquery",
+            PhysicalNode::NotInSubquery { .. } => "NotInSubquery",
+            PhysicalNode::ScalarSubquery { .. } => "ScalarSubquery",
+            PhysicalNode::WithQuery { .. } => "WithQuery",
+            PhysicalNode::Unwind { .. } => "Unwind",
+            PhysicalNode::GraphIndexScan { .. } => "GraphIndexScan",
+            PhysicalNode::IndexJoin { .. } => "IndexJoin",
+            PhysicalNode::Insert { .. } => "Insert",
+            PhysicalNode::Update { .. } => "Update",
+            PhysicalNode::Delete { .. } => "Delete",
+            PhysicalNode::SingleRow { .. } => "SingleRow",
+        }
+    }
+
     /// Get the estimated row count of this node
     pub fn get_row_count(&self) -> usize {
         match self {
             PhysicalNode::NodeSeqScan { estimated_rows, .. } => *estimated_rows,
             PhysicalNode::NodeIndexScan { estimated_rows, .. } => *estimated_rows,
+            PhysicalNode::IndexDistinctScan { estimated_rows, .. } => *estimated_rows,
             PhysicalNode::EdgeSeqScan { estimated_rows, .. } => *estimated_rows,
             PhysicalNode::IndexedExpand { estimated_rows, .. } => *estimated_rows,
             PhysicalNode::HashExpand { estimated_rows, .. } => *estimated_rows,
             PhysicalNode::PathTraversal { estimated_rows, .. } => *estimated_rows,
+            PhysicalNode::GraphScope { estimated_rows, .. } => *estimated_rows,
             PhysicalNode::Filter { estimated_rows, .. } => *estimated_rows,
             PhysicalNode::Project { estimated_rows, .. } => *estimated_rows,
             PhysicalNode::HashJoin { estimated_rows, .. } => *estimated_rows,
@@ -1220,6 +1401,8 @@ impl PhysicalNode {
             PhysicalNode::ExternalSort { estimated_rows, .. } => *estimated_rows,
             PhysicalNode::InMemorySort { estimated_rows, .. } => *estimated_rows,
             PhysicalNode::Distinct { estimated_rows, .. } => *estimated_rows,
+            PhysicalNode::TopK { estimated_rows, .. } => *estimated_rows,
+            PhysicalNode::VectorTopK { estimated_rows, .. } => *estimated_rows,
             PhysicalNode::Limit { estimated_rows, .. } => *estimated_rows,
             PhysicalNode::GenericFunction { estimated_rows, .. } => *estimated_rows,
             PhysicalNode::ExistsSubquery { estimated_rows, .. } => *estimated_rows,
@@ -1247,6 +1430,7 @@ impl PhysicalNode {
             PhysicalNode::IndexedExpand { input, .. }
             | PhysicalNode::HashExpand { input, .. }
             | PhysicalNode::PathTraversal { input, .. }
+            | PhysicalNode::GraphScope { input, .. }
             | PhysicalNode::Filter { input, .. }
             | PhysicalNode::Project { input, .. }
             | PhysicalNode::HashAggregate { input, .. }
@@ -1254,6 +1438,8 @@ impl PhysicalNode {
             | PhysicalNode::ExternalSort { input, .. }
             | PhysicalNode::InMemorySort { input, .. }
             | PhysicalNode::Distinct { input, .. }
+            | PhysicalNode::TopK { input, .. }
+            | PhysicalNode::VectorTopK { input, .. }
             | PhysicalNode::Limit { input, .. }
             | PhysicalNode::GenericFunction { input, .. } => {
                 operators.extend(input.get_operators());
@@ -1325,12 +1511,106 @@ impl PhysicalNode {
         operators
     }
 
+    /// Get the direct inputs of this node, in plan order
+    pub fn children(&self) -> Vec<&PhysicalNode> {
+        match self {
+            PhysicalNode::IndexedExpand { input, .. }
+            | PhysicalNode::HashExpand { input, .. }
+            | PhysicalNode::PathTraversal { input, .. }
+            | PhysicalNode::GraphScope { input, .. }
+            | PhysicalNode::Filter { input, .. }
+            | PhysicalNode::Project { input, .. }
+            | PhysicalNode::HashAggregate { input, .. }
+            | PhysicalNode::SortAggregate { input, .. }
+            | PhysicalNode::Having { input, .. }
+            | PhysicalNode::ExternalSort { input, .. }
+            | PhysicalNode::InMemorySort { input, .. }
+            | PhysicalNode::Distinct { input, .. }
+            | PhysicalNode::TopK { input, .. }
+            | PhysicalNode::VectorTopK { input, .. }
+            | PhysicalNode::Limit { input, .. }
+            | PhysicalNode::GenericFunction { input, .. }
+            | PhysicalNode::Update { input, .. }
+            | PhysicalNode::Delete { input, .. } => vec![input.as_ref()],
+
+            PhysicalNode::ExistsSubquery { subplan, .. }
+            | PhysicalNode::NotExistsSubquery { subplan, .. }
+            | PhysicalNode::InSubquery { subplan, .. }
+            | PhysicalNode::NotInSubquery { subplan, .. }
+            | PhysicalNode::ScalarSubquery { subplan, .. } => vec![subplan.as_ref()],
+
+            PhysicalNode::Unwind { input, .. } => {
+                input.iter().map(|input| input.as_ref()).collect()
+            }
+
+            PhysicalNode::HashJoin { build, probe, .. } => vec![build.as_ref(), probe.as_ref()],
+
+            PhysicalNode::NestedLoopJoin { left, right, .. }
+            | PhysicalNode::SortMergeJoin { left, right, .. }
+            | PhysicalNode::IndexJoin { left, right, .. }
+            | PhysicalNode::Intersect { left, right, .. }
+            | PhysicalNode::Except { left, right, .. } => vec![left.as_ref(), right.as_ref()],
+
+            PhysicalNode::UnionAll { inputs, .. } => inputs.iter().collect(),
+
+            _ => Vec::new(), // Leaf nodes
+        }
+    }
+
+    /// Get mutable access to the direct inputs of this node, in plan order
+    pub fn children_mut(&mut self) -> Vec<&mut PhysicalNode> {
+        match self {
+            PhysicalNode::IndexedExpand { input, .. }
+            | PhysicalNode::HashExpand { input, .. }
+            | PhysicalNode::PathTraversal { input, .. }
+            | PhysicalNode::GraphScope { input, .. }
+            | PhysicalNode::Filter { input, .. }
+            | PhysicalNode::Project { input, .. }
+            | PhysicalNode::HashAggregate { input, .. }
+            | PhysicalNode::SortAggregate { input, .. }
+            | PhysicalNode::Having { input, .. }
+            | PhysicalNode::ExternalSort { input, .. }
+            | PhysicalNode::InMemorySort { input, .. }
+            | PhysicalNode::Distinct { input, .. }
+            | PhysicalNode::TopK { input, .. }
+            | PhysicalNode::VectorTopK { input, .. }
+            | PhysicalNode::Limit { input, .. }
+            | PhysicalNode::GenericFunction { input, .. }
+            | PhysicalNode::Update { input, .. }
+            | PhysicalNode::Delete { input, .. } => vec![input.as_mut()],
+
+            PhysicalNode::ExistsSubquery { subplan, .. }
+            | PhysicalNode::NotExistsSubquery { subplan, .. }
+            | PhysicalNode::InSubquery { subplan, .. }
+            | PhysicalNode::NotInSubquery { subplan, .. }
+            | PhysicalNode::ScalarSubquery { subplan, .. } => vec![subplan.as_mut()],
+
+            PhysicalNode::Unwind { input, .. } => {
+                input.iter_mut().map(|input| input.as_mut()).collect()
+            }
+
+            PhysicalNode::HashJoin { build, probe, .. } => vec![build.as_mut(), probe.as_mut()],
+
+            PhysicalNode::NestedLoopJoin { left, right, .. }
+            | PhysicalNode::SortMergeJoin { left, right, .. }
+            | PhysicalNode::IndexJoin { left, right, .. }
+            | PhysicalNode::Intersect { left, right, .. }
+            | PhysicalNode::Except { left, right, .. } => vec![left.as_mut(), right.as_mut()],
+
+            PhysicalNode::UnionAll { inputs, .. } => inputs.iter_mut().collect(),
+
+            _ => Vec::new(), // Leaf nodes
+        }
+    }
+
     /// Get the operator type for this node
     fn get_operator_type(&self) -> PhysicalOperator {
         match self {
             PhysicalNode::NodeSeqScan { .. }
             | PhysicalNode::NodeIndexScan { .. }
-            | PhysicalNode::EdgeSeqScan { .. } => PhysicalOperator::Scan,
+            | PhysicalNode::IndexDistinctScan { .. }
+            | PhysicalNode::EdgeSeqScan { .. }
+            | PhysicalNode::GraphScope { .. } => PhysicalOperator::Scan,
 
             PhysicalNode::IndexedExpand { .. } | PhysicalNode::HashExpand { .. } => {
                 PhysicalOperator::Join
@@ -1351,9 +1631,10 @@ impl PhysicalNode {
                 PhysicalOperator::Aggregate
             }
 
-            PhysicalNode::ExternalSort { .. } | PhysicalNode::InMemorySort { .. } => {
-                PhysicalOperator::Sort
-            }
+            PhysicalNode::ExternalSort { .. }
+            | PhysicalNode::InMemorySort { .. }
+            | PhysicalNode::TopK { .. }
+            | PhysicalNode::VectorTopK { .. } => PhysicalOperator::Sort,
 
             PhysicalNode::Distinct { .. } => PhysicalOperator::Sort, // DISTINCT is like sorting with deduplication
 
@@ -1377,3 +1658,15 @@ impl PhysicalNode {
         }
     }
 }
+
+/// Whether an ORDER BY expression is a call to a vector similarity function,
+/// whose numeric score can be ranked by [`PhysicalNode::VectorTopK`]
+fn is_vector_score(expression: &Expression) -> bool {
+    match expression {
+        Expression::FunctionCall(call) => matches!(
+            call.name.to_uppercase().as_str(),
+            "COSINE_SIMILARITY" | "EUCLIDEAN_DISTANCE" | "DOT_PRODUCT"
+        ),
+        _ => false,
+    }
+}
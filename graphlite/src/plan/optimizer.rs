@@ -8,15 +8,17 @@
 
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::sync::Arc;
 use thiserror::Error;
 
-use crate::ast::{Document, Expression, Operator, Query};
+use crate::ast::{Document, Expression, Operator, Query, ScanHint};
 use crate::plan::builders::{LogicalBuilder, PhysicalBuilder};
 use crate::plan::cost::{CostEstimate, CostModel, Statistics};
 use crate::plan::logical::{LogicalNode, LogicalPlan};
-use crate::plan::optimizers::{LogicalOptimizer, PhysicalOptimizer};
-use crate::plan::physical::PhysicalPlan;
+use crate::plan::optimizers::{LogicalOptimizer, PhysicalOptimizer, ScanChoice};
+use crate::plan::physical::{PhysicalNode, PhysicalPlan};
 use crate::plan::trace::{PlanTrace, PlanTracer, PlanningPhase, TraceMetadata};
+use crate::storage::indexes::IndexManager;
 use crate::storage::GraphCache;
 
 /// Main query planner that orchestrates the planning process
@@ -34,6 +36,12 @@ pub struct QueryPlanner {
     logical_optimizer: LogicalOptimizer,
     /// Physical plan optimizer
     physical_optimizer: PhysicalOptimizer,
+    /// Index manager used to check that hinted property indexes exist
+    index_manager: Option<Arc<IndexManager>>,
+    /// Full path of the graph whose indexes hinted scans may use
+    graph_path: Option<String>,
+    /// Warnings raised while planning, such as a hint naming a missing index
+    warnings: Vec<String>,
 }
 
 /// Optimization levels for query planning
@@ -83,6 +91,9 @@ impl QueryPlanner {
             physical_builder: PhysicalBuilder::new(),
             logical_optimizer: LogicalOptimizer::new(optimization_level),
             physical_optimizer: PhysicalOptimizer::new(avoid_index_scan),
+            index_manager: None,
+            graph_path: None,
+            warnings: Vec::new(),
         }
     }
 
@@ -100,9 +111,29 @@ impl QueryPlanner {
             physical_builder: PhysicalBuilder::new(),
             logical_optimizer: LogicalOptimizer::new(level),
             physical_optimizer: PhysicalOptimizer::new(avoid_index_scan),
+            index_manager: None,
+            graph_path: None,
+            warnings: Vec::new(),
         }
     }
 
+    /// Check hinted property indexes against this index manager
+    pub fn with_index_manager(mut self, index_manager: Arc<IndexManager>) -> Self {
+        self.index_manager = Some(index_manager);
+        self
+    }
+
+    /// Check hinted property indexes against the indexes of this graph
+    pub fn with_graph(mut self, graph_path: Option<String>) -> Self {
+        self.graph_path = graph_path;
+        self
+    }
+
+    /// Warnings raised while planning the last query
+    pub fn warnings(&self) -> &[String] {
+        &self.warnings
+    }
+
     /// Update statistics from a graph
     #[allow(dead_code)] // ROADMAP v0.5.0 - Statistics-driven query optimization
     pub fn update_statistics(&mut self, graph: &GraphCache) {
@@ -158,6 +189,9 @@ impl QueryPlanner {
             physical_builder: PhysicalBuilder::new(),
             logical_optimizer: LogicalOptimizer::new(optimization_level),
             physical_optimizer: PhysicalOptimizer::new(avoid_index_scan),
+            index_manager: None,
+            graph_path: None,
+            warnings: Vec::new(),
         }
     }
 
@@ -173,8 +207,13 @@ impl QueryPlanner {
             }
         };
 
+        self.resolve_scan_hints(query);
+
         // Generate logical plan
-        let logical_plan = self.create_logical_plan(query)?;
+        let logical_plan =
+            tracing::info_span!("plan").in_scope(|| self.create_logical_plan(query))?;
+
+        let _optimize_span = tracing::info_span!("optimize").entered();
 
         // Optimize logical plan
         let mut optimized_logical = self.optimize_logical_plan(logical_plan)?;
@@ -219,6 +258,8 @@ impl QueryPlanner {
             }
         };
 
+        self.resolve_scan_hints(query);
+
         // Generate logical plan
         tracer.start_step(
             PlanningPhase::LogicalPlanGeneration,
@@ -307,6 +348,64 @@ impl QueryPlanner {
         Ok(tracer.finalize(optimized_logical, optimized_physical))
     }
 
+    /// Pass the query's USING hints on to the physical optimizer
+    ///
+    /// An index hint falls back to the planner's own choice, with a warning,
+    /// when no index of the planner's graph covers the hinted label and property.
+    fn resolve_scan_hints(&mut self, query: &Query) {
+        let mut hints = Vec::new();
+        Self::collect_scan_hints(query, &mut hints);
+
+        self.warnings.clear();
+        let mut scan_hints = HashMap::new();
+        for hint in hints {
+            match hint {
+                ScanHint::Index {
+                    variable,
+                    label,
+                    property,
+                } => {
+                    let index_exists = match (&self.index_manager, &self.graph_path) {
+                        (Some(manager), Some(graph_path)) => manager
+                            .find_index_by_label_and_property(graph_path, label, property)
+                            .is_some(),
+                        _ => false,
+                    };
+                    if index_exists {
+                        scan_hints.insert(variable.clone(), ScanChoice::Index);
+                    } else {
+                        self.warnings.push(format!(
+                            "Ignoring hint USING INDEX {}:{}({}): no index exists on {}({})",
+                            variable, label, property, label, property
+                        ));
+                    }
+                }
+                ScanHint::Scan { variable } => {
+                    scan_hints.insert(variable.clone(), ScanChoice::Sequential);
+                }
+            }
+        }
+        self.physical_optimizer.set_scan_hints(scan_hints);
+    }
+
+    /// Collect the USING hints of every MATCH clause in a query
+    fn collect_scan_hints<'a>(query: &'a Query, hints: &mut Vec<&'a ScanHint>) {
+        match query {
+            Query::Basic(basic) => hints.extend(&basic.match_clause.hints),
+            Query::SetOperation(set_op) => {
+                Self::collect_scan_hints(&set_op.left, hints);
+                Self::collect_scan_hints(&set_op.right, hints);
+            }
+            Query::Limited { query, .. } => Self::collect_scan_hints(query, hints),
+            Query::WithQuery(with_query) => {
+                for segment in &with_query.segments {
+                    hints.extend(&segment.match_clause.hints);
+                }
+            }
+            _ => {}
+        }
+    }
+
     /// Get the name of the current optimization level
     fn optimization_level_name(&self) -> String {
         match self.optimization_level {
@@ -396,7 +495,164 @@ impl QueryPlanner {
     /// Optimize physical plan
     fn optimize_physical_plan(&self, plan: PhysicalPlan) -> Result<PhysicalPlan, PlanningError> {
         // Delegate to PhysicalOptimizer
-        self.physical_optimizer.optimize(plan)
+        let mut optimized = self.physical_optimizer.optimize(plan)?;
+        Self::push_equalities_into_index_scans(&mut optimized.root);
+        Ok(self.use_index_distinct_scan(optimized))
+    }
+
+    /// Hand each index scan the property equalities of the filter above it
+    ///
+    /// In `MATCH (p:Person) WHERE p.email = $email`, the scan of `p` records
+    /// `email = $email` so that an index on Person(email) can find the
+    /// matching nodes. The filter still checks every row the scan returns.
+    fn push_equalities_into_index_scans(node: &mut PhysicalNode) {
+        if let PhysicalNode::Filter {
+            condition, input, ..
+        } = node
+        {
+            if let PhysicalNode::NodeIndexScan {
+                variable,
+                equalities,
+                ..
+            } = input.as_mut()
+            {
+                *equalities = Self::property_equalities(variable, condition)
+                    .into_iter()
+                    .map(|(property, value)| (property.to_string(), value.clone()))
+                    .collect();
+            }
+        }
+        for child in node.children_mut() {
+            Self::push_equalities_into_index_scans(child);
+        }
+    }
+
+    /// Equalities `variable.property = value` that must hold for a condition to
+    /// be true, where the value is a literal or a parameter
+    fn property_equalities<'a>(
+        variable: &str,
+        condition: &'a Expression,
+    ) -> Vec<(&'a str, &'a Expression)> {
+        let Expression::Binary(binary) = condition else {
+            return Vec::new();
+        };
+        match binary.operator {
+            Operator::And => {
+                let mut equalities = Self::property_equalities(variable, &binary.left);
+                equalities.extend(Self::property_equalities(variable, &binary.right));
+                equalities
+            }
+            Operator::Equal => match (binary.left.as_ref(), binary.right.as_ref()) {
+                (
+                    Expression::PropertyAccess(access),
+                    value @ (Expression::Literal(_) | Expression::Parameter(_)),
+                )
+                | (
+                    value @ (Expression::Literal(_) | Expression::Parameter(_)),
+                    Expression::PropertyAccess(access),
+                ) if access.object == *variable => {
+                    vec![(access.property.as_str(), value)]
+                }
+                _ => Vec::new(),
+            },
+            _ => Vec::new(),
+        }
+    }
+
+    /// Read the values of a DISTINCT projection of one indexed property from
+    /// its index
+    ///
+    /// `MATCH (p:Person) RETURN DISTINCT p.city` scans every person and then
+    /// removes duplicate cities. When the planner's graph has an index on
+    /// Person(city), the scan and the Distinct are replaced by an
+    /// IndexDistinctScan that yields each city once. Any other plan is kept.
+    fn use_index_distinct_scan(&self, mut plan: PhysicalPlan) -> PhysicalPlan {
+        // ORDER BY, SKIP and LIMIT apply to the distinct rows
+        let mut node = &mut plan.root;
+        while let PhysicalNode::Limit { input, .. }
+        | PhysicalNode::InMemorySort { input, .. }
+        | PhysicalNode::ExternalSort { input, .. }
+        | PhysicalNode::TopK { input, .. } = node
+        {
+            node = input;
+        }
+
+        match self.index_distinct_scan(node) {
+            Some(replacement) => {
+                *node = replacement;
+                PhysicalPlan::new(plan.root)
+            }
+            None => plan,
+        }
+    }
+
+    /// Build the IndexDistinctScan replacing `Distinct(Project(scan))`, when
+    /// the projection is a single property of the scanned label and an index
+    /// of the planner's graph covers it
+    fn index_distinct_scan(&self, node: &PhysicalNode) -> Option<PhysicalNode> {
+        let (Some(manager), Some(graph_path)) = (&self.index_manager, &self.graph_path) else {
+            return None;
+        };
+        let PhysicalNode::Distinct {
+            input,
+            estimated_rows,
+            ..
+        } = node
+        else {
+            return None;
+        };
+        let PhysicalNode::Project {
+            expressions,
+            input: scan,
+            ..
+        } = input.as_ref()
+        else {
+            return None;
+        };
+        let [item] = expressions.as_slice() else {
+            return None;
+        };
+        let Expression::PropertyAccess(access) = &item.expression else {
+            return None;
+        };
+        let (PhysicalNode::NodeSeqScan {
+            variable,
+            labels,
+            properties,
+            ..
+        }
+        | PhysicalNode::NodeIndexScan {
+            variable,
+            labels,
+            properties,
+            ..
+        }) = scan.as_ref()
+        else {
+            return None;
+        };
+        let [label] = labels.as_slice() else {
+            return None;
+        };
+        if *variable != access.object || properties.as_ref().is_some_and(|p| !p.is_empty()) {
+            return None;
+        }
+
+        let index_name =
+            manager.find_index_by_label_and_property(graph_path, label, &access.property)?;
+        let scan = PhysicalNode::IndexDistinctScan {
+            variable: variable.clone(),
+            label: label.clone(),
+            property: access.property.clone(),
+            index_name,
+            estimated_rows: *estimated_rows,
+            estimated_cost: *estimated_rows as f64 * 0.01,
+        };
+        Some(PhysicalNode::Project {
+            expressions: expressions.clone(),
+            estimated_cost: scan.get_cost() + (*estimated_rows as f64 * 0.005),
+            input: Box::new(scan),
+            estimated_rows: *estimated_rows,
+        })
     }
 
     /// Generate alternative join orders
@@ -439,13 +695,11 @@ impl QueryPlanner {
     }
 
     /// Get planning statistics
-    #[allow(dead_code)] // ROADMAP v0.3.0 - Statistics accessor for cost model (see ROADMAP.md §5)
     pub fn get_statistics(&self) -> &Statistics {
         &self.statistics
     }
 
     /// Get cost model
-    #[allow(dead_code)] // ROADMAP v0.3.0 - Cost model accessor for plan estimation (see ROADMAP.md §5)
     pub fn get_cost_model(&self) -> &CostModel {
         &self.cost_model
     }
@@ -220,6 +220,11 @@ impl LogicalOptimizer {
                 input: Box::new(self.optimize_logical_node(*input)?),
             }),
 
+            LogicalNode::GraphScope { graph, input } => Ok(LogicalNode::GraphScope {
+                graph,
+                input: Box::new(self.optimize_logical_node(*input)?),
+            }),
+
             LogicalNode::Distinct { input } => Ok(LogicalNode::Distinct {
                 input: Box::new(self.optimize_logical_node(*input)?),
             }),
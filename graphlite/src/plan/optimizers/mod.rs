@@ -14,4 +14,4 @@ pub mod physical_optimizer;
 
 // Re-export for convenience
 pub use logical_optimizer::LogicalOptimizer;
-pub use physical_optimizer::PhysicalOptimizer;
+pub use physical_optimizer::{PhysicalOptimizer, ScanChoice};
@@ -10,19 +10,40 @@
 //!
 //! Extracted from optimizer.rs as part of Phase 3 refactoring.
 
+use std::collections::HashMap;
+
 use crate::plan::optimizer::PlanningError;
 use crate::plan::physical::{PhysicalNode, PhysicalPlan};
 
+/// Scan method a query hint forces for a node variable
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScanChoice {
+    /// USING INDEX: keep or introduce a NodeIndexScan
+    Index,
+    /// USING SCAN: use a NodeSeqScan
+    Sequential,
+}
+
 /// Optimizer for physical plans
 #[derive(Debug)]
 pub struct PhysicalOptimizer {
     avoid_index_scan: bool,
+    /// Scan choices forced by query hints, keyed by node variable
+    scan_hints: HashMap<String, ScanChoice>,
 }
 
 impl PhysicalOptimizer {
     /// Create a new physical optimizer
     pub fn new(avoid_index_scan: bool) -> Self {
-        Self { avoid_index_scan }
+        Self {
+            avoid_index_scan,
+            scan_hints: HashMap::new(),
+        }
+    }
+
+    /// Force the scan method of node variables, overriding the index scan setting
+    pub fn set_scan_hints(&mut self, scan_hints: HashMap<String, ScanChoice>) {
+        self.scan_hints = scan_hints;
     }
 
     /// Optimize a physical plan
@@ -30,8 +51,8 @@ impl PhysicalOptimizer {
     pub fn optimize(&self, plan: PhysicalPlan) -> Result<PhysicalPlan, PlanningError> {
         let mut optimized_plan = plan;
 
-        // Apply index scan optimization based on setting
-        if self.avoid_index_scan {
+        // Apply index scan optimization based on setting and hints
+        if self.avoid_index_scan || !self.scan_hints.is_empty() {
             optimized_plan = self.disable_index_scans(optimized_plan)?;
         }
 
@@ -49,20 +70,37 @@ impl PhysicalOptimizer {
     }
 
     /// Recursively transform physical nodes to disable index scans
+    /// Node scans of hinted variables use the hinted scan method instead.
     /// Originally: optimizer.rs line 2143
     fn transform_node_disable_indexes(
         &self,
         node: PhysicalNode,
     ) -> Result<PhysicalNode, PlanningError> {
         match node {
-            // Replace NodeIndexScan with NodeSeqScan
+            // Replace NodeIndexScan with NodeSeqScan unless hinted otherwise
             PhysicalNode::NodeIndexScan {
                 variable,
                 labels,
                 properties,
+                equalities,
                 estimated_rows,
-                ..
+                estimated_cost,
             } => {
+                let use_index = match self.scan_hints.get(&variable) {
+                    Some(choice) => *choice == ScanChoice::Index,
+                    None => !self.avoid_index_scan,
+                };
+                if use_index {
+                    return Ok(PhysicalNode::NodeIndexScan {
+                        variable,
+                        labels,
+                        properties,
+                        equalities,
+                        estimated_rows,
+                        estimated_cost,
+                    });
+                }
+
                 // Sequential scan typically has higher cost than index scan
                 let estimated_cost = estimated_rows as f64 * 0.1;
                 Ok(PhysicalNode::NodeSeqScan {
@@ -74,6 +112,24 @@ impl PhysicalOptimizer {
                 })
             }
 
+            // Replace NodeSeqScan with NodeIndexScan when hinted
+            PhysicalNode::NodeSeqScan {
+                variable,
+                labels,
+                properties,
+                estimated_rows,
+                estimated_cost,
+            } if self.scan_hints.get(&variable) == Some(&ScanChoice::Index) => {
+                Ok(PhysicalNode::NodeIndexScan {
+                    variable,
+                    labels,
+                    properties,
+                    equalities: Vec::new(),
+                    estimated_rows,
+                    estimated_cost: estimated_cost * 0.5, // Cheaper with index
+                })
+            }
+
             // Replace IndexedExpand with HashExpand (non-indexed expansion)
             PhysicalNode::IndexedExpand {
                 from_variable,
@@ -84,9 +140,23 @@ impl PhysicalOptimizer {
                 properties,
                 input,
                 estimated_rows,
-                ..
+                estimated_cost,
             } => {
                 let transformed_input = Box::new(self.transform_node_disable_indexes(*input)?);
+                if !self.avoid_index_scan {
+                    // Only scan hints are being applied
+                    return Ok(PhysicalNode::IndexedExpand {
+                        from_variable,
+                        edge_variable,
+                        to_variable,
+                        edge_labels,
+                        direction,
+                        properties,
+                        input: transformed_input,
+                        estimated_rows,
+                        estimated_cost,
+                    });
+                }
                 let estimated_cost = estimated_rows as f64 * 0.3; // Higher cost without index
                 Ok(PhysicalNode::HashExpand {
                     from_variable,
@@ -102,6 +172,21 @@ impl PhysicalOptimizer {
             }
 
             // Recursively transform nodes with single input
+            PhysicalNode::GraphScope {
+                graph,
+                input,
+                estimated_rows,
+                estimated_cost,
+            } => {
+                let transformed_input = Box::new(self.transform_node_disable_indexes(*input)?);
+                Ok(PhysicalNode::GraphScope {
+                    graph,
+                    input: transformed_input,
+                    estimated_rows,
+                    estimated_cost,
+                })
+            }
+
             PhysicalNode::Filter {
                 condition,
                 input,
@@ -213,6 +298,46 @@ impl PhysicalOptimizer {
                 })
             }
 
+            PhysicalNode::TopK {
+                expressions,
+                count,
+                offset,
+                input,
+                estimated_rows,
+                estimated_cost,
+            } => {
+                let transformed_input = Box::new(self.transform_node_disable_indexes(*input)?);
+                Ok(PhysicalNode::TopK {
+                    expressions,
+                    count,
+                    offset,
+                    input: transformed_input,
+                    estimated_rows,
+                    estimated_cost,
+                })
+            }
+
+            PhysicalNode::VectorTopK {
+                score,
+                ascending,
+                count,
+                offset,
+                input,
+                estimated_rows,
+                estimated_cost,
+            } => {
+                let transformed_input = Box::new(self.transform_node_disable_indexes(*input)?);
+                Ok(PhysicalNode::VectorTopK {
+                    score,
+                    ascending,
+                    count,
+                    offset,
+                    input: transformed_input,
+                    estimated_rows,
+                    estimated_cost,
+                })
+            }
+
             PhysicalNode::Limit {
                 count,
                 offset,
@@ -353,6 +478,7 @@ impl PhysicalOptimizer {
 
             // Nodes that are already non-indexed (no transformation needed)
             PhysicalNode::NodeSeqScan { .. }
+            | PhysicalNode::IndexDistinctScan { .. }
             | PhysicalNode::EdgeSeqScan { .. }
             | PhysicalNode::HashExpand { .. }
             | PhysicalNode::GenericFunction { .. }
@@ -572,6 +698,7 @@ mod tests {
             variable: "n".to_string(),
             labels: vec!["Person".to_string()],
             properties: None,
+            equalities: Vec::new(),
             estimated_rows: 100,
             estimated_cost: 5.0,
         });
@@ -597,6 +724,7 @@ mod tests {
             variable: "n".to_string(),
             labels: vec!["Person".to_string()],
             properties: None,
+            equalities: Vec::new(),
             estimated_rows: 100,
             estimated_cost: 5.0,
         });
@@ -657,6 +785,26 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_scan_hint_overrides_index_scan_setting() {
+        let mut optimizer = PhysicalOptimizer::new(true);
+        optimizer.set_scan_hints(HashMap::from([("n".to_string(), ScanChoice::Index)]));
+
+        let plan = PhysicalPlan::new(PhysicalNode::NodeSeqScan {
+            variable: "n".to_string(),
+            labels: vec!["Person".to_string()],
+            properties: None,
+            estimated_rows: 100,
+            estimated_cost: 10.0,
+        });
+
+        let transformed = optimizer.optimize(plan).unwrap();
+        assert!(matches!(
+            transformed.root,
+            PhysicalNode::NodeIndexScan { ref variable, .. } if variable == "n"
+        ));
+    }
+
     #[test]
     fn test_disable_index_scans_preserves_seq_scan() {
         let optimizer = PhysicalOptimizer::new(true);
@@ -691,6 +839,7 @@ mod tests {
                 variable: "n".to_string(),
                 labels: vec![],
                 properties: None,
+                equalities: Vec::new(),
                 estimated_rows: 100,
                 estimated_cost: 5.0,
             }),
@@ -728,6 +877,7 @@ mod tests {
                 variable: "n".to_string(),
                 labels: vec![],
                 properties: None,
+                equalities: Vec::new(),
                 estimated_rows: 100,
                 estimated_cost: 5.0,
             }),
@@ -735,6 +885,7 @@ mod tests {
                 variable: "m".to_string(),
                 labels: vec![],
                 properties: None,
+                equalities: Vec::new(),
                 estimated_rows: 100,
                 estimated_cost: 5.0,
             }),
@@ -393,6 +393,7 @@ mod tests {
         let pattern = PathPattern {
             assignment: None,
             path_type: None,
+            graph: None,
             elements: vec![],
             location: Location::default(),
         };
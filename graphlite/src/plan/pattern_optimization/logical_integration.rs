@@ -507,6 +507,7 @@ mod tests {
         let pattern = PathPattern {
             assignment: None,
             path_type: None,
+            graph: None,
             elements: vec![],
             location: Location::default(),
         };
@@ -526,6 +527,7 @@ mod tests {
             .map(|_| PathPattern {
                 assignment: None,
                 path_type: None,
+                graph: None,
                 elements: vec![],
                 location: Location::default(),
             })
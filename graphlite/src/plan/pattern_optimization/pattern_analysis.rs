@@ -345,11 +345,14 @@ mod tests {
         let start_pattern = PathPattern {
             assignment: None,
             path_type: None,
+            graph: None,
             elements: vec![
                 PatternElement::Node(Node {
                     identifier: Some("a".to_string()),
                     labels: vec![],
+                    label_expression: None,
                     properties: None,
+                    predicate: None,
                     location: Location::default(),
                 }),
                 PatternElement::Edge(Edge {
@@ -358,12 +361,15 @@ mod tests {
                     properties: None,
                     direction: crate::ast::EdgeDirection::Outgoing,
                     quantifier: None,
+                    predicate: None,
                     location: Location::default(),
                 }),
                 PatternElement::Node(Node {
                     identifier: Some("b".to_string()),
                     labels: vec![],
+                    label_expression: None,
                     properties: None,
+                    predicate: None,
                     location: Location::default(),
                 }),
             ],
@@ -378,6 +384,7 @@ mod tests {
                 properties: None,
                 direction: crate::ast::EdgeDirection::Outgoing,
                 quantifier: None,
+                predicate: None,
                 location: Location::default(),
             },
             to_var: "c".to_string(),
@@ -410,6 +417,7 @@ mod tests {
             start_pattern: PathPattern {
                 assignment: None,
                 path_type: None,
+                graph: None,
                 elements: vec![],
                 location: Location::default(),
             },
@@ -421,6 +429,7 @@ mod tests {
                     properties: None,
                     direction: crate::ast::EdgeDirection::Outgoing,
                     quantifier: None,
+                    predicate: None,
                     location: Location::default(),
                 },
                 to_var: "m".to_string(),
@@ -452,7 +452,9 @@ mod tests {
             } else {
                 vec![label.to_string()]
             },
+            label_expression: None,
             properties: None,
+            predicate: None,
             location: Location::default(),
         })
     }
@@ -464,6 +466,7 @@ mod tests {
             properties: None,
             direction: EdgeDirection::Outgoing,
             quantifier: None,
+            predicate: None,
             location: Location::default(),
         })
     }
@@ -472,6 +475,7 @@ mod tests {
         PathPattern {
             assignment: None,
             path_type: None,
+            graph: None,
             elements,
             location: Location::default(),
         }
@@ -6,6 +6,7 @@
 //! This module provides tracing capabilities to capture the query planning
 //! process for debugging and explanation purposes.
 
+use crate::ast::EdgeDirection;
 use crate::plan::cost::CostEstimate;
 use crate::plan::logical::{LogicalNode, LogicalPlan};
 use crate::plan::physical::{PhysicalNode, PhysicalPlan};
@@ -254,6 +255,24 @@ impl PlanTrace {
                     labels.len()
                 ));
             }
+            PhysicalNode::IndexDistinctScan {
+                variable,
+                label,
+                property,
+                index_name,
+                estimated_rows,
+                estimated_cost,
+            } => {
+                output.push_str(&format!(
+                    "{}IndexDistinctScan[{}:{}({})] → {} rows, cost: {:.1}\n",
+                    prefix, variable, label, property, estimated_rows, estimated_cost
+                ));
+                output.push_str(&format!(
+                    "{}    Index: {}\n",
+                    " ".repeat(prefix.len()),
+                    index_name
+                ));
+            }
             PhysicalNode::EdgeSeqScan {
                 variable,
                 labels,
@@ -337,6 +356,18 @@ impl PlanTrace {
                 ));
                 self.format_plan_node(input, output, depth + 1, true);
             }
+            PhysicalNode::GraphScope {
+                graph,
+                input,
+                estimated_rows,
+                estimated_cost,
+            } => {
+                output.push_str(&format!(
+                    "{}GraphScope[{}] → {} rows, cost: {:.1}\n",
+                    prefix, graph, estimated_rows, estimated_cost
+                ));
+                self.format_plan_node(input, output, depth + 1, true);
+            }
             PhysicalNode::Filter {
                 condition,
                 input,
@@ -433,6 +464,57 @@ impl PlanTrace {
                 ));
                 self.format_plan_node(input, output, depth + 1, true);
             }
+            PhysicalNode::TopK {
+                expressions,
+                count,
+                offset,
+                input,
+                estimated_rows,
+                estimated_cost,
+            } => {
+                let offset_str = offset.map(|o| format!(" OFFSET {}", o)).unwrap_or_default();
+                output.push_str(&format!(
+                    "{}TopK[{} columns, LIMIT {}{}] → {} rows, cost: {:.1}\n",
+                    prefix,
+                    expressions.len(),
+                    count,
+                    offset_str,
+                    estimated_rows,
+                    estimated_cost
+                ));
+                output.push_str(&format!(
+                    "{}    Method: Bounded heap ({} rows kept)\n",
+                    " ".repeat(prefix.len()),
+                    count + offset.unwrap_or(0)
+                ));
+                self.format_plan_node(input, output, depth + 1, true);
+            }
+            PhysicalNode::VectorTopK {
+                ascending,
+                count,
+                offset,
+                input,
+                estimated_rows,
+                estimated_cost,
+                ..
+            } => {
+                let offset_str = offset.map(|o| format!(" OFFSET {}", o)).unwrap_or_default();
+                output.push_str(&format!(
+                    "{}VectorTopK[{}, LIMIT {}{}] → {} rows, cost: {:.1}\n",
+                    prefix,
+                    if *ascending { "ASC" } else { "DESC" },
+                    count,
+                    offset_str,
+                    estimated_rows,
+                    estimated_cost
+                ));
+                output.push_str(&format!(
+                    "{}    Method: Score min-heap ({} rows kept)\n",
+                    " ".repeat(prefix.len()),
+                    count + offset.unwrap_or(0)
+                ));
+                self.format_plan_node(input, output, depth + 1, true);
+            }
             PhysicalNode::Limit {
                 count,
                 offset,
@@ -774,6 +856,10 @@ impl PlanTrace {
                 output.push_str(&format!("{}Limit({}, {:?})\n", prefix, count, offset));
                 output.push_str(&self.format_logical_plan(input, indent + 1));
             }
+            LogicalNode::GraphScope { graph, input } => {
+                output.push_str(&format!("{}GraphScope({})\n", prefix, graph));
+                output.push_str(&self.format_logical_plan(input, indent + 1));
+            }
             LogicalNode::SingleRow => {
                 output.push_str(&format!("{}SingleRow()\n", prefix));
             }
@@ -823,6 +909,19 @@ impl PlanTrace {
                     estimated_cost
                 ));
             }
+            PhysicalNode::IndexDistinctScan {
+                variable,
+                label,
+                property,
+                index_name,
+                estimated_rows,
+                estimated_cost,
+            } => {
+                output.push_str(&format!(
+                    "{}IndexDistinctScan({}:{}({}) using {}) [rows={}, cost={:.2}]\n",
+                    prefix, variable, label, property, index_name, estimated_rows, estimated_cost
+                ));
+            }
             PhysicalNode::EdgeSeqScan {
                 variable,
                 labels,
@@ -879,6 +978,18 @@ impl PlanTrace {
                 ));
                 output.push_str(&self.format_physical_plan(input, indent + 1));
             }
+            PhysicalNode::GraphScope {
+                graph,
+                input,
+                estimated_rows,
+                estimated_cost,
+            } => {
+                output.push_str(&format!(
+                    "{}GraphScope({}) [rows={}, cost={:.2}]\n",
+                    prefix, graph, estimated_rows, estimated_cost
+                ));
+                output.push_str(&self.format_physical_plan(input, indent + 1));
+            }
             PhysicalNode::Filter {
                 input,
                 selectivity,
@@ -940,6 +1051,45 @@ impl PlanTrace {
                 ));
                 output.push_str(&self.format_physical_plan(input, indent + 1));
             }
+            PhysicalNode::TopK {
+                expressions,
+                count,
+                offset,
+                input,
+                estimated_rows,
+                estimated_cost,
+            } => {
+                output.push_str(&format!(
+                    "{}TopK({} cols, {}, {:?}) [rows={}, cost={:.2}]\n",
+                    prefix,
+                    expressions.len(),
+                    count,
+                    offset,
+                    estimated_rows,
+                    estimated_cost
+                ));
+                output.push_str(&self.format_physical_plan(input, indent + 1));
+            }
+            PhysicalNode::VectorTopK {
+                ascending,
+                count,
+                offset,
+                input,
+                estimated_rows,
+                estimated_cost,
+                ..
+            } => {
+                output.push_str(&format!(
+                    "{}VectorTopK({}, {}, {:?}) [rows={}, cost={:.2}]\n",
+                    prefix,
+                    if *ascending { "ASC" } else { "DESC" },
+                    count,
+                    offset,
+                    estimated_rows,
+                    estimated_cost
+                ));
+                output.push_str(&self.format_physical_plan(input, indent + 1));
+            }
             PhysicalNode::Limit {
                 count,
                 offset,
@@ -1079,3 +1229,387 @@ impl TraceMetadata {
         }
     }
 }
+
+/// Planner estimates for a single physical operator, for EXPLAIN
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EstimatedNode {
+    pub operator: String,
+    pub estimated_rows: usize,
+    /// Cost model estimate for this operator, including its inputs
+    pub estimated_cost: f64,
+    pub children: Vec<EstimatedNode>,
+}
+
+impl EstimatedNode {
+    /// Find the first operator with the given name in this subtree
+    pub fn find(&self, operator: &str) -> Option<&EstimatedNode> {
+        if self.operator == operator {
+            return Some(self);
+        }
+        self.children.iter().find_map(|child| child.find(operator))
+    }
+
+    /// Format the operator tree with each operator's estimates
+    pub fn format_tree(&self) -> String {
+        let mut output = String::new();
+        self.format_node(&mut output, 0);
+        output
+    }
+
+    fn format_node(&self, output: &mut String, depth: usize) {
+        output.push_str(&format!(
+            "{}{} (estimated rows={}, cost={:.2})\n",
+            "  ".repeat(depth),
+            self.operator,
+            self.estimated_rows,
+            self.estimated_cost
+        ));
+        for child in &self.children {
+            child.format_node(output, depth + 1);
+        }
+    }
+}
+
+/// Measured execution of a single physical operator, for EXPLAIN ANALYZE
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AnalyzedNode {
+    pub operator: String,
+    pub estimated_rows: usize,
+    pub estimated_cost: f64,
+    pub actual_rows: usize,
+    /// Most rows the operator held at once, for operators that report it
+    pub rows_held: Option<usize>,
+    /// Wall-clock time spent in this operator, including its inputs
+    pub elapsed: Duration,
+    pub children: Vec<AnalyzedNode>,
+}
+
+impl AnalyzedNode {
+    /// Time spent in this operator alone, excluding its inputs
+    pub fn self_time(&self) -> Duration {
+        let children: Duration = self.children.iter().map(|child| child.elapsed).sum();
+        self.elapsed.saturating_sub(children)
+    }
+
+    /// Find the first operator with the given name in this subtree
+    pub fn find(&self, operator: &str) -> Option<&AnalyzedNode> {
+        if self.operator == operator {
+            return Some(self);
+        }
+        self.children.iter().find_map(|child| child.find(operator))
+    }
+
+    /// Format the measured operator tree next to the planner's estimates
+    pub fn format_tree(&self) -> String {
+        let mut output = String::new();
+        self.format_node(&mut output, 0);
+        output
+    }
+
+    fn format_node(&self, output: &mut String, depth: usize) {
+        let rows_held = self
+            .rows_held
+            .map(|rows| format!(", rows held={}", rows))
+            .unwrap_or_default();
+        output.push_str(&format!(
+            "{}{} (estimated rows={}, cost={:.2}) (actual rows={}{}, time={:.3}ms)\n",
+            "  ".repeat(depth),
+            self.operator,
+            self.estimated_rows,
+            self.estimated_cost,
+            self.actual_rows,
+            rows_held,
+            self.elapsed.as_secs_f64() * 1000.0
+        ));
+        for child in &self.children {
+            child.format_node(output, depth + 1);
+        }
+    }
+}
+
+/// Render a physical operator tree as a Graphviz DOT digraph
+///
+/// Every operator becomes one DOT node labelled with its name, its key
+/// attributes and its estimated rows and cost; every input relationship
+/// becomes an edge from the input to the operator consuming it. When
+/// `estimates` is given (a tree built from the same plan, as EXPLAIN does)
+/// its cost model figures replace the planner's own.
+pub fn plan_to_dot(root: &PhysicalNode, estimates: Option<&EstimatedNode>) -> String {
+    let mut output = String::from("digraph plan {\n");
+    output.push_str("  rankdir=BT;\n");
+    output.push_str("  node [shape=box, fontname=\"monospace\"];\n");
+    let mut next_id = 0;
+    write_dot_node(root, estimates, &mut output, &mut next_id);
+    output.push_str("}\n");
+    output
+}
+
+/// Write one operator and its inputs, returning the operator's DOT id
+fn write_dot_node(
+    node: &PhysicalNode,
+    estimate: Option<&EstimatedNode>,
+    output: &mut String,
+    next_id: &mut usize,
+) -> usize {
+    let id = *next_id;
+    *next_id += 1;
+
+    let (rows, cost) = match estimate {
+        Some(estimate) => (estimate.estimated_rows, estimate.estimated_cost),
+        None => (node.get_row_count(), node.get_cost()),
+    };
+    let mut lines = vec![node.operator_name().to_string()];
+    lines.extend(dot_attributes(node));
+    lines.push(format!("rows={}, cost={:.2}", rows, cost));
+    let label = lines
+        .iter()
+        .map(|line| escape_dot(line))
+        .collect::<Vec<_>>()
+        .join("\\n");
+    output.push_str(&format!("  n{} [label=\"{}\"];\n", id, label));
+
+    for (index, child) in node.children().into_iter().enumerate() {
+        let child_estimate = estimate.and_then(|estimate| estimate.children.get(index));
+        let child_id = write_dot_node(child, child_estimate, output, next_id);
+        output.push_str(&format!("  n{} -> n{};\n", child_id, id));
+    }
+    id
+}
+
+/// Short description of the attributes that distinguish an operator
+fn dot_attributes(node: &PhysicalNode) -> Vec<String> {
+    match node {
+        PhysicalNode::NodeSeqScan {
+            variable,
+            labels,
+            properties,
+            ..
+        }
+        | PhysicalNode::NodeIndexScan {
+            variable,
+            labels,
+            properties,
+            ..
+        }
+        | PhysicalNode::EdgeSeqScan {
+            variable,
+            labels,
+            properties,
+            ..
+        } => {
+            let mut attributes = vec![if labels.is_empty() {
+                variable.clone()
+            } else {
+                format!("{}:{}", variable, labels.join("|"))
+            }];
+            if let Some(properties) = properties.as_ref().filter(|p| !p.is_empty()) {
+                let mut keys: Vec<&str> = properties.keys().map(String::as_str).collect();
+                keys.sort_unstable();
+                attributes.push(format!("properties: {}", keys.join(", ")));
+            }
+            attributes
+        }
+        PhysicalNode::IndexedExpand {
+            from_variable,
+            edge_variable,
+            to_variable,
+            edge_labels,
+            direction,
+            ..
+        }
+        | PhysicalNode::HashExpand {
+            from_variable,
+            edge_variable,
+            to_variable,
+            edge_labels,
+            direction,
+            ..
+        } => {
+            let mut edge = edge_variable.clone().unwrap_or_default();
+            if !edge_labels.is_empty() {
+                edge.push(':');
+                edge.push_str(&edge_labels.join("|"));
+            }
+            let (left, right) = match direction {
+                EdgeDirection::Outgoing => ("-", "->"),
+                EdgeDirection::Incoming => ("<-", "-"),
+                EdgeDirection::Both => ("<-", "->"),
+                EdgeDirection::Undirected => ("-", "-"),
+            };
+            vec![format!(
+                "({}){}[{}]{}({})",
+                from_variable, left, edge, right, to_variable
+            )]
+        }
+        PhysicalNode::PathTraversal {
+            path_type,
+            from_variable,
+            to_variable,
+            ..
+        } => vec![format!(
+            "{:?} ({})..({})",
+            path_type, from_variable, to_variable
+        )],
+        PhysicalNode::GraphScope { graph, .. } => vec![format!("graph: {}", graph)],
+        PhysicalNode::Filter { selectivity, .. } => {
+            vec![format!("selectivity: {:.3}", selectivity)]
+        }
+        PhysicalNode::Project { expressions, .. } => {
+            let columns: Vec<String> = expressions
+                .iter()
+                .enumerate()
+                .map(|(index, item)| {
+                    item.alias
+                        .clone()
+                        .unwrap_or_else(|| format!("column_{}", index + 1))
+                })
+                .collect();
+            vec![format!("columns: {}", columns.join(", "))]
+        }
+        PhysicalNode::HashJoin { join_type, .. }
+        | PhysicalNode::NestedLoopJoin { join_type, .. }
+        | PhysicalNode::SortMergeJoin { join_type, .. } => vec![format!("{:?}", join_type)],
+        PhysicalNode::IndexJoin {
+            join_type,
+            index_name,
+            ..
+        } => vec![format!("{:?}", join_type), format!("index: {}", index_name)],
+        PhysicalNode::UnionAll { all, .. }
+        | PhysicalNode::Intersect { all, .. }
+        | PhysicalNode::Except { all, .. } => {
+            vec![if *all { "ALL" } else { "DISTINCT" }.to_string()]
+        }
+        PhysicalNode::HashAggregate {
+            group_by,
+            aggregates,
+            ..
+        }
+        | PhysicalNode::SortAggregate {
+            group_by,
+            aggregates,
+            ..
+        } => vec![format!(
+            "group keys: {}, aggregates: {}",
+            group_by.len(),
+            aggregates.len()
+        )],
+        PhysicalNode::ExternalSort { expressions, .. }
+        | PhysicalNode::InMemorySort { expressions, .. } => {
+            vec![format!("sort keys: {}", expressions.len())]
+        }
+        PhysicalNode::TopK { count, offset, .. }
+        | PhysicalNode::VectorTopK { count, offset, .. }
+        | PhysicalNode::Limit { count, offset, .. } => match offset {
+            Some(offset) => vec![format!("limit: {}, offset: {}", count, offset)],
+            None => vec![format!("limit: {}", count)],
+        },
+        PhysicalNode::GenericFunction { function_name, .. } => {
+            vec![format!("function: {}", function_name)]
+        }
+        PhysicalNode::Unwind { variable, .. } => vec![format!("as: {}", variable)],
+        PhysicalNode::GraphIndexScan { index_name, .. } => {
+            vec![format!("index: {}", index_name)]
+        }
+        PhysicalNode::IndexDistinctScan {
+            variable,
+            label,
+            property,
+            index_name,
+            ..
+        } => vec![
+            format!("{}:{}({})", variable, label, property),
+            format!("index: {}", index_name),
+        ],
+        PhysicalNode::Insert {
+            node_creations,
+            edge_creations,
+            ..
+        } => vec![format!(
+            "nodes: {}, edges: {}",
+            node_creations.len(),
+            edge_creations.len()
+        )],
+        PhysicalNode::Update {
+            target_variable, ..
+        } => vec![format!("target: {}", target_variable)],
+        PhysicalNode::Delete {
+            target_variables,
+            detach,
+            ..
+        } => {
+            let prefix = if *detach { "detach " } else { "" };
+            vec![format!(
+                "{}targets: {}",
+                prefix,
+                target_variables.join(", ")
+            )]
+        }
+        _ => Vec::new(),
+    }
+}
+
+/// Escape text for use inside a quoted DOT label
+fn escape_dot(text: &str) -> String {
+    text.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+/// Collects per-operator timings and row counts while a physical plan executes
+///
+/// The executor calls `enter_operator` before running a node and `exit_operator`
+/// once it has produced its rows. Operators entered while another is running
+/// become its children, so the recorded tree mirrors the plan that ran.
+#[derive(Debug, Default)]
+pub struct ExecutionTracer {
+    stack: Vec<OperatorFrame>,
+    roots: Vec<AnalyzedNode>,
+}
+
+/// Measurements of an operator that is still running
+#[derive(Debug, Default)]
+struct OperatorFrame {
+    children: Vec<AnalyzedNode>,
+    rows_held: Option<usize>,
+}
+
+impl ExecutionTracer {
+    /// Create a new execution tracer
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Start measuring an operator
+    pub fn enter_operator(&mut self) {
+        self.stack.push(OperatorFrame::default());
+    }
+
+    /// Record the most rows the operator most recently entered held at once
+    pub fn record_rows_held(&mut self, rows: usize) {
+        if let Some(frame) = self.stack.last_mut() {
+            frame.rows_held = Some(rows);
+        }
+    }
+
+    /// Finish measuring the operator most recently entered
+    pub fn exit_operator(&mut self, node: &PhysicalNode, elapsed: Duration, actual_rows: usize) {
+        let frame = self.stack.pop().unwrap_or_default();
+        let analyzed = AnalyzedNode {
+            operator: node.operator_name().to_string(),
+            estimated_rows: node.get_row_count(),
+            estimated_cost: node.get_cost(),
+            actual_rows,
+            rows_held: frame.rows_held,
+            elapsed,
+            children: frame.children,
+        };
+
+        match self.stack.last_mut() {
+            Some(parent) => parent.children.push(analyzed),
+            None => self.roots.push(analyzed),
+        }
+    }
+
+    /// Take the measured tree of the outermost operator, if any ran
+    pub fn take_root(&mut self) -> Option<AnalyzedNode> {
+        self.roots.pop()
+    }
+}
@@ -16,6 +16,8 @@ use crate::schema::enforcement::config::SchemaEnforcementConfig;
 use crate::schema::types::GraphTypeDefinition;
 use crate::schema::types::SchemaEnforcementMode;
 use crate::schema::validator::SchemaValidator;
+use crate::storage::indexes::{ConstraintKind, PropertyConstraint};
+use crate::storage::{GraphCache, Node};
 
 /// Runtime validator that hooks into query execution
 /// Uses synchronous operations using synchronous operations
@@ -214,6 +216,125 @@ impl RuntimeValidator {
         }
     }
 
+    /// Validate the nodes written by a statement against the graph's property
+    /// constraints (synchronous)
+    ///
+    /// Ids of nodes that no longer exist, e.g. deleted later in the same
+    /// statement, are skipped.
+    pub fn validate_property_constraints(
+        graph: &GraphCache,
+        constraints: &[PropertyConstraint],
+        node_ids: &[String],
+    ) -> Result<(), ExecutionError> {
+        if constraints.is_empty() {
+            return Ok(());
+        }
+
+        for node_id in node_ids {
+            if let Some(node) = graph.get_node(node_id) {
+                for constraint in constraints
+                    .iter()
+                    .filter(|c| node.labels.contains(&c.label))
+                {
+                    Self::validate_node_constraint(graph, constraint, node)?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Validate every existing node with the constraint's label, used before a
+    /// new constraint is registered (synchronous)
+    pub fn validate_constraint_on_graph(
+        graph: &GraphCache,
+        constraint: &PropertyConstraint,
+    ) -> Result<(), ExecutionError> {
+        // Values are keyed by their debug form, as storage values are not hashable
+        let mut seen: HashMap<String, &str> = HashMap::new();
+
+        for node in graph.get_nodes_by_label(&constraint.label) {
+            let value = Self::constrained_value(constraint, node);
+            match (constraint.kind, value) {
+                (ConstraintKind::NotNull, None) => {
+                    return Err(Self::violation(constraint, node, None));
+                }
+                (ConstraintKind::Unique, Some(value)) => {
+                    if let Some(other_id) = seen.insert(format!("{:?}", value), node.id.as_str()) {
+                        return Err(Self::violation(constraint, node, Some((value, other_id))));
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Validate a single node against one constraint
+    ///
+    /// Uniqueness is checked against the other nodes carrying the label, found
+    /// through the graph's label index.
+    fn validate_node_constraint(
+        graph: &GraphCache,
+        constraint: &PropertyConstraint,
+        node: &Node,
+    ) -> Result<(), ExecutionError> {
+        let value = Self::constrained_value(constraint, node);
+        match (constraint.kind, value) {
+            (ConstraintKind::NotNull, None) => Err(Self::violation(constraint, node, None)),
+            (ConstraintKind::Unique, Some(value)) => {
+                let duplicate = graph
+                    .get_nodes_by_label(&constraint.label)
+                    .into_iter()
+                    .find(|other| {
+                        other.id != node.id
+                            && other.properties.get(&constraint.property) == Some(value)
+                    });
+                match duplicate {
+                    Some(other) => Err(Self::violation(
+                        constraint,
+                        node,
+                        Some((value, other.id.as_str())),
+                    )),
+                    None => Ok(()),
+                }
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// The node's value for the constrained property, treating NULL as missing
+    fn constrained_value<'a>(
+        constraint: &PropertyConstraint,
+        node: &'a Node,
+    ) -> Option<&'a crate::storage::Value> {
+        node.properties
+            .get(&constraint.property)
+            .filter(|value| !value.is_null())
+    }
+
+    /// Build the error reported for a node that breaks a constraint
+    fn violation(
+        constraint: &PropertyConstraint,
+        node: &Node,
+        duplicate: Option<(&crate::storage::Value, &str)>,
+    ) -> ExecutionError {
+        let detail = match duplicate {
+            Some((value, other_id)) => {
+                format!("value {} is already used by node '{}'", value, other_id)
+            }
+            None => format!("property '{}' is missing or null", constraint.property),
+        };
+        ExecutionError::ConstraintViolation(format!(
+            "node '{}' violates constraint '{}' ({}): {}",
+            node.id,
+            constraint.name,
+            constraint.describe(),
+            detail
+        ))
+    }
+
     /// Get the graph type definition for a graph (synchronous)
     fn get_graph_type(
         &self,
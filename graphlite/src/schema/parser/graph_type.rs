@@ -213,6 +213,7 @@ fn parse_edge_type(tokens: &[Token]) -> IResult<&[Token], EdgeTypeDefinition> {
             constraints: Vec::new(),
             description: None,
             cardinality: EdgeCardinality::default(),
+            on_delete: None,
         },
     ))
 }
@@ -118,6 +118,10 @@ pub struct EdgeTypeDefinition {
     pub constraints: Vec<Constraint>,
     pub description: Option<String>,
     pub cardinality: EdgeCardinality,
+    /// What deleting an endpoint node does to edges of this type; without an
+    /// action such a node can only be removed with DETACH DELETE
+    #[serde(default)]
+    pub on_delete: Option<ForeignKeyAction>,
 }
 
 /// Cardinality constraints for edges
@@ -6,6 +6,7 @@
 //! This module provides a consolidated session management model that combines
 //! authentication, authorization, and database session state management.
 
+use crate::exec::collation::Collation;
 use crate::session::transaction_state::SessionTransactionState;
 use crate::storage::{GraphCache, StorageManager, Value};
 use crate::txn::TransactionManager;
@@ -194,6 +195,8 @@ pub struct UserSession {
     pub current_schema: Option<String>,
     /// Current timezone setting
     pub current_timezone: Option<String>,
+    /// String collation for sorting and comparisons
+    pub collation: Collation,
 
     // === Session State ===
     /// Session parameters (SET commands, user variables)
@@ -204,6 +207,14 @@ pub struct UserSession {
     pub transaction_state: Arc<SessionTransactionState>,
     /// Catalog metadata cache (reduces contention on CatalogManager)
     pub catalog_cache: SessionCatalogCache,
+    /// Most rows a query may return before it fails (None = unlimited)
+    pub max_result_rows: Option<usize>,
+    /// Keep nodes that duplicate an existing node's labels and properties
+    /// instead of skipping them
+    pub allow_duplicate_nodes: bool,
+    /// Scan nodes and edges, and emit groups, in ID order so that queries
+    /// without ORDER BY return rows in a reproducible order
+    pub deterministic_order: bool,
 
     // === Session Lifecycle ===
     /// When the session was created
@@ -234,10 +245,14 @@ impl UserSession {
             current_graph: None,
             current_schema: None,
             current_timezone: None,
+            collation: Collation::default(),
             parameters: HashMap::new(),
             permissions,
             transaction_state,
             catalog_cache: SessionCatalogCache::new(),
+            max_result_rows: None,
+            allow_duplicate_nodes: false,
+            deterministic_order: false,
             created_at: now,
             last_activity: now,
             active: true,
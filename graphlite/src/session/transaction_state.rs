@@ -6,11 +6,13 @@
 //! This module provides transaction state management at the session level,
 //! ensuring proper isolation and consistency for multi-statement transactions.
 
+use crate::catalog::manager::CatalogManager;
 use crate::exec::ExecutionError;
 use crate::txn::isolation::IsolationLevel;
 use crate::txn::{TransactionId, TransactionLog, TransactionManager, UndoOperation};
 use std::collections::HashMap;
 use std::sync::{Arc, RwLock};
+use std::time::Instant;
 
 /// Session-scoped transaction state
 #[derive(Clone)]
@@ -27,8 +29,14 @@ pub struct SessionTransactionState {
     /// Auto-commit mode for this session
     auto_commit: Arc<RwLock<bool>>,
 
+    /// Whether a failed statement rolls back the whole open transaction
+    rollback_on_error: Arc<RwLock<bool>>,
+
     /// Transaction isolation level
     isolation_level: Arc<RwLock<IsolationLevel>>,
+
+    /// When the current transaction last ran a statement
+    last_activity: Arc<RwLock<Instant>>,
 }
 
 impl SessionTransactionState {
@@ -39,7 +47,9 @@ impl SessionTransactionState {
             current_transaction: Arc::new(RwLock::new(None)),
             transaction_logs: Arc::new(RwLock::new(HashMap::new())),
             auto_commit: Arc::new(RwLock::new(true)),
+            rollback_on_error: Arc::new(RwLock::new(false)),
             isolation_level: Arc::new(RwLock::new(IsolationLevel::ReadCommitted)),
+            last_activity: Arc::new(RwLock::new(Instant::now())),
         }
     }
 
@@ -79,6 +89,37 @@ impl SessionTransactionState {
         Ok(())
     }
 
+    /// Check if a failed statement rolls back the open transaction
+    pub fn rolls_back_on_error(&self) -> Result<bool, ExecutionError> {
+        self.rollback_on_error
+            .read()
+            .map(|guard| *guard)
+            .map_err(|_| {
+                ExecutionError::RuntimeError("Failed to read rollback-on-error state".to_string())
+            })
+    }
+
+    /// Set whether a failed statement rolls back the open transaction
+    pub fn set_rollback_on_error(&self, enabled: bool) -> Result<(), ExecutionError> {
+        let mut rollback_on_error = self.rollback_on_error.write().map_err(|_| {
+            ExecutionError::RuntimeError("Failed to update rollback-on-error state".to_string())
+        })?;
+        *rollback_on_error = enabled;
+        Ok(())
+    }
+
+    /// Begin an implicit transaction when auto-commit is off and none is open
+    ///
+    /// The transaction stays open across statements until an explicit COMMIT
+    /// or ROLLBACK. Returns whether a transaction was started.
+    pub fn begin_implicit_transaction(&self) -> Result<bool, ExecutionError> {
+        if self.is_auto_commit()? || self.has_active_transaction()? {
+            return Ok(false);
+        }
+        self.begin_transaction()?;
+        Ok(true)
+    }
+
     /// Get the isolation level
     pub fn isolation_level(&self) -> Result<IsolationLevel, ExecutionError> {
         self.isolation_level
@@ -96,6 +137,67 @@ impl SessionTransactionState {
         Ok(())
     }
 
+    /// Record activity on the current transaction, resetting its idle timer
+    pub fn touch(&self) {
+        if let Ok(mut last_activity) = self.last_activity.write() {
+            *last_activity = Instant::now();
+        }
+    }
+
+    /// Roll back the current transaction if it has been idle past the
+    /// transaction manager's timeout
+    ///
+    /// Returns whether a transaction was rolled back.
+    pub fn rollback_if_idle(
+        &self,
+        storage: &Arc<crate::storage::StorageManager>,
+        catalog: Option<&Arc<RwLock<CatalogManager>>>,
+    ) -> Result<bool, ExecutionError> {
+        let timeout = match self.manager.idle_timeout() {
+            Some(timeout) => timeout,
+            None => return Ok(false),
+        };
+        if !self.has_active_transaction()? {
+            return Ok(false);
+        }
+        let idle = self
+            .last_activity
+            .read()
+            .map(|last_activity| last_activity.elapsed())
+            .map_err(|_| {
+                ExecutionError::RuntimeError("Failed to read transaction activity".to_string())
+            })?;
+        if idle < timeout {
+            return Ok(false);
+        }
+
+        log::warn!(
+            "Rolling back transaction idle for {:?} (timeout {:?})",
+            idle,
+            timeout
+        );
+        self.rollback_transaction_with_catalog(Some(storage), catalog)?;
+        Ok(true)
+    }
+
+    /// Check the idle timeout before running a statement in this session
+    ///
+    /// Fails with `TransactionTimedOut` if the open transaction had to be
+    /// rolled back; otherwise resets the idle timer.
+    pub fn check_idle_timeout(
+        &self,
+        storage: &Arc<crate::storage::StorageManager>,
+        catalog: Option<&Arc<RwLock<CatalogManager>>>,
+    ) -> Result<(), ExecutionError> {
+        if self.rollback_if_idle(storage, catalog)? {
+            return Err(ExecutionError::TransactionTimedOut(
+                "transaction was idle too long and has been rolled back".to_string(),
+            ));
+        }
+        self.touch();
+        Ok(())
+    }
+
     /// Begin a new transaction
     pub fn begin_transaction(&self) -> Result<TransactionId, ExecutionError> {
         // Check if there's already an active transaction
@@ -120,6 +222,7 @@ impl SessionTransactionState {
             ExecutionError::RuntimeError("Failed to update transaction logs".to_string())
         })?;
         logs.insert(txn_id, TransactionLog::new(txn_id));
+        self.touch();
 
         log::info!("Session began transaction: {:?}", txn_id);
         Ok(txn_id)
@@ -144,7 +247,13 @@ impl SessionTransactionState {
         let mut logs = self.transaction_logs.write().map_err(|_| {
             ExecutionError::RuntimeError("Failed to update transaction logs".to_string())
         })?;
-        logs.remove(&txn_id);
+        let log = logs.remove(&txn_id);
+        drop(logs);
+
+        // Tell subscribers what the transaction changed
+        if let Some(log) = log {
+            self.manager.change_feed().publish(&log.undo_operations);
+        }
 
         log::info!("Session committed transaction: {:?}", txn_id);
         Ok(())
@@ -159,6 +268,16 @@ impl SessionTransactionState {
     pub fn rollback_transaction_with_storage(
         &self,
         storage: Option<&Arc<crate::storage::StorageManager>>,
+    ) -> Result<(), ExecutionError> {
+        self.rollback_transaction_with_catalog(storage, None)
+    }
+
+    /// Rollback the current transaction, also undoing DDL (CREATE SCHEMA/GRAPH/INDEX)
+    /// when a catalog manager is provided
+    pub fn rollback_transaction_with_catalog(
+        &self,
+        storage: Option<&Arc<crate::storage::StorageManager>>,
+        catalog: Option<&Arc<RwLock<CatalogManager>>>,
     ) -> Result<(), ExecutionError> {
         log::info!("ROLLBACK: Attempting rollback, checking for active transaction...");
         let current_txn = self.current_transaction_id()?;
@@ -188,7 +307,7 @@ impl SessionTransactionState {
                 txn_id
             );
             for operation in undo_operations.iter().rev() {
-                if let Err(e) = self.apply_undo_operation(operation, storage_manager) {
+                if let Err(e) = self.apply_undo_operation(operation, storage_manager, catalog) {
                     log::error!(
                         "Failed to apply undo operation: {:?}. Error: {}",
                         operation,
@@ -227,6 +346,7 @@ impl SessionTransactionState {
         &self,
         operation: &crate::txn::UndoOperation,
         storage: &Arc<crate::storage::StorageManager>,
+        catalog: Option<&Arc<RwLock<CatalogManager>>>,
     ) -> Result<(), ExecutionError> {
         use crate::txn::UndoOperation;
 
@@ -243,7 +363,7 @@ impl SessionTransactionState {
                         i + 1,
                         operations.len()
                     );
-                    self.apply_undo_operation(op, storage)?;
+                    self.apply_undo_operation(op, storage, catalog)?;
                 }
                 log::info!(
                     "ROLLBACK: Successfully processed batch of {} operations",
@@ -385,7 +505,7 @@ impl SessionTransactionState {
                     })?;
 
                 // Find and update the node
-                if let Some(node) = graph.get_node_mut(node_id) {
+                if let Some(mut node) = graph.get_node_mut(node_id) {
                     node.properties = old_properties.clone();
                     node.labels = old_labels.clone();
                 }
@@ -523,6 +643,24 @@ impl SessionTransactionState {
 
                 log::info!("Rolled back UpdateEdge: {}", edge_id);
             }
+            UndoOperation::CreateSchema { .. }
+            | UndoOperation::CreateGraph { .. }
+            | UndoOperation::CreateIndex { .. } => match catalog {
+                Some(catalog_manager) => {
+                    crate::exec::schema_engine::operations::undo_ddl_operation(
+                        operation,
+                        catalog_manager,
+                        storage,
+                    )?;
+                    log::info!("Rolled back DDL operation: {:?}", operation);
+                }
+                None => {
+                    log::warn!(
+                        "No catalog manager provided for rollback - cannot undo {:?}",
+                        operation
+                    );
+                }
+            },
         }
 
         Ok(())
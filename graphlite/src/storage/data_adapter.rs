@@ -17,7 +17,7 @@ use crate::storage::{
 };
 use crate::storage::{StorageDriver, StorageTree};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 // Safe to use block_on here as they're not called from within async contexts
 thread_local! {
@@ -148,6 +148,8 @@ impl DataAdapter {
     }
 
     /// Save a GraphCache for a specific graph path using provided driver connection
+    ///
+    /// The writes are not flushed; call `driver.flush()` to make them durable.
     pub fn save_graph_by_path(
         &self,
         driver: &dyn StorageDriver<Tree = Box<dyn StorageTree>>,
@@ -203,9 +205,7 @@ impl DataAdapter {
         let metadata_data = bincode::serialize(&metadata)?;
         metadata_tree.insert(b"stats", &metadata_data)?;
 
-        // Flush to disk
-        driver.flush()?;
-
+        // Flushing is left to the caller's flush policy
         Ok(())
     }
 
@@ -324,6 +324,54 @@ impl DataAdapter {
         Ok(())
     }
 
+    /// Drop the trees left behind by deleted graphs
+    ///
+    /// Deleting a graph only clears its trees, so their names linger in
+    /// storage. The trees of a graph not in `live_graphs` are dropped when all
+    /// of them are empty; a saved graph always has metadata, so graphs that
+    /// were never loaded are kept. Returns the number of trees dropped.
+    pub fn drop_deleted_graph_trees(
+        &self,
+        driver: &dyn StorageDriver<Tree = Box<dyn StorageTree>>,
+        live_graphs: &[String],
+    ) -> Result<usize, Box<dyn std::error::Error>> {
+        let live: HashSet<String> = live_graphs
+            .iter()
+            .map(|graph| Self::normalize_graph_path(graph))
+            .collect();
+        let tree_names: HashSet<String> = driver.list_trees()?.into_iter().collect();
+
+        let mut dropped = 0;
+        for graph_prefix in self.list_graphs(driver)? {
+            if live.contains(&graph_prefix) {
+                continue;
+            }
+            let trees: Vec<String> = ["nodes_", "edges_", "metadata_"]
+                .iter()
+                .map(|table| format!("{}{}", table, graph_prefix))
+                .filter(|name| tree_names.contains(name))
+                .collect();
+
+            let mut all_empty = true;
+            for name in &trees {
+                if !driver.open_tree(name)?.is_empty()? {
+                    all_empty = false;
+                    break;
+                }
+            }
+            if !all_empty {
+                continue;
+            }
+
+            for name in &trees {
+                driver.drop_tree(name)?;
+                dropped += 1;
+            }
+            log::debug!("Dropped the trees of deleted graph '{}'", graph_prefix);
+        }
+        Ok(dropped)
+    }
+
     /// Save catalog provider data to persistent storage
     pub fn save_catalog_provider(
         &self,
@@ -9,7 +9,103 @@
 
 use crate::catalog::providers::schema::SchemaId;
 use crate::storage::types::{Edge, GraphError, Node};
-use std::collections::HashMap;
+use crate::storage::value::Value;
+use chrono::{DateTime, Utc};
+use std::collections::{BTreeSet, HashMap};
+use std::ops::{Deref, DerefMut};
+
+/// Entries of a single-property node index
+#[derive(Debug, Clone, Default)]
+struct PropertyIndex {
+    /// Node IDs by property value
+    nodes_by_value: HashMap<Value, BTreeSet<String>>,
+    /// IDs of the label's nodes without the property, or with a null in it
+    nodes_without_value: BTreeSet<String>,
+}
+
+impl PropertyIndex {
+    fn insert(&mut self, node: &Node, property: &str) {
+        match node.properties.get(property) {
+            Some(value) if !value.is_null() => {
+                self.nodes_by_value
+                    .entry(value.clone())
+                    .or_default()
+                    .insert(node.id.clone());
+            }
+            _ => {
+                self.nodes_without_value.insert(node.id.clone());
+            }
+        }
+    }
+
+    fn remove(&mut self, node: &Node, property: &str) {
+        match node.properties.get(property) {
+            Some(value) if !value.is_null() => {
+                if let Some(ids) = self.nodes_by_value.get_mut(value) {
+                    ids.remove(&node.id);
+                    if ids.is_empty() {
+                        self.nodes_by_value.remove(value);
+                    }
+                }
+            }
+            _ => {
+                self.nodes_without_value.remove(&node.id);
+            }
+        }
+    }
+}
+
+/// Property indexes of a graph: label -> property -> entries
+type PropertyIndexes = HashMap<String, HashMap<String, PropertyIndex>>;
+
+fn index_node(property_indexes: &mut PropertyIndexes, node: &Node) {
+    for label in &node.labels {
+        if let Some(indexes) = property_indexes.get_mut(label) {
+            for (property, index) in indexes.iter_mut() {
+                index.insert(node, property);
+            }
+        }
+    }
+}
+
+fn unindex_node(property_indexes: &mut PropertyIndexes, node: &Node) {
+    for label in &node.labels {
+        if let Some(indexes) = property_indexes.get_mut(label) {
+            for (property, index) in indexes.iter_mut() {
+                index.remove(node, property);
+            }
+        }
+    }
+}
+
+/// Mutable access to a node of a [`GraphCache`]
+///
+/// The node leaves the graph's property indexes while it is borrowed and
+/// goes back in under its new labels and values when the borrow ends.
+pub struct NodeMut<'a> {
+    node: &'a mut Node,
+    property_indexes: &'a mut PropertyIndexes,
+}
+
+impl Deref for NodeMut<'_> {
+    type Target = Node;
+
+    fn deref(&self) -> &Node {
+        self.node
+    }
+}
+
+impl DerefMut for NodeMut<'_> {
+    fn deref_mut(&mut self) -> &mut Node {
+        self.node
+    }
+}
+
+impl Drop for NodeMut<'_> {
+    fn drop(&mut self) {
+        index_node(self.property_indexes, self.node);
+    }
+}
 
 /// In-memory graph cache with indices for fast lookups
 #[derive(Debug, Clone)]
@@ -34,6 +130,9 @@ pub struct GraphCache {
 
     /// Adjacency list: node_id -> list of incoming edge IDs
     adjacency_in: HashMap<String, Vec<String>>,
+
+    /// Single-property indexes: label -> property -> entries
+    property_indexes: PropertyIndexes,
 }
 
 impl GraphCache {
@@ -47,6 +146,7 @@ impl GraphCache {
             edge_labels: HashMap::new(),
             adjacency_out: HashMap::new(),
             adjacency_in: HashMap::new(),
+            property_indexes: HashMap::new(),
         }
     }
 
@@ -69,6 +169,9 @@ impl GraphCache {
         self.adjacency_out.insert(node.id.clone(), Vec::new());
         self.adjacency_in.insert(node.id.clone(), Vec::new());
 
+        // Update property indexes
+        index_node(&mut self.property_indexes, &node);
+
         // Store the node
         self.nodes.insert(node.id.clone(), node);
 
@@ -139,9 +242,14 @@ impl GraphCache {
         self.nodes.get(id)
     }
 
-    /// Get a mutable reference to a node by ID
-    pub fn get_node_mut(&mut self, id: &str) -> Option<&mut Node> {
-        self.nodes.get_mut(id)
+    /// Get mutable access to a node by ID
+    pub fn get_node_mut(&mut self, id: &str) -> Option<NodeMut<'_>> {
+        let node = self.nodes.get_mut(id)?;
+        unindex_node(&mut self.property_indexes, node);
+        Some(NodeMut {
+            node,
+            property_indexes: &mut self.property_indexes,
+        })
     }
 
     /// Get an edge by ID
@@ -283,6 +391,7 @@ impl GraphCache {
             .nodes
             .remove(node_id)
             .ok_or_else(|| GraphError::NodeNotFound(node_id.to_string()))?;
+        unindex_node(&mut self.property_indexes, &node);
 
         // Remove from label indices
         for label in &node.labels {
@@ -341,6 +450,39 @@ impl GraphCache {
         Ok(edge)
     }
 
+    /// Remove nodes with `label` whose `property` is a timestamp at or before `now`
+    ///
+    /// Timestamps may be datetime values or RFC 3339 strings; nodes without
+    /// the property, or with any other value in it, never expire. Connected
+    /// edges are removed along with the nodes. Returns the number of nodes removed.
+    pub fn remove_expired_nodes(
+        &mut self,
+        label: &str,
+        property: &str,
+        now: DateTime<Utc>,
+    ) -> usize {
+        let expired: Vec<String> = self
+            .get_nodes_by_label(label)
+            .into_iter()
+            .filter(|node| {
+                let expires_at = match node.properties.get(property) {
+                    Some(Value::String(text)) => DateTime::parse_from_rfc3339(text)
+                        .ok()
+                        .map(|dt| dt.with_timezone(&Utc)),
+                    Some(value) => value.as_datetime_utc(),
+                    None => None,
+                };
+                expires_at.is_some_and(|expires_at| expires_at <= now)
+            })
+            .map(|node| node.id.clone())
+            .collect();
+
+        for node_id in &expired {
+            let _ = self.remove_node(node_id);
+        }
+        expired.len()
+    }
+
     /// Get graph statistics
     pub fn stats(&self) -> GraphStats {
         GraphStats {
@@ -379,6 +521,99 @@ impl GraphCache {
         self.edge_labels.clear();
         self.adjacency_out.clear();
         self.adjacency_in.clear();
+        for indexes in self.property_indexes.values_mut() {
+            for index in indexes.values_mut() {
+                *index = PropertyIndex::default();
+            }
+        }
+    }
+
+    /// Index a property of the nodes with a label
+    ///
+    /// The entries are built from the label's current nodes and kept up to
+    /// date as nodes are added, changed and removed. Creating an index the
+    /// graph already has does nothing.
+    pub fn create_property_index(&mut self, label: &str, property: &str) {
+        if self.has_property_index(label, property) {
+            return;
+        }
+        let mut index = PropertyIndex::default();
+        for node in self.get_nodes_by_label(label) {
+            index.insert(node, property);
+        }
+        self.property_indexes
+            .entry(label.to_string())
+            .or_default()
+            .insert(property.to_string(), index);
+    }
+
+    /// Remove the index of a label's property
+    pub fn drop_property_index(&mut self, label: &str, property: &str) {
+        if let Some(indexes) = self.property_indexes.get_mut(label) {
+            indexes.remove(property);
+            if indexes.is_empty() {
+                self.property_indexes.remove(label);
+            }
+        }
+    }
+
+    /// Check if a label's property is indexed
+    pub fn has_property_index(&self, label: &str, property: &str) -> bool {
+        self.property_index(label, property).is_some()
+    }
+
+    /// Labels and properties of every property index
+    pub fn property_index_keys(&self) -> Vec<(String, String)> {
+        self.property_indexes
+            .iter()
+            .flat_map(|(label, indexes)| {
+                indexes
+                    .keys()
+                    .map(move |property| (label.clone(), property.clone()))
+            })
+            .collect()
+    }
+
+    /// IDs of the label's nodes with `value` in an indexed property, in node ID order
+    ///
+    /// Null never matches. Returns `None` when the property is not indexed.
+    pub fn lookup_property_index(
+        &self,
+        label: &str,
+        property: &str,
+        value: &Value,
+    ) -> Option<Vec<String>> {
+        let index = self.property_index(label, property)?;
+        Some(
+            index
+                .nodes_by_value
+                .get(value)
+                .map(|ids| ids.iter().cloned().collect())
+                .unwrap_or_default(),
+        )
+    }
+
+    /// Every distinct value of an indexed property, in the node ID order of
+    /// the node each value is first found on
+    ///
+    /// A label node without the property counts as a null value. Returns
+    /// `None` when the property is not indexed.
+    pub fn property_index_values(&self, label: &str, property: &str) -> Option<Vec<Value>> {
+        let index = self.property_index(label, property)?;
+        let mut values: Vec<(&String, Value)> = index
+            .nodes_by_value
+            .iter()
+            .filter_map(|(value, ids)| ids.first().map(|id| (id, value.clone())))
+            .collect();
+        if let Some(id) = index.nodes_without_value.first() {
+            values.push((id, Value::Null));
+        }
+        values.sort_by(|a, b| a.0.cmp(b.0));
+        Some(values.into_iter().map(|(_, value)| value).collect())
+    }
+
+    fn property_index(&self, label: &str, property: &str) -> Option<&PropertyIndex> {
+        self.property_indexes.get(label)?.get(property)
     }
 }
 
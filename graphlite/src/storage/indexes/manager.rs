@@ -3,19 +3,24 @@
 //
 //! Index manager for GraphLite
 //!
-//! Simplified index manager that supports only graph indexes.
+//! Simplified index manager for graph indexes and single-property node indexes.
 
 use log::{debug, info, warn};
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::sync::{Arc, RwLock};
 
-use super::IndexError;
+use super::{IndexError, PropertyConstraint, PropertyIndexTarget};
 use crate::storage::GraphCache;
 
 /// Manager for all indexes in the system
+#[derive(Debug)]
 pub struct IndexManager {
     /// Index names storage
     index_names: Arc<RwLock<HashSet<String>>>,
+    /// Graph, label and property of each single-property index, keyed by index name
+    index_targets: Arc<RwLock<HashMap<String, PropertyIndexTarget>>>,
+    /// Property constraints keyed by constraint name
+    constraints: Arc<RwLock<HashMap<String, PropertyConstraint>>>,
 }
 
 impl Default for IndexManager {
@@ -29,6 +34,8 @@ impl IndexManager {
     pub fn new() -> Self {
         Self {
             index_names: Arc::new(RwLock::new(HashSet::new())),
+            index_targets: Arc::new(RwLock::new(HashMap::new())),
+            constraints: Arc::new(RwLock::new(HashMap::new())),
         }
     }
 
@@ -37,7 +44,7 @@ impl IndexManager {
         &self,
         name: String,
         _index_type: super::IndexType,
-        _config: super::IndexConfig,
+        config: super::IndexConfig,
     ) -> Result<(), IndexError> {
         info!("Creating index '{}'", name);
 
@@ -53,6 +60,25 @@ impl IndexManager {
         // Store index name
         index_names.insert(name.clone());
 
+        // Remember what a single-property index covers so queries can find it
+        let parameter = |key: &str| config.parameters.get(key).and_then(|v| v.as_string());
+        if let (Some(graph_path), Some(label), Some(property)) = (
+            parameter("__graph__"),
+            parameter("__label__"),
+            parameter("__property__"),
+        ) {
+            if let Ok(mut index_targets) = self.index_targets.write() {
+                index_targets.insert(
+                    name.clone(),
+                    PropertyIndexTarget {
+                        graph_path: graph_path.to_string(),
+                        label: label.to_string(),
+                        property: property.to_string(),
+                    },
+                );
+            }
+        }
+
         debug!("Index '{}' created successfully", name);
         Ok(())
     }
@@ -70,10 +96,25 @@ impl IndexManager {
             return Err(IndexError::NotFound(name.to_string()));
         }
 
+        if let Ok(mut index_targets) = self.index_targets.write() {
+            index_targets.remove(name);
+        }
+
         debug!("Index '{}' deleted successfully", name);
         Ok(())
     }
 
+    /// Re-register a single-property index loaded from the catalog
+    pub fn restore_index(&self, name: String, target: PropertyIndexTarget) {
+        debug!("Restoring index '{}' on {}", name, target.graph_path);
+        if let Ok(mut index_names) = self.index_names.write() {
+            index_names.insert(name.clone());
+        }
+        if let Ok(mut index_targets) = self.index_targets.write() {
+            index_targets.insert(name, target);
+        }
+    }
+
     /// Check if an index exists
     pub fn index_exists(&self, name: &str) -> bool {
         self.index_names
@@ -90,6 +131,70 @@ impl IndexManager {
             .unwrap_or_else(|_| Vec::new())
     }
 
+    /// Register a property constraint
+    pub fn create_constraint(&self, constraint: PropertyConstraint) -> Result<(), IndexError> {
+        info!(
+            "Creating constraint '{}' ({})",
+            constraint.name,
+            constraint.describe()
+        );
+
+        let mut constraints = self
+            .constraints
+            .write()
+            .map_err(|e| IndexError::creation(format!("Failed to acquire lock: {}", e)))?;
+
+        if constraints.contains_key(&constraint.name) {
+            return Err(IndexError::AlreadyExists(constraint.name));
+        }
+
+        constraints.insert(constraint.name.clone(), constraint);
+        Ok(())
+    }
+
+    /// Remove a property constraint
+    pub fn drop_constraint(&self, name: &str) -> Result<PropertyConstraint, IndexError> {
+        info!("Dropping constraint '{}'", name);
+
+        let mut constraints = self
+            .constraints
+            .write()
+            .map_err(|e| IndexError::creation(format!("Failed to acquire lock: {}", e)))?;
+
+        constraints
+            .remove(name)
+            .ok_or_else(|| IndexError::NotFound(name.to_string()))
+    }
+
+    /// Check if a constraint exists
+    pub fn constraint_exists(&self, name: &str) -> bool {
+        self.constraints
+            .read()
+            .map(|constraints| constraints.contains_key(name))
+            .unwrap_or(false)
+    }
+
+    /// All property constraints declared on a graph
+    pub fn constraints_for_graph(&self, graph_path: &str) -> Vec<PropertyConstraint> {
+        self.constraints
+            .read()
+            .map(|constraints| {
+                constraints
+                    .values()
+                    .filter(|c| c.graph_path == graph_path)
+                    .cloned()
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Remove every constraint declared on a graph, e.g. when it is dropped
+    pub fn drop_constraints_for_graph(&self, graph_path: &str) {
+        if let Ok(mut constraints) = self.constraints.write() {
+            constraints.retain(|_, c| c.graph_path != graph_path);
+        }
+    }
+
     /// Reindex a text index (stub for compatibility)
     pub fn reindex_text_index(
         &self,
@@ -116,12 +221,39 @@ impl IndexManager {
         Vec::new()
     }
 
-    /// Find index by label and property (stub for compatibility)
+    /// Find the name of an index declared on a label and property of a graph
     pub fn find_index_by_label_and_property(
         &self,
-        _label: &str,
-        _property: &str,
+        graph_path: &str,
+        label: &str,
+        property: &str,
     ) -> Option<String> {
-        None
+        self.index_targets.read().ok().and_then(|index_targets| {
+            index_targets
+                .iter()
+                .find(|(_, target)| {
+                    target.graph_path == graph_path
+                        && target.label == label
+                        && target.property == property
+                })
+                .map(|(name, _)| name.clone())
+        })
+    }
+
+    /// Labels and properties indexed on a graph
+    ///
+    /// The entries of these indexes live in the graph itself, see
+    /// [`GraphCache::create_property_index`].
+    pub fn property_index_targets(&self, graph_path: &str) -> Vec<PropertyIndexTarget> {
+        self.index_targets
+            .read()
+            .map(|index_targets| {
+                index_targets
+                    .values()
+                    .filter(|target| target.graph_path == graph_path)
+                    .cloned()
+                    .collect()
+            })
+            .unwrap_or_default()
     }
 }
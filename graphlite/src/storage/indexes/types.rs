@@ -130,6 +130,52 @@ impl IndexConfig {
     }
 }
 
+/// Kind of a per-label property constraint
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum ConstraintKind {
+    /// No two nodes with the label share a value for the property
+    Unique,
+    /// Every node with the label has a non-null value for the property
+    NotNull,
+}
+
+/// A property constraint declared with CREATE CONSTRAINT
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct PropertyConstraint {
+    /// Constraint name
+    pub name: String,
+    /// Full path of the graph the constraint applies to
+    pub graph_path: String,
+    /// Node label the constraint applies to
+    pub label: String,
+    /// Constrained property
+    pub property: String,
+    /// What the constraint asserts
+    pub kind: ConstraintKind,
+}
+
+impl PropertyConstraint {
+    /// Describe the assertion, e.g. `Person.email IS UNIQUE`
+    pub fn describe(&self) -> String {
+        let assertion = match self.kind {
+            ConstraintKind::Unique => "IS UNIQUE",
+            ConstraintKind::NotNull => "IS NOT NULL",
+        };
+        format!("{}.{} {}", self.label, self.property, assertion)
+    }
+}
+
+/// The graph, label and property a single-property index covers
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct PropertyIndexTarget {
+    /// Full path of the graph the index was created on
+    pub graph_path: String,
+    /// Indexed node label
+    pub label: String,
+    /// Indexed property
+    pub property: String,
+}
+
 /// Partition strategy for distributed indexes
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum PartitionStrategy {
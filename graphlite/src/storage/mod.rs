@@ -23,13 +23,13 @@ pub mod value;
 
 pub use graph_cache::GraphCache;
 pub use types::{Edge, Node, StorageError};
-pub use value::{TimeWindow, Value};
-// Only expose StorageType for configuration
-pub use persistent::StorageType;
+pub use value::{DurationValue, TimeWindow, Value};
+// Only expose storage configuration types
+pub use persistent::{FlushPolicy, StorageType};
 // Public exports for examples and tests
 pub use persistent::{StorageDriver, StorageTree};
 // Public interface - only StorageManager should be used externally
-pub use storage_manager::{StorageManager, StorageMethod};
+pub use storage_manager::{CompactionReport, StorageManager, StorageMethod};
 // Index system (stub)
 // TTL management
 // pub use ttl_manager::{TTLManager, TTLCleanupStats};  // TODO: Not yet extracted
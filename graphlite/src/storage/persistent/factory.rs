@@ -7,7 +7,7 @@
 //! It handles the instantiation and setup of different storage driver types.
 
 use super::traits::{StorageDriver, StorageTree};
-use super::types::{StorageResult, StorageType};
+use super::types::{FlushPolicy, StorageResult, StorageType};
 use std::path::Path;
 
 /// Factory function to create a storage driver based on configuration
@@ -18,25 +18,36 @@ use std::path::Path;
 /// # Arguments
 /// * `storage_type` - The type of storage driver to create (Sled or Memory)
 /// * `path` - The filesystem path where the database should be stored
+/// * `flush_policy` - When the driver flushes on its own: at the policy's
+///   interval for [`FlushPolicy::Periodic`], never for [`FlushPolicy::Manual`].
+///   Drivers without files ignore it
 ///
 /// # Returns
 /// A boxed trait object that implements StorageDriver
 ///
 /// # Examples
 /// ```ignore
-/// use crate::storage::drivers::{create_storage_driver, StorageType};
+/// use crate::storage::drivers::{create_storage_driver, FlushPolicy, StorageType};
 ///
-/// let driver = create_storage_driver(StorageType::Sled, "./data")?;
+/// let driver = create_storage_driver(StorageType::Sled, "./data", FlushPolicy::OnCommit)?;
 /// let tree = driver.open_tree("my_tree")?;
 /// ```
 pub fn create_storage_driver<P: AsRef<Path>>(
     storage_type: StorageType,
     path: P,
+    flush_policy: FlushPolicy,
 ) -> StorageResult<Box<dyn StorageDriver<Tree = Box<dyn StorageTree>>>> {
     match storage_type {
         StorageType::Sled => {
             use crate::storage::persistent::sled::SledDriver;
-            let driver = SledDriver::open(path)?;
+            let driver = match flush_policy {
+                FlushPolicy::OnCommit => SledDriver::open(path)?,
+                FlushPolicy::Periodic(interval) => SledDriver::open_with_flush_interval(
+                    path,
+                    Some((interval.as_millis() as u64).max(1)),
+                )?,
+                FlushPolicy::Manual => SledDriver::open_with_flush_interval(path, None)?,
+            };
             Ok(Box::new(driver) as Box<dyn StorageDriver<Tree = Box<dyn StorageTree>>>)
         }
         StorageType::Memory => {
@@ -55,7 +66,9 @@ mod tests {
     #[test]
     fn test_create_sled_driver() {
         let temp_dir = TempDir::new().unwrap();
-        let driver = create_storage_driver(StorageType::Sled, temp_dir.path()).unwrap();
+        let driver =
+            create_storage_driver(StorageType::Sled, temp_dir.path(), FlushPolicy::OnCommit)
+                .unwrap();
         assert_eq!(driver.storage_type(), StorageType::Sled);
     }
 }
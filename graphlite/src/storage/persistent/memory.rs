@@ -169,6 +169,11 @@ impl StorageDriver for MemoryStorageDriver {
         Ok(())
     }
 
+    fn drop_tree(&self, name: &str) -> StorageResult<()> {
+        self.trees.write().remove(name);
+        Ok(())
+    }
+
     fn tree_stats(&self, name: &str) -> StorageResult<Option<TreeStatistics>> {
         let trees = self.trees.read();
         if let Some(tree) = trees.get(name) {
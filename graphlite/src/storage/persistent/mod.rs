@@ -21,10 +21,10 @@
 //! # Example Usage
 //!
 //! ```ignore
-//! use crate::storage::persistent::{create_storage_driver, StorageType};
+//! use crate::storage::persistent::{create_storage_driver, FlushPolicy, StorageType};
 //!
 //! // Create a driver
-//! let driver = create_storage_driver(StorageType::Sled, "./data")?;
+//! let driver = create_storage_driver(StorageType::Sled, "./data", FlushPolicy::OnCommit)?;
 //!
 //! // Open a tree (like a table or collection)
 //! let tree = driver.open_tree("my_data")?;
@@ -47,4 +47,4 @@ pub mod sled;
 // Public API re-exports
 pub use factory::create_storage_driver;
 pub use traits::{StorageDriver, StorageTree};
-pub use types::StorageType;
+pub use types::{FlushPolicy, StorageType};
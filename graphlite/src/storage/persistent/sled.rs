@@ -109,6 +109,22 @@ impl StorageTree for SledTree {
     }
 }
 
+impl SledDriver {
+    /// Open a database that flushes in the background every `flush_every_ms`
+    /// milliseconds, or only on explicit flushes if `None`
+    pub fn open_with_flush_interval<P: AsRef<Path>>(
+        path: P,
+        flush_every_ms: Option<u64>,
+    ) -> StorageResult<Self> {
+        let db = sled::Config::new()
+            .path(path)
+            .flush_every_ms(flush_every_ms)
+            .open()
+            .map_err(|e| StorageDriverError::BackendSpecific(e.to_string()))?;
+        Ok(SledDriver { db })
+    }
+}
+
 impl StorageDriver for SledDriver {
     type Tree = Box<dyn StorageTree>;
 
@@ -181,4 +197,17 @@ impl StorageDriver for SledDriver {
         // Sled doesn't provide detailed statistics, return None for now
         Ok(None)
     }
+
+    fn drop_tree(&self, name: &str) -> StorageResult<()> {
+        self.db
+            .drop_tree(name.as_bytes())
+            .map_err(|e| StorageDriverError::BackendSpecific(e.to_string()))?;
+        Ok(())
+    }
+
+    fn size_on_disk(&self) -> StorageResult<u64> {
+        self.db
+            .size_on_disk()
+            .map_err(|e| StorageDriverError::BackendSpecific(e.to_string()))
+    }
 }
@@ -98,6 +98,14 @@ pub trait StorageDriver: Send + Sync {
     /// Get statistics for a tree
     fn tree_stats(&self, name: &str) -> StorageResult<Option<TreeStatistics>>;
 
+    /// Drop a tree/column family and all of its data
+    fn drop_tree(&self, name: &str) -> StorageResult<()>;
+
+    /// Bytes the database currently occupies on disk, 0 if it has no files
+    fn size_on_disk(&self) -> StorageResult<u64> {
+        Ok(0)
+    }
+
     /// Explicitly close the storage driver and release any file locks
     /// This is called before dropping to ensure clean shutdown
     fn shutdown(&mut self) -> StorageResult<()> {
@@ -25,6 +25,21 @@ pub enum StorageType {
     Memory,
 }
 
+/// When writes are flushed to disk
+///
+/// Unflushed writes survive a clean close but may be lost if the process
+/// crashes. Catalog changes are always flushed immediately.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+pub enum FlushPolicy {
+    /// Flush after every committed write (the default)
+    #[default]
+    OnCommit,
+    /// Flush in the background at a fixed interval
+    Periodic(std::time::Duration),
+    /// Flush only when explicitly asked to
+    Manual,
+}
+
 impl std::str::FromStr for StorageType {
     type Err = String;
 
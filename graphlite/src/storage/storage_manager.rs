@@ -15,15 +15,17 @@
 
 use crate::catalog::manager::CatalogManager;
 use crate::storage::data_adapter::DataAdapter;
+use crate::storage::graph_cache::GraphStats;
 use crate::storage::indexes::IndexManager;
 use crate::storage::multi_graph::MultiGraphManager;
-use crate::storage::persistent::{create_storage_driver, StorageDriver, StorageTree};
+use crate::storage::persistent::{create_storage_driver, FlushPolicy, StorageDriver, StorageTree};
 use crate::storage::StorageType;
 use crate::storage::{GraphCache, StorageError};
 use log::{debug, error, info};
 use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
 use std::path::Path;
-use std::sync::Arc;
+use std::sync::{Arc, RwLock};
 
 /// Storage method configuration
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Default)]
@@ -37,6 +39,12 @@ pub enum StorageMethod {
     DiskAndMemory,
 }
 
+/// Key in the metadata tree reserved for storage health probes
+const HEALTH_PROBE_KEY: &[u8] = b"__health_probe__";
+
+/// Key in the catalog tree holding the application's schema version
+const SCHEMA_VERSION_KEY: &[u8] = b"schema_version";
+
 /// Storage manager that orchestrates all storage tiers
 #[derive(Clone)]
 pub struct StorageManager {
@@ -57,6 +65,26 @@ pub struct StorageManager {
 
     /// Index manager for text indexes
     index_manager: Option<Arc<IndexManager>>,
+
+    /// When graph writes are flushed to disk
+    flush_policy: FlushPolicy,
+
+    /// Held for reading while graph trees are written, and for writing while
+    /// compaction drops the trees of deleted graphs
+    tree_lock: Arc<RwLock<()>>,
+}
+
+/// Outcome of [`StorageManager::compact`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct CompactionReport {
+    /// Bytes on disk before compacting
+    pub bytes_before: u64,
+    /// Bytes on disk after compacting
+    pub bytes_after: u64,
+    /// Bytes given back, 0 if the files did not shrink
+    pub reclaimed_bytes: u64,
+    /// Trees of deleted graphs that were dropped
+    pub dropped_trees: usize,
 }
 
 impl StorageManager {
@@ -65,16 +93,28 @@ impl StorageManager {
         path: P,
         method: StorageMethod,
         storage_type: StorageType,
+    ) -> Result<Self, Box<dyn std::error::Error>> {
+        Self::new_with_flush_policy(path, method, storage_type, FlushPolicy::default())
+    }
+
+    /// Create a new storage manager that flushes graph writes per `flush_policy`
+    pub fn new_with_flush_policy<P: AsRef<Path>>(
+        path: P,
+        method: StorageMethod,
+        storage_type: StorageType,
+        flush_policy: FlushPolicy,
     ) -> Result<Self, Box<dyn std::error::Error>> {
         info!(
-            "Creating storage manager with method: {:?}, storage type: {:?}",
-            method, storage_type
+            "Creating storage manager with method: {:?}, storage type: {:?}, flush policy: {:?}",
+            method, storage_type, flush_policy
         );
 
         match method {
-            StorageMethod::DiskOnly => Self::init_disk_only(path, storage_type),
+            StorageMethod::DiskOnly => Self::init_disk_only(path, storage_type, flush_policy),
             StorageMethod::MemoryOnly => Self::init_memory_only(path, storage_type),
-            StorageMethod::DiskAndMemory => Self::init_disk_and_memory(path, storage_type),
+            StorageMethod::DiskAndMemory => {
+                Self::init_disk_and_memory(path, storage_type, flush_policy)
+            }
         }
     }
 
@@ -82,6 +122,7 @@ impl StorageManager {
     fn init_disk_only<P: AsRef<Path>>(
         path: P,
         storage_type: StorageType,
+        flush_policy: FlushPolicy,
     ) -> Result<Self, Box<dyn std::error::Error>> {
         info!(
             "Initializing disk-only storage with {} at path: {:?}",
@@ -90,7 +131,7 @@ impl StorageManager {
         );
 
         // Create single storage driver instance
-        let driver = create_storage_driver(storage_type, path.as_ref())?;
+        let driver = create_storage_driver(storage_type, path.as_ref(), flush_policy)?;
 
         // Pre-create commonly used column families/trees
         let common_trees = vec!["nodes", "edges", "metadata", "catalog", "auth"];
@@ -118,9 +159,36 @@ impl StorageManager {
             memory_store: None,
             storage_type,
             index_manager: Some(index_manager),
+            flush_policy,
+            tree_lock: Arc::new(RwLock::new(())),
         })
     }
 
+    /// Build an in-memory storage manager whose driver can be made to fail
+    ///
+    /// Setting the returned flag makes every driver call return an error, to
+    /// simulate a storage outage in tests.
+    #[cfg(test)]
+    pub(crate) fn with_faulty_driver() -> (Self, Arc<std::sync::atomic::AtomicBool>) {
+        let failing = Arc::new(std::sync::atomic::AtomicBool::new(false));
+        let driver: Box<dyn StorageDriver<Tree = Box<dyn StorageTree>>> =
+            Box::new(fault_injection::FaultyDriver {
+                inner: crate::storage::persistent::memory::MemoryStorageDriver::new(),
+                failing: failing.clone(),
+            });
+        let storage = Self {
+            cache: Arc::new(MultiGraphManager::new()),
+            storage_driver: Some(Arc::new(driver)),
+            persistent_store: Some(Arc::new(DataAdapter::new())),
+            memory_store: None,
+            storage_type: StorageType::Memory,
+            index_manager: Some(Arc::new(IndexManager::new())),
+            flush_policy: FlushPolicy::OnCommit,
+            tree_lock: Arc::new(RwLock::new(())),
+        };
+        (storage, failing)
+    }
+
     /// Initialize storage manager with memory-only storage (Redis/Valkey)
     fn init_memory_only<P: AsRef<Path>>(
         _path: P,
@@ -136,6 +204,7 @@ impl StorageManager {
     fn init_disk_and_memory<P: AsRef<Path>>(
         path: P,
         storage_type: StorageType,
+        flush_policy: FlushPolicy,
     ) -> Result<Self, Box<dyn std::error::Error>> {
         info!(
             "Initializing disk and memory storage with {} at path: {:?}",
@@ -145,7 +214,7 @@ impl StorageManager {
 
         // TODO: Implement both disk and memory storage initialization
         // For now, fall back to disk-only
-        Self::init_disk_only(path, storage_type)
+        Self::init_disk_only(path, storage_type, flush_policy)
     }
 
     /// Get a graph by name
@@ -155,8 +224,11 @@ impl StorageManager {
 
         // 1. Check local cache first
         match self.cache.get_graph(name) {
-            Ok(Some(graph)) => {
+            Ok(Some(mut graph)) => {
                 debug!("Graph '{}' found in local cache", name);
+                if self.sync_property_indexes(name, &mut graph) {
+                    self.cache.add_graph(name.to_string(), graph.clone())?;
+                }
                 return Ok(Some(graph));
             }
             Ok(None) => {
@@ -184,8 +256,9 @@ impl StorageManager {
         if let Some(persistent_store) = &self.persistent_store {
             if let Some(driver) = &self.storage_driver {
                 match persistent_store.load_graph_by_path(driver.as_ref().as_ref(), name) {
-                    Ok(graph) => {
+                    Ok(mut graph) => {
                         debug!("Graph '{}' loaded from persistent storage", name);
+                        self.sync_property_indexes(name, &mut graph);
 
                         // Add to cache for future access
                         self.cache.add_graph(name.to_string(), graph.clone())?;
@@ -204,6 +277,38 @@ impl StorageManager {
         Ok(None)
     }
 
+    /// Bring a graph's property indexes in line with the indexes declared on it
+    ///
+    /// Builds the entries of indexes the graph does not hold yet, as after
+    /// CREATE INDEX or a load from disk, and drops the entries of dropped
+    /// indexes. Returns whether the graph changed.
+    fn sync_property_indexes(&self, name: &str, graph: &mut GraphCache) -> bool {
+        let Some(index_manager) = &self.index_manager else {
+            return false;
+        };
+        let declared: HashSet<(String, String)> = index_manager
+            .property_index_targets(name)
+            .into_iter()
+            .map(|target| (target.label, target.property))
+            .collect();
+        let held: HashSet<(String, String)> = graph.property_index_keys().into_iter().collect();
+        if declared == held {
+            return false;
+        }
+
+        for (label, property) in held.difference(&declared) {
+            graph.drop_property_index(label, property);
+        }
+        for (label, property) in declared.difference(&held) {
+            debug!(
+                "Building index of {}.{} on graph '{}'",
+                label, property, name
+            );
+            graph.create_property_index(label, property);
+        }
+        true
+    }
+
     /// Save a graph
     /// Updates cache, memory store (if available), and persistent storage
     pub fn save_graph(&self, name: &str, graph: GraphCache) -> Result<(), StorageError> {
@@ -232,6 +337,7 @@ impl StorageManager {
         if let Some(persistent_store) = &self.persistent_store {
             if let Some(driver) = &self.storage_driver {
                 debug!("Attempting to persist graph '{}' to disk", name);
+                let _trees = self.tree_lock.read().unwrap_or_else(|e| e.into_inner());
                 persistent_store
                     .save_graph_by_path(driver.as_ref().as_ref(), &graph, name)
                     .map_err(|e| {
@@ -241,6 +347,14 @@ impl StorageManager {
                             name, e
                         ))
                     })?;
+                if self.flush_policy == FlushPolicy::OnCommit {
+                    driver.flush().map_err(|e| {
+                        StorageError::PersistenceError(format!(
+                            "Failed to flush graph '{}': {}",
+                            name, e
+                        ))
+                    })?;
+                }
                 debug!("Successfully persisted graph '{}' to disk", name);
             } else {
                 debug!(
@@ -326,6 +440,7 @@ impl StorageManager {
         if let Some(persistent_store) = &self.persistent_store {
             // Use delete_graph instead of clear() to only delete this specific graph
             if let Some(driver) = &self.storage_driver {
+                let _trees = self.tree_lock.read().unwrap_or_else(|e| e.into_inner());
                 persistent_store
                     .delete_graph(driver.as_ref().as_ref(), name)
                     .map_err(|e| {
@@ -345,6 +460,11 @@ impl StorageManager {
             );
         }
 
+        // 4. Forget constraints declared on the graph
+        if let Some(index_manager) = &self.index_manager {
+            index_manager.drop_constraints_for_graph(name);
+        }
+
         debug!(
             "Successfully deleted graph '{}' from all storage tiers",
             name
@@ -352,6 +472,30 @@ impl StorageManager {
         Ok(())
     }
 
+    /// Remove every node and edge from a graph, keeping the graph itself
+    ///
+    /// The emptied graph is written in a single save, which clears its node and
+    /// edge trees on disk instead of deleting elements one by one. The graph's
+    /// property indexes stay declared and are rebuilt empty. Returns the
+    /// statistics of the data that was removed.
+    pub fn truncate_graph(&self, name: &str) -> Result<GraphStats, StorageError> {
+        debug!("Truncating graph '{}' in storage manager", name);
+
+        let mut graph = self
+            .get_graph(name)?
+            .ok_or_else(|| StorageError::GraphNotFound(name.to_string()))?;
+        let removed = graph.stats();
+        graph.clear();
+
+        self.save_graph(name, graph)?;
+
+        debug!(
+            "Truncated graph '{}': removed {} nodes and {} edges",
+            name, removed.node_count, removed.edge_count
+        );
+        Ok(removed)
+    }
+
     /// List all available graphs
     pub fn list_graphs(&self) -> Result<Vec<String>, StorageError> {
         self.get_graph_names()
@@ -499,6 +643,154 @@ impl StorageManager {
         }
     }
 
+    /// Check that the storage backend accepts a write and reads it back
+    ///
+    /// Writes a reserved key in the metadata tree, reads it and removes it
+    /// again, so a probe leaves no data behind.
+    pub fn probe(&self) -> Result<(), StorageError> {
+        let driver = self.storage_driver.as_ref().ok_or_else(|| {
+            StorageError::PersistenceError("No storage driver available".to_string())
+        })?;
+        let tree = driver
+            .open_tree("metadata")
+            .map_err(|e| StorageError::PersistenceError(format!("Failed to open tree: {}", e)))?;
+
+        let token = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_nanos()
+            .to_le_bytes();
+        tree.insert(HEALTH_PROBE_KEY, &token)
+            .map_err(|e| StorageError::PersistenceError(format!("Probe write failed: {}", e)))?;
+        let read = tree.get(HEALTH_PROBE_KEY);
+        let removed = tree.remove(HEALTH_PROBE_KEY);
+
+        match read {
+            Ok(Some(value)) if value == token => {}
+            Ok(_) => {
+                return Err(StorageError::PersistenceError(
+                    "Probe read returned a different value than was written".to_string(),
+                ))
+            }
+            Err(e) => {
+                return Err(StorageError::PersistenceError(format!(
+                    "Probe read failed: {}",
+                    e
+                )))
+            }
+        }
+        removed.map_err(|e| StorageError::PersistenceError(format!("Probe cleanup failed: {}", e)))
+    }
+
+    /// When graph writes are flushed to disk
+    pub fn flush_policy(&self) -> FlushPolicy {
+        self.flush_policy
+    }
+
+    /// Flush every pending write to disk
+    ///
+    /// Needed for durability under [`FlushPolicy::Manual`]; harmless under
+    /// the other policies.
+    pub fn flush(&self) -> Result<(), StorageError> {
+        let driver = self.storage_driver.as_ref().ok_or_else(|| {
+            StorageError::PersistenceError("No storage driver available".to_string())
+        })?;
+        driver
+            .flush()
+            .map_err(|e| StorageError::PersistenceError(format!("Failed to flush storage: {}", e)))
+    }
+
+    /// Compact persistent storage and report the space reclaimed
+    ///
+    /// Drops the trees that deleted graphs leave behind and flushes the
+    /// driver, measuring its size on disk before and after. Queries keep
+    /// running meanwhile; saves only wait while trees are being dropped.
+    /// Indexes keep no stored entries, so there is nothing to rebuild.
+    pub fn compact(&self) -> Result<CompactionReport, StorageError> {
+        let driver = self.storage_driver.as_ref().ok_or_else(|| {
+            StorageError::PersistenceError("No storage driver available".to_string())
+        })?;
+        let size_on_disk = || {
+            driver.size_on_disk().map_err(|e| {
+                StorageError::PersistenceError(format!("Failed to measure storage size: {}", e))
+            })
+        };
+        let bytes_before = size_on_disk()?;
+
+        let dropped_trees = match &self.persistent_store {
+            Some(persistent_store) => {
+                let _trees = self.tree_lock.write().unwrap_or_else(|e| e.into_inner());
+                let live_graphs = self.cache.get_graph_names()?;
+                persistent_store
+                    .drop_deleted_graph_trees(driver.as_ref().as_ref(), &live_graphs)
+                    .map_err(|e| {
+                        StorageError::PersistenceError(format!(
+                            "Failed to drop deleted graph trees: {}",
+                            e
+                        ))
+                    })?
+            }
+            None => 0,
+        };
+
+        driver.flush().map_err(|e| {
+            StorageError::PersistenceError(format!("Failed to flush storage: {}", e))
+        })?;
+        let bytes_after = size_on_disk()?;
+
+        info!(
+            "Compacted storage: dropped {} trees, {} -> {} bytes",
+            dropped_trees, bytes_before, bytes_after
+        );
+        Ok(CompactionReport {
+            bytes_before,
+            bytes_after,
+            reclaimed_bytes: bytes_before.saturating_sub(bytes_after),
+            dropped_trees,
+        })
+    }
+
+    /// Read the application-defined schema version, 0 if none was ever set
+    pub fn schema_version(&self) -> Result<u64, StorageError> {
+        let tree = self.catalog_tree()?;
+        match tree.get(SCHEMA_VERSION_KEY).map_err(|e| {
+            StorageError::PersistenceError(format!("Failed to read schema version: {}", e))
+        })? {
+            Some(bytes) => {
+                let bytes: [u8; 8] = bytes.as_slice().try_into().map_err(|_| {
+                    StorageError::PersistenceError(format!(
+                        "Stored schema version has {} bytes, expected 8",
+                        bytes.len()
+                    ))
+                })?;
+                Ok(u64::from_le_bytes(bytes))
+            }
+            None => Ok(0),
+        }
+    }
+
+    /// Record the application-defined schema version and flush it to disk
+    pub fn set_schema_version(&self, version: u64) -> Result<(), StorageError> {
+        let tree = self.catalog_tree()?;
+        tree.insert(SCHEMA_VERSION_KEY, &version.to_le_bytes())
+            .map_err(|e| {
+                StorageError::PersistenceError(format!("Failed to write schema version: {}", e))
+            })?;
+        tree.flush().map_err(|e| {
+            StorageError::PersistenceError(format!("Failed to flush schema version: {}", e))
+        })
+    }
+
+    /// Open the tree that holds persisted catalog state
+    fn catalog_tree(&self) -> Result<Box<dyn StorageTree>, StorageError> {
+        let driver = self.storage_driver.as_ref().ok_or_else(|| {
+            StorageError::PersistenceError("No storage driver available".to_string())
+        })?;
+        driver
+            .open_tree("catalog")
+            .map_err(|e| StorageError::PersistenceError(format!("Failed to open tree: {}", e)))
+    }
+
     /// Clear only the in-memory cache (not persistent storage)
     pub fn clear_cache(&self) -> Result<(), StorageError> {
         debug!("Clearing storage cache");
@@ -715,3 +1007,97 @@ impl std::fmt::Debug for StorageManager {
             .finish_non_exhaustive()
     }
 }
+
+#[cfg(test)]
+mod fault_injection {
+    use crate::storage::persistent::memory::MemoryStorageDriver;
+    use crate::storage::persistent::traits::{IndexTreeOptions, TreeStatistics};
+    use crate::storage::persistent::types::{StorageDriverError, StorageResult};
+    use crate::storage::persistent::{StorageDriver, StorageTree};
+    use crate::storage::StorageType;
+    use std::path::Path;
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::Arc;
+
+    /// Memory driver that fails every call while `failing` is set
+    pub(super) struct FaultyDriver {
+        pub(super) inner: MemoryStorageDriver,
+        pub(super) failing: Arc<AtomicBool>,
+    }
+
+    impl FaultyDriver {
+        fn check(&self) -> StorageResult<()> {
+            if self.failing.load(Ordering::SeqCst) {
+                Err(StorageDriverError::BackendSpecific(
+                    "injected storage failure".to_string(),
+                ))
+            } else {
+                Ok(())
+            }
+        }
+    }
+
+    impl StorageDriver for FaultyDriver {
+        type Tree = Box<dyn StorageTree>;
+
+        fn open<P: AsRef<Path>>(_path: P) -> StorageResult<Self> {
+            Ok(Self {
+                inner: MemoryStorageDriver::new(),
+                failing: Arc::new(AtomicBool::new(false)),
+            })
+        }
+
+        fn open_tree(&self, name: &str) -> StorageResult<Self::Tree> {
+            self.check()?;
+            self.inner.open_tree(name)
+        }
+
+        fn list_trees(&self) -> StorageResult<Vec<String>> {
+            self.check()?;
+            self.inner.list_trees()
+        }
+
+        fn flush(&self) -> StorageResult<()> {
+            self.check()?;
+            self.inner.flush()
+        }
+
+        fn storage_type(&self) -> StorageType {
+            StorageType::Memory
+        }
+
+        fn open_index_tree(
+            &self,
+            name: &str,
+            index_options: IndexTreeOptions,
+        ) -> StorageResult<Self::Tree> {
+            self.check()?;
+            self.inner.open_index_tree(name, index_options)
+        }
+
+        fn list_indexes(&self) -> StorageResult<Vec<String>> {
+            self.check()?;
+            self.inner.list_indexes()
+        }
+
+        fn drop_index(&self, name: &str) -> StorageResult<()> {
+            self.check()?;
+            self.inner.drop_index(name)
+        }
+
+        fn tree_stats(&self, name: &str) -> StorageResult<Option<TreeStatistics>> {
+            self.check()?;
+            self.inner.tree_stats(name)
+        }
+
+        fn drop_tree(&self, name: &str) -> StorageResult<()> {
+            self.check()?;
+            self.inner.drop_tree(name)
+        }
+
+        fn size_on_disk(&self) -> StorageResult<u64> {
+            self.check()?;
+            self.inner.size_on_disk()
+        }
+    }
+}
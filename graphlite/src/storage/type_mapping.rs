@@ -55,6 +55,7 @@ impl TypeMapping {
                 | GqlType::Date,
             ) => true,
             (Value::TimeWindow(_), GqlType::Duration { .. }) => true,
+            (Value::Duration(_), GqlType::Duration { .. }) => true,
 
             // Collection types
             (
@@ -194,6 +195,7 @@ impl TypeMapping {
             Value::DateTimeWithFixedOffset(_) => GqlType::ZonedDateTime { precision: None },
             Value::DateTimeWithNamedTz(_, _) => GqlType::ZonedDateTime { precision: None },
             Value::TimeWindow(_) => GqlType::Duration { precision: None },
+            Value::Duration(_) => GqlType::Duration { precision: None },
             Value::Array(arr) => {
                 // Infer element type from first element (or default to String)
                 let element_type = arr
@@ -231,6 +233,7 @@ impl TypeMapping {
             Value::Node(_) => GqlType::String { max_length: None }, // Nodes are complex objects, use String for now
             Value::Edge(_) => GqlType::String { max_length: None }, // Edges are complex objects, use String for now
             Value::Temporal(_) => GqlType::String { max_length: None }, // Temporal values are complex, use String for now
+            Value::Map(_) => GqlType::Record,
         }
     }
 
@@ -283,9 +286,7 @@ impl TypeMapping {
             | GqlType::ZonedDateTime { .. }
             | GqlType::LocalTime { .. }
             | GqlType::LocalDateTime { .. } => Value::DateTime(chrono::Utc::now()),
-            GqlType::Duration { .. } => Value::TimeWindow(
-                crate::storage::TimeWindow::new(chrono::Utc::now(), chrono::Utc::now()).unwrap(),
-            ),
+            GqlType::Duration { .. } => Value::Duration(crate::storage::DurationValue::default()),
             GqlType::List { .. } => {
                 // Create an empty array with appropriate element type
                 Value::Array(vec![])
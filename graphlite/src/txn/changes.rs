@@ -0,0 +1,278 @@
+// Copyright (c) 2024-2025 DeepGraph Inc.
+// SPDX-License-Identifier: Apache-2.0
+//
+//! Change notifications for committed writes
+//!
+//! When a transaction commits, or a statement commits on its own in
+//! auto-commit mode, every node and edge it inserted, updated or deleted is
+//! published to the subscribers of its graph. Each subscriber receives its
+//! notifications in commit order on a background thread of its own, so a
+//! slow callback never holds up a writer. Delivery is best-effort: nothing is
+//! replayed to a subscriber that registers late or whose callback panicked.
+
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::mpsc::{self, Sender};
+use std::sync::RwLock;
+
+use super::log::UndoOperation;
+use crate::exec::error::ExecutionError;
+
+/// Id that write executors give the undo operation of a statement that
+/// changed nothing
+const NO_OPERATIONS_ID: &str = "no_operations";
+
+/// What a committed write did to an entity
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ChangeKind {
+    Insert,
+    Update,
+    Delete,
+}
+
+/// Whether a change touched a node or an edge
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EntityKind {
+    Node,
+    Edge,
+}
+
+/// One node or edge changed by a committed write
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChangeEvent {
+    /// Full path of the graph the entity belongs to, e.g. `/schema/graph`
+    pub graph_path: String,
+    /// Whether the entity is a node or an edge
+    pub entity: EntityKind,
+    /// Storage ID of the node or edge
+    pub entity_id: String,
+    /// Whether it was inserted, updated or deleted
+    pub kind: ChangeKind,
+}
+
+impl ChangeEvent {
+    /// The changes recorded by an undo operation, in the order they were made
+    pub(crate) fn collect(undo_op: &UndoOperation, events: &mut Vec<ChangeEvent>) {
+        let (graph_path, entity, entity_id, kind) = match undo_op {
+            UndoOperation::InsertNode {
+                graph_path,
+                node_id,
+            } => (graph_path, EntityKind::Node, node_id, ChangeKind::Insert),
+            UndoOperation::UpdateNode {
+                graph_path,
+                node_id,
+                ..
+            } => (graph_path, EntityKind::Node, node_id, ChangeKind::Update),
+            UndoOperation::DeleteNode {
+                graph_path,
+                node_id,
+                ..
+            } => (graph_path, EntityKind::Node, node_id, ChangeKind::Delete),
+            UndoOperation::InsertEdge {
+                graph_path,
+                edge_id,
+            } => (graph_path, EntityKind::Edge, edge_id, ChangeKind::Insert),
+            UndoOperation::UpdateEdge {
+                graph_path,
+                edge_id,
+                ..
+            } => (graph_path, EntityKind::Edge, edge_id, ChangeKind::Update),
+            UndoOperation::DeleteEdge {
+                graph_path,
+                edge_id,
+                ..
+            } => (graph_path, EntityKind::Edge, edge_id, ChangeKind::Delete),
+            UndoOperation::Batch { operations } => {
+                for operation in operations {
+                    Self::collect(operation, events);
+                }
+                return;
+            }
+            UndoOperation::CreateSchema { .. }
+            | UndoOperation::CreateGraph { .. }
+            | UndoOperation::CreateIndex { .. } => return,
+        };
+        if entity_id == NO_OPERATIONS_ID {
+            return;
+        }
+        events.push(ChangeEvent {
+            graph_path: graph_path.clone(),
+            entity,
+            entity_id: entity_id.clone(),
+            kind,
+        });
+    }
+}
+
+/// Handle returned by [`ChangeFeed::subscribe`], used to unsubscribe
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SubscriptionId(u64);
+
+struct Subscriber {
+    graph_path: String,
+    sender: Sender<ChangeEvent>,
+}
+
+/// Subscribers to committed changes, shared by every session
+#[derive(Default)]
+pub struct ChangeFeed {
+    next_id: AtomicU64,
+    subscribers: RwLock<HashMap<SubscriptionId, Subscriber>>,
+}
+
+impl ChangeFeed {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Call `callback` for every committed change to the graph at `graph_path`
+    ///
+    /// The callback runs on a thread started for this subscription, which
+    /// ends when the subscription is removed or the feed is dropped. Fails if
+    /// that thread cannot be started, in which case nothing is subscribed.
+    pub fn subscribe<F>(
+        &self,
+        graph_path: &str,
+        callback: F,
+    ) -> Result<SubscriptionId, ExecutionError>
+    where
+        F: Fn(&ChangeEvent) + Send + 'static,
+    {
+        let (sender, receiver) = mpsc::channel::<ChangeEvent>();
+        std::thread::Builder::new()
+            .name("graphlite-change-feed".to_string())
+            .spawn(move || {
+                for event in receiver {
+                    callback(&event);
+                }
+            })
+            .map_err(|e| {
+                ExecutionError::RuntimeError(format!("Failed to start change feed thread: {}", e))
+            })?;
+
+        let mut subscribers = self.subscribers.write().map_err(|e| {
+            ExecutionError::RuntimeError(format!("Failed to acquire subscriber lock: {}", e))
+        })?;
+        let id = SubscriptionId(self.next_id.fetch_add(1, Ordering::Relaxed));
+        subscribers.insert(
+            id,
+            Subscriber {
+                graph_path: graph_path.to_string(),
+                sender,
+            },
+        );
+        Ok(id)
+    }
+
+    /// Stop delivering changes to a subscription
+    ///
+    /// Returns whether the subscription existed. Changes already queued for
+    /// it are still delivered.
+    pub fn unsubscribe(&self, id: SubscriptionId) -> bool {
+        self.subscribers
+            .write()
+            .map(|mut subscribers| subscribers.remove(&id).is_some())
+            .unwrap_or(false)
+    }
+
+    /// Whether anyone is subscribed, so callers can skip collecting changes
+    pub fn has_subscribers(&self) -> bool {
+        self.subscribers
+            .read()
+            .map(|subscribers| !subscribers.is_empty())
+            .unwrap_or(false)
+    }
+
+    /// Deliver the changes recorded by committed undo operations
+    pub fn publish(&self, undo_operations: &[UndoOperation]) {
+        if !self.has_subscribers() {
+            return;
+        }
+        let mut events = Vec::new();
+        for undo_op in undo_operations {
+            ChangeEvent::collect(undo_op, &mut events);
+        }
+        if events.is_empty() {
+            return;
+        }
+
+        let Ok(subscribers) = self.subscribers.read() else {
+            return;
+        };
+        for subscriber in subscribers.values() {
+            for event in events
+                .iter()
+                .filter(|event| event.graph_path == subscriber.graph_path)
+            {
+                // A closed channel means the callback panicked; drop the change
+                let _ = subscriber.sender.send(event.clone());
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::{Arc, Mutex};
+    use std::time::{Duration, Instant};
+
+    fn wait_for(received: &Arc<Mutex<Vec<ChangeEvent>>>, count: usize) -> Vec<ChangeEvent> {
+        let deadline = Instant::now() + Duration::from_secs(5);
+        while received.lock().unwrap().len() < count && Instant::now() < deadline {
+            std::thread::sleep(Duration::from_millis(5));
+        }
+        received.lock().unwrap().clone()
+    }
+
+    #[test]
+    fn test_publish_delivers_changes_for_the_subscribed_graph() {
+        let feed = ChangeFeed::new();
+        let received = Arc::new(Mutex::new(Vec::new()));
+        let sink = received.clone();
+        let id = feed
+            .subscribe("/s/g", move |event| {
+                sink.lock().unwrap().push(event.clone())
+            })
+            .unwrap();
+
+        feed.publish(&[
+            UndoOperation::Batch {
+                operations: vec![
+                    UndoOperation::InsertNode {
+                        graph_path: "/s/g".to_string(),
+                        node_id: "n1".to_string(),
+                    },
+                    UndoOperation::InsertEdge {
+                        graph_path: "/s/g".to_string(),
+                        edge_id: "e1".to_string(),
+                    },
+                ],
+            },
+            UndoOperation::InsertNode {
+                graph_path: "/s/other".to_string(),
+                node_id: "n2".to_string(),
+            },
+            UndoOperation::InsertNode {
+                graph_path: "/s/g".to_string(),
+                node_id: NO_OPERATIONS_ID.to_string(),
+            },
+        ]);
+
+        let events = wait_for(&received, 2);
+        assert_eq!(
+            events
+                .iter()
+                .map(|e| (e.entity, e.entity_id.as_str(), e.kind))
+                .collect::<Vec<_>>(),
+            vec![
+                (EntityKind::Node, "n1", ChangeKind::Insert),
+                (EntityKind::Edge, "e1", ChangeKind::Insert),
+            ]
+        );
+
+        assert!(feed.unsubscribe(id));
+        assert!(!feed.has_subscribers());
+        assert!(!feed.unsubscribe(id));
+    }
+}
@@ -47,6 +47,15 @@ pub enum UndoOperation {
     /// A batch of operations that must be undone together atomically
     /// Used for multi-property SET operations and other compound statements
     Batch { operations: Vec<UndoOperation> },
+    /// A schema was created - to undo, drop it
+    CreateSchema { schema_name: String },
+    /// A graph was created - to undo, drop it from the catalog and storage
+    CreateGraph {
+        schema_name: String,
+        graph_name: String,
+    },
+    /// An index was created - to undo, drop it
+    CreateIndex { index_name: String },
 }
 
 /// Transaction operation log for a single transaction
@@ -140,6 +149,9 @@ impl TransactionLog {
                     + edge_id.len()
                     + estimate_edge_size(deleted_edge)
             }
+            UndoOperation::CreateSchema { .. }
+            | UndoOperation::CreateGraph { .. }
+            | UndoOperation::CreateIndex { .. } => estimate_ddl_size(&operation),
             UndoOperation::Batch { operations } => {
                 // For batch operations, sum up the size of all individual operations
                 let mut total_size = std::mem::size_of::<UndoOperation>();
@@ -211,6 +223,9 @@ impl TransactionLog {
                                 + edge_id.len()
                                 + estimate_edge_size(deleted_edge)
                         }
+                        UndoOperation::CreateSchema { .. }
+                        | UndoOperation::CreateGraph { .. }
+                        | UndoOperation::CreateIndex { .. } => estimate_ddl_size(op),
                         UndoOperation::Batch { .. } => {
                             // Nested batches - just use base size to avoid infinite recursion
                             std::mem::size_of::<UndoOperation>()
@@ -267,7 +282,7 @@ pub struct TransactionLogStats {
 }
 
 /// Estimate memory usage of a Value
-fn estimate_value_size(value: &Value) -> usize {
+pub(crate) fn estimate_value_size(value: &Value) -> usize {
     match value {
         Value::Null => 0,
         Value::Boolean(_) => 1,
@@ -275,6 +290,11 @@ fn estimate_value_size(value: &Value) -> usize {
         Value::String(s) => s.len(),
         Value::List(list) | Value::Array(list) => list.iter().map(estimate_value_size).sum(),
         Value::Vector(vec) => vec.len() * 4, // f32 is 4 bytes
+        Value::Duration(_) => 12,
+        Value::Map(map) => map
+            .iter()
+            .map(|(k, v)| k.len() + estimate_value_size(v))
+            .sum(),
         _ => 64, // Rough estimate for complex types like DateTime, TimeWindow, Path
     }
 }
@@ -307,6 +327,20 @@ fn estimate_edge_size(edge: &Edge) -> usize {
     std::mem::size_of::<Edge>() + id_size + from_size + to_size + label_size + props_size
 }
 
+/// Estimate memory usage of a DDL undo operation
+fn estimate_ddl_size(operation: &UndoOperation) -> usize {
+    let names_size = match operation {
+        UndoOperation::CreateSchema { schema_name } => schema_name.len(),
+        UndoOperation::CreateGraph {
+            schema_name,
+            graph_name,
+        } => schema_name.len() + graph_name.len(),
+        UndoOperation::CreateIndex { index_name } => index_name.len(),
+        _ => 0,
+    };
+    std::mem::size_of::<UndoOperation>() + names_size
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
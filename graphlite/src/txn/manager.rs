@@ -7,10 +7,12 @@
 
 use std::collections::HashMap;
 use std::sync::{Arc, Mutex, RwLock};
+use std::time::Duration;
 
 use crate::exec::error::ExecutionError;
 use crate::session::SessionManager;
 
+use super::changes::ChangeFeed;
 use super::isolation::IsolationLevel;
 use super::state::{AccessMode, OperationType, TransactionId, TransactionState, TxnIsolationLevel};
 use super::wal::{PersistentWAL, WALEntry, WALEntryType};
@@ -27,6 +29,10 @@ pub struct TransactionManager {
     wal: Arc<PersistentWAL>,
     /// Session manager for transaction-session association
     session_manager: Option<Arc<SessionManager>>,
+    /// Idle time after which an open transaction is rolled back (None = never)
+    idle_timeout: RwLock<Option<Duration>>,
+    /// Subscribers notified of committed changes
+    change_feed: ChangeFeed,
 }
 
 impl TransactionManager {
@@ -42,13 +48,47 @@ impl TransactionManager {
             ExecutionError::RuntimeError(format!("Failed to initialize WAL: {}", e))
         })?;
 
-        Ok(Self {
+        Ok(Self::with_wal(wal))
+    }
+
+    /// Create a transaction manager whose WAL is never written to disk
+    pub fn in_memory() -> Self {
+        Self::with_wal(PersistentWAL::in_memory())
+    }
+
+    fn with_wal(wal: PersistentWAL) -> Self {
+        Self {
             active_transactions: Arc::new(RwLock::new(HashMap::new())),
             default_isolation_level: IsolationLevel::ReadCommitted,
             next_transaction_characteristics: Arc::new(Mutex::new(None)),
             wal: Arc::new(wal),
             session_manager: None,
-        })
+            idle_timeout: RwLock::new(None),
+            change_feed: ChangeFeed::new(),
+        }
+    }
+
+    /// Subscribers notified of the changes every transaction commits
+    pub fn change_feed(&self) -> &ChangeFeed {
+        &self.change_feed
+    }
+
+    /// Idle time after which an open transaction is rolled back
+    pub fn idle_timeout(&self) -> Option<Duration> {
+        self.idle_timeout
+            .read()
+            .map(|timeout| *timeout)
+            .unwrap_or(None)
+    }
+
+    /// Set the idle timeout for open transactions, or None to disable it
+    ///
+    /// A transaction that runs no statement for this long is rolled back the
+    /// next time its session is used, or by a sweep over all sessions.
+    pub fn set_idle_timeout(&self, timeout: Option<Duration>) {
+        if let Ok(mut idle_timeout) = self.idle_timeout.write() {
+            *idle_timeout = timeout;
+        }
     }
 
     /// Start a new transaction
@@ -19,6 +19,7 @@
 //! - Transaction logging and recovery
 //! - Nested transaction support
 
+pub mod changes;
 pub mod isolation;
 pub mod log;
 pub mod manager;
@@ -26,6 +27,7 @@ pub mod recovery;
 pub mod state;
 pub mod wal;
 
+pub use changes::{ChangeEvent, ChangeKind, EntityKind, SubscriptionId};
 pub use isolation::IsolationLevel;
 pub use log::{TransactionLog, UndoOperation};
 pub use manager::TransactionManager;
@@ -67,6 +67,8 @@ pub struct PersistentWAL {
     current_file_size: Arc<Mutex<u64>>,
     /// Separate catalog WAL for faster recovery
     catalog_wal: Option<Arc<CatalogWAL>>,
+    /// Whether entries are written to disk (false for in-memory databases)
+    persistent: bool,
 }
 
 /// Separate WAL for catalog operations
@@ -419,6 +421,7 @@ impl PersistentWAL {
             current_file_path: Arc::new(Mutex::new(None)),
             current_file_size: Arc::new(Mutex::new(0)),
             catalog_wal: Some(Arc::new(catalog_wal)),
+            persistent: true,
         };
 
         // Initialize WAL by finding the latest file and sequence numbers
@@ -427,6 +430,22 @@ impl PersistentWAL {
         Ok(wal)
     }
 
+    /// Create a WAL that only hands out sequence numbers and never touches disk
+    ///
+    /// Used by in-memory databases, which have nothing to recover after a restart.
+    pub fn in_memory() -> Self {
+        Self {
+            wal_dir: PathBuf::new(),
+            current_writer: Arc::new(Mutex::new(None)),
+            current_file_number: Arc::new(Mutex::new(0)),
+            global_sequence: Arc::new(Mutex::new(0)),
+            current_file_path: Arc::new(Mutex::new(None)),
+            current_file_size: Arc::new(Mutex::new(0)),
+            catalog_wal: None,
+            persistent: false,
+        }
+    }
+
     /// Initialize WAL by scanning existing files
     fn initialize(&mut self) -> Result<(), WALError> {
         let mut max_file_number = 0u64;
@@ -471,6 +490,10 @@ impl PersistentWAL {
 
     /// Write a WAL entry to persistent storage
     pub fn write_entry(&self, entry: WALEntry) -> Result<(), WALError> {
+        if !self.persistent {
+            return Ok(());
+        }
+
         let serialized = entry.serialize();
 
         // Check if we need to rotate to a new file
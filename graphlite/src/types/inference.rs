@@ -48,6 +48,12 @@ impl TypeInferenceEngine {
             GqlType::BigInt,
             true, // variadic
         );
+        self.register_function(
+            "APPROX_COUNT_DISTINCT",
+            vec![],
+            GqlType::BigInt,
+            true, // variadic
+        );
         self.register_function("SUM", vec![GqlType::Integer], GqlType::BigInt, false);
         self.register_function("AVG", vec![GqlType::Integer], GqlType::Double, false);
         self.register_function(
@@ -62,6 +68,14 @@ impl TypeInferenceEngine {
             GqlType::Integer, // Placeholder
             true,             // variadic
         );
+        for name in ["FIRST", "LAST"] {
+            self.register_function(
+                name,
+                vec![],           // Will be inferred from arguments
+                GqlType::Integer, // Placeholder
+                true,             // variadic
+            );
+        }
 
         // String functions
         self.register_function(
@@ -1012,3 +1012,214 @@ fn test_labels_aggregation_with_multiple_labels_and_order_by() {
         }
     }
 }
+
+#[test]
+fn test_collect_list_per_group_with_distinct() {
+    let fixture = TestFixture::new().expect("Failed to create test fixture");
+    fixture
+        .setup_graph("test_collect_list_per_group_with_distinct")
+        .expect("Failed to setup graph");
+
+    fixture
+        .query(
+            "INSERT (:Person {name: 'Alice', city: 'NYC', age: 30}),
+                    (:Person {name: 'Bob', city: 'NYC', age: 40}),
+                    (:Person {name: 'Alice', city: 'NYC', age: 50}),
+                    (:Person {name: 'Carol', city: 'LA', age: 25}),
+                    (:Person {city: 'LA', age: 35})",
+        )
+        .expect("Failed to insert people");
+
+    // Sorted string contents of each city's collected list
+    let names_by_city = |query: &str| -> HashMap<String, Vec<String>> {
+        let result = fixture.assert_query_succeeds(query);
+        result
+            .rows
+            .iter()
+            .map(|row| {
+                let city = match row.values.get("p.city") {
+                    Some(Value::String(city)) => city.clone(),
+                    other => panic!("Expected city string, got {:?}", other),
+                };
+                let mut names: Vec<String> = match row.values.get("names") {
+                    Some(Value::List(names)) => names
+                        .iter()
+                        .map(|name| match name {
+                            Value::String(name) => name.clone(),
+                            other => panic!("Expected name string, got {:?}", other),
+                        })
+                        .collect(),
+                    other => panic!("COLLECT should return a list, got {:?}", other),
+                };
+                names.sort();
+                (city, names)
+            })
+            .collect()
+    };
+
+    // One row per city; the person without a name contributes no null entry
+    let all_names =
+        names_by_city("MATCH (p:Person) RETURN p.city, COLLECT(p.name) AS names GROUP BY p.city");
+    assert_eq!(all_names.len(), 2);
+    assert_eq!(all_names["NYC"], vec!["Alice", "Alice", "Bob"]);
+    assert_eq!(all_names["LA"], vec!["Carol"]);
+
+    // DISTINCT removes duplicate names within a group
+    let distinct_names = names_by_city(
+        "MATCH (p:Person) RETURN p.city, COLLECT(DISTINCT p.name) AS names GROUP BY p.city",
+    );
+    assert_eq!(distinct_names["NYC"], vec!["Alice", "Bob"]);
+    assert_eq!(distinct_names["LA"], vec!["Carol"]);
+}
+
+#[test]
+fn test_approx_count_distinct_within_error_bound() {
+    let fixture = TestFixture::new().expect("Failed to create test fixture");
+    fixture
+        .setup_graph("test_approx_count_distinct")
+        .expect("Failed to setup graph");
+
+    // 6000 visits from 3000 distinct users, split across two sites
+    let distinct_users = 3000;
+    for batch in 0..6 {
+        let visits: Vec<String> = (0..1000)
+            .map(|i| {
+                let visit = batch * 1000 + i;
+                format!(
+                    "(:Visit {{n: {}, user: 'user-{}', site: '{}'}})",
+                    visit,
+                    visit % distinct_users,
+                    if visit % 2 == 0 { "a" } else { "b" }
+                )
+            })
+            .collect();
+        fixture
+            .query(&format!("INSERT {}", visits.join(", ")))
+            .expect("Failed to insert visits");
+    }
+
+    let exact =
+        fixture.assert_query_succeeds("MATCH (v:Visit) RETURN count(DISTINCT v.user) AS users");
+    assert_eq!(
+        exact.rows[0].values.get("users"),
+        Some(&Value::Number(distinct_users as f64))
+    );
+
+    // The sketch has about 1.6% standard error; allow three times that
+    let result = fixture
+        .assert_query_succeeds("MATCH (v:Visit) RETURN APPROX_COUNT_DISTINCT(v.user) AS users");
+    let estimate = match result.rows[0].values.get("users") {
+        Some(Value::Number(estimate)) => *estimate,
+        other => panic!("Expected a number, got {:?}", other),
+    };
+    let error = (estimate - distinct_users as f64).abs() / distinct_users as f64;
+    assert!(
+        error < 0.05,
+        "estimate {} is off by {:.2}%",
+        estimate,
+        error * 100.0
+    );
+
+    // Works per group, and never counts nulls
+    fixture
+        .query("INSERT (:Visit {site: 'a'})")
+        .expect("Failed to insert anonymous visit");
+    let result = fixture.assert_query_succeeds(
+        "MATCH (v:Visit) RETURN v.site, APPROX_COUNT_DISTINCT(v.user) AS users GROUP BY v.site ORDER BY v.site",
+    );
+    assert_eq!(result.rows.len(), 2);
+    for row in &result.rows {
+        // Even visits land on site a, so each site sees half of the users
+        match row.values.get("users") {
+            Some(Value::Number(estimate)) => {
+                let error = (estimate - 1500.0).abs() / 1500.0;
+                assert!(error < 0.05, "per-site estimate {} is off", estimate);
+            }
+            other => panic!("Expected a number, got {:?}", other),
+        }
+    }
+}
+
+#[test]
+fn test_group_by_numeric_column_groups_equal_values() {
+    let fixture = TestFixture::new().expect("Failed to create test fixture");
+    fixture
+        .setup_graph("test_group_by_numeric")
+        .expect("Failed to setup graph");
+    fixture.assert_query_succeeds(
+        "INSERT (:Reading {sensor: 'a', amount: 1.0}), (:Reading {sensor: 'b', amount: 1}),
+                (:Reading {sensor: 'c', amount: 2.5}), (:Reading {sensor: 'd', amount: 2.5}),
+                (:Reading {sensor: 'e', amount: 3})",
+    );
+
+    let result = fixture.assert_query_succeeds(
+        "MATCH (r:Reading) RETURN r.amount, count(r) AS readings GROUP BY r.amount ORDER BY r.amount",
+    );
+    let groups: Vec<(Option<&Value>, Option<&Value>)> = result
+        .rows
+        .iter()
+        .map(|row| (row.values.get("r.amount"), row.values.get("readings")))
+        .collect();
+    assert_eq!(
+        groups,
+        vec![
+            (Some(&Value::Number(1.0)), Some(&Value::Number(2.0))),
+            (Some(&Value::Number(2.5)), Some(&Value::Number(2.0))),
+            (Some(&Value::Number(3.0)), Some(&Value::Number(1.0))),
+        ]
+    );
+}
+
+#[test]
+fn test_first_last_and_ordered_array_agg() {
+    let fixture = TestFixture::new().expect("Failed to create test fixture");
+    fixture
+        .setup_graph("test_first_last_array_agg")
+        .expect("Failed to setup graph");
+    fixture.assert_query_succeeds(
+        "INSERT (:Member {team: 'red', name: 'Cleo', age: 41}),
+                (:Member {team: 'red', name: 'Abe', age: 23}),
+                (:Member {team: 'red', name: 'Bea', age: 35}),
+                (:Member {team: 'blue', name: 'Dan', age: 52}),
+                (:Member {team: 'blue', name: 'Eve', age: 19})",
+    );
+
+    let result = fixture.assert_query_succeeds(
+        "MATCH (m:Member)
+         RETURN m.team, FIRST(m.name ORDER BY m.age) AS youngest,
+                LAST(m.name ORDER BY m.age) AS oldest,
+                ARRAY_AGG(m.name ORDER BY m.age) AS by_age
+         GROUP BY m.team ORDER BY m.team",
+    );
+    assert_eq!(result.rows.len(), 2);
+
+    let names =
+        |list: &[&str]| Value::List(list.iter().map(|n| Value::String(n.to_string())).collect());
+    let blue = &result.rows[0].values;
+    assert_eq!(blue.get("m.team"), Some(&Value::String("blue".to_string())));
+    assert_eq!(
+        blue.get("youngest"),
+        Some(&Value::String("Eve".to_string()))
+    );
+    assert_eq!(blue.get("oldest"), Some(&Value::String("Dan".to_string())));
+    assert_eq!(blue.get("by_age"), Some(&names(&["Eve", "Dan"])));
+
+    let red = &result.rows[1].values;
+    assert_eq!(red.get("youngest"), Some(&Value::String("Abe".to_string())));
+    assert_eq!(red.get("oldest"), Some(&Value::String("Cleo".to_string())));
+    assert_eq!(red.get("by_age"), Some(&names(&["Abe", "Bea", "Cleo"])));
+
+    // Descending order flips the boundaries
+    let result = fixture.assert_query_succeeds(
+        "MATCH (m:Member) RETURN ARRAY_AGG(m.age ORDER BY m.age DESC) AS ages",
+    );
+    assert_eq!(
+        result.rows[0].values.get("ages"),
+        Some(&Value::List(
+            [52.0, 41.0, 35.0, 23.0, 19.0]
+                .iter()
+                .map(|a| Value::Number(*a))
+                .collect()
+        ))
+    );
+}
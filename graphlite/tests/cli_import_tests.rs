@@ -0,0 +1,144 @@
+//! Tests for the CLI `import` subcommand
+
+#[path = "testutils/mod.rs"]
+mod testutils;
+
+use graphlite::{QueryCoordinator, Value};
+use testutils::cli_fixture::CliFixture;
+
+const PEOPLE_CSV: &str = "id,name,age,active\n\
+1,Alice,30,true\n\
+2,\"Smith, Bob\",41,false\n\
+3,O'Brien,27,true\n\
+4,Dana,,true\n\
+5,Broken,99\n";
+
+#[test]
+fn test_import_csv_into_graph() {
+    let fixture = CliFixture::empty().expect("Failed to create CLI fixture");
+    let csv_path = fixture.db_path().with_file_name("people.csv");
+    std::fs::write(&csv_path, PEOPLE_CSV).expect("Failed to write CSV fixture");
+    let csv_path = csv_path.to_str().unwrap();
+    let import_args = [
+        "--graph",
+        "/imports/people",
+        "--label",
+        "Person",
+        "--file",
+        csv_path,
+        "--id",
+        "id",
+    ];
+
+    // The last row is short a field, so a strict import stops at it
+    let output = fixture.run_command("import", &import_args);
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("Line 6"));
+
+    let mut args = import_args.to_vec();
+    args.extend(["--dry-run", "--skip-bad-rows"]);
+    let output = fixture.run_command("import", &args);
+    assert!(output.status.success());
+    assert!(String::from_utf8_lossy(&output.stdout)
+        .contains("Dry run: 4 rows parsed, 1 bad rows, nothing written"));
+
+    let mut args = import_args.to_vec();
+    args.extend(["--skip-bad-rows", "--batch-size", "3"]);
+    let output = fixture.run_command("import", &args);
+    assert!(
+        output.status.success(),
+        "Import failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert!(
+        String::from_utf8_lossy(&output.stdout).contains("Imported 4 rows into /imports/people")
+    );
+
+    let coordinator =
+        QueryCoordinator::from_path(fixture.db_path()).expect("Failed to open database");
+    let session_id = coordinator
+        .create_simple_session("admin")
+        .expect("Failed to create session");
+    coordinator
+        .process_query("SESSION SET GRAPH /imports/people", &session_id)
+        .expect("Failed to set graph");
+
+    let result = coordinator
+        .process_query("MATCH (p:Person) RETURN count(p) AS total", &session_id)
+        .expect("Count query failed");
+    assert_eq!(
+        result.rows[0].values.get("total"),
+        Some(&Value::Number(4.0))
+    );
+
+    let result = coordinator
+        .process_query(
+            "MATCH (p:Person) WHERE p.id = 2 RETURN p.name AS name, p.age AS age, p.active AS active",
+            &session_id,
+        )
+        .expect("Lookup query failed");
+    let row = &result.rows[0].values;
+    assert_eq!(
+        row.get("name"),
+        Some(&Value::String("Smith, Bob".to_string()))
+    );
+    assert_eq!(row.get("age"), Some(&Value::Number(41.0)));
+    assert_eq!(row.get("active"), Some(&Value::Boolean(false)));
+
+    let result = coordinator
+        .process_query(
+            "MATCH (p:Person) WHERE p.id = 3 RETURN p.name AS name",
+            &session_id,
+        )
+        .expect("Lookup query failed");
+    assert_eq!(
+        result.rows[0].values.get("name"),
+        Some(&Value::String("O'Brien".to_string()))
+    );
+}
+
+#[test]
+fn test_import_keeps_non_canonical_numbers_as_strings() {
+    let fixture = CliFixture::empty().expect("Failed to create CLI fixture");
+    let csv_path = fixture.db_path().with_file_name("places.csv");
+    std::fs::write(&csv_path, "id,zip,code,scale,ratio\n1,02134,007,1e3,2.5\n")
+        .expect("Failed to write CSV fixture");
+    let output = fixture.run_command(
+        "import",
+        &[
+            "--graph",
+            "/imports/places",
+            "--label",
+            "Place",
+            "--file",
+            csv_path.to_str().unwrap(),
+        ],
+    );
+    assert!(
+        output.status.success(),
+        "Import failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let coordinator =
+        QueryCoordinator::from_path(fixture.db_path()).expect("Failed to open database");
+    let session_id = coordinator
+        .create_simple_session("admin")
+        .expect("Failed to create session");
+    coordinator
+        .process_query("SESSION SET GRAPH /imports/places", &session_id)
+        .expect("Failed to set graph");
+
+    let result = coordinator
+        .process_query(
+            "MATCH (p:Place) RETURN p.id AS id, p.zip AS zip, p.code AS code, p.scale AS scale, p.ratio AS ratio",
+            &session_id,
+        )
+        .expect("Lookup query failed");
+    let row = &result.rows[0].values;
+    assert_eq!(row.get("id"), Some(&Value::Number(1.0)));
+    assert_eq!(row.get("zip"), Some(&Value::String("02134".to_string())));
+    assert_eq!(row.get("code"), Some(&Value::String("007".to_string())));
+    assert_eq!(row.get("scale"), Some(&Value::String("1e3".to_string())));
+    assert_eq!(row.get("ratio"), Some(&Value::Number(2.5)));
+}
@@ -38,6 +38,27 @@ fn test_graph_ddl_operations() {
     fixture.assert_query_succeeds(&format!("DROP SCHEMA {} CASCADE", test_schema));
 }
 
+#[test]
+fn test_create_graph_if_not_exists_keeps_existing_graph() {
+    let fixture = TestFixture::empty().expect("Failed to create test fixture");
+    let graph = "/if_not_exists_schema/people";
+
+    fixture.assert_query_succeeds("CREATE SCHEMA /if_not_exists_schema");
+    fixture.assert_query_succeeds(&format!("CREATE GRAPH IF NOT EXISTS {}", graph));
+    fixture.assert_query_succeeds(&format!("SESSION SET GRAPH {}", graph));
+    fixture.assert_query_succeeds("INSERT (:Person {name: 'Alice'})");
+
+    // A second create is a no-op that leaves the data in place
+    fixture.assert_query_succeeds(&format!("CREATE GRAPH IF NOT EXISTS {}", graph));
+    fixture.assert_first_value(
+        "MATCH (p:Person) RETURN count(p) AS total",
+        "total",
+        Value::Number(1.0),
+    );
+
+    fixture.assert_query_fails(&format!("CREATE GRAPH {}", graph), "already exists");
+}
+
 #[test]
 fn test_complex_ddl_scenarios() {
     let fixture = TestFixture::empty().expect("Failed to create test fixture");
@@ -111,10 +132,7 @@ fn test_ddl_cascade_operations() {
 
     // 3. Session should be reset/invalid after CASCADE drop
     // The current session graph should be invalid now
-    fixture.assert_query_fails(
-        "INSERT (n:Test {name: 'Should fail'})",
-        "No graph context available",
-    );
+    fixture.assert_query_fails("INSERT (n:Test {name: 'Should fail'})", "NoCurrentGraph");
 }
 
 #[test]
@@ -232,7 +250,7 @@ fn test_session_graph_reset_on_drop() {
     // Now trying to insert should fail because session graph was reset
     fixture.assert_query_fails(
         "INSERT (n:TestNode {name: 'should_fail'})",
-        "No graph context available",
+        "NoCurrentGraph",
     );
 
     // Clean up
@@ -266,7 +284,7 @@ fn test_session_graph_reset_on_relative_drop() {
     // Now trying to insert should fail because session graph was reset
     fixture.assert_query_fails(
         "INSERT (n:TestNode {name: 'should_fail'})",
-        "No graph context available",
+        "NoCurrentGraph",
     );
 
     // Clean up
@@ -401,3 +419,194 @@ fn test_drop_graph_preserves_session_schema() {
     // Clean up
     fixture.assert_query_succeeds(&format!("DROP SCHEMA {} CASCADE", test_schema));
 }
+
+#[test]
+fn test_truncate_and_clear_graph_remove_all_data() {
+    let fixture = TestFixture::empty().expect("Failed to create test fixture");
+
+    fixture.assert_query_succeeds("CREATE SCHEMA /truncate_schema");
+    fixture.assert_query_succeeds("CREATE GRAPH /truncate_schema/truncate_graph");
+    fixture.assert_query_succeeds("SESSION SET GRAPH /truncate_schema/truncate_graph");
+    fixture.assert_query_succeeds(
+        "INSERT (:Person {name: 'Alice'})-[:KNOWS]->(:Person {name: 'Bob'}), \
+         (:Person {name: 'Carol'})",
+    );
+    fixture.assert_query_succeeds("CREATE INDEX person_name_idx ON Person (name)");
+
+    let result = fixture.assert_query_succeeds("TRUNCATE GRAPH /truncate_schema/truncate_graph");
+    assert_eq!(
+        result.rows_affected, 4,
+        "3 nodes and 1 edge should be removed"
+    );
+    assert_eq!(
+        result.rows[0].values.get("status"),
+        Some(&Value::String(
+            "Graph '/truncate_schema/truncate_graph' truncated: removed 3 nodes and 1 edges"
+                .to_string()
+        ))
+    );
+
+    fixture.assert_first_value(
+        "MATCH (n) RETURN count(n) AS nodes",
+        "nodes",
+        Value::Number(0.0),
+    );
+    fixture.assert_first_value(
+        "MATCH ()-[e]->() RETURN count(e) AS edges",
+        "edges",
+        Value::Number(0.0),
+    );
+
+    // The index definition survives; only the graph's data is reset
+    fixture.assert_query_fails(
+        "CREATE INDEX person_name_idx ON Person (name)",
+        "already exists",
+    );
+    let lookup = "MATCH (p:Person) USING INDEX p:Person(name) WHERE p.name = 'Alice' RETURN p.name";
+    let plan = fixture.explain(lookup).expect("Failed to explain query");
+    assert!(plan.operator("NodeIndexScan").is_some());
+    assert!(fixture.assert_query_succeeds(lookup).rows.is_empty());
+    let distinct = "MATCH (p:Person) RETURN DISTINCT p.name";
+    let plan = fixture.explain(distinct).expect("Failed to explain query");
+    assert!(plan.operator("IndexDistinctScan").is_some());
+    assert!(fixture.assert_query_succeeds(distinct).rows.is_empty());
+
+    // The emptied graph is still usable, and CLEAR reports its removals the same way
+    fixture.assert_query_succeeds("INSERT (:Person {name: 'Dave'})");
+    let result = fixture.assert_query_succeeds(
+        "MATCH (p:Person) USING INDEX p:Person(name) WHERE p.name = 'Dave' RETURN p.name",
+    );
+    assert_eq!(result.rows.len(), 1);
+    let result = fixture.assert_query_succeeds("CLEAR GRAPH /truncate_schema/truncate_graph");
+    assert_eq!(result.rows_affected, 1);
+    fixture.assert_first_value(
+        "MATCH (n) RETURN count(n) AS nodes",
+        "nodes",
+        Value::Number(0.0),
+    );
+}
+
+#[test]
+fn test_copy_graph_duplicates_data_and_leaves_source_untouched() {
+    let fixture = TestFixture::empty().expect("Failed to create test fixture");
+
+    fixture.assert_query_succeeds("CREATE SCHEMA /copy_src_schema");
+    fixture.assert_query_succeeds("CREATE SCHEMA /copy_dest_schema");
+    fixture.assert_query_succeeds("CREATE GRAPH /copy_src_schema/source");
+    fixture.assert_query_succeeds("SESSION SET GRAPH /copy_src_schema/source");
+    fixture.assert_query_succeeds(
+        "INSERT (:Person {name: 'Alice', age: 30})-[:KNOWS {since: 2020}]->(:Person {name: 'Bob', age: 25}), \
+         (:City {name: 'Paris'})",
+    );
+
+    let result = fixture
+        .assert_query_succeeds("COPY GRAPH /copy_src_schema/source TO /copy_dest_schema/snapshot");
+    assert_eq!(
+        result.rows_affected, 4,
+        "3 nodes and 1 edge should be copied"
+    );
+
+    // Copying onto an existing graph or from a missing one creates nothing
+    fixture.assert_query_fails(
+        "COPY GRAPH /copy_src_schema/source TO /copy_dest_schema/snapshot",
+        "already exists",
+    );
+    fixture.assert_query_fails(
+        "COPY GRAPH /copy_src_schema/missing TO /copy_dest_schema/other",
+        "not found",
+    );
+    fixture.assert_query_succeeds("CREATE GRAPH /copy_dest_schema/other");
+
+    fixture.assert_query_succeeds("SESSION SET GRAPH /copy_dest_schema/snapshot");
+    fixture.assert_first_value(
+        "MATCH (n) RETURN count(n) AS nodes",
+        "nodes",
+        Value::Number(3.0),
+    );
+    fixture.assert_first_value(
+        "MATCH ()-[e]->() RETURN count(e) AS edges",
+        "edges",
+        Value::Number(1.0),
+    );
+    fixture.assert_first_value(
+        "MATCH (a:Person)-[k:KNOWS]->(b:Person) RETURN b.name AS name",
+        "name",
+        Value::String("Bob".to_string()),
+    );
+    fixture.assert_first_value(
+        "MATCH (a:Person {name: 'Alice'})-[k:KNOWS]->() RETURN k.since AS since",
+        "since",
+        Value::Number(2020.0),
+    );
+
+    // Writes to the copy do not reach the source
+    fixture.assert_query_succeeds("MATCH (p:Person {name: 'Alice'}) SET p.age = 31");
+    fixture.assert_query_succeeds("INSERT (:Person {name: 'Carol'})");
+
+    fixture.assert_query_succeeds("SESSION SET GRAPH /copy_src_schema/source");
+    fixture.assert_first_value(
+        "MATCH (n) RETURN count(n) AS nodes",
+        "nodes",
+        Value::Number(3.0),
+    );
+    fixture.assert_first_value(
+        "MATCH (p:Person {name: 'Alice'}) RETURN p.age AS age",
+        "age",
+        Value::Number(30.0),
+    );
+}
+
+#[test]
+fn test_graph_ttl_hides_expired_nodes() {
+    let fixture = TestFixture::empty().expect("Failed to create test fixture");
+
+    fixture.assert_query_succeeds("CREATE SCHEMA /ttl_schema");
+    fixture.assert_query_succeeds(
+        "CREATE GRAPH /ttl_schema/sessions WITH (ttl_label = 'Session', ttl_property = 'expires_at')",
+    );
+    fixture.assert_query_succeeds("SESSION SET GRAPH /ttl_schema/sessions");
+    // SESSION is a keyword, so the label needs delimiting in queries
+    fixture.assert_query_succeeds(
+        "INSERT (:`Session` {token: 'stale', expires_at: '2000-01-01T00:00:00Z'})-[:OWNED_BY]->(:User {name: 'Alice'}), \
+         (:`Session` {token: 'live', expires_at: '2999-01-01T00:00:00Z'}), \
+         (:`Session` {token: 'forever'}), \
+         (:Audit {token: 'old', expires_at: '2000-01-01T00:00:00Z'})",
+    );
+
+    let result =
+        fixture.assert_query_succeeds("MATCH (s:`Session`) RETURN s.token AS token ORDER BY token");
+    let tokens: Vec<_> = result
+        .rows
+        .iter()
+        .map(|row| row.values.get("token").cloned())
+        .collect();
+    assert_eq!(
+        tokens,
+        vec![
+            Some(Value::String("forever".to_string())),
+            Some(Value::String("live".to_string())),
+        ],
+        "the expired session should be hidden before it is physically deleted"
+    );
+
+    // Edges of expired nodes disappear with them; other labels never expire
+    fixture.assert_first_value(
+        "MATCH ()-[e:OWNED_BY]->() RETURN count(e) AS edges",
+        "edges",
+        Value::Number(0.0),
+    );
+    fixture.assert_first_value(
+        "MATCH (a:Audit) RETURN count(a) AS audits",
+        "audits",
+        Value::Number(1.0),
+    );
+
+    fixture.assert_query_fails(
+        "CREATE GRAPH /ttl_schema/partial WITH (ttl_label = 'Session')",
+        "must be given together",
+    );
+    fixture.assert_query_fails(
+        "CREATE GRAPH /ttl_schema/retained WITH (retention = 'Session')",
+        "Unknown graph option",
+    );
+}
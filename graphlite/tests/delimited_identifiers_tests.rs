@@ -401,3 +401,66 @@ fn test_backtick_case_sensitivity() {
         Some(&Value::Number(1.0))
     );
 }
+
+#[test]
+fn test_backtick_graph_names_are_stored_verbatim() {
+    let fixture = TestFixture::new().unwrap();
+    let schema = fixture.schema_name().to_string();
+
+    // Spaces, slashes and escaped backticks all stay part of a single name
+    for (quoted, name, id) in [
+        ("`my graph`", "my graph", 1),
+        ("`sales/2024`", "sales/2024", 2),
+        ("`it``s`", "it`s", 3),
+    ] {
+        fixture
+            .query(&format!("CREATE GRAPH /{}/{}", schema, quoted))
+            .unwrap();
+        fixture
+            .query(&format!("SESSION SET GRAPH /{}/{}", schema, quoted))
+            .unwrap();
+        fixture
+            .query(&format!("INSERT (n:Item {{id: {}}})", id))
+            .unwrap();
+
+        let result = fixture
+            .query("MATCH (n:Item) RETURN count(n) as count, max(n.id) as id")
+            .unwrap();
+        assert_eq!(
+            result.rows[0].values.get("count"),
+            Some(&Value::Number(1.0))
+        );
+        assert_eq!(
+            result.rows[0].values.get("id"),
+            Some(&Value::Number(id as f64))
+        );
+
+        let graphs = fixture.query("CALL gql.list_graphs()").unwrap();
+        assert!(graphs
+            .rows
+            .iter()
+            .any(|row| row.values.get("graph_name") == Some(&Value::String(name.to_string()))));
+    }
+}
+
+#[test]
+fn test_regular_identifiers_starting_with_keywords() {
+    let fixture = TestFixture::new().unwrap();
+    fixture.setup_graph("keyword_prefixed_identifiers").unwrap();
+
+    // An underscore continues the identifier rather than ending a keyword
+    fixture
+        .query("INSERT (n:Event {next_day: 'Tuesday', create_date: '2024-01-01'})")
+        .unwrap();
+    let result = fixture
+        .query("MATCH (n:Event) RETURN n.next_day AS next_day, n.create_date AS for_date")
+        .unwrap();
+    assert_eq!(
+        result.rows[0].values.get("next_day"),
+        Some(&Value::String("Tuesday".to_string()))
+    );
+    assert_eq!(
+        result.rows[0].values.get("for_date"),
+        Some(&Value::String("2024-01-01".to_string()))
+    );
+}
@@ -202,6 +202,32 @@ fn test_remove_operations() {
     assert!(result.rows[0].values.get("status").unwrap() == &Value::Null);
 }
 
+#[test]
+fn test_set_from_property_then_remove() {
+    let fixture = TestFixture::new().expect("Failed to create test fixture");
+    fixture
+        .setup_graph("test_set_then_remove")
+        .expect("Failed to setup graph");
+    fixture.assert_query_succeeds("INSERT (:Renamed {name: 'Alice', flag: true})");
+
+    // SET reads the value before REMOVE drops it
+    fixture.assert_query_succeeds(
+        "MATCH (n:Renamed) SET n.full_name = n.name REMOVE n.name, n:Renamed",
+    );
+
+    let result = fixture
+        .query("MATCH (n {flag: true}) RETURN n.full_name AS full_name, n.name AS name, labels(n) AS labels")
+        .unwrap();
+    assert_eq!(result.rows.len(), 1);
+    let row = &result.rows[0].values;
+    assert_eq!(
+        row.get("full_name"),
+        Some(&Value::String("Alice".to_string()))
+    );
+    assert_eq!(row.get("name"), Some(&Value::Null));
+    assert_eq!(row.get("labels"), Some(&Value::List(vec![])));
+}
+
 #[test]
 fn test_count_aggregation_with_empty_results() {
     let fixture = TestFixture::new().expect("Failed to create test fixture");
@@ -380,6 +406,128 @@ fn test_delete_operations() {
     );
 }
 
+#[test]
+fn test_delete_follows_edge_type_on_delete_policy() {
+    let fixture = TestFixture::new().expect("Failed to create test fixture");
+    fixture
+        .setup_graph("test_edge_on_delete")
+        .expect("Failed to setup graph");
+
+    fixture.assert_query_succeeds(&format!(
+        "CREATE GRAPH TYPE /{}/ownership_type (
+            NODE TYPE Owner, Asset
+            EDGE TYPE OWNS ON DELETE CASCADE, GUARANTEES ON DELETE RESTRICT
+        )",
+        fixture.schema_name()
+    ));
+
+    fixture.assert_query_succeeds(
+        "INSERT (:Owner {name: 'alice'}), (:Owner {name: 'bob'}),
+                (:Asset {name: 'house'}), (:Asset {name: 'car'})",
+    );
+    fixture.assert_query_succeeds(
+        "MATCH (o:Owner {name: 'alice'}), (a:Asset)
+         INSERT (o)-[:OWNS]->(a)",
+    );
+    fixture.assert_query_succeeds(
+        "MATCH (o:Owner {name: 'bob'}), (a:Asset {name: 'car'})
+         INSERT (o)-[:GUARANTEES]->(a)",
+    );
+
+    // CASCADE: a plain DELETE removes the node together with its OWNS edges
+    fixture.assert_query_succeeds("MATCH (o:Owner {name: 'alice'}) DELETE o");
+    fixture.assert_first_value(
+        "MATCH ()-[r:OWNS]->() RETURN count(r) as count",
+        "count",
+        Value::Number(0.0),
+    );
+    fixture.assert_first_value(
+        "MATCH (o:Owner) RETURN count(o) as count",
+        "count",
+        Value::Number(1.0),
+    );
+
+    // RESTRICT: deleting an endpoint of a GUARANTEES edge is refused
+    fixture.assert_query_fails(
+        "MATCH (a:Asset {name: 'car'}) DELETE a",
+        "edge type GUARANTEES is declared ON DELETE RESTRICT",
+    );
+    fixture.assert_first_value(
+        "MATCH ()-[r:GUARANTEES]->() RETURN count(r) as count",
+        "count",
+        Value::Number(1.0),
+    );
+
+    // Edge types without a policy still require DETACH DELETE
+    fixture.assert_query_succeeds(
+        "MATCH (o:Owner {name: 'bob'}), (a:Asset {name: 'house'})
+         INSERT (o)-[:INSURES]->(a)",
+    );
+    fixture.assert_query_fails(
+        "MATCH (a:Asset {name: 'house'}) DELETE a",
+        "Use DETACH DELETE",
+    );
+}
+
+#[test]
+fn test_delete_with_return() {
+    let fixture = TestFixture::new().expect("Failed to create test fixture");
+    fixture
+        .setup_graph("test_delete_with_return")
+        .expect("Failed to setup graph");
+
+    fixture.assert_query_succeeds(
+        "INSERT (:Temp {id: 1}), (:Temp {id: 2}), (:Temp {id: 3}), (:Keep {id: 4})",
+    );
+
+    let result = fixture
+        .query("MATCH (n:Temp) WHERE n.id >= 2 DELETE n RETURN n.id AS id")
+        .expect("DELETE with RETURN should succeed");
+    assert_eq!(result.variables, vec!["id".to_string()]);
+    assert_eq!(result.rows_affected, 2);
+    let mut ids: Vec<f64> = result
+        .rows
+        .iter()
+        .map(|row| match row.values.get("id") {
+            Some(Value::Number(id)) => *id,
+            other => panic!("Expected a numeric id, got {:?}", other),
+        })
+        .collect();
+    ids.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    assert_eq!(ids, vec![2.0, 3.0]);
+
+    // The returned nodes are gone, the rest remain
+    fixture.assert_first_value(
+        "MATCH (n:Temp) RETURN count(n) as count",
+        "count",
+        Value::Number(1.0),
+    );
+    fixture.assert_first_value(
+        "MATCH (n:Keep) RETURN count(n) as count",
+        "count",
+        Value::Number(1.0),
+    );
+
+    // Returning the variable yields the deleted node itself
+    let result = fixture
+        .query("MATCH (n:Keep) DELETE n RETURN n")
+        .expect("DELETE with RETURN should succeed");
+    match result.rows[0].values.get("n") {
+        Some(Value::Node(node)) => {
+            assert_eq!(node.labels, vec!["Keep".to_string()]);
+            assert_eq!(node.properties.get("id"), Some(&Value::Number(4.0)));
+        }
+        other => panic!("Expected the deleted node, got {:?}", other),
+    }
+
+    // Nothing matched: no rows, but the requested columns
+    let result = fixture
+        .query("MATCH (n:Missing) DELETE n RETURN n.id")
+        .expect("DELETE with RETURN should succeed");
+    assert!(result.rows.is_empty());
+    assert_eq!(result.variables, vec!["n.id".to_string()]);
+}
+
 #[test]
 fn test_dml_data_driven_cases() {
     let test_suite = TestSuite {
@@ -632,6 +780,88 @@ fn test_dml_error_cases() {
     if result.is_ok() {}
 }
 
+#[test]
+fn test_property_constraints() {
+    let fixture = TestFixture::new().expect("Failed to create test fixture");
+    fixture
+        .setup_graph("test_property_constraints")
+        .expect("Failed to setup graph");
+
+    fixture.assert_query_succeeds("CREATE CONSTRAINT ON Person ASSERT email IS UNIQUE");
+    fixture
+        .assert_query_succeeds("CREATE CONSTRAINT person_name ON Person ASSERT name IS NOT NULL");
+
+    fixture.assert_query_succeeds("INSERT (:Person {name: 'Alice', email: 'a@example.com'})");
+    fixture.assert_query_fails(
+        "INSERT (:Person {name: 'Alicia', email: 'a@example.com'})",
+        "ConstraintViolation",
+    );
+    fixture.assert_query_fails("INSERT (:Person {email: 'b@example.com'})", "person_name");
+
+    // SET is checked too, and a failed statement leaves the graph unchanged
+    fixture.assert_query_succeeds("INSERT (:Person {name: 'Bob', email: 'b@example.com'})");
+    fixture.assert_query_fails(
+        "MATCH (p:Person {name: 'Bob'}) SET p.email = 'a@example.com'",
+        "Person_email_unique",
+    );
+    fixture.assert_first_value(
+        "MATCH (p:Person {name: 'Bob'}) RETURN p.email AS email",
+        "email",
+        Value::String("b@example.com".to_string()),
+    );
+
+    // Other labels are not constrained, and dropping the constraint lifts it
+    fixture.assert_query_succeeds("INSERT (:Company {email: 'a@example.com'})");
+    fixture.assert_query_succeeds("DROP CONSTRAINT Person_email_unique");
+    fixture.assert_query_succeeds("INSERT (:Person {name: 'Alicia', email: 'a@example.com'})");
+
+    // A constraint the existing data already violates cannot be created
+    fixture.assert_query_fails(
+        "CREATE CONSTRAINT ON Person ASSERT email IS UNIQUE",
+        "ConstraintViolation",
+    );
+}
+
+#[test]
+fn test_create_index_and_constraint_if_not_exists() {
+    let fixture = TestFixture::new().expect("Failed to create test fixture");
+    fixture
+        .setup_graph("test_create_if_not_exists")
+        .expect("Failed to setup graph");
+
+    fixture.assert_query_succeeds("CREATE INDEX IF NOT EXISTS person_email_idx ON Person (email)");
+    let result = fixture
+        .assert_query_succeeds("CREATE INDEX IF NOT EXISTS person_email_idx ON Person (email)");
+    assert_eq!(result.rows_affected, 0, "Second create should be a no-op");
+    fixture.assert_query_fails(
+        "CREATE INDEX person_email_idx ON Person (email)",
+        "already exists",
+    );
+
+    // Only one index was created: it can be dropped exactly once
+    fixture.assert_query_succeeds("DROP INDEX person_email_idx");
+    fixture.assert_query_fails("DROP INDEX person_email_idx", "does not exist");
+
+    fixture.assert_query_succeeds(
+        "CREATE CONSTRAINT IF NOT EXISTS person_email ON Person ASSERT email IS UNIQUE",
+    );
+    let result = fixture.assert_query_succeeds(
+        "CREATE CONSTRAINT IF NOT EXISTS person_email ON Person ASSERT email IS UNIQUE",
+    );
+    assert_eq!(result.rows_affected, 0, "Second create should be a no-op");
+    fixture.assert_query_fails(
+        "CREATE CONSTRAINT person_email ON Person ASSERT email IS UNIQUE",
+        "already exists",
+    );
+
+    // An unnamed IF NOT EXISTS constraint matches the same assertion under another name
+    let result = fixture
+        .assert_query_succeeds("CREATE CONSTRAINT IF NOT EXISTS ON Person ASSERT email IS UNIQUE");
+    assert_eq!(result.rows_affected, 0);
+    fixture.assert_query_succeeds("DROP CONSTRAINT person_email");
+    fixture.assert_query_fails("DROP CONSTRAINT Person_email_unique", "does not exist");
+}
+
 #[test]
 fn test_dml_performance() {
     let fixture = TestFixture::new().expect("Failed to create test fixture");
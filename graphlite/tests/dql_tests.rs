@@ -145,6 +145,42 @@ fn test_where_clause_operations() {
     assert!(!result.rows.is_empty());
 }
 
+#[test]
+fn test_inline_where_in_patterns() {
+    let fixture = TestFixture::new().expect("Failed to create test fixture");
+    fixture
+        .setup_graph("test_inline_where_in_patterns")
+        .expect("Failed to setup graph");
+    fixture
+        .insert_fraud_data()
+        .expect("Failed to insert fraud data");
+
+    let ids = |query: &str| -> Vec<Value> {
+        fixture
+            .assert_query_succeeds(query)
+            .rows
+            .iter()
+            .map(|row| row.values.get("id").cloned().unwrap_or(Value::Null))
+            .collect()
+    };
+
+    // Inline node predicate matches the trailing WHERE form
+    let inline = ids("MATCH (a:Account WHERE a.balance > 1000) RETURN a.id AS id ORDER BY id");
+    let trailing = ids("MATCH (a:Account) WHERE a.balance > 1000 RETURN a.id AS id ORDER BY id");
+    assert!(!inline.is_empty());
+    assert_eq!(inline, trailing);
+
+    // Inline predicates on edges and target nodes
+    let inline = ids(
+        "MATCH (a:Account)-[t:Transaction WHERE t.amount > 100]->(m:Merchant WHERE m.category = 'retail') \
+         RETURN a.id AS id ORDER BY id",
+    );
+    let trailing = ids("MATCH (a:Account)-[t:Transaction]->(m:Merchant) \
+         WHERE t.amount > 100 AND m.category = 'retail' \
+         RETURN a.id AS id ORDER BY id");
+    assert_eq!(inline, trailing);
+}
+
 #[test]
 fn test_return_clause_variations() {
     let fixture = TestFixture::new().expect("Failed to create test fixture");
@@ -335,6 +371,135 @@ fn test_call_statement_operations() {
     }
 }
 
+#[test]
+fn test_db_schemas_and_graphs_procedures() {
+    // A database without schemas lists nothing rather than failing
+    let temp_dir = tempfile::tempdir().expect("Failed to create temp dir");
+    let coordinator = graphlite::QueryCoordinator::from_path(temp_dir.path().join("empty_db"))
+        .expect("Failed to open database");
+    let session_id = coordinator
+        .create_simple_session("admin")
+        .expect("Failed to create session");
+    for procedure in ["CALL db.schemas()", "CALL db.graphs()"] {
+        let result = coordinator
+            .process_query(procedure, &session_id)
+            .expect("Procedure should succeed on an empty database");
+        assert!(
+            result.rows.is_empty(),
+            "{} should return no rows",
+            procedure
+        );
+    }
+
+    let fixture = TestFixture::new().expect("Failed to create test fixture");
+    let other_schema = format!("{}_other", fixture.schema_name());
+    fixture
+        .setup_graph("people")
+        .expect("Failed to setup graph");
+    fixture.assert_query_succeeds(
+        "INSERT (:Person {name: 'Alice'})-[:KNOWS]->(:Person {name: 'Bob'})",
+    );
+    fixture.assert_query_succeeds(&format!("CREATE SCHEMA /{}", other_schema));
+    fixture.assert_query_succeeds(&format!("CREATE GRAPH /{}/orders", other_schema));
+    fixture.assert_query_succeeds(&format!("CREATE GRAPH /{}/products", other_schema));
+
+    let schemas = fixture.assert_query_succeeds("CALL db.schemas()");
+    let graph_counts: HashMap<String, Value> = schemas
+        .rows
+        .iter()
+        .map(|row| match row.values.get("schema_name") {
+            Some(Value::String(name)) => (name.clone(), row.values["graph_count"].clone()),
+            other => panic!("Expected a schema name, got {:?}", other),
+        })
+        .collect();
+    assert_eq!(
+        graph_counts.get(fixture.schema_name()),
+        Some(&Value::Number(1.0))
+    );
+    assert_eq!(graph_counts.get(&other_schema), Some(&Value::Number(2.0)));
+
+    let graphs = fixture.assert_query_succeeds(&format!("CALL db.graphs('{}')", other_schema));
+    let names: Vec<&Value> = graphs
+        .rows
+        .iter()
+        .map(|row| &row.values["graph_name"])
+        .collect();
+    assert_eq!(
+        names,
+        vec![
+            &Value::String("orders".to_string()),
+            &Value::String("products".to_string())
+        ]
+    );
+
+    let people = fixture.assert_query_succeeds(&format!(
+        "CALL db.graphs() YIELD schema_name, graph_name, node_count, edge_count \
+         WHERE schema_name = '{}'",
+        fixture.schema_name()
+    ));
+    assert_eq!(people.rows.len(), 1);
+    let row = &people.rows[0];
+    assert_eq!(
+        row.values.get("graph_name"),
+        Some(&Value::String("people".to_string()))
+    );
+    assert_eq!(row.values.get("node_count"), Some(&Value::Number(2.0)));
+    assert_eq!(row.values.get("edge_count"), Some(&Value::Number(1.0)));
+}
+
+#[test]
+fn test_db_procedures_lists_registered_procedures() {
+    let fixture = TestFixture::new().expect("Failed to create test fixture");
+
+    let result = fixture.assert_query_succeeds(
+        "CALL db.procedures() YIELD name, signature, min_args, max_args, description",
+    );
+    let procedures: HashMap<String, &HashMap<String, Value>> = result
+        .rows
+        .iter()
+        .map(|row| match row.values.get("name") {
+            Some(Value::String(name)) => (name.clone(), &row.values),
+            other => panic!("Expected a procedure name, got {:?}", other),
+        })
+        .collect();
+    assert_eq!(
+        procedures.len(),
+        result.rows.len(),
+        "Each procedure is listed once"
+    );
+
+    for (name, min_args, max_args) in [
+        ("gql.list_schemas", 0.0, 0.0),
+        ("gql.authenticate_user", 2.0, 2.0),
+        ("gql.clear_cache", 0.0, 1.0),
+        ("db.schemas", 0.0, 0.0),
+        ("db.graphs", 0.0, 1.0),
+        ("db.connected_components", 2.0, 2.0),
+        ("db.procedures", 0.0, 0.0),
+    ] {
+        let procedure = procedures
+            .get(name)
+            .unwrap_or_else(|| panic!("{} should be listed", name));
+        assert_eq!(procedure.get("min_args"), Some(&Value::Number(min_args)));
+        assert_eq!(procedure.get("max_args"), Some(&Value::Number(max_args)));
+        assert!(matches!(procedure.get("signature"), Some(Value::String(s)) if s.starts_with('(')));
+        assert!(
+            matches!(procedure.get("description"), Some(Value::String(s)) if !s.is_empty()),
+            "{} should have a description",
+            name
+        );
+    }
+    assert_eq!(
+        procedures["db.graphs"].get("signature"),
+        Some(&Value::String("([schema_name :: STRING])".to_string()))
+    );
+
+    fixture.assert_query_fails(
+        "CALL db.procedures('extra')",
+        "db.procedures expects no arguments",
+    );
+}
+
 #[test]
 fn test_dql_data_driven_cases() {
     let test_suite = TestSuite {
@@ -679,3 +844,613 @@ fn test_query_optimization() {
         "Less selective query should still be reasonable"
     );
 }
+
+#[test]
+fn test_order_by_limit_matches_full_sort() {
+    let fixture = TestFixture::new().expect("Failed to create test fixture");
+    fixture
+        .setup_graph("test_order_by_limit_top_k")
+        .expect("Failed to setup graph");
+
+    // Scores repeat, so ties are broken by id to make the expected order unique
+    let nodes: Vec<String> = (0..60)
+        .map(|i| format!("(:Player {{id: {}, score: {}}})", i, (i * 7) % 10))
+        .collect();
+    for chunk in nodes.chunks(20) {
+        fixture
+            .query(&format!("INSERT {}", chunk.join(", ")))
+            .expect("Failed to insert players");
+    }
+
+    let ids = |query: &str| -> Vec<Value> {
+        fixture
+            .assert_query_succeeds(query)
+            .rows
+            .iter()
+            .map(|row| row.values.get("id").cloned().unwrap_or(Value::Null))
+            .collect()
+    };
+
+    let full = ids("MATCH (p:Player) RETURN p.id AS id, p.score AS score ORDER BY score DESC, id");
+    assert_eq!(full.len(), 60);
+
+    let top = ids(
+        "MATCH (p:Player) RETURN p.id AS id, p.score AS score ORDER BY score DESC, id LIMIT 10",
+    );
+    assert_eq!(top, full[..10].to_vec());
+
+    let page = ids(
+        "MATCH (p:Player) RETURN p.id AS id, p.score AS score ORDER BY score DESC, id LIMIT 10 OFFSET 5",
+    );
+    assert_eq!(page, full[5..15].to_vec());
+}
+
+#[test]
+fn test_order_by_nulls_first_and_last() {
+    let fixture = TestFixture::new().expect("Failed to create test fixture");
+    fixture
+        .setup_graph("test_order_by_nulls_placement")
+        .expect("Failed to setup graph");
+    fixture
+        .query(
+            "INSERT (:Player {id: 1, score: 10}), (:Player {id: 2}), (:Player {id: 3, score: 30}), \
+             (:Player {id: 4}), (:Player {id: 5, score: 20})",
+        )
+        .expect("Failed to insert players");
+
+    let ids = |order: &str| -> Vec<f64> {
+        fixture
+            .assert_query_succeeds(&format!(
+                "MATCH (p:Player) RETURN p.id AS id, p.score AS score ORDER BY {}",
+                order
+            ))
+            .rows
+            .iter()
+            .map(|row| row.values.get("id").and_then(Value::as_number).unwrap())
+            .collect()
+    };
+
+    // Explicit placement holds in both directions
+    assert_eq!(
+        ids("score DESC NULLS LAST, id"),
+        vec![3.0, 5.0, 1.0, 2.0, 4.0]
+    );
+    assert_eq!(
+        ids("score ASC NULLS LAST, id"),
+        vec![1.0, 5.0, 3.0, 2.0, 4.0]
+    );
+    assert_eq!(
+        ids("score ASC NULLS FIRST, id"),
+        vec![2.0, 4.0, 1.0, 5.0, 3.0]
+    );
+    assert_eq!(
+        ids("score DESC NULLS FIRST, id"),
+        vec![2.0, 4.0, 3.0, 5.0, 1.0]
+    );
+
+    // By default NULL sorts as the largest value
+    assert_eq!(ids("score, id"), ids("score ASC NULLS LAST, id"));
+    assert_eq!(ids("score DESC, id"), ids("score DESC NULLS FIRST, id"));
+
+    // The bounded top-K path places NULLs the same way
+    assert_eq!(
+        ids("score DESC NULLS LAST, id LIMIT 3"),
+        vec![3.0, 5.0, 1.0]
+    );
+    assert_eq!(
+        ids("score ASC NULLS FIRST, id LIMIT 3"),
+        vec![2.0, 4.0, 1.0]
+    );
+}
+
+#[test]
+fn test_session_collation_for_sorting_and_equality() {
+    let fixture = TestFixture::new().expect("Failed to create test fixture");
+    fixture
+        .setup_graph("test_session_collation")
+        .expect("Failed to setup graph");
+    fixture
+        .query(
+            "INSERT (:Fruit {id: 1, name: 'apple'}), (:Fruit {id: 2, name: 'Äpple'}), \
+             (:Fruit {id: 3, name: 'banana'}), (:Fruit {id: 4, name: 'APPLE'}), \
+             (:Fruit {id: 5, name: 'Cherry'})",
+        )
+        .expect("Failed to insert fruit");
+
+    let ids = |order: &str| -> Vec<f64> {
+        fixture
+            .assert_query_succeeds(&format!(
+                "MATCH (f:Fruit) RETURN f.id AS id, f.name AS name ORDER BY {}",
+                order
+            ))
+            .rows
+            .iter()
+            .map(|row| row.values.get("id").and_then(Value::as_number).unwrap())
+            .collect()
+    };
+    let apples = "MATCH (f:Fruit) WHERE f.name = 'apple' RETURN count(f) AS apples";
+
+    // Codepoint order: uppercase before lowercase, accented letters last
+    assert_eq!(ids("name, id"), vec![4.0, 5.0, 1.0, 3.0, 2.0]);
+    fixture.assert_first_value(apples, "apples", Value::Number(1.0));
+
+    fixture.assert_query_succeeds("SESSION SET COLLATION 'en_US_ci'");
+    assert_eq!(ids("name, id"), vec![1.0, 2.0, 4.0, 3.0, 5.0]);
+    assert_eq!(ids("name, id LIMIT 3"), vec![1.0, 2.0, 4.0]);
+    fixture.assert_first_value(apples, "apples", Value::Number(3.0));
+
+    fixture.assert_query_fails("SESSION SET COLLATION 'klingon'", "Unknown collation");
+
+    fixture.assert_query_succeeds("SESSION SET COLLATION 'codepoint'");
+    assert_eq!(ids("name, id"), vec![4.0, 5.0, 1.0, 3.0, 2.0]);
+    fixture.assert_first_value(apples, "apples", Value::Number(1.0));
+}
+
+#[test]
+fn test_parameterized_limit_and_skip() {
+    let fixture = TestFixture::new().expect("Failed to create test fixture");
+    fixture
+        .setup_graph("test_parameterized_limit_and_skip")
+        .expect("Failed to setup graph");
+
+    let nodes: Vec<String> = (0..20).map(|i| format!("(:Item {{id: {}}})", i)).collect();
+    fixture
+        .query(&format!("INSERT {}", nodes.join(", ")))
+        .expect("Failed to insert items");
+
+    let query = "MATCH (i:Item) RETURN i.id AS id ORDER BY id LIMIT $k";
+    fixture.assert_query_succeeds("SESSION SET VALUE $k = 5");
+    assert_eq!(fixture.assert_query_succeeds(query).rows.len(), 5);
+    fixture.assert_query_succeeds("SESSION SET VALUE $k = 10");
+    assert_eq!(fixture.assert_query_succeeds(query).rows.len(), 10);
+
+    fixture.assert_query_succeeds("SESSION SET VALUE $s = 15");
+    let page = fixture
+        .assert_query_succeeds("MATCH (i:Item) RETURN i.id AS id ORDER BY id LIMIT $k SKIP $s");
+    assert_eq!(page.rows.len(), 5);
+    assert_eq!(page.rows[0].values.get("id"), Some(&Value::Number(15.0)));
+
+    fixture.assert_query_succeeds("SESSION SET VALUE $k = -1");
+    fixture.assert_query_fails(query, "must not be negative");
+    fixture.assert_query_succeeds("SESSION SET VALUE $k = NULL");
+    fixture.assert_query_fails(query, "must not be null");
+    fixture.assert_query_fails(
+        "MATCH (i:Item) RETURN i.id AS id LIMIT $unbound",
+        "not bound",
+    );
+    assert!(fixture
+        .query("MATCH (i:Item) RETURN i.id AS id LIMIT -1")
+        .is_err());
+}
+
+#[test]
+fn test_window_functions() {
+    let fixture = TestFixture::new().expect("Failed to create test fixture");
+    fixture
+        .setup_graph("test_window_functions")
+        .expect("Failed to setup graph");
+
+    fixture
+        .query(
+            "INSERT (:Person {name: 'Ann', score: 90, city: 'Oslo'}), \
+             (:Person {name: 'Bob', score: 80, city: 'Rome'}), \
+             (:Person {name: 'Cid', score: 80, city: 'Oslo'}), \
+             (:Person {name: 'Dee', score: 70, city: 'Rome'}), \
+             (:Person {name: 'Eve', score: 60, city: 'Oslo'})",
+        )
+        .expect("Failed to insert persons");
+
+    let result = fixture.assert_query_succeeds(
+        "MATCH (p:Person) RETURN p.name AS name, \
+         ROW_NUMBER() OVER (ORDER BY p.score DESC) AS rn, \
+         RANK() OVER (ORDER BY p.score DESC) AS rnk, \
+         COUNT(*) OVER (PARTITION BY p.city) AS city_count \
+         ORDER BY rn",
+    );
+    let column = |name: &str| -> Vec<Value> {
+        result
+            .rows
+            .iter()
+            .map(|row| row.values.get(name).cloned().unwrap_or(Value::Null))
+            .collect()
+    };
+
+    // Row numbers are 1..n without gaps, ranks share a number on ties
+    let numbers =
+        |values: &[f64]| -> Vec<Value> { values.iter().map(|v| Value::Number(*v)).collect() };
+    assert_eq!(column("rn"), numbers(&[1.0, 2.0, 3.0, 4.0, 5.0]));
+    assert_eq!(column("rnk"), numbers(&[1.0, 2.0, 2.0, 4.0, 5.0]));
+    assert_eq!(column("name")[0], Value::String("Ann".to_string()));
+    assert_eq!(column("name")[4], Value::String("Eve".to_string()));
+
+    // Partition counts: Oslo has three people, Rome two
+    for row in &result.rows {
+        let expected = match row.values.get("name") {
+            Some(Value::String(name)) if name == "Bob" || name == "Dee" => 2.0,
+            _ => 3.0,
+        };
+        assert_eq!(row.values.get("city_count"), Some(&Value::Number(expected)));
+    }
+
+    fixture.assert_query_fails(
+        "MATCH (p:Person) RETURN LAG(p.score) OVER (ORDER BY p.score) AS prev",
+        "window function",
+    );
+}
+
+#[test]
+fn test_list_indexing_and_slicing() {
+    let fixture = TestFixture::new().expect("Failed to create test fixture");
+    fixture
+        .setup_graph("test_list_indexing_and_slicing")
+        .expect("Failed to setup graph");
+
+    let letters = |items: &[&str]| {
+        Value::List(
+            items
+                .iter()
+                .map(|item| Value::String(item.to_string()))
+                .collect(),
+        )
+    };
+
+    fixture.assert_first_value(
+        "RETURN ['a', 'b', 'c'][1] AS item",
+        "item",
+        Value::String("b".to_string()),
+    );
+    fixture.assert_first_value(
+        "RETURN ['a', 'b', 'c'][-1] AS item",
+        "item",
+        Value::String("c".to_string()),
+    );
+    fixture.assert_first_value("RETURN ['a', 'b', 'c'][3] AS item", "item", Value::Null);
+    fixture.assert_first_value("RETURN ['a', 'b', 'c'][-4] AS item", "item", Value::Null);
+
+    fixture.assert_first_value(
+        "RETURN ['a', 'b', 'c'][1..3] AS items",
+        "items",
+        letters(&["b", "c"]),
+    );
+    fixture.assert_first_value(
+        "RETURN ['a', 'b', 'c'][..-1] AS items",
+        "items",
+        letters(&["a", "b"]),
+    );
+    fixture.assert_first_value(
+        "RETURN ['a', 'b', 'c'][1..10] AS items",
+        "items",
+        letters(&["b", "c"]),
+    );
+    fixture.assert_first_value(
+        "RETURN ['a', 'b', 'c'][2..1] AS items",
+        "items",
+        letters(&[]),
+    );
+
+    fixture
+        .query("INSERT (:Doc {tags: ['x', 'y', 'z']})")
+        .expect("Failed to insert doc");
+    fixture.assert_first_value(
+        "MATCH (d:Doc) RETURN d.tags[-2..] AS tags",
+        "tags",
+        letters(&["y", "z"]),
+    );
+}
+
+#[test]
+fn test_scan_hints_force_index_or_sequential_scan() {
+    let fixture = TestFixture::new().expect("Failed to create test fixture");
+    fixture
+        .setup_graph("test_scan_hints")
+        .expect("Failed to setup graph");
+    fixture.assert_query_succeeds(
+        "INSERT (:Person {name: 'Alice', email: 'alice@example.com'}), \
+         (:Person {name: 'Bob', email: 'bob@example.com'})",
+    );
+
+    // Without an index the hint is ignored with a warning
+    let plan = fixture
+        .explain("MATCH (p:Person) USING INDEX p:Person(email) WHERE p.email = 'bob@example.com' RETURN p.name")
+        .expect("Failed to explain query");
+    assert!(plan.operator("NodeIndexScan").is_none());
+    assert!(plan.operator("NodeSeqScan").is_some());
+    assert_eq!(plan.warnings.len(), 1);
+    assert!(plan.warnings[0].contains("Person(email)"));
+
+    fixture.assert_query_succeeds("CREATE INDEX person_email_idx ON Person (email)");
+
+    let plan = fixture
+        .explain("MATCH (p:Person) USING INDEX p:Person(email) WHERE p.email = 'bob@example.com' RETURN p.name")
+        .expect("Failed to explain query");
+    assert!(plan.operator("NodeIndexScan").is_some());
+    assert!(plan.operator("NodeSeqScan").is_none());
+    assert!(plan.warnings.is_empty());
+
+    let plan = fixture
+        .explain(
+            "MATCH (p:Person) USING SCAN p:Person WHERE p.email = 'bob@example.com' RETURN p.name",
+        )
+        .expect("Failed to explain query");
+    assert!(plan.operator("NodeSeqScan").is_some());
+    assert!(plan.operator("NodeIndexScan").is_none());
+
+    // Hinted queries still return the same rows
+    fixture.assert_first_value(
+        "MATCH (p:Person) USING INDEX p:Person(email) WHERE p.email = 'bob@example.com' RETURN p.name",
+        "p.name",
+        Value::String("Bob".to_string()),
+    );
+
+    // Each index scan looks up the equalities of its own filter
+    let result = fixture.assert_query_succeeds(
+        "MATCH (p:Person) USING INDEX p:Person(email) WHERE p.email = 'alice@example.com' RETURN p.name \
+         UNION ALL \
+         MATCH (p:Person) USING INDEX p:Person(email) WHERE p.email = 'bob@example.com' RETURN p.name",
+    );
+    let mut names: Vec<_> = result
+        .rows
+        .iter()
+        .map(|row| row.values.get("p.name").cloned())
+        .collect();
+    names.sort_by_key(|name| format!("{:?}", name));
+    assert_eq!(
+        names,
+        vec![
+            Some(Value::String("Alice".to_string())),
+            Some(Value::String("Bob".to_string()))
+        ]
+    );
+}
+
+#[test]
+fn test_index_scan_reads_the_graph_index_across_reopen() {
+    let temp_dir = tempfile::tempdir().expect("Failed to create temp dir");
+    let db_path = temp_dir.path().join("index_db");
+    let hinted = "MATCH (p:Person) USING INDEX p:Person(email) \
+                  WHERE p.email = 'bob@example.com' RETURN p.name ORDER BY p.name";
+    let names = |coordinator: &graphlite::QueryCoordinator, session_id: &str| {
+        let result = coordinator
+            .process_query(hinted, session_id)
+            .expect("Hinted query failed");
+        result
+            .rows
+            .iter()
+            .map(|row| row.values.get("p.name").cloned())
+            .collect::<Vec<_>>()
+    };
+
+    let coordinator =
+        graphlite::QueryCoordinator::from_path(&db_path).expect("Failed to open database");
+    let session_id = coordinator
+        .create_simple_session("admin")
+        .expect("Failed to create session");
+    for statement in [
+        "CREATE SCHEMA /index_schema",
+        "CREATE GRAPH /index_schema/graph_a",
+        "CREATE GRAPH /index_schema/graph_b",
+        "SESSION SET GRAPH /index_schema/graph_b",
+        "INSERT (:Person {name: 'Bob', email: 'bob@example.com'})",
+        "SESSION SET GRAPH /index_schema/graph_a",
+        "INSERT (:Person {name: 'Alice', email: 'alice@example.com'}), \
+         (:Person {name: 'Bob', email: 'bob@example.com'}), \
+         (:Person {name: 'Carol', email: 'carol@example.com'})",
+        "CREATE INDEX person_email_idx ON Person (email)",
+    ] {
+        coordinator
+            .process_query(statement, &session_id)
+            .unwrap_or_else(|e| panic!("{} failed: {}", statement, e));
+    }
+
+    // The scan reads only the node the index finds
+    let analyzed = coordinator
+        .explain_analyze(hinted, &session_id)
+        .expect("Failed to analyze query");
+    let scan = analyzed
+        .operator("NodeIndexScan")
+        .expect("Hint should force an index scan");
+    assert_eq!(scan.actual_rows, 1, "{}", analyzed.format_tree());
+    assert_eq!(
+        names(&coordinator, &session_id),
+        vec![Some(Value::String("Bob".to_string()))]
+    );
+
+    // Changed nodes are found once the index has been read
+    coordinator
+        .process_query(
+            "MATCH (p:Person {name: 'Carol'}) SET p.email = 'bob@example.com'",
+            &session_id,
+        )
+        .expect("Update failed");
+    assert_eq!(
+        names(&coordinator, &session_id),
+        vec![
+            Some(Value::String("Bob".to_string())),
+            Some(Value::String("Carol".to_string()))
+        ]
+    );
+
+    // The index belongs to graph_a, so graph_b ignores the hint
+    coordinator
+        .process_query("SESSION SET GRAPH /index_schema/graph_b", &session_id)
+        .expect("Failed to set graph");
+    let plan = coordinator
+        .explain_query_in_session(hinted, &session_id)
+        .expect("Failed to explain query");
+    assert!(plan.operator("NodeIndexScan").is_none());
+    assert_eq!(plan.warnings.len(), 1);
+    drop(coordinator);
+
+    // The index is still there after reopening the database
+    let coordinator =
+        graphlite::QueryCoordinator::from_path(&db_path).expect("Failed to reopen database");
+    let session_id = coordinator
+        .create_simple_session("admin")
+        .expect("Failed to create session");
+    coordinator
+        .process_query("SESSION SET GRAPH /index_schema/graph_a", &session_id)
+        .expect("Failed to set graph");
+    let plan = coordinator
+        .explain_query_in_session(hinted, &session_id)
+        .expect("Failed to explain query");
+    assert!(plan.operator("NodeIndexScan").is_some());
+    assert!(plan.warnings.is_empty());
+    assert_eq!(
+        names(&coordinator, &session_id),
+        vec![
+            Some(Value::String("Bob".to_string())),
+            Some(Value::String("Carol".to_string()))
+        ]
+    );
+}
+
+#[test]
+fn test_distinct_of_indexed_property_reads_the_index() {
+    let fixture = TestFixture::new().expect("Failed to create test fixture");
+    fixture
+        .setup_graph("test_index_distinct")
+        .expect("Failed to setup graph");
+    fixture.assert_query_succeeds(
+        "INSERT (:Person {name: 'Ann', city: 'Oslo'}), (:Person {name: 'Ben', city: 'Rome'}), \
+         (:Person {name: 'Cat', city: 'Oslo'}), (:Person {name: 'Dan'}), \
+         (:Person {name: 'Eve', city: 'Lima'})",
+    );
+    let query = "MATCH (p:Person) RETURN DISTINCT p.city";
+    let cities = || -> Vec<String> {
+        let mut cities: Vec<String> = fixture
+            .assert_query_succeeds(query)
+            .rows
+            .iter()
+            .map(|row| format!("{:?}", row.values.get("p.city")))
+            .collect();
+        cities.sort();
+        cities
+    };
+
+    // Without an index the scanned rows are deduplicated
+    let plan = fixture.explain(query).expect("Failed to explain query");
+    assert!(plan.operator("Distinct").is_some());
+    assert!(plan.operator("IndexDistinctScan").is_none());
+    let baseline = cities();
+    assert_eq!(baseline.len(), 4);
+
+    fixture.assert_query_succeeds("CREATE INDEX person_city_idx ON Person (city)");
+
+    let plan = fixture.explain(query).expect("Failed to explain query");
+    assert!(
+        plan.operator("IndexDistinctScan").is_some(),
+        "{}",
+        plan.format_tree()
+    );
+    assert!(plan.operator("Distinct").is_none());
+    assert!(plan.operator("NodeSeqScan").is_none());
+    assert_eq!(cities(), baseline);
+
+    // The index sees nodes added after it was read
+    fixture.assert_query_succeeds("INSERT (:Person {name: 'Fay', city: 'Kyiv'})");
+    let mut expected = baseline.clone();
+    expected.push(format!("{:?}", Some(Value::String("Kyiv".to_string()))));
+    expected.sort();
+    assert_eq!(cities(), expected);
+
+    // Updates and deletes move nodes between or out of the index entries
+    fixture.assert_query_succeeds("MATCH (p:Person {name: 'Eve'}) SET p.city = 'Oslo'");
+    fixture.assert_query_succeeds("MATCH (p:Person {name: 'Ben'}) DELETE p");
+    let mut expected: Vec<String> = ["Kyiv", "Oslo"]
+        .into_iter()
+        .map(|city| format!("{:?}", Some(Value::String(city.to_string()))))
+        .collect();
+    expected.push(format!("{:?}", Some(Value::Null)));
+    expected.sort();
+    assert_eq!(cities(), expected);
+
+    // Other DISTINCT projections keep deduplicating the scanned rows
+    let plan = fixture
+        .explain("MATCH (p:Person) RETURN DISTINCT p.name")
+        .expect("Failed to explain query");
+    assert!(plan.operator("IndexDistinctScan").is_none());
+    assert!(plan.operator("Distinct").is_some());
+}
+
+#[test]
+fn test_between_selects_inclusive_range() {
+    let fixture = TestFixture::new().expect("Failed to create test fixture");
+    fixture
+        .setup_graph("test_between")
+        .expect("Failed to setup graph");
+    fixture.assert_query_succeeds(
+        "INSERT (:Person {name: 'Ann', age: 17}), (:Person {name: 'Ben', age: 18}), \
+         (:Person {name: 'Cat', age: 40}), (:Person {name: 'Dan', age: 65}), \
+         (:Person {name: 'Eve', age: 66}), (:Person {name: 'Fay'})",
+    );
+
+    let names = |query: &str| -> Vec<Value> {
+        fixture
+            .assert_query_succeeds(query)
+            .rows
+            .iter()
+            .map(|row| row.values.get("p.name").cloned().unwrap_or(Value::Null))
+            .collect()
+    };
+    let strings = |names: &[&str]| -> Vec<Value> {
+        names
+            .iter()
+            .map(|name| Value::String(name.to_string()))
+            .collect()
+    };
+
+    // Both bounds are included, and a missing age matches neither form
+    let between =
+        names("MATCH (p:Person) WHERE p.age BETWEEN 18 AND 65 RETURN p.name ORDER BY p.name");
+    assert_eq!(between, strings(&["Ben", "Cat", "Dan"]));
+    assert_eq!(
+        between,
+        names("MATCH (p:Person) WHERE 18 <= p.age AND p.age <= 65 RETURN p.name ORDER BY p.name")
+    );
+    assert_eq!(
+        names("MATCH (p:Person) WHERE p.age not between 18 AND 65 RETURN p.name ORDER BY p.name"),
+        strings(&["Ann", "Eve"])
+    );
+
+    // Bounds can be expressions, and BETWEEN combines with other predicates
+    assert_eq!(
+        names(
+            "MATCH (p:Person) WHERE p.age BETWEEN 10 + 8 AND 20 * 2 AND p.name <> 'Ben' \
+             RETURN p.name ORDER BY p.name"
+        ),
+        strings(&["Cat"])
+    );
+}
+
+#[test]
+fn test_order_by_limit_holds_only_the_kept_rows() {
+    let fixture = TestFixture::new().expect("Failed to create test fixture");
+    fixture
+        .setup_graph("test_order_by_limit_rows_held")
+        .expect("Failed to setup graph");
+
+    let nodes: Vec<String> = (0..500)
+        .map(|i| format!("(:Player {{id: {}, score: {}}})", i, (i * 37) % 500))
+        .collect();
+    for chunk in nodes.chunks(20) {
+        fixture
+            .query(&format!("INSERT {}", chunk.join(", ")))
+            .expect("Failed to insert players");
+    }
+
+    let query = "MATCH (p:Player) WHERE p.id >= 100 RETURN p.id AS id, p.score AS score ORDER BY score DESC LIMIT 10 OFFSET 5";
+    let analyzed = fixture
+        .explain_analyze(query)
+        .expect("Failed to analyze query");
+    let top_k = analyzed
+        .operator("TopK")
+        .unwrap_or_else(|| panic!("Plan should use TopK:\n{}", analyzed.format_tree()));
+
+    // All 400 matching rows flow through, but only the best 15 are ever held
+    assert_eq!(top_k.actual_rows, 10, "{}", analyzed.format_tree());
+    assert_eq!(top_k.rows_held, Some(15), "{}", analyzed.format_tree());
+    let project = top_k
+        .find("Project")
+        .expect("TopK should read a projection");
+    assert_eq!(project.actual_rows, 400, "{}", analyzed.format_tree());
+}
@@ -84,7 +84,7 @@ fn test_duplicate_edge_shows_warning() {
 
 #[test]
 fn test_duplicate_edge_with_regular_insert() {
-    // Test that duplicate edge detection works with inline node creation
+    // A plain INSERT repeating stored nodes and their edge fails
     let fixture = TestFixture::new().expect("Failed to create fixture");
 
     // Setup graph context
@@ -100,28 +100,26 @@ fn test_duplicate_edge_with_regular_insert() {
     assert!(result1.rows_affected >= 1, "Should create nodes and edge");
     assert!(result1.warnings.is_empty());
 
-    // Second insertion - nodes already exist, so only edge insertion is attempted
-    // Since nodes are duplicates, they won't be re-created, but we'll try to create the edge again
-    let result2 = fixture
+    // Second insertion without ON CONFLICT is an error
+    let error = fixture
         .query("INSERT (:User {id: 'u1'})-[:FOLLOWS {since: '2020-01-01'}]->(:User {id: 'u2'})")
-        .expect("Second insertion should succeed");
-
-    // Should have warnings - at minimum for duplicate nodes
-    // Edge warning appears only if nodes already existed and edge is duplicate
-    assert!(
-        !result2.warnings.is_empty(),
-        "Should have warnings for duplicates"
-    );
+        .expect_err("Duplicate insertion should fail");
+    assert!(error.contains("already exists"), "got: {}", error);
 
-    // Verify we have node duplicate warnings
-    let has_node_warnings = result2
-        .warnings
-        .iter()
-        .any(|w| w.contains("Duplicate node detected"));
+    // With ON CONFLICT DO NOTHING the nodes and the edge are skipped
+    let result3 = fixture
+        .query(
+            "INSERT (:User {id: 'u1'})-[:FOLLOWS {since: '2020-01-01'}]->(:User {id: 'u2'}) \
+             ON CONFLICT DO NOTHING",
+        )
+        .expect("Duplicate insertion ON CONFLICT DO NOTHING should succeed");
+    assert_eq!(result3.rows_affected, 0);
 
-    assert!(
-        has_node_warnings,
-        "Should have 'Duplicate node detected' warnings, got: {:?}",
-        result2.warnings
+    let count_result = fixture
+        .query("MATCH ()-[r:FOLLOWS]->() RETURN COUNT(r) AS edge_count")
+        .expect("Count query should succeed");
+    assert_eq!(
+        count_result.rows[0].values["edge_count"].as_number(),
+        Some(1.0)
     );
 }
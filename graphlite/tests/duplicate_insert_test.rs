@@ -6,7 +6,7 @@ mod testutils;
 use testutils::test_fixture::TestFixture;
 
 #[test]
-fn test_duplicate_insert_creates_two_nodes() {
+fn test_duplicate_insert_fails_without_on_conflict() {
     let fixture = TestFixture::new().expect("Failed to create fixture");
 
     // Setup a fresh graph for this test
@@ -19,65 +19,14 @@ fn test_duplicate_insert_creates_two_nodes() {
         .query("INSERT (:Person {name: 'Charlie', age: 35, city: 'Seattle'})")
         .expect("First INSERT should succeed");
 
-    // Execute SECOND INSERT (identical)
-    fixture
-        .query("INSERT (:Person {name: 'Charlie', age: 35, city: 'Seattle'})")
-        .expect("Second INSERT should succeed");
-
-    // Query to count how many Person nodes exist
-    let count_result = fixture
-        .query("MATCH (p:Person) RETURN count(p) as total")
-        .expect("Count query should succeed");
-
-    // Verify the count
-    assert_eq!(
-        count_result.rows.len(),
-        1,
-        "Should return exactly one row for count query"
-    );
-
-    let count_value = count_result.rows[0]
-        .values
-        .get("total")
-        .expect("Should have 'total' field in result");
-
-    let actual_count = match count_value {
-        graphlite::Value::Number(n) => *n,
-        _ => panic!("Count should be a number, got: {:?}", count_value),
-    };
-
-    // Assert: GraphLite's hash-based deduplication means only 1 node is created
-    assert_eq!(
-        actual_count, 1.0,
-        "Expected 1 node (hash-based deduplication), but found {} nodes",
-        actual_count
-    );
-
-    // Verify that the second INSERT returned a warning about the duplicate
-    // Let's re-run the INSERT to capture the warning
-    let duplicate_insert_result = fixture
+    // Execute SECOND INSERT (identical): without ON CONFLICT it is an error
+    let error = fixture
         .query("INSERT (:Person {name: 'Charlie', age: 35, city: 'Seattle'})")
-        .expect("Duplicate INSERT should succeed with warning");
-
-    // Verify warning was generated
-    assert!(
-        !duplicate_insert_result.warnings.is_empty(),
-        "Expected warning for duplicate INSERT, but got none"
-    );
-
-    // Verify warning message content
-    let warning = &duplicate_insert_result.warnings[0];
+        .expect_err("Duplicate INSERT should fail");
     assert!(
-        warning.contains("Duplicate node detected"),
-        "Warning should mention 'Duplicate node detected', got: {}",
-        warning
-    );
-
-    // Verify rows_affected is 0 for duplicate insert
-    assert_eq!(
-        duplicate_insert_result.rows_affected, 0,
-        "Expected rows_affected = 0 for duplicate INSERT, got {}",
-        duplicate_insert_result.rows_affected
+        error.contains("already exists") && error.contains("ON CONFLICT"),
+        "Error should name the conflict, got: {}",
+        error
     );
 
     // Query to see all Person nodes with their IDs
@@ -110,7 +59,7 @@ fn test_duplicate_insert_creates_two_nodes() {
 }
 
 #[test]
-fn test_insert_with_same_content_node_ids() {
+fn test_failed_duplicate_insert_stores_nothing() {
     let fixture = TestFixture::new().expect("Failed to create fixture");
 
     // Setup a fresh graph for this test
@@ -118,69 +67,28 @@ fn test_insert_with_same_content_node_ids() {
         .setup_graph("node_id_test_graph")
         .expect("Failed to setup graph");
 
-    // Insert first node
     fixture
         .query("INSERT (n:Person {name: 'Alice', age: 30})")
         .expect("First INSERT should succeed");
 
-    // Insert second identical node
-    fixture
-        .query("INSERT (n:Person {name: 'Alice', age: 30})")
-        .expect("Second INSERT should succeed");
+    // The new node is not kept when another node of the statement conflicts
+    assert!(fixture
+        .query("INSERT (:Person {name: 'Bob', age: 31}), (:Person {name: 'Alice', age: 30})")
+        .is_err());
 
-    // Get all node IDs
     let result = fixture
-        .query("MATCH (p:Person) RETURN p.name, ID(p) as node_id")
+        .query("MATCH (p:Person) RETURN p.name")
         .expect("Query should succeed");
-
-    // Verify only one node was created
     assert_eq!(
         result.rows.len(),
         1,
-        "Expected exactly 1 Person node (hash-based deduplication), found {}",
+        "Expected only the first Person node, found {}",
         result.rows.len()
     );
-
-    // Verify the node has correct name
-    let person = &result.rows[0];
-    assert_eq!(
-        person.values.get("p.name"),
-        Some(&graphlite::Value::String("Alice".to_string())),
-        "Person node should have name 'Alice'"
-    );
-
-    // Get the node ID (should be the same for both inserts due to hash-based deduplication)
-    let node_id = person
-        .values
-        .get("node_id")
-        .expect("Should have 'node_id' field");
-
-    // Re-run the second INSERT to verify it returns a warning
-    let duplicate_result = fixture
-        .query("INSERT (n:Person {name: 'Alice', age: 30})")
-        .expect("Duplicate INSERT should succeed with warning");
-
-    // Verify warning exists
-    assert!(
-        !duplicate_result.warnings.is_empty(),
-        "Expected warning for duplicate INSERT"
-    );
-    assert!(
-        duplicate_result.warnings[0].contains("Duplicate node detected"),
-        "Warning should mention duplicate detection, got: {}",
-        duplicate_result.warnings[0]
-    );
-
-    // Verify rows_affected is 0
-    assert_eq!(
-        duplicate_result.rows_affected, 0,
-        "Expected rows_affected = 0 for duplicate INSERT, got {}",
-        duplicate_result.rows_affected
-    );
 }
 
 #[test]
-fn test_multiple_inserts_in_sequence() {
+fn test_repeated_pattern_in_one_insert_is_one_node() {
     let fixture = TestFixture::new().expect("Failed to create fixture");
 
     // Setup a fresh graph for this test
@@ -188,60 +96,72 @@ fn test_multiple_inserts_in_sequence() {
         .setup_graph("sequence_test_graph")
         .expect("Failed to setup graph");
 
-    // Execute 3 identical inserts
-    for i in 1..=3 {
-        fixture
-            .query("INSERT (:Person {name: 'Bob', age: 25})")
-            .unwrap_or_else(|_| panic!("INSERT {} should succeed", i));
-    }
+    // Identical patterns of one statement name the same node and edge
+    let result = fixture
+        .query(
+            "INSERT (:Person {name: 'Bob', age: 25}), (:Person {name: 'Bob', age: 25}), \
+             (:User {id: 'u1'})-[:FOLLOWS]->(:User {id: 'u2'}), \
+             (:User {id: 'u1'})-[:FOLLOWS]->(:User {id: 'u2'})",
+        )
+        .expect("INSERT should succeed");
+    assert_eq!(result.rows_affected, 4);
+    assert!(result.warnings.is_empty(), "{:?}", result.warnings);
 
-    // Check total count
     let count_result = fixture
         .query("MATCH (p:Person {name: 'Bob'}) RETURN count(p) as total")
         .expect("Count query should succeed");
-
-    // Verify the count
     assert_eq!(
-        count_result.rows.len(),
-        1,
-        "Should return exactly one row for count query"
+        count_result.rows[0].values.get("total"),
+        Some(&graphlite::Value::Number(1.0))
     );
+}
 
-    let count_value = count_result.rows[0]
-        .values
-        .get("total")
-        .expect("Should have 'total' field in result");
+#[test]
+fn test_insert_on_conflict() {
+    let fixture = TestFixture::new().expect("Failed to create fixture");
+    fixture
+        .setup_graph("on_conflict_test_graph")
+        .expect("Failed to setup graph");
 
-    let actual_count = match count_value {
-        graphlite::Value::Number(n) => *n,
-        _ => panic!("Count should be a number, got: {:?}", count_value),
-    };
+    fixture.assert_query_succeeds("INSERT (:Person {email: 'a'})");
 
-    // Assert: With hash-based deduplication, 3 identical INSERTs create only 1 node
-    assert_eq!(
-        actual_count, 1.0,
-        "Expected 1 node after 3 identical INSERTs (hash-based deduplication), but found {} nodes",
-        actual_count
-    );
+    // DO NOTHING skips the duplicate without a warning
+    let result =
+        fixture.assert_query_succeeds("INSERT (:Person {email: 'a'}) ON CONFLICT DO NOTHING");
+    assert_eq!(result.rows_affected, 0);
+    assert!(result.warnings.is_empty(), "{:?}", result.warnings);
 
-    // Re-run an INSERT to verify it returns a warning
-    let duplicate_result = fixture
-        .query("INSERT (:Person {name: 'Bob', age: 25})")
-        .expect("Duplicate INSERT should succeed with warning");
+    // DO UPDATE merges the new properties into the stored node
+    let result = fixture.assert_query_succeeds(
+        "INSERT (:Person {email: 'a'}) ON CONFLICT DO UPDATE SET active = true, visits = 1 + 1",
+    );
+    assert_eq!(result.rows_affected, 1);
 
-    // Verify warning exists
-    assert!(
-        !duplicate_result.warnings.is_empty(),
-        "Expected warning for duplicate INSERT"
+    let result =
+        fixture.assert_query_succeeds("MATCH (p:Person) RETURN p.email, p.active, p.visits");
+    assert_eq!(result.rows.len(), 1);
+    let row = &result.rows[0];
+    assert_eq!(
+        row.values.get("p.email"),
+        Some(&graphlite::Value::String("a".to_string()))
     );
-    assert!(
-        duplicate_result.warnings[0].contains("Duplicate node detected"),
-        "Warning should mention duplicate detection"
+    assert_eq!(
+        row.values.get("p.active"),
+        Some(&graphlite::Value::Boolean(true))
     );
-
-    // Verify rows_affected is 0
     assert_eq!(
-        duplicate_result.rows_affected, 0,
-        "Expected rows_affected = 0 for duplicate INSERT"
+        row.values.get("p.visits"),
+        Some(&graphlite::Value::Number(2.0))
+    );
+
+    // A new node is inserted as usual
+    let result = fixture.assert_query_succeeds(
+        "INSERT (:Person {email: 'b'}) ON CONFLICT DO UPDATE SET active = true",
+    );
+    assert_eq!(result.rows_affected, 1);
+    fixture.assert_first_value(
+        "MATCH (p:Person {email: 'b'}) RETURN p.active",
+        "p.active",
+        graphlite::Value::Null,
     );
 }
@@ -43,11 +43,11 @@ fn test_duration_function_in_node_insert() {
     assert_eq!(query_result.rows.len(), 1, "Should return 1 node");
 
     let age_value = &query_result.rows[0].values["age"];
-    // Duration function should return a numeric value (seconds)
+    // Duration function should return a duration value
     assert!(age_value != &Value::Null, "Duration should not be null");
     assert!(
-        matches!(age_value, Value::Number(_)),
-        "Duration should be a number"
+        matches!(age_value, Value::Duration(_)),
+        "Duration should be a duration"
     );
 }
 
@@ -93,8 +93,8 @@ fn test_duration_function_in_edge_insert() {
         "Duration should not be null"
     );
     assert!(
-        matches!(duration_value, Value::Number(_)),
-        "Duration should be a number"
+        matches!(duration_value, Value::Duration(_)),
+        "Duration should be a duration"
     );
 
     let since_value = &query_result.rows[0].values["since"];
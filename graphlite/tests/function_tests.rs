@@ -9,7 +9,8 @@
 #[path = "testutils/mod.rs"]
 mod testutils;
 
-use graphlite::Value;
+use graphlite::{DurationValue, Value};
+use std::collections::HashMap;
 use testutils::test_fixture::{FixtureType, TestCase, TestFixture, TestSuite};
 
 // use testutils::generate_sample_fraud_data;
@@ -551,10 +552,582 @@ fn test_multiple_functions_planning() {
     assert!(row.values.contains_key("total"));
 }
 
+#[test]
+fn test_to_upper_and_to_lower() {
+    let fixture = TestFixture::new().expect("Failed to create test fixture");
+    fixture
+        .setup_graph("test_to_upper_and_to_lower")
+        .expect("Failed to setup graph");
+
+    fixture.assert_first_value(
+        "RETURN toUpper('café') as upper",
+        "upper",
+        Value::String("CAFÉ".to_string()),
+    );
+    fixture.assert_first_value(
+        "RETURN toLower('HELLO') as lower",
+        "lower",
+        Value::String("hello".to_string()),
+    );
+    fixture.assert_first_value("RETURN toLower(null) as lower", "lower", Value::Null);
+}
+
+// ==============================================================================
+// REGEX FUNCTION TESTS
+// ==============================================================================
+
+#[test]
+fn test_ft_regex_filter_over_many_rows() {
+    let fixture = TestFixture::new().expect("Failed to create test fixture");
+    fixture
+        .setup_graph("test_ft_regex_filter_over_many_rows")
+        .expect("Failed to setup graph");
+
+    for i in 0..500 {
+        fixture
+            .query(&format!(
+                "INSERT (:Doc {{id: {}, code: 'DOC-{:04}'}})",
+                i, i
+            ))
+            .expect("Failed to insert doc");
+    }
+
+    // Codes ending in 7 with a leading zero: DOC-0007, DOC-0017, ..., DOC-0497
+    let result = fixture.assert_query_succeeds(
+        "MATCH (d:Doc) WHERE FT_REGEX(d.code, '^DOC-0[0-9]{2}7$') RETURN d.code",
+    );
+    assert_eq!(result.rows.len(), 50);
+    for row in &result.rows {
+        match row.values.get("d.code") {
+            Some(Value::String(code)) => assert!(code.ends_with('7'), "Unexpected match {}", code),
+            other => panic!("Expected string code, got {:?}", other),
+        }
+    }
+
+    // Repeating the query must give identical results from a fresh per-query cache
+    let again = fixture.assert_query_succeeds(
+        "MATCH (d:Doc) WHERE FT_REGEX(d.code, '^DOC-0[0-9]{2}7$') RETURN d.code",
+    );
+    assert_eq!(again.rows.len(), result.rows.len());
+
+    fixture.assert_query_fails(
+        "MATCH (d:Doc) WHERE FT_REGEX(d.code, '(DOC') RETURN d.code",
+        "Invalid regex pattern",
+    );
+}
+
+#[test]
+fn test_similarity_score_orders_by_closeness() {
+    let fixture = TestFixture::new().expect("Failed to create test fixture");
+    fixture
+        .setup_graph("test_similarity_score_orders_by_closeness")
+        .expect("Failed to setup graph");
+
+    fixture.assert_first_value(
+        "RETURN SIMILARITY_SCORE('abc', 'abc') as score",
+        "score",
+        Value::Number(1.0),
+    );
+
+    for name in ["graphlite", "graphite", "granite", "zebra"] {
+        fixture
+            .query(&format!("INSERT (:Word {{name: '{}'}})", name))
+            .expect("Failed to insert word");
+    }
+
+    let result = fixture.assert_query_succeeds(
+        "MATCH (w:Word) RETURN w.name, SIMILARITY_SCORE(w.name, 'graphlite') as score ORDER BY score DESC",
+    );
+    let names: Vec<String> = result
+        .rows
+        .iter()
+        .map(|row| match row.values.get("w.name") {
+            Some(Value::String(name)) => name.clone(),
+            other => panic!("Expected string name, got {:?}", other),
+        })
+        .collect();
+    assert_eq!(names, vec!["graphlite", "graphite", "granite", "zebra"]);
+
+    for row in &result.rows {
+        match row.values.get("score") {
+            Some(Value::Number(score)) => assert!((0.0..=1.0).contains(score)),
+            other => panic!("Expected numeric score, got {:?}", other),
+        }
+    }
+}
+
+// ==============================================================================
+// VECTOR FUNCTION TESTS
+// ==============================================================================
+
+#[test]
+fn test_cosine_similarity_nearest_neighbor_ordering() {
+    let fixture = TestFixture::new().expect("Failed to create test fixture");
+    fixture
+        .setup_graph("test_cosine_similarity_nearest_neighbor_ordering")
+        .expect("Failed to setup graph");
+
+    for (title, embedding) in [
+        ("west", "[-1.0, 0.0]"),
+        ("north", "[0.0, 1.0]"),
+        ("east", "[1.0, 0.0]"),
+        ("northeast", "[0.7, 0.7]"),
+    ] {
+        fixture
+            .query(&format!(
+                "INSERT (:Doc {{title: '{}', embedding: {}}})",
+                title, embedding
+            ))
+            .expect("Failed to insert document");
+    }
+
+    fixture.assert_query_succeeds("SESSION SET VALUE $query_vec = [1.0, 0.1]");
+
+    let result = fixture.assert_query_succeeds(
+        "MATCH (d:Doc) RETURN d.title, COSINE_SIMILARITY(d.embedding, $query_vec) as score ORDER BY score DESC",
+    );
+    let titles: Vec<String> = result
+        .rows
+        .iter()
+        .map(|row| match row.values.get("d.title") {
+            Some(Value::String(title)) => title.clone(),
+            other => panic!("Expected string title, got {:?}", other),
+        })
+        .collect();
+    assert_eq!(titles, vec!["east", "northeast", "north", "west"]);
+
+    // Similarity-ordered LIMIT goes through the score heap and must agree
+    let result = fixture.assert_query_succeeds(
+        "MATCH (d:Doc) RETURN d.title, d.embedding ORDER BY COSINE_SIMILARITY(d.embedding, $query_vec) DESC LIMIT 2 OFFSET 1",
+    );
+    let titles: Vec<String> = result
+        .rows
+        .iter()
+        .map(|row| match row.values.get("d.title") {
+            Some(Value::String(title)) => title.clone(),
+            other => panic!("Expected string title, got {:?}", other),
+        })
+        .collect();
+    assert_eq!(titles, vec!["northeast", "north"]);
+
+    fixture.assert_query_succeeds("SESSION SET VALUE $bad_vec = [1.0, 0.0, 0.0]");
+    fixture.assert_query_fails(
+        "MATCH (d:Doc) RETURN COSINE_SIMILARITY(d.embedding, $bad_vec)",
+        "same dimension",
+    );
+    fixture.assert_query_fails(
+        "MATCH (d:Doc) RETURN COSINE_SIMILARITY(d.embedding, $unbound_vec)",
+        "not bound",
+    );
+
+    // An unbound parameter does not pick up a pattern variable of the same name
+    fixture.assert_query_fails("MATCH (d:Doc) RETURN $d", "not bound");
+}
+
+#[test]
+fn test_euclidean_distance_threshold_filter() {
+    let fixture = TestFixture::new().expect("Failed to create test fixture");
+    fixture
+        .setup_graph("test_euclidean_distance_threshold_filter")
+        .expect("Failed to setup graph");
+
+    fixture.assert_first_value(
+        "RETURN EUCLIDEAN_DISTANCE([0, 0], [3, 4]) as distance",
+        "distance",
+        Value::Number(5.0),
+    );
+    fixture.assert_first_value(
+        "RETURN DOT_PRODUCT([1, 2], [3, 4]) as product",
+        "product",
+        Value::Number(11.0),
+    );
+
+    for (name, v) in [
+        ("origin", "[0.0, 0.0]"),
+        ("near", "[0.5, 0.5]"),
+        ("far", "[3.0, 4.0]"),
+        ("farther", "[-6.0, 8.0]"),
+    ] {
+        fixture
+            .query(&format!("INSERT (:Point {{name: '{}', v: {}}})", name, v))
+            .expect("Failed to insert point");
+    }
+
+    fixture.assert_query_succeeds("SESSION SET VALUE $q = [0.1, 0.1]");
+
+    let result = fixture.assert_query_succeeds(
+        "MATCH (d:Point) WHERE EUCLIDEAN_DISTANCE(d.v, $q) < 1.0 \
+         RETURN d.name, EUCLIDEAN_DISTANCE(d.v, $q) as distance ORDER BY distance",
+    );
+    let names: Vec<String> = result
+        .rows
+        .iter()
+        .map(|row| match row.values.get("d.name") {
+            Some(Value::String(name)) => name.clone(),
+            other => panic!("Expected string name, got {:?}", other),
+        })
+        .collect();
+    assert_eq!(names, vec!["origin", "near"]);
+}
+
+// ==============================================================================
+// DURATION TESTS
+// ==============================================================================
+
+#[test]
+fn test_datetime_plus_duration() {
+    let fixture = TestFixture::new().expect("Failed to create test fixture");
+    fixture
+        .setup_graph("test_datetime_plus_duration")
+        .expect("Failed to setup graph");
+
+    let result = fixture.assert_query_succeeds(
+        "RETURN datetime('2024-01-01T00:00:00Z') + duration('P1D') as tomorrow",
+    );
+    match result.rows[0].values.get("tomorrow") {
+        Some(tomorrow @ Value::DateTime(_)) => {
+            assert_eq!(tomorrow.to_string(), "2024-01-02 00:00:00 UTC")
+        }
+        other => panic!("Expected datetime, got {:?}", other),
+    }
+
+    let result = fixture.assert_query_succeeds(
+        "RETURN datetime('2024-01-02T00:00:00Z') - duration('PT36H') as earlier",
+    );
+    match result.rows[0].values.get("earlier") {
+        Some(earlier @ Value::DateTime(_)) => {
+            assert_eq!(earlier.to_string(), "2023-12-31 12:00:00 UTC")
+        }
+        other => panic!("Expected datetime, got {:?}", other),
+    }
+
+    // 2024 is a leap year, so this spans two days
+    fixture.assert_first_value(
+        "RETURN datetime('2024-03-01T00:00:00Z') - datetime('2024-02-28T00:00:00Z') as elapsed",
+        "elapsed",
+        Value::Duration(DurationValue::from_seconds(2 * 86_400)),
+    );
+    fixture.assert_first_value(
+        "RETURN duration('PT1H') + duration('PT30M') as total",
+        "total",
+        Value::Duration(DurationValue::from_seconds(5_400)),
+    );
+}
+
+#[test]
+fn test_duration_comparison_and_ordering() {
+    let fixture = TestFixture::new().expect("Failed to create test fixture");
+    fixture
+        .setup_graph("test_duration_comparison_and_ordering")
+        .expect("Failed to setup graph");
+
+    fixture.assert_first_value(
+        "RETURN duration('PT90M') > duration('PT1H') as longer",
+        "longer",
+        Value::Boolean(true),
+    );
+    fixture.assert_first_value(
+        "RETURN duration('P1D') < duration('PT23H') as shorter",
+        "shorter",
+        Value::Boolean(false),
+    );
+
+    for (name, took) in [("build", "PT2H"), ("lint", "PT45S"), ("deploy", "P1D")] {
+        fixture
+            .query(&format!(
+                "INSERT (:Task {{name: '{}', took: duration('{}')}})",
+                name, took
+            ))
+            .expect("Failed to insert task");
+    }
+
+    let result = fixture.assert_query_succeeds(
+        "MATCH (t:Task) WHERE t.took > duration('PT1M') RETURN t.name, t.took ORDER BY t.took DESC",
+    );
+    let names: Vec<String> = result
+        .rows
+        .iter()
+        .map(|row| match row.values.get("t.name") {
+            Some(Value::String(name)) => name.clone(),
+            other => panic!("Expected string name, got {:?}", other),
+        })
+        .collect();
+    assert_eq!(names, vec!["deploy", "build"]);
+}
+
+#[test]
+fn test_degree_functions() {
+    let fixture = TestFixture::new().expect("Failed to create test fixture");
+    fixture
+        .setup_graph("test_degree_functions")
+        .expect("Failed to setup graph");
+
+    let names = ["hub", "a", "b", "c", "d", "e"];
+    for name in names {
+        fixture
+            .query(&format!("INSERT (:Person {{name: '{}'}})", name))
+            .expect("Failed to insert person");
+    }
+    // hub has 3 outgoing and 2 incoming KNOWS edges, plus one LIKES edge
+    for (from, to, label) in [
+        ("hub", "a", "KNOWS"),
+        ("hub", "b", "KNOWS"),
+        ("hub", "c", "KNOWS"),
+        ("d", "hub", "KNOWS"),
+        ("e", "hub", "KNOWS"),
+        ("hub", "e", "LIKES"),
+    ] {
+        fixture
+            .query(&format!(
+                "MATCH (x:Person {{name: '{}'}}), (y:Person {{name: '{}'}}) INSERT (x)-[:{}]->(y)",
+                from, to, label
+            ))
+            .expect("Failed to insert edge");
+    }
+
+    let result = fixture.assert_query_succeeds(
+        "MATCH (n:Person {name: 'hub'}) RETURN out_degree(n, 'KNOWS') AS out_knows, \
+         in_degree(n, 'KNOWS') AS in_knows, degree(n, 'KNOWS') AS knows, \
+         out_degree(n) AS out_all, degree(n) AS total",
+    );
+    assert_eq!(result.rows.len(), 1);
+    let row = &result.rows[0];
+    assert_eq!(row.values.get("out_knows"), Some(&Value::Number(3.0)));
+    assert_eq!(row.values.get("in_knows"), Some(&Value::Number(2.0)));
+    assert_eq!(row.values.get("knows"), Some(&Value::Number(5.0)));
+    assert_eq!(row.values.get("out_all"), Some(&Value::Number(4.0)));
+    assert_eq!(row.values.get("total"), Some(&Value::Number(6.0)));
+
+    // Degree functions work as WHERE predicates too
+    let result = fixture
+        .assert_query_succeeds("MATCH (n:Person) WHERE in_degree(n) > 0 RETURN n.name AS name");
+    assert_eq!(result.rows.len(), 5, "Only d has no incoming edges");
+}
+
+#[test]
+fn test_reachable_function() {
+    let fixture = TestFixture::new().expect("Failed to create test fixture");
+    fixture
+        .setup_graph("test_reachable_function")
+        .expect("Failed to setup graph");
+
+    for name in ["a", "b", "c", "d", "e"] {
+        fixture
+            .query(&format!("INSERT (:Person {{name: '{}'}})", name))
+            .expect("Failed to insert person");
+    }
+    // A chain a -> b -> c -> d, a cycle back to a, and a LIKES edge to e
+    for (from, to, label) in [
+        ("a", "b", "KNOWS"),
+        ("b", "c", "KNOWS"),
+        ("c", "d", "KNOWS"),
+        ("c", "a", "KNOWS"),
+        ("b", "e", "LIKES"),
+    ] {
+        fixture
+            .query(&format!(
+                "MATCH (x:Person {{name: '{}'}}), (y:Person {{name: '{}'}}) INSERT (x)-[:{}]->(y)",
+                from, to, label
+            ))
+            .expect("Failed to insert edge");
+    }
+
+    let reachable_names = |query: &str| -> Vec<String> {
+        let result = fixture.assert_query_succeeds(query);
+        match result.rows[0].values.get("reached") {
+            Some(Value::List(nodes)) => nodes
+                .iter()
+                .map(|node| match node {
+                    Value::Node(node) => match node.properties.get("name") {
+                        Some(Value::String(name)) => name.clone(),
+                        other => panic!("Unexpected name {:?}", other),
+                    },
+                    other => panic!("Expected a node, got {:?}", other),
+                })
+                .collect(),
+            other => panic!("Expected a list, got {:?}", other),
+        }
+    };
+
+    assert_eq!(
+        reachable_names("MATCH (a:Person {name: 'a'}) RETURN reachable(a, 'KNOWS', 2) AS reached"),
+        vec!["b", "c"]
+    );
+    // The cycle back to a neither repeats nodes nor includes the start
+    assert_eq!(
+        reachable_names("MATCH (a:Person {name: 'a'}) RETURN reachable(a, 'KNOWS', 10) AS reached"),
+        vec!["b", "c", "d"]
+    );
+    let mut any_label =
+        reachable_names("MATCH (a:Person {name: 'a'}) RETURN reachable(a, NULL, 2) AS reached");
+    any_label.sort();
+    assert_eq!(any_label, vec!["b", "c", "e"]);
+    assert!(reachable_names(
+        "MATCH (a:Person {name: 'a'}) RETURN reachable(a, 'KNOWS', 0) AS reached"
+    )
+    .is_empty());
+}
+
+#[test]
+fn test_weighted_shortest_path_function() {
+    let fixture = TestFixture::new().expect("Failed to create test fixture");
+    fixture
+        .setup_graph("test_weighted_shortest_path_function")
+        .expect("Failed to setup graph");
+
+    for name in ["a", "b", "c", "d", "e"] {
+        fixture
+            .query(&format!("INSERT (:City {{name: '{}'}})", name))
+            .expect("Failed to insert city");
+    }
+    // a -> b -> d costs 10, a -> c -> d costs 5 and the direct road costs 20
+    for (from, to, distance) in [
+        ("a", "b", 5),
+        ("b", "d", 5),
+        ("a", "c", 2),
+        ("c", "d", 3),
+        ("a", "d", 20),
+    ] {
+        fixture
+            .query(&format!(
+                "MATCH (x:City {{name: '{}'}}), (y:City {{name: '{}'}}) \
+                 INSERT (x)-[:ROAD {{distance: {}}}]->(y)",
+                from, to, distance
+            ))
+            .expect("Failed to insert road");
+    }
+
+    let cities = fixture.assert_query_succeeds("MATCH (n:City) RETURN n AS n, n.name AS name");
+    let names: HashMap<String, Value> = cities
+        .rows
+        .iter()
+        .map(|row| match row.values.get("n") {
+            Some(Value::Node(node)) => (node.id.clone(), row.values["name"].clone()),
+            other => panic!("Expected a node, got {:?}", other),
+        })
+        .collect();
+
+    let route = |query: &str| -> Option<(Vec<Value>, Value)> {
+        let result = fixture.assert_query_succeeds(query);
+        match result.rows[0].values.get("route") {
+            Some(Value::Map(route)) => match route.get("path") {
+                Some(Value::Path(path)) => Some((
+                    path.get_nodes()
+                        .iter()
+                        .map(|id| names[*id].clone())
+                        .collect(),
+                    route["cost"].clone(),
+                )),
+                other => panic!("Expected a path, got {:?}", other),
+            },
+            Some(Value::Null) => None,
+            other => panic!("Expected a route, got {:?}", other),
+        }
+    };
+    let city = |name: &str| Value::String(name.to_string());
+
+    assert_eq!(
+        route(
+            "MATCH (a:City {name: 'a'}), (d:City {name: 'd'}) \
+             RETURN shortest_path(a, d, weight => 'distance') AS route"
+        ),
+        Some((vec![city("a"), city("c"), city("d")], Value::Number(5.0)))
+    );
+    // Without a weight every road costs 1, so the direct road wins
+    assert_eq!(
+        route(
+            "MATCH (a:City {name: 'a'}), (d:City {name: 'd'}) \
+             RETURN shortest_path(a, d) AS route"
+        ),
+        Some((vec![city("a"), city("d")], Value::Number(1.0)))
+    );
+    assert_eq!(
+        route(
+            "MATCH (a:City {name: 'a'}), (e:City {name: 'e'}) \
+             RETURN shortest_path(a, e, weight => 'distance') AS route"
+        ),
+        None
+    );
+
+    fixture
+        .query(
+            "MATCH (d:City {name: 'd'}), (e:City {name: 'e'}) \
+             INSERT (d)-[:ROAD {distance: -1}]->(e)",
+        )
+        .expect("Failed to insert road");
+    fixture.assert_query_fails(
+        "MATCH (a:City {name: 'a'}), (e:City {name: 'e'}) \
+         RETURN shortest_path(a, e, weight => 'distance') AS route",
+        "negative weight",
+    );
+    fixture.assert_query_fails(
+        "MATCH (a:City {name: 'a'}), (e:City {name: 'e'}) \
+         RETURN shortest_path(a, e, cost => 'distance') AS route",
+        "Unknown SHORTEST_PATH argument",
+    );
+}
+
 // ==============================================================================
 // COMPREHENSIVE INTEGRATION TESTS
 // ==============================================================================
 
+#[test]
+fn test_session_timezone_applies_to_datetime_functions() {
+    let fixture = TestFixture::new().expect("Failed to create test fixture");
+    fixture
+        .setup_graph("test_session_timezone")
+        .expect("Failed to setup graph");
+
+    fixture.assert_query_succeeds(
+        "INSERT (:Event {name: 'launch', ts: datetime('2024-01-15T03:30:00Z')})",
+    );
+
+    // Sessions start in UTC
+    fixture.assert_first_value(
+        "MATCH (e:Event) RETURN HOUR(e.ts) AS hour",
+        "hour",
+        Value::Number(3.0),
+    );
+
+    // New York is five hours behind UTC in January, so the event is the evening before
+    fixture.assert_query_succeeds("SESSION SET TIMEZONE 'America/New_York'");
+    let result = fixture.assert_query_succeeds(
+        "MATCH (e:Event) RETURN HOUR(e.ts) AS hour, DAY(e.ts) AS day, \
+         EXTRACT('HOUR', e.ts) AS extracted, e.ts AS ts",
+    );
+    let row = &result.rows[0].values;
+    assert_eq!(row["hour"], Value::Number(22.0));
+    assert_eq!(row["day"], Value::Number(14.0));
+    assert_eq!(row["extracted"], Value::Number(22.0));
+    assert_eq!(
+        row["ts"].to_string(),
+        "2024-01-14 22:30:00 America/New_York"
+    );
+
+    fixture.assert_first_value(
+        "MATCH (e:Event) WHERE HOUR(e.ts) = 22 RETURN e.name AS name",
+        "name",
+        Value::String("launch".to_string()),
+    );
+
+    // Fixed offsets work the same way
+    fixture.assert_query_succeeds("SESSION SET TIME ZONE '-05:00'");
+    fixture.assert_first_value(
+        "MATCH (e:Event) RETURN HOUR(e.ts) AS hour",
+        "hour",
+        Value::Number(22.0),
+    );
+
+    fixture.assert_query_fails("SESSION SET TIMEZONE 'Mars/Olympus'", "Unknown time zone");
+
+    fixture.assert_query_succeeds("SESSION SET TIMEZONE 'UTC'");
+    fixture.assert_first_value(
+        "MATCH (e:Event) RETURN HOUR(e.ts) AS hour",
+        "hour",
+        Value::Number(3.0),
+    );
+}
+
 #[test]
 fn test_function_integration_comprehensive() {
     let _fixture = TestFixture::new().expect("Failed to create test fixture");
@@ -608,3 +1181,63 @@ fn test_function_integration_comprehensive() {
     );
     let _total = results.passed + results.failed;
 }
+
+#[test]
+fn test_nullif_greatest_and_least() {
+    let fixture = TestFixture::new().expect("Failed to create test fixture");
+    fixture
+        .setup_graph("test_nullif_greatest_and_least")
+        .expect("Failed to setup graph");
+    fixture.assert_query_succeeds("INSERT (:Item {x: 0, y: 4}), (:Item {x: 5})");
+
+    let result = fixture.assert_query_succeeds(
+        "MATCH (i:Item) RETURN i.x AS x, NULLIF(i.x, 0) AS divisor, \
+         GREATEST(i.x, i.y, 1) AS high, LEAST(i.x, i.y, 1) AS low ORDER BY x",
+    );
+    let column = |name: &str| -> Vec<Value> {
+        result
+            .rows
+            .iter()
+            .map(|row| row.values.get(name).cloned().unwrap_or(Value::Null))
+            .collect()
+    };
+    assert_eq!(column("divisor"), vec![Value::Null, Value::Number(5.0)]);
+    // The second item has no y, which is skipped rather than making the result NULL
+    assert_eq!(column("high"), vec![Value::Number(4.0), Value::Number(5.0)]);
+    assert_eq!(column("low"), vec![Value::Number(0.0), Value::Number(1.0)]);
+
+    let result = fixture.assert_query_succeeds(
+        "RETURN GREATEST(3, 7, 2) AS greatest, LEAST(3, 7, 2) AS least, \
+         GREATEST(NULL, 4, NULL) AS skipped, LEAST('pear', 'apple') AS word",
+    );
+    let row = &result.rows[0].values;
+    assert_eq!(row.get("greatest"), Some(&Value::Number(7.0)));
+    assert_eq!(row.get("least"), Some(&Value::Number(2.0)));
+    assert_eq!(row.get("skipped"), Some(&Value::Number(4.0)));
+    assert_eq!(row.get("word"), Some(&Value::String("apple".to_string())));
+
+    fixture.assert_query_fails("RETURN GREATEST(1, 'a') AS mixed", "cannot compare");
+}
+
+#[test]
+fn test_size_and_length_are_polymorphic() {
+    let fixture = TestFixture::new().expect("Failed to create test fixture");
+    fixture
+        .setup_graph("test_size_and_length_are_polymorphic")
+        .expect("Failed to setup graph");
+
+    let result = fixture.assert_query_succeeds(
+        "RETURN SIZE('abc') AS word, SIZE(['a', 'b']) AS items, SIZE({a: 1}) AS entries, \
+         SIZE('café') AS accented, LENGTH('café') AS length, LENGTH([1, 2, 3]) AS list_length",
+    );
+    let row = &result.rows[0].values;
+    assert_eq!(row.get("word"), Some(&Value::Number(3.0)));
+    assert_eq!(row.get("items"), Some(&Value::Number(2.0)));
+    assert_eq!(row.get("entries"), Some(&Value::Number(1.0)));
+    // Four characters, although 'é' takes two bytes
+    assert_eq!(row.get("accented"), Some(&Value::Number(4.0)));
+    assert_eq!(row.get("length"), Some(&Value::Number(4.0)));
+    assert_eq!(row.get("list_length"), Some(&Value::Number(3.0)));
+
+    fixture.assert_query_fails("RETURN SIZE(42) AS n", "Expected STRING");
+}
@@ -0,0 +1,115 @@
+//! Tests for db.* graph algorithm procedures
+
+#[path = "testutils/mod.rs"]
+mod testutils;
+
+use graphlite::Value;
+use std::collections::HashMap;
+use testutils::test_fixture::TestFixture;
+
+#[test]
+fn test_connected_components() {
+    let fixture = TestFixture::new().expect("Should create test fixture");
+    fixture
+        .setup_graph("test_connected_components")
+        .expect("Should set up graph");
+
+    fixture
+        .query(
+            "INSERT (:Person {name: 'Ann'}), (:Person {name: 'Bob'}), (:Person {name: 'Cid'}), \
+             (:Person {name: 'Dee'}), (:Person {name: 'Eve'}), (:Person {name: 'Fay'})",
+        )
+        .expect("Should insert persons");
+    for (from, to, label) in [
+        // Cluster {Ann, Bob, Cid}; Cid only reaches Bob against the edge direction
+        ("Ann", "Bob", "KNOWS"),
+        ("Cid", "Bob", "KNOWS"),
+        // Cluster {Dee, Eve}
+        ("Eve", "Dee", "KNOWS"),
+        // A different edge type must not merge the clusters
+        ("Bob", "Dee", "WORKS_WITH"),
+    ] {
+        fixture
+            .query(&format!(
+                "MATCH (a:Person {{name: '{}'}}), (b:Person {{name: '{}'}}) INSERT (a)-[:{}]->(b)",
+                from, to, label
+            ))
+            .expect("Should insert edge");
+    }
+
+    let result = fixture.assert_query_succeeds(
+        "CALL db.connected_components('Person', 'KNOWS') YIELD node_id, component_id",
+    );
+    assert_eq!(result.rows.len(), 6, "Every Person should get a component");
+
+    let mut sizes: HashMap<String, usize> = HashMap::new();
+    for row in &result.rows {
+        assert!(matches!(row.values.get("node_id"), Some(Value::String(_))));
+        let component = format!("{:?}", row.values.get("component_id"));
+        *sizes.entry(component).or_default() += 1;
+    }
+    let mut sizes: Vec<usize> = sizes.into_values().collect();
+    sizes.sort_unstable();
+    assert_eq!(
+        sizes,
+        vec![1, 2, 3],
+        "Fay is isolated, the two clusters stay apart"
+    );
+
+    fixture.assert_query_fails(
+        "CALL db.connected_components('Person')",
+        "expects 2 string arguments",
+    );
+}
+
+#[test]
+fn test_graph_stats() {
+    let fixture = TestFixture::new().expect("Should create test fixture");
+    fixture
+        .setup_graph("test_graph_stats")
+        .expect("Should set up graph");
+
+    // A star: the hub points at four leaves, and one node stands alone
+    fixture
+        .query(
+            "INSERT (:Hub {name: 'hub'}), (:Leaf {name: 'a'}), (:Leaf {name: 'b'}), \
+             (:Leaf {name: 'c'}), (:Leaf {name: 'd'}), (:Loner {name: 'e'})",
+        )
+        .expect("Should insert nodes");
+    fixture
+        .query("MATCH (h:Hub), (l:Leaf) INSERT (h)-[:LINKS]->(l)")
+        .expect("Should insert edges");
+
+    let result = fixture.assert_query_succeeds(
+        "CALL db.graph_stats() YIELD node_count, edge_count, average_degree, max_degree, \
+         density, degree_histogram",
+    );
+    assert_eq!(result.rows.len(), 1);
+    let stats = &result.rows[0].values;
+    assert_eq!(stats.get("node_count"), Some(&Value::Number(6.0)));
+    assert_eq!(stats.get("edge_count"), Some(&Value::Number(4.0)));
+    assert_eq!(
+        stats.get("max_degree"),
+        Some(&Value::Number(4.0)),
+        "The hub's degree"
+    );
+    match stats.get("average_degree") {
+        Some(Value::Number(average)) => assert!((average - 8.0 / 6.0).abs() < 1e-9),
+        other => panic!("Expected a numeric average degree, got {:?}", other),
+    }
+    match stats.get("density") {
+        Some(Value::Number(density)) => assert!((density - 4.0 / 30.0).abs() < 1e-9),
+        other => panic!("Expected a numeric density, got {:?}", other),
+    }
+    assert_eq!(
+        stats.get("degree_histogram"),
+        Some(&Value::Map(HashMap::from([
+            ("0".to_string(), Value::Number(1.0)),
+            ("1".to_string(), Value::Number(4.0)),
+            ("2-3".to_string(), Value::Number(0.0)),
+            ("4-7".to_string(), Value::Number(1.0)),
+        ])))
+    );
+
+    fixture.assert_query_fails("CALL db.graph_stats(1)", "expects no arguments");
+}
@@ -132,10 +132,16 @@ fn test_content_based_deduplication() {
         .expect("Count query should succeed");
     let initial_count = result1.rows[0].values["total_nodes"].as_number().unwrap();
 
-    // Try to insert duplicate
+    // A duplicate INSERT fails, and skipping it has to be asked for
+    assert!(
+        fixture
+            .query(r#"INSERT (n:Person {name: "Alice Smith"})"#)
+            .is_err(),
+        "Duplicate INSERT should fail"
+    );
     fixture
-        .query(r#"INSERT (n:Person {name: "Alice Smith"})"#)
-        .expect("Duplicate INSERT should succeed");
+        .query(r#"INSERT (n:Person {name: "Alice Smith"}) ON CONFLICT DO NOTHING"#)
+        .expect("Duplicate INSERT ON CONFLICT DO NOTHING should succeed");
 
     // Verify count didn't increase
     let result2 = fixture
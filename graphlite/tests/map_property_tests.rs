@@ -0,0 +1,135 @@
+#[path = "testutils/mod.rs"]
+mod testutils;
+
+use graphlite::Value;
+use testutils::test_fixture::TestFixture;
+
+#[test]
+fn test_nested_map_property_dot_and_bracket_access() {
+    let fixture = TestFixture::new().expect("Failed to create fixture");
+    fixture
+        .setup_graph("test_nested_map_property_access")
+        .expect("Failed to setup graph");
+
+    fixture
+        .query(
+            "INSERT (:Device {name: 'sensor', attrs: {kind: 'thermo', inner: {value: 42, unit: 'C'}}})",
+        )
+        .expect("Failed to insert node with nested map property");
+
+    fixture.assert_first_value(
+        "MATCH (d:Device) RETURN d.attrs.inner.value AS value",
+        "value",
+        Value::Number(42.0),
+    );
+    fixture.assert_first_value(
+        "MATCH (d:Device) RETURN d.attrs['kind'] AS kind",
+        "kind",
+        Value::String("thermo".to_string()),
+    );
+    fixture.assert_first_value(
+        "MATCH (d:Device) RETURN d.attrs['inner']['unit'] AS unit",
+        "unit",
+        Value::String("C".to_string()),
+    );
+
+    // Missing keys are NULL rather than errors, at any depth
+    fixture.assert_first_value(
+        "MATCH (d:Device) RETURN d.attrs.missing AS missing",
+        "missing",
+        Value::Null,
+    );
+    fixture.assert_first_value(
+        "MATCH (d:Device) RETURN d.attrs['missing']['deeper'] AS missing",
+        "missing",
+        Value::Null,
+    );
+
+    let result = fixture.assert_query_succeeds("MATCH (d:Device) RETURN d.attrs AS attrs");
+    match result.rows[0].values.get("attrs") {
+        Some(Value::Map(attrs)) => {
+            assert_eq!(
+                attrs.get("kind"),
+                Some(&Value::String("thermo".to_string()))
+            );
+            assert!(matches!(attrs.get("inner"), Some(Value::Map(_))));
+        }
+        other => panic!("Expected map property, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_map_property_key_order_does_not_affect_identity() {
+    let fixture = TestFixture::new().expect("Failed to create fixture");
+    fixture
+        .setup_graph("test_map_property_key_order")
+        .expect("Failed to setup graph");
+
+    fixture
+        .query("INSERT (:Config {settings: {a: 1, b: 2}})")
+        .expect("Failed to insert first config");
+    let duplicate = fixture.query("INSERT (:Config {settings: {b: 2, a: 1}})");
+
+    // Both inserts hash to the same content ID, so the second one conflicts
+    assert!(
+        duplicate.is_err(),
+        "Expected a conflict, got {:?}",
+        duplicate
+    );
+    fixture.assert_first_value(
+        "MATCH (c:Config) RETURN count(c) AS total",
+        "total",
+        Value::Number(1.0),
+    );
+}
+
+#[test]
+fn test_return_map_and_list_constructors() {
+    let fixture = TestFixture::new().expect("Failed to create fixture");
+    fixture
+        .setup_graph("test_return_map_constructors")
+        .expect("Failed to setup graph");
+
+    fixture
+        .query("INSERT (:Person {id: 7, name: 'Ada', age: 36})")
+        .expect("Failed to insert person");
+
+    let result = fixture.assert_query_succeeds(
+        "MATCH (p:Person) RETURN {id: p.id, tags: ['a', 'b'], profile: {name: p.name}} AS obj",
+    );
+    assert_eq!(result.rows.len(), 1);
+    let obj = match result.rows[0].values.get("obj") {
+        Some(Value::Map(obj)) => obj,
+        other => panic!("Expected map column, got {:?}", other),
+    };
+    assert_eq!(obj.len(), 3);
+    assert_eq!(obj.get("id"), Some(&Value::Number(7.0)));
+    assert_eq!(
+        obj.get("tags"),
+        Some(&Value::List(vec![
+            Value::String("a".to_string()),
+            Value::String("b".to_string()),
+        ]))
+    );
+    match obj.get("profile") {
+        Some(Value::Map(profile)) => {
+            assert_eq!(profile.get("name"), Some(&Value::String("Ada".to_string())))
+        }
+        other => panic!("Expected nested map, got {:?}", other),
+    }
+
+    // Lists of computed maps
+    let result = fixture.assert_query_succeeds(
+        "MATCH (p:Person) RETURN [{name: p.name}, {age: p.age + 1}] AS items",
+    );
+    match result.rows[0].values.get("items") {
+        Some(Value::List(items)) => {
+            assert_eq!(items.len(), 2);
+            assert_eq!(
+                items[1].as_map().and_then(|item| item.get("age")),
+                Some(&Value::Number(37.0))
+            );
+        }
+        other => panic!("Expected list of maps, got {:?}", other),
+    }
+}
@@ -242,3 +242,92 @@ fn test_match_set_single_property_rollback() {
         "Occupation should still be Engineer"
     );
 }
+
+#[test]
+fn test_match_set_property_map_merge_and_replace() {
+    let fixture = TestFixture::new().expect("Failed to create fixture");
+
+    fixture
+        .setup_graph("match_set_property_map_test")
+        .expect("Failed to setup graph");
+
+    fixture
+        .query("INSERT (:Person {name: 'Alice', age: 30, city: 'NYC'})")
+        .expect("Failed to create Alice");
+
+    let read_alice = |fixture: &TestFixture| {
+        fixture
+            .query(
+                "MATCH (p:Person) RETURN p.name as name, p.age as age, p.city as city, p.x as x, p.y as y",
+            )
+            .expect("Query should succeed")
+            .rows[0]
+            .values
+            .clone()
+    };
+    let is_missing = |value: Option<&Value>| matches!(value, None | Some(Value::Null));
+
+    // += adds and overwrites the listed keys, keeping everything else
+    fixture
+        .query("MATCH (p:Person) SET p += {x: 1, city: 'LA'}")
+        .expect("SET += should succeed");
+    let merged = read_alice(&fixture);
+    assert_eq!(merged.get("x"), Some(&Value::Number(1.0)));
+    assert_eq!(merged.get("city"), Some(&Value::String("LA".to_string())));
+    assert_eq!(
+        merged.get("name"),
+        Some(&Value::String("Alice".to_string()))
+    );
+    assert_eq!(merged.get("age"), Some(&Value::Number(30.0)));
+
+    // A null value removes the key
+    fixture
+        .query("MATCH (p:Person) SET p += {x: null}")
+        .expect("SET += with null should succeed");
+    assert!(is_missing(read_alice(&fixture).get("x")));
+
+    // = replaces the whole property map, inside a transaction that is rolled back
+    fixture
+        .query("START TRANSACTION")
+        .expect("Failed to start transaction");
+    fixture
+        .query("MATCH (p:Person) SET p = {y: 2}")
+        .expect("SET = should succeed");
+
+    let replaced = fixture
+        .query("MATCH (p:Person) RETURN p.name as name, p.y as y")
+        .expect("Query should succeed");
+    assert_eq!(replaced.rows.len(), 1);
+    assert_eq!(replaced.rows[0].values.get("y"), Some(&Value::Number(2.0)));
+    assert!(is_missing(replaced.rows[0].values.get("name")));
+
+    fixture.query("ROLLBACK").expect("ROLLBACK should succeed");
+
+    // Rollback restores the exact property map from before the transaction
+    let restored = read_alice(&fixture);
+    assert_eq!(
+        restored.get("name"),
+        Some(&Value::String("Alice".to_string()))
+    );
+    assert_eq!(restored.get("age"), Some(&Value::Number(30.0)));
+    assert_eq!(restored.get("city"), Some(&Value::String("LA".to_string())));
+    assert!(is_missing(restored.get("x")));
+    assert!(is_missing(restored.get("y")));
+}
+
+#[test]
+fn test_match_set_property_map_rejects_non_map() {
+    let fixture = TestFixture::new().expect("Failed to create fixture");
+
+    fixture
+        .setup_graph("match_set_non_map_test")
+        .expect("Failed to setup graph");
+    fixture
+        .query("INSERT (:Person {name: 'Alice'})")
+        .expect("Failed to create Alice");
+
+    fixture.assert_query_fails(
+        "MATCH (p:Person) SET p += 5",
+        "requires a map of properties",
+    );
+}
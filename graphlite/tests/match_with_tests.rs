@@ -1848,6 +1848,55 @@ fn test_unwind_set_operations() {
     );
 }
 
+#[test]
+fn test_unwind_collected_list_round_trip() {
+    let fixture = TestFixture::empty().expect("Failed to create test fixture");
+
+    fixture
+        .query(&format!(
+            "CREATE SCHEMA IF NOT EXISTS /{}",
+            fixture.schema_name()
+        ))
+        .unwrap();
+    fixture
+        .query(&format!(
+            "CREATE GRAPH /{}/unwind_round_trip",
+            fixture.schema_name()
+        ))
+        .unwrap();
+    fixture
+        .query(&format!(
+            "SESSION SET GRAPH /{}/unwind_round_trip",
+            fixture.schema_name()
+        ))
+        .unwrap();
+
+    fixture.assert_query_succeeds(
+        "INSERT (:Person {name: 'Alice'}), (:Person {name: 'Bob'}), (:Person {name: 'Carol'})",
+    );
+
+    // Collect into a list, then unwind it back into one row per name
+    let result = fixture.assert_query_succeeds(
+        "MATCH (p:Person) WITH COLLECT(p.name) AS names UNWIND names AS name RETURN name",
+    );
+    let mut names: Vec<String> = result
+        .rows
+        .iter()
+        .map(|row| match row.values.get("name") {
+            Some(Value::String(name)) => name.clone(),
+            other => panic!("Expected string name, got {:?}", other),
+        })
+        .collect();
+    names.sort();
+    assert_eq!(names, vec!["Alice", "Bob", "Carol"]);
+
+    // Collecting zero rows gives an empty list, which unwinds to no rows
+    let result = fixture.assert_query_succeeds(
+        "MATCH (p:Robot) WITH COLLECT(p.name) AS names UNWIND names AS name RETURN name",
+    );
+    assert!(result.rows.is_empty(), "got rows: {:?}", result.rows);
+}
+
 #[test]
 fn test_debug_with_clause_issue() {
     let fixture = TestFixture::empty().expect("Failed to create test fixture");
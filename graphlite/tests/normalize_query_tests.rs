@@ -0,0 +1,58 @@
+//! Tests for the canonical query form produced by normalize_query
+
+use graphlite::normalize_query;
+
+#[test]
+fn test_queries_differing_only_in_literals_normalize_identically() {
+    let canonical = normalize_query("MATCH (p:Person) WHERE p.age = 30 RETURN p.name");
+    assert_eq!(canonical, "MATCH (p:Person) WHERE p.age = $? RETURN p.name");
+
+    assert_eq!(
+        normalize_query("match (p:Person)\n   where p.age = 40.5\treturn p.name // adults"),
+        canonical
+    );
+    assert_eq!(
+        normalize_query("MATCH ( p : Person ) WHERE p.age = 'forty' RETURN p.name"),
+        canonical
+    );
+
+    assert_eq!(
+        normalize_query("INSERT (:Person {name: 'Ann', active: true, tags: [1, 2]})"),
+        normalize_query("INSERT (:Person {name: 'Bob', active: false, tags: [3, 4]})")
+    );
+}
+
+#[test]
+fn test_queries_differing_in_structure_normalize_differently() {
+    let canonical = normalize_query("MATCH (p:Person) WHERE p.age = 30 RETURN p.name");
+
+    assert_ne!(
+        normalize_query("MATCH (p:Person) WHERE p.age > 30 RETURN p.name"),
+        canonical
+    );
+    assert_ne!(
+        normalize_query("MATCH (p:Person) WHERE p.height = 30 RETURN p.name"),
+        canonical
+    );
+    assert_ne!(
+        normalize_query("MATCH (p:Company) WHERE p.age = 30 RETURN p.name"),
+        canonical
+    );
+    assert_ne!(
+        normalize_query("MATCH (p:Person) WHERE p.age = $age RETURN p.name"),
+        canonical
+    );
+}
+
+#[test]
+fn test_string_literals_are_not_split_or_rewritten() {
+    // Keywords, comment markers and whitespace inside a string stay part of the literal
+    assert_eq!(
+        normalize_query("MATCH (p) WHERE p.bio = 'match  where // return' RETURN p"),
+        "MATCH (p) WHERE p.bio = $? RETURN p"
+    );
+    assert_eq!(
+        normalize_query("MATCH (p) RETURN upper(p.name) AS n, p.`first name` ORDER BY n"),
+        "MATCH (p) RETURN upper(p.name) AS n, p.`first name` ORDER BY n"
+    );
+}
@@ -1060,3 +1060,311 @@ fn test_with_and_next_composition() {
         }
     );
 }
+
+#[test]
+fn test_return_star_expands_bound_variables() {
+    let fixture = TestFixture::new().expect("Failed to create test fixture");
+    fixture
+        .setup_graph("test_return_star")
+        .expect("Failed to setup graph");
+
+    fixture
+        .query("INSERT (:Person {name: 'Alice'})-[:KNOWS {since: 2020}]->(:Person {name: 'Bob'})")
+        .expect("Failed to insert data");
+
+    let result = fixture.assert_query_succeeds("MATCH (p:Person)-[r:KNOWS]->(q) RETURN *");
+    assert_eq!(result.variables, vec!["p", "r", "q"]);
+    assert_eq!(result.rows.len(), 1);
+
+    let row = &result.rows[0];
+    assert!(matches!(row.values.get("p"), Some(Value::Node(_))));
+    assert!(matches!(row.values.get("r"), Some(Value::Edge(_))));
+    assert!(matches!(row.values.get("q"), Some(Value::Node(_))));
+}
+
+#[test]
+fn test_edge_property_filter_during_expansion() {
+    let fixture = TestFixture::new().expect("Failed to create test fixture");
+    fixture
+        .setup_graph("test_edge_property_filter")
+        .expect("Failed to setup graph");
+
+    fixture.assert_query_succeeds(
+        "INSERT (:User {name: 'Ann'})-[:RATED {verified: true, stars: 5}]->(:Movie {title: 'Alien'}), \
+                (:User {name: 'Ben'})-[:RATED {verified: false, stars: 2}]->(:Movie {title: 'Brazil'}), \
+                (:User {name: 'Cal'})-[:RATED {stars: 4}]->(:Movie {title: 'Casablanca'}), \
+                (:User {name: 'Dee'})-[:RATED {verified: true, stars: 3}]->(:Movie {title: 'Dune'})",
+    );
+
+    let titles = |query: &str| -> Vec<Value> {
+        fixture
+            .assert_query_succeeds(query)
+            .rows
+            .iter()
+            .map(|row| row.values.get("title").cloned().unwrap_or(Value::Null))
+            .collect()
+    };
+
+    // Only the verified ratings survive the expansion
+    assert_eq!(
+        titles(
+            "MATCH (a:User)-[r:RATED {verified: true}]->(b:Movie) \
+             RETURN b.title AS title ORDER BY title"
+        ),
+        vec![
+            Value::String("Alien".to_string()),
+            Value::String("Dune".to_string()),
+        ]
+    );
+
+    // Edges without the property never match it
+    assert_eq!(
+        titles(
+            "MATCH (b:Movie)<-[r:RATED {verified: false}]-(a:User) \
+             RETURN b.title AS title ORDER BY title"
+        ),
+        vec![Value::String("Brazil".to_string())]
+    );
+
+    // Every constrained property has to match
+    assert_eq!(
+        titles(
+            "MATCH (a)-[r:RATED {verified: true, stars: 3}]->(b) \
+             RETURN b.title AS title ORDER BY title"
+        ),
+        vec![Value::String("Dune".to_string())]
+    );
+}
+
+#[test]
+fn test_any_relationship_expansion() {
+    let fixture = TestFixture::new().expect("Failed to create test fixture");
+    fixture
+        .setup_graph("test_any_relationship")
+        .expect("Failed to setup graph");
+
+    fixture.assert_query_succeeds(
+        "INSERT (:Person {name: 'Ann'})-[:KNOWS]->(:Person {name: 'Ben'}), \
+                (:Person {name: 'Cid'}), (:City {name: 'Oslo'})",
+    );
+    fixture.assert_query_succeeds(
+        "MATCH (a:Person {name: 'Ann'}), (c:Person {name: 'Cid'}) INSERT (a)-[:LIKES]->(c)",
+    );
+    fixture.assert_query_succeeds(
+        "MATCH (a:Person {name: 'Ann'}), (o:City {name: 'Oslo'}) INSERT (a)-[:LIVES_IN]->(o)",
+    );
+    fixture.assert_query_succeeds(
+        "MATCH (b:Person {name: 'Ben'}), (a:Person {name: 'Ann'}) INSERT (b)-[:KNOWS]->(a)",
+    );
+
+    let names = |query: &str| -> Vec<Value> {
+        fixture
+            .assert_query_succeeds(query)
+            .rows
+            .iter()
+            .map(|row| row.values.get("name").cloned().unwrap_or(Value::Null))
+            .collect()
+    };
+    let strings = |values: &[&str]| -> Vec<Value> {
+        values
+            .iter()
+            .map(|value| Value::String(value.to_string()))
+            .collect()
+    };
+
+    // Every out-neighbour, whatever the edge type
+    assert_eq!(
+        names("MATCH (a:Person {name: 'Ann'})-->(b) RETURN b.name AS name ORDER BY name"),
+        strings(&["Ben", "Cid", "Oslo"])
+    );
+    assert_eq!(
+        names("MATCH (a:Person {name: 'Ann'})-[]->(b) RETURN b.name AS name ORDER BY name"),
+        strings(&["Ben", "Cid", "Oslo"])
+    );
+
+    // Incoming and undirected shorthands
+    assert_eq!(
+        names("MATCH (a:Person {name: 'Ann'})<--(b) RETURN b.name AS name ORDER BY name"),
+        strings(&["Ben"])
+    );
+    assert_eq!(
+        names("MATCH (c:City)--(b) RETURN b.name AS name ORDER BY name"),
+        strings(&["Ann"])
+    );
+
+    // Shorthands chain like bracketed edges
+    assert_eq!(
+        names("MATCH (a:Person {name: 'Ben'})-->(b)-->(c) RETURN c.name AS name ORDER BY name"),
+        strings(&["Ben", "Cid", "Oslo"])
+    );
+}
+
+#[test]
+fn test_match_patterns_across_graphs() {
+    let fixture = TestFixture::empty().expect("Failed to create test fixture");
+
+    fixture.assert_query_succeeds("CREATE SCHEMA /cross_schema");
+    fixture.assert_query_succeeds("CREATE GRAPH /cross_schema/people");
+    fixture.assert_query_succeeds("CREATE GRAPH /cross_schema/companies");
+
+    fixture.assert_query_succeeds("SESSION SET GRAPH /cross_schema/people");
+    fixture.assert_query_succeeds(
+        "INSERT (:Person {name: 'Alice', employer: 'Acme'}), \
+         (:Person {name: 'Bob', employer: 'Globex'}), \
+         (:Person {name: 'Carol', employer: 'Initech'})",
+    );
+    fixture.assert_query_succeeds("SESSION SET GRAPH /cross_schema/companies");
+    fixture.assert_query_succeeds("INSERT (:Company {name: 'Acme'}), (:Company {name: 'Globex'})");
+
+    // The session graph holds companies only; people come from the other graph
+    let result = fixture.assert_query_succeeds(
+        "MATCH (a:Person) FROM /cross_schema/people, (b:Company) FROM /cross_schema/companies \
+         WHERE a.employer = b.name RETURN a.name AS person, b.name AS company ORDER BY person",
+    );
+    let pairs: Vec<(Value, Value)> = result
+        .rows
+        .iter()
+        .map(|row| {
+            (
+                row.values.get("person").cloned().unwrap_or(Value::Null),
+                row.values.get("company").cloned().unwrap_or(Value::Null),
+            )
+        })
+        .collect();
+    assert_eq!(
+        pairs,
+        vec![
+            (
+                Value::String("Alice".to_string()),
+                Value::String("Acme".to_string())
+            ),
+            (
+                Value::String("Bob".to_string()),
+                Value::String("Globex".to_string())
+            ),
+        ]
+    );
+
+    // Unscoped patterns keep matching the session graph
+    fixture.assert_first_value(
+        "MATCH (a:Person) FROM /cross_schema/people, (b:Company) RETURN count(*) AS pairs",
+        "pairs",
+        Value::Number(6.0),
+    );
+
+    fixture.assert_query_fails(
+        "MATCH (a:Person) FROM /cross_schema/missing RETURN a.name",
+        "not found",
+    );
+}
+
+#[test]
+fn test_label_expressions_require_all_or_any_labels() {
+    let fixture = TestFixture::new().expect("Failed to create test fixture");
+    fixture
+        .setup_graph("test_label_expressions")
+        .expect("Failed to setup graph");
+    fixture.assert_query_succeeds(
+        "INSERT (:Person {name: 'Ann'}), (:Person&Employee {name: 'Ben'}),
+                (:Employee {name: 'Cat'}), (:Company {name: 'Dot'}), (:Robot {name: 'Eve'})",
+    );
+
+    let names = |query: &str| -> Vec<Value> {
+        fixture
+            .assert_query_succeeds(query)
+            .rows
+            .iter()
+            .map(|row| row.values.get("name").cloned().unwrap_or(Value::Null))
+            .collect()
+    };
+    let expected = |list: &[&str]| -> Vec<Value> {
+        list.iter().map(|n| Value::String(n.to_string())).collect()
+    };
+
+    assert_eq!(
+        names("MATCH (n:Person&Employee) RETURN n.name AS name ORDER BY name"),
+        expected(&["Ben"])
+    );
+    assert_eq!(
+        names("MATCH (n:Employee&Person) RETURN n.name AS name ORDER BY name"),
+        expected(&["Ben"])
+    );
+    assert_eq!(
+        names("MATCH (n:Person|Company) RETURN n.name AS name ORDER BY name"),
+        expected(&["Ann", "Ben", "Dot"])
+    );
+    assert_eq!(
+        names("MATCH (n:(Person&Employee)|Company) RETURN n.name AS name ORDER BY name"),
+        expected(&["Ben", "Dot"])
+    );
+
+    // The expression also constrains the node an expansion reaches
+    fixture.assert_query_succeeds(
+        "MATCH (a:Person {name: 'Ann'}), (b) WHERE b.name <> 'Ann' INSERT (a)-[:KNOWS]->(b)",
+    );
+    assert_eq!(
+        names("MATCH (:Person {name: 'Ann'})-[:KNOWS]->(n:Employee|Robot) RETURN n.name AS name ORDER BY name"),
+        expected(&["Ben", "Cat", "Eve"])
+    );
+    assert_eq!(
+        names("MATCH (:Person {name: 'Ann'})-[:KNOWS]->(n:Person&Employee) RETURN n.name AS name"),
+        expected(&["Ben"])
+    );
+}
+
+#[test]
+fn test_hop_into_anchored_node_expands_incoming_edges() {
+    let fixture = TestFixture::new().expect("Failed to create test fixture");
+    fixture
+        .setup_graph("test_reverse_expansion")
+        .expect("Failed to setup graph");
+
+    let users = (0..300)
+        .map(|n| format!("(:User {{n: {}}})", n))
+        .collect::<Vec<_>>()
+        .join(", ");
+    fixture.assert_query_succeeds(&format!(
+        "INSERT (:Celebrity {{name: 'Star'}}), (:Celebrity {{name: 'Minor'}}), {}",
+        users
+    ));
+    fixture.assert_query_succeeds(
+        "MATCH (u:User), (c:Celebrity {name: 'Star'}) WHERE u.n < 200 INSERT (u)-[:FOLLOWS]->(c)",
+    );
+    fixture.assert_query_succeeds(
+        "MATCH (u:User), (c:Celebrity {name: 'Minor'}) WHERE u.n > 289 INSERT (u)-[:FOLLOWS]->(c)",
+    );
+
+    for query in [
+        "MATCH (f:User)-[:FOLLOWS]->(c:Celebrity {name: 'Star'}) RETURN count(f) AS followers",
+        "MATCH (c:Celebrity {name: 'Star'})<-[:FOLLOWS]-(f:User) RETURN count(f) AS followers",
+        "MATCH (f:User)-[:FOLLOWS]-(c:Celebrity {name: 'Star'}) RETURN count(f) AS followers",
+    ] {
+        fixture.assert_first_value(query, "followers", Value::Number(200.0));
+
+        // The scan finds only the anchored celebrity; the hop walks its
+        // incoming edges instead of the outgoing edges of every user
+        let analyzed = fixture
+            .explain_analyze(query)
+            .expect("Failed to analyze query");
+        let scan = analyzed
+            .operator("NodeSeqScan")
+            .or_else(|| analyzed.operator("NodeIndexScan"))
+            .expect("Plan should scan nodes");
+        assert_eq!(scan.actual_rows, 1, "{}", analyzed.format_tree());
+        let expand = analyzed
+            .operator("HashExpand")
+            .or_else(|| analyzed.operator("IndexedExpand"))
+            .expect("Plan should expand");
+        assert_eq!(expand.actual_rows, 200, "{}", analyzed.format_tree());
+        if !query.contains("]-(c") {
+            assert!(analyzed.plan.to_dot().contains("(c)<-[:FOLLOWS]-(f)"));
+        }
+    }
+
+    // Anonymous followers are counted the same way
+    fixture.assert_first_value(
+        "MATCH (:User)-[e:FOLLOWS]->(:Celebrity {name: 'Minor'}) RETURN count(e) AS followers",
+        "followers",
+        Value::Number(10.0),
+    );
+}
@@ -0,0 +1,109 @@
+//! Tests for QueryCoordinator::validate, which reports every validation
+//! error in a query together with its source location
+
+use graphlite::{QueryCoordinator, ValidationErrorType};
+use tempfile::tempdir;
+
+fn location_of(error: &graphlite::ValidationError) -> (usize, usize) {
+    let location = error
+        .location
+        .as_ref()
+        .unwrap_or_else(|| panic!("Expected a location for '{}'", error.message));
+    (location.line, location.column)
+}
+
+#[test]
+fn test_validate_reports_all_errors_with_locations() {
+    let temp_dir = tempdir().unwrap();
+    let coordinator = QueryCoordinator::from_path(temp_dir.path().join("db")).unwrap();
+
+    let errors = coordinator.validate(
+        "MATCH (p:Person)\n\
+         RETURN p.name, q.age\n\
+         HAVING count(p) > 1",
+    );
+
+    assert_eq!(errors.len(), 2, "Expected two errors, got {:?}", errors);
+
+    assert!(errors[0].message.contains("Undefined variable 'q'"));
+    assert_eq!(errors[0].error_type, ValidationErrorType::Semantic);
+    assert_eq!(location_of(&errors[0]), (2, 16));
+
+    assert!(errors[1].message.contains("HAVING requires a GROUP BY"));
+    assert_eq!(location_of(&errors[1]), (3, 1));
+}
+
+#[test]
+fn test_validate_reports_ungrouped_return_item() {
+    let temp_dir = tempdir().unwrap();
+    let coordinator = QueryCoordinator::from_path(temp_dir.path().join("db")).unwrap();
+
+    let errors = coordinator.validate(
+        "MATCH (p:Person)\n\
+         RETURN p.city, p.name, count(p) AS total\n\
+         GROUP BY p.city",
+    );
+
+    assert_eq!(errors.len(), 1, "Expected one error, got {:?}", errors);
+    assert!(errors[0].message.contains("'p.name'"));
+    assert_eq!(location_of(&errors[0]), (2, 16));
+}
+
+#[test]
+fn test_validate_accepts_valid_queries() {
+    let temp_dir = tempdir().unwrap();
+    let coordinator = QueryCoordinator::from_path(temp_dir.path().join("db")).unwrap();
+
+    assert!(coordinator
+        .validate("MATCH (p:Person) WHERE p.age > 30 RETURN p.name")
+        .is_empty());
+    // Aggregates without GROUP BY group implicitly by the other RETURN items
+    assert!(coordinator
+        .validate("MATCH (p:Person) RETURN p.city, count(*) AS total")
+        .is_empty());
+}
+
+#[test]
+fn test_validate_reports_parse_errors() {
+    let temp_dir = tempdir().unwrap();
+    let coordinator = QueryCoordinator::from_path(temp_dir.path().join("db")).unwrap();
+
+    let errors = coordinator.validate("MATCH (p:Person RETURN p");
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].error_type, ValidationErrorType::Syntax);
+    // Points at RETURN, where the closing parenthesis was expected
+    assert_eq!(location_of(&errors[0]), (1, 17));
+
+    let errors = coordinator.validate(
+        "MATCH (p:Person)\n\
+         WHERE p.age > 30 AND (p.name = 'Alice'\n\
+         RETURN p.name",
+    );
+    assert_eq!(errors.len(), 1);
+    assert_eq!(location_of(&errors[0]), (3, 1));
+}
+
+#[test]
+fn test_nesting_limit_is_per_coordinator() {
+    let temp_dir = tempdir().unwrap();
+    let strict = QueryCoordinator::from_path(temp_dir.path().join("strict")).unwrap();
+    let default = QueryCoordinator::from_path(temp_dir.path().join("default")).unwrap();
+    assert_eq!(
+        default.max_nesting_depth(),
+        graphlite::DEFAULT_MAX_NESTING_DEPTH
+    );
+
+    strict.set_max_nesting_depth(4);
+    let nested = |depth: usize| format!("RETURN {}1{}", "(".repeat(depth), ")".repeat(depth));
+
+    let errors = strict.validate(&nested(8));
+    assert_eq!(errors.len(), 1, "Expected one error, got {:?}", errors);
+    assert!(errors[0].message.contains("expression nesting too deep"));
+    assert!(strict.validate(&nested(3)).is_empty());
+
+    // Another coordinator in the same process keeps its own limit, and a
+    // query nested up to it parses quickly
+    let started = std::time::Instant::now();
+    assert!(default.validate(&nested(8)).is_empty());
+    assert!(started.elapsed() < std::time::Duration::from_secs(2));
+}
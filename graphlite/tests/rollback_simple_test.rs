@@ -74,3 +74,40 @@ fn test_simple_rollback_debug() {
         }
     }
 }
+
+#[test]
+fn test_rollback_undoes_create_schema_and_graph() {
+    let fixture = TestFixture::empty().expect("Failed to create fixture");
+
+    fixture.assert_query_succeeds("START TRANSACTION");
+    fixture.assert_query_succeeds("CREATE SCHEMA /txn_ddl_schema");
+    fixture.assert_query_succeeds("CREATE GRAPH /txn_ddl_schema/txn_ddl_graph");
+
+    // Force an error inside the transaction, then roll it back
+    fixture.assert_query_fails(
+        "CREATE GRAPH /txn_ddl_schema/txn_ddl_graph",
+        "already exists",
+    );
+    fixture.assert_query_succeeds("ROLLBACK");
+
+    let schemas = fixture.assert_query_succeeds("CALL gql.list_schemas()");
+    assert!(
+        !schemas.rows.iter().any(|row| row.values.get("schema_name")
+            == Some(&Value::String("txn_ddl_schema".to_string()))),
+        "Schema should not exist after ROLLBACK"
+    );
+
+    let graphs = fixture.assert_query_succeeds("CALL gql.list_graphs()");
+    assert!(
+        !graphs
+            .rows
+            .iter()
+            .any(|row| row.values.get("graph_name")
+                == Some(&Value::String("txn_ddl_graph".to_string()))),
+        "Graph should not exist after ROLLBACK"
+    );
+
+    // Both objects can be created again now that the rollback removed them
+    fixture.assert_query_succeeds("CREATE SCHEMA /txn_ddl_schema");
+    fixture.assert_query_succeeds("CREATE GRAPH /txn_ddl_schema/txn_ddl_graph");
+}
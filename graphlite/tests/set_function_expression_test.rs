@@ -1,7 +1,7 @@
 #[path = "testutils/mod.rs"]
 mod testutils;
 
-use graphlite::Value;
+use graphlite::{DurationValue, Value};
 use testutils::test_fixture::TestFixture;
 
 // ============================================================================
@@ -198,7 +198,7 @@ fn test_match_set_edge_with_temporal_functions() {
     // PT30M = 30 minutes = 1800 seconds
     assert_eq!(
         response_time,
-        &Value::Number(1800.0),
+        &Value::Duration(DurationValue::from_seconds(1800)),
         "duration('PT30M') should be 1800 seconds"
     );
 }
@@ -8,7 +8,7 @@ use graphlite::Value;
 use serde_json::Value as JsonValue;
 use std::collections::HashMap;
 use std::path::PathBuf;
-use std::process::Command;
+use std::process::{Command, Output};
 use tempfile::TempDir;
 
 /// CLI-based test fixture - all operations via command-line interface
@@ -142,6 +142,31 @@ impl CliFixture {
         String::from_utf8_lossy(&output.stderr).to_string()
     }
 
+    /// Run a CLI subcommand against this database as the admin user
+    pub fn run_command(&self, command: &str, args: &[&str]) -> Output {
+        Command::new("cargo")
+            .args([
+                "run",
+                "--quiet",
+                "--package",
+                "gql-cli",
+                "--bin",
+                "graphlite",
+                "--",
+                command,
+            ])
+            .arg("--path")
+            .arg(&self.db_path)
+            .arg("--user")
+            .arg(&self.admin_user)
+            .arg("--password")
+            .arg(&self.admin_password)
+            .args(args)
+            .env("RUST_LOG", "error") // Suppress INFO logs in CLI output
+            .output()
+            .expect("Failed to run CLI command")
+    }
+
     /// Get unique schema name for test isolation
     pub fn schema_name(&self) -> String {
         format!("test_{}", fastrand::u64(..))
@@ -3,7 +3,7 @@
 //! Provides isolated test database instances using ONLY the public QueryCoordinator API.
 //! Tests must not access internal components - use only public QueryCoordinator API.
 
-use graphlite::{QueryCoordinator, QueryResult, Value};
+use graphlite::{AnalyzedQueryPlan, QueryCoordinator, QueryPlan, QueryResult, Value};
 use std::collections::HashMap;
 use std::sync::Arc;
 
@@ -217,7 +217,23 @@ impl TestFixture {
     /// Execute a query
     pub fn query(&self, query_text: &str) -> Result<QueryResult, String> {
         // Use the coordinator to execute the query with proper orchestration
-        self.coordinator.process_query(query_text, &self.session_id)
+        self.coordinator
+            .process_query(query_text, &self.session_id)
+            .map_err(String::from)
+    }
+
+    /// Plan a query without running it
+    pub fn explain(&self, query_text: &str) -> Result<QueryPlan, String> {
+        self.coordinator
+            .explain_query_in_session(query_text, &self.session_id)
+            .map_err(String::from)
+    }
+
+    /// Run a query and measure each operator of its plan
+    pub fn explain_analyze(&self, query_text: &str) -> Result<AnalyzedQueryPlan, String> {
+        self.coordinator
+            .explain_analyze(query_text, &self.session_id)
+            .map_err(String::from)
     }
 
     /// Execute query and assert success
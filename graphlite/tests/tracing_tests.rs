@@ -0,0 +1,75 @@
+//! Tests for the tracing spans recorded around query phases
+
+#[path = "testutils/mod.rs"]
+mod testutils;
+
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use testutils::test_fixture::TestFixture;
+use tracing::span::{Attributes, Id};
+use tracing::Subscriber;
+use tracing_subscriber::layer::{Context, Layer, SubscriberExt};
+use tracing_subscriber::registry::LookupSpan;
+
+/// Records the name and lifetime of every span when it closes
+#[derive(Clone, Default)]
+struct SpanRecorder {
+    closed: Arc<Mutex<Vec<(String, Duration)>>>,
+}
+
+impl<S> Layer<S> for SpanRecorder
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+{
+    fn on_new_span(&self, _attrs: &Attributes<'_>, id: &Id, ctx: Context<'_, S>) {
+        if let Some(span) = ctx.span(id) {
+            span.extensions_mut().insert(Instant::now());
+        }
+    }
+
+    fn on_close(&self, id: Id, ctx: Context<'_, S>) {
+        if let Some(span) = ctx.span(&id) {
+            let started = span.extensions().get::<Instant>().copied();
+            if let Some(started) = started {
+                self.closed
+                    .lock()
+                    .unwrap()
+                    .push((span.name().to_string(), started.elapsed()));
+            }
+        }
+    }
+}
+
+#[test]
+fn test_query_phases_are_traced_in_order() {
+    let fixture = TestFixture::new().expect("Failed to create test fixture");
+    fixture
+        .setup_graph("test_query_phase_spans")
+        .expect("Failed to setup graph");
+    fixture.assert_query_succeeds(
+        "INSERT (:Person {name: 'Alice', age: 30}), (:Person {name: 'Bob', age: 25})",
+    );
+
+    let recorder = SpanRecorder::default();
+    let subscriber = tracing_subscriber::registry().with(recorder.clone());
+    tracing::subscriber::with_default(subscriber, || {
+        fixture.assert_query_succeeds(
+            "MATCH (p:Person) WHERE p.age > 20 RETURN p.name ORDER BY p.name",
+        );
+    });
+
+    let closed = recorder.closed.lock().unwrap().clone();
+    let names: Vec<&str> = closed.iter().map(|(name, _)| name.as_str()).collect();
+    assert_eq!(
+        names,
+        vec!["parse", "plan", "optimize", "execute", "query"],
+        "phases should close in the order they run, inside the query span"
+    );
+    for (name, duration) in &closed {
+        assert!(
+            *duration > Duration::ZERO,
+            "span '{}' should have a duration",
+            name
+        );
+    }
+}
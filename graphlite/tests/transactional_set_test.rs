@@ -99,3 +99,67 @@ fn test_set_transactional_success_all_valid() {
         "Birthday should be set"
     );
 }
+
+#[test]
+fn test_set_conditional_case_and_rollback() {
+    let fixture = TestFixture::new().expect("Failed to create fixture");
+
+    fixture
+        .setup_graph("conditional_set_test")
+        .expect("Failed to setup graph");
+
+    fixture
+        .query(
+            "INSERT (:Person {name: 'Ann', spend: 2500, tier: 'none'}), \
+             (:Person {name: 'Ben', spend: 1000, tier: 'none'}), \
+             (:Person {name: 'Cal', spend: 40, tier: 'none'})",
+        )
+        .expect("Failed to create persons");
+
+    let tiers = |fixture: &TestFixture| {
+        let result = fixture
+            .query("MATCH (p:Person) RETURN p.name as name, p.tier as tier ORDER BY name")
+            .expect("Query should succeed");
+        result
+            .rows
+            .iter()
+            .map(|row| row.values["tier"].clone())
+            .collect::<Vec<_>>()
+    };
+
+    fixture
+        .query("START TRANSACTION")
+        .expect("START TRANSACTION should succeed");
+    fixture
+        .query(
+            "MATCH (p:Person) SET p.tier = CASE WHEN p.spend > 1000 THEN 'gold' ELSE 'silver' END",
+        )
+        .expect("Conditional SET should succeed");
+
+    assert_eq!(
+        tiers(&fixture),
+        vec![
+            Value::String("gold".to_string()),
+            Value::String("silver".to_string()),
+            Value::String("silver".to_string()),
+        ]
+    );
+
+    fixture.query("ROLLBACK").expect("ROLLBACK should succeed");
+
+    assert_eq!(
+        tiers(&fixture),
+        vec![Value::String("none".to_string()); 3],
+        "Tiers should be restored by ROLLBACK"
+    );
+
+    // Simple CASE on the node's own property, without an ELSE branch
+    fixture
+        .query("MATCH (p:Person) SET p.tier = CASE p.name WHEN 'Cal' THEN 'new' END")
+        .expect("Simple CASE SET should succeed");
+
+    assert_eq!(
+        tiers(&fixture),
+        vec![Value::Null, Value::Null, Value::String("new".to_string()),]
+    );
+}
@@ -0,0 +1,38 @@
+//! Tests for the Display form of result values and rows
+
+use graphlite::{Row, Value};
+use std::collections::HashMap;
+
+#[test]
+fn test_value_display() {
+    let list = Value::List(vec![Value::String("a".to_string()), Value::Number(1.0)]);
+    assert_eq!(list.to_string(), "[a, 1]");
+    assert_eq!(list.debug_quoted().to_string(), "[\"a\", 1]");
+
+    assert_eq!(Value::Null.to_string(), "NULL");
+    assert_eq!(Value::Number(2.5).to_string(), "2.5");
+    assert_eq!(Value::Boolean(true).to_string(), "true");
+
+    let mut map = HashMap::new();
+    map.insert("b".to_string(), Value::String("x".to_string()));
+    map.insert("a".to_string(), Value::Null);
+    assert_eq!(Value::Map(map).to_string(), "{a: NULL, b: x}");
+}
+
+#[test]
+fn test_row_display() {
+    let row = Row::from_positional(
+        vec![
+            Value::String("Alice".to_string()),
+            Value::Number(30.0),
+            Value::Null,
+        ],
+        &["name".to_string(), "age".to_string(), "city".to_string()],
+    );
+    assert_eq!(row.to_string(), "Alice\t30\tNULL");
+
+    let mut values = HashMap::new();
+    values.insert("b".to_string(), Value::Number(2.0));
+    values.insert("a".to_string(), Value::Number(1.0));
+    assert_eq!(Row::from_values(values).to_string(), "1\t2");
+}
@@ -5,9 +5,79 @@
 //! for graph databases.
 
 use crate::error::{Error, Result};
+use crate::export::{self, OutputFormat};
+use crate::pagination::{Cursor, CursorStore, ResultPage};
 use crate::transaction::Transaction;
-use graphlite::{QueryCoordinator, QueryResult};
-use std::sync::Arc;
+use graphlite::{
+    AnalyzedQueryPlan, ChangeEvent, CompactionReport, DryRunReport, FlushPolicy, GraphLockGuard,
+    HealthReport, LockMode, QueryCoordinator, QueryError, QueryErrorKind, QueryLimits,
+    QueryMetrics, QueryPlan, QueryResult, SlowQuery, SubscriptionId, ValidationError, Value,
+};
+use std::collections::HashMap;
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex, OnceLock, RwLock, Weak};
+use std::time::Duration;
+
+/// Rows between calls to a [`Session::query_with_progress`] callback
+const PROGRESS_EVERY_ROWS: usize = 1000;
+
+/// Longest wait between calls to a [`Session::query_with_progress`] callback
+const PROGRESS_EVERY: Duration = Duration::from_millis(100);
+
+/// Process-wide registry of open coordinators, keyed by canonical database path
+///
+/// Sled holds an exclusive file lock on the database directory, so opening the
+/// same path twice in one process would fail. Every `GraphLite` handle for a
+/// path shares one coordinator instead. Entries are weak: the database closes
+/// once the last handle and session referencing it are dropped.
+static COORDINATORS: OnceLock<Mutex<HashMap<PathBuf, Weak<QueryCoordinator>>>> = OnceLock::new();
+
+/// Resolve the registry key for a database path
+///
+/// The directory is created first so that canonicalization resolves symlinks
+/// and relative components the same way for every caller.
+fn registry_key(path: &Path) -> Result<PathBuf> {
+    std::fs::create_dir_all(path)
+        .and_then(|_| std::fs::canonicalize(path))
+        .map_err(|e| Error::Connection(format!("Failed to open database: {}", e)))
+}
+
+/// Options for [`GraphLite::open_with_options`]
+///
+/// # Examples
+///
+/// ```no_run
+/// use graphlite_sdk::{FlushPolicy, GraphLite, OpenOptions};
+/// use std::time::Duration;
+///
+/// let options = OpenOptions::new().flush_policy(FlushPolicy::Periodic(Duration::from_millis(200)));
+/// let db = GraphLite::open_with_options("./mydb", options)?;
+/// # Ok::<(), graphlite_sdk::Error>(())
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct OpenOptions {
+    flush_policy: FlushPolicy,
+}
+
+impl OpenOptions {
+    /// Options with every setting at its default
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set when writes are flushed to disk
+    ///
+    /// Defaults to [`FlushPolicy::OnCommit`]. With [`FlushPolicy::Periodic`]
+    /// or [`FlushPolicy::Manual`], writes not yet flushed survive a clean
+    /// close but may be lost if the process crashes; under `Manual`, call
+    /// [`GraphLite::flush`] to make them durable.
+    pub fn flush_policy(mut self, flush_policy: FlushPolicy) -> Self {
+        self.flush_policy = flush_policy;
+        self
+    }
+}
 
 /// Main entry point for GraphLite database operations
 ///
@@ -55,8 +125,71 @@ impl GraphLite {
     /// let db = GraphLite::open("./mydb")?;
     /// # Ok::<(), graphlite_sdk::Error>(())
     /// ```
-    pub fn open<P: AsRef<std::path::Path>>(path: P) -> Result<Self> {
-        let coordinator = QueryCoordinator::from_path(path)
+    ///
+    /// Opening a path that is already open in this process returns a handle to
+    /// the same underlying coordinator, so storage, catalog and transactions
+    /// are shared between handles.
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Self> {
+        Self::open_with_options(path, OpenOptions::default())
+    }
+
+    /// Open a GraphLite database at the given path with the given options
+    ///
+    /// If the path is already open in this process, the existing database is
+    /// shared and `options` are ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use graphlite_sdk::{FlushPolicy, GraphLite, OpenOptions};
+    ///
+    /// // Bulk load without flushing after every write
+    /// let db = GraphLite::open_with_options(
+    ///     "./mydb",
+    ///     OpenOptions::new().flush_policy(FlushPolicy::Manual),
+    /// )?;
+    /// // ... load data ...
+    /// db.flush()?;
+    /// # Ok::<(), graphlite_sdk::Error>(())
+    /// ```
+    pub fn open_with_options<P: AsRef<Path>>(path: P, options: OpenOptions) -> Result<Self> {
+        let key = registry_key(path.as_ref())?;
+
+        let mut coordinators = COORDINATORS
+            .get_or_init(|| Mutex::new(HashMap::new()))
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+
+        if let Some(coordinator) = coordinators.get(&key).and_then(Weak::upgrade) {
+            return Ok(GraphLite { coordinator });
+        }
+
+        // Drop entries whose databases have already been closed
+        coordinators.retain(|_, coordinator| coordinator.strong_count() > 0);
+
+        let coordinator = QueryCoordinator::from_path_with_flush_policy(&key, options.flush_policy)
+            .map_err(|e| Error::Connection(format!("Failed to open database: {}", e)))?;
+        coordinators.insert(key, Arc::downgrade(&coordinator));
+        Ok(GraphLite { coordinator })
+    }
+
+    /// Open a fresh database that lives entirely in memory
+    ///
+    /// Nothing is written to disk and the data is discarded when the last
+    /// handle and session are dropped. Each call returns an independent,
+    /// empty database, which makes this convenient for tests.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use graphlite_sdk::GraphLite;
+    ///
+    /// let db = GraphLite::open_in_memory()?;
+    /// let session = db.session("admin")?;
+    /// # Ok::<(), graphlite_sdk::Error>(())
+    /// ```
+    pub fn open_in_memory() -> Result<Self> {
+        let coordinator = QueryCoordinator::in_memory()
             .map_err(|e| Error::Connection(format!("Failed to open database: {}", e)))?;
         Ok(GraphLite { coordinator })
     }
@@ -90,12 +223,346 @@ impl GraphLite {
             .map_err(|e| Error::Session(format!("Failed to create session: {}", e)))?;
 
         Ok(Session {
-            id: session_id,
+            id: RwLock::new(session_id),
             coordinator: self.coordinator.clone(),
             username: username.to_string(),
+            auto_reconnect: AtomicBool::new(false),
+            cursors: CursorStore::default(),
         })
     }
 
+    /// Validate a query without executing it
+    ///
+    /// Returns every problem found in the query rather than stopping at the
+    /// first one, each with the line and column where it starts. An empty
+    /// vector means the query is valid.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use graphlite_sdk::GraphLite;
+    /// # let db = GraphLite::open("./mydb")?;
+    /// for error in db.validate("MATCH (p:Person) RETURN q.name") {
+    ///     println!("{:?}: {}", error.location, error.message);
+    /// }
+    /// # Ok::<(), graphlite_sdk::Error>(())
+    /// ```
+    pub fn validate(&self, query: &str) -> Vec<ValidationError> {
+        self.coordinator.validate(query)
+    }
+
+    /// Check that the database is able to serve queries
+    ///
+    /// Reports the status and latency of storage, the catalog and session
+    /// management. Suitable for liveness and readiness probes: the check is
+    /// cheap and does not modify any data.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use graphlite_sdk::GraphLite;
+    /// # let db = GraphLite::open("./mydb")?;
+    /// let report = db.health_check();
+    /// if !report.is_healthy() {
+    ///     for component in &report.components {
+    ///         println!("{}: {:?} {:?}", component.name, component.status, component.message);
+    ///     }
+    /// }
+    /// # Ok::<(), graphlite_sdk::Error>(())
+    /// ```
+    pub fn health_check(&self) -> HealthReport {
+        self.coordinator.health_check()
+    }
+
+    /// Call `callback` for every query that takes at least `threshold`
+    ///
+    /// The callback receives the query text, its type and timing, and runs on
+    /// the thread that executed the query before the result is returned, so
+    /// keep it short. The hook belongs to the database, not to this handle:
+    /// it sees queries from every handle and session open on the same path.
+    /// Registering a new callback replaces the previous one.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use graphlite_sdk::GraphLite;
+    /// # use std::time::Duration;
+    /// # let db = GraphLite::open("./mydb")?;
+    /// db.on_slow_query(Duration::from_millis(250), |slow| {
+    ///     eprintln!("{:?} {:?}: {}", slow.elapsed, slow.info.query_type, slow.query_text);
+    /// });
+    /// # Ok::<(), graphlite_sdk::Error>(())
+    /// ```
+    pub fn on_slow_query<F>(&self, threshold: Duration, callback: F)
+    where
+        F: Fn(&SlowQuery) + Send + Sync + 'static,
+    {
+        self.coordinator.on_slow_query(threshold, callback)
+    }
+
+    /// Cumulative query counters: totals, errors, counts per query type and latency
+    ///
+    /// Like the slow-query hook, the counters belong to the database and
+    /// cover queries from every handle and session open on the same path.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use graphlite_sdk::GraphLite;
+    /// # let db = GraphLite::open("./mydb")?;
+    /// let metrics = db.metrics();
+    /// println!(
+    ///     "{} queries, {} errors, {:?} on average",
+    ///     metrics.total_queries,
+    ///     metrics.total_errors,
+    ///     metrics.average_latency()
+    /// );
+    /// # Ok::<(), graphlite_sdk::Error>(())
+    /// ```
+    pub fn metrics(&self) -> QueryMetrics {
+        self.coordinator.metrics()
+    }
+
+    /// Set every counter reported by [`GraphLite::metrics`] back to zero
+    pub fn reset_metrics(&self) {
+        self.coordinator.reset_metrics()
+    }
+
+    /// Get notified of every node and edge that committed writes change in
+    /// the graph at `graph_path`
+    ///
+    /// Like the slow-query hook, the subscription belongs to the database and
+    /// sees commits from every handle and session open on the same path. The
+    /// callback runs on a background thread in commit order; delivery is
+    /// best-effort. Pass the returned id to [`GraphLite::unsubscribe`] to stop.
+    /// Fails if the delivery thread cannot be started.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use graphlite_sdk::GraphLite;
+    /// # let db = GraphLite::open("./mydb")?;
+    /// db.subscribe("/social/people", |change| {
+    ///     println!("{:?} {:?} {}", change.kind, change.entity, change.entity_id);
+    /// })?;
+    /// # Ok::<(), graphlite_sdk::Error>(())
+    /// ```
+    pub fn subscribe<F>(&self, graph_path: &str, callback: F) -> Result<SubscriptionId>
+    where
+        F: Fn(&ChangeEvent) + Send + 'static,
+    {
+        self.coordinator
+            .subscribe(graph_path, callback)
+            .map_err(Error::GraphLite)
+    }
+
+    /// Remove a subscription made with [`GraphLite::subscribe`]
+    ///
+    /// Returns whether the subscription existed.
+    pub fn unsubscribe(&self, subscription: SubscriptionId) -> bool {
+        self.coordinator.unsubscribe(subscription)
+    }
+
+    /// Roll back transactions that stay idle for longer than `timeout`
+    ///
+    /// The next statement in a session whose transaction timed out fails
+    /// with [`Error::TransactionTimedOut`]. Pass `None` to disable the
+    /// timeout (the default).
+    pub fn set_transaction_timeout(&self, timeout: Option<Duration>) {
+        self.coordinator.set_transaction_timeout(timeout)
+    }
+
+    /// Roll back every transaction that has exceeded the idle timeout
+    ///
+    /// Sessions that are never used again keep their transaction open until
+    /// this runs; call it periodically to clean them up. Returns the number
+    /// of transactions rolled back.
+    pub fn rollback_idle_transactions(&self) -> usize {
+        self.coordinator.rollback_idle_transactions()
+    }
+
+    /// Lock a graph, waiting as long as it takes
+    ///
+    /// A [`LockMode::Shared`] lock blocks writes to the graph from other
+    /// threads; a [`LockMode::Exclusive`] lock blocks their reads as well.
+    /// Statements on the locking thread are not blocked, so a maintenance
+    /// job can lock a graph and keep modifying it. A wait that would deadlock
+    /// with another thread fails with [`Error::Conflict`]. The lock is
+    /// released when the returned guard is dropped.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use graphlite_sdk::{GraphLite, LockMode};
+    /// # let db = GraphLite::open("./mydb")?;
+    /// let session = db.session("admin")?;
+    /// session.execute("SESSION SET GRAPH /app/social")?;
+    ///
+    /// let guard = db.lock_graph("/app/social", LockMode::Exclusive)?;
+    /// session.execute("MATCH (p:Person) WHERE p.stale = true DETACH DELETE p")?;
+    /// drop(guard);
+    /// # Ok::<(), graphlite_sdk::Error>(())
+    /// ```
+    pub fn lock_graph(&self, graph_path: &str, mode: LockMode) -> Result<GraphLockGuard> {
+        self.coordinator
+            .lock_graph(graph_path, mode, None)
+            .map_err(|e| Error::from_engine(e.kind(), e.to_string(), Error::Query))
+    }
+
+    /// Lock a graph like [`GraphLite::lock_graph`], giving up after `timeout`
+    ///
+    /// Fails with [`Error::LockTimeout`] if a conflicting lock is still held
+    /// when the timeout expires. With a zero timeout a busy graph fails at
+    /// once with [`Error::Conflict`].
+    pub fn lock_graph_timeout(
+        &self,
+        graph_path: &str,
+        mode: LockMode,
+        timeout: Duration,
+    ) -> Result<GraphLockGuard> {
+        self.coordinator
+            .lock_graph(graph_path, mode, Some(timeout))
+            .map_err(|e| Error::from_engine(e.kind(), e.to_string(), Error::Query))
+    }
+
+    /// Cap how many queries run at once across all sessions
+    ///
+    /// At most `max_concurrent` queries execute at a time and up to
+    /// `max_queued` more wait for a slot. Queries beyond that fail
+    /// immediately with [`Error::Overloaded`], which protects memory under
+    /// bursty load. Pass `None` to remove the cap (the default).
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use graphlite_sdk::GraphLite;
+    /// # let db = GraphLite::open("./mydb")?;
+    /// db.set_max_concurrency(Some((4, 64)));
+    /// # Ok::<(), graphlite_sdk::Error>(())
+    /// ```
+    pub fn set_max_concurrency(&self, limit: Option<(usize, usize)>) {
+        self.coordinator.set_max_concurrency(limit);
+    }
+
+    /// Limit how many queries per second `username` may run
+    ///
+    /// Each user has an independent token bucket holding up to `burst`
+    /// queries and refilling at `queries_per_sec`. Queries that find the
+    /// bucket empty fail with [`Error::RateLimited`]; other users are not
+    /// affected.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use graphlite_sdk::GraphLite;
+    /// # let db = GraphLite::open("./mydb")?;
+    /// db.set_rate_limit("tenant_a", 50.0, 100);
+    /// # Ok::<(), graphlite_sdk::Error>(())
+    /// ```
+    pub fn set_rate_limit(&self, username: &str, queries_per_sec: f64, burst: u32) {
+        self.coordinator
+            .set_rate_limit(username, queries_per_sec, burst);
+    }
+
+    /// Remove the rate limit set with [`GraphLite::set_rate_limit`]
+    pub fn clear_rate_limit(&self, username: &str) {
+        self.coordinator.clear_rate_limit(username);
+    }
+
+    /// Answer repeated MATCH queries from a cache of their result rows
+    ///
+    /// Identical queries from the same user against the same graph, with
+    /// the same parameters, are served from the cache until a statement
+    /// changes that graph's data. At most `capacity` results are kept, each
+    /// for at most `ttl` if given. Cache hits and misses are counted in
+    /// [`GraphLite::metrics`]. Off by default.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use graphlite_sdk::GraphLite;
+    /// # use std::time::Duration;
+    /// # let db = GraphLite::open("./mydb")?;
+    /// db.set_result_cache(1000, Some(Duration::from_secs(60)));
+    /// # Ok::<(), graphlite_sdk::Error>(())
+    /// ```
+    pub fn set_result_cache(&self, capacity: usize, ttl: Option<Duration>) {
+        self.coordinator.set_result_cache(capacity, ttl);
+    }
+
+    /// Turn off the cache enabled with [`GraphLite::set_result_cache`]
+    pub fn disable_result_cache(&self) {
+        self.coordinator.disable_result_cache();
+    }
+
+    /// Limit how deeply expressions and parenthesized queries may nest
+    ///
+    /// Deeper queries fail with a parse error instead of overflowing the
+    /// stack, which protects servers that run untrusted queries. Applies to
+    /// every session of this database; the default is
+    /// [`graphlite::DEFAULT_MAX_NESTING_DEPTH`].
+    pub fn set_max_nesting_depth(&self, max_depth: usize) {
+        self.coordinator.set_max_nesting_depth(max_depth);
+    }
+
+    /// Get the application-defined schema version of this database
+    ///
+    /// Returns 0 until [`GraphLite::set_schema_version`] is first called.
+    /// Together they let an application run each migration exactly once.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use graphlite_sdk::GraphLite;
+    /// # let db = GraphLite::open("./mydb")?;
+    /// if db.schema_version()? < 2 {
+    ///     let session = db.session("admin")?;
+    ///     session.execute("CREATE GRAPH IF NOT EXISTS /app/audit")?;
+    ///     db.set_schema_version(2)?;
+    /// }
+    /// # Ok::<(), graphlite_sdk::Error>(())
+    /// ```
+    pub fn schema_version(&self) -> Result<u64> {
+        self.coordinator.schema_version().map_err(Error::GraphLite)
+    }
+
+    /// Persist the application-defined schema version of this database
+    ///
+    /// The version is stored in the system catalog and flushed before this
+    /// returns, so it survives reopening the database.
+    pub fn set_schema_version(&self, version: u64) -> Result<()> {
+        self.coordinator
+            .set_schema_version(version)
+            .map_err(Error::GraphLite)
+    }
+
+    /// Flush every pending write to disk
+    ///
+    /// Required for durability when the database was opened with
+    /// [`FlushPolicy::Manual`]; otherwise it only makes writes durable sooner.
+    pub fn flush(&self) -> Result<()> {
+        self.coordinator.flush().map_err(Error::GraphLite)
+    }
+
+    /// Compact the database files
+    ///
+    /// Drops the storage left behind by deleted graphs and flushes pending
+    /// writes, then reports the bytes on disk before and after. Queries from
+    /// other sessions keep running while it works.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use graphlite_sdk::GraphLite;
+    /// # let db = GraphLite::open("./mydb")?;
+    /// let report = db.compact()?;
+    /// println!("reclaimed {} bytes", report.reclaimed_bytes);
+    /// # Ok::<(), graphlite_sdk::Error>(())
+    /// ```
+    pub fn compact(&self) -> Result<CompactionReport> {
+        self.coordinator.compact().map_err(Error::GraphLite)
+    }
+
     /// Get access to the underlying QueryCoordinator
     ///
     /// Provides direct access to the low-level API when needed for
@@ -126,18 +593,26 @@ impl GraphLite {
 /// # Ok::<(), graphlite_sdk::Error>(())
 /// ```
 pub struct Session {
-    id: String,
+    /// Replaced with a fresh session ID when auto-reconnect recreates the session
+    id: RwLock<String>,
     coordinator: Arc<QueryCoordinator>,
     username: String,
+    auto_reconnect: AtomicBool,
+    /// Pagination cursors this session has issued
+    cursors: CursorStore,
 }
 
 impl Session {
     /// Get the session ID
     ///
     /// The session ID is used internally for query execution and
-    /// transaction management.
-    pub fn id(&self) -> &str {
-        &self.id
+    /// transaction management. It changes when auto-reconnect replaces an
+    /// invalidated session.
+    pub fn id(&self) -> String {
+        self.id
+            .read()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .clone()
     }
 
     /// Get the username associated with this session
@@ -145,6 +620,84 @@ impl Session {
         &self.username
     }
 
+    /// Check whether the session is still valid on the database
+    ///
+    /// Returns `false` once the session has been closed, invalidated or has
+    /// expired, after which queries fail with [`Error::Session`] unless
+    /// auto-reconnect is enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use graphlite_sdk::GraphLite;
+    /// # let db = GraphLite::open("./mydb")?;
+    /// let session = db.session("admin")?;
+    /// assert!(session.ping());
+    /// # Ok::<(), graphlite_sdk::Error>(())
+    /// ```
+    pub fn ping(&self) -> bool {
+        self.coordinator.is_session_valid(&self.id())
+    }
+
+    /// Recreate the session when a query finds it no longer exists
+    ///
+    /// When enabled, [`Session::query`] and [`Session::execute`] that fail
+    /// because the session was invalidated open a fresh session for the same
+    /// user and run the statement again. The fresh session starts without a
+    /// current schema or graph and without an open transaction. Disabled by
+    /// default.
+    pub fn set_auto_reconnect(&self, enabled: bool) {
+        self.auto_reconnect.store(enabled, Ordering::Relaxed);
+    }
+
+    /// Run `run` with the session ID, recreating the session and retrying
+    /// once if auto-reconnect is on and the session no longer exists
+    fn with_reconnect<T>(
+        &self,
+        run: impl Fn(&str) -> std::result::Result<T, QueryError>,
+    ) -> std::result::Result<T, QueryError> {
+        let id = self.id();
+        match run(&id) {
+            Err(e)
+                if *e.kind() == QueryErrorKind::SessionNotFound
+                    && self.auto_reconnect.load(Ordering::Relaxed) =>
+            {
+                let fresh = self.coordinator.create_simple_session(&self.username)?;
+                *self
+                    .id
+                    .write()
+                    .unwrap_or_else(|poisoned| poisoned.into_inner()) = fresh.clone();
+                run(&fresh)
+            }
+            result => result,
+        }
+    }
+
+    /// Get the full path of the graph this session is using
+    ///
+    /// Returns `None` until a graph is chosen with `SESSION SET GRAPH`.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use graphlite_sdk::GraphLite;
+    /// # let db = GraphLite::open("./mydb")?;
+    /// let session = db.session("admin")?;
+    /// session.execute("SESSION SET GRAPH /social/people")?;
+    /// assert_eq!(session.current_graph().as_deref(), Some("/social/people"));
+    /// # Ok::<(), graphlite_sdk::Error>(())
+    /// ```
+    pub fn current_graph(&self) -> Option<String> {
+        self.coordinator.current_graph(&self.id()).ok().flatten()
+    }
+
+    /// Get the path of the schema this session is using
+    ///
+    /// Returns `None` until a schema is chosen with `SESSION SET SCHEMA`.
+    pub fn current_schema(&self) -> Option<String> {
+        self.coordinator.current_schema(&self.id()).ok().flatten()
+    }
+
     /// Execute a GQL query in this session
     ///
     /// This is the main method for executing queries. For simple read queries,
@@ -170,46 +723,146 @@ impl Session {
     /// # Ok::<(), graphlite_sdk::Error>(())
     /// ```
     pub fn query(&self, query: &str) -> Result<QueryResult> {
-        self.coordinator
-            .process_query(query, &self.id)
-            .map_err(|e| Error::Query(format!("Query failed: {}", e)))
+        self.with_reconnect(|id| self.coordinator.process_query(query, id))
+            .map_err(|e| {
+                Error::from_query(
+                    &self.coordinator,
+                    query,
+                    e.kind(),
+                    format!("Query failed: {}", e),
+                    Error::Query,
+                )
+            })
     }
 
-    /// Execute a statement without returning results
-    ///
-    /// This is useful for DDL statements (CREATE SCHEMA, CREATE GRAPH, etc.)
-    /// and DML statements where you don't need the results.
-    ///
-    /// # Arguments
+    /// Execute an untrusted query within resource limits
     ///
-    /// * `statement` - GQL statement to execute
+    /// The query fails with an error naming the first limit it hits:
+    /// [`Error::ReadOnlyViolation`] for a write under a read-only limit,
+    /// [`Error::MemoryLimitExceeded`], [`Error::QueryTimedOut`], or
+    /// [`Error::ResultTooLarge`] for more rows than the row limit. Memory and
+    /// time are checked as the query's scans and expansions produce rows, so
+    /// a runaway query stops early.
     ///
     /// # Examples
     ///
     /// ```no_run
-    /// # use graphlite_sdk::GraphLite;
+    /// # use graphlite_sdk::{GraphLite, QueryLimits};
+    /// # use std::time::Duration;
     /// # let db = GraphLite::open("./mydb")?;
     /// let session = db.session("admin")?;
     ///
-    /// // Create a node
-    /// session.execute("CREATE (p:Person {name: 'Alice', age: 30})")?;
-    ///
-    /// // Create a schema
-    /// session.execute("CREATE SCHEMA my_schema")?;
+    /// let limits = QueryLimits::new()
+    ///     .max_memory_bytes(16 * 1024 * 1024)
+    ///     .max_rows(1000)
+    ///     .timeout(Duration::from_secs(1))
+    ///     .read_only(true);
+    /// let result = session.execute_sandboxed("MATCH (p:Person) RETURN p.name", &limits)?;
     /// # Ok::<(), graphlite_sdk::Error>(())
     /// ```
-    pub fn execute(&self, statement: &str) -> Result<()> {
-        self.coordinator
-            .process_query(statement, &self.id)
-            .map_err(|e| Error::Query(format!("Execute failed: {}", e)))?;
+    pub fn execute_sandboxed(&self, query: &str, limits: &QueryLimits) -> Result<QueryResult> {
+        self.with_reconnect(|id| {
+            self.coordinator
+                .process_query_with_limits(query, id, limits)
+        })
+        .map_err(|e| {
+            Error::from_query(
+                &self.coordinator,
+                query,
+                e.kind(),
+                format!("Query failed: {}", e),
+                Error::Query,
+            )
+        })
+    }
+
+    /// Execute a query with values bound to its parameters
+    ///
+    /// The query refers to each value as `$name`. The bindings last for this
+    /// query only; parameters set on the session keep their values.
+    ///
+    /// # Arguments
+    ///
+    /// * `query` - GQL query string
+    /// * `parameters` - Parameter values by name
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use graphlite_sdk::{GraphLite, Value};
+    /// # use std::collections::HashMap;
+    /// # let db = GraphLite::open("./mydb")?;
+    /// let session = db.session("admin")?;
+    /// let parameters = HashMap::from([("name".to_string(), Value::from("Alice"))]);
+    /// let result = session.query_with_parameters(
+    ///     "MATCH (n:Person) WHERE n.name = $name RETURN n",
+    ///     &parameters,
+    /// )?;
+    /// # Ok::<(), graphlite_sdk::Error>(())
+    /// ```
+    pub fn query_with_parameters(
+        &self,
+        query: &str,
+        parameters: &HashMap<String, Value>,
+    ) -> Result<QueryResult> {
+        self.with_reconnect(|id| {
+            self.coordinator
+                .execute_many(query, id, std::slice::from_ref(parameters))
+        })
+        .map(|mut results| results.remove(0))
+        .map_err(|e| {
+            Error::from_query(
+                &self.coordinator,
+                query,
+                e.kind(),
+                format!("Query failed: {}", e),
+                Error::Query,
+            )
+        })
+    }
+
+    /// Execute a statement without returning results
+    ///
+    /// This is useful for DDL statements (CREATE SCHEMA, CREATE GRAPH, etc.)
+    /// and DML statements where you don't need the results.
+    ///
+    /// # Arguments
+    ///
+    /// * `statement` - GQL statement to execute
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use graphlite_sdk::GraphLite;
+    /// # let db = GraphLite::open("./mydb")?;
+    /// let session = db.session("admin")?;
+    ///
+    /// // Create a node
+    /// session.execute("CREATE (p:Person {name: 'Alice', age: 30})")?;
+    ///
+    /// // Create a schema
+    /// session.execute("CREATE SCHEMA my_schema")?;
+    /// # Ok::<(), graphlite_sdk::Error>(())
+    /// ```
+    pub fn execute(&self, statement: &str) -> Result<()> {
+        self.with_reconnect(|id| self.coordinator.process_query(statement, id))
+            .map_err(|e| {
+                Error::from_query(
+                    &self.coordinator,
+                    statement,
+                    e.kind(),
+                    format!("Execute failed: {}", e),
+                    Error::Query,
+                )
+            })?;
         Ok(())
     }
 
-    /// Begin a new transaction
+    /// Plan a query without running it and report each operator's estimates
     ///
-    /// Transactions provide ACID guarantees and can be committed or rolled back.
-    /// Following the rusqlite pattern, transactions will automatically roll back
-    /// when dropped unless explicitly committed.
+    /// Every operator in the plan carries the planner's estimated output rows
+    /// and the cost model's estimated cost, which includes the cost of its
+    /// inputs. Only MATCH and SELECT queries can be explained.
     ///
     /// # Examples
     ///
@@ -218,35 +871,2445 @@ impl Session {
     /// # let db = GraphLite::open("./mydb")?;
     /// let session = db.session("admin")?;
     ///
-    /// // Transaction with explicit commit
-    /// let mut tx = session.transaction()?;
-    /// tx.execute("CREATE (p:Person {name: 'Alice'})")?;
-    /// tx.execute("CREATE (p:Person {name: 'Bob'})")?;
-    /// tx.commit()?;
+    /// let plan = session.explain("MATCH (p:Person) WHERE p.age > 30 RETURN p.name")?;
+    /// println!("{}", plan.format_tree());
+    /// # Ok::<(), graphlite_sdk::Error>(())
+    /// ```
+    pub fn explain(&self, query: &str) -> Result<QueryPlan> {
+        self.coordinator
+            .explain_query_in_session(query, &self.id())
+            .map_err(|e| {
+                Error::from_query(
+                    &self.coordinator,
+                    query,
+                    e.kind(),
+                    format!("Explain failed: {}", e),
+                    Error::Query,
+                )
+            })
+    }
+
+    /// Execute a query and report the measured time and row count of each operator
     ///
-    /// // Transaction that auto-rolls back (dropped without commit)
-    /// {
-    ///     let mut tx = session.transaction()?;
-    ///     tx.execute("CREATE (p:Person {name: 'Charlie'})")?;
-    ///     // tx is dropped here, changes are rolled back
+    /// The query runs for real, with the same side effects as [`Session::query`].
+    /// Compare each operator's actual rows and time against the planner's
+    /// estimates to find which one dominates.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use graphlite_sdk::GraphLite;
+    /// # let db = GraphLite::open("./mydb")?;
+    /// let session = db.session("admin")?;
+    ///
+    /// let analyzed = session.explain_analyze("MATCH (p:Person) WHERE p.age > 30 RETURN p.name")?;
+    /// println!("{}", analyzed.format_tree());
+    /// # Ok::<(), graphlite_sdk::Error>(())
+    /// ```
+    pub fn explain_analyze(&self, query: &str) -> Result<AnalyzedQueryPlan> {
+        self.coordinator
+            .explain_analyze(query, &self.id())
+            .map_err(|e| {
+                Error::from_query(
+                    &self.coordinator,
+                    query,
+                    e.kind(),
+                    format!("Explain analyze failed: {}", e),
+                    Error::Query,
+                )
+            })
+    }
+
+    /// Report how many nodes and edges a write statement would change, without changing them
+    ///
+    /// Supports `MATCH ... DELETE`, `MATCH ... SET` and `MATCH ... REMOVE`.
+    /// The MATCH and WHERE run as a read query; the write is skipped.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use graphlite_sdk::GraphLite;
+    /// # let db = GraphLite::open("./mydb")?;
+    /// let session = db.session("admin")?;
+    ///
+    /// let report = session.execute_dry_run("MATCH (n:Temp) DELETE n")?;
+    /// if report.affected < 1_000 {
+    ///     session.execute("MATCH (n:Temp) DELETE n")?;
     /// }
     /// # Ok::<(), graphlite_sdk::Error>(())
     /// ```
-    pub fn transaction(&self) -> Result<Transaction<'_>> {
-        Transaction::begin(self)
+    pub fn execute_dry_run(&self, statement: &str) -> Result<DryRunReport> {
+        self.coordinator
+            .dry_run(statement, &self.id())
+            .map_err(|e| {
+                Error::from_query(
+                    &self.coordinator,
+                    statement,
+                    e.kind(),
+                    format!("Dry run failed: {}", e),
+                    Error::Query,
+                )
+            })
     }
 
-    /// Get the internal coordinator (for internal SDK use)
-    pub(crate) fn coordinator(&self) -> &QueryCoordinator {
-        &self.coordinator
+    /// Fail queries that return more than `max_rows` rows
+    ///
+    /// A query over the limit returns [`Error::ResultTooLarge`] instead of its
+    /// rows. This is a safety rail against accidentally huge results, not a
+    /// replacement for LIMIT. Pass `None` to remove the limit (the default).
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use graphlite_sdk::GraphLite;
+    /// # let db = GraphLite::open("./mydb")?;
+    /// let session = db.session("admin")?;
+    /// session.set_max_result_rows(Some(10_000))?;
+    /// # Ok::<(), graphlite_sdk::Error>(())
+    /// ```
+    pub fn set_max_result_rows(&self, max_rows: Option<usize>) -> Result<()> {
+        self.coordinator
+            .set_max_result_rows(&self.id(), max_rows)
+            .map_err(Error::Session)
     }
-}
 
-#[cfg(test)]
-mod tests {
+    /// Keep INSERTed nodes that are identical to an existing node
+    ///
+    /// Nodes are identified by their labels and properties, so by default a
+    /// second `INSERT (:Event {type: 'x'})` is skipped with a duplicate
+    /// warning. With this enabled, each insert stores a distinct node. Off by
+    /// default; ON CONFLICT clauses behave the same either way.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use graphlite_sdk::GraphLite;
+    /// # let db = GraphLite::open("./mydb")?;
+    /// let session = db.session("admin")?;
+    /// session.set_allow_duplicate_nodes(true)?;
+    /// session.execute("INSERT (:Event {type: 'click'})")?;
+    /// session.execute("INSERT (:Event {type: 'click'})")?; // a second node
+    /// # Ok::<(), graphlite_sdk::Error>(())
+    /// ```
+    pub fn set_allow_duplicate_nodes(&self, enabled: bool) -> Result<()> {
+        self.coordinator
+            .set_allow_duplicate_nodes(&self.id(), enabled)
+            .map_err(Error::Session)
+    }
 
-    #[test]
-    fn test_connection_types_compile() {
-        // Compilation test - ensures types are properly defined
+    /// Return rows in a reproducible order for queries without ORDER BY
+    ///
+    /// Nodes are scanned, and groups emitted, in ID order, so running the same
+    /// query over the same data gives the same row order, even after the
+    /// database is reopened. Useful for tests and debugging; off by default.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use graphlite_sdk::GraphLite;
+    /// # let db = GraphLite::open("./mydb")?;
+    /// let session = db.session("admin")?;
+    /// session.set_deterministic_order(true)?;
+    /// let result = session.query("MATCH (p:Person) RETURN p.name")?;
+    /// # Ok::<(), graphlite_sdk::Error>(())
+    /// ```
+    pub fn set_deterministic_order(&self, enabled: bool) -> Result<()> {
+        self.coordinator
+            .set_deterministic_order(&self.id(), enabled)
+            .map_err(Error::Session)
+    }
+
+    /// Turn auto-commit on (the default) or off
+    ///
+    /// With auto-commit off, writes accumulate in one implicit transaction
+    /// until [`Session::commit`] or [`Session::rollback`], as in JDBC. A
+    /// failed statement leaves the transaction open unless
+    /// [`Session::set_rollback_on_error`] is enabled. Turning auto-commit back
+    /// on commits the open transaction.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use graphlite_sdk::GraphLite;
+    /// # let db = GraphLite::open("./mydb")?;
+    /// let session = db.session("admin")?;
+    /// session.set_autocommit(false)?;
+    /// session.execute("INSERT (:Person {name: 'Alice'})")?;
+    /// session.execute("INSERT (:Person {name: 'Bob'})")?;
+    /// session.commit()?;
+    /// # Ok::<(), graphlite_sdk::Error>(())
+    /// ```
+    pub fn set_autocommit(&self, enabled: bool) -> Result<()> {
+        self.coordinator
+            .set_autocommit(&self.id(), enabled)
+            .map_err(Error::Transaction)
+    }
+
+    /// Roll back the whole open transaction when a statement fails
+    ///
+    /// Off by default, so a failed statement can be corrected and retried
+    /// without losing the earlier statements of the transaction.
+    pub fn set_rollback_on_error(&self, enabled: bool) -> Result<()> {
+        self.coordinator
+            .set_rollback_on_error(&self.id(), enabled)
+            .map_err(Error::Transaction)
+    }
+
+    /// Commit the session's open transaction
+    ///
+    /// Used with auto-commit off; fails if no transaction is open.
+    pub fn commit(&self) -> Result<()> {
+        self.coordinator
+            .process_query("COMMIT", &self.id())
+            .map_err(|e| {
+                Error::from_engine(
+                    e.kind(),
+                    format!("Failed to commit: {}", e),
+                    Error::Transaction,
+                )
+            })?;
+        Ok(())
+    }
+
+    /// Roll back the session's open transaction
+    ///
+    /// Used with auto-commit off; fails if no transaction is open.
+    pub fn rollback(&self) -> Result<()> {
+        self.coordinator
+            .process_query("ROLLBACK", &self.id())
+            .map_err(|e| {
+                Error::from_engine(
+                    e.kind(),
+                    format!("Failed to rollback: {}", e),
+                    Error::Transaction,
+                )
+            })?;
+        Ok(())
+    }
+
+    /// Execute a query and write its rows to `writer` as CSV or JSON
+    ///
+    /// Rows are formatted and written one at a time, and each row is released
+    /// once written, so exporting a huge result never holds a second,
+    /// formatted copy of it in memory. The writer is buffered internally and
+    /// flushed before returning. Returns the number of rows written.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use graphlite_sdk::{GraphLite, OutputFormat};
+    /// # let db = GraphLite::open("./mydb")?;
+    /// let session = db.session("admin")?;
+    ///
+    /// let mut file = std::fs::File::create("people.csv")?;
+    /// session.query_to_writer(
+    ///     "MATCH (p:Person) RETURN p.name AS name, p.age AS age",
+    ///     &mut file,
+    ///     OutputFormat::Csv,
+    /// )?;
+    /// # Ok::<(), graphlite_sdk::Error>(())
+    /// ```
+    pub fn query_to_writer<W: std::io::Write>(
+        &self,
+        query: &str,
+        writer: &mut W,
+        format: OutputFormat,
+    ) -> Result<usize> {
+        let result = self.query(query)?;
+        export::write_result(result, writer, format)
+    }
+
+    /// Execute a query, calling `on_progress` with the rows produced so far
+    ///
+    /// The callback runs while the query executes: after every 1000 rows the
+    /// query's scans and expansions produce, or every 100 ms if rows arrive
+    /// more slowly, with a strictly increasing count. The count includes
+    /// rows later removed by filters or limits, so it measures work done
+    /// rather than rows returned. Useful for driving a progress bar.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use graphlite_sdk::GraphLite;
+    /// # let db = GraphLite::open("./mydb")?;
+    /// let session = db.session("admin")?;
+    ///
+    /// let result = session.query_with_progress("MATCH (p:Person) RETURN p.name", |rows| {
+    ///     eprint!("\r{} rows scanned", rows);
+    /// })?;
+    /// # Ok::<(), graphlite_sdk::Error>(())
+    /// ```
+    pub fn query_with_progress<F>(&self, query: &str, on_progress: F) -> Result<QueryResult>
+    where
+        F: FnMut(usize) + Send + 'static,
+    {
+        self.coordinator
+            .process_query_with_progress(
+                query,
+                &self.id(),
+                PROGRESS_EVERY_ROWS,
+                PROGRESS_EVERY,
+                on_progress,
+            )
+            .map_err(|e| {
+                Error::from_query(
+                    &self.coordinator,
+                    query,
+                    e.kind(),
+                    format!("Query failed: {}", e),
+                    Error::Query,
+                )
+            })
+    }
+
+    /// Execute a read-only query and return its first page of rows
+    ///
+    /// The returned page carries an opaque `next_cursor` while more rows
+    /// remain; pass it to [`Session::query_resume`] on this session to fetch
+    /// the next page. Each page runs the query with the page pushed into it
+    /// as SKIP and LIMIT, so paginate queries with an ORDER BY on a unique
+    /// key when the page boundaries must be exact.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use graphlite_sdk::GraphLite;
+    /// # let db = GraphLite::open("./mydb")?;
+    /// let session = db.session("admin")?;
+    ///
+    /// let mut page = session.query_paged("MATCH (p:Person) RETURN p.name ORDER BY p.name", 100)?;
+    /// loop {
+    ///     for row in &page.rows {
+    ///         println!("{:?}", row.get_value("p.name"));
+    ///     }
+    ///     match page.next_cursor {
+    ///         Some(cursor) => page = session.query_resume(&cursor)?,
+    ///         None => break,
+    ///     }
+    /// }
+    /// # Ok::<(), graphlite_sdk::Error>(())
+    /// ```
+    pub fn query_paged(&self, query: &str, page_size: usize) -> Result<ResultPage> {
+        if page_size == 0 {
+            return Err(Error::InvalidOperation(
+                "Page size must be at least 1".to_string(),
+            ));
+        }
+        self.fetch_page(query, page_size, 0)
+    }
+
+    /// Fetch the page a cursor from [`Session::query_paged`] points at
+    ///
+    /// Fails for cursors this session did not issue. Once the page after a
+    /// cursor has been read, the new page's cursor replaces it.
+    pub fn query_resume(&self, cursor: &str) -> Result<ResultPage> {
+        let position = self.cursors.get(cursor)?;
+        let page = self.fetch_page(&position.query, position.page_size, position.offset)?;
+        self.cursors.remove(cursor);
+        Ok(page)
+    }
+
+    fn fetch_page(&self, query: &str, page_size: usize, offset: usize) -> Result<ResultPage> {
+        let info = self
+            .coordinator
+            .analyze_query(query)
+            .map_err(|e| Error::Query(format!("Query failed: {}", e)))?;
+        if !info.is_read_only {
+            return Err(Error::InvalidOperation(
+                "Only read-only queries can be paginated".to_string(),
+            ));
+        }
+
+        // One row past the page tells whether another page follows
+        let mut result = self
+            .with_reconnect(|id| {
+                self.coordinator
+                    .process_query_page(query, id, offset, page_size.saturating_add(1))
+            })
+            .map_err(|e| {
+                Error::from_query(
+                    &self.coordinator,
+                    query,
+                    e.kind(),
+                    format!("Query failed: {}", e),
+                    Error::Query,
+                )
+            })?;
+        let has_more = result.rows.len() > page_size;
+        result.rows.truncate(page_size);
+        let next_cursor = has_more.then(|| {
+            self.cursors.issue(Cursor {
+                query: query.to_string(),
+                page_size,
+                offset: offset + page_size,
+            })
+        });
+
+        Ok(ResultPage {
+            rows: result.rows,
+            variables: result.variables,
+            next_cursor,
+        })
+    }
+
+    /// Match the nodes with a label whose properties equal the filters
+    ///
+    /// For labels and property keys chosen at runtime. Both must be plain
+    /// identifiers (a letter or `_`, then letters, digits or `_`), so no
+    /// input can change the shape of the query; anything else fails with
+    /// [`Error::Validation`] before a query is built. Filter values are
+    /// bound as parameters. Each row holds one matching node as `n`.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use graphlite_sdk::{GraphLite, Value};
+    /// # use std::collections::HashMap;
+    /// # let db = GraphLite::open("./mydb")?;
+    /// let session = db.session("admin")?;
+    /// let label = "Person".to_string();
+    /// let filters = HashMap::from([("city".to_string(), Value::from("Berlin"))]);
+    /// let result = session.match_by_label(&label, &filters)?;
+    /// # Ok::<(), graphlite_sdk::Error>(())
+    /// ```
+    pub fn match_by_label(
+        &self,
+        label: &str,
+        filters: &HashMap<String, Value>,
+    ) -> Result<QueryResult> {
+        validate_identifier("label", label)?;
+        let mut keys: Vec<&String> = filters.keys().collect();
+        keys.sort();
+        for key in &keys {
+            validate_identifier("property key", key)?;
+        }
+
+        // Backticks keep a label that is also a keyword from being parsed as one
+        let mut query = format!("MATCH (n:`{}`)", label);
+        let mut parameters = HashMap::with_capacity(keys.len());
+        for (i, key) in keys.iter().enumerate() {
+            query.push_str(if i == 0 { " WHERE " } else { " AND " });
+            query.push_str(&format!("n.{} = $filter_{}", key, i));
+            parameters.insert(format!("filter_{}", i), filters[*key].clone());
+        }
+        query.push_str(" RETURN n");
+
+        self.query_with_parameters(&query, &parameters)
+    }
+
+    /// Check whether a node with a label has a property equal to `value`
+    ///
+    /// The value is bound as a parameter, so any string can be looked up. A
+    /// null value never matches.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use graphlite_sdk::{GraphLite, Value};
+    /// # let db = GraphLite::open("./mydb")?;
+    /// let session = db.session("admin")?;
+    /// let email = Value::String("alice@example.com".to_string());
+    /// if !session.node_exists("Person", "email", &email)? {
+    ///     session.execute("INSERT (:Person {email: 'alice@example.com'})")?;
+    /// }
+    /// # Ok::<(), graphlite_sdk::Error>(())
+    /// ```
+    pub fn node_exists(&self, label: &str, key: &str, value: &Value) -> Result<bool> {
+        let pattern = format!("(n:{})", quote_identifier(label));
+        self.exists_probe(&pattern, "n", key, value)
+    }
+
+    /// Check whether an edge with a label has a property equal to `value`
+    ///
+    /// Like [`Session::node_exists`], the value is bound as a parameter.
+    pub fn edge_exists(&self, label: &str, key: &str, value: &Value) -> Result<bool> {
+        let pattern = format!("()-[e:{}]->()", quote_identifier(label));
+        self.exists_probe(&pattern, "e", key, value)
+    }
+
+    fn exists_probe(
+        &self,
+        pattern: &str,
+        variable: &str,
+        key: &str,
+        value: &Value,
+    ) -> Result<bool> {
+        // NULL never equals a stored property
+        if matches!(value, Value::Null) {
+            return Ok(false);
+        }
+        let property = format!("{}.{}", variable, quote_identifier(key));
+        let query = format!(
+            "MATCH {} WHERE {} = $value RETURN {} LIMIT 1",
+            pattern, property, property
+        );
+        let parameters = HashMap::from([("value".to_string(), value.clone())]);
+        Ok(!self
+            .query_with_parameters(&query, &parameters)?
+            .rows
+            .is_empty())
+    }
+
+    /// Create one edge per pair of endpoints, all in a single transaction
+    ///
+    /// Each endpoint is looked up by its label and key property; key values
+    /// and edge properties are bound as parameters. Every edge gets the same
+    /// label and properties. A pair whose endpoint does not exist is recorded in the
+    /// report's `failed` list and skipped; with `fail_fast` it instead rolls
+    /// back the whole batch and returns an error. A key that matches several
+    /// nodes creates an edge for each of them.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use graphlite_sdk::{GraphLite, NodeKey};
+    /// # use std::collections::HashMap;
+    /// # let db = GraphLite::open("./mydb")?;
+    /// let session = db.session("admin")?;
+    /// let pairs = vec![
+    ///     (NodeKey::new("Person", "name", "Alice"), NodeKey::new("Person", "name", "Bob")),
+    ///     (NodeKey::new("Person", "name", "Bob"), NodeKey::new("Person", "name", "Carol")),
+    /// ];
+    /// let report = session.create_edges("KNOWS", &pairs, &HashMap::new(), false)?;
+    /// println!("created {}, failed {}", report.created, report.failed.len());
+    /// # Ok::<(), graphlite_sdk::Error>(())
+    /// ```
+    pub fn create_edges(
+        &self,
+        label: &str,
+        pairs: &[(NodeKey, NodeKey)],
+        properties: &HashMap<String, Value>,
+        fail_fast: bool,
+    ) -> Result<EdgeBatchReport> {
+        let mut property_names: Vec<&String> = properties.keys().collect();
+        property_names.sort();
+        let edge_parameters: HashMap<String, Value> = property_names
+            .iter()
+            .enumerate()
+            .map(|(i, name)| (format!("edge_property_{}", i), properties[*name].clone()))
+            .collect();
+        let property_map = property_names
+            .iter()
+            .enumerate()
+            .map(|(i, name)| format!("{}: $edge_property_{}", quote_identifier(name), i))
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        let mut report = EdgeBatchReport::default();
+        let mut tx = self.transaction()?;
+        for (index, (from, to)) in pairs.iter().enumerate() {
+            let statement = format!(
+                "MATCH (a:{}), (b:{}) WHERE a.{} = $from_key AND b.{} = $to_key \
+                 INSERT (a)-[:{} {{{}}}]->(b)",
+                quote_identifier(&from.label),
+                quote_identifier(&to.label),
+                quote_identifier(&from.key),
+                quote_identifier(&to.key),
+                quote_identifier(label),
+                property_map
+            );
+            let mut parameters = edge_parameters.clone();
+            parameters.insert("from_key".to_string(), from.value.clone());
+            parameters.insert("to_key".to_string(), to.value.clone());
+            let batch = tx.execute_many(&statement, std::slice::from_ref(&parameters))?;
+            if batch.rows_affected > 0 {
+                report.created += batch.rows_affected;
+                continue;
+            }
+
+            let mut missing = Vec::new();
+            for endpoint in [from, to] {
+                if !self.node_exists(&endpoint.label, &endpoint.key, &endpoint.value)? {
+                    missing.push(endpoint.clone());
+                }
+            }
+            let failure = FailedEdge { index, missing };
+            if fail_fast {
+                return Err(Error::NotFound(format!(
+                    "Edge {} not created: {}",
+                    index,
+                    failure.describe()
+                )));
+            }
+            report.failed.push(failure);
+        }
+        tx.commit()?;
+        Ok(report)
+    }
+
+    /// Rename a property on every node with a label, in its own transaction
+    ///
+    /// Either every matching node is renamed or, if the statement fails, none
+    /// is. Use [`Transaction::rename_property`] to rename as part of a larger
+    /// transaction.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use graphlite_sdk::GraphLite;
+    /// # let db = GraphLite::open("./mydb")?;
+    /// let session = db.session("admin")?;
+    /// session.rename_property("Person", "name", "full_name")?;
+    /// # Ok::<(), graphlite_sdk::Error>(())
+    /// ```
+    pub fn rename_property(&self, label: &str, from: &str, to: &str) -> Result<()> {
+        let mut tx = self.transaction()?;
+        tx.rename_property(label, from, to)?;
+        tx.commit()
+    }
+
+    /// Change the label of every edge labelled `from` to `to`, in its own
+    /// transaction
+    ///
+    /// Either every matching edge is retyped or none is. Use
+    /// [`Transaction::rename_edge_type`] to retype as part of a larger
+    /// transaction.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use graphlite_sdk::GraphLite;
+    /// # let db = GraphLite::open("./mydb")?;
+    /// let session = db.session("admin")?;
+    /// session.rename_edge_type("KNOWS", "FRIEND_OF")?;
+    /// # Ok::<(), graphlite_sdk::Error>(())
+    /// ```
+    pub fn rename_edge_type(&self, from: &str, to: &str) -> Result<()> {
+        let mut tx = self.transaction()?;
+        tx.rename_edge_type(from, to)?;
+        tx.commit()
+    }
+
+    /// Begin a new transaction
+    ///
+    /// Transactions provide ACID guarantees and can be committed or rolled back.
+    /// Following the rusqlite pattern, transactions will automatically roll back
+    /// when dropped unless explicitly committed.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use graphlite_sdk::GraphLite;
+    /// # let db = GraphLite::open("./mydb")?;
+    /// let session = db.session("admin")?;
+    ///
+    /// // Transaction with explicit commit
+    /// let mut tx = session.transaction()?;
+    /// tx.execute("CREATE (p:Person {name: 'Alice'})")?;
+    /// tx.execute("CREATE (p:Person {name: 'Bob'})")?;
+    /// tx.commit()?;
+    ///
+    /// // Transaction that auto-rolls back (dropped without commit)
+    /// {
+    ///     let mut tx = session.transaction()?;
+    ///     tx.execute("CREATE (p:Person {name: 'Charlie'})")?;
+    ///     // tx is dropped here, changes are rolled back
+    /// }
+    /// # Ok::<(), graphlite_sdk::Error>(())
+    /// ```
+    pub fn transaction(&self) -> Result<Transaction<'_>> {
+        Transaction::begin(self)
+    }
+
+    /// Begin a new transaction tied to this session
+    ///
+    /// Same as [`Session::transaction`]. Statements run through the returned
+    /// transaction use this session's context, including its current graph.
+    /// The transaction ends with [`Transaction::commit`] or
+    /// [`Transaction::rollback`], and rolls back if dropped before either.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use graphlite_sdk::GraphLite;
+    /// # let db = GraphLite::open("./mydb")?;
+    /// let session = db.session("admin")?;
+    /// let mut tx = session.begin()?;
+    /// tx.execute("INSERT (:Person {name: 'Alice'})")?;
+    /// tx.commit()?;
+    /// # Ok::<(), graphlite_sdk::Error>(())
+    /// ```
+    pub fn begin(&self) -> Result<Transaction<'_>> {
+        Transaction::begin(self)
+    }
+
+    /// Get the internal coordinator (for internal SDK use)
+    pub(crate) fn coordinator(&self) -> &QueryCoordinator {
+        &self.coordinator
+    }
+}
+
+/// A node identified by its label and the value of a key property
+#[derive(Debug, Clone, PartialEq)]
+pub struct NodeKey {
+    pub label: String,
+    pub key: String,
+    pub value: Value,
+}
+
+impl NodeKey {
+    /// Identify the node with label `label` whose `key` property equals `value`
+    pub fn new(label: impl Into<String>, key: impl Into<String>, value: impl Into<Value>) -> Self {
+        Self {
+            label: label.into(),
+            key: key.into(),
+            value: value.into(),
+        }
+    }
+}
+
+impl fmt::Display for NodeKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({} {{{}: {}}})", self.label, self.key, self.value)
+    }
+}
+
+/// Outcome of [`Session::create_edges`]
+#[derive(Debug, Clone, Default)]
+pub struct EdgeBatchReport {
+    /// Edges created
+    pub created: usize,
+    /// Pairs that created no edge, in input order
+    pub failed: Vec<FailedEdge>,
+}
+
+/// A pair passed to [`Session::create_edges`] that created no edge
+#[derive(Debug, Clone)]
+pub struct FailedEdge {
+    /// Position of the pair in the input
+    pub index: usize,
+    /// Endpoints that were not found
+    pub missing: Vec<NodeKey>,
+}
+
+impl FailedEdge {
+    fn describe(&self) -> String {
+        if self.missing.is_empty() {
+            return "no edge was created".to_string();
+        }
+        let missing: Vec<String> = self.missing.iter().map(ToString::to_string).collect();
+        format!("missing endpoint {}", missing.join(", "))
+    }
+}
+
+/// Whether a name is a letter or '_' followed by letters, digits or '_'
+fn is_plain_identifier(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+/// Quote a label or property name with backticks unless it is a plain identifier
+pub(crate) fn quote_identifier(name: &str) -> String {
+    if is_plain_identifier(name) {
+        name.to_string()
+    } else {
+        format!("`{}`", name.replace('`', "``"))
+    }
+}
+
+/// Reject a name that is not a plain identifier
+fn validate_identifier(kind: &str, name: &str) -> Result<()> {
+    if is_plain_identifier(name) {
+        Ok(())
+    } else {
+        Err(Error::Validation(format!(
+            "Invalid {} {:?}: expected a letter or '_' followed by letters, digits or '_'",
+            kind, name
+        )))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::thread;
+
+    #[test]
+    fn test_connection_types_compile() {
+        // Compilation test - ensures types are properly defined
+    }
+
+    #[test]
+    fn test_open_in_memory() {
+        let cwd_before: Vec<_> = std::fs::read_dir(".")
+            .unwrap()
+            .map(|entry| entry.unwrap().path())
+            .collect();
+
+        let db = GraphLite::open_in_memory().unwrap();
+        let session = db.session("admin").unwrap();
+        session
+            .execute("CREATE SCHEMA IF NOT EXISTS /memory_schema")
+            .unwrap();
+        session
+            .execute("CREATE GRAPH IF NOT EXISTS /memory_schema/memory_graph")
+            .unwrap();
+        session
+            .execute("SESSION SET GRAPH /memory_schema/memory_graph")
+            .unwrap();
+        session
+            .execute("INSERT (:Person {name: 'Alice'})-[:KNOWS]->(:Person {name: 'Bob'})")
+            .unwrap();
+
+        let result = session
+            .query("MATCH (a:Person)-[:KNOWS]->(b:Person) RETURN a.name, b.name")
+            .unwrap();
+        assert_eq!(result.rows.len(), 1);
+        assert_eq!(
+            result.rows[0].values.get("a.name"),
+            Some(&Value::String("Alice".to_string()))
+        );
+        assert_eq!(
+            result.rows[0].values.get("b.name"),
+            Some(&Value::String("Bob".to_string()))
+        );
+
+        // A second in-memory database starts out empty
+        let other = GraphLite::open_in_memory().unwrap();
+        assert!(!Arc::ptr_eq(&db.coordinator, &other.coordinator));
+        let other_session = other.session("admin").unwrap();
+        assert!(other_session
+            .execute("SESSION SET GRAPH /memory_schema/memory_graph")
+            .is_err());
+
+        let cwd_after: Vec<_> = std::fs::read_dir(".")
+            .unwrap()
+            .map(|entry| entry.unwrap().path())
+            .collect();
+        assert_eq!(cwd_before.len(), cwd_after.len());
+    }
+
+    #[test]
+    fn test_current_graph_and_schema() {
+        let db = GraphLite::open_in_memory().unwrap();
+        let session = db.session("admin").unwrap();
+        assert_eq!(session.current_graph(), None);
+        assert_eq!(session.current_schema(), None);
+
+        session
+            .execute("CREATE SCHEMA IF NOT EXISTS /context_schema")
+            .unwrap();
+        session
+            .execute("CREATE GRAPH IF NOT EXISTS /context_schema/context_graph")
+            .unwrap();
+        session
+            .execute("SESSION SET SCHEMA /context_schema")
+            .unwrap();
+        session
+            .execute("SESSION SET GRAPH /context_schema/context_graph")
+            .unwrap();
+        assert_eq!(
+            session.current_graph().as_deref(),
+            Some("/context_schema/context_graph")
+        );
+        assert_eq!(session.current_schema().as_deref(), Some("/context_schema"));
+
+        // Context is per session
+        let fresh = db.session("admin").unwrap();
+        assert_eq!(fresh.current_graph(), None);
+        assert_eq!(fresh.current_schema(), None);
+    }
+
+    #[test]
+    fn test_ping_and_auto_reconnect() {
+        let db = GraphLite::open_in_memory().unwrap();
+        let session = db.session("admin").unwrap();
+        assert!(session.ping());
+
+        // Without auto-reconnect an invalidated session stays unusable
+        db.coordinator().close_session(&session.id()).unwrap();
+        assert!(!session.ping());
+        let err = session
+            .execute("CREATE SCHEMA IF NOT EXISTS /reconnect")
+            .unwrap_err();
+        assert!(matches!(err, Error::Session(_)), "{:?}", err);
+
+        // With it the next query runs on a fresh session
+        let stale_id = session.id();
+        session.set_auto_reconnect(true);
+        session
+            .execute("CREATE SCHEMA IF NOT EXISTS /reconnect")
+            .unwrap();
+        assert_ne!(session.id(), stale_id);
+        assert!(session.ping());
+        assert_eq!(session.username(), "admin");
+    }
+
+    #[test]
+    fn test_rename_property() {
+        let db = GraphLite::open_in_memory().unwrap();
+        let session = db.session("admin").unwrap();
+        session
+            .execute("CREATE SCHEMA IF NOT EXISTS /rename_schema")
+            .unwrap();
+        session
+            .execute("CREATE GRAPH IF NOT EXISTS /rename_schema/rename_graph")
+            .unwrap();
+        session
+            .execute("SESSION SET GRAPH /rename_schema/rename_graph")
+            .unwrap();
+        session
+            .execute("INSERT (:Person {name: 'Alice', age: 30}), (:Person {name: 'Bob', age: 25})")
+            .unwrap();
+
+        let names = |column: &str| -> Vec<Value> {
+            let result = session
+                .query(&format!(
+                    "MATCH (p:Person) RETURN p.age, p.{} ORDER BY p.age",
+                    column
+                ))
+                .unwrap();
+            result
+                .rows
+                .iter()
+                .map(|row| {
+                    row.values
+                        .get(&format!("p.{}", column))
+                        .cloned()
+                        .unwrap_or(Value::Null)
+                })
+                .collect()
+        };
+        let originals = vec![
+            Value::String("Bob".to_string()),
+            Value::String("Alice".to_string()),
+        ];
+
+        // Rolling back the transaction restores the original property
+        {
+            let mut tx = session.transaction().unwrap();
+            tx.rename_property("Person", "name", "full_name").unwrap();
+            tx.rollback().unwrap();
+        }
+        assert_eq!(names("name"), originals);
+        assert_eq!(names("full_name"), vec![Value::Null, Value::Null]);
+
+        session
+            .rename_property("Person", "name", "full_name")
+            .unwrap();
+        assert_eq!(names("full_name"), originals);
+        assert_eq!(names("name"), vec![Value::Null, Value::Null]);
+    }
+
+    #[test]
+    fn test_match_by_label_validates_names() {
+        let db = GraphLite::open_in_memory().unwrap();
+        let session = db.session("admin").unwrap();
+        session
+            .execute("CREATE SCHEMA IF NOT EXISTS /label_schema")
+            .unwrap();
+        session
+            .execute("CREATE GRAPH IF NOT EXISTS /label_schema/label_graph")
+            .unwrap();
+        session
+            .execute("SESSION SET GRAPH /label_schema/label_graph")
+            .unwrap();
+        session
+            .execute(
+                "INSERT (:Person {name: 'Alice', city: 'Berlin'}), \
+                 (:Person {name: 'Bob', city: 'Paris'}), (:City {name: 'Berlin'})",
+            )
+            .unwrap();
+
+        let no_filters = HashMap::new();
+        for label in [
+            "Per son",
+            "Person'",
+            "Person) DETACH DELETE (n",
+            "",
+            "1Person",
+        ] {
+            let err = session.match_by_label(label, &no_filters).unwrap_err();
+            assert!(matches!(err, Error::Validation(_)), "{:?}", err);
+        }
+        let bad_key = HashMap::from([("city' OR 1=1".to_string(), Value::from("x"))]);
+        let err = session.match_by_label("Person", &bad_key).unwrap_err();
+        assert!(matches!(err, Error::Validation(_)), "{:?}", err);
+
+        let label = String::from("Person");
+        assert_eq!(
+            session
+                .match_by_label(&label, &no_filters)
+                .unwrap()
+                .rows
+                .len(),
+            2
+        );
+
+        // A label that is also a keyword is still a label
+        assert!(session
+            .match_by_label("Order", &no_filters)
+            .unwrap()
+            .rows
+            .is_empty());
+
+        // A value is bound, not spliced, so quotes in it are just data
+        let filters = HashMap::from([("city".to_string(), Value::from("Berlin"))]);
+        let result = session.match_by_label(&label, &filters).unwrap();
+        assert_eq!(result.rows.len(), 1);
+        match result.rows[0].values.get("n") {
+            Some(Value::Node(node)) => assert_eq!(
+                node.properties.get("name"),
+                Some(&Value::String("Alice".to_string()))
+            ),
+            other => panic!("Expected a node, got {:?}", other),
+        }
+        let filters = HashMap::from([("city".to_string(), Value::from("Berlin' OR '1'='1"))]);
+        assert!(session
+            .match_by_label(&label, &filters)
+            .unwrap()
+            .rows
+            .is_empty());
+    }
+
+    #[test]
+    fn test_begin_rolls_back_on_drop() {
+        let db = GraphLite::open_in_memory().unwrap();
+        let session = db.session("admin").unwrap();
+        session
+            .execute("CREATE SCHEMA IF NOT EXISTS /begin_schema")
+            .unwrap();
+        session
+            .execute("CREATE GRAPH IF NOT EXISTS /begin_schema/begin_graph")
+            .unwrap();
+        session
+            .execute("SESSION SET GRAPH /begin_schema/begin_graph")
+            .unwrap();
+
+        let count = || {
+            session
+                .query("MATCH (p:Person) RETURN p.name")
+                .unwrap()
+                .rows
+                .len()
+        };
+
+        // Dropped without commit: the insert is rolled back
+        {
+            let mut tx = session.begin().unwrap();
+            tx.execute("INSERT (:Person {name: 'Alice'})").unwrap();
+        }
+        assert_eq!(count(), 0);
+
+        let mut tx = session.begin().unwrap();
+        tx.execute("INSERT (:Person {name: 'Bob'})").unwrap();
+        tx.commit().unwrap();
+        assert_eq!(count(), 1);
+    }
+
+    #[test]
+    fn test_rename_edge_type() {
+        let db = GraphLite::open_in_memory().unwrap();
+        let session = db.session("admin").unwrap();
+        session
+            .execute("CREATE SCHEMA IF NOT EXISTS /retype_schema")
+            .unwrap();
+        session
+            .execute("CREATE GRAPH IF NOT EXISTS /retype_schema/retype_graph")
+            .unwrap();
+        session
+            .execute("SESSION SET GRAPH /retype_schema/retype_graph")
+            .unwrap();
+        session
+            .execute(
+                "INSERT (a:Person {name: 'Alice'}), (b:Person {name: 'Bob'}), \
+                 (c:Person {name: 'Carol'}), \
+                 (a)-[:OLD_TYPE {since: 2020}]->(b), (b)-[:OLD_TYPE {since: 2021}]->(c)",
+            )
+            .unwrap();
+
+        let edges = |label: &str| -> Vec<Value> {
+            let result = session
+                .query(&format!(
+                    "MATCH (a)-[r:{}]->(b) RETURN a.name, r.since ORDER BY r.since",
+                    label
+                ))
+                .unwrap();
+            result
+                .rows
+                .iter()
+                .map(|row| row.values.get("a.name").cloned().unwrap_or(Value::Null))
+                .collect()
+        };
+        let sources = vec![
+            Value::String("Alice".to_string()),
+            Value::String("Bob".to_string()),
+        ];
+
+        // Rolling back the transaction restores the original type
+        {
+            let mut tx = session.transaction().unwrap();
+            tx.rename_edge_type("OLD_TYPE", "NEW_TYPE").unwrap();
+            tx.rollback().unwrap();
+        }
+        assert_eq!(edges("OLD_TYPE"), sources);
+        assert!(edges("NEW_TYPE").is_empty());
+
+        session.rename_edge_type("OLD_TYPE", "NEW_TYPE").unwrap();
+        assert_eq!(edges("NEW_TYPE"), sources);
+        assert!(edges("OLD_TYPE").is_empty());
+
+        // The old type is free again, so an edge of it can be inserted alongside
+        session
+            .execute(
+                "MATCH (a:Person {name: 'Alice'}), (b:Person {name: 'Bob'}) \
+                 INSERT (a)-[:OLD_TYPE {since: 2020}]->(b)",
+            )
+            .unwrap();
+        assert_eq!(edges("OLD_TYPE"), vec![Value::String("Alice".to_string())]);
+        assert_eq!(edges("NEW_TYPE"), sources);
+    }
+
+    #[test]
+    fn test_max_result_rows() {
+        let db = GraphLite::open_in_memory().unwrap();
+        let session = db.session("admin").unwrap();
+        session
+            .execute("CREATE SCHEMA IF NOT EXISTS /limit_schema")
+            .unwrap();
+        session
+            .execute("CREATE GRAPH IF NOT EXISTS /limit_schema/limit_graph")
+            .unwrap();
+        session
+            .execute("SESSION SET GRAPH /limit_schema/limit_graph")
+            .unwrap();
+        let nodes: Vec<String> = (0..200).map(|i| format!("(:Item {{n: {}}})", i)).collect();
+        session
+            .execute(&format!("INSERT {}", nodes.join(", ")))
+            .unwrap();
+
+        session.set_max_result_rows(Some(100)).unwrap();
+        let err = session.query("MATCH (i:Item) RETURN i.n").unwrap_err();
+        assert!(matches!(err, Error::ResultTooLarge(_)), "{:?}", err);
+
+        let result = session
+            .query("MATCH (i:Item) WHERE i.n < 50 RETURN i.n")
+            .unwrap();
+        assert_eq!(result.rows.len(), 50);
+
+        session.set_max_result_rows(None).unwrap();
+        let result = session.query("MATCH (i:Item) RETURN i.n").unwrap();
+        assert_eq!(result.rows.len(), 200);
+    }
+
+    #[test]
+    fn test_execute_sandboxed_reports_the_limit_hit() {
+        let db = GraphLite::open_in_memory().unwrap();
+        let session = db.session("admin").unwrap();
+        session
+            .execute("CREATE SCHEMA IF NOT EXISTS /sandbox_schema")
+            .unwrap();
+        session
+            .execute("CREATE GRAPH IF NOT EXISTS /sandbox_schema/sandbox_graph")
+            .unwrap();
+        session
+            .execute("SESSION SET GRAPH /sandbox_schema/sandbox_graph")
+            .unwrap();
+        let nodes: Vec<String> = (0..200).map(|i| format!("(:Item {{n: {}}})", i)).collect();
+        session
+            .execute(&format!("INSERT {}", nodes.join(", ")))
+            .unwrap();
+
+        let err = session
+            .execute_sandboxed(
+                "MATCH (i:Item) RETURN i.n",
+                &QueryLimits::new().max_memory_bytes(1024),
+            )
+            .unwrap_err();
+        assert!(matches!(err, Error::MemoryLimitExceeded(_)), "{:?}", err);
+
+        let err = session
+            .execute_sandboxed(
+                "MATCH (i:Item) RETURN i.n",
+                &QueryLimits::new().max_rows(100),
+            )
+            .unwrap_err();
+        assert!(matches!(err, Error::ResultTooLarge(_)), "{:?}", err);
+
+        let err = session
+            .execute_sandboxed(
+                "MATCH (i:Item) RETURN i.n",
+                &QueryLimits::new().timeout(Duration::ZERO),
+            )
+            .unwrap_err();
+        assert!(matches!(err, Error::QueryTimedOut(_)), "{:?}", err);
+
+        let err = session
+            .execute_sandboxed(
+                "INSERT (:Item {n: 200})",
+                &QueryLimits::new().read_only(true),
+            )
+            .unwrap_err();
+        assert!(matches!(err, Error::ReadOnlyViolation(_)), "{:?}", err);
+
+        let read_only = QueryLimits::new().read_only(true);
+        for statement in [
+            "CALL gql.clear_cache()",
+            "SESSION SET GRAPH /sandbox_schema/sandbox_graph",
+            "SESSION RESET",
+        ] {
+            let err = session
+                .execute_sandboxed(statement, &read_only)
+                .unwrap_err();
+            assert!(
+                matches!(err, Error::ReadOnlyViolation(_)),
+                "{}: {:?}",
+                statement,
+                err
+            );
+        }
+        assert!(session
+            .execute_sandboxed("CALL gql.list_graphs()", &read_only)
+            .is_ok());
+
+        // The row limit counts returned rows, not the rows a LIMIT cuts off
+        for query in [
+            "MATCH (i:Item) RETURN i.n LIMIT 50",
+            "MATCH (i:Item) RETURN i.n ORDER BY i.n LIMIT 50",
+        ] {
+            let result = session
+                .execute_sandboxed(query, &QueryLimits::new().max_rows(100))
+                .unwrap();
+            assert_eq!(result.rows.len(), 50, "{}", query);
+        }
+
+        let limits = QueryLimits::new()
+            .max_memory_bytes(1024 * 1024)
+            .max_rows(100)
+            .timeout(Duration::from_secs(30))
+            .read_only(true);
+        let result = session
+            .execute_sandboxed("MATCH (i:Item) WHERE i.n < 50 RETURN i.n", &limits)
+            .unwrap();
+        assert_eq!(result.rows.len(), 50);
+        let result = session.query("MATCH (i:Item) RETURN i.n").unwrap();
+        assert_eq!(result.rows.len(), 200);
+    }
+
+    #[test]
+    fn test_autocommit_off() {
+        let db = GraphLite::open_in_memory().unwrap();
+        let session = db.session("admin").unwrap();
+        session
+            .execute("CREATE SCHEMA IF NOT EXISTS /autocommit_schema")
+            .unwrap();
+        session
+            .execute("CREATE GRAPH IF NOT EXISTS /autocommit_schema/autocommit_graph")
+            .unwrap();
+        session
+            .execute("SESSION SET GRAPH /autocommit_schema/autocommit_graph")
+            .unwrap();
+        let count = || {
+            let result = session
+                .query("MATCH (p:Person) RETURN count(p) AS total")
+                .unwrap();
+            result.rows[0].values.get("total").cloned()
+        };
+
+        session.set_autocommit(false).unwrap();
+        session.execute("INSERT (:Person {name: 'Alice'})").unwrap();
+        session.execute("INSERT (:Person {name: 'Bob'})").unwrap();
+        session.rollback().unwrap();
+        assert_eq!(count(), Some(Value::Number(0.0)));
+
+        // A failed statement does not end the transaction
+        session.execute("INSERT (:Person {name: 'Carol'})").unwrap();
+        assert!(session.execute("INSERT (:Person {name: })").is_err());
+        session.execute("INSERT (:Person {name: 'Dave'})").unwrap();
+        session.commit().unwrap();
+        assert_eq!(count(), Some(Value::Number(2.0)));
+        assert!(session.commit().is_err());
+
+        // Re-enabling auto-commit commits the open transaction
+        session.execute("INSERT (:Person {name: 'Erin'})").unwrap();
+        session.set_autocommit(true).unwrap();
+        assert!(session.rollback().is_err());
+        assert_eq!(count(), Some(Value::Number(3.0)));
+    }
+
+    #[test]
+    fn test_lock_graph() {
+        let db = GraphLite::open_in_memory().unwrap();
+        let session = db.session("admin").unwrap();
+        session
+            .execute("CREATE SCHEMA IF NOT EXISTS /lock_schema")
+            .unwrap();
+        session
+            .execute("CREATE GRAPH IF NOT EXISTS /lock_schema/lock_graph")
+            .unwrap();
+        session
+            .execute("SESSION SET GRAPH /lock_schema/lock_graph")
+            .unwrap();
+
+        let guard = db
+            .lock_graph("/lock_schema/lock_graph", LockMode::Exclusive)
+            .unwrap();
+        // The locking thread itself can still write
+        session.execute("INSERT (:Person {name: 'Alice'})").unwrap();
+
+        let writer_session = db.session("admin").unwrap();
+        writer_session
+            .execute("SESSION SET GRAPH /lock_schema/lock_graph")
+            .unwrap();
+        let (done_tx, done_rx) = std::sync::mpsc::channel();
+        let writer = thread::spawn(move || {
+            writer_session
+                .execute("INSERT (:Person {name: 'Bob'})")
+                .unwrap();
+            done_tx.send(()).unwrap();
+        });
+        assert!(done_rx.recv_timeout(Duration::from_millis(200)).is_err());
+
+        let err = thread::scope(|scope| {
+            scope
+                .spawn(|| {
+                    db.lock_graph_timeout(
+                        "/lock_schema/lock_graph",
+                        LockMode::Shared,
+                        Duration::from_millis(50),
+                    )
+                    .unwrap_err()
+                })
+                .join()
+                .unwrap()
+        });
+        assert!(matches!(err, Error::LockTimeout(_)), "{:?}", err);
+        assert!(err.is_retryable());
+
+        drop(guard);
+        done_rx.recv_timeout(Duration::from_secs(5)).unwrap();
+        writer.join().unwrap();
+
+        let result = session
+            .query("MATCH (p:Person) RETURN count(p) AS total")
+            .unwrap();
+        assert_eq!(
+            result.rows[0].values.get("total"),
+            Some(&Value::Number(2.0))
+        );
+    }
+
+    #[test]
+    fn test_lock_graph_deadlock_is_a_conflict() {
+        let db = GraphLite::open_in_memory().unwrap();
+        let session = db.session("admin").unwrap();
+        session
+            .execute("CREATE SCHEMA IF NOT EXISTS /deadlock_schema")
+            .unwrap();
+        for graph in ["graph_a", "graph_b"] {
+            session
+                .execute(&format!(
+                    "CREATE GRAPH IF NOT EXISTS /deadlock_schema/{}",
+                    graph
+                ))
+                .unwrap();
+        }
+
+        // Each thread holds one graph and then waits for the other's; the
+        // wait that closes the cycle fails instead of blocking forever
+        let graph_b = db
+            .lock_graph("/deadlock_schema/graph_b", LockMode::Exclusive)
+            .unwrap();
+        let (locked_tx, locked_rx) = std::sync::mpsc::channel();
+        thread::scope(|scope| {
+            let other = scope.spawn(|| {
+                let _graph_a = db
+                    .lock_graph("/deadlock_schema/graph_a", LockMode::Exclusive)
+                    .unwrap();
+                locked_tx.send(()).unwrap();
+                db.lock_graph("/deadlock_schema/graph_b", LockMode::Exclusive)
+                    .map(drop)
+            });
+            locked_rx.recv().unwrap();
+            thread::sleep(Duration::from_millis(50));
+            let mine = db.lock_graph("/deadlock_schema/graph_a", LockMode::Exclusive);
+            let mine_failed = mine.is_err();
+            if let Err(err) = &mine {
+                assert!(matches!(err, Error::Conflict(_)), "{:?}", err);
+                assert!(err.is_retryable());
+            }
+            drop(mine);
+            drop(graph_b);
+
+            let theirs = other.join().unwrap();
+            if let Err(err) = &theirs {
+                assert!(matches!(err, Error::Conflict(_)), "{:?}", err);
+            }
+            assert!(mine_failed != theirs.is_err());
+        });
+    }
+
+    #[test]
+    fn test_explain_estimates_each_operator() {
+        let db = GraphLite::open_in_memory().unwrap();
+        let session = db.session("admin").unwrap();
+
+        let plan = session
+            .explain("MATCH (p:Person) WHERE p.age = 30 RETURN p.name")
+            .unwrap();
+        let filter = plan
+            .operator("Filter")
+            .unwrap_or_else(|| panic!("No Filter operator in:\n{}", plan.format_tree()));
+        let scan = &filter.children[0];
+        assert!(scan.operator.contains("Scan"), "{}", plan.format_tree());
+        assert!(filter.estimated_rows < scan.estimated_rows);
+        assert!(filter.estimated_cost > scan.estimated_cost);
+        assert!(plan
+            .format_tree()
+            .contains(&format!("Filter (estimated rows={}", filter.estimated_rows)));
+
+        assert!(session.explain("CREATE SCHEMA /other").is_err());
+    }
+
+    #[test]
+    fn test_explain_to_dot() {
+        let db = GraphLite::open_in_memory().unwrap();
+        let session = db.session("admin").unwrap();
+
+        let plan = session
+            .explain(
+                "MATCH (p:Person)-[:KNOWS]->(f:Person) WHERE p.age > 30 \
+                 RETURN f.name AS name ORDER BY name LIMIT 5",
+            )
+            .unwrap();
+
+        fn count_operators(node: &crate::EstimatedNode) -> usize {
+            1 + node.children.iter().map(count_operators).sum::<usize>()
+        }
+        let operators = count_operators(&plan.operators);
+        assert!(operators >= 4, "{}", plan.format_tree());
+
+        let dot = plan.to_dot();
+        assert!(dot.starts_with("digraph plan {"), "{}", dot);
+        assert_eq!(dot.matches("[label=").count(), operators, "{}", dot);
+        assert_eq!(dot.matches(" -> ").count(), operators - 1, "{}", dot);
+        assert!(dot.contains("Filter"), "{}", dot);
+        assert!(dot.contains("(p)-[:KNOWS]->(f)"), "{}", dot);
+
+        // The physical plan renders the same tree with the planner's estimates
+        let physical = plan.physical_plan.to_dot();
+        assert_eq!(physical.matches("[label=").count(), operators);
+        assert_eq!(physical.matches(" -> ").count(), operators - 1);
+    }
+
+    #[test]
+    fn test_explain_analyze() {
+        let db = GraphLite::open_in_memory().unwrap();
+        let session = db.session("admin").unwrap();
+        session
+            .execute("CREATE SCHEMA IF NOT EXISTS /analyze_schema")
+            .unwrap();
+        session
+            .execute("CREATE GRAPH IF NOT EXISTS /analyze_schema/analyze_graph")
+            .unwrap();
+        session
+            .execute("SESSION SET GRAPH /analyze_schema/analyze_graph")
+            .unwrap();
+        let nodes: Vec<String> = (0..50)
+            .map(|i| format!("(:Person {{age: {}}})", i))
+            .collect();
+        session
+            .execute(&format!("INSERT {}", nodes.join(", ")))
+            .unwrap();
+
+        let analyzed = session
+            .explain_analyze("MATCH (p:Person) WHERE p.age >= 30 RETURN p.age")
+            .unwrap();
+        assert_eq!(analyzed.row_count, 20);
+
+        let filter = analyzed
+            .operator("Filter")
+            .unwrap_or_else(|| panic!("No Filter operator in:\n{}", analyzed.format_tree()));
+        assert_eq!(filter.actual_rows, 20);
+        assert!(filter.elapsed > Duration::ZERO);
+
+        let scan = &filter.children[0];
+        assert!(scan.operator.contains("Scan"), "{}", analyzed.format_tree());
+        assert_eq!(scan.actual_rows, 50);
+        assert!(scan.elapsed > Duration::ZERO);
+        assert!(filter.elapsed >= scan.elapsed);
+
+        assert!(session.explain_analyze("CREATE SCHEMA /other").is_err());
+    }
+
+    #[test]
+    fn test_parse_error_location() {
+        let db = GraphLite::open_in_memory().unwrap();
+        let session = db.session("admin").unwrap();
+
+        let err = session.query("MATCH (n:Person RETURN n.name").unwrap_err();
+        match err {
+            Error::Parse { line, column, .. } => {
+                assert_eq!(line, 1);
+                assert_eq!(column, 17);
+            }
+            other => panic!("Expected a parse error, got {:?}", other),
+        }
+
+        let err = session
+            .execute("INSERT (:Person {name: 'Alice'}}")
+            .unwrap_err();
+        assert!(matches!(err, Error::Parse { .. }), "{:?}", err);
+    }
+
+    #[test]
+    fn test_query_without_current_graph() {
+        let db = GraphLite::open_in_memory().unwrap();
+        let session = db.session("admin").unwrap();
+        assert_eq!(session.current_graph(), None);
+
+        for statement in [
+            "MATCH (n:Person) RETURN n.name",
+            "INSERT (:Person {name: 'Alice'})",
+        ] {
+            match session.query(statement).unwrap_err() {
+                Error::NoCurrentGraph { hint } => {
+                    assert!(hint.starts_with("Use SESSION SET GRAPH"), "{}", hint)
+                }
+                other => panic!("Expected NoCurrentGraph for {}, got {:?}", statement, other),
+            }
+        }
+    }
+
+    #[test]
+    fn test_error_is_retryable() {
+        let db = GraphLite::open_in_memory().unwrap();
+        let session = db.session("admin").unwrap();
+        session
+            .execute("CREATE SCHEMA IF NOT EXISTS /retry_schema")
+            .unwrap();
+        session
+            .execute("CREATE GRAPH IF NOT EXISTS /retry_schema/retry_graph")
+            .unwrap();
+        session
+            .execute("SESSION SET GRAPH /retry_schema/retry_graph")
+            .unwrap();
+
+        let err = session.query("MATCH (n:Person RETURN n.name").unwrap_err();
+        assert!(matches!(err, Error::Parse { .. }), "{:?}", err);
+        assert!(!err.is_retryable());
+
+        session
+            .execute("CREATE CONSTRAINT ON Person ASSERT email IS UNIQUE")
+            .unwrap();
+        session
+            .execute("INSERT (:Person {name: 'Alice', email: 'a@example.com'})")
+            .unwrap();
+        let err = session
+            .execute("INSERT (:Person {name: 'Alicia', email: 'a@example.com'})")
+            .unwrap_err();
+        assert!(!err.is_retryable(), "{:?}", err);
+
+        assert!(!Error::TypeConversion("expected a number".to_string()).is_retryable());
+
+        // A graph busy with another thread's lock is a retryable conflict
+        let guard = db
+            .lock_graph("/retry_schema/retry_graph", LockMode::Exclusive)
+            .unwrap();
+        let err = thread::scope(|scope| {
+            scope
+                .spawn(|| {
+                    db.lock_graph_timeout(
+                        "/retry_schema/retry_graph",
+                        LockMode::Shared,
+                        Duration::ZERO,
+                    )
+                    .unwrap_err()
+                })
+                .join()
+                .unwrap()
+        });
+        drop(guard);
+        assert!(matches!(err, Error::Conflict(_)), "{:?}", err);
+        assert!(err.is_retryable());
+
+        assert!(Error::TransactionTimedOut("idle".to_string()).is_retryable());
+        assert!(Error::Io(std::io::ErrorKind::TimedOut.into()).is_retryable());
+        assert!(!Error::Io(std::io::ErrorKind::NotFound.into()).is_retryable());
+    }
+
+    #[test]
+    fn test_open_same_path_shares_coordinator() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let db_path = temp_dir.path().join("shared_db");
+
+        let first = GraphLite::open(&db_path).unwrap();
+        let second = GraphLite::open(&db_path).unwrap();
+        assert!(Arc::ptr_eq(&first.coordinator, &second.coordinator));
+
+        let writer = first.session("admin").unwrap();
+        writer
+            .execute("CREATE SCHEMA IF NOT EXISTS /shared_schema")
+            .unwrap();
+        writer
+            .execute("CREATE GRAPH IF NOT EXISTS /shared_schema/shared_graph")
+            .unwrap();
+        writer
+            .execute("SESSION SET GRAPH /shared_schema/shared_graph")
+            .unwrap();
+        writer
+            .execute("INSERT (:Person {name: 'Alice'}), (:Person {name: 'Bob'})")
+            .unwrap();
+
+        let readers: Vec<_> = [&first, &second, &first, &second]
+            .iter()
+            .map(|db| {
+                let session = db.session("reader").unwrap();
+                session
+                    .execute("SESSION SET GRAPH /shared_schema/shared_graph")
+                    .unwrap();
+                thread::spawn(move || {
+                    let result = session
+                        .query("MATCH (p:Person) RETURN count(p) AS total")
+                        .unwrap();
+                    result.rows[0].values.get("total").cloned()
+                })
+            })
+            .collect();
+
+        for reader in readers {
+            let total = reader.join().expect("reader thread panicked");
+            assert_eq!(total, Some(graphlite::Value::Number(2.0)));
+        }
+    }
+
+    #[test]
+    fn test_slow_query_hook_fires() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let db = GraphLite::open(temp_dir.path().join("slow_db")).unwrap();
+        let session = db.session("admin").unwrap();
+        session
+            .execute("CREATE SCHEMA IF NOT EXISTS /slow")
+            .unwrap();
+
+        let reported = Arc::new(Mutex::new(Vec::new()));
+        let sink = reported.clone();
+        db.on_slow_query(Duration::ZERO, move |slow| {
+            sink.lock().unwrap().push(slow.clone());
+        });
+
+        session
+            .execute("CREATE GRAPH IF NOT EXISTS /slow/graph")
+            .unwrap();
+
+        let reported = reported.lock().unwrap();
+        assert_eq!(reported.len(), 1);
+        assert_eq!(
+            reported[0].query_text,
+            "CREATE GRAPH IF NOT EXISTS /slow/graph"
+        );
+        assert_eq!(reported[0].session_id, session.id());
+        assert_eq!(
+            reported[0].info.query_type,
+            graphlite::QueryType::CreateGraph
+        );
+        assert!(reported[0].elapsed > Duration::ZERO);
+    }
+
+    #[test]
+    fn test_subscribe_receives_committed_changes_from_other_sessions() {
+        let db = GraphLite::open_in_memory().unwrap();
+        let setup = db.session("admin").unwrap();
+        setup
+            .execute("CREATE SCHEMA IF NOT EXISTS /feed_schema")
+            .unwrap();
+        setup
+            .execute("CREATE GRAPH IF NOT EXISTS /feed_schema/feed_graph")
+            .unwrap();
+
+        let (sender, receiver) = std::sync::mpsc::channel();
+        let sender = Mutex::new(sender);
+        db.subscribe("/feed_schema/feed_graph", move |change| {
+            let _ = sender.lock().unwrap().send(change.clone());
+        })
+        .unwrap();
+
+        let writer = db.session("writer").unwrap();
+        writer
+            .execute("SESSION SET GRAPH /feed_schema/feed_graph")
+            .unwrap();
+        writer.execute("INSERT (:Person {name: 'Alice'})").unwrap();
+        let node_id = |name: &str| {
+            let result = writer
+                .query(&format!("MATCH (p:Person {{name: '{}'}}) RETURN p", name))
+                .unwrap();
+            match result.rows[0].values.get("p") {
+                Some(Value::Node(node)) => node.id.clone(),
+                other => panic!("expected a node, got {:?}", other),
+            }
+        };
+
+        let change = receiver.recv_timeout(Duration::from_secs(5)).unwrap();
+        assert_eq!(change.graph_path, "/feed_schema/feed_graph");
+        assert_eq!(change.entity, graphlite::EntityKind::Node);
+        assert_eq!(change.kind, graphlite::ChangeKind::Insert);
+        assert_eq!(change.entity_id, node_id("Alice"));
+
+        // Rolled back writes are never published; committed ones are
+        {
+            let mut tx = writer.begin().unwrap();
+            tx.execute("INSERT (:Person {name: 'Bob'})").unwrap();
+        }
+        let mut tx = writer.begin().unwrap();
+        tx.execute("INSERT (:Person {name: 'Carol'})").unwrap();
+        tx.commit().unwrap();
+
+        let change = receiver.recv_timeout(Duration::from_secs(5)).unwrap();
+        assert_eq!(change.kind, graphlite::ChangeKind::Insert);
+        assert_eq!(change.entity_id, node_id("Carol"));
+        assert!(receiver.recv_timeout(Duration::from_millis(100)).is_err());
+    }
+
+    #[test]
+    fn test_metrics_count_queries_by_type_and_errors() {
+        let db = GraphLite::open_in_memory().unwrap();
+        let session = db.session("admin").unwrap();
+        session
+            .execute("CREATE SCHEMA IF NOT EXISTS /metrics")
+            .unwrap();
+        session
+            .execute("CREATE GRAPH IF NOT EXISTS /metrics/graph")
+            .unwrap();
+        session.execute("SESSION SET GRAPH /metrics/graph").unwrap();
+        db.reset_metrics();
+        assert_eq!(db.metrics().total_queries, 0);
+
+        session.execute("INSERT (:Person {name: 'Alice'})").unwrap();
+        session.query("MATCH (p:Person) RETURN p.name").unwrap();
+        session.query("MATCH (p:Person) RETURN count(p)").unwrap();
+        assert!(session.query("MATCH (p:Person RETURN p").is_err());
+
+        let metrics = db.metrics();
+        assert_eq!(metrics.total_queries, 4);
+        assert_eq!(metrics.total_errors, 1);
+        assert_eq!(metrics.count(&graphlite::QueryType::Match), 2);
+        assert_eq!(metrics.count(&graphlite::QueryType::Insert), 1);
+        assert_eq!(metrics.queries_by_type.values().sum::<u64>(), 3);
+        assert!(metrics.max_latency >= metrics.average_latency());
+        assert!(metrics.average_latency() > Duration::ZERO);
+
+        db.reset_metrics();
+        let metrics = db.metrics();
+        assert_eq!(metrics.total_queries, 0);
+        assert!(metrics.queries_by_type.is_empty());
+        assert_eq!(metrics.average_latency(), Duration::ZERO);
+    }
+
+    #[test]
+    fn test_last_handle_drop_closes_database() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let db_path = temp_dir.path().join("reopen_db");
+
+        let db = GraphLite::open(&db_path).unwrap();
+        let weak = Arc::downgrade(&db.coordinator);
+        let session = db.session("admin").unwrap();
+        drop(db);
+        // Sessions keep the database open
+        assert!(weak.upgrade().is_some());
+
+        drop(session);
+        assert!(weak.upgrade().is_none());
+
+        // Reopening after close must acquire the storage lock again
+        let reopened = GraphLite::open(&db_path).unwrap();
+        assert!(reopened.session("admin").is_ok());
+    }
+
+    #[test]
+    fn test_max_concurrency_rejects_excess_queries() {
+        let db = GraphLite::open_in_memory().unwrap();
+        let session = db.session("admin").unwrap();
+        session
+            .execute("CREATE SCHEMA IF NOT EXISTS /queue_schema")
+            .unwrap();
+        session
+            .execute("CREATE GRAPH IF NOT EXISTS /queue_schema/queue_graph")
+            .unwrap();
+        session
+            .execute("SESSION SET GRAPH /queue_schema/queue_graph")
+            .unwrap();
+        session.execute("INSERT (:Person {name: 'Alice'})").unwrap();
+
+        let readers: Vec<Session> = (0..4)
+            .map(|_| {
+                let reader = db.session("admin").unwrap();
+                reader
+                    .execute("SESSION SET GRAPH /queue_schema/queue_graph")
+                    .unwrap();
+                reader
+            })
+            .collect();
+
+        // One query runs, one waits in the queue, the rest are turned away
+        db.set_max_concurrency(Some((1, 1)));
+        // Readers block on the lock, so the first admitted query holds its slot
+        let guard = db
+            .lock_graph("/queue_schema/queue_graph", LockMode::Exclusive)
+            .unwrap();
+
+        let (result_tx, result_rx) = std::sync::mpsc::channel();
+        let handles: Vec<_> = readers
+            .into_iter()
+            .map(|reader| {
+                let result_tx = result_tx.clone();
+                thread::spawn(move || {
+                    let result = reader.query("MATCH (p:Person) RETURN p.name");
+                    result_tx
+                        .send(result.map(|result| result.rows.len()))
+                        .unwrap();
+                })
+            })
+            .collect();
+
+        for _ in 0..2 {
+            let err = result_rx
+                .recv_timeout(Duration::from_secs(5))
+                .unwrap()
+                .unwrap_err();
+            assert!(matches!(err, Error::Overloaded(_)), "{:?}", err);
+            assert!(err.is_retryable());
+        }
+
+        // Releasing the lock lets the running and the queued query finish
+        drop(guard);
+        for _ in 0..2 {
+            let rows = result_rx.recv_timeout(Duration::from_secs(5)).unwrap();
+            assert_eq!(rows.unwrap(), 1);
+        }
+        for handle in handles {
+            handle.join().unwrap();
+        }
+
+        db.set_max_concurrency(None);
+        assert!(session.query("MATCH (p:Person) RETURN p.name").is_ok());
+    }
+
+    #[test]
+    fn test_rate_limit_is_per_user() {
+        let db = GraphLite::open_in_memory().unwrap();
+        let alice = db.session("alice").unwrap();
+        let bob = db.session("bob").unwrap();
+        alice
+            .execute("CREATE SCHEMA IF NOT EXISTS /rate_schema")
+            .unwrap();
+        alice
+            .execute("CREATE GRAPH IF NOT EXISTS /rate_schema/rate_graph")
+            .unwrap();
+        for session in [&alice, &bob] {
+            session
+                .execute("SESSION SET GRAPH /rate_schema/rate_graph")
+                .unwrap();
+        }
+
+        // Two queries up front, then one more every 10 seconds
+        db.set_rate_limit("alice", 0.1, 2);
+
+        let results: Vec<_> = (0..5).map(|_| alice.query("MATCH (n) RETURN n")).collect();
+        assert!(results[0].is_ok() && results[1].is_ok());
+        for result in &results[2..] {
+            let err = result.as_ref().unwrap_err();
+            assert!(matches!(err, Error::RateLimited(_)), "{:?}", err);
+            assert!(err.is_retryable());
+        }
+
+        // Bob has no limit and is unaffected by Alice's bucket
+        for _ in 0..5 {
+            bob.query("MATCH (n) RETURN n").unwrap();
+        }
+
+        db.clear_rate_limit("alice");
+        alice.query("MATCH (n) RETURN n").unwrap();
+    }
+
+    #[test]
+    fn test_result_cache_serves_repeats_until_a_write() {
+        let db = GraphLite::open_in_memory().unwrap();
+        let session = db.session("admin").unwrap();
+        session
+            .execute("CREATE SCHEMA IF NOT EXISTS /result_cache")
+            .unwrap();
+        session
+            .execute("CREATE GRAPH IF NOT EXISTS /result_cache/graph")
+            .unwrap();
+        session
+            .execute("SESSION SET GRAPH /result_cache/graph")
+            .unwrap();
+        session.execute("INSERT (:City {name: 'Oslo'})").unwrap();
+        db.set_result_cache(16, Some(Duration::from_secs(60)));
+        db.reset_metrics();
+
+        let query = "MATCH (c:City) RETURN c.name ORDER BY c.name";
+        assert_eq!(session.query(query).unwrap().rows.len(), 1);
+        // Layout differences do not matter
+        let cached = session
+            .query("MATCH (c:City)\n  RETURN c.name  ORDER BY c.name")
+            .unwrap();
+        assert_eq!(cached.rows.len(), 1);
+        let metrics = db.metrics();
+        assert_eq!(metrics.result_cache_misses, 1);
+        assert_eq!(metrics.result_cache_hits, 1);
+
+        session.execute("INSERT (:City {name: 'Bergen'})").unwrap();
+        assert_eq!(db.metrics().result_cache_invalidations, 1);
+
+        let fresh = session.query(query).unwrap();
+        assert_eq!(fresh.rows.len(), 2);
+        assert_eq!(
+            fresh.rows[0].values.get("c.name"),
+            Some(&Value::String("Bergen".to_string()))
+        );
+        let metrics = db.metrics();
+        assert_eq!(metrics.result_cache_misses, 2);
+        assert_eq!(metrics.result_cache_hits, 1);
+
+        // Queries naming graphs with FROM bypass the cache
+        let scoped = "MATCH (c:City) FROM /result_cache/graph RETURN c.name";
+        assert_eq!(session.query(scoped).unwrap().rows.len(), 2);
+        assert_eq!(session.query(scoped).unwrap().rows.len(), 2);
+        let metrics = db.metrics();
+        assert_eq!(metrics.result_cache_misses, 2);
+        assert_eq!(metrics.result_cache_hits, 1);
+
+        db.disable_result_cache();
+        session.query(query).unwrap();
+        assert_eq!(db.metrics().result_cache_hits, 1);
+    }
+
+    #[test]
+    fn test_result_cache_skips_graphs_with_node_expiry() {
+        let db = GraphLite::open_in_memory().unwrap();
+        let session = db.session("admin").unwrap();
+        session
+            .execute("CREATE SCHEMA IF NOT EXISTS /cache_ttl")
+            .unwrap();
+        session
+            .execute(
+                "CREATE GRAPH IF NOT EXISTS /cache_ttl/tokens \
+                 WITH (ttl_label = 'Token', ttl_property = 'expires_at')",
+            )
+            .unwrap();
+        session
+            .execute("SESSION SET GRAPH /cache_ttl/tokens")
+            .unwrap();
+        session.execute("INSERT (:Token {name: 'short'})").unwrap();
+        session
+            .execute("MATCH (t:Token) SET t.expires_at = DATE_ADD(NOW(), 1, 'SECOND')")
+            .unwrap();
+        db.set_result_cache(16, Some(Duration::from_secs(60)));
+        db.reset_metrics();
+
+        let query = "MATCH (t:Token) RETURN t.name";
+        assert_eq!(session.query(query).unwrap().rows.len(), 1);
+
+        // The token expires without any write; the repeat must not be served
+        // the row from before
+        std::thread::sleep(Duration::from_millis(1200));
+        assert_eq!(session.query(query).unwrap().rows.len(), 0);
+        let metrics = db.metrics();
+        assert_eq!(metrics.result_cache_hits, 0);
+        assert_eq!(metrics.result_cache_misses, 0);
+    }
+
+    #[test]
+    fn test_result_cache_skips_non_deterministic_functions() {
+        let db = GraphLite::open_in_memory().unwrap();
+        let session = db.session("admin").unwrap();
+        session
+            .execute("CREATE SCHEMA IF NOT EXISTS /cache_rand")
+            .unwrap();
+        session
+            .execute("CREATE GRAPH IF NOT EXISTS /cache_rand/graph")
+            .unwrap();
+        session
+            .execute("SESSION SET GRAPH /cache_rand/graph")
+            .unwrap();
+        db.set_result_cache(16, Some(Duration::from_secs(60)));
+        db.reset_metrics();
+
+        let first = session.query("RETURN rand() AS r").unwrap();
+        let second = session.query("RETURN rand() AS r").unwrap();
+        assert_ne!(
+            first.rows[0].values.get("r"),
+            second.rows[0].values.get("r")
+        );
+        let metrics = db.metrics();
+        assert_eq!(metrics.result_cache_hits, 0);
+        assert_eq!(metrics.result_cache_misses, 0);
+    }
+
+    #[test]
+    fn test_compact_drops_deleted_graphs_and_keeps_data() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let db = GraphLite::open(temp_dir.path().join("compact_db")).unwrap();
+        let session = db.session("admin").unwrap();
+        session
+            .execute("CREATE SCHEMA IF NOT EXISTS /compact")
+            .unwrap();
+        session
+            .execute("CREATE GRAPH IF NOT EXISTS /compact/kept")
+            .unwrap();
+        session
+            .execute("CREATE GRAPH IF NOT EXISTS /compact/scratch")
+            .unwrap();
+
+        session.execute("SESSION SET GRAPH /compact/kept").unwrap();
+        for i in 0..200 {
+            session
+                .execute(&format!("INSERT (:Item {{n: {}}})", i))
+                .unwrap();
+        }
+        session
+            .execute("MATCH (i:Item) WHERE i.n >= 50 DELETE i")
+            .unwrap();
+        session
+            .execute("SESSION SET GRAPH /compact/scratch")
+            .unwrap();
+        session.execute("INSERT (:Temp), (:Temp)").unwrap();
+        session.execute("DROP GRAPH /compact/scratch").unwrap();
+
+        let report = db.compact().unwrap();
+        assert_eq!(report.dropped_trees, 3);
+        assert_eq!(
+            report.reclaimed_bytes,
+            report.bytes_before.saturating_sub(report.bytes_after)
+        );
+
+        // Nothing is left to drop the second time
+        assert_eq!(db.compact().unwrap().dropped_trees, 0);
+
+        session.execute("SESSION SET GRAPH /compact/kept").unwrap();
+        let result = session
+            .query("MATCH (i:Item) RETURN count(i) AS items, sum(i.n) AS total")
+            .unwrap();
+        assert_eq!(
+            result.rows[0].values.get("items"),
+            Some(&Value::Number(50.0))
+        );
+        assert_eq!(
+            result.rows[0].values.get("total"),
+            Some(&Value::Number(1225.0))
+        );
+    }
+
+    #[test]
+    fn test_periodic_flush_survives_clean_reopen() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let db_path = temp_dir.path().join("periodic_db");
+
+        let options =
+            OpenOptions::new().flush_policy(FlushPolicy::Periodic(Duration::from_millis(20)));
+        let db = GraphLite::open_with_options(&db_path, options).unwrap();
+        let session = db.session("admin").unwrap();
+        session
+            .execute("CREATE SCHEMA IF NOT EXISTS /flush")
+            .unwrap();
+        session
+            .execute("CREATE GRAPH IF NOT EXISTS /flush/periodic")
+            .unwrap();
+        session
+            .execute("SESSION SET GRAPH /flush/periodic")
+            .unwrap();
+        session
+            .execute("INSERT (:Reading {n: 1}), (:Reading {n: 2})")
+            .unwrap();
+        drop(session);
+        drop(db);
+
+        let reopened = GraphLite::open(&db_path).unwrap();
+        let session = reopened.session("admin").unwrap();
+        session
+            .execute("SESSION SET GRAPH /flush/periodic")
+            .unwrap();
+        let result = session
+            .query("MATCH (r:Reading) RETURN count(r) AS readings")
+            .unwrap();
+        assert_eq!(
+            result.rows[0].values.get("readings"),
+            Some(&Value::Number(2.0))
+        );
+    }
+
+    #[test]
+    fn test_manual_flush_makes_writes_durable() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let db_path = temp_dir.path().join("manual_db");
+
+        let options = OpenOptions::new().flush_policy(FlushPolicy::Manual);
+        let db = GraphLite::open_with_options(&db_path, options).unwrap();
+        let session = db.session("admin").unwrap();
+        session
+            .execute("CREATE SCHEMA IF NOT EXISTS /flush")
+            .unwrap();
+        session
+            .execute("CREATE GRAPH IF NOT EXISTS /flush/manual")
+            .unwrap();
+        session.execute("SESSION SET GRAPH /flush/manual").unwrap();
+        session
+            .execute("INSERT (:Row {n: 1}), (:Row {n: 2})")
+            .unwrap();
+        db.flush().unwrap();
+
+        // Copying the files of the still-open database is what a crash
+        // would leave on disk
+        let crashed_path = temp_dir.path().join("crashed_db");
+        copy_dir(&db_path, &crashed_path);
+
+        let recovered = GraphLite::open(&crashed_path).unwrap();
+        let session = recovered.session("admin").unwrap();
+        session.execute("SESSION SET GRAPH /flush/manual").unwrap();
+        let result = session
+            .query("MATCH (r:Row) RETURN count(r) AS rows")
+            .unwrap();
+        assert_eq!(result.rows[0].values.get("rows"), Some(&Value::Number(2.0)));
+    }
+
+    fn copy_dir(from: &Path, to: &Path) {
+        std::fs::create_dir_all(to).unwrap();
+        for entry in std::fs::read_dir(from).unwrap() {
+            let entry = entry.unwrap();
+            let target = to.join(entry.file_name());
+            if entry.file_type().unwrap().is_dir() {
+                copy_dir(&entry.path(), &target);
+            } else {
+                std::fs::copy(entry.path(), target).unwrap();
+            }
+        }
+    }
+
+    #[test]
+    fn test_schema_version_survives_reopen() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let db_path = temp_dir.path().join("versioned_db");
+
+        let db = GraphLite::open(&db_path).unwrap();
+        assert_eq!(db.schema_version().unwrap(), 0);
+        db.set_schema_version(3).unwrap();
+        assert_eq!(db.schema_version().unwrap(), 3);
+        drop(db);
+
+        let reopened = GraphLite::open(&db_path).unwrap();
+        assert_eq!(reopened.schema_version().unwrap(), 3);
+    }
+
+    #[test]
+    fn test_node_and_edge_exists() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let db = GraphLite::open(temp_dir.path().join("exists_db")).unwrap();
+        let session = db.session("admin").unwrap();
+        session
+            .execute("CREATE SCHEMA IF NOT EXISTS /lookup")
+            .unwrap();
+        session
+            .execute("CREATE GRAPH IF NOT EXISTS /lookup/people")
+            .unwrap();
+        session.execute("SESSION SET GRAPH /lookup/people").unwrap();
+        session
+            .execute(
+                "INSERT (:Person {email: 'alice@example.com', age: 30})\
+                 -[:KNOWS {since: 2020}]->(:Person {email: \"o'brien@example.com\"})",
+            )
+            .unwrap();
+
+        let email = |s: &str| Value::String(s.to_string());
+        assert!(session
+            .node_exists("Person", "email", &email("alice@example.com"))
+            .unwrap());
+        assert!(session
+            .node_exists("Person", "email", &email("o'brien@example.com"))
+            .unwrap());
+        assert!(!session
+            .node_exists("Person", "email", &email("bob@example.com"))
+            .unwrap());
+        assert!(session
+            .node_exists("Person", "age", &Value::Number(30.0))
+            .unwrap());
+        assert!(!session
+            .node_exists("Person", "email", &Value::Null)
+            .unwrap());
+        assert!(!session
+            .node_exists("Company", "email", &email("alice@example.com"))
+            .unwrap());
+
+        assert!(session
+            .edge_exists("KNOWS", "since", &Value::Number(2020.0))
+            .unwrap());
+        assert!(!session
+            .edge_exists("KNOWS", "since", &Value::Number(2021.0))
+            .unwrap());
+
+        // Strings no single quote character can delimit are found too
+        let awkward = email("it's a \"quote\" \\");
+        session
+            .query_with_parameters(
+                "INSERT (:Person {email: $email})",
+                &HashMap::from([("email".to_string(), awkward.clone())]),
+            )
+            .unwrap();
+        assert!(session.node_exists("Person", "email", &awkward).unwrap());
+    }
+
+    #[test]
+    fn test_create_edges_reports_missing_endpoints() {
+        let db = GraphLite::open_in_memory().unwrap();
+        let session = db.session("admin").unwrap();
+        session
+            .execute("CREATE SCHEMA IF NOT EXISTS /bulk")
+            .unwrap();
+        session
+            .execute("CREATE GRAPH IF NOT EXISTS /bulk/people")
+            .unwrap();
+        session.execute("SESSION SET GRAPH /bulk/people").unwrap();
+        session
+            .execute(
+                "INSERT (:Person {name: 'Alice'}), (:Person {name: 'Bob'}), \
+                 (:Person {name: 'Carol'}), (:City {code: 7})",
+            )
+            .unwrap();
+
+        let person = |name: &str| NodeKey::new("Person", "name", name);
+        let pairs = vec![
+            (person("Alice"), person("Bob")),
+            (person("Bob"), person("Carol")),
+            (person("Alice"), person("Dave")),
+            (person("Carol"), NodeKey::new("City", "code", 7.0)),
+        ];
+        let properties = HashMap::from([("since".to_string(), Value::Number(2024.0))]);
+
+        let report = session
+            .create_edges("KNOWS", &pairs, &properties, false)
+            .unwrap();
+        assert_eq!(report.created, 3);
+        assert_eq!(report.failed.len(), 1);
+        assert_eq!(report.failed[0].index, 2);
+        assert_eq!(report.failed[0].missing, vec![person("Dave")]);
+
+        let edges = session
+            .query("MATCH ()-[k:KNOWS {since: 2024}]->() RETURN count(k) AS edges")
+            .unwrap();
+        assert_eq!(edges.rows[0].values["edges"], Value::Number(3.0));
+
+        // With fail_fast the whole batch rolls back at the first missing endpoint
+        let pairs = vec![
+            (person("Bob"), person("Alice")),
+            (person("Eve"), person("Alice")),
+        ];
+        let err = session
+            .create_edges("LIKES", &pairs, &HashMap::new(), true)
+            .unwrap_err();
+        assert!(err.to_string().contains("Eve"), "{}", err);
+        let likes = session
+            .query("MATCH ()-[l:LIKES]->() RETURN count(l) AS edges")
+            .unwrap();
+        assert_eq!(likes.rows[0].values["edges"], Value::Number(0.0));
+
+        // Key and property values are bound, so any string works
+        let awkward = "O'Brien \"Jr.\" \\";
+        session
+            .query_with_parameters(
+                "INSERT (:Person {name: $name})",
+                &HashMap::from([("name".to_string(), Value::from(awkward))]),
+            )
+            .unwrap();
+        let pairs = vec![(person(awkward), person("Alice"))];
+        let properties = HashMap::from([("note".to_string(), Value::from(awkward))]);
+        let report = session
+            .create_edges("MENTORS", &pairs, &properties, true)
+            .unwrap();
+        assert_eq!(report.created, 1);
+        let mentors = session
+            .query("MATCH (a)-[m:MENTORS]->() RETURN a.name, m.note")
+            .unwrap();
+        assert_eq!(mentors.rows[0].values["a.name"], Value::from(awkward));
+        assert_eq!(mentors.rows[0].values["m.note"], Value::from(awkward));
+    }
+
+    #[test]
+    fn test_dry_run_counts_targets_without_writing() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let db = GraphLite::open(temp_dir.path().join("dry_run_db")).unwrap();
+        let session = db.session("admin").unwrap();
+        session.execute("CREATE SCHEMA IF NOT EXISTS /dry").unwrap();
+        session
+            .execute("CREATE GRAPH IF NOT EXISTS /dry/scratch")
+            .unwrap();
+        session.execute("SESSION SET GRAPH /dry/scratch").unwrap();
+        session
+            .execute(
+                "INSERT (:Temp {n: 1}), (:Temp {n: 2}), (:Temp {n: 3}), (:Temp {n: 4}), \
+                 (:Keep {n: 5})",
+            )
+            .unwrap();
+
+        let report = session.execute_dry_run("MATCH (n:Temp) DELETE n").unwrap();
+        assert_eq!(report.affected, 4);
+        assert_eq!(report.matched_rows, 4);
+
+        let report = session
+            .execute_dry_run("MATCH (n:Temp) WHERE n.n > 2 SET n.stale = true")
+            .unwrap();
+        assert_eq!(report.affected, 2);
+
+        let count = session
+            .query("MATCH (n:Temp) RETURN count(n) AS total")
+            .unwrap();
+        assert_eq!(count.rows[0].values.get("total"), Some(&Value::Number(4.0)));
+        let stale = session
+            .query("MATCH (n:Temp) WHERE n.stale = true RETURN n")
+            .unwrap();
+        assert!(stale.rows.is_empty());
+
+        assert!(session.execute_dry_run("INSERT (:Temp {n: 6})").is_err());
+    }
+
+    #[test]
+    fn test_allow_duplicate_nodes_keeps_identical_inserts() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let db = GraphLite::open(temp_dir.path().join("duplicates_db")).unwrap();
+        let session = db.session("admin").unwrap();
+        session
+            .execute("CREATE SCHEMA IF NOT EXISTS /dups")
+            .unwrap();
+        session
+            .execute("CREATE GRAPH IF NOT EXISTS /dups/events")
+            .unwrap();
+        session.execute("SESSION SET GRAPH /dups/events").unwrap();
+
+        let count = |label: &str| {
+            session
+                .query(&format!("MATCH (n:{}) RETURN count(n) AS total", label))
+                .unwrap()
+                .rows[0]
+                .values
+                .get("total")
+                .cloned()
+        };
+
+        // Off by default: the second insert conflicts with the first
+        session.execute("INSERT (:Event {type: 'x'})").unwrap();
+        assert!(session.query("INSERT (:Event {type: 'x'})").is_err());
+        assert_eq!(count("Event"), Some(Value::Number(1.0)));
+
+        session.set_allow_duplicate_nodes(true).unwrap();
+        let third = session.query("INSERT (:Event {type: 'x'})").unwrap();
+        assert!(third.warnings.is_empty());
+        assert_eq!(count("Event"), Some(Value::Number(2.0)));
+
+        // Edges attach to the new copy rather than the existing node
+        session
+            .execute("INSERT (:Event {type: 'x'})-[:NEXT]->(:Step {n: 1})")
+            .unwrap();
+        assert_eq!(count("Event"), Some(Value::Number(3.0)));
+        let linked = session
+            .query("MATCH (e:Event)-[:NEXT]->(s:Step) RETURN count(e) AS total")
+            .unwrap();
+        assert_eq!(
+            linked.rows[0].values.get("total"),
+            Some(&Value::Number(1.0))
+        );
+
+        session.set_allow_duplicate_nodes(false).unwrap();
+        assert!(session.execute("INSERT (:Event {type: 'x'})").is_err());
+        assert_eq!(count("Event"), Some(Value::Number(3.0)));
+    }
+
+    #[test]
+    fn test_deterministic_order_survives_reopen() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let path = temp_dir.path().join("ordered_db");
+        let queries = [
+            "MATCH (n:Item) RETURN n.id AS id, n.kind AS kind",
+            "MATCH (a:Item)-[:NEXT]->(b:Item) RETURN a.id AS a, b.id AS b",
+            "MATCH (n:Item) RETURN n.kind AS kind, count(n) AS total GROUP BY n.kind",
+        ];
+
+        let run = |setup: bool| -> Vec<String> {
+            let db = GraphLite::open(&path).unwrap();
+            let session = db.session("admin").unwrap();
+            if setup {
+                session
+                    .execute("CREATE SCHEMA IF NOT EXISTS /ordered")
+                    .unwrap();
+                session
+                    .execute("CREATE GRAPH IF NOT EXISTS /ordered/items")
+                    .unwrap();
+            }
+            session.execute("SESSION SET GRAPH /ordered/items").unwrap();
+            if setup {
+                for batch in 0..4 {
+                    let nodes = (0..10)
+                        .map(|i| {
+                            let id = batch * 10 + i;
+                            format!("(:Item {{id: {}, kind: 'k{}'}})", id, id % 7)
+                        })
+                        .collect::<Vec<_>>()
+                        .join(", ");
+                    session.execute(&format!("INSERT {}", nodes)).unwrap();
+                }
+                session
+                    .execute(
+                        "MATCH (a:Item), (b:Item) WHERE b.id = a.id + 1 \
+                         INSERT (a)-[:NEXT]->(b)",
+                    )
+                    .unwrap();
+            }
+
+            session.set_deterministic_order(true).unwrap();
+            queries
+                .iter()
+                .map(|query| {
+                    let result = session.query(query).unwrap();
+                    let mut out = Vec::new();
+                    export::write_result(result, &mut out, OutputFormat::Json).unwrap();
+                    String::from_utf8(out).unwrap()
+                })
+                .collect()
+        };
+
+        let first = run(true);
+        let second = run(false);
+        assert_eq!(first, second);
+        assert_eq!(run(false), first);
+        assert_eq!(first[0].matches("\"id\"").count(), 40);
+    }
+
+    #[test]
+    fn test_query_with_progress_reports_increasing_counts() {
+        let db = GraphLite::open_in_memory().unwrap();
+        let session = db.session("admin").unwrap();
+        session
+            .execute("CREATE SCHEMA IF NOT EXISTS /progress")
+            .unwrap();
+        session
+            .execute("CREATE GRAPH IF NOT EXISTS /progress/items")
+            .unwrap();
+        session
+            .execute("SESSION SET GRAPH /progress/items")
+            .unwrap();
+        session.set_allow_duplicate_nodes(true).unwrap();
+        for batch in 0..50 {
+            let nodes: Vec<String> = (0..100)
+                .map(|i| format!("(:Item {{n: {}}})", batch * 100 + i))
+                .collect();
+            session
+                .execute(&format!("INSERT {}", nodes.join(", ")))
+                .unwrap();
+        }
+
+        let reports = Arc::new(Mutex::new(Vec::new()));
+        let sink = reports.clone();
+        let result = session
+            .query_with_progress("MATCH (i:Item) RETURN i.n", move |rows| {
+                sink.lock().unwrap().push(rows)
+            })
+            .unwrap();
+        assert_eq!(result.rows.len(), 5000);
+
+        let reports = reports.lock().unwrap();
+        assert!(reports.len() >= 5, "too few reports: {:?}", reports);
+        assert!(reports.windows(2).all(|pair| pair[0] < pair[1]));
+        assert!(reports.iter().all(|&rows| rows <= 5000));
     }
 }
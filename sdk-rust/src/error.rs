@@ -1,5 +1,6 @@
 //! Error types for the GraphLite SDK
 
+use graphlite::{QueryCoordinator, QueryErrorKind};
 use thiserror::Error;
 
 /// Result type alias for SDK operations
@@ -20,6 +21,16 @@ pub enum Error {
     #[error("Query error: {0}")]
     Query(String),
 
+    /// The query text could not be parsed
+    ///
+    /// `line` and `column` are 1-based and point at the offending token.
+    #[error("{message} (line {line}, column {column})")]
+    Parse {
+        message: String,
+        line: usize,
+        column: usize,
+    },
+
     /// Transaction errors
     #[error("Transaction error: {0}")]
     Transaction(String),
@@ -36,6 +47,10 @@ pub enum Error {
     #[error("Invalid operation: {0}")]
     InvalidOperation(String),
 
+    /// An argument was rejected before any query was built from it
+    #[error("Validation error: {0}")]
+    Validation(String),
+
     /// Resource not found errors
     #[error("Not found: {0}")]
     NotFound(String),
@@ -44,6 +59,50 @@ pub enum Error {
     #[error("Connection error: {0}")]
     Connection(String),
 
+    /// The open transaction was idle past the timeout and has been rolled back
+    #[error("Transaction timed out: {0}")]
+    TransactionTimedOut(String),
+
+    /// The query returned more rows than the session's result-size limit or
+    /// the row limit it ran under
+    #[error("Result too large: {0}")]
+    ResultTooLarge(String),
+
+    /// A sandboxed query produced more data than its memory limit
+    #[error("Memory limit exceeded: {0}")]
+    MemoryLimitExceeded(String),
+
+    /// A sandboxed query ran longer than its time limit
+    #[error("Query timed out: {0}")]
+    QueryTimedOut(String),
+
+    /// A read-only sandboxed query tried to write data or change the catalog
+    #[error("Read-only violation: {0}")]
+    ReadOnlyViolation(String),
+
+    /// The operation collided with a concurrent one (deadlock, write conflict
+    /// or a busy lock)
+    #[error("Conflict: {0}")]
+    Conflict(String),
+
+    /// A graph lock could not be acquired before the timeout
+    #[error("Lock timeout: {0}")]
+    LockTimeout(String),
+
+    /// The query was rejected because the concurrency limit and its queue
+    /// were both full
+    #[error("Overloaded: {0}")]
+    Overloaded(String),
+
+    /// The session's user ran more queries than their rate limit allows
+    #[error("Rate limited: {0}")]
+    RateLimited(String),
+
+    /// The statement needs a graph, but none was chosen with
+    /// `SESSION SET GRAPH` and the query names none
+    #[error("No current graph: {hint}")]
+    NoCurrentGraph { hint: String },
+
     /// I/O errors
     #[error("I/O error: {0}")]
     Io(#[from] std::io::Error),
@@ -60,3 +119,78 @@ impl From<&str> for Error {
         Error::GraphLite(s.to_string())
     }
 }
+
+impl Error {
+    /// Wrap an error from the database, giving failures of a kind that has a
+    /// dedicated variant that variant
+    pub(crate) fn from_engine(
+        kind: &QueryErrorKind,
+        message: String,
+        wrap: fn(String) -> Error,
+    ) -> Error {
+        match kind {
+            QueryErrorKind::TransactionTimedOut => Error::TransactionTimedOut(message),
+            QueryErrorKind::ResultTooLarge => Error::ResultTooLarge(message),
+            QueryErrorKind::MemoryLimitExceeded => Error::MemoryLimitExceeded(message),
+            QueryErrorKind::QueryTimedOut => Error::QueryTimedOut(message),
+            QueryErrorKind::ReadOnlyViolation => Error::ReadOnlyViolation(message),
+            QueryErrorKind::Conflict => Error::Conflict(message),
+            QueryErrorKind::LockTimeout => Error::LockTimeout(message),
+            QueryErrorKind::Overloaded => Error::Overloaded(message),
+            QueryErrorKind::RateLimited => Error::RateLimited(message),
+            QueryErrorKind::SessionNotFound => Error::Session(message),
+            QueryErrorKind::NoCurrentGraph { hint } => Error::NoCurrentGraph { hint: hint.clone() },
+            _ => wrap(message),
+        }
+    }
+
+    /// Whether retrying the failed operation may succeed
+    ///
+    /// True for transient conditions: a conflict with a concurrent operation,
+    /// a lock wait that timed out, a query rejected under overload or by a
+    /// rate limit, a transaction rolled back after timing out, or an
+    /// interrupted or timed out I/O call. Logic errors such as parse, constraint or type errors are
+    /// not retryable, as the same request fails the same way again.
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            Error::Conflict(_)
+            | Error::LockTimeout(_)
+            | Error::Overloaded(_)
+            | Error::RateLimited(_)
+            | Error::TransactionTimedOut(_) => true,
+            Error::Io(e) => matches!(
+                e.kind(),
+                std::io::ErrorKind::Interrupted
+                    | std::io::ErrorKind::TimedOut
+                    | std::io::ErrorKind::WouldBlock
+            ),
+            _ => false,
+        }
+    }
+
+    /// Like [`Error::from_engine`], but reports a parse failure of `query` as
+    /// [`Error::Parse`] with the position of the offending token
+    pub(crate) fn from_query(
+        coordinator: &QueryCoordinator,
+        query: &str,
+        kind: &QueryErrorKind,
+        message: String,
+        wrap: fn(String) -> Error,
+    ) -> Error {
+        if *kind == QueryErrorKind::Parse {
+            let located = coordinator.validate(query).into_iter().find_map(|error| {
+                error
+                    .location
+                    .map(|location| (error.message, location.line, location.column))
+            });
+            if let Some((message, line, column)) = located {
+                return Error::Parse {
+                    message,
+                    line,
+                    column,
+                };
+            }
+        }
+        Error::from_engine(kind, message, wrap)
+    }
+}
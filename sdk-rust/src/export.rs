@@ -0,0 +1,219 @@
+//! Writing query results to files and sockets as CSV or JSON
+//!
+//! [`Session::query_to_writer`](crate::Session::query_to_writer) formats rows
+//! one at a time straight into an [`std::io::Write`], releasing each row once
+//! it is written. No formatted copy of the whole result is built, so memory
+//! stays flat however large the export is.
+
+use crate::error::Result;
+use graphlite::{QueryResult, Value};
+use std::io::{BufWriter, Write};
+
+/// Output format for [`Session::query_to_writer`](crate::Session::query_to_writer)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutputFormat {
+    /// RFC 4180 CSV with a header row of column names
+    ///
+    /// Fields containing commas, quotes or line breaks are quoted, with
+    /// embedded quotes doubled. NULL is written as an empty field.
+    Csv,
+    /// A JSON array with one object per row, keys in column order
+    Json,
+}
+
+/// Write every row of `result` to `writer`, returning the number of rows written
+pub(crate) fn write_result<W: Write>(
+    result: QueryResult,
+    writer: &mut W,
+    format: OutputFormat,
+) -> Result<usize> {
+    let mut out = BufWriter::new(writer);
+    let columns = result.variables;
+    let mut written = 0;
+
+    match format {
+        OutputFormat::Csv => {
+            write_csv_record(&mut out, columns.iter().map(String::as_str))?;
+            for row in result.rows {
+                let fields = columns.iter().map(|column| match row.values.get(column) {
+                    None | Some(Value::Null) => String::new(),
+                    Some(Value::String(s)) => s.clone(),
+                    Some(value) => value.to_string(),
+                });
+                write_csv_record(&mut out, fields)?;
+                written += 1;
+            }
+        }
+        OutputFormat::Json => {
+            out.write_all(b"[")?;
+            for row in result.rows {
+                if written > 0 {
+                    out.write_all(b",")?;
+                }
+                out.write_all(b"\n{")?;
+                for (i, column) in columns.iter().enumerate() {
+                    if i > 0 {
+                        out.write_all(b",")?;
+                    }
+                    let value = row
+                        .values
+                        .get(column)
+                        .map_or(serde_json::Value::Null, to_json);
+                    serde_json::to_writer(&mut out, column)?;
+                    out.write_all(b":")?;
+                    serde_json::to_writer(&mut out, &value)?;
+                }
+                out.write_all(b"}")?;
+                written += 1;
+            }
+            out.write_all(b"\n]\n")?;
+        }
+    }
+
+    out.flush()?;
+    Ok(written)
+}
+
+/// Write one CSV line, quoting fields that need it
+fn write_csv_record<W, I, S>(out: &mut W, fields: I) -> Result<()>
+where
+    W: Write,
+    I: IntoIterator<Item = S>,
+    S: AsRef<str>,
+{
+    for (i, field) in fields.into_iter().enumerate() {
+        if i > 0 {
+            out.write_all(b",")?;
+        }
+        let field = field.as_ref();
+        if field.contains([',', '"', '\n', '\r']) {
+            out.write_all(b"\"")?;
+            out.write_all(field.replace('"', "\"\"").as_bytes())?;
+            out.write_all(b"\"")?;
+        } else {
+            out.write_all(field.as_bytes())?;
+        }
+    }
+    out.write_all(b"\n")?;
+    Ok(())
+}
+
+/// Convert a value to JSON; values without a JSON counterpart become strings
+fn to_json(value: &Value) -> serde_json::Value {
+    match value {
+        Value::Null => serde_json::Value::Null,
+        Value::Boolean(b) => serde_json::Value::Bool(*b),
+        Value::Number(n) => serde_json::Number::from_f64(*n)
+            .map(serde_json::Value::Number)
+            .unwrap_or(serde_json::Value::Null),
+        Value::String(s) => serde_json::Value::String(s.clone()),
+        Value::List(items) | Value::Array(items) => {
+            serde_json::Value::Array(items.iter().map(to_json).collect())
+        }
+        Value::Map(map) => serde_json::Value::Object(
+            map.iter()
+                .map(|(key, value)| (key.clone(), to_json(value)))
+                .collect(),
+        ),
+        other => serde_json::Value::String(other.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::GraphLite;
+
+    /// Minimal RFC 4180 reader for checking the writer's output
+    fn parse_csv(text: &str) -> Vec<Vec<String>> {
+        let mut records = Vec::new();
+        let mut record = Vec::new();
+        let mut field = String::new();
+        let mut in_quotes = false;
+        let mut chars = text.chars().peekable();
+        while let Some(c) = chars.next() {
+            match (c, in_quotes) {
+                ('"', true) if chars.peek() == Some(&'"') => {
+                    field.push('"');
+                    chars.next();
+                }
+                ('"', _) => in_quotes = !in_quotes,
+                (',', false) => record.push(std::mem::take(&mut field)),
+                ('\n', false) => {
+                    record.push(std::mem::take(&mut field));
+                    records.push(std::mem::take(&mut record));
+                }
+                (c, _) => field.push(c),
+            }
+        }
+        records
+    }
+
+    #[test]
+    fn test_query_to_writer_streams_quoted_csv_and_json() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let db = GraphLite::open(temp_dir.path().join("export_db")).unwrap();
+        let session = db.session("admin").unwrap();
+        session
+            .execute("CREATE SCHEMA IF NOT EXISTS /export")
+            .unwrap();
+        session
+            .execute("CREATE GRAPH IF NOT EXISTS /export/items")
+            .unwrap();
+        session.execute("SESSION SET GRAPH /export/items").unwrap();
+
+        let names = [
+            "plain",
+            "with, comma",
+            "with \"quotes\"",
+            "multi\nline",
+            "all, \"of\"\nthem",
+        ];
+        for batch in 0..10 {
+            let patterns = (0..10)
+                .map(|i| {
+                    let id = batch * 10 + i;
+                    format!(
+                        "(:Item {{id: {}, name: '{}'}})",
+                        id,
+                        names[id % names.len()]
+                    )
+                })
+                .collect::<Vec<_>>()
+                .join(", ");
+            session.execute(&format!("INSERT {}", patterns)).unwrap();
+        }
+
+        // A cross product gives a result of 10,000 rows
+        let query = "MATCH (a:Item), (b:Item) \
+                     RETURN a.id AS id, b.id AS item, b.name AS name, b.missing AS missing \
+                     ORDER BY id, item";
+        let expected = session.query(query).unwrap();
+
+        let mut csv = Vec::new();
+        let written = session
+            .query_to_writer(query, &mut csv, OutputFormat::Csv)
+            .unwrap();
+        assert_eq!(written, 10_000);
+
+        let records = parse_csv(std::str::from_utf8(&csv).unwrap());
+        assert_eq!(records[0], vec!["id", "item", "name", "missing"]);
+        assert_eq!(records.len(), expected.rows.len() + 1);
+        for (record, row) in records[1..].iter().zip(&expected.rows) {
+            assert_eq!(record[0], row.values["id"].to_string());
+            assert_eq!(record[1], row.values["item"].to_string());
+            assert_eq!(Value::String(record[2].clone()), row.values["name"]);
+            assert_eq!(record[3], "");
+        }
+        assert_eq!(records[5][2], "all, \"of\"\nthem");
+
+        let mut json = Vec::new();
+        session
+            .query_to_writer(query, &mut json, OutputFormat::Json)
+            .unwrap();
+        let parsed: Vec<serde_json::Value> = serde_json::from_slice(&json).unwrap();
+        assert_eq!(parsed.len(), 10_000);
+        assert_eq!(parsed[0]["missing"], serde_json::Value::Null);
+        assert!(parsed[0]["name"].is_string());
+    }
+}
@@ -66,21 +66,33 @@
 //! - [`query`] - Query builder and execution
 //! - [`transaction`] - Transaction support
 //! - [`result`] - Result handling and deserialization
+//! - [`pagination`] - Cursor-based paging over large results
+//! - [`export`] - Writing results as CSV or JSON
 //! - [`error`] - Error types and handling
 
 // Re-export core types for convenience
-pub use graphlite::{QueryInfo, QueryPlan, QueryResult, QueryType, Row, Value};
+pub use graphlite::{
+    normalize_query, AnalyzedNode, AnalyzedQueryPlan, ChangeEvent, ChangeKind, CompactionReport,
+    ComponentHealth, DryRunReport, DurationValue, EntityKind, EstimatedNode, FlushPolicy,
+    GraphLockGuard, HealthReport, HealthStatus, Location, LockMode, QueryInfo, QueryLimits,
+    QueryMetrics, QueryPlan, QueryResult, QueryType, Row, SlowQuery, SubscriptionId,
+    ValidationError, ValidationErrorType, Value,
+};
 
 // SDK modules
 pub mod connection;
 pub mod error;
+pub mod export;
+pub mod pagination;
 pub mod query;
 pub mod result;
 pub mod transaction;
 
 // Re-export main types for convenience
-pub use connection::{GraphLite, Session};
+pub use connection::{EdgeBatchReport, FailedEdge, GraphLite, NodeKey, OpenOptions, Session};
 pub use error::{Error, Result};
-pub use query::QueryBuilder;
-pub use result::TypedResult;
-pub use transaction::Transaction;
+pub use export::OutputFormat;
+pub use pagination::ResultPage;
+pub use query::{ParameterizedQuery, QueryBuilder};
+pub use result::{TypedResult, TypedRow, ValueType};
+pub use transaction::{BatchResult, Transaction};
@@ -0,0 +1,226 @@
+//! Cursor-based pagination over query results
+//!
+//! A page request runs the query with the page pushed into it as SKIP and
+//! LIMIT, and returns that page's rows together with an opaque cursor for
+//! the next page. The cursor is a random id that refers to the query and its
+//! position, which the session that issued it keeps; it carries no query
+//! text, so a client cannot alter what a resumed page runs. It can be handed
+//! to a client and sent back later, e.g. across HTTP requests, and is only
+//! valid on the session that issued it.
+//!
+//! Pages are cut from a stable ordering of the result:
+//! - Queries with ORDER BY keep their order. Include a unique key in the
+//!   ORDER BY so that ties cannot swap places between pages.
+//! - Queries without ORDER BY scan in a reproducible order while paged.
+//!
+//! Each page is read from the data as it is when the page is requested, so
+//! rows added or removed between pages shift later pages.
+
+use crate::error::{Error, Result};
+use graphlite::Row;
+use std::collections::{HashMap, VecDeque};
+use std::sync::Mutex;
+
+/// Most cursors a session keeps; issuing more forgets the oldest
+const MAX_OPEN_CURSORS: usize = 1024;
+
+/// One page of a paginated query result
+#[derive(Debug, Clone)]
+pub struct ResultPage {
+    /// Rows on this page
+    pub rows: Vec<Row>,
+    /// Column names from the RETURN clause
+    pub variables: Vec<String>,
+    /// Cursor for the next page, or None if this is the last page
+    pub next_cursor: Option<String>,
+}
+
+impl ResultPage {
+    /// Whether more pages follow this one
+    pub fn has_more(&self) -> bool {
+        self.next_cursor.is_some()
+    }
+}
+
+/// Position in a paginated result, as referred to by a cursor id
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Cursor {
+    pub query: String,
+    pub page_size: usize,
+    pub offset: usize,
+}
+
+/// Cursors a session has issued, by id
+#[derive(Debug, Default)]
+pub(crate) struct CursorStore {
+    open: Mutex<OpenCursors>,
+}
+
+#[derive(Debug, Default)]
+struct OpenCursors {
+    cursors: HashMap<String, Cursor>,
+    /// Ids in the order they were issued, for forgetting the oldest
+    issued: VecDeque<String>,
+}
+
+impl CursorStore {
+    /// Keep a cursor and return the id that refers to it
+    pub fn issue(&self, cursor: Cursor) -> String {
+        let id = uuid::Uuid::new_v4().simple().to_string();
+        let mut open = self
+            .open
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        open.cursors.insert(id.clone(), cursor);
+        open.issued.push_back(id.clone());
+        if open.issued.len() > MAX_OPEN_CURSORS {
+            if let Some(oldest) = open.issued.pop_front() {
+                open.cursors.remove(&oldest);
+            }
+        }
+        id
+    }
+
+    /// Look up the cursor an id refers to
+    pub fn get(&self, id: &str) -> Result<Cursor> {
+        self.open
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .cursors
+            .get(id)
+            .cloned()
+            .ok_or_else(|| Error::InvalidOperation("Invalid pagination cursor".to_string()))
+    }
+
+    /// Forget a cursor once the page after it has been read
+    pub fn remove(&self, id: &str) {
+        self.open
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .cursors
+            .remove(id);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::GraphLite;
+    use graphlite::Value;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_cursor_store_issues_opaque_ids() {
+        let store = CursorStore::default();
+        let cursor = Cursor {
+            query: "MATCH (n) RETURN n.name".to_string(),
+            page_size: 10,
+            offset: 20,
+        };
+        let id = store.issue(cursor.clone());
+        assert!(!id.contains("MATCH"));
+        assert_eq!(store.get(&id).unwrap(), cursor);
+
+        store.remove(&id);
+        assert!(store.get(&id).is_err());
+        assert!(store.get("not a cursor").is_err());
+
+        // The oldest cursors are forgotten once too many are open
+        let ids: Vec<String> = (0..=MAX_OPEN_CURSORS)
+            .map(|offset| {
+                store.issue(Cursor {
+                    offset,
+                    ..cursor.clone()
+                })
+            })
+            .collect();
+        assert!(store.get(&ids[0]).is_err());
+        assert_eq!(
+            store.get(&ids[MAX_OPEN_CURSORS]).unwrap().offset,
+            MAX_OPEN_CURSORS
+        );
+    }
+
+    #[test]
+    fn test_paginate_without_gaps_or_duplicates() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let db = GraphLite::open(temp_dir.path().join("paged_db")).unwrap();
+        let session = db.session("admin").unwrap();
+        session
+            .execute("CREATE SCHEMA IF NOT EXISTS /paging")
+            .unwrap();
+        session
+            .execute("CREATE GRAPH IF NOT EXISTS /paging/items")
+            .unwrap();
+        session.execute("SESSION SET GRAPH /paging/items").unwrap();
+        for i in 0..25 {
+            session
+                .execute(&format!("INSERT (:Item {{n: {}}})", i))
+                .unwrap();
+        }
+
+        for query in [
+            "MATCH (i:Item) RETURN i.n AS n ORDER BY i.n",
+            "MATCH (i:Item) RETURN i.n AS n",
+        ] {
+            let mut pages = vec![session.query_paged(query, 10).unwrap()];
+            while let Some(cursor) = pages.last().unwrap().next_cursor.clone() {
+                pages.push(session.query_resume(&cursor).unwrap());
+            }
+
+            let sizes: Vec<usize> = pages.iter().map(|page| page.rows.len()).collect();
+            assert_eq!(sizes, vec![10, 10, 5], "page sizes for {}", query);
+
+            let seen: HashSet<i64> = pages
+                .iter()
+                .flat_map(|page| &page.rows)
+                .map(|row| match row.values.get("n") {
+                    Some(Value::Number(n)) => *n as i64,
+                    other => panic!("Expected number, got {:?}", other),
+                })
+                .collect();
+            assert_eq!(
+                seen,
+                (0..25).collect::<HashSet<i64>>(),
+                "rows for {}",
+                query
+            );
+        }
+
+        // Ordered queries keep their order across pages
+        let first = session
+            .query_paged("MATCH (i:Item) RETURN i.n AS n ORDER BY i.n DESC", 10)
+            .unwrap();
+        assert_eq!(first.rows[0].values.get("n"), Some(&Value::Number(24.0)));
+        let second = session
+            .query_resume(first.next_cursor.as_ref().unwrap())
+            .unwrap();
+        assert_eq!(second.rows[0].values.get("n"), Some(&Value::Number(14.0)));
+
+        // A LIMIT in the query still caps the pages
+        let first = session
+            .query_paged(
+                "MATCH (i:Item) RETURN i.n AS n ORDER BY i.n LIMIT 15 OFFSET 2",
+                10,
+            )
+            .unwrap();
+        assert_eq!(first.rows[0].values.get("n"), Some(&Value::Number(2.0)));
+        let last = session
+            .query_resume(first.next_cursor.as_ref().unwrap())
+            .unwrap();
+        assert_eq!(last.rows.len(), 5);
+        assert_eq!(last.rows[4].values.get("n"), Some(&Value::Number(16.0)));
+        assert!(!last.has_more());
+
+        // Cursors are only valid on the session that issued them
+        let cursor = second.next_cursor.clone().unwrap();
+        let other = db.session("admin").unwrap();
+        assert!(other.query_resume(&cursor).is_err());
+        assert!(session.query_resume(&format!("{}0", cursor)).is_err());
+        assert_eq!(session.query_resume(&cursor).unwrap().rows.len(), 5);
+
+        // Mutations cannot be paginated, since each page re-runs the query
+        assert!(session.query_paged("INSERT (:Item {n: 99})", 10).is_err());
+        assert!(session.query_paged("MATCH (i:Item) RETURN i", 0).is_err());
+    }
+}
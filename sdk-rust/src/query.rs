@@ -1,11 +1,22 @@
 //! Query builder for fluent GQL query construction
 //!
 //! This module provides a builder API for constructing GQL queries in a
-//! type-safe and ergonomic way.
+//! type-safe and ergonomic way. Values given to the builder are bound as
+//! `$name` parameters rather than spliced into the query text.
 
-use crate::connection::Session;
+use crate::connection::{quote_identifier, Session};
 use crate::error::{Error, Result};
-use graphlite::QueryResult;
+use graphlite::{QueryResult, Value};
+use std::collections::HashMap;
+
+/// A built query together with the values of its parameters
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParameterizedQuery {
+    /// GQL text, referring to bound values as `$name`
+    pub query: String,
+    /// Parameter values by name, without the `$`
+    pub parameters: HashMap<String, Value>,
+}
 
 /// Fluent API for building GQL queries
 ///
@@ -15,7 +26,7 @@ use graphlite::QueryResult;
 /// # Examples
 ///
 /// ```no_run
-/// # use graphlite_sdk::GraphLite;
+/// # use graphlite_sdk::{GraphLite, QueryBuilder};
 /// # let db = GraphLite::open("./mydb")?;
 /// # let session = db.session("admin")?;
 /// // Using the builder
@@ -27,10 +38,17 @@ use graphlite::QueryResult;
 ///
 /// // Equivalent to:
 /// // "MATCH (p:Person) WHERE p.age > 25 RETURN p.name, p.age"
+///
+/// // Binding values as parameters, without a session
+/// let query = QueryBuilder::match_node("p", "Person")
+///     .where_eq("age", 30)
+///     .return_field("p.name")
+///     .build()?;
+/// let result = session.query_with_parameters(&query.query, &query.parameters)?;
 /// # Ok::<(), graphlite_sdk::Error>(())
 /// ```
 pub struct QueryBuilder<'session> {
-    session: &'session Session,
+    session: Option<&'session Session>,
     match_patterns: Vec<String>,
     where_clauses: Vec<String>,
     with_clauses: Vec<String>,
@@ -38,13 +56,16 @@ pub struct QueryBuilder<'session> {
     order_by: Option<String>,
     skip: Option<usize>,
     limit: Option<usize>,
+    parameters: HashMap<String, Value>,
+    /// Variable of the last node matched with match_node
+    node_variable: Option<String>,
 }
 
 impl<'session> QueryBuilder<'session> {
     /// Create a new query builder
     pub(crate) fn new(session: &'session Session) -> Self {
         QueryBuilder {
-            session,
+            session: Some(session),
             match_patterns: Vec::new(),
             where_clauses: Vec::new(),
             with_clauses: Vec::new(),
@@ -52,6 +73,48 @@ impl<'session> QueryBuilder<'session> {
             order_by: None,
             skip: None,
             limit: None,
+            parameters: HashMap::new(),
+            node_variable: None,
+        }
+    }
+
+    /// Start a query matching the nodes with a label
+    ///
+    /// The builder is not tied to a session: build it, then run the result
+    /// with [`Session::query_with_parameters`].
+    ///
+    /// # Arguments
+    ///
+    /// * `variable` - Variable the nodes are bound to
+    /// * `label` - Label the nodes must have
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use graphlite_sdk::QueryBuilder;
+    /// let query = QueryBuilder::match_node("p", "Person")
+    ///     .return_field("p.name")
+    ///     .build()?;
+    ///
+    /// assert_eq!(query.query, "MATCH (p:Person) RETURN p.name");
+    /// # Ok::<(), graphlite_sdk::Error>(())
+    /// ```
+    pub fn match_node(variable: &str, label: &str) -> QueryBuilder<'static> {
+        QueryBuilder {
+            session: None,
+            match_patterns: vec![format!(
+                "({}:{})",
+                quote_identifier(variable),
+                quote_identifier(label)
+            )],
+            where_clauses: Vec::new(),
+            with_clauses: Vec::new(),
+            return_clause: None,
+            order_by: None,
+            skip: None,
+            limit: None,
+            parameters: HashMap::new(),
+            node_variable: Some(variable.to_string()),
         }
     }
 
@@ -106,6 +169,63 @@ impl<'session> QueryBuilder<'session> {
         self
     }
 
+    /// Require a property to equal a value
+    ///
+    /// The value is bound as a parameter named after the variable and the
+    /// property. A property without a `variable.` prefix belongs to the node
+    /// from [`match_node`](Self::match_node). Conditions are AND'ed together.
+    ///
+    /// # Arguments
+    ///
+    /// * `property` - Property name, optionally prefixed with its variable
+    /// * `value` - Value the property must equal
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use graphlite_sdk::{QueryBuilder, Value};
+    /// let query = QueryBuilder::match_node("p", "Person")
+    ///     .where_eq("age", 30)
+    ///     .return_field("p.name")
+    ///     .build()?;
+    ///
+    /// assert_eq!(
+    ///     query.query,
+    ///     "MATCH (p:Person) WHERE p.age = $p_age RETURN p.name"
+    /// );
+    /// assert_eq!(query.parameters["p_age"], Value::Number(30.0));
+    /// # Ok::<(), graphlite_sdk::Error>(())
+    /// ```
+    pub fn where_eq(mut self, property: &str, value: impl Into<Value>) -> Self {
+        let (variable, property) = match property.split_once('.') {
+            Some((variable, property)) => (Some(variable.to_string()), property),
+            None => (self.node_variable.clone(), property),
+        };
+
+        let mut target = quote_identifier(property);
+        let mut base_name: String = property.to_string();
+        if let Some(variable) = variable {
+            target = format!("{}.{}", quote_identifier(&variable), target);
+            base_name = format!("{}_{}", variable, base_name);
+        }
+        let base_name: String = base_name
+            .chars()
+            .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+            .collect();
+
+        // A property compared twice gets a numbered second parameter
+        let mut name = base_name.clone();
+        let mut suffix = 2;
+        while self.parameters.contains_key(&name) {
+            name = format!("{}_{}", base_name, suffix);
+            suffix += 1;
+        }
+
+        self.where_clauses.push(format!("{} = ${}", target, name));
+        self.parameters.insert(name, value.into());
+        self
+    }
+
     /// Add a WITH clause
     ///
     /// WITH clauses are used for query chaining and intermediate results.
@@ -156,6 +276,34 @@ impl<'session> QueryBuilder<'session> {
         self
     }
 
+    /// Add an item to the RETURN clause
+    ///
+    /// Can be called multiple times; items are returned in call order.
+    ///
+    /// # Arguments
+    ///
+    /// * `field` - Expression to return, such as `p.name`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use graphlite_sdk::QueryBuilder;
+    /// let query = QueryBuilder::match_node("p", "Person")
+    ///     .return_field("p.name")
+    ///     .return_field("p.age")
+    ///     .build()?;
+    ///
+    /// assert_eq!(query.query, "MATCH (p:Person) RETURN p.name, p.age");
+    /// # Ok::<(), graphlite_sdk::Error>(())
+    /// ```
+    pub fn return_field(mut self, field: &str) -> Self {
+        self.return_clause = Some(match self.return_clause {
+            Some(clause) => format!("{}, {}", clause, field),
+            None => field.to_string(),
+        });
+        self
+    }
+
     /// Set the ORDER BY clause
     ///
     /// # Arguments
@@ -229,9 +377,10 @@ impl<'session> QueryBuilder<'session> {
         self
     }
 
-    /// Build the query string without executing
+    /// Build the query without executing
     ///
-    /// Returns the constructed GQL query as a string.
+    /// Returns the constructed GQL query with the values bound to its
+    /// parameters.
     ///
     /// # Examples
     ///
@@ -245,10 +394,11 @@ impl<'session> QueryBuilder<'session> {
     ///     .return_clause("p.name")
     ///     .build()?;
     ///
-    /// assert_eq!(query, "MATCH (p:Person) WHERE p.age > 25 RETURN p.name");
+    /// assert_eq!(query.query, "MATCH (p:Person) WHERE p.age > 25 RETURN p.name");
+    /// assert!(query.parameters.is_empty());
     /// # Ok::<(), graphlite_sdk::Error>(())
     /// ```
-    pub fn build(&self) -> Result<String> {
+    pub fn build(&self) -> Result<ParameterizedQuery> {
         let mut query = String::new();
 
         // MATCH clauses
@@ -300,12 +450,18 @@ impl<'session> QueryBuilder<'session> {
             query.push_str(&format!(" LIMIT {}", limit));
         }
 
-        Ok(query.trim().to_string())
+        Ok(ParameterizedQuery {
+            query: query.trim().to_string(),
+            parameters: self.parameters.clone(),
+        })
     }
 
     /// Execute the query and return results
     ///
-    /// Builds and executes the query in one step.
+    /// Builds and executes the query in one step on the builder's session.
+    /// Builders started with [`match_node`](Self::match_node) have no
+    /// session; run their built query with
+    /// [`Session::query_with_parameters`] instead.
     ///
     /// # Examples
     ///
@@ -326,8 +482,17 @@ impl<'session> QueryBuilder<'session> {
     /// # Ok::<(), graphlite_sdk::Error>(())
     /// ```
     pub fn execute(&self) -> Result<QueryResult> {
+        let session = self.session.ok_or_else(|| {
+            Error::InvalidOperation(
+                "Query builder has no session; use Session::query_with_parameters".to_string(),
+            )
+        })?;
         let query = self.build()?;
-        self.session.query(&query)
+        if query.parameters.is_empty() {
+            session.query(&query.query)
+        } else {
+            session.query_with_parameters(&query.query, &query.parameters)
+        }
     }
 }
 
@@ -355,6 +520,8 @@ impl Session {
 
 #[cfg(test)]
 mod tests {
+    use super::*;
+    use crate::GraphLite;
 
     // Note: These are unit tests that test query building logic
     // Integration tests would require a real database
@@ -363,4 +530,105 @@ mod tests {
     fn test_query_builder_types_compile() {
         // Compilation test
     }
+
+    #[test]
+    fn test_fluent_builder_binds_parameters() {
+        let query = QueryBuilder::match_node("p", "Person")
+            .where_eq("age", 30)
+            .where_eq("p.name", "Alice")
+            .where_eq("age", 31)
+            .return_field("p.name")
+            .return_field("p.age")
+            .build()
+            .unwrap();
+        assert_eq!(
+            query.query,
+            "MATCH (p:Person) WHERE p.age = $p_age AND p.name = $p_name \
+             AND p.age = $p_age_2 RETURN p.name, p.age"
+        );
+        assert_eq!(
+            query.parameters,
+            HashMap::from([
+                ("p_age".to_string(), Value::Number(30.0)),
+                ("p_name".to_string(), Value::String("Alice".to_string())),
+                ("p_age_2".to_string(), Value::Number(31.0)),
+            ])
+        );
+
+        // Quoting keeps a value with quotes out of the query text
+        let query = QueryBuilder::match_node("p", "Person")
+            .where_eq("name", "O'Brien")
+            .return_field("p.name")
+            .build()
+            .unwrap();
+        assert_eq!(
+            query.query,
+            "MATCH (p:Person) WHERE p.name = $p_name RETURN p.name"
+        );
+
+        assert!(matches!(
+            QueryBuilder::match_node("p", "Person").build(),
+            Err(Error::InvalidOperation(_))
+        ));
+        assert!(matches!(
+            QueryBuilder::match_node("p", "Person")
+                .return_field("p")
+                .execute(),
+            Err(Error::InvalidOperation(_))
+        ));
+    }
+
+    #[test]
+    fn test_fluent_builder_query_executes() {
+        let db = GraphLite::open_in_memory().unwrap();
+        let session = db.session("admin").unwrap();
+        session
+            .execute("CREATE SCHEMA IF NOT EXISTS /builder_schema")
+            .unwrap();
+        session
+            .execute("CREATE GRAPH IF NOT EXISTS /builder_schema/builder_graph")
+            .unwrap();
+        session
+            .execute("SESSION SET GRAPH /builder_schema/builder_graph")
+            .unwrap();
+        session
+            .execute(
+                "INSERT (:Person {name: 'Alice', age: 30}), (:Person {name: 'Bob', age: 25}), \
+                 (:Person {name: 'Carol', age: 30})",
+            )
+            .unwrap();
+
+        let query = QueryBuilder::match_node("p", "Person")
+            .where_eq("age", 30)
+            .return_field("p.name")
+            .order_by("p.name")
+            .build()
+            .unwrap();
+        let result = session
+            .query_with_parameters(&query.query, &query.parameters)
+            .unwrap();
+        let names: Vec<&Value> = result
+            .rows
+            .iter()
+            .map(|row| &row.values["p.name"])
+            .collect();
+        assert_eq!(
+            names,
+            vec![
+                &Value::String("Alice".to_string()),
+                &Value::String("Carol".to_string())
+            ]
+        );
+
+        // A session's builder binds the parameters when it executes
+        let result = session
+            .query_builder()
+            .match_pattern("(p:Person)")
+            .where_eq("p.name", "Bob")
+            .return_field("p.age")
+            .execute()
+            .unwrap();
+        assert_eq!(result.rows.len(), 1);
+        assert_eq!(result.rows[0].values["p.age"], Value::Number(25.0));
+    }
 }
@@ -6,6 +6,8 @@
 use crate::error::{Error, Result};
 use graphlite::{QueryResult, Row, Value};
 use serde::de::DeserializeOwned;
+use std::fmt;
+use std::sync::Arc;
 
 /// Wrapper around QueryResult with additional type-safe methods
 ///
@@ -137,8 +139,13 @@ impl TypedResult {
     /// # }
     /// ```
     pub fn deserialize_row<T: DeserializeOwned>(&self, row: &Row) -> Result<T> {
-        // Convert row.values HashMap to JSON
-        let json_value = serde_json::to_value(&row.values)?;
+        // Convert row.values to a JSON object, with map columns as nested objects
+        let json_value = serde_json::Value::Object(
+            row.values
+                .iter()
+                .map(|(column, value)| (column.clone(), value_to_json(value)))
+                .collect(),
+        );
         let result = serde_json::from_value(json_value)?;
         Ok(result)
     }
@@ -220,6 +227,225 @@ impl TypedResult {
     pub fn rows(&self) -> &[Row] {
         &self.inner.rows
     }
+
+    /// Get each column's name, in RETURN order, with the type of its values
+    ///
+    /// The type is inferred from the values in the result: a column whose
+    /// values have different types reports [`ValueType::Any`], and one that
+    /// is null in some rows reports [`ValueType::Nullable`]. A column with no
+    /// rows to look at is [`ValueType::Any`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use graphlite_sdk::GraphLite;
+    /// # use graphlite_sdk::{TypedResult, ValueType};
+    /// # fn main() -> Result<(), graphlite_sdk::Error> {
+    /// # let db = GraphLite::open("./mydb")?;
+    /// # let session = db.session("admin")?;
+    /// let result = session.query("MATCH (p:Person) RETURN p.name AS name, p.age AS age")?;
+    /// for (column, value_type) in TypedResult::from(result).schema() {
+    ///     println!("{}: {}", column, value_type);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn schema(&self) -> Vec<(String, ValueType)> {
+        self.inner
+            .variables
+            .iter()
+            .map(|column| {
+                let value_type = self
+                    .inner
+                    .rows
+                    .iter()
+                    .map(|row| ValueType::of(row.get_value(column).unwrap_or(&Value::Null)))
+                    .reduce(ValueType::merge)
+                    .unwrap_or(ValueType::Any);
+                (column.clone(), value_type)
+            })
+            .collect()
+    }
+
+    /// Get all rows with their values laid out in RETURN column order
+    ///
+    /// Useful for generic output such as CSV or tables, where columns are
+    /// walked by position rather than looked up by name.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use graphlite_sdk::GraphLite;
+    /// # use graphlite_sdk::TypedResult;
+    /// # fn main() -> Result<(), graphlite_sdk::Error> {
+    /// # let db = GraphLite::open("./mydb")?;
+    /// # let session = db.session("admin")?;
+    /// let result = session.query("MATCH (p:Person) RETURN p.name AS name, p.age AS age")?;
+    /// let typed = TypedResult::from(result);
+    /// for row in typed.typed_rows() {
+    ///     let line: Vec<String> = row.values().iter().map(|v| v.to_string()).collect();
+    ///     println!("{}", line.join(","));
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn typed_rows(&self) -> Vec<TypedRow> {
+        let columns: Arc<[String]> = self.inner.variables.clone().into();
+        self.inner
+            .rows
+            .iter()
+            .map(|row| TypedRow::new(Arc::clone(&columns), row))
+            .collect()
+    }
+}
+
+/// A result row whose values are ordered like the RETURN clause's columns
+///
+/// Rows of one result share the same column list.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TypedRow {
+    columns: Arc<[String]>,
+    values: Vec<Value>,
+}
+
+impl TypedRow {
+    fn new(columns: Arc<[String]>, row: &Row) -> Self {
+        let values = columns
+            .iter()
+            .map(|column| row.get_value(column).cloned().unwrap_or(Value::Null))
+            .collect();
+        TypedRow { columns, values }
+    }
+
+    /// Column names in RETURN order
+    pub fn columns(&self) -> &[String] {
+        &self.columns
+    }
+
+    /// Values in the same order as [`columns`](Self::columns)
+    pub fn values(&self) -> &[Value] {
+        &self.values
+    }
+
+    /// Get a value by column name
+    pub fn get(&self, name: &str) -> Option<&Value> {
+        self.columns
+            .iter()
+            .position(|column| column == name)
+            .map(|index| &self.values[index])
+    }
+
+    /// Get a value by column position
+    pub fn get_index(&self, index: usize) -> Option<&Value> {
+        self.values.get(index)
+    }
+
+    /// Number of columns
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Check if the row has no columns
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Iterate over (column, value) pairs in RETURN order
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &Value)> {
+        self.columns
+            .iter()
+            .map(String::as_str)
+            .zip(self.values.iter())
+    }
+}
+
+/// Type of the values in a result column, as reported by [`TypedResult::schema`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ValueType {
+    String,
+    Number,
+    Boolean,
+    /// Any date-time, with or without a time zone
+    DateTime,
+    Duration,
+    TimeWindow,
+    Temporal,
+    /// A list or array
+    List,
+    Vector,
+    Map,
+    Node,
+    Edge,
+    Path,
+    /// Every value is null
+    Null,
+    /// Values of more than one type, or no values at all
+    Any,
+    /// Values of the inner type, null in some rows
+    Nullable(Box<ValueType>),
+}
+
+impl ValueType {
+    /// The type of a single value
+    pub fn of(value: &Value) -> Self {
+        match value {
+            Value::String(_) => ValueType::String,
+            Value::Number(_) => ValueType::Number,
+            Value::Boolean(_) => ValueType::Boolean,
+            Value::DateTime(_)
+            | Value::DateTimeWithFixedOffset(_)
+            | Value::DateTimeWithNamedTz(_, _) => ValueType::DateTime,
+            Value::Duration(_) => ValueType::Duration,
+            Value::TimeWindow(_) => ValueType::TimeWindow,
+            Value::Temporal(_) => ValueType::Temporal,
+            Value::Array(_) | Value::List(_) => ValueType::List,
+            Value::Vector(_) => ValueType::Vector,
+            Value::Map(_) => ValueType::Map,
+            Value::Node(_) => ValueType::Node,
+            Value::Edge(_) => ValueType::Edge,
+            Value::Path(_) => ValueType::Path,
+            Value::Null => ValueType::Null,
+        }
+    }
+
+    /// Whether the column can hold nulls
+    pub fn is_nullable(&self) -> bool {
+        matches!(self, ValueType::Null | ValueType::Nullable(_))
+    }
+
+    /// The type of a column holding values of both types
+    fn merge(self, other: ValueType) -> ValueType {
+        let nullable = self.is_nullable() || other.is_nullable();
+        let inner = match (self.non_null(), other.non_null()) {
+            (None, None) => return ValueType::Null,
+            (Some(inner), None) | (None, Some(inner)) => inner,
+            (Some(a), Some(b)) if a == b => a,
+            _ => ValueType::Any,
+        };
+        if nullable {
+            ValueType::Nullable(Box::new(inner))
+        } else {
+            inner
+        }
+    }
+
+    /// The type with nullability stripped, or None for an all-null column
+    fn non_null(self) -> Option<ValueType> {
+        match self {
+            ValueType::Null => None,
+            ValueType::Nullable(inner) => Some(*inner),
+            other => Some(other),
+        }
+    }
+}
+
+impl fmt::Display for ValueType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ValueType::Nullable(inner) => write!(f, "{}?", inner),
+            other => write!(f, "{:?}", other),
+        }
+    }
 }
 
 impl From<QueryResult> for TypedResult {
@@ -245,6 +471,12 @@ fn value_to_json(value: &Value) -> serde_json::Value {
             let items: Vec<serde_json::Value> = arr.iter().map(value_to_json).collect();
             serde_json::Value::Array(items)
         }
+        Value::Map(map) => serde_json::Value::Object(
+            map.iter()
+                .map(|(key, value)| (key.clone(), value_to_json(value)))
+                .collect(),
+        ),
+        Value::Duration(d) => serde_json::Value::String(d.to_string()),
         // For complex types like Node, Edge, Path, etc., use serde serialization
         _ => serde_json::to_value(value).unwrap_or(serde_json::Value::Null),
     }
@@ -260,4 +492,147 @@ mod tests {
         let json = value_to_json(&value);
         assert_eq!(json, serde_json::json!(42.0));
     }
+
+    #[test]
+    fn test_typed_rows_follow_return_order() {
+        let db = crate::GraphLite::open_in_memory().unwrap();
+        let session = db.session("admin").unwrap();
+        session
+            .execute("CREATE SCHEMA IF NOT EXISTS /typed_rows")
+            .unwrap();
+        session
+            .execute("CREATE GRAPH IF NOT EXISTS /typed_rows/g")
+            .unwrap();
+        session.execute("SESSION SET GRAPH /typed_rows/g").unwrap();
+        session
+            .execute("INSERT (:T {a: 1, b: 'two', c: true})")
+            .unwrap();
+
+        let result = session
+            .query("MATCH (t:T) RETURN t.a AS a, t.b AS b, t.c AS c")
+            .unwrap();
+        let rows = TypedResult::from(result).typed_rows();
+        assert_eq!(rows.len(), 1);
+        let row = &rows[0];
+        assert_eq!(row.columns(), ["a", "b", "c"]);
+        assert_eq!(row.get_index(1), row.get("b"));
+        assert_eq!(row.get_index(1), Some(&Value::String("two".to_string())));
+        assert_eq!(
+            row.values(),
+            [
+                Value::Number(1.0),
+                Value::String("two".to_string()),
+                Value::Boolean(true)
+            ]
+        );
+        assert_eq!(row.get_index(3), None);
+
+        // Order comes from the RETURN clause, not from the column names
+        let result = session
+            .query("MATCH (t:T) RETURN t.c AS c, t.a AS a, t.b AS b")
+            .unwrap();
+        let rows = TypedResult::from(result).typed_rows();
+        let columns: Vec<&str> = rows[0].iter().map(|(column, _)| column).collect();
+        assert_eq!(columns, ["c", "a", "b"]);
+        assert_eq!(rows[0].get_index(0), Some(&Value::Boolean(true)));
+    }
+
+    #[test]
+    fn test_schema_reports_column_types_in_return_order() {
+        let db = crate::GraphLite::open_in_memory().unwrap();
+        let session = db.session("admin").unwrap();
+        session
+            .execute("CREATE SCHEMA IF NOT EXISTS /schema_rows")
+            .unwrap();
+        session
+            .execute("CREATE GRAPH IF NOT EXISTS /schema_rows/g")
+            .unwrap();
+        session.execute("SESSION SET GRAPH /schema_rows/g").unwrap();
+        session
+            .execute(
+                "INSERT (:Person {name: 'Ada', age: 36, email: 'ada@example.com', tag: 'x'}), \
+                 (:Person {name: 'Alan', age: 41, tag: 7})",
+            )
+            .unwrap();
+
+        let result = session
+            .query("MATCH (p:Person) RETURN p.name AS name, p.age AS age")
+            .unwrap();
+        assert_eq!(
+            TypedResult::from(result).schema(),
+            vec![
+                ("name".to_string(), ValueType::String),
+                ("age".to_string(), ValueType::Number),
+            ]
+        );
+
+        let result = session
+            .query("MATCH (p:Person) RETURN p.email AS email, p.tag AS tag, p.missing AS missing")
+            .unwrap();
+        let schema = TypedResult::from(result).schema();
+        assert_eq!(
+            schema,
+            vec![
+                (
+                    "email".to_string(),
+                    ValueType::Nullable(Box::new(ValueType::String))
+                ),
+                ("tag".to_string(), ValueType::Any),
+                ("missing".to_string(), ValueType::Null),
+            ]
+        );
+        assert!(schema[0].1.is_nullable());
+        assert!(!schema[1].1.is_nullable());
+        assert_eq!(schema[0].1.to_string(), "String?");
+    }
+
+    #[test]
+    fn test_map_columns_deserialize_into_structs() {
+        #[derive(serde::Deserialize, Debug, PartialEq)]
+        struct Profile {
+            name: String,
+        }
+
+        #[derive(serde::Deserialize, Debug, PartialEq)]
+        struct Person {
+            id: f64,
+            tags: Vec<String>,
+            profile: Profile,
+        }
+
+        #[derive(serde::Deserialize)]
+        struct Row {
+            person: Person,
+        }
+
+        let db = crate::GraphLite::open_in_memory().unwrap();
+        let session = db.session("admin").unwrap();
+        session
+            .execute("CREATE SCHEMA IF NOT EXISTS /map_rows")
+            .unwrap();
+        session
+            .execute("CREATE GRAPH IF NOT EXISTS /map_rows/g")
+            .unwrap();
+        session.execute("SESSION SET GRAPH /map_rows/g").unwrap();
+        session
+            .execute("INSERT (:Person {id: 1, name: 'Ada'})")
+            .unwrap();
+
+        let result = session
+            .query(
+                "MATCH (p:Person) \
+                 RETURN {id: p.id, tags: ['a', 'b'], profile: {name: p.name}} AS person",
+            )
+            .unwrap();
+        let typed = TypedResult::from(result);
+        let expected = Person {
+            id: 1.0,
+            tags: vec!["a".to_string(), "b".to_string()],
+            profile: Profile {
+                name: "Ada".to_string(),
+            },
+        };
+        assert_eq!(typed.first::<Row>().unwrap().person, expected);
+        assert_eq!(typed.scalar::<Person>().unwrap(), expected);
+    }
 }
@@ -5,9 +5,10 @@
 //! - RAII ensures no forgotten rollbacks
 //! - Explicit commit() required to persist changes
 
-use crate::connection::Session;
+use crate::connection::{quote_identifier, Session};
 use crate::error::{Error, Result};
-use graphlite::QueryResult;
+use graphlite::{QueryResult, Value};
+use std::collections::HashMap;
 
 /// Represents an active database transaction
 ///
@@ -43,6 +44,15 @@ pub struct Transaction<'conn> {
     drop_behavior: DropBehavior,
 }
 
+/// Results of [`Transaction::execute_many`]
+#[derive(Debug, Clone)]
+pub struct BatchResult {
+    /// One result per parameter set, in order
+    pub results: Vec<QueryResult>,
+    /// Rows affected across all runs
+    pub rows_affected: usize,
+}
+
 /// Behavior when a transaction is dropped
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum DropBehavior {
@@ -65,7 +75,7 @@ impl<'conn> Transaction<'conn> {
         // Execute BEGIN TRANSACTION
         session
             .coordinator()
-            .process_query("BEGIN TRANSACTION", session.id())
+            .process_query("BEGIN TRANSACTION", &session.id())
             .map_err(|e| Error::Transaction(format!("Failed to begin transaction: {}", e)))?;
 
         Ok(Transaction {
@@ -102,8 +112,16 @@ impl<'conn> Transaction<'conn> {
 
         self.session
             .coordinator()
-            .process_query(statement, self.session.id())
-            .map_err(|e| Error::Transaction(format!("Execute failed: {}", e)))?;
+            .process_query(statement, &self.session.id())
+            .map_err(|e| {
+                Error::from_query(
+                    self.session.coordinator(),
+                    statement,
+                    e.kind(),
+                    format!("Execute failed: {}", e),
+                    Error::Transaction,
+                )
+            })?;
 
         Ok(())
     }
@@ -135,8 +153,140 @@ impl<'conn> Transaction<'conn> {
 
         self.session
             .coordinator()
-            .process_query(query, self.session.id())
-            .map_err(|e| Error::Transaction(format!("Query failed: {}", e)))
+            .process_query(query, &self.session.id())
+            .map_err(|e| {
+                Error::from_query(
+                    self.session.coordinator(),
+                    query,
+                    e.kind(),
+                    format!("Query failed: {}", e),
+                    Error::Transaction,
+                )
+            })
+    }
+
+    /// Execute a statement once for each parameter set
+    ///
+    /// The statement is parsed and planned once and then run in a tight loop,
+    /// with each set's values bound to the statement's `$name` parameters for
+    /// its run only. The runs are
+    /// part of this transaction, and the first failing run stops the batch.
+    ///
+    /// # Arguments
+    ///
+    /// * `statement` - GQL statement referring to its parameters as `$name`
+    /// * `parameter_sets` - Parameter values for each run
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use graphlite_sdk::{GraphLite, Value};
+    /// # use std::collections::HashMap;
+    /// # let db = GraphLite::open("./mydb")?;
+    /// # let session = db.session("admin")?;
+    /// let people: Vec<HashMap<String, Value>> = ["Alice", "Bob"]
+    ///     .iter()
+    ///     .map(|name| HashMap::from([("name".to_string(), Value::from(*name))]))
+    ///     .collect();
+    ///
+    /// let mut tx = session.transaction()?;
+    /// let batch = tx.execute_many("INSERT (:Person {name: $name})", &people)?;
+    /// assert_eq!(batch.rows_affected, 2);
+    /// tx.commit()?;
+    /// # Ok::<(), graphlite_sdk::Error>(())
+    /// ```
+    pub fn execute_many(
+        &mut self,
+        statement: &str,
+        parameter_sets: &[HashMap<String, Value>],
+    ) -> Result<BatchResult> {
+        if self.committed {
+            return Err(Error::Transaction(
+                "Transaction already committed".to_string(),
+            ));
+        }
+
+        let results = self
+            .session
+            .coordinator()
+            .execute_many(statement, &self.session.id(), parameter_sets)
+            .map_err(|e| {
+                Error::from_query(
+                    self.session.coordinator(),
+                    statement,
+                    e.kind(),
+                    format!("Execute failed: {}", e),
+                    Error::Transaction,
+                )
+            })?;
+
+        let rows_affected = results.iter().map(|result| result.rows_affected).sum();
+        Ok(BatchResult {
+            results,
+            rows_affected,
+        })
+    }
+
+    /// Rename a property on every node with a label
+    ///
+    /// Copies `from` into `to` and removes `from` in one statement, so the
+    /// rename is undone with the rest of the transaction on rollback. Nodes
+    /// without `from` are left as they are; an existing `to` is overwritten.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use graphlite_sdk::GraphLite;
+    /// # let db = GraphLite::open("./mydb")?;
+    /// # let session = db.session("admin")?;
+    /// let mut tx = session.transaction()?;
+    /// tx.rename_property("Person", "name", "full_name")?;
+    /// tx.commit()?;
+    /// # Ok::<(), graphlite_sdk::Error>(())
+    /// ```
+    pub fn rename_property(&mut self, label: &str, from: &str, to: &str) -> Result<()> {
+        if from == to {
+            return Ok(());
+        }
+        let from = quote_identifier(from);
+        let statement = format!(
+            "MATCH (n:{}) WHERE n.{} IS NOT NULL SET n.{} = n.{} REMOVE n.{}",
+            quote_identifier(label),
+            from,
+            quote_identifier(to),
+            from,
+            from
+        );
+        self.execute(&statement)
+    }
+
+    /// Change the label of every edge labelled `from` to `to`
+    ///
+    /// Edges whose ID is derived from their label get the ID of their new
+    /// label, and the edge label index follows the change. Rolling back the
+    /// transaction restores the original label and ID.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use graphlite_sdk::GraphLite;
+    /// # let db = GraphLite::open("./mydb")?;
+    /// # let session = db.session("admin")?;
+    /// let mut tx = session.transaction()?;
+    /// tx.rename_edge_type("KNOWS", "FRIEND_OF")?;
+    /// tx.commit()?;
+    /// # Ok::<(), graphlite_sdk::Error>(())
+    /// ```
+    pub fn rename_edge_type(&mut self, from: &str, to: &str) -> Result<()> {
+        if from == to {
+            return Ok(());
+        }
+        let statement = format!(
+            "MATCH (a)-[r:{}]->(b) SET r:{}",
+            quote_identifier(from),
+            quote_identifier(to)
+        );
+        self.execute(&statement)
     }
 
     /// Commit the transaction
@@ -214,8 +364,14 @@ impl<'conn> Transaction<'conn> {
 
         self.session
             .coordinator()
-            .process_query("COMMIT", self.session.id())
-            .map_err(|e| Error::Transaction(format!("Failed to commit: {}", e)))?;
+            .process_query("COMMIT", &self.session.id())
+            .map_err(|e| {
+                Error::from_engine(
+                    e.kind(),
+                    format!("Failed to commit: {}", e),
+                    Error::Transaction,
+                )
+            })?;
 
         self.committed = true;
         Ok(())
@@ -229,8 +385,14 @@ impl<'conn> Transaction<'conn> {
 
         self.session
             .coordinator()
-            .process_query("ROLLBACK", self.session.id())
-            .map_err(|e| Error::Transaction(format!("Failed to rollback: {}", e)))?;
+            .process_query("ROLLBACK", &self.session.id())
+            .map_err(|e| {
+                Error::from_engine(
+                    e.kind(),
+                    format!("Failed to rollback: {}", e),
+                    Error::Transaction,
+                )
+            })?;
 
         self.committed = true; // Mark as finished
         Ok(())
@@ -271,10 +433,132 @@ impl<'conn> Drop for Transaction<'conn> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::GraphLite;
+    use std::sync::{Arc, Mutex};
+    use std::time::Duration;
 
     #[test]
     fn test_drop_behavior() {
         assert_eq!(DropBehavior::Rollback, DropBehavior::Rollback);
         assert_ne!(DropBehavior::Rollback, DropBehavior::Commit);
     }
+
+    #[test]
+    fn test_idle_transaction_times_out() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let db = GraphLite::open(temp_dir.path().join("timeout_db")).unwrap();
+        let session = db.session("admin").unwrap();
+        session
+            .execute("CREATE SCHEMA IF NOT EXISTS /timeout")
+            .unwrap();
+        session
+            .execute("CREATE GRAPH IF NOT EXISTS /timeout/graph")
+            .unwrap();
+        session.execute("SESSION SET GRAPH /timeout/graph").unwrap();
+        db.set_transaction_timeout(Some(Duration::from_millis(100)));
+
+        let mut tx = session.transaction().unwrap();
+        tx.execute("INSERT (:Person {name: 'Alice'})").unwrap();
+        std::thread::sleep(Duration::from_millis(250));
+
+        let err = tx.execute("INSERT (:Person {name: 'Bob'})").unwrap_err();
+        assert!(
+            matches!(err, Error::TransactionTimedOut(_)),
+            "unexpected error: {}",
+            err
+        );
+        // The timed-out transaction was rolled back and is gone
+        assert!(tx.commit().is_err());
+        let result = session
+            .query("MATCH (p:Person) RETURN count(p) AS total")
+            .unwrap();
+        assert_eq!(
+            result.rows[0].values.get("total"),
+            Some(&graphlite::Value::Number(0.0))
+        );
+
+        // Activity resets the timer, and abandoned transactions can be swept
+        let mut tx = session.transaction().unwrap();
+        for i in 0..3 {
+            std::thread::sleep(Duration::from_millis(50));
+            tx.execute(&format!("INSERT (:Person {{name: 'Carol', n: {}}})", i))
+                .unwrap();
+        }
+        tx.set_drop_behavior(DropBehavior::Ignore);
+        drop(tx);
+        std::thread::sleep(Duration::from_millis(250));
+        assert_eq!(db.rollback_idle_transactions(), 1);
+        assert_eq!(db.rollback_idle_transactions(), 0);
+    }
+
+    #[test]
+    fn test_execute_many_inserts_parameterized_nodes() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let db = GraphLite::open(temp_dir.path().join("batch_db")).unwrap();
+        let session = db.session("admin").unwrap();
+        session
+            .execute("CREATE SCHEMA IF NOT EXISTS /batch")
+            .unwrap();
+        session
+            .execute("CREATE GRAPH IF NOT EXISTS /batch/graph")
+            .unwrap();
+        session.execute("SESSION SET GRAPH /batch/graph").unwrap();
+        session.execute("SESSION SET VALUE $id = 3").unwrap();
+
+        let parsed = Arc::new(Mutex::new(Vec::new()));
+        let sink = parsed.clone();
+        db.on_slow_query(Duration::ZERO, move |slow| {
+            sink.lock().unwrap().push(slow.query_text.clone());
+        });
+
+        let statement = "INSERT (:Item {id: $id, name: $name})";
+        let parameter_sets: Vec<HashMap<String, Value>> = (0..500)
+            .map(|i| {
+                HashMap::from([
+                    ("id".to_string(), Value::Number(i as f64)),
+                    ("name".to_string(), Value::String(format!("item-{}", i))),
+                ])
+            })
+            .collect();
+
+        let mut tx = session.transaction().unwrap();
+        let planned_before = db.metrics().statements_planned;
+        let batch = tx.execute_many(statement, &parameter_sets).unwrap();
+        assert_eq!(batch.results.len(), 500);
+        assert_eq!(batch.rows_affected, 500);
+        assert_eq!(db.metrics().statements_planned - planned_before, 1);
+        tx.commit().unwrap();
+
+        // The statement was parsed once for the whole batch, next to the
+        // BEGIN and COMMIT around it
+        let parsed_count = parsed
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|query| *query == statement)
+            .count();
+        assert_eq!(parsed_count, 1);
+
+        let result = session
+            .query("MATCH (i:Item) RETURN count(i) AS total, max(i.id) AS top")
+            .unwrap();
+        assert_eq!(result.rows[0].values["total"], Value::Number(500.0));
+        assert_eq!(result.rows[0].values["top"], Value::Number(499.0));
+        let result = session
+            .query("MATCH (i:Item {id: 42}) RETURN i.name AS name")
+            .unwrap();
+        assert_eq!(
+            result.rows[0].values["name"],
+            Value::String("item-42".to_string())
+        );
+
+        // The session's own parameter values were never replaced
+        let result = session
+            .query("MATCH (i:Item) RETURN i.id AS id ORDER BY id LIMIT $id")
+            .unwrap();
+        assert_eq!(result.rows.len(), 3);
+        assert!(session
+            .query("MATCH (i:Item) RETURN i.id AS id LIMIT $name")
+            .is_err());
+    }
 }